@@ -48,7 +48,7 @@ def translate_type_base(cty):
     if "c_char" in cty:
         return "u8"
     elif "c_int" in cty:
-        return "i32"
+        return "Int"
     elif "c_double_complex" in cty:
         return "c64"
     elif "c_float_complex" in cty:
@@ -64,7 +64,7 @@ def translate_body_argument(name, rty):
     if rty == "u8":
         return "&({} as c_char)".format(name)
 
-    elif rty == "i32":
+    elif rty == "Int":
         return "&{}".format(name)
 
     elif rty.startswith("f"):
@@ -97,9 +97,14 @@ def translate_return_type(cty):
 
     assert False, "cannot translate `{}`".format(cty)
 
+def returns_complex(f):
+    return f.args[0][0] == "pres"
+
 def format_header(f):
     args = format_header_arguments(f)
-    if f.ret is None:
+    if returns_complex(f):
+        return "pub unsafe fn {}({}) -> {}".format(f.name, args, translate_type_base(f.args[0][1]))
+    elif f.ret is None:
         return "pub unsafe fn {}({})".format(f.name, args)
     else:
         return "pub unsafe fn {}({}) -> {}".format(f.name, args, translate_return_type(f.ret))
@@ -107,20 +112,28 @@ def format_header(f):
 def format_body(f):
     args = format_body_arguments(f)
     ret = format_body_return(f)
-    if ret is None:
+    if returns_complex(f):
+        return "complex::{}({})".format(f.name, args)
+    elif ret is None:
         return "ffi::{}_({})".format(f.name, args)
     else:
         return "ffi::{}_({}) as {}".format(f.name, args, ret)
 
+def function_arguments(f):
+    if returns_complex(f):
+        return f.args[1:]
+    else:
+        return f.args
+
 def format_header_arguments(f):
     s = []
-    for arg in f.args:
+    for arg in function_arguments(f):
         s.append("{}: {}".format(arg[0], translate_argument(*arg, f=f)))
     return ", ".join(s)
 
 def format_body_arguments(f):
     s = []
-    for arg in f.args:
+    for arg in function_arguments(f):
         rty = translate_argument(*arg, f=f)
         s.append(translate_body_argument(arg[0], rty))
     return ", ".join(s)
@@ -141,6 +154,18 @@ def prepare(level, code):
     lines = filter(lambda line: not re.match(r'^\s*$', line), lines)
     return [Function.parse(level, line) for line in lines]
 
+def translate_declaration_type(cty):
+    cty = re.sub(r"\bc_int\b", "Int", cty)
+    cty = cty.replace("c_double_complex", "c64")
+    return cty.replace("c_float_complex", "c32")
+
+def format_declaration(f):
+    args = ", ".join("{}: {}".format(name, translate_declaration_type(cty)) for name, cty in f.args)
+    if f.ret is None:
+        return "pub fn {}_({});".format(f.name, args)
+    else:
+        return "pub fn {}_({}) -> {};".format(f.name, args, translate_declaration_type(f.ret))
+
 def do(functions, reference):
     for f in functions:
         if reference is not None:
@@ -149,13 +174,41 @@ def do(functions, reference):
         print(format_header(f) + " {")
         print("    " + format_body(f) + "\n}")
 
+def do_ilp64(functions):
+    print('\nextern "C" {')
+    for f in functions:
+        if returns_complex(f):
+            print('    #[cfg(not(feature = "complex-return"))]')
+        print('    #[link_name = "{}_64_"]'.format(f.name))
+        print("    " + format_declaration(f))
+    print("}")
+
+def do_dynamic(functions):
+    for f in functions:
+        if returns_complex(f):
+            print('    #[cfg(not(feature = "complex-return"))]')
+        print("    " + format_declaration(f))
+        if returns_complex(f):
+            value = Function(f.level, f.name, f.args[1:], f.args[0][1].replace("*mut ", ""))
+            print('    #[cfg(feature = "complex-return")]')
+            print("    " + format_declaration(value))
+
 if __name__ == '__main__':
     parser = argparse.ArgumentParser()
     parser.add_argument('--sys', required=True)
     parser.add_argument('--doc')
+    parser.add_argument('--ilp64', action='store_true')
+    parser.add_argument('--dynamic', action='store_true')
     arguments = parser.parse_args()
     sections = read_functions(os.path.join(arguments.sys, 'src', 'lib.rs'))
     assert(len(sections) == 3)
-    do(prepare(1, sections[0]), arguments.doc)
-    do(prepare(2, sections[1]), arguments.doc)
-    do(prepare(3, sections[2]), arguments.doc)
+    if arguments.ilp64:
+        for level, section in enumerate(sections, start=1):
+            do_ilp64(prepare(level, section))
+    elif arguments.dynamic:
+        for level, section in enumerate(sections, start=1):
+            do_dynamic(prepare(level, section))
+    else:
+        do(prepare(1, sections[0]), arguments.doc)
+        do(prepare(2, sections[1]), arguments.doc)
+        do(prepare(3, sections[2]), arguments.doc)
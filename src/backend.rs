@@ -0,0 +1,223 @@
+//! Introspection of the backend.
+//!
+//! The backend is detected by looking up symbols specific to each implementation, such as
+//! `openblas_get_config`, in the library loaded with the `dynamic` feature or, otherwise, among
+//! the shared libraries of the process, which requires a Unix system. A backend linked statically
+//! is usually invisible and is then reported as unknown. With the `ilp64` feature, the symbols of
+//! OpenBLAS and of the reference BLAS are looked up with the suffix `64_`.
+
+use std::ffi::CStr;
+use std::fmt;
+
+use libc::{c_char, c_int, c_void};
+
+#[cfg(not(feature = "ilp64"))]
+const SUFFIX: &str = "";
+#[cfg(feature = "ilp64")]
+const SUFFIX: &str = "64_";
+
+/// An implementation of BLAS.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Vendor {
+    /// Apple Accelerate.
+    Accelerate,
+    /// BLIS.
+    Blis,
+    /// Intel MKL.
+    Mkl,
+    /// The reference implementation of Netlib or another one without detectable extensions.
+    Netlib,
+    /// OpenBLAS.
+    OpenBlas,
+    /// The implementation in Rust enabled by the `reference` feature.
+    Reference,
+    /// An implementation that cannot be detected.
+    Unknown,
+}
+
+impl Vendor {
+    /// Return the name of the implementation.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Vendor::Accelerate => "Accelerate",
+            Vendor::Blis => "BLIS",
+            Vendor::Mkl => "MKL",
+            Vendor::Netlib => "Netlib",
+            Vendor::OpenBlas => "OpenBLAS",
+            Vendor::Reference => "Reference",
+            Vendor::Unknown => "Unknown",
+        }
+    }
+}
+
+impl fmt::Display for Vendor {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(self.name())
+    }
+}
+
+/// Return the implementation in use.
+pub fn vendor() -> Vendor {
+    if cfg!(feature = "reference") {
+        Vendor::Reference
+    } else if lookup("MKL_Get_Version_String").is_some() {
+        Vendor::Mkl
+    } else if lookup(&suffixed("openblas_get_config")).is_some() {
+        Vendor::OpenBlas
+    } else if lookup("bli_info_get_version_str").is_some() {
+        Vendor::Blis
+    } else if lookup("appleblas_sgeadd").is_some() || lookup("BLASGetThreading").is_some() {
+        Vendor::Accelerate
+    } else if lookup(&suffixed("dgemm_")).is_some() {
+        Vendor::Netlib
+    } else {
+        Vendor::Unknown
+    }
+}
+
+/// Return the version of the implementation if it is reported.
+///
+/// For OpenBLAS, the version is followed by the options of the build.
+pub fn version() -> Option<String> {
+    unsafe {
+        match vendor() {
+            Vendor::Mkl => {
+                let get: unsafe extern "C" fn(*mut c_char, c_int) =
+                    function("MKL_Get_Version_String")?;
+                let mut buffer = [0 as c_char; 256];
+                get(buffer.as_mut_ptr(), buffer.len() as c_int);
+                *buffer.last_mut().unwrap() = 0;
+                Some(string(buffer.as_ptr())?.trim().to_owned())
+            }
+            Vendor::OpenBlas => {
+                let get: unsafe extern "C" fn() -> *const c_char =
+                    function(&suffixed("openblas_get_config"))?;
+                let config = string(get())?;
+                Some(
+                    config
+                        .strip_prefix("OpenBLAS ")
+                        .unwrap_or(config)
+                        .to_owned(),
+                )
+            }
+            Vendor::Blis => {
+                let get: unsafe extern "C" fn() -> *const c_char =
+                    function("bli_info_get_version_str")?;
+                string(get()).map(str::to_owned)
+            }
+            Vendor::Reference => Some(env!("CARGO_PKG_VERSION").to_owned()),
+            _ => None,
+        }
+    }
+}
+
+/// Return the number of threads used by the implementation if it is reported.
+pub fn threads() -> Option<usize> {
+    let threads = unsafe {
+        match vendor() {
+            Vendor::Mkl => {
+                let get: unsafe extern "C" fn() -> c_int = function("MKL_Get_Max_Threads")?;
+                get() as i64
+            }
+            Vendor::OpenBlas => {
+                let get: unsafe extern "C" fn() -> c_int =
+                    function(&suffixed("openblas_get_num_threads"))?;
+                get() as i64
+            }
+            Vendor::Blis => {
+                let get: unsafe extern "C" fn() -> i64 = function("bli_thread_get_num_threads")?;
+                get()
+            }
+            Vendor::Reference => 1,
+            _ => return None,
+        }
+    };
+    usize::try_from(threads).ok().filter(|&threads| threads > 0)
+}
+
+/// Set the number of threads used by the implementation.
+///
+/// The function returns `false` if the implementation does not support setting the number or if
+/// `threads` is zero or out of range.
+pub fn set_threads(threads: usize) -> bool {
+    if threads == 0 {
+        return false;
+    }
+    unsafe {
+        match vendor() {
+            Vendor::Mkl => call("MKL_Set_Num_Threads", threads),
+            Vendor::OpenBlas => call(&suffixed("openblas_set_num_threads"), threads),
+            Vendor::Blis => {
+                let set = match function::<unsafe extern "C" fn(i64)>("bli_thread_set_num_threads")
+                {
+                    Some(set) => set,
+                    None => return false,
+                };
+                match i64::try_from(threads) {
+                    Ok(threads) => {
+                        set(threads);
+                        true
+                    }
+                    Err(_) => false,
+                }
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Call a setter taking a C integer.
+unsafe fn call(symbol: &str, threads: usize) -> bool {
+    match (
+        function::<unsafe extern "C" fn(c_int)>(symbol),
+        c_int::try_from(threads),
+    ) {
+        (Some(set), Ok(threads)) => {
+            set(threads);
+            true
+        }
+        _ => false,
+    }
+}
+
+fn suffixed(name: &str) -> String {
+    format!("{}{}", name, SUFFIX)
+}
+
+/// Return a function, which has type `F`.
+unsafe fn function<F: Copy>(symbol: &str) -> Option<F> {
+    let address = lookup(symbol)?;
+    Some(std::mem::transmute_copy::<*mut c_void, F>(&address))
+}
+
+unsafe fn string<'a>(pointer: *const c_char) -> Option<&'a str> {
+    if pointer.is_null() {
+        None
+    } else {
+        CStr::from_ptr(pointer).to_str().ok()
+    }
+}
+
+/// Return the address of a symbol.
+#[cfg(all(feature = "dynamic", not(feature = "reference")))]
+fn lookup(symbol: &str) -> Option<*mut c_void> {
+    crate::dynamic::lookup(symbol)
+}
+
+/// Return the address of a symbol.
+#[cfg(all(unix, not(all(feature = "dynamic", not(feature = "reference")))))]
+fn lookup(symbol: &str) -> Option<*mut c_void> {
+    let symbol = std::ffi::CString::new(symbol).ok()?;
+    let address = unsafe { libc::dlsym(libc::RTLD_DEFAULT, symbol.as_ptr()) };
+    if address.is_null() {
+        None
+    } else {
+        Some(address)
+    }
+}
+
+/// Return the address of a symbol.
+#[cfg(not(any(unix, all(feature = "dynamic", not(feature = "reference")))))]
+fn lookup(_: &str) -> Option<*mut c_void> {
+    None
+}
@@ -0,0 +1,619 @@
+//! Matrices in band storage.
+//!
+//! A band matrix stores its diagonals in the rows of a column-major array whose columns are
+//! those of the matrix, which is the layout expected by `gbmv`, `sbmv`, `hbmv`, `tbmv`, and
+//! `tbsv`. A general band matrix with `kl` subdiagonals and `ku` superdiagonals keeps element
+//! `(i, j)` in row `ku + i - j` of column `j`, and the leading dimension is at least
+//! `kl + ku + 1`. A symmetric, Hermitian, or triangular band matrix with `k` off-diagonals keeps
+//! one triangle in the same way with `kl = 0` or `ku = 0`. The types in this module couple the
+//! data, which can be owned or borrowed, with this layout and call the band routines with it.
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use blas::band::BandMatrix;
+//!
+//! let dense = vec![
+//!     1.0, 4.0, 0.0,
+//!     2.0, 5.0, 7.0,
+//!     0.0, 6.0, 8.0,
+//! ];
+//! let a = BandMatrix::from_dense(3, 3, 1, 1, &dense, 3).unwrap();
+//! assert_eq!(a.get(2, 1), Some(7.0));
+//! assert_eq!(a.get(2, 0), Some(0.0));
+//!
+//! let x = vec![1.0, 1.0, 1.0];
+//! let mut y = vec![0.0; 3];
+//! a.mul_vec(blas::Transpose::None, 1.0, (&x[..]).into(), 0.0, (&mut y[..]).into())
+//!     .unwrap();
+//! assert_eq!(y, vec![3.0, 15.0, 15.0]);
+//! ```
+
+use core::marker::PhantomData;
+
+#[cfg(feature = "alloc")]
+use alloc::vec;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::safe::{check_dimension, check_leading_dimension, check_len, matrix_len, BlasError};
+use crate::view::{self, check_match, MatRef, VecMut, VecRef};
+use crate::{Blas, Diag, Int, Scalar, Transpose, Uplo};
+
+/// A general band matrix.
+#[derive(Clone, Copy, Debug)]
+pub struct BandMatrix<T, D> {
+    data: D,
+    rows: Int,
+    cols: Int,
+    kl: Int,
+    ku: Int,
+    ld: Int,
+    marker: PhantomData<T>,
+}
+
+/// A symmetric band matrix.
+#[derive(Clone, Copy, Debug)]
+pub struct SymmetricBand<T, D> {
+    band: Band<T, D>,
+}
+
+/// A Hermitian band matrix.
+///
+/// The imaginary parts of the diagonal are assumed to be zero.
+#[derive(Clone, Copy, Debug)]
+pub struct HermitianBand<T, D> {
+    band: Band<T, D>,
+}
+
+/// A triangular band matrix.
+///
+/// With a unit diagonal, the diagonal is not referenced and reads as one.
+#[derive(Clone, Copy, Debug)]
+pub struct TriangularBand<T, D> {
+    band: Band<T, D>,
+    diag: Diag,
+}
+
+/// The data and layout shared by the square band matrices.
+#[derive(Clone, Copy, Debug)]
+struct Band<T, D> {
+    data: D,
+    n: Int,
+    k: Int,
+    ld: Int,
+    uplo: Uplo,
+    marker: PhantomData<T>,
+}
+
+impl<T: Scalar, D: AsRef<[T]>> BandMatrix<T, D> {
+    /// Create a matrix with `rows` rows, `cols` columns, `kl` subdiagonals, and `ku`
+    /// superdiagonals whose band is stored in `data` with leading dimension `ld`.
+    pub fn new(
+        rows: Int,
+        cols: Int,
+        kl: Int,
+        ku: Int,
+        data: D,
+        ld: Int,
+    ) -> Result<Self, BlasError> {
+        let routine = "BandMatrix::new";
+        check_dimension(routine, 1, rows)?;
+        check_dimension(routine, 2, cols)?;
+        check_dimension(routine, 3, kl)?;
+        check_dimension(routine, 4, ku)?;
+        let height = kl.saturating_add(ku).saturating_add(1);
+        check_leading_dimension(routine, 6, ld, height)?;
+        check_len(
+            routine,
+            5,
+            matrix_len(height, cols, ld),
+            data.as_ref().len(),
+        )?;
+        Ok(BandMatrix {
+            data,
+            rows,
+            cols,
+            kl,
+            ku,
+            ld,
+            marker: PhantomData,
+        })
+    }
+
+    /// Return the number of rows.
+    #[inline]
+    pub fn rows(&self) -> Int {
+        self.rows
+    }
+
+    /// Return the number of columns.
+    #[inline]
+    pub fn cols(&self) -> Int {
+        self.cols
+    }
+
+    /// Return the number of subdiagonals.
+    #[inline]
+    pub fn kl(&self) -> Int {
+        self.kl
+    }
+
+    /// Return the number of superdiagonals.
+    #[inline]
+    pub fn ku(&self) -> Int {
+        self.ku
+    }
+
+    /// Return the leading dimension.
+    #[inline]
+    pub fn ld(&self) -> Int {
+        self.ld
+    }
+
+    /// Return the band storage.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        self.data.as_ref()
+    }
+
+    /// Return the underlying data.
+    #[inline]
+    pub fn into_inner(self) -> D {
+        self.data
+    }
+
+    /// Return element `(i, j)`, which is zero outside of the band.
+    #[inline]
+    pub fn get(&self, i: Int, j: Int) -> Option<T> {
+        if i < 0 || j < 0 || i >= self.rows || j >= self.cols {
+            return None;
+        }
+        match self.position(i, j) {
+            Some(position) => Some(self.data.as_ref()[position]),
+            None => Some(T::zero()),
+        }
+    }
+
+    /// Return the stored element `(i, j)`, which is in the band.
+    #[inline]
+    pub fn get_mut(&mut self, i: Int, j: Int) -> Option<&mut T>
+    where
+        D: AsMut<[T]>,
+    {
+        if i < 0 || j < 0 || i >= self.rows || j >= self.cols {
+            return None;
+        }
+        let position = self.position(i, j)?;
+        Some(&mut self.data.as_mut()[position])
+    }
+
+    /// Return the matrix in dense column-major storage with leading dimension `rows`.
+    #[cfg(feature = "alloc")]
+    pub fn to_dense(&self) -> Vec<T> {
+        let mut dense = Vec::with_capacity((self.rows as usize) * (self.cols as usize));
+        for j in 0..self.cols {
+            dense.extend((0..self.rows).map(|i| self.get(i, j).unwrap()));
+        }
+        dense
+    }
+
+    /// Return the position of element `(i, j)` if it is in the band.
+    #[inline]
+    fn position(&self, i: Int, j: Int) -> Option<usize> {
+        if j - i <= self.ku && i - j <= self.kl {
+            Some((self.ku - (j - i)) as usize + j as usize * self.ld as usize)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Scalar> BandMatrix<T, Vec<T>> {
+    /// Extract the band of a dense matrix with `rows` rows, `cols` columns, and leading
+    /// dimension `lda`.
+    ///
+    /// The band is stored with leading dimension `kl + ku + 1`, and the elements outside of it
+    /// are ignored.
+    pub fn from_dense(
+        rows: Int,
+        cols: Int,
+        kl: Int,
+        ku: Int,
+        a: &[T],
+        lda: Int,
+    ) -> Result<Self, BlasError> {
+        let routine = "BandMatrix::from_dense";
+        check_dimension(routine, 1, rows)?;
+        check_dimension(routine, 2, cols)?;
+        check_dimension(routine, 3, kl)?;
+        check_dimension(routine, 4, ku)?;
+        check_leading_dimension(routine, 6, lda, rows.max(1))?;
+        check_len(routine, 5, matrix_len(rows, cols, lda), a.len())?;
+        let height = kl.saturating_add(ku).saturating_add(1);
+        let mut band = BandMatrix {
+            data: vec![T::zero(); matrix_len(height, cols, height)],
+            rows,
+            cols,
+            kl,
+            ku,
+            ld: height,
+            marker: PhantomData,
+        };
+        for j in 0..cols {
+            for i in 0..rows {
+                if let Some(position) = band.position(i, j) {
+                    band.data[position] = a[i as usize + j as usize * lda as usize];
+                }
+            }
+        }
+        Ok(band)
+    }
+}
+
+impl<T: Blas, D: AsRef<[T]>> BandMatrix<T, D> {
+    /// Compute `y := alpha * op(A) * x + beta * y`.
+    pub fn mul_vec(
+        &self,
+        trans: Transpose,
+        alpha: T,
+        x: VecRef<T>,
+        beta: T,
+        y: VecMut<T>,
+    ) -> Result<(), BlasError> {
+        let routine = "BandMatrix::mul_vec";
+        let (rows, cols) = match trans {
+            Transpose::None => (self.rows, self.cols),
+            _ => (self.cols, self.rows),
+        };
+        check_match(routine, 3, x.len(), cols)?;
+        check_match(routine, 5, y.len(), rows)?;
+        let a = self.storage()?;
+        view::gbmv(trans, self.kl, self.ku, alpha, a, x, beta, y)
+    }
+
+    #[inline]
+    fn storage(&self) -> Result<MatRef<'_, T>, BlasError> {
+        let height = self.kl.saturating_add(self.ku).saturating_add(1);
+        MatRef::new(self.data.as_ref(), height, self.cols, self.ld)
+    }
+}
+
+macro_rules! implement {
+    ($name:ident) => {
+        impl<T: Scalar, D: AsRef<[T]>> $name<T, D> {
+            /// Return the order.
+            #[inline]
+            pub fn n(&self) -> Int {
+                self.band.n
+            }
+
+            /// Return the number of off-diagonals in the stored triangle.
+            #[inline]
+            pub fn k(&self) -> Int {
+                self.band.k
+            }
+
+            /// Return the leading dimension.
+            #[inline]
+            pub fn ld(&self) -> Int {
+                self.band.ld
+            }
+
+            /// Return the stored triangle.
+            #[inline]
+            pub fn uplo(&self) -> Uplo {
+                self.band.uplo
+            }
+
+            /// Return the band storage.
+            #[inline]
+            pub fn as_slice(&self) -> &[T] {
+                self.band.data.as_ref()
+            }
+
+            /// Return the underlying data.
+            #[inline]
+            pub fn into_inner(self) -> D {
+                self.band.data
+            }
+
+            /// Return the stored element `(i, j)`, which is in the band of the stored triangle.
+            #[inline]
+            pub fn get_mut(&mut self, i: Int, j: Int) -> Option<&mut T>
+            where
+                D: AsMut<[T]>,
+            {
+                let position = self.band.position(i, j)?;
+                Some(&mut self.band.data.as_mut()[position])
+            }
+
+            /// Return the matrix in dense column-major storage with leading dimension `n`.
+            #[cfg(feature = "alloc")]
+            pub fn to_dense(&self) -> Vec<T> {
+                let n = self.band.n;
+                let mut dense = Vec::with_capacity((n as usize) * (n as usize));
+                for j in 0..n {
+                    dense.extend((0..n).map(|i| self.get(i, j).unwrap()));
+                }
+                dense
+            }
+        }
+    };
+}
+
+implement!(SymmetricBand);
+implement!(HermitianBand);
+implement!(TriangularBand);
+
+impl<T: Scalar, D: AsRef<[T]>> SymmetricBand<T, D> {
+    /// Create a symmetric matrix of order `n` with `k` off-diagonals whose triangle `uplo` is
+    /// stored in `data` with leading dimension `ld`.
+    pub fn new(uplo: Uplo, n: Int, k: Int, data: D, ld: Int) -> Result<Self, BlasError> {
+        Ok(SymmetricBand {
+            band: Band::new(("SymmetricBand::new", 2), uplo, n, k, data, ld)?,
+        })
+    }
+
+    /// Return element `(i, j)`, which is zero outside of the band.
+    #[inline]
+    pub fn get(&self, i: Int, j: Int) -> Option<T> {
+        self.band.get(i, j, |value| value)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Scalar> SymmetricBand<T, Vec<T>> {
+    /// Extract the band of the triangle `uplo` of a dense matrix of order `n` with leading
+    /// dimension `lda`.
+    ///
+    /// The band is stored with leading dimension `k + 1`.
+    pub fn from_dense(uplo: Uplo, n: Int, k: Int, a: &[T], lda: Int) -> Result<Self, BlasError> {
+        Ok(SymmetricBand {
+            band: Band::from_dense(("SymmetricBand::from_dense", 2), uplo, n, k, a, lda)?,
+        })
+    }
+}
+
+impl<T: Blas<Real = T>, D: AsRef<[T]>> SymmetricBand<T, D> {
+    /// Compute `y := alpha * A * x + beta * y`.
+    pub fn mul_vec(&self, alpha: T, x: VecRef<T>, beta: T, y: VecMut<T>) -> Result<(), BlasError> {
+        self.band
+            .mul_vec("SymmetricBand::mul_vec", alpha, x, beta, y)
+    }
+}
+
+impl<T: Scalar, D: AsRef<[T]>> HermitianBand<T, D> {
+    /// Create a Hermitian matrix of order `n` with `k` off-diagonals whose triangle `uplo` is
+    /// stored in `data` with leading dimension `ld`.
+    pub fn new(uplo: Uplo, n: Int, k: Int, data: D, ld: Int) -> Result<Self, BlasError> {
+        Ok(HermitianBand {
+            band: Band::new(("HermitianBand::new", 2), uplo, n, k, data, ld)?,
+        })
+    }
+
+    /// Return element `(i, j)`, which is zero outside of the band.
+    #[inline]
+    pub fn get(&self, i: Int, j: Int) -> Option<T> {
+        let value = self.band.get(i, j, T::conj)?;
+        if i == j {
+            Some(T::from_real(value.re()))
+        } else {
+            Some(value)
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Scalar> HermitianBand<T, Vec<T>> {
+    /// Extract the band of the triangle `uplo` of a dense matrix of order `n` with leading
+    /// dimension `lda`.
+    ///
+    /// The band is stored with leading dimension `k + 1`.
+    pub fn from_dense(uplo: Uplo, n: Int, k: Int, a: &[T], lda: Int) -> Result<Self, BlasError> {
+        Ok(HermitianBand {
+            band: Band::from_dense(("HermitianBand::from_dense", 2), uplo, n, k, a, lda)?,
+        })
+    }
+}
+
+impl<T: Blas, D: AsRef<[T]>> HermitianBand<T, D> {
+    /// Compute `y := alpha * A * x + beta * y`.
+    pub fn mul_vec(&self, alpha: T, x: VecRef<T>, beta: T, y: VecMut<T>) -> Result<(), BlasError> {
+        self.band
+            .mul_vec("HermitianBand::mul_vec", alpha, x, beta, y)
+    }
+}
+
+impl<T: Scalar, D: AsRef<[T]>> TriangularBand<T, D> {
+    /// Create a triangular matrix of order `n` with `k` off-diagonals whose triangle `uplo` is
+    /// stored in `data` with leading dimension `ld`.
+    pub fn new(
+        uplo: Uplo,
+        diag: Diag,
+        n: Int,
+        k: Int,
+        data: D,
+        ld: Int,
+    ) -> Result<Self, BlasError> {
+        Ok(TriangularBand {
+            band: Band::new(("TriangularBand::new", 3), uplo, n, k, data, ld)?,
+            diag,
+        })
+    }
+
+    /// Return the kind of the diagonal.
+    #[inline]
+    pub fn diag(&self) -> Diag {
+        self.diag
+    }
+
+    /// Return element `(i, j)`, which is zero outside of the band of the stored triangle.
+    #[inline]
+    pub fn get(&self, i: Int, j: Int) -> Option<T> {
+        if i < 0 || j < 0 || i >= self.band.n || j >= self.band.n {
+            return None;
+        }
+        if i == j && self.diag == Diag::Unit {
+            return Some(T::one());
+        }
+        match self.band.position(i, j) {
+            Some(position) => Some(self.band.data.as_ref()[position]),
+            None => Some(T::zero()),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Scalar> TriangularBand<T, Vec<T>> {
+    /// Extract the band of the triangle `uplo` of a dense matrix of order `n` with leading
+    /// dimension `lda`.
+    ///
+    /// The band is stored with leading dimension `k + 1`.
+    pub fn from_dense(
+        uplo: Uplo,
+        diag: Diag,
+        n: Int,
+        k: Int,
+        a: &[T],
+        lda: Int,
+    ) -> Result<Self, BlasError> {
+        Ok(TriangularBand {
+            band: Band::from_dense(("TriangularBand::from_dense", 3), uplo, n, k, a, lda)?,
+            diag,
+        })
+    }
+}
+
+impl<T: Blas, D: AsRef<[T]>> TriangularBand<T, D> {
+    /// Compute `x := op(A) * x`.
+    pub fn mul_vec_in_place(&self, trans: Transpose, x: VecMut<T>) -> Result<(), BlasError> {
+        check_match("TriangularBand::mul_vec_in_place", 2, x.len(), self.band.n)?;
+        let (uplo, k) = (self.band.uplo, self.band.k);
+        view::tbmv(uplo, trans, self.diag, k, self.band.storage()?, x)
+    }
+
+    /// Solve `op(A) * x = b`, where `x` holds `b` on entry.
+    pub fn solve_in_place(&self, trans: Transpose, x: VecMut<T>) -> Result<(), BlasError> {
+        check_match("TriangularBand::solve_in_place", 2, x.len(), self.band.n)?;
+        let (uplo, k) = (self.band.uplo, self.band.k);
+        view::tbsv(uplo, trans, self.diag, k, self.band.storage()?, x)
+    }
+}
+
+impl<T: Scalar, D: AsRef<[T]>> Band<T, D> {
+    /// Check the order, which is parameter `param` of `routine`, and the arguments following it.
+    fn new(
+        (routine, param): (&'static str, usize),
+        uplo: Uplo,
+        n: Int,
+        k: Int,
+        data: D,
+        ld: Int,
+    ) -> Result<Self, BlasError> {
+        check_dimension(routine, param, n)?;
+        check_dimension(routine, param + 1, k)?;
+        check_leading_dimension(routine, param + 3, ld, k.saturating_add(1))?;
+        let len = matrix_len(k.saturating_add(1), n, ld);
+        check_len(routine, param + 2, len, data.as_ref().len())?;
+        Ok(Band {
+            data,
+            n,
+            k,
+            ld,
+            uplo,
+            marker: PhantomData,
+        })
+    }
+
+    /// Return element `(i, j)`, applying `mirror` to the elements of the other triangle.
+    #[inline]
+    fn get(&self, i: Int, j: Int, mirror: impl Fn(T) -> T) -> Option<T> {
+        if i < 0 || j < 0 || i >= self.n || j >= self.n {
+            return None;
+        }
+        let data = self.data.as_ref();
+        if let Some(position) = self.position(i, j) {
+            return Some(data[position]);
+        }
+        match self.position(j, i) {
+            Some(position) => Some(mirror(data[position])),
+            None => Some(T::zero()),
+        }
+    }
+
+    /// Return the position of element `(i, j)` if it is in the band of the stored triangle.
+    #[inline]
+    fn position(&self, i: Int, j: Int) -> Option<usize> {
+        if i < 0 || j < 0 || i >= self.n || j >= self.n {
+            return None;
+        }
+        let (i, j, k, ld) = (i as usize, j as usize, self.k as usize, self.ld as usize);
+        match self.uplo {
+            Uplo::Upper if i <= j && j - i <= k => Some(k + i - j + j * ld),
+            Uplo::Lower if i >= j && i - j <= k => Some(i - j + j * ld),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn storage(&self) -> Result<MatRef<'_, T>, BlasError> {
+        MatRef::new(
+            self.data.as_ref(),
+            self.k.saturating_add(1),
+            self.n,
+            self.ld,
+        )
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Scalar> Band<T, Vec<T>> {
+    /// Extract the band of a dense matrix whose order is parameter `param` of `routine`.
+    fn from_dense(
+        (routine, param): (&'static str, usize),
+        uplo: Uplo,
+        n: Int,
+        k: Int,
+        a: &[T],
+        lda: Int,
+    ) -> Result<Self, BlasError> {
+        check_dimension(routine, param, n)?;
+        check_dimension(routine, param + 1, k)?;
+        check_leading_dimension(routine, param + 3, lda, n.max(1))?;
+        check_len(routine, param + 2, matrix_len(n, n, lda), a.len())?;
+        let height = k.saturating_add(1);
+        let mut band = Band {
+            data: vec![T::zero(); matrix_len(height, n, height)],
+            n,
+            k,
+            ld: height,
+            uplo,
+            marker: PhantomData,
+        };
+        for j in 0..n {
+            for i in 0..n {
+                if let Some(position) = band.position(i, j) {
+                    band.data[position] = a[i as usize + j as usize * lda as usize];
+                }
+            }
+        }
+        Ok(band)
+    }
+}
+
+impl<T: Blas, D: AsRef<[T]>> Band<T, D> {
+    fn mul_vec(
+        &self,
+        routine: &'static str,
+        alpha: T,
+        x: VecRef<T>,
+        beta: T,
+        y: VecMut<T>,
+    ) -> Result<(), BlasError> {
+        check_match(routine, 2, x.len(), self.n)?;
+        check_match(routine, 4, y.len(), self.n)?;
+        view::hbmv(self.uplo, self.k, alpha, self.storage()?, x, beta, y)
+    }
+}
@@ -0,0 +1,1321 @@
+//! Declarations of the ILP64 interface of CBLAS, whose integers are 64 bits wide and whose
+//! symbols are suffixed with `64_`.
+
+use core::ffi::{c_double, c_float};
+
+pub use cblas_sys::*;
+
+use crate::Int;
+
+extern "C" {
+    #[link_name = "cblas_sgemv64_"]
+    pub fn cblas_sgemv(
+        layout: CBLAS_LAYOUT,
+        transa: CBLAS_TRANSPOSE,
+        m: Int,
+        n: Int,
+        alpha: c_float,
+        a: *const c_float,
+        lda: Int,
+        x: *const c_float,
+        incx: Int,
+        beta: c_float,
+        y: *mut c_float,
+        incy: Int,
+    );
+    #[link_name = "cblas_sgbmv64_"]
+    pub fn cblas_sgbmv(
+        layout: CBLAS_LAYOUT,
+        transa: CBLAS_TRANSPOSE,
+        m: Int,
+        n: Int,
+        kl: Int,
+        ku: Int,
+        alpha: c_float,
+        a: *const c_float,
+        lda: Int,
+        x: *const c_float,
+        incx: Int,
+        beta: c_float,
+        y: *mut c_float,
+        incy: Int,
+    );
+    #[link_name = "cblas_strmv64_"]
+    pub fn cblas_strmv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: Int,
+        a: *const c_float,
+        lda: Int,
+        x: *mut c_float,
+        incx: Int,
+    );
+    #[link_name = "cblas_stbmv64_"]
+    pub fn cblas_stbmv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: Int,
+        k: Int,
+        a: *const c_float,
+        lda: Int,
+        x: *mut c_float,
+        incx: Int,
+    );
+    #[link_name = "cblas_stpmv64_"]
+    pub fn cblas_stpmv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: Int,
+        ap: *const c_float,
+        x: *mut c_float,
+        incx: Int,
+    );
+    #[link_name = "cblas_strsv64_"]
+    pub fn cblas_strsv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: Int,
+        a: *const c_float,
+        lda: Int,
+        x: *mut c_float,
+        incx: Int,
+    );
+    #[link_name = "cblas_stbsv64_"]
+    pub fn cblas_stbsv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: Int,
+        k: Int,
+        a: *const c_float,
+        lda: Int,
+        x: *mut c_float,
+        incx: Int,
+    );
+    #[link_name = "cblas_stpsv64_"]
+    pub fn cblas_stpsv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: Int,
+        ap: *const c_float,
+        x: *mut c_float,
+        incx: Int,
+    );
+    #[link_name = "cblas_dgemv64_"]
+    pub fn cblas_dgemv(
+        layout: CBLAS_LAYOUT,
+        transa: CBLAS_TRANSPOSE,
+        m: Int,
+        n: Int,
+        alpha: c_double,
+        a: *const c_double,
+        lda: Int,
+        x: *const c_double,
+        incx: Int,
+        beta: c_double,
+        y: *mut c_double,
+        incy: Int,
+    );
+    #[link_name = "cblas_dgbmv64_"]
+    pub fn cblas_dgbmv(
+        layout: CBLAS_LAYOUT,
+        transa: CBLAS_TRANSPOSE,
+        m: Int,
+        n: Int,
+        kl: Int,
+        ku: Int,
+        alpha: c_double,
+        a: *const c_double,
+        lda: Int,
+        x: *const c_double,
+        incx: Int,
+        beta: c_double,
+        y: *mut c_double,
+        incy: Int,
+    );
+    #[link_name = "cblas_dtrmv64_"]
+    pub fn cblas_dtrmv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: Int,
+        a: *const c_double,
+        lda: Int,
+        x: *mut c_double,
+        incx: Int,
+    );
+    #[link_name = "cblas_dtbmv64_"]
+    pub fn cblas_dtbmv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: Int,
+        k: Int,
+        a: *const c_double,
+        lda: Int,
+        x: *mut c_double,
+        incx: Int,
+    );
+    #[link_name = "cblas_dtpmv64_"]
+    pub fn cblas_dtpmv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: Int,
+        ap: *const c_double,
+        x: *mut c_double,
+        incx: Int,
+    );
+    #[link_name = "cblas_dtrsv64_"]
+    pub fn cblas_dtrsv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: Int,
+        a: *const c_double,
+        lda: Int,
+        x: *mut c_double,
+        incx: Int,
+    );
+    #[link_name = "cblas_dtbsv64_"]
+    pub fn cblas_dtbsv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: Int,
+        k: Int,
+        a: *const c_double,
+        lda: Int,
+        x: *mut c_double,
+        incx: Int,
+    );
+    #[link_name = "cblas_dtpsv64_"]
+    pub fn cblas_dtpsv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: Int,
+        ap: *const c_double,
+        x: *mut c_double,
+        incx: Int,
+    );
+    #[link_name = "cblas_cgemv64_"]
+    pub fn cblas_cgemv(
+        layout: CBLAS_LAYOUT,
+        transa: CBLAS_TRANSPOSE,
+        m: Int,
+        n: Int,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: Int,
+        x: *const c_float_complex,
+        incx: Int,
+        beta: *const c_float_complex,
+        y: *mut c_float_complex,
+        incy: Int,
+    );
+    #[link_name = "cblas_cgbmv64_"]
+    pub fn cblas_cgbmv(
+        layout: CBLAS_LAYOUT,
+        transa: CBLAS_TRANSPOSE,
+        m: Int,
+        n: Int,
+        kl: Int,
+        ku: Int,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: Int,
+        x: *const c_float_complex,
+        incx: Int,
+        beta: *const c_float_complex,
+        y: *mut c_float_complex,
+        incy: Int,
+    );
+    #[link_name = "cblas_ctrmv64_"]
+    pub fn cblas_ctrmv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: Int,
+        a: *const c_float_complex,
+        lda: Int,
+        x: *mut c_float_complex,
+        incx: Int,
+    );
+    #[link_name = "cblas_ctbmv64_"]
+    pub fn cblas_ctbmv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: Int,
+        k: Int,
+        a: *const c_float_complex,
+        lda: Int,
+        x: *mut c_float_complex,
+        incx: Int,
+    );
+    #[link_name = "cblas_ctpmv64_"]
+    pub fn cblas_ctpmv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: Int,
+        ap: *const c_float_complex,
+        x: *mut c_float_complex,
+        incx: Int,
+    );
+    #[link_name = "cblas_ctrsv64_"]
+    pub fn cblas_ctrsv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: Int,
+        a: *const c_float_complex,
+        lda: Int,
+        x: *mut c_float_complex,
+        incx: Int,
+    );
+    #[link_name = "cblas_ctbsv64_"]
+    pub fn cblas_ctbsv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: Int,
+        k: Int,
+        a: *const c_float_complex,
+        lda: Int,
+        x: *mut c_float_complex,
+        incx: Int,
+    );
+    #[link_name = "cblas_ctpsv64_"]
+    pub fn cblas_ctpsv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: Int,
+        ap: *const c_float_complex,
+        x: *mut c_float_complex,
+        incx: Int,
+    );
+    #[link_name = "cblas_zgemv64_"]
+    pub fn cblas_zgemv(
+        layout: CBLAS_LAYOUT,
+        transa: CBLAS_TRANSPOSE,
+        m: Int,
+        n: Int,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: Int,
+        x: *const c_double_complex,
+        incx: Int,
+        beta: *const c_double_complex,
+        y: *mut c_double_complex,
+        incy: Int,
+    );
+    #[link_name = "cblas_zgbmv64_"]
+    pub fn cblas_zgbmv(
+        layout: CBLAS_LAYOUT,
+        transa: CBLAS_TRANSPOSE,
+        m: Int,
+        n: Int,
+        kl: Int,
+        ku: Int,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: Int,
+        x: *const c_double_complex,
+        incx: Int,
+        beta: *const c_double_complex,
+        y: *mut c_double_complex,
+        incy: Int,
+    );
+    #[link_name = "cblas_ztrmv64_"]
+    pub fn cblas_ztrmv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: Int,
+        a: *const c_double_complex,
+        lda: Int,
+        x: *mut c_double_complex,
+        incx: Int,
+    );
+    #[link_name = "cblas_ztbmv64_"]
+    pub fn cblas_ztbmv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: Int,
+        k: Int,
+        a: *const c_double_complex,
+        lda: Int,
+        x: *mut c_double_complex,
+        incx: Int,
+    );
+    #[link_name = "cblas_ztpmv64_"]
+    pub fn cblas_ztpmv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: Int,
+        ap: *const c_double_complex,
+        x: *mut c_double_complex,
+        incx: Int,
+    );
+    #[link_name = "cblas_ztrsv64_"]
+    pub fn cblas_ztrsv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: Int,
+        a: *const c_double_complex,
+        lda: Int,
+        x: *mut c_double_complex,
+        incx: Int,
+    );
+    #[link_name = "cblas_ztbsv64_"]
+    pub fn cblas_ztbsv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: Int,
+        k: Int,
+        a: *const c_double_complex,
+        lda: Int,
+        x: *mut c_double_complex,
+        incx: Int,
+    );
+    #[link_name = "cblas_ztpsv64_"]
+    pub fn cblas_ztpsv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: Int,
+        ap: *const c_double_complex,
+        x: *mut c_double_complex,
+        incx: Int,
+    );
+    #[link_name = "cblas_ssymv64_"]
+    pub fn cblas_ssymv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        n: Int,
+        alpha: c_float,
+        a: *const c_float,
+        lda: Int,
+        x: *const c_float,
+        incx: Int,
+        beta: c_float,
+        y: *mut c_float,
+        incy: Int,
+    );
+    #[link_name = "cblas_ssbmv64_"]
+    pub fn cblas_ssbmv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        n: Int,
+        k: Int,
+        alpha: c_float,
+        a: *const c_float,
+        lda: Int,
+        x: *const c_float,
+        incx: Int,
+        beta: c_float,
+        y: *mut c_float,
+        incy: Int,
+    );
+    #[link_name = "cblas_sspmv64_"]
+    pub fn cblas_sspmv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        n: Int,
+        alpha: c_float,
+        ap: *const c_float,
+        x: *const c_float,
+        incx: Int,
+        beta: c_float,
+        y: *mut c_float,
+        incy: Int,
+    );
+    #[link_name = "cblas_sger64_"]
+    pub fn cblas_sger(
+        layout: CBLAS_LAYOUT,
+        m: Int,
+        n: Int,
+        alpha: c_float,
+        x: *const c_float,
+        incx: Int,
+        y: *const c_float,
+        incy: Int,
+        a: *mut c_float,
+        lda: Int,
+    );
+    #[link_name = "cblas_ssyr64_"]
+    pub fn cblas_ssyr(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        n: Int,
+        alpha: c_float,
+        x: *const c_float,
+        incx: Int,
+        a: *mut c_float,
+        lda: Int,
+    );
+    #[link_name = "cblas_sspr64_"]
+    pub fn cblas_sspr(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        n: Int,
+        alpha: c_float,
+        x: *const c_float,
+        incx: Int,
+        ap: *mut c_float,
+    );
+    #[link_name = "cblas_ssyr264_"]
+    pub fn cblas_ssyr2(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        n: Int,
+        alpha: c_float,
+        x: *const c_float,
+        incx: Int,
+        y: *const c_float,
+        incy: Int,
+        a: *mut c_float,
+        lda: Int,
+    );
+    #[link_name = "cblas_sspr264_"]
+    pub fn cblas_sspr2(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        n: Int,
+        alpha: c_float,
+        x: *const c_float,
+        incx: Int,
+        y: *const c_float,
+        incy: Int,
+        a: *mut c_float,
+    );
+    #[link_name = "cblas_dsymv64_"]
+    pub fn cblas_dsymv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        n: Int,
+        alpha: c_double,
+        a: *const c_double,
+        lda: Int,
+        x: *const c_double,
+        incx: Int,
+        beta: c_double,
+        y: *mut c_double,
+        incy: Int,
+    );
+    #[link_name = "cblas_dsbmv64_"]
+    pub fn cblas_dsbmv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        n: Int,
+        k: Int,
+        alpha: c_double,
+        a: *const c_double,
+        lda: Int,
+        x: *const c_double,
+        incx: Int,
+        beta: c_double,
+        y: *mut c_double,
+        incy: Int,
+    );
+    #[link_name = "cblas_dspmv64_"]
+    pub fn cblas_dspmv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        n: Int,
+        alpha: c_double,
+        ap: *const c_double,
+        x: *const c_double,
+        incx: Int,
+        beta: c_double,
+        y: *mut c_double,
+        incy: Int,
+    );
+    #[link_name = "cblas_dger64_"]
+    pub fn cblas_dger(
+        layout: CBLAS_LAYOUT,
+        m: Int,
+        n: Int,
+        alpha: c_double,
+        x: *const c_double,
+        incx: Int,
+        y: *const c_double,
+        incy: Int,
+        a: *mut c_double,
+        lda: Int,
+    );
+    #[link_name = "cblas_dsyr64_"]
+    pub fn cblas_dsyr(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        n: Int,
+        alpha: c_double,
+        x: *const c_double,
+        incx: Int,
+        a: *mut c_double,
+        lda: Int,
+    );
+    #[link_name = "cblas_dspr64_"]
+    pub fn cblas_dspr(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        n: Int,
+        alpha: c_double,
+        x: *const c_double,
+        incx: Int,
+        ap: *mut c_double,
+    );
+    #[link_name = "cblas_dsyr264_"]
+    pub fn cblas_dsyr2(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        n: Int,
+        alpha: c_double,
+        x: *const c_double,
+        incx: Int,
+        y: *const c_double,
+        incy: Int,
+        a: *mut c_double,
+        lda: Int,
+    );
+    #[link_name = "cblas_dspr264_"]
+    pub fn cblas_dspr2(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        n: Int,
+        alpha: c_double,
+        x: *const c_double,
+        incx: Int,
+        y: *const c_double,
+        incy: Int,
+        a: *mut c_double,
+    );
+    #[link_name = "cblas_chemv64_"]
+    pub fn cblas_chemv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        n: Int,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: Int,
+        x: *const c_float_complex,
+        incx: Int,
+        beta: *const c_float_complex,
+        y: *mut c_float_complex,
+        incy: Int,
+    );
+    #[link_name = "cblas_chbmv64_"]
+    pub fn cblas_chbmv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        n: Int,
+        k: Int,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: Int,
+        x: *const c_float_complex,
+        incx: Int,
+        beta: *const c_float_complex,
+        y: *mut c_float_complex,
+        incy: Int,
+    );
+    #[link_name = "cblas_chpmv64_"]
+    pub fn cblas_chpmv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        n: Int,
+        alpha: *const c_float_complex,
+        ap: *const c_float_complex,
+        x: *const c_float_complex,
+        incx: Int,
+        beta: *const c_float_complex,
+        y: *mut c_float_complex,
+        incy: Int,
+    );
+    #[link_name = "cblas_cgeru64_"]
+    pub fn cblas_cgeru(
+        layout: CBLAS_LAYOUT,
+        m: Int,
+        n: Int,
+        alpha: *const c_float_complex,
+        x: *const c_float_complex,
+        incx: Int,
+        y: *const c_float_complex,
+        incy: Int,
+        a: *mut c_float_complex,
+        lda: Int,
+    );
+    #[link_name = "cblas_cgerc64_"]
+    pub fn cblas_cgerc(
+        layout: CBLAS_LAYOUT,
+        m: Int,
+        n: Int,
+        alpha: *const c_float_complex,
+        x: *const c_float_complex,
+        incx: Int,
+        y: *const c_float_complex,
+        incy: Int,
+        a: *mut c_float_complex,
+        lda: Int,
+    );
+    #[link_name = "cblas_cher64_"]
+    pub fn cblas_cher(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        n: Int,
+        alpha: c_float,
+        x: *const c_float_complex,
+        incx: Int,
+        a: *mut c_float_complex,
+        lda: Int,
+    );
+    #[link_name = "cblas_chpr64_"]
+    pub fn cblas_chpr(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        n: Int,
+        alpha: c_float,
+        x: *const c_float_complex,
+        incx: Int,
+        a: *mut c_float_complex,
+    );
+    #[link_name = "cblas_cher264_"]
+    pub fn cblas_cher2(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        n: Int,
+        alpha: *const c_float_complex,
+        x: *const c_float_complex,
+        incx: Int,
+        y: *const c_float_complex,
+        incy: Int,
+        a: *mut c_float_complex,
+        lda: Int,
+    );
+    #[link_name = "cblas_chpr264_"]
+    pub fn cblas_chpr2(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        n: Int,
+        alpha: *const c_float_complex,
+        x: *const c_float_complex,
+        incx: Int,
+        y: *const c_float_complex,
+        incy: Int,
+        ap: *mut c_float_complex,
+    );
+    #[link_name = "cblas_zhemv64_"]
+    pub fn cblas_zhemv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        n: Int,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: Int,
+        x: *const c_double_complex,
+        incx: Int,
+        beta: *const c_double_complex,
+        y: *mut c_double_complex,
+        incy: Int,
+    );
+    #[link_name = "cblas_zhbmv64_"]
+    pub fn cblas_zhbmv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        n: Int,
+        k: Int,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: Int,
+        x: *const c_double_complex,
+        incx: Int,
+        beta: *const c_double_complex,
+        y: *mut c_double_complex,
+        incy: Int,
+    );
+    #[link_name = "cblas_zhpmv64_"]
+    pub fn cblas_zhpmv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        n: Int,
+        alpha: *const c_double_complex,
+        ap: *const c_double_complex,
+        x: *const c_double_complex,
+        incx: Int,
+        beta: *const c_double_complex,
+        y: *mut c_double_complex,
+        incy: Int,
+    );
+    #[link_name = "cblas_zgeru64_"]
+    pub fn cblas_zgeru(
+        layout: CBLAS_LAYOUT,
+        m: Int,
+        n: Int,
+        alpha: *const c_double_complex,
+        x: *const c_double_complex,
+        incx: Int,
+        y: *const c_double_complex,
+        incy: Int,
+        a: *mut c_double_complex,
+        lda: Int,
+    );
+    #[link_name = "cblas_zgerc64_"]
+    pub fn cblas_zgerc(
+        layout: CBLAS_LAYOUT,
+        m: Int,
+        n: Int,
+        alpha: *const c_double_complex,
+        x: *const c_double_complex,
+        incx: Int,
+        y: *const c_double_complex,
+        incy: Int,
+        a: *mut c_double_complex,
+        lda: Int,
+    );
+    #[link_name = "cblas_zher64_"]
+    pub fn cblas_zher(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        n: Int,
+        alpha: c_double,
+        x: *const c_double_complex,
+        incx: Int,
+        a: *mut c_double_complex,
+        lda: Int,
+    );
+    #[link_name = "cblas_zhpr64_"]
+    pub fn cblas_zhpr(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        n: Int,
+        alpha: c_double,
+        x: *const c_double_complex,
+        incx: Int,
+        a: *mut c_double_complex,
+    );
+    #[link_name = "cblas_zher264_"]
+    pub fn cblas_zher2(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        n: Int,
+        alpha: *const c_double_complex,
+        x: *const c_double_complex,
+        incx: Int,
+        y: *const c_double_complex,
+        incy: Int,
+        a: *mut c_double_complex,
+        lda: Int,
+    );
+    #[link_name = "cblas_zhpr264_"]
+    pub fn cblas_zhpr2(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        n: Int,
+        alpha: *const c_double_complex,
+        x: *const c_double_complex,
+        incx: Int,
+        y: *const c_double_complex,
+        incy: Int,
+        ap: *mut c_double_complex,
+    );
+    #[link_name = "cblas_sgemm64_"]
+    pub fn cblas_sgemm(
+        layout: CBLAS_LAYOUT,
+        transa: CBLAS_TRANSPOSE,
+        transb: CBLAS_TRANSPOSE,
+        m: Int,
+        n: Int,
+        k: Int,
+        alpha: c_float,
+        a: *const c_float,
+        lda: Int,
+        b: *const c_float,
+        ldb: Int,
+        beta: c_float,
+        c: *mut c_float,
+        ldc: Int,
+    );
+    #[link_name = "cblas_ssymm64_"]
+    pub fn cblas_ssymm(
+        layout: CBLAS_LAYOUT,
+        side: CBLAS_SIDE,
+        uplo: CBLAS_UPLO,
+        m: Int,
+        n: Int,
+        alpha: c_float,
+        a: *const c_float,
+        lda: Int,
+        b: *const c_float,
+        ldb: Int,
+        beta: c_float,
+        c: *mut c_float,
+        ldc: Int,
+    );
+    #[link_name = "cblas_ssyrk64_"]
+    pub fn cblas_ssyrk(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        trans: CBLAS_TRANSPOSE,
+        n: Int,
+        k: Int,
+        alpha: c_float,
+        a: *const c_float,
+        lda: Int,
+        beta: c_float,
+        c: *mut c_float,
+        ldc: Int,
+    );
+    #[link_name = "cblas_ssyr2k64_"]
+    pub fn cblas_ssyr2k(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        trans: CBLAS_TRANSPOSE,
+        n: Int,
+        k: Int,
+        alpha: c_float,
+        a: *const c_float,
+        lda: Int,
+        b: *const c_float,
+        ldb: Int,
+        beta: c_float,
+        c: *mut c_float,
+        ldc: Int,
+    );
+    #[link_name = "cblas_strmm64_"]
+    pub fn cblas_strmm(
+        layout: CBLAS_LAYOUT,
+        side: CBLAS_SIDE,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        m: Int,
+        n: Int,
+        alpha: c_float,
+        a: *const c_float,
+        lda: Int,
+        b: *mut c_float,
+        ldb: Int,
+    );
+    #[link_name = "cblas_strsm64_"]
+    pub fn cblas_strsm(
+        layout: CBLAS_LAYOUT,
+        side: CBLAS_SIDE,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        m: Int,
+        n: Int,
+        alpha: c_float,
+        a: *const c_float,
+        lda: Int,
+        b: *mut c_float,
+        ldb: Int,
+    );
+    #[link_name = "cblas_dgemm64_"]
+    pub fn cblas_dgemm(
+        layout: CBLAS_LAYOUT,
+        transa: CBLAS_TRANSPOSE,
+        transb: CBLAS_TRANSPOSE,
+        m: Int,
+        n: Int,
+        k: Int,
+        alpha: c_double,
+        a: *const c_double,
+        lda: Int,
+        b: *const c_double,
+        ldb: Int,
+        beta: c_double,
+        c: *mut c_double,
+        ldc: Int,
+    );
+    #[link_name = "cblas_dsymm64_"]
+    pub fn cblas_dsymm(
+        layout: CBLAS_LAYOUT,
+        side: CBLAS_SIDE,
+        uplo: CBLAS_UPLO,
+        m: Int,
+        n: Int,
+        alpha: c_double,
+        a: *const c_double,
+        lda: Int,
+        b: *const c_double,
+        ldb: Int,
+        beta: c_double,
+        c: *mut c_double,
+        ldc: Int,
+    );
+    #[link_name = "cblas_dsyrk64_"]
+    pub fn cblas_dsyrk(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        trans: CBLAS_TRANSPOSE,
+        n: Int,
+        k: Int,
+        alpha: c_double,
+        a: *const c_double,
+        lda: Int,
+        beta: c_double,
+        c: *mut c_double,
+        ldc: Int,
+    );
+    #[link_name = "cblas_dsyr2k64_"]
+    pub fn cblas_dsyr2k(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        trans: CBLAS_TRANSPOSE,
+        n: Int,
+        k: Int,
+        alpha: c_double,
+        a: *const c_double,
+        lda: Int,
+        b: *const c_double,
+        ldb: Int,
+        beta: c_double,
+        c: *mut c_double,
+        ldc: Int,
+    );
+    #[link_name = "cblas_dtrmm64_"]
+    pub fn cblas_dtrmm(
+        layout: CBLAS_LAYOUT,
+        side: CBLAS_SIDE,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        m: Int,
+        n: Int,
+        alpha: c_double,
+        a: *const c_double,
+        lda: Int,
+        b: *mut c_double,
+        ldb: Int,
+    );
+    #[link_name = "cblas_dtrsm64_"]
+    pub fn cblas_dtrsm(
+        layout: CBLAS_LAYOUT,
+        side: CBLAS_SIDE,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        m: Int,
+        n: Int,
+        alpha: c_double,
+        a: *const c_double,
+        lda: Int,
+        b: *mut c_double,
+        ldb: Int,
+    );
+    #[link_name = "cblas_cgemm64_"]
+    pub fn cblas_cgemm(
+        layout: CBLAS_LAYOUT,
+        transa: CBLAS_TRANSPOSE,
+        transb: CBLAS_TRANSPOSE,
+        m: Int,
+        n: Int,
+        k: Int,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: Int,
+        b: *const c_float_complex,
+        ldb: Int,
+        beta: *const c_float_complex,
+        c: *mut c_float_complex,
+        ldc: Int,
+    );
+    #[link_name = "cblas_csymm64_"]
+    pub fn cblas_csymm(
+        layout: CBLAS_LAYOUT,
+        side: CBLAS_SIDE,
+        uplo: CBLAS_UPLO,
+        m: Int,
+        n: Int,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: Int,
+        b: *const c_float_complex,
+        ldb: Int,
+        beta: *const c_float_complex,
+        c: *mut c_float_complex,
+        ldc: Int,
+    );
+    #[link_name = "cblas_csyrk64_"]
+    pub fn cblas_csyrk(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        trans: CBLAS_TRANSPOSE,
+        n: Int,
+        k: Int,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: Int,
+        beta: *const c_float_complex,
+        c: *mut c_float_complex,
+        ldc: Int,
+    );
+    #[link_name = "cblas_csyr2k64_"]
+    pub fn cblas_csyr2k(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        trans: CBLAS_TRANSPOSE,
+        n: Int,
+        k: Int,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: Int,
+        b: *const c_float_complex,
+        ldb: Int,
+        beta: *const c_float_complex,
+        c: *mut c_float_complex,
+        ldc: Int,
+    );
+    #[link_name = "cblas_ctrmm64_"]
+    pub fn cblas_ctrmm(
+        layout: CBLAS_LAYOUT,
+        side: CBLAS_SIDE,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        m: Int,
+        n: Int,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: Int,
+        b: *mut c_float_complex,
+        ldb: Int,
+    );
+    #[link_name = "cblas_ctrsm64_"]
+    pub fn cblas_ctrsm(
+        layout: CBLAS_LAYOUT,
+        side: CBLAS_SIDE,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        m: Int,
+        n: Int,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: Int,
+        b: *mut c_float_complex,
+        ldb: Int,
+    );
+    #[link_name = "cblas_zgemm64_"]
+    pub fn cblas_zgemm(
+        layout: CBLAS_LAYOUT,
+        transa: CBLAS_TRANSPOSE,
+        transb: CBLAS_TRANSPOSE,
+        m: Int,
+        n: Int,
+        k: Int,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: Int,
+        b: *const c_double_complex,
+        ldb: Int,
+        beta: *const c_double_complex,
+        c: *mut c_double_complex,
+        ldc: Int,
+    );
+    #[link_name = "cblas_zsymm64_"]
+    pub fn cblas_zsymm(
+        layout: CBLAS_LAYOUT,
+        side: CBLAS_SIDE,
+        uplo: CBLAS_UPLO,
+        m: Int,
+        n: Int,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: Int,
+        b: *const c_double_complex,
+        ldb: Int,
+        beta: *const c_double_complex,
+        c: *mut c_double_complex,
+        ldc: Int,
+    );
+    #[link_name = "cblas_zsyrk64_"]
+    pub fn cblas_zsyrk(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        trans: CBLAS_TRANSPOSE,
+        n: Int,
+        k: Int,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: Int,
+        beta: *const c_double_complex,
+        c: *mut c_double_complex,
+        ldc: Int,
+    );
+    #[link_name = "cblas_zsyr2k64_"]
+    pub fn cblas_zsyr2k(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        trans: CBLAS_TRANSPOSE,
+        n: Int,
+        k: Int,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: Int,
+        b: *const c_double_complex,
+        ldb: Int,
+        beta: *const c_double_complex,
+        c: *mut c_double_complex,
+        ldc: Int,
+    );
+    #[link_name = "cblas_ztrmm64_"]
+    pub fn cblas_ztrmm(
+        layout: CBLAS_LAYOUT,
+        side: CBLAS_SIDE,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        m: Int,
+        n: Int,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: Int,
+        b: *mut c_double_complex,
+        ldb: Int,
+    );
+    #[link_name = "cblas_ztrsm64_"]
+    pub fn cblas_ztrsm(
+        layout: CBLAS_LAYOUT,
+        side: CBLAS_SIDE,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        m: Int,
+        n: Int,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: Int,
+        b: *mut c_double_complex,
+        ldb: Int,
+    );
+    #[link_name = "cblas_chemm64_"]
+    pub fn cblas_chemm(
+        layout: CBLAS_LAYOUT,
+        side: CBLAS_SIDE,
+        uplo: CBLAS_UPLO,
+        m: Int,
+        n: Int,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: Int,
+        b: *const c_float_complex,
+        ldb: Int,
+        beta: *const c_float_complex,
+        c: *mut c_float_complex,
+        ldc: Int,
+    );
+    #[link_name = "cblas_cherk64_"]
+    pub fn cblas_cherk(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        trans: CBLAS_TRANSPOSE,
+        n: Int,
+        k: Int,
+        alpha: c_float,
+        a: *const c_float_complex,
+        lda: Int,
+        beta: c_float,
+        c: *mut c_float_complex,
+        ldc: Int,
+    );
+    #[link_name = "cblas_cher2k64_"]
+    pub fn cblas_cher2k(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        trans: CBLAS_TRANSPOSE,
+        n: Int,
+        k: Int,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: Int,
+        b: *const c_float_complex,
+        ldb: Int,
+        beta: c_float,
+        c: *mut c_float_complex,
+        ldc: Int,
+    );
+    #[link_name = "cblas_zhemm64_"]
+    pub fn cblas_zhemm(
+        layout: CBLAS_LAYOUT,
+        side: CBLAS_SIDE,
+        uplo: CBLAS_UPLO,
+        m: Int,
+        n: Int,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: Int,
+        b: *const c_double_complex,
+        ldb: Int,
+        beta: *const c_double_complex,
+        c: *mut c_double_complex,
+        ldc: Int,
+    );
+    #[link_name = "cblas_zherk64_"]
+    pub fn cblas_zherk(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        trans: CBLAS_TRANSPOSE,
+        n: Int,
+        k: Int,
+        alpha: c_double,
+        a: *const c_double_complex,
+        lda: Int,
+        beta: c_double,
+        c: *mut c_double_complex,
+        ldc: Int,
+    );
+    #[link_name = "cblas_zher2k64_"]
+    pub fn cblas_zher2k(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        trans: CBLAS_TRANSPOSE,
+        n: Int,
+        k: Int,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: Int,
+        b: *const c_double_complex,
+        ldb: Int,
+        beta: c_double,
+        c: *mut c_double_complex,
+        ldc: Int,
+    );
+}
@@ -0,0 +1,2849 @@
+//! Wrappers for [CBLAS] \(C).
+//!
+//! The functions in this module mirror the Level 2 and Level 3 wrappers at the root of the
+//! crate but call the C interface, which takes the storage order of the matrices as the first
+//! argument. Row-major data can therefore be passed as is. As in [`typed`], the rank updates of
+//! complex matrices accept [`NoneOrTrans`] or [`NoneOrConj`] instead of [`Transpose`].
+//!
+//! The module is available with the `cblas` feature.
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use blas::cblas::*;
+//! use blas::Transpose;
+//!
+//! let (m, n, k) = (2, 4, 3);
+//! let a = vec![
+//!     1.0, 2.0, 3.0,
+//!     4.0, 5.0, 6.0,
+//! ];
+//! let b = vec![
+//!     1.0,  2.0,  3.0,  4.0,
+//!     5.0,  6.0,  7.0,  8.0,
+//!     9.0, 10.0, 11.0, 12.0,
+//! ];
+//! let mut c = vec![
+//!     2.0, 6.0, 0.0, 4.0,
+//!     7.0, 2.0, 7.0, 2.0,
+//! ];
+//!
+//! unsafe {
+//!     dgemm(Layout::RowMajor, Transpose::None, Transpose::None,
+//!           m, n, k, 1.0, &a, k, &b, n, 1.0, &mut c, n);
+//! }
+//!
+//! assert!(
+//!     c == vec![
+//!         40.0,  50.0,  50.0,  60.0,
+//!         90.0, 100.0, 120.0, 130.0,
+//!     ]
+//! );
+//! ```
+//!
+//! [cblas]: https://en.wikipedia.org/wiki/BLAS
+//! [`typed`]: crate::typed
+//! [`NoneOrTrans`]: crate::NoneOrTrans
+//! [`NoneOrConj`]: crate::NoneOrConj
+//! [`Transpose`]: crate::Transpose
+
+#[cfg(not(feature = "ilp64"))]
+use cblas_sys as ffi;
+
+#[cfg(feature = "ilp64")]
+mod ilp64;
+
+#[cfg(feature = "ilp64")]
+use self::ilp64 as ffi;
+
+use crate::{c32, c64, Diag, Int, NoneOrConj, NoneOrTrans, Side, Transpose, Uplo};
+
+/// The storage order of a matrix.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Layout {
+    /// Consecutive elements of a row are adjacent in memory.
+    RowMajor,
+    /// Consecutive elements of a column are adjacent in memory.
+    ColMajor,
+}
+
+impl From<Layout> for ffi::CBLAS_LAYOUT {
+    #[inline]
+    fn from(layout: Layout) -> Self {
+        match layout {
+            Layout::RowMajor => ffi::CblasRowMajor,
+            Layout::ColMajor => ffi::CblasColMajor,
+        }
+    }
+}
+
+impl From<Transpose> for ffi::CBLAS_TRANSPOSE {
+    #[inline]
+    fn from(trans: Transpose) -> Self {
+        match trans {
+            Transpose::None => ffi::CblasNoTrans,
+            Transpose::Ordinary => ffi::CblasTrans,
+            Transpose::Conjugate => ffi::CblasConjTrans,
+        }
+    }
+}
+
+impl From<NoneOrTrans> for ffi::CBLAS_TRANSPOSE {
+    #[inline]
+    fn from(trans: NoneOrTrans) -> Self {
+        Transpose::from(trans).into()
+    }
+}
+
+impl From<NoneOrConj> for ffi::CBLAS_TRANSPOSE {
+    #[inline]
+    fn from(trans: NoneOrConj) -> Self {
+        Transpose::from(trans).into()
+    }
+}
+
+impl From<Uplo> for ffi::CBLAS_UPLO {
+    #[inline]
+    fn from(uplo: Uplo) -> Self {
+        match uplo {
+            Uplo::Upper => ffi::CblasUpper,
+            Uplo::Lower => ffi::CblasLower,
+        }
+    }
+}
+
+impl From<Diag> for ffi::CBLAS_DIAG {
+    #[inline]
+    fn from(diag: Diag) -> Self {
+        match diag {
+            Diag::NonUnit => ffi::CblasNonUnit,
+            Diag::Unit => ffi::CblasUnit,
+        }
+    }
+}
+
+impl From<Side> for ffi::CBLAS_SIDE {
+    #[inline]
+    fn from(side: Side) -> Self {
+        match side {
+            Side::Left => ffi::CblasLeft,
+            Side::Right => ffi::CblasRight,
+        }
+    }
+}
+
+#[inline]
+pub unsafe fn sgemv(
+    layout: Layout,
+    trans: Transpose,
+    m: Int,
+    n: Int,
+    alpha: f32,
+    a: &[f32],
+    lda: Int,
+    x: &[f32],
+    incx: Int,
+    beta: f32,
+    y: &mut [f32],
+    incy: Int,
+) {
+    ffi::cblas_sgemv(
+        layout.into(),
+        trans.into(),
+        m,
+        n,
+        alpha,
+        a.as_ptr(),
+        lda,
+        x.as_ptr(),
+        incx,
+        beta,
+        y.as_mut_ptr(),
+        incy,
+    )
+}
+
+#[inline]
+pub unsafe fn sgbmv(
+    layout: Layout,
+    trans: Transpose,
+    m: Int,
+    n: Int,
+    kl: Int,
+    ku: Int,
+    alpha: f32,
+    a: &[f32],
+    lda: Int,
+    x: &[f32],
+    incx: Int,
+    beta: f32,
+    y: &mut [f32],
+    incy: Int,
+) {
+    ffi::cblas_sgbmv(
+        layout.into(),
+        trans.into(),
+        m,
+        n,
+        kl,
+        ku,
+        alpha,
+        a.as_ptr(),
+        lda,
+        x.as_ptr(),
+        incx,
+        beta,
+        y.as_mut_ptr(),
+        incy,
+    )
+}
+
+#[inline]
+pub unsafe fn ssymv(
+    layout: Layout,
+    uplo: Uplo,
+    n: Int,
+    alpha: f32,
+    a: &[f32],
+    lda: Int,
+    x: &[f32],
+    incx: Int,
+    beta: f32,
+    y: &mut [f32],
+    incy: Int,
+) {
+    ffi::cblas_ssymv(
+        layout.into(),
+        uplo.into(),
+        n,
+        alpha,
+        a.as_ptr(),
+        lda,
+        x.as_ptr(),
+        incx,
+        beta,
+        y.as_mut_ptr(),
+        incy,
+    )
+}
+
+#[inline]
+pub unsafe fn ssbmv(
+    layout: Layout,
+    uplo: Uplo,
+    n: Int,
+    k: Int,
+    alpha: f32,
+    a: &[f32],
+    lda: Int,
+    x: &[f32],
+    incx: Int,
+    beta: f32,
+    y: &mut [f32],
+    incy: Int,
+) {
+    ffi::cblas_ssbmv(
+        layout.into(),
+        uplo.into(),
+        n,
+        k,
+        alpha,
+        a.as_ptr(),
+        lda,
+        x.as_ptr(),
+        incx,
+        beta,
+        y.as_mut_ptr(),
+        incy,
+    )
+}
+
+#[inline]
+pub unsafe fn sspmv(
+    layout: Layout,
+    uplo: Uplo,
+    n: Int,
+    alpha: f32,
+    ap: &[f32],
+    x: &[f32],
+    incx: Int,
+    beta: f32,
+    y: &mut [f32],
+    incy: Int,
+) {
+    ffi::cblas_sspmv(
+        layout.into(),
+        uplo.into(),
+        n,
+        alpha,
+        ap.as_ptr(),
+        x.as_ptr(),
+        incx,
+        beta,
+        y.as_mut_ptr(),
+        incy,
+    )
+}
+
+#[inline]
+pub unsafe fn strmv(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    n: Int,
+    a: &[f32],
+    lda: Int,
+    x: &mut [f32],
+    incx: Int,
+) {
+    ffi::cblas_strmv(
+        layout.into(),
+        uplo.into(),
+        trans.into(),
+        diag.into(),
+        n,
+        a.as_ptr(),
+        lda,
+        x.as_mut_ptr(),
+        incx,
+    )
+}
+
+#[inline]
+pub unsafe fn stbmv(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    n: Int,
+    k: Int,
+    a: &[f32],
+    lda: Int,
+    x: &mut [f32],
+    incx: Int,
+) {
+    ffi::cblas_stbmv(
+        layout.into(),
+        uplo.into(),
+        trans.into(),
+        diag.into(),
+        n,
+        k,
+        a.as_ptr(),
+        lda,
+        x.as_mut_ptr(),
+        incx,
+    )
+}
+
+#[inline]
+pub unsafe fn stpmv(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    n: Int,
+    ap: &[f32],
+    x: &mut [f32],
+    incx: Int,
+) {
+    ffi::cblas_stpmv(
+        layout.into(),
+        uplo.into(),
+        trans.into(),
+        diag.into(),
+        n,
+        ap.as_ptr(),
+        x.as_mut_ptr(),
+        incx,
+    )
+}
+
+#[inline]
+pub unsafe fn strsv(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    n: Int,
+    a: &[f32],
+    lda: Int,
+    x: &mut [f32],
+    incx: Int,
+) {
+    ffi::cblas_strsv(
+        layout.into(),
+        uplo.into(),
+        trans.into(),
+        diag.into(),
+        n,
+        a.as_ptr(),
+        lda,
+        x.as_mut_ptr(),
+        incx,
+    )
+}
+
+#[inline]
+pub unsafe fn stbsv(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    n: Int,
+    k: Int,
+    a: &[f32],
+    lda: Int,
+    x: &mut [f32],
+    incx: Int,
+) {
+    ffi::cblas_stbsv(
+        layout.into(),
+        uplo.into(),
+        trans.into(),
+        diag.into(),
+        n,
+        k,
+        a.as_ptr(),
+        lda,
+        x.as_mut_ptr(),
+        incx,
+    )
+}
+
+#[inline]
+pub unsafe fn stpsv(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    n: Int,
+    ap: &[f32],
+    x: &mut [f32],
+    incx: Int,
+) {
+    ffi::cblas_stpsv(
+        layout.into(),
+        uplo.into(),
+        trans.into(),
+        diag.into(),
+        n,
+        ap.as_ptr(),
+        x.as_mut_ptr(),
+        incx,
+    )
+}
+
+#[inline]
+pub unsafe fn sger(
+    layout: Layout,
+    m: Int,
+    n: Int,
+    alpha: f32,
+    x: &[f32],
+    incx: Int,
+    y: &[f32],
+    incy: Int,
+    a: &mut [f32],
+    lda: Int,
+) {
+    ffi::cblas_sger(
+        layout.into(),
+        m,
+        n,
+        alpha,
+        x.as_ptr(),
+        incx,
+        y.as_ptr(),
+        incy,
+        a.as_mut_ptr(),
+        lda,
+    )
+}
+
+#[inline]
+pub unsafe fn ssyr(
+    layout: Layout,
+    uplo: Uplo,
+    n: Int,
+    alpha: f32,
+    x: &[f32],
+    incx: Int,
+    a: &mut [f32],
+    lda: Int,
+) {
+    ffi::cblas_ssyr(
+        layout.into(),
+        uplo.into(),
+        n,
+        alpha,
+        x.as_ptr(),
+        incx,
+        a.as_mut_ptr(),
+        lda,
+    )
+}
+
+#[inline]
+pub unsafe fn sspr(
+    layout: Layout,
+    uplo: Uplo,
+    n: Int,
+    alpha: f32,
+    x: &[f32],
+    incx: Int,
+    ap: &mut [f32],
+) {
+    ffi::cblas_sspr(
+        layout.into(),
+        uplo.into(),
+        n,
+        alpha,
+        x.as_ptr(),
+        incx,
+        ap.as_mut_ptr(),
+    )
+}
+
+#[inline]
+pub unsafe fn ssyr2(
+    layout: Layout,
+    uplo: Uplo,
+    n: Int,
+    alpha: f32,
+    x: &[f32],
+    incx: Int,
+    y: &[f32],
+    incy: Int,
+    a: &mut [f32],
+    lda: Int,
+) {
+    ffi::cblas_ssyr2(
+        layout.into(),
+        uplo.into(),
+        n,
+        alpha,
+        x.as_ptr(),
+        incx,
+        y.as_ptr(),
+        incy,
+        a.as_mut_ptr(),
+        lda,
+    )
+}
+
+#[inline]
+pub unsafe fn sspr2(
+    layout: Layout,
+    uplo: Uplo,
+    n: Int,
+    alpha: f32,
+    x: &[f32],
+    incx: Int,
+    y: &[f32],
+    incy: Int,
+    ap: &mut [f32],
+) {
+    ffi::cblas_sspr2(
+        layout.into(),
+        uplo.into(),
+        n,
+        alpha,
+        x.as_ptr(),
+        incx,
+        y.as_ptr(),
+        incy,
+        ap.as_mut_ptr(),
+    )
+}
+
+#[inline]
+pub unsafe fn dgemv(
+    layout: Layout,
+    trans: Transpose,
+    m: Int,
+    n: Int,
+    alpha: f64,
+    a: &[f64],
+    lda: Int,
+    x: &[f64],
+    incx: Int,
+    beta: f64,
+    y: &mut [f64],
+    incy: Int,
+) {
+    ffi::cblas_dgemv(
+        layout.into(),
+        trans.into(),
+        m,
+        n,
+        alpha,
+        a.as_ptr(),
+        lda,
+        x.as_ptr(),
+        incx,
+        beta,
+        y.as_mut_ptr(),
+        incy,
+    )
+}
+
+#[inline]
+pub unsafe fn dgbmv(
+    layout: Layout,
+    trans: Transpose,
+    m: Int,
+    n: Int,
+    kl: Int,
+    ku: Int,
+    alpha: f64,
+    a: &[f64],
+    lda: Int,
+    x: &[f64],
+    incx: Int,
+    beta: f64,
+    y: &mut [f64],
+    incy: Int,
+) {
+    ffi::cblas_dgbmv(
+        layout.into(),
+        trans.into(),
+        m,
+        n,
+        kl,
+        ku,
+        alpha,
+        a.as_ptr(),
+        lda,
+        x.as_ptr(),
+        incx,
+        beta,
+        y.as_mut_ptr(),
+        incy,
+    )
+}
+
+#[inline]
+pub unsafe fn dsymv(
+    layout: Layout,
+    uplo: Uplo,
+    n: Int,
+    alpha: f64,
+    a: &[f64],
+    lda: Int,
+    x: &[f64],
+    incx: Int,
+    beta: f64,
+    y: &mut [f64],
+    incy: Int,
+) {
+    ffi::cblas_dsymv(
+        layout.into(),
+        uplo.into(),
+        n,
+        alpha,
+        a.as_ptr(),
+        lda,
+        x.as_ptr(),
+        incx,
+        beta,
+        y.as_mut_ptr(),
+        incy,
+    )
+}
+
+#[inline]
+pub unsafe fn dsbmv(
+    layout: Layout,
+    uplo: Uplo,
+    n: Int,
+    k: Int,
+    alpha: f64,
+    a: &[f64],
+    lda: Int,
+    x: &[f64],
+    incx: Int,
+    beta: f64,
+    y: &mut [f64],
+    incy: Int,
+) {
+    ffi::cblas_dsbmv(
+        layout.into(),
+        uplo.into(),
+        n,
+        k,
+        alpha,
+        a.as_ptr(),
+        lda,
+        x.as_ptr(),
+        incx,
+        beta,
+        y.as_mut_ptr(),
+        incy,
+    )
+}
+
+#[inline]
+pub unsafe fn dspmv(
+    layout: Layout,
+    uplo: Uplo,
+    n: Int,
+    alpha: f64,
+    ap: &[f64],
+    x: &[f64],
+    incx: Int,
+    beta: f64,
+    y: &mut [f64],
+    incy: Int,
+) {
+    ffi::cblas_dspmv(
+        layout.into(),
+        uplo.into(),
+        n,
+        alpha,
+        ap.as_ptr(),
+        x.as_ptr(),
+        incx,
+        beta,
+        y.as_mut_ptr(),
+        incy,
+    )
+}
+
+#[inline]
+pub unsafe fn dtrmv(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    n: Int,
+    a: &[f64],
+    lda: Int,
+    x: &mut [f64],
+    incx: Int,
+) {
+    ffi::cblas_dtrmv(
+        layout.into(),
+        uplo.into(),
+        trans.into(),
+        diag.into(),
+        n,
+        a.as_ptr(),
+        lda,
+        x.as_mut_ptr(),
+        incx,
+    )
+}
+
+#[inline]
+pub unsafe fn dtbmv(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    n: Int,
+    k: Int,
+    a: &[f64],
+    lda: Int,
+    x: &mut [f64],
+    incx: Int,
+) {
+    ffi::cblas_dtbmv(
+        layout.into(),
+        uplo.into(),
+        trans.into(),
+        diag.into(),
+        n,
+        k,
+        a.as_ptr(),
+        lda,
+        x.as_mut_ptr(),
+        incx,
+    )
+}
+
+#[inline]
+pub unsafe fn dtpmv(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    n: Int,
+    ap: &[f64],
+    x: &mut [f64],
+    incx: Int,
+) {
+    ffi::cblas_dtpmv(
+        layout.into(),
+        uplo.into(),
+        trans.into(),
+        diag.into(),
+        n,
+        ap.as_ptr(),
+        x.as_mut_ptr(),
+        incx,
+    )
+}
+
+#[inline]
+pub unsafe fn dtrsv(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    n: Int,
+    a: &[f64],
+    lda: Int,
+    x: &mut [f64],
+    incx: Int,
+) {
+    ffi::cblas_dtrsv(
+        layout.into(),
+        uplo.into(),
+        trans.into(),
+        diag.into(),
+        n,
+        a.as_ptr(),
+        lda,
+        x.as_mut_ptr(),
+        incx,
+    )
+}
+
+#[inline]
+pub unsafe fn dtbsv(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    n: Int,
+    k: Int,
+    a: &[f64],
+    lda: Int,
+    x: &mut [f64],
+    incx: Int,
+) {
+    ffi::cblas_dtbsv(
+        layout.into(),
+        uplo.into(),
+        trans.into(),
+        diag.into(),
+        n,
+        k,
+        a.as_ptr(),
+        lda,
+        x.as_mut_ptr(),
+        incx,
+    )
+}
+
+#[inline]
+pub unsafe fn dtpsv(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    n: Int,
+    ap: &[f64],
+    x: &mut [f64],
+    incx: Int,
+) {
+    ffi::cblas_dtpsv(
+        layout.into(),
+        uplo.into(),
+        trans.into(),
+        diag.into(),
+        n,
+        ap.as_ptr(),
+        x.as_mut_ptr(),
+        incx,
+    )
+}
+
+#[inline]
+pub unsafe fn dger(
+    layout: Layout,
+    m: Int,
+    n: Int,
+    alpha: f64,
+    x: &[f64],
+    incx: Int,
+    y: &[f64],
+    incy: Int,
+    a: &mut [f64],
+    lda: Int,
+) {
+    ffi::cblas_dger(
+        layout.into(),
+        m,
+        n,
+        alpha,
+        x.as_ptr(),
+        incx,
+        y.as_ptr(),
+        incy,
+        a.as_mut_ptr(),
+        lda,
+    )
+}
+
+#[inline]
+pub unsafe fn dsyr(
+    layout: Layout,
+    uplo: Uplo,
+    n: Int,
+    alpha: f64,
+    x: &[f64],
+    incx: Int,
+    a: &mut [f64],
+    lda: Int,
+) {
+    ffi::cblas_dsyr(
+        layout.into(),
+        uplo.into(),
+        n,
+        alpha,
+        x.as_ptr(),
+        incx,
+        a.as_mut_ptr(),
+        lda,
+    )
+}
+
+#[inline]
+pub unsafe fn dspr(
+    layout: Layout,
+    uplo: Uplo,
+    n: Int,
+    alpha: f64,
+    x: &[f64],
+    incx: Int,
+    ap: &mut [f64],
+) {
+    ffi::cblas_dspr(
+        layout.into(),
+        uplo.into(),
+        n,
+        alpha,
+        x.as_ptr(),
+        incx,
+        ap.as_mut_ptr(),
+    )
+}
+
+#[inline]
+pub unsafe fn dsyr2(
+    layout: Layout,
+    uplo: Uplo,
+    n: Int,
+    alpha: f64,
+    x: &[f64],
+    incx: Int,
+    y: &[f64],
+    incy: Int,
+    a: &mut [f64],
+    lda: Int,
+) {
+    ffi::cblas_dsyr2(
+        layout.into(),
+        uplo.into(),
+        n,
+        alpha,
+        x.as_ptr(),
+        incx,
+        y.as_ptr(),
+        incy,
+        a.as_mut_ptr(),
+        lda,
+    )
+}
+
+#[inline]
+pub unsafe fn dspr2(
+    layout: Layout,
+    uplo: Uplo,
+    n: Int,
+    alpha: f64,
+    x: &[f64],
+    incx: Int,
+    y: &[f64],
+    incy: Int,
+    ap: &mut [f64],
+) {
+    ffi::cblas_dspr2(
+        layout.into(),
+        uplo.into(),
+        n,
+        alpha,
+        x.as_ptr(),
+        incx,
+        y.as_ptr(),
+        incy,
+        ap.as_mut_ptr(),
+    )
+}
+
+#[inline]
+pub unsafe fn cgemv(
+    layout: Layout,
+    trans: Transpose,
+    m: Int,
+    n: Int,
+    alpha: c32,
+    a: &[c32],
+    lda: Int,
+    x: &[c32],
+    incx: Int,
+    beta: c32,
+    y: &mut [c32],
+    incy: Int,
+) {
+    ffi::cblas_cgemv(
+        layout.into(),
+        trans.into(),
+        m,
+        n,
+        &alpha as *const _ as *const _,
+        a.as_ptr() as *const _,
+        lda,
+        x.as_ptr() as *const _,
+        incx,
+        &beta as *const _ as *const _,
+        y.as_mut_ptr() as *mut _,
+        incy,
+    )
+}
+
+#[inline]
+pub unsafe fn cgbmv(
+    layout: Layout,
+    trans: Transpose,
+    m: Int,
+    n: Int,
+    kl: Int,
+    ku: Int,
+    alpha: c32,
+    a: &[c32],
+    lda: Int,
+    x: &[c32],
+    incx: Int,
+    beta: c32,
+    y: &mut [c32],
+    incy: Int,
+) {
+    ffi::cblas_cgbmv(
+        layout.into(),
+        trans.into(),
+        m,
+        n,
+        kl,
+        ku,
+        &alpha as *const _ as *const _,
+        a.as_ptr() as *const _,
+        lda,
+        x.as_ptr() as *const _,
+        incx,
+        &beta as *const _ as *const _,
+        y.as_mut_ptr() as *mut _,
+        incy,
+    )
+}
+
+#[inline]
+pub unsafe fn chemv(
+    layout: Layout,
+    uplo: Uplo,
+    n: Int,
+    alpha: c32,
+    a: &[c32],
+    lda: Int,
+    x: &[c32],
+    incx: Int,
+    beta: c32,
+    y: &mut [c32],
+    incy: Int,
+) {
+    ffi::cblas_chemv(
+        layout.into(),
+        uplo.into(),
+        n,
+        &alpha as *const _ as *const _,
+        a.as_ptr() as *const _,
+        lda,
+        x.as_ptr() as *const _,
+        incx,
+        &beta as *const _ as *const _,
+        y.as_mut_ptr() as *mut _,
+        incy,
+    )
+}
+
+#[inline]
+pub unsafe fn chbmv(
+    layout: Layout,
+    uplo: Uplo,
+    n: Int,
+    k: Int,
+    alpha: c32,
+    a: &[c32],
+    lda: Int,
+    x: &[c32],
+    incx: Int,
+    beta: c32,
+    y: &mut [c32],
+    incy: Int,
+) {
+    ffi::cblas_chbmv(
+        layout.into(),
+        uplo.into(),
+        n,
+        k,
+        &alpha as *const _ as *const _,
+        a.as_ptr() as *const _,
+        lda,
+        x.as_ptr() as *const _,
+        incx,
+        &beta as *const _ as *const _,
+        y.as_mut_ptr() as *mut _,
+        incy,
+    )
+}
+
+#[inline]
+pub unsafe fn chpmv(
+    layout: Layout,
+    uplo: Uplo,
+    n: Int,
+    alpha: c32,
+    ap: &[c32],
+    x: &[c32],
+    incx: Int,
+    beta: c32,
+    y: &mut [c32],
+    incy: Int,
+) {
+    ffi::cblas_chpmv(
+        layout.into(),
+        uplo.into(),
+        n,
+        &alpha as *const _ as *const _,
+        ap.as_ptr() as *const _,
+        x.as_ptr() as *const _,
+        incx,
+        &beta as *const _ as *const _,
+        y.as_mut_ptr() as *mut _,
+        incy,
+    )
+}
+
+#[inline]
+pub unsafe fn ctrmv(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    n: Int,
+    a: &[c32],
+    lda: Int,
+    x: &mut [c32],
+    incx: Int,
+) {
+    ffi::cblas_ctrmv(
+        layout.into(),
+        uplo.into(),
+        trans.into(),
+        diag.into(),
+        n,
+        a.as_ptr() as *const _,
+        lda,
+        x.as_mut_ptr() as *mut _,
+        incx,
+    )
+}
+
+#[inline]
+pub unsafe fn ctbmv(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    n: Int,
+    k: Int,
+    a: &[c32],
+    lda: Int,
+    x: &mut [c32],
+    incx: Int,
+) {
+    ffi::cblas_ctbmv(
+        layout.into(),
+        uplo.into(),
+        trans.into(),
+        diag.into(),
+        n,
+        k,
+        a.as_ptr() as *const _,
+        lda,
+        x.as_mut_ptr() as *mut _,
+        incx,
+    )
+}
+
+#[inline]
+pub unsafe fn ctpmv(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    n: Int,
+    ap: &[c32],
+    x: &mut [c32],
+    incx: Int,
+) {
+    ffi::cblas_ctpmv(
+        layout.into(),
+        uplo.into(),
+        trans.into(),
+        diag.into(),
+        n,
+        ap.as_ptr() as *const _,
+        x.as_mut_ptr() as *mut _,
+        incx,
+    )
+}
+
+#[inline]
+pub unsafe fn ctrsv(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    n: Int,
+    a: &[c32],
+    lda: Int,
+    x: &mut [c32],
+    incx: Int,
+) {
+    ffi::cblas_ctrsv(
+        layout.into(),
+        uplo.into(),
+        trans.into(),
+        diag.into(),
+        n,
+        a.as_ptr() as *const _,
+        lda,
+        x.as_mut_ptr() as *mut _,
+        incx,
+    )
+}
+
+#[inline]
+pub unsafe fn ctbsv(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    n: Int,
+    k: Int,
+    a: &[c32],
+    lda: Int,
+    x: &mut [c32],
+    incx: Int,
+) {
+    ffi::cblas_ctbsv(
+        layout.into(),
+        uplo.into(),
+        trans.into(),
+        diag.into(),
+        n,
+        k,
+        a.as_ptr() as *const _,
+        lda,
+        x.as_mut_ptr() as *mut _,
+        incx,
+    )
+}
+
+#[inline]
+pub unsafe fn ctpsv(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    n: Int,
+    ap: &[c32],
+    x: &mut [c32],
+    incx: Int,
+) {
+    ffi::cblas_ctpsv(
+        layout.into(),
+        uplo.into(),
+        trans.into(),
+        diag.into(),
+        n,
+        ap.as_ptr() as *const _,
+        x.as_mut_ptr() as *mut _,
+        incx,
+    )
+}
+
+#[inline]
+pub unsafe fn cgeru(
+    layout: Layout,
+    m: Int,
+    n: Int,
+    alpha: c32,
+    x: &[c32],
+    incx: Int,
+    y: &[c32],
+    incy: Int,
+    a: &mut [c32],
+    lda: Int,
+) {
+    ffi::cblas_cgeru(
+        layout.into(),
+        m,
+        n,
+        &alpha as *const _ as *const _,
+        x.as_ptr() as *const _,
+        incx,
+        y.as_ptr() as *const _,
+        incy,
+        a.as_mut_ptr() as *mut _,
+        lda,
+    )
+}
+
+#[inline]
+pub unsafe fn cgerc(
+    layout: Layout,
+    m: Int,
+    n: Int,
+    alpha: c32,
+    x: &[c32],
+    incx: Int,
+    y: &[c32],
+    incy: Int,
+    a: &mut [c32],
+    lda: Int,
+) {
+    ffi::cblas_cgerc(
+        layout.into(),
+        m,
+        n,
+        &alpha as *const _ as *const _,
+        x.as_ptr() as *const _,
+        incx,
+        y.as_ptr() as *const _,
+        incy,
+        a.as_mut_ptr() as *mut _,
+        lda,
+    )
+}
+
+#[inline]
+pub unsafe fn cher(
+    layout: Layout,
+    uplo: Uplo,
+    n: Int,
+    alpha: f32,
+    x: &[c32],
+    incx: Int,
+    a: &mut [c32],
+    lda: Int,
+) {
+    ffi::cblas_cher(
+        layout.into(),
+        uplo.into(),
+        n,
+        alpha,
+        x.as_ptr() as *const _,
+        incx,
+        a.as_mut_ptr() as *mut _,
+        lda,
+    )
+}
+
+#[inline]
+pub unsafe fn chpr(
+    layout: Layout,
+    uplo: Uplo,
+    n: Int,
+    alpha: f32,
+    x: &[c32],
+    incx: Int,
+    ap: &mut [c32],
+) {
+    ffi::cblas_chpr(
+        layout.into(),
+        uplo.into(),
+        n,
+        alpha,
+        x.as_ptr() as *const _,
+        incx,
+        ap.as_mut_ptr() as *mut _,
+    )
+}
+
+#[inline]
+pub unsafe fn chpr2(
+    layout: Layout,
+    uplo: Uplo,
+    n: Int,
+    alpha: c32,
+    x: &[c32],
+    incx: Int,
+    y: &[c32],
+    incy: Int,
+    ap: &mut [c32],
+) {
+    ffi::cblas_chpr2(
+        layout.into(),
+        uplo.into(),
+        n,
+        &alpha as *const _ as *const _,
+        x.as_ptr() as *const _,
+        incx,
+        y.as_ptr() as *const _,
+        incy,
+        ap.as_mut_ptr() as *mut _,
+    )
+}
+
+#[inline]
+pub unsafe fn cher2(
+    layout: Layout,
+    uplo: Uplo,
+    n: Int,
+    alpha: c32,
+    x: &[c32],
+    incx: Int,
+    y: &[c32],
+    incy: Int,
+    a: &mut [c32],
+    lda: Int,
+) {
+    ffi::cblas_cher2(
+        layout.into(),
+        uplo.into(),
+        n,
+        &alpha as *const _ as *const _,
+        x.as_ptr() as *const _,
+        incx,
+        y.as_ptr() as *const _,
+        incy,
+        a.as_mut_ptr() as *mut _,
+        lda,
+    )
+}
+
+#[inline]
+pub unsafe fn zgemv(
+    layout: Layout,
+    trans: Transpose,
+    m: Int,
+    n: Int,
+    alpha: c64,
+    a: &[c64],
+    lda: Int,
+    x: &[c64],
+    incx: Int,
+    beta: c64,
+    y: &mut [c64],
+    incy: Int,
+) {
+    ffi::cblas_zgemv(
+        layout.into(),
+        trans.into(),
+        m,
+        n,
+        &alpha as *const _ as *const _,
+        a.as_ptr() as *const _,
+        lda,
+        x.as_ptr() as *const _,
+        incx,
+        &beta as *const _ as *const _,
+        y.as_mut_ptr() as *mut _,
+        incy,
+    )
+}
+
+#[inline]
+pub unsafe fn zgbmv(
+    layout: Layout,
+    trans: Transpose,
+    m: Int,
+    n: Int,
+    kl: Int,
+    ku: Int,
+    alpha: c64,
+    a: &[c64],
+    lda: Int,
+    x: &[c64],
+    incx: Int,
+    beta: c64,
+    y: &mut [c64],
+    incy: Int,
+) {
+    ffi::cblas_zgbmv(
+        layout.into(),
+        trans.into(),
+        m,
+        n,
+        kl,
+        ku,
+        &alpha as *const _ as *const _,
+        a.as_ptr() as *const _,
+        lda,
+        x.as_ptr() as *const _,
+        incx,
+        &beta as *const _ as *const _,
+        y.as_mut_ptr() as *mut _,
+        incy,
+    )
+}
+
+#[inline]
+pub unsafe fn zhemv(
+    layout: Layout,
+    uplo: Uplo,
+    n: Int,
+    alpha: c64,
+    a: &[c64],
+    lda: Int,
+    x: &[c64],
+    incx: Int,
+    beta: c64,
+    y: &mut [c64],
+    incy: Int,
+) {
+    ffi::cblas_zhemv(
+        layout.into(),
+        uplo.into(),
+        n,
+        &alpha as *const _ as *const _,
+        a.as_ptr() as *const _,
+        lda,
+        x.as_ptr() as *const _,
+        incx,
+        &beta as *const _ as *const _,
+        y.as_mut_ptr() as *mut _,
+        incy,
+    )
+}
+
+#[inline]
+pub unsafe fn zhbmv(
+    layout: Layout,
+    uplo: Uplo,
+    n: Int,
+    k: Int,
+    alpha: c64,
+    a: &[c64],
+    lda: Int,
+    x: &[c64],
+    incx: Int,
+    beta: c64,
+    y: &mut [c64],
+    incy: Int,
+) {
+    ffi::cblas_zhbmv(
+        layout.into(),
+        uplo.into(),
+        n,
+        k,
+        &alpha as *const _ as *const _,
+        a.as_ptr() as *const _,
+        lda,
+        x.as_ptr() as *const _,
+        incx,
+        &beta as *const _ as *const _,
+        y.as_mut_ptr() as *mut _,
+        incy,
+    )
+}
+
+#[inline]
+pub unsafe fn zhpmv(
+    layout: Layout,
+    uplo: Uplo,
+    n: Int,
+    alpha: c64,
+    ap: &[c64],
+    x: &[c64],
+    incx: Int,
+    beta: c64,
+    y: &mut [c64],
+    incy: Int,
+) {
+    ffi::cblas_zhpmv(
+        layout.into(),
+        uplo.into(),
+        n,
+        &alpha as *const _ as *const _,
+        ap.as_ptr() as *const _,
+        x.as_ptr() as *const _,
+        incx,
+        &beta as *const _ as *const _,
+        y.as_mut_ptr() as *mut _,
+        incy,
+    )
+}
+
+#[inline]
+pub unsafe fn ztrmv(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    n: Int,
+    a: &[c64],
+    lda: Int,
+    x: &mut [c64],
+    incx: Int,
+) {
+    ffi::cblas_ztrmv(
+        layout.into(),
+        uplo.into(),
+        trans.into(),
+        diag.into(),
+        n,
+        a.as_ptr() as *const _,
+        lda,
+        x.as_mut_ptr() as *mut _,
+        incx,
+    )
+}
+
+#[inline]
+pub unsafe fn ztbmv(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    n: Int,
+    k: Int,
+    a: &[c64],
+    lda: Int,
+    x: &mut [c64],
+    incx: Int,
+) {
+    ffi::cblas_ztbmv(
+        layout.into(),
+        uplo.into(),
+        trans.into(),
+        diag.into(),
+        n,
+        k,
+        a.as_ptr() as *const _,
+        lda,
+        x.as_mut_ptr() as *mut _,
+        incx,
+    )
+}
+
+#[inline]
+pub unsafe fn ztpmv(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    n: Int,
+    ap: &[c64],
+    x: &mut [c64],
+    incx: Int,
+) {
+    ffi::cblas_ztpmv(
+        layout.into(),
+        uplo.into(),
+        trans.into(),
+        diag.into(),
+        n,
+        ap.as_ptr() as *const _,
+        x.as_mut_ptr() as *mut _,
+        incx,
+    )
+}
+
+#[inline]
+pub unsafe fn ztrsv(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    n: Int,
+    a: &[c64],
+    lda: Int,
+    x: &mut [c64],
+    incx: Int,
+) {
+    ffi::cblas_ztrsv(
+        layout.into(),
+        uplo.into(),
+        trans.into(),
+        diag.into(),
+        n,
+        a.as_ptr() as *const _,
+        lda,
+        x.as_mut_ptr() as *mut _,
+        incx,
+    )
+}
+
+#[inline]
+pub unsafe fn ztbsv(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    n: Int,
+    k: Int,
+    a: &[c64],
+    lda: Int,
+    x: &mut [c64],
+    incx: Int,
+) {
+    ffi::cblas_ztbsv(
+        layout.into(),
+        uplo.into(),
+        trans.into(),
+        diag.into(),
+        n,
+        k,
+        a.as_ptr() as *const _,
+        lda,
+        x.as_mut_ptr() as *mut _,
+        incx,
+    )
+}
+
+#[inline]
+pub unsafe fn ztpsv(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    n: Int,
+    ap: &[c64],
+    x: &mut [c64],
+    incx: Int,
+) {
+    ffi::cblas_ztpsv(
+        layout.into(),
+        uplo.into(),
+        trans.into(),
+        diag.into(),
+        n,
+        ap.as_ptr() as *const _,
+        x.as_mut_ptr() as *mut _,
+        incx,
+    )
+}
+
+#[inline]
+pub unsafe fn zgeru(
+    layout: Layout,
+    m: Int,
+    n: Int,
+    alpha: c64,
+    x: &[c64],
+    incx: Int,
+    y: &[c64],
+    incy: Int,
+    a: &mut [c64],
+    lda: Int,
+) {
+    ffi::cblas_zgeru(
+        layout.into(),
+        m,
+        n,
+        &alpha as *const _ as *const _,
+        x.as_ptr() as *const _,
+        incx,
+        y.as_ptr() as *const _,
+        incy,
+        a.as_mut_ptr() as *mut _,
+        lda,
+    )
+}
+
+#[inline]
+pub unsafe fn zgerc(
+    layout: Layout,
+    m: Int,
+    n: Int,
+    alpha: c64,
+    x: &[c64],
+    incx: Int,
+    y: &[c64],
+    incy: Int,
+    a: &mut [c64],
+    lda: Int,
+) {
+    ffi::cblas_zgerc(
+        layout.into(),
+        m,
+        n,
+        &alpha as *const _ as *const _,
+        x.as_ptr() as *const _,
+        incx,
+        y.as_ptr() as *const _,
+        incy,
+        a.as_mut_ptr() as *mut _,
+        lda,
+    )
+}
+
+#[inline]
+pub unsafe fn zher(
+    layout: Layout,
+    uplo: Uplo,
+    n: Int,
+    alpha: f64,
+    x: &[c64],
+    incx: Int,
+    a: &mut [c64],
+    lda: Int,
+) {
+    ffi::cblas_zher(
+        layout.into(),
+        uplo.into(),
+        n,
+        alpha,
+        x.as_ptr() as *const _,
+        incx,
+        a.as_mut_ptr() as *mut _,
+        lda,
+    )
+}
+
+#[inline]
+pub unsafe fn zhpr(
+    layout: Layout,
+    uplo: Uplo,
+    n: Int,
+    alpha: f64,
+    x: &[c64],
+    incx: Int,
+    ap: &mut [c64],
+) {
+    ffi::cblas_zhpr(
+        layout.into(),
+        uplo.into(),
+        n,
+        alpha,
+        x.as_ptr() as *const _,
+        incx,
+        ap.as_mut_ptr() as *mut _,
+    )
+}
+
+#[inline]
+pub unsafe fn zher2(
+    layout: Layout,
+    uplo: Uplo,
+    n: Int,
+    alpha: c64,
+    x: &[c64],
+    incx: Int,
+    y: &[c64],
+    incy: Int,
+    a: &mut [c64],
+    lda: Int,
+) {
+    ffi::cblas_zher2(
+        layout.into(),
+        uplo.into(),
+        n,
+        &alpha as *const _ as *const _,
+        x.as_ptr() as *const _,
+        incx,
+        y.as_ptr() as *const _,
+        incy,
+        a.as_mut_ptr() as *mut _,
+        lda,
+    )
+}
+
+#[inline]
+pub unsafe fn zhpr2(
+    layout: Layout,
+    uplo: Uplo,
+    n: Int,
+    alpha: c64,
+    x: &[c64],
+    incx: Int,
+    y: &[c64],
+    incy: Int,
+    ap: &mut [c64],
+) {
+    ffi::cblas_zhpr2(
+        layout.into(),
+        uplo.into(),
+        n,
+        &alpha as *const _ as *const _,
+        x.as_ptr() as *const _,
+        incx,
+        y.as_ptr() as *const _,
+        incy,
+        ap.as_mut_ptr() as *mut _,
+    )
+}
+
+#[inline]
+pub unsafe fn sgemm(
+    layout: Layout,
+    transa: Transpose,
+    transb: Transpose,
+    m: Int,
+    n: Int,
+    k: Int,
+    alpha: f32,
+    a: &[f32],
+    lda: Int,
+    b: &[f32],
+    ldb: Int,
+    beta: f32,
+    c: &mut [f32],
+    ldc: Int,
+) {
+    ffi::cblas_sgemm(
+        layout.into(),
+        transa.into(),
+        transb.into(),
+        m,
+        n,
+        k,
+        alpha,
+        a.as_ptr(),
+        lda,
+        b.as_ptr(),
+        ldb,
+        beta,
+        c.as_mut_ptr(),
+        ldc,
+    )
+}
+
+#[inline]
+pub unsafe fn ssymm(
+    layout: Layout,
+    side: Side,
+    uplo: Uplo,
+    m: Int,
+    n: Int,
+    alpha: f32,
+    a: &[f32],
+    lda: Int,
+    b: &[f32],
+    ldb: Int,
+    beta: f32,
+    c: &mut [f32],
+    ldc: Int,
+) {
+    ffi::cblas_ssymm(
+        layout.into(),
+        side.into(),
+        uplo.into(),
+        m,
+        n,
+        alpha,
+        a.as_ptr(),
+        lda,
+        b.as_ptr(),
+        ldb,
+        beta,
+        c.as_mut_ptr(),
+        ldc,
+    )
+}
+
+#[inline]
+pub unsafe fn ssyrk(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Transpose,
+    n: Int,
+    k: Int,
+    alpha: f32,
+    a: &[f32],
+    lda: Int,
+    beta: f32,
+    c: &mut [f32],
+    ldc: Int,
+) {
+    ffi::cblas_ssyrk(
+        layout.into(),
+        uplo.into(),
+        trans.into(),
+        n,
+        k,
+        alpha,
+        a.as_ptr(),
+        lda,
+        beta,
+        c.as_mut_ptr(),
+        ldc,
+    )
+}
+
+#[inline]
+pub unsafe fn ssyr2k(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Transpose,
+    n: Int,
+    k: Int,
+    alpha: f32,
+    a: &[f32],
+    lda: Int,
+    b: &[f32],
+    ldb: Int,
+    beta: f32,
+    c: &mut [f32],
+    ldc: Int,
+) {
+    ffi::cblas_ssyr2k(
+        layout.into(),
+        uplo.into(),
+        trans.into(),
+        n,
+        k,
+        alpha,
+        a.as_ptr(),
+        lda,
+        b.as_ptr(),
+        ldb,
+        beta,
+        c.as_mut_ptr(),
+        ldc,
+    )
+}
+
+#[inline]
+pub unsafe fn strmm(
+    layout: Layout,
+    side: Side,
+    uplo: Uplo,
+    transa: Transpose,
+    diag: Diag,
+    m: Int,
+    n: Int,
+    alpha: f32,
+    a: &[f32],
+    lda: Int,
+    b: &mut [f32],
+    ldb: Int,
+) {
+    ffi::cblas_strmm(
+        layout.into(),
+        side.into(),
+        uplo.into(),
+        transa.into(),
+        diag.into(),
+        m,
+        n,
+        alpha,
+        a.as_ptr(),
+        lda,
+        b.as_mut_ptr(),
+        ldb,
+    )
+}
+
+#[inline]
+pub unsafe fn strsm(
+    layout: Layout,
+    side: Side,
+    uplo: Uplo,
+    transa: Transpose,
+    diag: Diag,
+    m: Int,
+    n: Int,
+    alpha: f32,
+    a: &[f32],
+    lda: Int,
+    b: &mut [f32],
+    ldb: Int,
+) {
+    ffi::cblas_strsm(
+        layout.into(),
+        side.into(),
+        uplo.into(),
+        transa.into(),
+        diag.into(),
+        m,
+        n,
+        alpha,
+        a.as_ptr(),
+        lda,
+        b.as_mut_ptr(),
+        ldb,
+    )
+}
+
+#[inline]
+pub unsafe fn dgemm(
+    layout: Layout,
+    transa: Transpose,
+    transb: Transpose,
+    m: Int,
+    n: Int,
+    k: Int,
+    alpha: f64,
+    a: &[f64],
+    lda: Int,
+    b: &[f64],
+    ldb: Int,
+    beta: f64,
+    c: &mut [f64],
+    ldc: Int,
+) {
+    ffi::cblas_dgemm(
+        layout.into(),
+        transa.into(),
+        transb.into(),
+        m,
+        n,
+        k,
+        alpha,
+        a.as_ptr(),
+        lda,
+        b.as_ptr(),
+        ldb,
+        beta,
+        c.as_mut_ptr(),
+        ldc,
+    )
+}
+
+#[inline]
+pub unsafe fn dsymm(
+    layout: Layout,
+    side: Side,
+    uplo: Uplo,
+    m: Int,
+    n: Int,
+    alpha: f64,
+    a: &[f64],
+    lda: Int,
+    b: &[f64],
+    ldb: Int,
+    beta: f64,
+    c: &mut [f64],
+    ldc: Int,
+) {
+    ffi::cblas_dsymm(
+        layout.into(),
+        side.into(),
+        uplo.into(),
+        m,
+        n,
+        alpha,
+        a.as_ptr(),
+        lda,
+        b.as_ptr(),
+        ldb,
+        beta,
+        c.as_mut_ptr(),
+        ldc,
+    )
+}
+
+#[inline]
+pub unsafe fn dsyrk(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Transpose,
+    n: Int,
+    k: Int,
+    alpha: f64,
+    a: &[f64],
+    lda: Int,
+    beta: f64,
+    c: &mut [f64],
+    ldc: Int,
+) {
+    ffi::cblas_dsyrk(
+        layout.into(),
+        uplo.into(),
+        trans.into(),
+        n,
+        k,
+        alpha,
+        a.as_ptr(),
+        lda,
+        beta,
+        c.as_mut_ptr(),
+        ldc,
+    )
+}
+
+#[inline]
+pub unsafe fn dsyr2k(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Transpose,
+    n: Int,
+    k: Int,
+    alpha: f64,
+    a: &[f64],
+    lda: Int,
+    b: &[f64],
+    ldb: Int,
+    beta: f64,
+    c: &mut [f64],
+    ldc: Int,
+) {
+    ffi::cblas_dsyr2k(
+        layout.into(),
+        uplo.into(),
+        trans.into(),
+        n,
+        k,
+        alpha,
+        a.as_ptr(),
+        lda,
+        b.as_ptr(),
+        ldb,
+        beta,
+        c.as_mut_ptr(),
+        ldc,
+    )
+}
+
+#[inline]
+pub unsafe fn dtrmm(
+    layout: Layout,
+    side: Side,
+    uplo: Uplo,
+    transa: Transpose,
+    diag: Diag,
+    m: Int,
+    n: Int,
+    alpha: f64,
+    a: &[f64],
+    lda: Int,
+    b: &mut [f64],
+    ldb: Int,
+) {
+    ffi::cblas_dtrmm(
+        layout.into(),
+        side.into(),
+        uplo.into(),
+        transa.into(),
+        diag.into(),
+        m,
+        n,
+        alpha,
+        a.as_ptr(),
+        lda,
+        b.as_mut_ptr(),
+        ldb,
+    )
+}
+
+#[inline]
+pub unsafe fn dtrsm(
+    layout: Layout,
+    side: Side,
+    uplo: Uplo,
+    transa: Transpose,
+    diag: Diag,
+    m: Int,
+    n: Int,
+    alpha: f64,
+    a: &[f64],
+    lda: Int,
+    b: &mut [f64],
+    ldb: Int,
+) {
+    ffi::cblas_dtrsm(
+        layout.into(),
+        side.into(),
+        uplo.into(),
+        transa.into(),
+        diag.into(),
+        m,
+        n,
+        alpha,
+        a.as_ptr(),
+        lda,
+        b.as_mut_ptr(),
+        ldb,
+    )
+}
+
+#[inline]
+pub unsafe fn cgemm(
+    layout: Layout,
+    transa: Transpose,
+    transb: Transpose,
+    m: Int,
+    n: Int,
+    k: Int,
+    alpha: c32,
+    a: &[c32],
+    lda: Int,
+    b: &[c32],
+    ldb: Int,
+    beta: c32,
+    c: &mut [c32],
+    ldc: Int,
+) {
+    ffi::cblas_cgemm(
+        layout.into(),
+        transa.into(),
+        transb.into(),
+        m,
+        n,
+        k,
+        &alpha as *const _ as *const _,
+        a.as_ptr() as *const _,
+        lda,
+        b.as_ptr() as *const _,
+        ldb,
+        &beta as *const _ as *const _,
+        c.as_mut_ptr() as *mut _,
+        ldc,
+    )
+}
+
+#[inline]
+pub unsafe fn csymm(
+    layout: Layout,
+    side: Side,
+    uplo: Uplo,
+    m: Int,
+    n: Int,
+    alpha: c32,
+    a: &[c32],
+    lda: Int,
+    b: &[c32],
+    ldb: Int,
+    beta: c32,
+    c: &mut [c32],
+    ldc: Int,
+) {
+    ffi::cblas_csymm(
+        layout.into(),
+        side.into(),
+        uplo.into(),
+        m,
+        n,
+        &alpha as *const _ as *const _,
+        a.as_ptr() as *const _,
+        lda,
+        b.as_ptr() as *const _,
+        ldb,
+        &beta as *const _ as *const _,
+        c.as_mut_ptr() as *mut _,
+        ldc,
+    )
+}
+
+#[inline]
+pub unsafe fn chemm(
+    layout: Layout,
+    side: Side,
+    uplo: Uplo,
+    m: Int,
+    n: Int,
+    alpha: c32,
+    a: &[c32],
+    lda: Int,
+    b: &[c32],
+    ldb: Int,
+    beta: c32,
+    c: &mut [c32],
+    ldc: Int,
+) {
+    ffi::cblas_chemm(
+        layout.into(),
+        side.into(),
+        uplo.into(),
+        m,
+        n,
+        &alpha as *const _ as *const _,
+        a.as_ptr() as *const _,
+        lda,
+        b.as_ptr() as *const _,
+        ldb,
+        &beta as *const _ as *const _,
+        c.as_mut_ptr() as *mut _,
+        ldc,
+    )
+}
+
+#[inline]
+pub unsafe fn csyrk(
+    layout: Layout,
+    uplo: Uplo,
+    trans: NoneOrTrans,
+    n: Int,
+    k: Int,
+    alpha: c32,
+    a: &[c32],
+    lda: Int,
+    beta: c32,
+    c: &mut [c32],
+    ldc: Int,
+) {
+    ffi::cblas_csyrk(
+        layout.into(),
+        uplo.into(),
+        trans.into(),
+        n,
+        k,
+        &alpha as *const _ as *const _,
+        a.as_ptr() as *const _,
+        lda,
+        &beta as *const _ as *const _,
+        c.as_mut_ptr() as *mut _,
+        ldc,
+    )
+}
+
+#[inline]
+pub unsafe fn cherk(
+    layout: Layout,
+    uplo: Uplo,
+    trans: NoneOrConj,
+    n: Int,
+    k: Int,
+    alpha: f32,
+    a: &[c32],
+    lda: Int,
+    beta: f32,
+    c: &mut [c32],
+    ldc: Int,
+) {
+    ffi::cblas_cherk(
+        layout.into(),
+        uplo.into(),
+        trans.into(),
+        n,
+        k,
+        alpha,
+        a.as_ptr() as *const _,
+        lda,
+        beta,
+        c.as_mut_ptr() as *mut _,
+        ldc,
+    )
+}
+
+#[inline]
+pub unsafe fn csyr2k(
+    layout: Layout,
+    uplo: Uplo,
+    trans: NoneOrTrans,
+    n: Int,
+    k: Int,
+    alpha: c32,
+    a: &[c32],
+    lda: Int,
+    b: &[c32],
+    ldb: Int,
+    beta: c32,
+    c: &mut [c32],
+    ldc: Int,
+) {
+    ffi::cblas_csyr2k(
+        layout.into(),
+        uplo.into(),
+        trans.into(),
+        n,
+        k,
+        &alpha as *const _ as *const _,
+        a.as_ptr() as *const _,
+        lda,
+        b.as_ptr() as *const _,
+        ldb,
+        &beta as *const _ as *const _,
+        c.as_mut_ptr() as *mut _,
+        ldc,
+    )
+}
+
+#[inline]
+pub unsafe fn cher2k(
+    layout: Layout,
+    uplo: Uplo,
+    trans: NoneOrConj,
+    n: Int,
+    k: Int,
+    alpha: c32,
+    a: &[c32],
+    lda: Int,
+    b: &[c32],
+    ldb: Int,
+    beta: f32,
+    c: &mut [c32],
+    ldc: Int,
+) {
+    ffi::cblas_cher2k(
+        layout.into(),
+        uplo.into(),
+        trans.into(),
+        n,
+        k,
+        &alpha as *const _ as *const _,
+        a.as_ptr() as *const _,
+        lda,
+        b.as_ptr() as *const _,
+        ldb,
+        beta,
+        c.as_mut_ptr() as *mut _,
+        ldc,
+    )
+}
+
+#[inline]
+pub unsafe fn ctrmm(
+    layout: Layout,
+    side: Side,
+    uplo: Uplo,
+    transa: Transpose,
+    diag: Diag,
+    m: Int,
+    n: Int,
+    alpha: c32,
+    a: &[c32],
+    lda: Int,
+    b: &mut [c32],
+    ldb: Int,
+) {
+    ffi::cblas_ctrmm(
+        layout.into(),
+        side.into(),
+        uplo.into(),
+        transa.into(),
+        diag.into(),
+        m,
+        n,
+        &alpha as *const _ as *const _,
+        a.as_ptr() as *const _,
+        lda,
+        b.as_mut_ptr() as *mut _,
+        ldb,
+    )
+}
+
+#[inline]
+pub unsafe fn ctrsm(
+    layout: Layout,
+    side: Side,
+    uplo: Uplo,
+    transa: Transpose,
+    diag: Diag,
+    m: Int,
+    n: Int,
+    alpha: c32,
+    a: &[c32],
+    lda: Int,
+    b: &mut [c32],
+    ldb: Int,
+) {
+    ffi::cblas_ctrsm(
+        layout.into(),
+        side.into(),
+        uplo.into(),
+        transa.into(),
+        diag.into(),
+        m,
+        n,
+        &alpha as *const _ as *const _,
+        a.as_ptr() as *const _,
+        lda,
+        b.as_mut_ptr() as *mut _,
+        ldb,
+    )
+}
+
+#[inline]
+pub unsafe fn zgemm(
+    layout: Layout,
+    transa: Transpose,
+    transb: Transpose,
+    m: Int,
+    n: Int,
+    k: Int,
+    alpha: c64,
+    a: &[c64],
+    lda: Int,
+    b: &[c64],
+    ldb: Int,
+    beta: c64,
+    c: &mut [c64],
+    ldc: Int,
+) {
+    ffi::cblas_zgemm(
+        layout.into(),
+        transa.into(),
+        transb.into(),
+        m,
+        n,
+        k,
+        &alpha as *const _ as *const _,
+        a.as_ptr() as *const _,
+        lda,
+        b.as_ptr() as *const _,
+        ldb,
+        &beta as *const _ as *const _,
+        c.as_mut_ptr() as *mut _,
+        ldc,
+    )
+}
+
+#[inline]
+pub unsafe fn zsymm(
+    layout: Layout,
+    side: Side,
+    uplo: Uplo,
+    m: Int,
+    n: Int,
+    alpha: c64,
+    a: &[c64],
+    lda: Int,
+    b: &[c64],
+    ldb: Int,
+    beta: c64,
+    c: &mut [c64],
+    ldc: Int,
+) {
+    ffi::cblas_zsymm(
+        layout.into(),
+        side.into(),
+        uplo.into(),
+        m,
+        n,
+        &alpha as *const _ as *const _,
+        a.as_ptr() as *const _,
+        lda,
+        b.as_ptr() as *const _,
+        ldb,
+        &beta as *const _ as *const _,
+        c.as_mut_ptr() as *mut _,
+        ldc,
+    )
+}
+
+#[inline]
+pub unsafe fn zhemm(
+    layout: Layout,
+    side: Side,
+    uplo: Uplo,
+    m: Int,
+    n: Int,
+    alpha: c64,
+    a: &[c64],
+    lda: Int,
+    b: &[c64],
+    ldb: Int,
+    beta: c64,
+    c: &mut [c64],
+    ldc: Int,
+) {
+    ffi::cblas_zhemm(
+        layout.into(),
+        side.into(),
+        uplo.into(),
+        m,
+        n,
+        &alpha as *const _ as *const _,
+        a.as_ptr() as *const _,
+        lda,
+        b.as_ptr() as *const _,
+        ldb,
+        &beta as *const _ as *const _,
+        c.as_mut_ptr() as *mut _,
+        ldc,
+    )
+}
+
+#[inline]
+pub unsafe fn zsyrk(
+    layout: Layout,
+    uplo: Uplo,
+    trans: NoneOrTrans,
+    n: Int,
+    k: Int,
+    alpha: c64,
+    a: &[c64],
+    lda: Int,
+    beta: c64,
+    c: &mut [c64],
+    ldc: Int,
+) {
+    ffi::cblas_zsyrk(
+        layout.into(),
+        uplo.into(),
+        trans.into(),
+        n,
+        k,
+        &alpha as *const _ as *const _,
+        a.as_ptr() as *const _,
+        lda,
+        &beta as *const _ as *const _,
+        c.as_mut_ptr() as *mut _,
+        ldc,
+    )
+}
+
+#[inline]
+pub unsafe fn zherk(
+    layout: Layout,
+    uplo: Uplo,
+    trans: NoneOrConj,
+    n: Int,
+    k: Int,
+    alpha: f64,
+    a: &[c64],
+    lda: Int,
+    beta: f64,
+    c: &mut [c64],
+    ldc: Int,
+) {
+    ffi::cblas_zherk(
+        layout.into(),
+        uplo.into(),
+        trans.into(),
+        n,
+        k,
+        alpha,
+        a.as_ptr() as *const _,
+        lda,
+        beta,
+        c.as_mut_ptr() as *mut _,
+        ldc,
+    )
+}
+
+#[inline]
+pub unsafe fn zsyr2k(
+    layout: Layout,
+    uplo: Uplo,
+    trans: NoneOrTrans,
+    n: Int,
+    k: Int,
+    alpha: c64,
+    a: &[c64],
+    lda: Int,
+    b: &[c64],
+    ldb: Int,
+    beta: c64,
+    c: &mut [c64],
+    ldc: Int,
+) {
+    ffi::cblas_zsyr2k(
+        layout.into(),
+        uplo.into(),
+        trans.into(),
+        n,
+        k,
+        &alpha as *const _ as *const _,
+        a.as_ptr() as *const _,
+        lda,
+        b.as_ptr() as *const _,
+        ldb,
+        &beta as *const _ as *const _,
+        c.as_mut_ptr() as *mut _,
+        ldc,
+    )
+}
+
+#[inline]
+pub unsafe fn zher2k(
+    layout: Layout,
+    uplo: Uplo,
+    trans: NoneOrConj,
+    n: Int,
+    k: Int,
+    alpha: c64,
+    a: &[c64],
+    lda: Int,
+    b: &[c64],
+    ldb: Int,
+    beta: f64,
+    c: &mut [c64],
+    ldc: Int,
+) {
+    ffi::cblas_zher2k(
+        layout.into(),
+        uplo.into(),
+        trans.into(),
+        n,
+        k,
+        &alpha as *const _ as *const _,
+        a.as_ptr() as *const _,
+        lda,
+        b.as_ptr() as *const _,
+        ldb,
+        beta,
+        c.as_mut_ptr() as *mut _,
+        ldc,
+    )
+}
+
+#[inline]
+pub unsafe fn ztrmm(
+    layout: Layout,
+    side: Side,
+    uplo: Uplo,
+    transa: Transpose,
+    diag: Diag,
+    m: Int,
+    n: Int,
+    alpha: c64,
+    a: &[c64],
+    lda: Int,
+    b: &mut [c64],
+    ldb: Int,
+) {
+    ffi::cblas_ztrmm(
+        layout.into(),
+        side.into(),
+        uplo.into(),
+        transa.into(),
+        diag.into(),
+        m,
+        n,
+        &alpha as *const _ as *const _,
+        a.as_ptr() as *const _,
+        lda,
+        b.as_mut_ptr() as *mut _,
+        ldb,
+    )
+}
+
+#[inline]
+pub unsafe fn ztrsm(
+    layout: Layout,
+    side: Side,
+    uplo: Uplo,
+    transa: Transpose,
+    diag: Diag,
+    m: Int,
+    n: Int,
+    alpha: c64,
+    a: &[c64],
+    lda: Int,
+    b: &mut [c64],
+    ldb: Int,
+) {
+    ffi::cblas_ztrsm(
+        layout.into(),
+        side.into(),
+        uplo.into(),
+        transa.into(),
+        diag.into(),
+        m,
+        n,
+        &alpha as *const _ as *const _,
+        a.as_ptr() as *const _,
+        lda,
+        b.as_mut_ptr() as *mut _,
+        ldb,
+    )
+}
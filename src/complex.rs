@@ -0,0 +1,77 @@
+//! Complex functions, whose results are returned differently by different backends.
+//!
+//! Fortran compilers return complex values either by value, which is the convention of gfortran
+//! and OpenBLAS, or via a hidden first argument, which is the f2c convention declared by
+//! `blas-sys` and followed by Intel MKL and Accelerate. The latter is used by default, and the
+//! former is used when the `complex-return` feature is enabled.
+
+use crate::{c32, c64, Int};
+
+#[cfg(any(feature = "reference", not(feature = "complex-return")))]
+macro_rules! implement {
+    ($($name:ident: $ffi:ident -> $scalar:ty,)*) => ($(
+        #[inline]
+        pub unsafe fn $name(
+            n: *const Int,
+            x: *const $scalar,
+            incx: *const Int,
+            y: *const $scalar,
+            incy: *const Int,
+        ) -> $scalar {
+            let mut pres = <$scalar>::new(0.0, 0.0);
+            let (x, y) = (x as *const _, y as *const _);
+            crate::ffi::$ffi(&mut pres as *mut _ as *mut _, n, x, incx, y, incy);
+            pres
+        }
+    )*);
+}
+
+#[cfg(all(
+    feature = "complex-return",
+    feature = "dynamic",
+    not(feature = "reference")
+))]
+macro_rules! implement {
+    ($($name:ident: $ffi:ident -> $scalar:ty,)*) => ($(
+        #[inline]
+        pub unsafe fn $name(
+            n: *const Int,
+            x: *const $scalar,
+            incx: *const Int,
+            y: *const $scalar,
+            incy: *const Int,
+        ) -> $scalar {
+            crate::ffi::$ffi(n, x, incx, y, incy)
+        }
+    )*);
+}
+
+#[cfg(all(
+    feature = "complex-return",
+    not(any(feature = "dynamic", feature = "reference")),
+))]
+macro_rules! implement {
+    ($($name:ident: $ffi:ident -> $scalar:ty,)*) => (
+        extern "C" {
+            $(
+                #[cfg_attr(feature = "ilp64", link_name = concat!(stringify!($ffi), "64_"))]
+                pub fn $ffi(
+                    n: *const Int,
+                    x: *const $scalar,
+                    incx: *const Int,
+                    y: *const $scalar,
+                    incy: *const Int,
+                ) -> $scalar;
+            )*
+        }
+
+        $(pub use $ffi as $name;)*
+    );
+}
+
+implement! {
+    cdotu: cdotu_ -> c32,
+    cdotc: cdotc_ -> c32,
+    zdotu: zdotu_ -> c64,
+    zdotc: zdotc_ -> c64,
+}
@@ -0,0 +1,210 @@
+//! Functions resolved from the loaded library, which mirror the declarations of `blas-sys`.
+//!
+//! The declarations are generated by `bin/generate.py --dynamic`.
+
+use core::ffi::{c_char, c_double, c_float};
+
+use crate::{c32, c64, Int};
+
+use super::{loaded, missing, resolve};
+
+macro_rules! declare {
+    ($($(#[$attr:meta])* pub fn $name:ident($($arg:ident: $ty:ty),* $(,)?) $(-> $ret:ty)?;)*) => (
+        /// Pointers to the functions, which are `None` for missing symbols.
+        pub struct Functions {
+            $($(#[$attr])* $name: Option<unsafe extern "C" fn($($ty),*) $(-> $ret)?>,)*
+        }
+
+        impl Functions {
+            pub unsafe fn resolve(library: &libloading::Library) -> Self {
+                Functions {
+                    $($(#[$attr])* $name: resolve(library, stringify!($name)),)*
+                }
+            }
+
+            pub fn missing(&self) -> Vec<String> {
+                let mut missing = Vec::new();
+                $(
+                    $(#[$attr])*
+                    if self.$name.is_none() {
+                        missing.push(super::symbol(stringify!($name)));
+                    }
+                )*
+                missing
+            }
+        }
+
+        $(
+            $(#[$attr])*
+            #[inline]
+            pub unsafe fn $name($($arg: $ty),*) $(-> $ret)? {
+                let library = loaded();
+                match library.functions.$name {
+                    Some(function) => function($($arg),*),
+                    None => missing(library, stringify!($name)),
+                }
+            }
+        )*
+    );
+}
+
+declare! {
+    pub fn srotg_(a: *mut c_float, b: *mut c_float, c: *mut c_float, s: *mut c_float);
+    pub fn srotmg_(d1: *mut c_float, d2: *mut c_float, x1: *mut c_float, y1: *const c_float, param: *mut c_float);
+    pub fn srot_(n: *const Int, x: *mut c_float, incx: *const Int, y: *mut c_float, incy: *const Int, c: *const c_float, s: *const c_float);
+    pub fn srotm_(n: *const Int, x: *mut c_float, incx: *const Int, y: *mut c_float, incy: *const Int, param: *const c_float);
+    pub fn sswap_(n: *const Int, x: *mut c_float, incx: *const Int, y: *mut c_float, incy: *const Int);
+    pub fn sscal_(n: *const Int, a: *const c_float, x: *mut c_float, incx: *const Int);
+    pub fn scopy_(n: *const Int, x: *const c_float, incx: *const Int, y: *mut c_float, incy: *const Int);
+    pub fn saxpy_(n: *const Int, alpha: *const c_float, x: *const c_float, incx: *const Int, y: *mut c_float, incy: *const Int);
+    pub fn sdot_(n: *const Int, x: *const c_float, incx: *const Int, y: *const c_float, incy: *const Int) -> c_float;
+    pub fn sdsdot_(n: *const Int, sb: *const c_float, x: *const c_float, incx: *const Int, y: *const c_float, incy: *const Int) -> c_float;
+    pub fn snrm2_(n: *const Int, x: *const c_float, incx: *const Int) -> c_float;
+    pub fn scnrm2_(n: *const Int, x: *const c32, incx: *const Int) -> c_float;
+    pub fn sasum_(n: *const Int, x: *const c_float, incx: *const Int) -> c_float;
+    pub fn isamax_(n: *const Int, x: *const c_float, incx: *const Int) -> Int;
+    pub fn drotg_(a: *mut c_double, b: *mut c_double, c: *mut c_double, s: *mut c_double);
+    pub fn drotmg_(d1: *mut c_double, d2: *mut c_double, x1: *mut c_double, y1: *const c_double, param: *mut c_double);
+    pub fn drot_(n: *const Int, x: *mut c_double, incx: *const Int, y: *mut c_double, incy: *const Int, c: *const c_double, s: *const c_double);
+    pub fn drotm_(n: *const Int, x: *mut c_double, incx: *const Int, y: *mut c_double, incy: *const Int, param: *const c_double);
+    pub fn dswap_(n: *const Int, x: *mut c_double, incx: *const Int, y: *mut c_double, incy: *const Int);
+    pub fn dscal_(n: *const Int, a: *const c_double, x: *mut c_double, incx: *const Int);
+    pub fn dcopy_(n: *const Int, x: *const c_double, incx: *const Int, y: *mut c_double, incy: *const Int);
+    pub fn daxpy_(n: *const Int, alpha: *const c_double, x: *const c_double, incx: *const Int, y: *mut c_double, incy: *const Int);
+    pub fn ddot_(n: *const Int, x: *const c_double, incx: *const Int, y: *const c_double, incy: *const Int) -> c_double;
+    pub fn dsdot_(n: *const Int, x: *const c_float, incx: *const Int, y: *const c_float, incy: *const Int) -> c_double;
+    pub fn dnrm2_(n: *const Int, x: *const c_double, incx: *const Int) -> c_double;
+    pub fn dznrm2_(n: *const Int, x: *const c64, incx: *const Int) -> c_double;
+    pub fn dasum_(n: *const Int, x: *const c_double, incx: *const Int) -> c_double;
+    pub fn idamax_(n: *const Int, x: *const c_double, incx: *const Int) -> Int;
+    pub fn crotg_(a: *mut c32, b: *const c32, c: *mut c_float, s: *mut c32);
+    pub fn csrot_(n: *const Int, x: *mut c32, incx: *const Int, y: *mut c32, incy: *const Int, c: *const c_float, s: *const c_float);
+    pub fn cswap_(n: *const Int, x: *mut c32, incx: *const Int, y: *mut c32, incy: *const Int);
+    pub fn cscal_(n: *const Int, a: *const c32, x: *mut c32, incx: *const Int);
+    pub fn csscal_(n: *const Int, a: *const c_float, x: *mut c32, incx: *const Int);
+    pub fn ccopy_(n: *const Int, x: *const c32, incx: *const Int, y: *mut c32, incy: *const Int);
+    pub fn caxpy_(n: *const Int, alpha: *const c32, x: *const c32, incx: *const Int, y: *mut c32, incy: *const Int);
+    #[cfg(not(feature = "complex-return"))]
+    pub fn cdotu_(pres: *mut c32, n: *const Int, x: *const c32, incx: *const Int, y: *const c32, incy: *const Int);
+    #[cfg(feature = "complex-return")]
+    pub fn cdotu_(n: *const Int, x: *const c32, incx: *const Int, y: *const c32, incy: *const Int) -> c32;
+    #[cfg(not(feature = "complex-return"))]
+    pub fn cdotc_(pres: *mut c32, n: *const Int, x: *const c32, incx: *const Int, y: *const c32, incy: *const Int);
+    #[cfg(feature = "complex-return")]
+    pub fn cdotc_(n: *const Int, x: *const c32, incx: *const Int, y: *const c32, incy: *const Int) -> c32;
+    pub fn scasum_(n: *const Int, x: *const c32, incx: *const Int) -> c_float;
+    pub fn icamax_(n: *const Int, x: *const c32, incx: *const Int) -> Int;
+    pub fn zrotg_(a: *mut c64, b: *const c64, c: *mut c_double, s: *mut c64);
+    pub fn zdrot_(n: *const Int, x: *mut c64, incx: *const Int, y: *mut c64, incy: *const Int, c: *const c_double, s: *const c_double);
+    pub fn zswap_(n: *const Int, x: *mut c64, incx: *const Int, y: *mut c64, incy: *const Int);
+    pub fn zscal_(n: *const Int, a: *const c64, x: *mut c64, incx: *const Int);
+    pub fn zdscal_(n: *const Int, a: *const c_double, x: *mut c64, incx: *const Int);
+    pub fn zcopy_(n: *const Int, x: *const c64, incx: *const Int, y: *mut c64, incy: *const Int);
+    pub fn zaxpy_(n: *const Int, alpha: *const c64, x: *const c64, incx: *const Int, y: *mut c64, incy: *const Int);
+    #[cfg(not(feature = "complex-return"))]
+    pub fn zdotu_(pres: *mut c64, n: *const Int, x: *const c64, incx: *const Int, y: *const c64, incy: *const Int);
+    #[cfg(feature = "complex-return")]
+    pub fn zdotu_(n: *const Int, x: *const c64, incx: *const Int, y: *const c64, incy: *const Int) -> c64;
+    #[cfg(not(feature = "complex-return"))]
+    pub fn zdotc_(pres: *mut c64, n: *const Int, x: *const c64, incx: *const Int, y: *const c64, incy: *const Int);
+    #[cfg(feature = "complex-return")]
+    pub fn zdotc_(n: *const Int, x: *const c64, incx: *const Int, y: *const c64, incy: *const Int) -> c64;
+    pub fn dzasum_(n: *const Int, x: *const c64, incx: *const Int) -> c_double;
+    pub fn izamax_(n: *const Int, x: *const c64, incx: *const Int) -> Int;
+    pub fn sgemv_(trans: *const c_char, m: *const Int, n: *const Int, alpha: *const c_float, a: *const c_float, lda: *const Int, x: *const c_float, incx: *const Int, beta: *const c_float, y: *mut c_float, incy: *const Int);
+    pub fn sgbmv_(trans: *const c_char, m: *const Int, n: *const Int, kl: *const Int, ku: *const Int, alpha: *const c_float, a: *const c_float, lda: *const Int, x: *const c_float, incx: *const Int, beta: *const c_float, y: *mut c_float, incy: *const Int);
+    pub fn ssymv_(uplo: *const c_char, n: *const Int, alpha: *const c_float, a: *const c_float, lda: *const Int, x: *const c_float, incx: *const Int, beta: *const c_float, y: *mut c_float, incy: *const Int);
+    pub fn ssbmv_(uplo: *const c_char, n: *const Int, k: *const Int, alpha: *const c_float, a: *const c_float, lda: *const Int, x: *const c_float, incx: *const Int, beta: *const c_float, y: *mut c_float, incy: *const Int);
+    pub fn sspmv_(uplo: *const c_char, n: *const Int, alpha: *const c_float, ap: *const c_float, x: *const c_float, incx: *const Int, beta: *const c_float, y: *mut c_float, incy: *const Int);
+    pub fn strmv_(uplo: *const c_char, transa: *const c_char, diag: *const c_char, n: *const Int, a: *const c_float, lda: *const Int, b: *mut c_float, incx: *const Int);
+    pub fn stbmv_(uplo: *const c_char, trans: *const c_char, diag: *const c_char, n: *const Int, k: *const Int, a: *const c_float, lda: *const Int, x: *mut c_float, incx: *const Int);
+    pub fn stpmv_(uplo: *const c_char, trans: *const c_char, diag: *const c_char, n: *const Int, ap: *const c_float, x: *mut c_float, incx: *const Int);
+    pub fn strsv_(uplo: *const c_char, trans: *const c_char, diag: *const c_char, n: *const Int, a: *const c_float, lda: *const Int, x: *mut c_float, incx: *const Int);
+    pub fn stbsv_(uplo: *const c_char, trans: *const c_char, diag: *const c_char, n: *const Int, k: *const Int, a: *const c_float, lda: *const Int, x: *mut c_float, incx: *const Int);
+    pub fn stpsv_(uplo: *const c_char, trans: *const c_char, diag: *const c_char, n: *const Int, ap: *const c_float, x: *mut c_float, incx: *const Int);
+    pub fn sger_(m: *const Int, n: *const Int, alpha: *const c_float, x: *const c_float, incx: *const Int, y: *const c_float, incy: *const Int, a: *mut c_float, lda: *const Int);
+    pub fn ssyr_(uplo: *const c_char, n: *const Int, alpha: *const c_float, x: *const c_float, incx: *const Int, a: *mut c_float, lda: *const Int);
+    pub fn sspr_(uplo: *const c_char, n: *const Int, alpha: *const c_float, x: *const c_float, incx: *const Int, ap: *mut c_float);
+    pub fn ssyr2_(uplo: *const c_char, n: *const Int, alpha: *const c_float, x: *const c_float, incx: *const Int, y: *const c_float, incy: *const Int, a: *mut c_float, lda: *const Int);
+    pub fn sspr2_(uplo: *const c_char, n: *const Int, alpha: *const c_float, x: *const c_float, incx: *const Int, y: *const c_float, incy: *const Int, ap: *mut c_float);
+    pub fn dgemv_(trans: *const c_char, m: *const Int, n: *const Int, alpha: *const c_double, a: *const c_double, lda: *const Int, x: *const c_double, incx: *const Int, beta: *const c_double, y: *mut c_double, incy: *const Int);
+    pub fn dgbmv_(trans: *const c_char, m: *const Int, n: *const Int, kl: *const Int, ku: *const Int, alpha: *const c_double, a: *const c_double, lda: *const Int, x: *const c_double, incx: *const Int, beta: *const c_double, y: *mut c_double, incy: *const Int);
+    pub fn dsymv_(uplo: *const c_char, n: *const Int, alpha: *const c_double, a: *const c_double, lda: *const Int, x: *const c_double, incx: *const Int, beta: *const c_double, y: *mut c_double, incy: *const Int);
+    pub fn dsbmv_(uplo: *const c_char, n: *const Int, k: *const Int, alpha: *const c_double, a: *const c_double, lda: *const Int, x: *const c_double, incx: *const Int, beta: *const c_double, y: *mut c_double, incy: *const Int);
+    pub fn dspmv_(uplo: *const c_char, n: *const Int, alpha: *const c_double, ap: *const c_double, x: *const c_double, incx: *const Int, beta: *const c_double, y: *mut c_double, incy: *const Int);
+    pub fn dtrmv_(uplo: *const c_char, transa: *const c_char, diag: *const c_char, n: *const Int, a: *const c_double, lda: *const Int, b: *mut c_double, incx: *const Int);
+    pub fn dtbmv_(uplo: *const c_char, trans: *const c_char, diag: *const c_char, n: *const Int, k: *const Int, a: *const c_double, lda: *const Int, x: *mut c_double, incx: *const Int);
+    pub fn dtpmv_(uplo: *const c_char, trans: *const c_char, diag: *const c_char, n: *const Int, ap: *const c_double, x: *mut c_double, incx: *const Int);
+    pub fn dtrsv_(uplo: *const c_char, trans: *const c_char, diag: *const c_char, n: *const Int, a: *const c_double, lda: *const Int, x: *mut c_double, incx: *const Int);
+    pub fn dtbsv_(uplo: *const c_char, trans: *const c_char, diag: *const c_char, n: *const Int, k: *const Int, a: *const c_double, lda: *const Int, x: *mut c_double, incx: *const Int);
+    pub fn dtpsv_(uplo: *const c_char, trans: *const c_char, diag: *const c_char, n: *const Int, ap: *const c_double, x: *mut c_double, incx: *const Int);
+    pub fn dger_(m: *const Int, n: *const Int, alpha: *const c_double, x: *const c_double, incx: *const Int, y: *const c_double, incy: *const Int, a: *mut c_double, lda: *const Int);
+    pub fn dsyr_(uplo: *const c_char, n: *const Int, alpha: *const c_double, x: *const c_double, incx: *const Int, a: *mut c_double, lda: *const Int);
+    pub fn dspr_(uplo: *const c_char, n: *const Int, alpha: *const c_double, x: *const c_double, incx: *const Int, ap: *mut c_double);
+    pub fn dsyr2_(uplo: *const c_char, n: *const Int, alpha: *const c_double, x: *const c_double, incx: *const Int, y: *const c_double, incy: *const Int, a: *mut c_double, lda: *const Int);
+    pub fn dspr2_(uplo: *const c_char, n: *const Int, alpha: *const c_double, x: *const c_double, incx: *const Int, y: *const c_double, incy: *const Int, ap: *mut c_double);
+    pub fn cgemv_(trans: *const c_char, m: *const Int, n: *const Int, alpha: *const c32, a: *const c32, lda: *const Int, x: *const c32, incx: *const Int, beta: *const c32, y: *mut c32, incy: *const Int);
+    pub fn cgbmv_(trans: *const c_char, m: *const Int, n: *const Int, kl: *const Int, ku: *const Int, alpha: *const c32, a: *const c32, lda: *const Int, x: *const c32, incx: *const Int, beta: *const c32, y: *mut c32, incy: *const Int);
+    pub fn chemv_(uplo: *const c_char, n: *const Int, alpha: *const c32, a: *const c32, lda: *const Int, x: *const c32, incx: *const Int, beta: *const c32, y: *mut c32, incy: *const Int);
+    pub fn chbmv_(uplo: *const c_char, n: *const Int, k: *const Int, alpha: *const c32, a: *const c32, lda: *const Int, x: *const c32, incx: *const Int, beta: *const c32, y: *mut c32, incy: *const Int);
+    pub fn chpmv_(uplo: *const c_char, n: *const Int, alpha: *const c32, ap: *const c32, x: *const c32, incx: *const Int, beta: *const c32, y: *mut c32, incy: *const Int);
+    pub fn ctrmv_(uplo: *const c_char, transa: *const c_char, diag: *const c_char, n: *const Int, a: *const c32, lda: *const Int, b: *mut c32, incx: *const Int);
+    pub fn ctbmv_(uplo: *const c_char, trans: *const c_char, diag: *const c_char, n: *const Int, k: *const Int, a: *const c32, lda: *const Int, x: *mut c32, incx: *const Int);
+    pub fn ctpmv_(uplo: *const c_char, trans: *const c_char, diag: *const c_char, n: *const Int, ap: *const c32, x: *mut c32, incx: *const Int);
+    pub fn ctrsv_(uplo: *const c_char, trans: *const c_char, diag: *const c_char, n: *const Int, a: *const c32, lda: *const Int, x: *mut c32, incx: *const Int);
+    pub fn ctbsv_(uplo: *const c_char, trans: *const c_char, diag: *const c_char, n: *const Int, k: *const Int, a: *const c32, lda: *const Int, x: *mut c32, incx: *const Int);
+    pub fn ctpsv_(uplo: *const c_char, trans: *const c_char, diag: *const c_char, n: *const Int, ap: *const c32, x: *mut c32, incx: *const Int);
+    pub fn cgeru_(m: *const Int, n: *const Int, alpha: *const c32, x: *const c32, incx: *const Int, y: *const c32, incy: *const Int, a: *mut c32, lda: *const Int);
+    pub fn cgerc_(m: *const Int, n: *const Int, alpha: *const c32, x: *const c32, incx: *const Int, y: *const c32, incy: *const Int, a: *mut c32, lda: *const Int);
+    pub fn cher_(uplo: *const c_char, n: *const Int, alpha: *const c_float, x: *const c32, incx: *const Int, a: *mut c32, lda: *const Int);
+    pub fn chpr_(uplo: *const c_char, n: *const Int, alpha: *const c_float, x: *const c32, incx: *const Int, ap: *mut c32);
+    pub fn chpr2_(uplo: *const c_char, n: *const Int, alpha: *const c32, x: *const c32, incx: *const Int, y: *const c32, incy: *const Int, ap: *mut c32);
+    pub fn cher2_(uplo: *const c_char, n: *const Int, alpha: *const c32, x: *const c32, incx: *const Int, y: *const c32, incy: *const Int, a: *mut c32, lda: *const Int);
+    pub fn zgemv_(trans: *const c_char, m: *const Int, n: *const Int, alpha: *const c64, a: *const c64, lda: *const Int, x: *const c64, incx: *const Int, beta: *const c64, y: *mut c64, incy: *const Int);
+    pub fn zgbmv_(trans: *const c_char, m: *const Int, n: *const Int, kl: *const Int, ku: *const Int, alpha: *const c64, a: *const c64, lda: *const Int, x: *const c64, incx: *const Int, beta: *const c64, y: *mut c64, incy: *const Int);
+    pub fn zhemv_(uplo: *const c_char, n: *const Int, alpha: *const c64, a: *const c64, lda: *const Int, x: *const c64, incx: *const Int, beta: *const c64, y: *mut c64, incy: *const Int);
+    pub fn zhbmv_(uplo: *const c_char, n: *const Int, k: *const Int, alpha: *const c64, a: *const c64, lda: *const Int, x: *const c64, incx: *const Int, beta: *const c64, y: *mut c64, incy: *const Int);
+    pub fn zhpmv_(uplo: *const c_char, n: *const Int, alpha: *const c64, ap: *const c64, x: *const c64, incx: *const Int, beta: *const c64, y: *mut c64, incy: *const Int);
+    pub fn ztrmv_(uplo: *const c_char, transa: *const c_char, diag: *const c_char, n: *const Int, a: *const c64, lda: *const Int, b: *mut c64, incx: *const Int);
+    pub fn ztbmv_(uplo: *const c_char, trans: *const c_char, diag: *const c_char, n: *const Int, k: *const Int, a: *const c64, lda: *const Int, x: *mut c64, incx: *const Int);
+    pub fn ztpmv_(uplo: *const c_char, trans: *const c_char, diag: *const c_char, n: *const Int, ap: *const c64, x: *mut c64, incx: *const Int);
+    pub fn ztrsv_(uplo: *const c_char, trans: *const c_char, diag: *const c_char, n: *const Int, a: *const c64, lda: *const Int, x: *mut c64, incx: *const Int);
+    pub fn ztbsv_(uplo: *const c_char, trans: *const c_char, diag: *const c_char, n: *const Int, k: *const Int, a: *const c64, lda: *const Int, x: *mut c64, incx: *const Int);
+    pub fn ztpsv_(uplo: *const c_char, trans: *const c_char, diag: *const c_char, n: *const Int, ap: *const c64, x: *mut c64, incx: *const Int);
+    pub fn zgeru_(m: *const Int, n: *const Int, alpha: *const c64, x: *const c64, incx: *const Int, y: *const c64, incy: *const Int, a: *mut c64, lda: *const Int);
+    pub fn zgerc_(m: *const Int, n: *const Int, alpha: *const c64, x: *const c64, incx: *const Int, y: *const c64, incy: *const Int, a: *mut c64, lda: *const Int);
+    pub fn zher_(uplo: *const c_char, n: *const Int, alpha: *const c_double, x: *const c64, incx: *const Int, a: *mut c64, lda: *const Int);
+    pub fn zhpr_(uplo: *const c_char, n: *const Int, alpha: *const c_double, x: *const c64, incx: *const Int, ap: *mut c64);
+    pub fn zher2_(uplo: *const c_char, n: *const Int, alpha: *const c64, x: *const c64, incx: *const Int, y: *const c64, incy: *const Int, a: *mut c64, lda: *const Int);
+    pub fn zhpr2_(uplo: *const c_char, n: *const Int, alpha: *const c64, x: *const c64, incx: *const Int, y: *const c64, incy: *const Int, ap: *mut c64);
+    pub fn sgemm_(transa: *const c_char, transb: *const c_char, m: *const Int, n: *const Int, k: *const Int, alpha: *const c_float, a: *const c_float, lda: *const Int, b: *const c_float, ldb: *const Int, beta: *const c_float, c: *mut c_float, ldc: *const Int);
+    pub fn ssymm_(side: *const c_char, uplo: *const c_char, m: *const Int, n: *const Int, alpha: *const c_float, a: *const c_float, lda: *const Int, b: *const c_float, ldb: *const Int, beta: *const c_float, c: *mut c_float, ldc: *const Int);
+    pub fn ssyrk_(uplo: *const c_char, trans: *const c_char, n: *const Int, k: *const Int, alpha: *const c_float, a: *const c_float, lda: *const Int, beta: *const c_float, c: *mut c_float, ldc: *const Int);
+    pub fn ssyr2k_(uplo: *const c_char, trans: *const c_char, n: *const Int, k: *const Int, alpha: *const c_float, a: *const c_float, lda: *const Int, b: *const c_float, ldb: *const Int, beta: *const c_float, c: *mut c_float, ldc: *const Int);
+    pub fn strmm_(side: *const c_char, uplo: *const c_char, transa: *const c_char, diag: *const c_char, m: *const Int, n: *const Int, alpha: *const c_float, a: *const c_float, lda: *const Int, b: *mut c_float, ldb: *const Int);
+    pub fn strsm_(side: *const c_char, uplo: *const c_char, transa: *const c_char, diag: *const c_char, m: *const Int, n: *const Int, alpha: *const c_float, a: *const c_float, lda: *const Int, b: *mut c_float, ldb: *const Int);
+    pub fn dgemm_(transa: *const c_char, transb: *const c_char, m: *const Int, n: *const Int, k: *const Int, alpha: *const c_double, a: *const c_double, lda: *const Int, b: *const c_double, ldb: *const Int, beta: *const c_double, c: *mut c_double, ldc: *const Int);
+    pub fn dsymm_(side: *const c_char, uplo: *const c_char, m: *const Int, n: *const Int, alpha: *const c_double, a: *const c_double, lda: *const Int, b: *const c_double, ldb: *const Int, beta: *const c_double, c: *mut c_double, ldc: *const Int);
+    pub fn dsyrk_(uplo: *const c_char, trans: *const c_char, n: *const Int, k: *const Int, alpha: *const c_double, a: *const c_double, lda: *const Int, beta: *const c_double, c: *mut c_double, ldc: *const Int);
+    pub fn dsyr2k_(uplo: *const c_char, trans: *const c_char, n: *const Int, k: *const Int, alpha: *const c_double, a: *const c_double, lda: *const Int, b: *const c_double, ldb: *const Int, beta: *const c_double, c: *mut c_double, ldc: *const Int);
+    pub fn dtrmm_(side: *const c_char, uplo: *const c_char, transa: *const c_char, diag: *const c_char, m: *const Int, n: *const Int, alpha: *const c_double, a: *const c_double, lda: *const Int, b: *mut c_double, ldb: *const Int);
+    pub fn dtrsm_(side: *const c_char, uplo: *const c_char, transa: *const c_char, diag: *const c_char, m: *const Int, n: *const Int, alpha: *const c_double, a: *const c_double, lda: *const Int, b: *mut c_double, ldb: *const Int);
+    pub fn cgemm_(transa: *const c_char, transb: *const c_char, m: *const Int, n: *const Int, k: *const Int, alpha: *const c32, a: *const c32, lda: *const Int, b: *const c32, ldb: *const Int, beta: *const c32, c: *mut c32, ldc: *const Int);
+    pub fn csymm_(side: *const c_char, uplo: *const c_char, m: *const Int, n: *const Int, alpha: *const c32, a: *const c32, lda: *const Int, b: *const c32, ldb: *const Int, beta: *const c32, c: *mut c32, ldc: *const Int);
+    pub fn chemm_(side: *const c_char, uplo: *const c_char, m: *const Int, n: *const Int, alpha: *const c32, a: *const c32, lda: *const Int, b: *const c32, ldb: *const Int, beta: *const c32, c: *mut c32, ldc: *const Int);
+    pub fn csyrk_(uplo: *const c_char, trans: *const c_char, n: *const Int, k: *const Int, alpha: *const c32, a: *const c32, lda: *const Int, beta: *const c32, c: *mut c32, ldc: *const Int);
+    pub fn cherk_(uplo: *const c_char, trans: *const c_char, n: *const Int, k: *const Int, alpha: *const c_float, a: *const c32, lda: *const Int, beta: *const c_float, c: *mut c32, ldc: *const Int);
+    pub fn csyr2k_(uplo: *const c_char, trans: *const c_char, n: *const Int, k: *const Int, alpha: *const c32, a: *const c32, lda: *const Int, b: *const c32, ldb: *const Int, beta: *const c32, c: *mut c32, ldc: *const Int);
+    pub fn cher2k_(uplo: *const c_char, trans: *const c_char, n: *const Int, k: *const Int, alpha: *const c32, a: *const c32, lda: *const Int, b: *const c32, ldb: *const Int, beta: *const c_float, c: *mut c32, ldc: *const Int);
+    pub fn ctrmm_(side: *const c_char, uplo: *const c_char, transa: *const c_char, diag: *const c_char, m: *const Int, n: *const Int, alpha: *const c32, a: *const c32, lda: *const Int, b: *mut c32, ldb: *const Int);
+    pub fn ctrsm_(side: *const c_char, uplo: *const c_char, transa: *const c_char, diag: *const c_char, m: *const Int, n: *const Int, alpha: *const c32, a: *const c32, lda: *const Int, b: *mut c32, ldb: *const Int);
+    pub fn zgemm_(transa: *const c_char, transb: *const c_char, m: *const Int, n: *const Int, k: *const Int, alpha: *const c64, a: *const c64, lda: *const Int, b: *const c64, ldb: *const Int, beta: *const c64, c: *mut c64, ldc: *const Int);
+    pub fn zsymm_(side: *const c_char, uplo: *const c_char, m: *const Int, n: *const Int, alpha: *const c64, a: *const c64, lda: *const Int, b: *const c64, ldb: *const Int, beta: *const c64, c: *mut c64, ldc: *const Int);
+    pub fn zhemm_(side: *const c_char, uplo: *const c_char, m: *const Int, n: *const Int, alpha: *const c64, a: *const c64, lda: *const Int, b: *const c64, ldb: *const Int, beta: *const c64, c: *mut c64, ldc: *const Int);
+    pub fn zsyrk_(uplo: *const c_char, trans: *const c_char, n: *const Int, k: *const Int, alpha: *const c64, a: *const c64, lda: *const Int, beta: *const c64, c: *mut c64, ldc: *const Int);
+    pub fn zherk_(uplo: *const c_char, trans: *const c_char, n: *const Int, k: *const Int, alpha: *const c_double, a: *const c64, lda: *const Int, beta: *const c_double, c: *mut c64, ldc: *const Int);
+    pub fn zsyr2k_(uplo: *const c_char, trans: *const c_char, n: *const Int, k: *const Int, alpha: *const c64, a: *const c64, lda: *const Int, b: *const c64, ldb: *const Int, beta: *const c64, c: *mut c64, ldc: *const Int);
+    pub fn zher2k_(uplo: *const c_char, trans: *const c_char, n: *const Int, k: *const Int, alpha: *const c64, a: *const c64, lda: *const Int, b: *const c64, ldb: *const Int, beta: *const c_double, c: *mut c64, ldc: *const Int);
+    pub fn ztrmm_(side: *const c_char, uplo: *const c_char, transa: *const c_char, diag: *const c_char, m: *const Int, n: *const Int, alpha: *const c64, a: *const c64, lda: *const Int, b: *mut c64, ldb: *const Int);
+    pub fn ztrsm_(side: *const c_char, uplo: *const c_char, transa: *const c_char, diag: *const c_char, m: *const Int, n: *const Int, alpha: *const c64, a: *const c64, lda: *const Int, b: *mut c64, ldb: *const Int);
+}
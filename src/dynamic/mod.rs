@@ -0,0 +1,191 @@
+//! Loading of the backend at runtime.
+//!
+//! With the `dynamic` feature, the wrappers resolve their symbols from a shared library opened
+//! when the program runs instead of the one linked via `blas-sys`. The library is given either by
+//! calling [`load`] or [`load_first`] before the first wrapper is called or by the environment
+//! variable [`VARIABLE`], which holds one or several paths separated as in `PATH`, the first of
+//! which that can be opened being used. With the `ilp64` feature, the symbols are suffixed with
+//! `64_`. A wrapper whose symbol is missing from the library panics naming the symbol, and
+//! [`missing_symbols`] lists such symbols beforehand.
+//!
+//! The `reference` feature takes precedence, in which case the library is never used.
+
+use std::error;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+#[cfg_attr(feature = "reference", allow(dead_code))]
+pub(crate) mod ffi;
+
+/// The environment variable read when no library has been loaded explicitly.
+pub const VARIABLE: &str = "BLAS_LIBRARY";
+
+#[cfg(not(feature = "ilp64"))]
+const SUFFIX: &str = "";
+#[cfg(feature = "ilp64")]
+const SUFFIX: &str = "64_";
+
+static LIBRARY: OnceLock<Library> = OnceLock::new();
+
+/// An error of loading a library.
+#[derive(Debug)]
+pub enum Error {
+    /// A library has already been loaded.
+    AlreadyLoaded,
+    /// No library is given.
+    NotConfigured,
+    /// A library cannot be opened.
+    Open { path: PathBuf, message: String },
+}
+
+struct Library {
+    path: PathBuf,
+    functions: ffi::Functions,
+    library: libloading::Library,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::AlreadyLoaded => write!(formatter, "a BLAS library has already been loaded"),
+            Error::NotConfigured => write!(
+                formatter,
+                "no BLAS library is loaded and {} is not set",
+                VARIABLE,
+            ),
+            Error::Open {
+                ref path,
+                ref message,
+            } => write!(
+                formatter,
+                "cannot open the BLAS library {}: {}",
+                path.display(),
+                message,
+            ),
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+impl Library {
+    fn open(path: &Path) -> Result<Self, Error> {
+        let library = unsafe { libloading::Library::new(path) }.map_err(|error| Error::Open {
+            path: path.to_owned(),
+            message: error.to_string(),
+        })?;
+        Ok(Library {
+            path: path.to_owned(),
+            functions: unsafe { ffi::Functions::resolve(&library) },
+            library,
+        })
+    }
+}
+
+/// Load the library at `path`.
+///
+/// Loading happens at most once per process, and the library stays loaded until the process
+/// exits.
+pub fn load<P: AsRef<Path>>(path: P) -> Result<(), Error> {
+    load_first(Some(path))
+}
+
+/// Load the first library in `paths` that can be opened.
+///
+/// The error of the last path is returned if none can be opened, and `NotConfigured` is
+/// returned if there is none.
+pub fn load_first<I, P>(paths: I) -> Result<(), Error>
+where
+    I: IntoIterator<Item = P>,
+    P: AsRef<Path>,
+{
+    if LIBRARY.get().is_some() {
+        return Err(Error::AlreadyLoaded);
+    }
+    let library = open_first(paths)?;
+    LIBRARY.set(library).map_err(|_| Error::AlreadyLoaded)
+}
+
+/// Return the path of the loaded library.
+pub fn path() -> Option<&'static Path> {
+    LIBRARY.get().map(|library| library.path.as_path())
+}
+
+/// Return the symbols missing from the library, loading it from [`VARIABLE`] if necessary.
+pub fn missing_symbols() -> Result<Vec<String>, Error> {
+    Ok(library()?.functions.missing())
+}
+
+fn open_first<I, P>(paths: I) -> Result<Library, Error>
+where
+    I: IntoIterator<Item = P>,
+    P: AsRef<Path>,
+{
+    let mut result = Err(Error::NotConfigured);
+    for path in paths {
+        result = Library::open(path.as_ref());
+        if result.is_ok() {
+            break;
+        }
+    }
+    result
+}
+
+fn library() -> Result<&'static Library, Error> {
+    if let Some(library) = LIBRARY.get() {
+        return Ok(library);
+    }
+    let value = std::env::var_os(VARIABLE).ok_or(Error::NotConfigured)?;
+    let paths = std::env::split_paths(&value).filter(|path| !path.as_os_str().is_empty());
+    let library = open_first(paths)?;
+    Ok(LIBRARY.get_or_init(|| library))
+}
+
+/// Return the address of a symbol, loading the library from [`VARIABLE`] if necessary.
+#[cfg_attr(feature = "reference", allow(dead_code))]
+pub(crate) fn lookup(symbol: &str) -> Option<*mut core::ffi::c_void> {
+    let library = library().ok()?;
+    let address = unsafe {
+        library
+            .library
+            .get::<*mut core::ffi::c_void>(symbol.as_bytes())
+    };
+    address.ok().map(|address| *address)
+}
+
+/// Return the name of the symbol of a function.
+fn symbol(name: &str) -> String {
+    format!("{}{}", name, SUFFIX)
+}
+
+/// Resolve a function, which has type `F`.
+unsafe fn resolve<F: Copy>(library: &libloading::Library, name: &str) -> Option<F> {
+    library
+        .get::<F>(symbol(name).as_bytes())
+        .ok()
+        .map(|function| *function)
+}
+
+/// Return the library, panicking if it cannot be loaded.
+#[inline]
+fn loaded() -> &'static Library {
+    match library() {
+        Ok(library) => library,
+        Err(error) => failed(error),
+    }
+}
+
+#[cold]
+fn failed(error: Error) -> ! {
+    panic!("{}", error)
+}
+
+#[cold]
+fn missing(library: &Library, name: &str) -> ! {
+    panic!(
+        "the symbol {} is missing from the BLAS library {}",
+        symbol(name),
+        library.path.display(),
+    )
+}
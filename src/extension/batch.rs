@@ -0,0 +1,271 @@
+//! Batched matrix multiplication.
+//!
+//! The routines are taken from the backend with the `extension-gemm-batch` feature, which
+//! requires the interface of Intel MKL. Otherwise, they call the wrappers of `gemm` for each
+//! matrix, in parallel with the `parallel` feature.
+
+use crate::{c32, c64, Int};
+
+#[cfg(any(not(feature = "extension-gemm-batch"), feature = "reference"))]
+use self::fallback::*;
+
+macro_rules! implement {
+    ($($name:ident: $ffi:ident, $strided:ident: $strided_ffi:ident, $gemm:ident -> $scalar:ty,)*) => ($(
+        #[cfg(all(feature = "extension-gemm-batch", not(feature = "reference")))]
+        extern "C" {
+            #[cfg_attr(feature = "ilp64", link_name = concat!(stringify!($ffi), "64_"))]
+            fn $ffi(
+                transa: *const core::ffi::c_char,
+                transb: *const core::ffi::c_char,
+                m: *const Int,
+                n: *const Int,
+                k: *const Int,
+                alpha: *const $scalar,
+                a: *const *const $scalar,
+                lda: *const Int,
+                b: *const *const $scalar,
+                ldb: *const Int,
+                beta: *const $scalar,
+                c: *const *mut $scalar,
+                ldc: *const Int,
+                group_count: *const Int,
+                group_size: *const Int,
+            );
+
+            #[cfg_attr(feature = "ilp64", link_name = concat!(stringify!($strided_ffi), "64_"))]
+            fn $strided_ffi(
+                transa: *const core::ffi::c_char,
+                transb: *const core::ffi::c_char,
+                m: *const Int,
+                n: *const Int,
+                k: *const Int,
+                alpha: *const $scalar,
+                a: *const $scalar,
+                lda: *const Int,
+                stridea: *const Int,
+                b: *const $scalar,
+                ldb: *const Int,
+                strideb: *const Int,
+                beta: *const $scalar,
+                c: *mut $scalar,
+                ldc: *const Int,
+                stridec: *const Int,
+                batch_size: *const Int,
+            );
+        }
+
+        /// Compute `C := alpha * op(A) * op(B) + beta * C` for groups of matrices.
+        ///
+        /// The matrices are given by pointers and are divided into consecutive groups whose
+        /// sizes are given by `group_size`. The other arguments give one value per group. The
+        /// matrices `C` must not overlap.
+        #[inline]
+        pub unsafe fn $name(
+            transa: &[u8],
+            transb: &[u8],
+            m: &[Int],
+            n: &[Int],
+            k: &[Int],
+            alpha: &[$scalar],
+            a: &[*const $scalar],
+            lda: &[Int],
+            b: &[*const $scalar],
+            ldb: &[Int],
+            beta: &[$scalar],
+            c: &[*mut $scalar],
+            ldc: &[Int],
+            group_size: &[Int],
+        ) {
+            #[cfg(all(feature = "extension-gemm-batch", not(feature = "reference")))]
+            $ffi(
+                transa.as_ptr() as *const _,
+                transb.as_ptr() as *const _,
+                m.as_ptr(),
+                n.as_ptr(),
+                k.as_ptr(),
+                alpha.as_ptr(),
+                a.as_ptr(),
+                lda.as_ptr(),
+                b.as_ptr(),
+                ldb.as_ptr(),
+                beta.as_ptr(),
+                c.as_ptr(),
+                ldc.as_ptr(),
+                &(group_size.len() as Int),
+                group_size.as_ptr(),
+            );
+            #[cfg(any(not(feature = "extension-gemm-batch"), feature = "reference"))]
+            {
+                let mut offset = 0;
+                for (g, &size) in group_size.iter().enumerate() {
+                    let size = size.max(0) as usize;
+                    let (ta, tb) = (transa[g], transb[g]);
+                    let (m, n, k) = (m[g], n[g], k[g]);
+                    let (lda, ldb, ldc) = (lda[g], ldb[g], ldc[g]);
+                    let (lena, lenb) = operand_lengths(ta, tb, m, n, k, lda, ldb);
+                    let lenc = matrix_len(m, n, ldc);
+                    let (alpha, beta) = (alpha[g], beta[g]);
+                    let range = offset..offset + size;
+                    let pointers = Shared((&a[range.clone()], &b[range.clone()], &c[range]));
+                    for_each(size, |i| {
+                        let (a, b, c) = pointers.get();
+                        let (a, b) = (slice(a[i], lena), slice(b[i], lenb));
+                        let c = slice_mut(c[i], lenc);
+                        crate::$gemm(ta, tb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc);
+                    });
+                    offset += size;
+                }
+            }
+        }
+
+        /// Compute `C := alpha * op(A) * op(B) + beta * C` for `batch_size` matrices that are
+        /// `stridea`, `strideb`, and `stridec` elements apart.
+        ///
+        /// The strides are nonnegative, and the matrices `C` must not overlap. Each slice must
+        /// span `(batch_size - 1) * stride` elements followed by one more matrix, which the
+        /// fallback assumes without checking.
+        #[inline]
+        pub unsafe fn $strided(
+            transa: u8,
+            transb: u8,
+            m: Int,
+            n: Int,
+            k: Int,
+            alpha: $scalar,
+            a: &[$scalar],
+            lda: Int,
+            stridea: Int,
+            b: &[$scalar],
+            ldb: Int,
+            strideb: Int,
+            beta: $scalar,
+            c: &mut [$scalar],
+            ldc: Int,
+            stridec: Int,
+            batch_size: Int,
+        ) {
+            #[cfg(all(feature = "extension-gemm-batch", not(feature = "reference")))]
+            $strided_ffi(
+                &(transa as core::ffi::c_char),
+                &(transb as core::ffi::c_char),
+                &m,
+                &n,
+                &k,
+                &alpha,
+                a.as_ptr(),
+                &lda,
+                &stridea,
+                b.as_ptr(),
+                &ldb,
+                &strideb,
+                &beta,
+                c.as_mut_ptr(),
+                &ldc,
+                &stridec,
+                &batch_size,
+            );
+            #[cfg(any(not(feature = "extension-gemm-batch"), feature = "reference"))]
+            {
+                let batch_size = batch_size.max(0) as usize;
+                let (stridea, strideb) = (stridea as usize, strideb as usize);
+                let (stridec, lenc) = (stridec as usize, matrix_len(m, n, ldc));
+                let c = Shared(c.as_mut_ptr());
+                for_each(batch_size, |i| {
+                    let (a, b) = (tail(a, i * stridea), tail(b, i * strideb));
+                    let c = slice_mut(c.get().add(i * stridec), lenc);
+                    crate::$gemm(transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc);
+                });
+            }
+        }
+    )*);
+}
+
+implement! {
+    sgemm_batch: sgemm_batch_, sgemm_batch_strided: sgemm_batch_strided_, sgemm -> f32,
+    dgemm_batch: dgemm_batch_, dgemm_batch_strided: dgemm_batch_strided_, dgemm -> f64,
+    cgemm_batch: cgemm_batch_, cgemm_batch_strided: cgemm_batch_strided_, cgemm -> c32,
+    zgemm_batch: zgemm_batch_, zgemm_batch_strided: zgemm_batch_strided_, zgemm -> c64,
+}
+
+/// Helpers of the loops over the matrices of a batch.
+#[cfg(any(not(feature = "extension-gemm-batch"), feature = "reference"))]
+mod fallback {
+    pub(super) use crate::safe::matrix_len;
+
+    use crate::Int;
+
+    /// Pointers shared between the threads processing a batch, which access disjoint matrices.
+    #[derive(Clone, Copy)]
+    pub struct Shared<T>(pub T);
+
+    unsafe impl<T> Send for Shared<T> {}
+    unsafe impl<T> Sync for Shared<T> {}
+
+    impl<T: Copy> Shared<T> {
+        #[inline]
+        pub fn get(&self) -> T {
+            self.0
+        }
+    }
+
+    /// Call `f` for each matrix of a batch.
+    #[cfg(not(feature = "parallel"))]
+    #[inline]
+    pub fn for_each(size: usize, f: impl Fn(usize)) {
+        (0..size).for_each(f)
+    }
+
+    /// Call `f` for each matrix of a batch.
+    #[cfg(feature = "parallel")]
+    #[inline]
+    pub fn for_each(size: usize, f: impl Fn(usize) + Send + Sync) {
+        use rayon::prelude::*;
+        (0..size).into_par_iter().for_each(f)
+    }
+
+    /// Return the numbers of elements spanned by `A` and `B`.
+    pub fn operand_lengths(
+        transa: u8,
+        transb: u8,
+        m: Int,
+        n: Int,
+        k: Int,
+        lda: Int,
+        ldb: Int,
+    ) -> (usize, usize) {
+        let (rowsa, colsa) = if transa.eq_ignore_ascii_case(&b'N') {
+            (m, k)
+        } else {
+            (k, m)
+        };
+        let (rowsb, colsb) = if transb.eq_ignore_ascii_case(&b'N') {
+            (k, n)
+        } else {
+            (n, k)
+        };
+        (matrix_len(rowsa, colsa, lda), matrix_len(rowsb, colsb, ldb))
+    }
+
+    #[inline]
+    pub fn tail<T>(data: &[T], offset: usize) -> &[T] {
+        data.get(offset..).unwrap_or(&[])
+    }
+
+    #[inline]
+    pub unsafe fn slice<'a, T>(data: *const T, len: usize) -> &'a [T] {
+        if len == 0 {
+            &[]
+        } else {
+            core::slice::from_raw_parts(data, len)
+        }
+    }
+
+    #[inline]
+    pub unsafe fn slice_mut<'a, T>(data: *mut T, len: usize) -> &'a mut [T] {
+        if len == 0 {
+            &mut []
+        } else {
+            core::slice::from_raw_parts_mut(data, len)
+        }
+    }
+}
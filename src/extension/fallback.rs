@@ -0,0 +1,286 @@
+//! Portable implementations of the extensions, which follow OpenBLAS in their argument checks
+//! and panic with the message that `XERBLA` would print unless the error is caught by
+//! `catch_blas_errors`.
+
+#[cfg(all(
+    feature = "alloc",
+    any(not(feature = "extension-matcopy"), feature = "reference"),
+))]
+use alloc::vec::Vec;
+
+#[cfg(any(not(feature = "extension-axpby"), feature = "reference"))]
+use crate::util::start;
+#[cfg(any(
+    not(all(feature = "extension-gemmt", feature = "extension-matcopy")),
+    feature = "reference",
+))]
+use crate::util::xerbla;
+use crate::{Int, Scalar};
+
+#[cfg(any(
+    not(all(feature = "extension-gemmt", feature = "extension-matcopy")),
+    feature = "reference",
+))]
+#[inline]
+fn is(flag: u8, accepted: &[u8]) -> bool {
+    accepted.contains(&flag.to_ascii_uppercase())
+}
+
+#[cfg(any(
+    not(all(feature = "extension-gemmt", feature = "extension-matcopy")),
+    feature = "reference",
+))]
+#[inline]
+fn conjugate<T: Scalar>(value: T, conj: bool) -> T {
+    if conj {
+        value.conj()
+    } else {
+        value
+    }
+}
+
+/// Return `alpha * value`, which is zero without reading `value` when `alpha` is zero.
+#[inline]
+fn scaled<T: Scalar>(alpha: T, value: impl FnOnce() -> T) -> T {
+    if alpha == T::zero() {
+        T::zero()
+    } else {
+        alpha * value()
+    }
+}
+
+#[cfg(any(not(feature = "extension-axpby"), feature = "reference"))]
+pub fn axpby<T: Scalar>(n: Int, alpha: T, x: &[T], incx: Int, beta: T, y: &mut [T], incy: Int) {
+    if n <= 0 {
+        return;
+    }
+    let (mut ix, mut iy) = (start(n, incx), start(n, incy));
+    for _ in 0..n {
+        let mut value = scaled(alpha, || x[ix as usize]);
+        if beta != T::zero() {
+            value = value + beta * y[iy as usize];
+        }
+        y[iy as usize] = value;
+        ix += incx as isize;
+        iy += incy as isize;
+    }
+}
+
+#[cfg(any(not(feature = "extension-gemmt"), feature = "reference"))]
+pub fn gemmt<T: Scalar>(
+    name: &str,
+    uplo: u8,
+    transa: u8,
+    transb: u8,
+    n: Int,
+    k: Int,
+    alpha: T,
+    a: &[T],
+    lda: Int,
+    b: &[T],
+    ldb: Int,
+    beta: T,
+    c: &mut [T],
+    ldc: Int,
+) {
+    let nota = transa.eq_ignore_ascii_case(&b'N');
+    let notb = transb.eq_ignore_ascii_case(&b'N');
+    let nrowa = if nota { n } else { k };
+    let nrowb = if notb { k } else { n };
+    let info = if !is(uplo, b"UL") {
+        1
+    } else if !is(transa, b"NTC") {
+        2
+    } else if !is(transb, b"NTC") {
+        3
+    } else if n < 0 {
+        4
+    } else if k < 0 {
+        5
+    } else if lda < nrowa.max(1) {
+        8
+    } else if ldb < nrowb.max(1) {
+        10
+    } else if ldc < n.max(1) {
+        13
+    } else {
+        0
+    };
+    if info != 0 {
+        return xerbla(name, info);
+    }
+    let upper = uplo.eq_ignore_ascii_case(&b'U');
+    let (conja, conjb) = (
+        transa.eq_ignore_ascii_case(&b'C'),
+        transb.eq_ignore_ascii_case(&b'C'),
+    );
+    let (n, k) = (n as usize, k as usize);
+    let (lda, ldb, ldc) = (lda as usize, ldb as usize, ldc as usize);
+    let op_a = |i: usize, l: usize| {
+        if nota {
+            a[i + l * lda]
+        } else {
+            conjugate(a[l + i * lda], conja)
+        }
+    };
+    let op_b = |l: usize, j: usize| {
+        if notb {
+            b[l + j * ldb]
+        } else {
+            conjugate(b[j + l * ldb], conjb)
+        }
+    };
+    for j in 0..n {
+        let rows = if upper { 0..j + 1 } else { j..n };
+        for i in rows {
+            let mut value = scaled(alpha, || {
+                (0..k).fold(T::zero(), |sum, l| sum + op_a(i, l) * op_b(l, j))
+            });
+            if beta != T::zero() {
+                value = value + beta * c[i + j * ldc];
+            }
+            c[i + j * ldc] = value;
+        }
+    }
+}
+
+#[cfg(any(not(feature = "extension-matcopy"), feature = "reference"))]
+/// The layout of a copy, normalized to column-major storage.
+struct Copy {
+    rows: usize,
+    cols: usize,
+    lda: usize,
+    ldb: usize,
+    trans: bool,
+    conj: bool,
+}
+
+#[cfg(any(not(feature = "extension-matcopy"), feature = "reference"))]
+/// Check the arguments of `imatcopy` and `omatcopy`, whose output leading dimension is
+/// parameter `ldb_param`.
+fn check_copy(
+    name: &str,
+    order: u8,
+    trans: u8,
+    rows: Int,
+    cols: Int,
+    lda: Int,
+    ldb: Int,
+    ldb_param: Int,
+) -> Option<Copy> {
+    let row_major = order.eq_ignore_ascii_case(&b'R');
+    let (rows, cols) = if row_major {
+        (cols, rows)
+    } else {
+        (rows, cols)
+    };
+    let transposed = is(trans, b"TC");
+    let rowsb = if transposed { cols } else { rows };
+    let info = if !is(order, b"CR") {
+        1
+    } else if !is(trans, b"NTCR") {
+        2
+    } else if rows < 0 {
+        if row_major {
+            4
+        } else {
+            3
+        }
+    } else if cols < 0 {
+        if row_major {
+            3
+        } else {
+            4
+        }
+    } else if lda < rows.max(1) {
+        7
+    } else if ldb < rowsb.max(1) {
+        ldb_param
+    } else {
+        0
+    };
+    if info != 0 {
+        xerbla(name, info);
+        return None;
+    }
+    if rows == 0 || cols == 0 {
+        return None;
+    }
+    Some(Copy {
+        rows: rows as usize,
+        cols: cols as usize,
+        lda: lda as usize,
+        ldb: ldb as usize,
+        trans: transposed,
+        conj: is(trans, b"CR"),
+    })
+}
+
+#[cfg(any(not(feature = "extension-matcopy"), feature = "reference"))]
+impl Copy {
+    /// Return the position of element `(i, j)` of `A` in `B`.
+    #[inline]
+    fn target(&self, i: usize, j: usize) -> usize {
+        if self.trans {
+            j + i * self.ldb
+        } else {
+            i + j * self.ldb
+        }
+    }
+}
+
+#[cfg(all(
+    feature = "alloc",
+    any(not(feature = "extension-matcopy"), feature = "reference"),
+))]
+pub fn imatcopy<T: Scalar>(
+    name: &str,
+    order: u8,
+    trans: u8,
+    rows: Int,
+    cols: Int,
+    alpha: T,
+    ab: &mut [T],
+    lda: Int,
+    ldb: Int,
+) {
+    let copy = match check_copy(name, order, trans, rows, cols, lda, ldb, 8) {
+        Some(copy) => copy,
+        None => return,
+    };
+    let mut values = Vec::with_capacity(copy.rows * copy.cols);
+    for j in 0..copy.cols {
+        for i in 0..copy.rows {
+            values.push(scaled(alpha, || conjugate(ab[i + j * copy.lda], copy.conj)));
+        }
+    }
+    for j in 0..copy.cols {
+        for i in 0..copy.rows {
+            ab[copy.target(i, j)] = values[i + j * copy.rows];
+        }
+    }
+}
+
+#[cfg(any(not(feature = "extension-matcopy"), feature = "reference"))]
+pub fn omatcopy<T: Scalar>(
+    name: &str,
+    order: u8,
+    trans: u8,
+    rows: Int,
+    cols: Int,
+    alpha: T,
+    a: &[T],
+    lda: Int,
+    b: &mut [T],
+    ldb: Int,
+) {
+    let copy = match check_copy(name, order, trans, rows, cols, lda, ldb, 9) {
+        Some(copy) => copy,
+        None => return,
+    };
+    for j in 0..copy.cols {
+        for i in 0..copy.rows {
+            b[copy.target(i, j)] = scaled(alpha, || conjugate(a[i + j * copy.lda], copy.conj));
+        }
+    }
+}
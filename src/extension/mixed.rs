@@ -0,0 +1,215 @@
+//! Matrix multiplication with half-precision inputs and single-precision outputs.
+//!
+//! The routines are taken from the backend with the `extension-sbgemm` and `extension-shgemm`
+//! features, which require OpenBLAS, and with the `extension-gemm-bf16` feature, which requires
+//! Intel MKL. Otherwise, they convert `A` and `B` to single precision and call `sgemm`, which
+//! requires the `alloc` feature.
+
+pub use half::{bf16, f16};
+
+#[cfg(all(
+    feature = "alloc",
+    any(
+        not(all(
+            feature = "extension-gemm-bf16",
+            feature = "extension-sbgemm",
+            feature = "extension-shgemm",
+        )),
+        feature = "reference",
+    ),
+))]
+use alloc::vec::Vec;
+
+#[cfg_attr(not(feature = "alloc"), allow(unused_imports))]
+use crate::Int;
+
+macro_rules! implement {
+    ($($name:ident: $ffi:ident, $feature:literal -> $scalar:ty,)*) => ($(
+        #[cfg(all(feature = $feature, not(feature = "reference")))]
+        extern "C" {
+            #[cfg_attr(feature = "ilp64", link_name = concat!(stringify!($ffi), "64_"))]
+            fn $ffi(
+                transa: *const core::ffi::c_char,
+                transb: *const core::ffi::c_char,
+                m: *const Int,
+                n: *const Int,
+                k: *const Int,
+                alpha: *const f32,
+                a: *const $scalar,
+                lda: *const Int,
+                b: *const $scalar,
+                ldb: *const Int,
+                beta: *const f32,
+                c: *mut f32,
+                ldc: *const Int,
+            );
+        }
+
+        /// Compute `C := alpha * op(A) * op(B) + beta * C`, where `A` and `B` have half
+        /// precision.
+        #[cfg(any(feature = "alloc", all(feature = $feature, not(feature = "reference"))))]
+        #[inline]
+        pub unsafe fn $name(
+            transa: u8,
+            transb: u8,
+            m: Int,
+            n: Int,
+            k: Int,
+            alpha: f32,
+            a: &[$scalar],
+            lda: Int,
+            b: &[$scalar],
+            ldb: Int,
+            beta: f32,
+            c: &mut [f32],
+            ldc: Int,
+        ) {
+            #[cfg(all(feature = $feature, not(feature = "reference")))]
+            $ffi(
+                &(transa as core::ffi::c_char),
+                &(transb as core::ffi::c_char),
+                &m,
+                &n,
+                &k,
+                &alpha,
+                a.as_ptr(),
+                &lda,
+                b.as_ptr(),
+                &ldb,
+                &beta,
+                c.as_mut_ptr(),
+                &ldc,
+            );
+            #[cfg(any(not(feature = $feature), feature = "reference"))]
+            fallback(transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc);
+        }
+    )*);
+}
+
+implement! {
+    sbgemm: sbgemm_, "extension-sbgemm" -> bf16,
+    shgemm: shgemm_, "extension-shgemm" -> f16,
+}
+
+#[cfg(all(feature = "extension-gemm-bf16", not(feature = "reference")))]
+const CBLAS_COL_MAJOR: core::ffi::c_int = 102;
+#[cfg(all(feature = "extension-gemm-bf16", not(feature = "reference")))]
+const CBLAS_NO_TRANS: core::ffi::c_int = 111;
+#[cfg(all(feature = "extension-gemm-bf16", not(feature = "reference")))]
+const CBLAS_TRANS: core::ffi::c_int = 112;
+#[cfg(all(feature = "extension-gemm-bf16", not(feature = "reference")))]
+const CBLAS_CONJ_TRANS: core::ffi::c_int = 113;
+
+#[cfg(all(feature = "extension-gemm-bf16", not(feature = "reference")))]
+extern "C" {
+    #[cfg_attr(feature = "ilp64", link_name = "cblas_gemm_bf16bf16f32_64")]
+    fn cblas_gemm_bf16bf16f32(
+        layout: core::ffi::c_int,
+        transa: core::ffi::c_int,
+        transb: core::ffi::c_int,
+        m: Int,
+        n: Int,
+        k: Int,
+        alpha: f32,
+        a: *const bf16,
+        lda: Int,
+        b: *const bf16,
+        ldb: Int,
+        beta: f32,
+        c: *mut f32,
+        ldc: Int,
+    );
+}
+
+/// Compute `C := alpha * op(A) * op(B) + beta * C`, where `A` and `B` have half precision.
+///
+/// The matrices are stored in column-major order as for `sbgemm`, and the routine is taken from
+/// `cblas_gemm_bf16bf16f32` of Intel MKL.
+#[cfg(any(
+    feature = "alloc",
+    all(feature = "extension-gemm-bf16", not(feature = "reference")),
+))]
+#[inline]
+pub unsafe fn gemm_bf16bf16f32(
+    transa: u8,
+    transb: u8,
+    m: Int,
+    n: Int,
+    k: Int,
+    alpha: f32,
+    a: &[bf16],
+    lda: Int,
+    b: &[bf16],
+    ldb: Int,
+    beta: f32,
+    c: &mut [f32],
+    ldc: Int,
+) {
+    #[cfg(all(feature = "extension-gemm-bf16", not(feature = "reference")))]
+    cblas_gemm_bf16bf16f32(
+        CBLAS_COL_MAJOR,
+        cblas_transpose(transa),
+        cblas_transpose(transb),
+        m,
+        n,
+        k,
+        alpha,
+        a.as_ptr(),
+        lda,
+        b.as_ptr(),
+        ldb,
+        beta,
+        c.as_mut_ptr(),
+        ldc,
+    );
+    #[cfg(any(not(feature = "extension-gemm-bf16"), feature = "reference"))]
+    fallback(transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc);
+}
+
+/// Translate a transpose flag into the value of `CBLAS_TRANSPOSE`, where an invalid flag is
+/// reported by the backend.
+#[cfg(all(feature = "extension-gemm-bf16", not(feature = "reference")))]
+#[inline]
+fn cblas_transpose(trans: u8) -> core::ffi::c_int {
+    match trans.to_ascii_uppercase() {
+        b'N' => CBLAS_NO_TRANS,
+        b'T' => CBLAS_TRANS,
+        b'C' => CBLAS_CONJ_TRANS,
+        _ => 0,
+    }
+}
+
+#[cfg(all(
+    feature = "alloc",
+    any(
+        not(all(
+            feature = "extension-gemm-bf16",
+            feature = "extension-sbgemm",
+            feature = "extension-shgemm",
+        )),
+        feature = "reference",
+    ),
+))]
+unsafe fn fallback<T: Copy>(
+    transa: u8,
+    transb: u8,
+    m: Int,
+    n: Int,
+    k: Int,
+    alpha: f32,
+    a: &[T],
+    lda: Int,
+    b: &[T],
+    ldb: Int,
+    beta: f32,
+    c: &mut [f32],
+    ldc: Int,
+) where
+    f32: From<T>,
+{
+    let a: Vec<f32> = a.iter().map(|&value| value.into()).collect();
+    let b: Vec<f32> = b.iter().map(|&value| value.into()).collect();
+    crate::sgemm(
+        transa, transb, m, n, k, alpha, &a, lda, &b, ldb, beta, c, ldc,
+    );
+}
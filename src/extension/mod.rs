@@ -0,0 +1,251 @@
+//! Extensions to BLAS.
+//!
+//! The routines are not part of the reference BLAS but are exported by OpenBLAS, Intel MKL, and
+//! BLIS. Each family is taken from the backend when the corresponding feature is enabled
+//! (`extension-axpby`, `extension-gemmt`, or `extension-matcopy`) and is computed by a portable
+//! fallback otherwise, which is also the case with the `reference` feature. The fallback of
+//! `imatcopy` requires the `alloc` feature.
+
+use crate::{c32, c64, Int};
+
+mod batch;
+#[cfg(any(
+    not(all(
+        feature = "extension-axpby",
+        feature = "extension-gemmt",
+        feature = "extension-matcopy",
+    )),
+    feature = "reference",
+))]
+mod fallback;
+#[cfg(feature = "half")]
+mod mixed;
+
+pub use batch::*;
+#[cfg(feature = "half")]
+pub use mixed::*;
+
+macro_rules! implement_axpby {
+    ($($name:ident: $ffi:ident -> $scalar:ty,)*) => ($(
+        #[cfg(all(feature = "extension-axpby", not(feature = "reference")))]
+        extern "C" {
+            #[cfg_attr(feature = "ilp64", link_name = concat!(stringify!($ffi), "64_"))]
+            fn $ffi(
+                n: *const Int,
+                alpha: *const $scalar,
+                x: *const $scalar,
+                incx: *const Int,
+                beta: *const $scalar,
+                y: *mut $scalar,
+                incy: *const Int,
+            );
+        }
+
+        /// Compute `y := alpha * x + beta * y`.
+        #[inline]
+        pub unsafe fn $name(
+            n: Int,
+            alpha: $scalar,
+            x: &[$scalar],
+            incx: Int,
+            beta: $scalar,
+            y: &mut [$scalar],
+            incy: Int,
+        ) {
+            #[cfg(all(feature = "extension-axpby", not(feature = "reference")))]
+            $ffi(&n, &alpha, x.as_ptr(), &incx, &beta, y.as_mut_ptr(), &incy);
+            #[cfg(any(not(feature = "extension-axpby"), feature = "reference"))]
+            fallback::axpby(n, alpha, x, incx, beta, y, incy);
+        }
+    )*);
+}
+
+macro_rules! implement_gemmt {
+    ($($name:ident: $ffi:ident -> $scalar:ty,)*) => ($(
+        #[cfg(all(feature = "extension-gemmt", not(feature = "reference")))]
+        extern "C" {
+            #[cfg_attr(feature = "ilp64", link_name = concat!(stringify!($ffi), "64_"))]
+            fn $ffi(
+                uplo: *const core::ffi::c_char,
+                transa: *const core::ffi::c_char,
+                transb: *const core::ffi::c_char,
+                n: *const Int,
+                k: *const Int,
+                alpha: *const $scalar,
+                a: *const $scalar,
+                lda: *const Int,
+                b: *const $scalar,
+                ldb: *const Int,
+                beta: *const $scalar,
+                c: *mut $scalar,
+                ldc: *const Int,
+            );
+        }
+
+        /// Compute `C := alpha * op(A) * op(B) + beta * C`, updating only the triangle of `C`
+        /// given by `uplo`.
+        #[inline]
+        pub unsafe fn $name(
+            uplo: u8,
+            transa: u8,
+            transb: u8,
+            n: Int,
+            k: Int,
+            alpha: $scalar,
+            a: &[$scalar],
+            lda: Int,
+            b: &[$scalar],
+            ldb: Int,
+            beta: $scalar,
+            c: &mut [$scalar],
+            ldc: Int,
+        ) {
+            #[cfg(all(feature = "extension-gemmt", not(feature = "reference")))]
+            $ffi(
+                &(uplo as core::ffi::c_char),
+                &(transa as core::ffi::c_char),
+                &(transb as core::ffi::c_char),
+                &n,
+                &k,
+                &alpha,
+                a.as_ptr(),
+                &lda,
+                b.as_ptr(),
+                &ldb,
+                &beta,
+                c.as_mut_ptr(),
+                &ldc,
+            );
+            #[cfg(any(not(feature = "extension-gemmt"), feature = "reference"))]
+            fallback::gemmt(
+                stringify!($name),
+                uplo, transa, transb, n, k, alpha, a, lda, b, ldb, beta, c, ldc,
+            );
+        }
+    )*);
+}
+
+macro_rules! implement_matcopy {
+    ($($iname:ident: $iffi:ident, $oname:ident: $offi:ident -> $scalar:ty,)*) => ($(
+        #[cfg(all(feature = "extension-matcopy", not(feature = "reference")))]
+        extern "C" {
+            #[cfg_attr(feature = "ilp64", link_name = concat!(stringify!($iffi), "64_"))]
+            fn $iffi(
+                order: *const core::ffi::c_char,
+                trans: *const core::ffi::c_char,
+                rows: *const Int,
+                cols: *const Int,
+                alpha: *const $scalar,
+                ab: *mut $scalar,
+                lda: *const Int,
+                ldb: *const Int,
+            );
+
+            #[cfg_attr(feature = "ilp64", link_name = concat!(stringify!($offi), "64_"))]
+            fn $offi(
+                order: *const core::ffi::c_char,
+                trans: *const core::ffi::c_char,
+                rows: *const Int,
+                cols: *const Int,
+                alpha: *const $scalar,
+                a: *const $scalar,
+                lda: *const Int,
+                b: *mut $scalar,
+                ldb: *const Int,
+            );
+        }
+
+        /// Compute `B := alpha * op(A)` in place, where `A` has leading dimension `lda` and `B`
+        /// has leading dimension `ldb`.
+        ///
+        /// `order` is `C` for column-major and `R` for row-major storage, and `trans` is `N`,
+        /// `T`, `C` for the conjugate transpose, or `R` for the conjugate without transposition.
+        #[cfg(any(
+            feature = "alloc",
+            all(feature = "extension-matcopy", not(feature = "reference")),
+        ))]
+        #[inline]
+        pub unsafe fn $iname(
+            order: u8,
+            trans: u8,
+            rows: Int,
+            cols: Int,
+            alpha: $scalar,
+            ab: &mut [$scalar],
+            lda: Int,
+            ldb: Int,
+        ) {
+            #[cfg(all(feature = "extension-matcopy", not(feature = "reference")))]
+            $iffi(
+                &(order as core::ffi::c_char),
+                &(trans as core::ffi::c_char),
+                &rows,
+                &cols,
+                &alpha,
+                ab.as_mut_ptr(),
+                &lda,
+                &ldb,
+            );
+            #[cfg(any(not(feature = "extension-matcopy"), feature = "reference"))]
+            fallback::imatcopy(
+                stringify!($iname),
+                order, trans, rows, cols, alpha, ab, lda, ldb,
+            );
+        }
+
+        /// Compute `B := alpha * op(A)` out of place.
+        ///
+        /// The flags are interpreted as in the in-place variant.
+        #[inline]
+        pub unsafe fn $oname(
+            order: u8,
+            trans: u8,
+            rows: Int,
+            cols: Int,
+            alpha: $scalar,
+            a: &[$scalar],
+            lda: Int,
+            b: &mut [$scalar],
+            ldb: Int,
+        ) {
+            #[cfg(all(feature = "extension-matcopy", not(feature = "reference")))]
+            $offi(
+                &(order as core::ffi::c_char),
+                &(trans as core::ffi::c_char),
+                &rows,
+                &cols,
+                &alpha,
+                a.as_ptr(),
+                &lda,
+                b.as_mut_ptr(),
+                &ldb,
+            );
+            #[cfg(any(not(feature = "extension-matcopy"), feature = "reference"))]
+            fallback::omatcopy(
+                stringify!($oname),
+                order, trans, rows, cols, alpha, a, lda, b, ldb,
+            );
+        }
+    )*);
+}
+
+implement_axpby! {
+    saxpby: saxpby_ -> f32,
+    daxpby: daxpby_ -> f64,
+    caxpby: caxpby_ -> c32,
+    zaxpby: zaxpby_ -> c64,
+}
+
+implement_gemmt! {
+    sgemmt: sgemmt_ -> f32,
+    dgemmt: dgemmt_ -> f64,
+    cgemmt: cgemmt_ -> c32,
+    zgemmt: zgemmt_ -> c64,
+}
+
+implement_matcopy! {
+    simatcopy: simatcopy_, somatcopy: somatcopy_ -> f32,
+    dimatcopy: dimatcopy_, domatcopy: domatcopy_ -> f64,
+    cimatcopy: cimatcopy_, comatcopy: comatcopy_ -> c32,
+    zimatcopy: zimatcopy_, zomatcopy: zomatcopy_ -> c64,
+}
@@ -0,0 +1,114 @@
+//! Typed flags.
+
+/// The operation applied to a matrix.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum Transpose {
+    /// `op(A) = A`.
+    None = b'N',
+    /// `op(A) = A^T`.
+    Ordinary = b'T',
+    /// `op(A) = A^H`.
+    Conjugate = b'C',
+}
+
+/// The operation applied to a matrix by a symmetric rank update of complex matrices.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum NoneOrTrans {
+    /// `op(A) = A`.
+    None = b'N',
+    /// `op(A) = A^T`.
+    Ordinary = b'T',
+}
+
+/// The operation applied to a matrix by a Hermitian rank update.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum NoneOrConj {
+    /// `op(A) = A`.
+    None = b'N',
+    /// `op(A) = A^H`.
+    Conjugate = b'C',
+}
+
+/// The triangle of a matrix that is referenced.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum Uplo {
+    /// The upper triangle.
+    Upper = b'U',
+    /// The lower triangle.
+    Lower = b'L',
+}
+
+/// The kind of the diagonal of a triangular matrix.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum Diag {
+    /// The diagonal is read from the matrix.
+    NonUnit = b'N',
+    /// The diagonal is assumed to consist of ones and is not referenced.
+    Unit = b'U',
+}
+
+/// The side from which a matrix is applied.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum Side {
+    /// `op(A) * B`.
+    Left = b'L',
+    /// `B * op(A)`.
+    Right = b'R',
+}
+
+macro_rules! implement {
+    ($name:ident, $($variant:ident => $code:literal,)*) => {
+        impl From<$name> for u8 {
+            #[inline]
+            fn from(flag: $name) -> u8 {
+                flag as u8
+            }
+        }
+
+        impl TryFrom<u8> for $name {
+            type Error = u8;
+
+            /// Convert a character code, ignoring case as `LSAME` does.
+            #[inline]
+            fn try_from(code: u8) -> Result<Self, u8> {
+                match code.to_ascii_uppercase() {
+                    $($code => Ok($name::$variant),)*
+                    _ => Err(code),
+                }
+            }
+        }
+    };
+}
+
+implement!(Transpose, None => b'N', Ordinary => b'T', Conjugate => b'C',);
+implement!(NoneOrTrans, None => b'N', Ordinary => b'T',);
+implement!(NoneOrConj, None => b'N', Conjugate => b'C',);
+implement!(Uplo, Upper => b'U', Lower => b'L',);
+implement!(Diag, NonUnit => b'N', Unit => b'U',);
+implement!(Side, Left => b'L', Right => b'R',);
+
+impl From<NoneOrTrans> for Transpose {
+    #[inline]
+    fn from(trans: NoneOrTrans) -> Transpose {
+        match trans {
+            NoneOrTrans::None => Transpose::None,
+            NoneOrTrans::Ordinary => Transpose::Ordinary,
+        }
+    }
+}
+
+impl From<NoneOrConj> for Transpose {
+    #[inline]
+    fn from(trans: NoneOrConj) -> Transpose {
+        match trans {
+            NoneOrConj::None => Transpose::None,
+            NoneOrConj::Conjugate => Transpose::Conjugate,
+        }
+    }
+}
@@ -0,0 +1,2175 @@
+//! Precision-generic interface.
+
+use core::fmt::Debug;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+use core::ffi::c_char;
+
+use crate::{c32, c64, ffi, Diag, Int, NoneOrConj, NoneOrTrans, Side, Transpose, Uplo};
+
+/// A scalar type supported by BLAS.
+pub trait Scalar:
+    Copy
+    + Debug
+    + Default
+    + PartialEq
+    + Send
+    + Sync
+    + 'static
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    /// The type of the real part.
+    type Real: Scalar<Real = Self::Real> + PartialOrd;
+
+    /// Return zero.
+    fn zero() -> Self;
+
+    /// Return one.
+    fn one() -> Self;
+
+    /// Construct a scalar from its real part.
+    fn from_real(re: Self::Real) -> Self;
+
+    /// Return the real part.
+    fn re(self) -> Self::Real;
+
+    /// Return the complex conjugate.
+    fn conj(self) -> Self;
+}
+
+/// A scalar type with BLAS routines.
+///
+/// The associated functions call the routine of the corresponding precision. The
+/// Hermitian routines (`hemv`, `her`, `herk`, and so on) fall back to their symmetric
+/// counterparts for real types, and `dotc` and `gerc` coincide with `dot` and `geru`. The trait
+/// is implemented for `f32`, `f64`, `c32`, and `c64` and cannot be implemented outside the crate.
+///
+/// ## Example
+///
+/// ```no_run
+/// use blas::{Blas, Int, Transpose};
+///
+/// fn multiply<T: Blas>(m: Int, n: Int, k: Int, a: &[T], b: &[T], c: &mut [T]) {
+///     let (none, one, zero) = (Transpose::None, T::one(), T::zero());
+///     unsafe { T::gemm(none, none, m, n, k, one, a, m, b, k, zero, c, m) }
+/// }
+/// ```
+pub trait Blas: Scalar + raw::Raw {
+    #[inline]
+    unsafe fn rot(
+        n: Int,
+        x: &mut [Self],
+        incx: Int,
+        y: &mut [Self],
+        incy: Int,
+        c: Self::Real,
+        s: Self::Real,
+    ) {
+        Self::rot_(n, x.as_mut_ptr(), incx, y.as_mut_ptr(), incy, c, s)
+    }
+
+    #[inline]
+    unsafe fn swap(n: Int, x: &mut [Self], incx: Int, y: &mut [Self], incy: Int) {
+        Self::swap_(n, x.as_mut_ptr(), incx, y.as_mut_ptr(), incy)
+    }
+
+    #[inline]
+    unsafe fn scal(n: Int, a: Self, x: &mut [Self], incx: Int) {
+        Self::scal_(n, a, x.as_mut_ptr(), incx)
+    }
+
+    /// Scale a vector by a real scalar.
+    #[inline]
+    unsafe fn rscal(n: Int, a: Self::Real, x: &mut [Self], incx: Int) {
+        Self::rscal_(n, a, x.as_mut_ptr(), incx)
+    }
+
+    #[inline]
+    unsafe fn copy(n: Int, x: &[Self], incx: Int, y: &mut [Self], incy: Int) {
+        Self::copy_(n, x.as_ptr(), incx, y.as_mut_ptr(), incy)
+    }
+
+    #[inline]
+    unsafe fn axpy(n: Int, alpha: Self, x: &[Self], incx: Int, y: &mut [Self], incy: Int) {
+        Self::axpy_(n, alpha, x.as_ptr(), incx, y.as_mut_ptr(), incy)
+    }
+
+    #[inline]
+    unsafe fn dot(n: Int, x: &[Self], incx: Int, y: &[Self], incy: Int) -> Self {
+        Self::dot_(n, x.as_ptr(), incx, y.as_ptr(), incy)
+    }
+
+    #[inline]
+    unsafe fn dotc(n: Int, x: &[Self], incx: Int, y: &[Self], incy: Int) -> Self {
+        Self::dotc_(n, x.as_ptr(), incx, y.as_ptr(), incy)
+    }
+
+    #[inline]
+    unsafe fn nrm2(n: Int, x: &[Self], incx: Int) -> Self::Real {
+        Self::nrm2_(n, x.as_ptr(), incx)
+    }
+
+    #[inline]
+    unsafe fn asum(n: Int, x: &[Self], incx: Int) -> Self::Real {
+        Self::asum_(n, x.as_ptr(), incx)
+    }
+
+    #[inline]
+    unsafe fn iamax(n: Int, x: &[Self], incx: Int) -> usize {
+        Self::iamax_(n, x.as_ptr(), incx)
+    }
+
+    #[inline]
+    unsafe fn gemv(
+        trans: Transpose,
+        m: Int,
+        n: Int,
+        alpha: Self,
+        a: &[Self],
+        lda: Int,
+        x: &[Self],
+        incx: Int,
+        beta: Self,
+        y: &mut [Self],
+        incy: Int,
+    ) {
+        Self::gemv_(
+            trans,
+            m,
+            n,
+            alpha,
+            a.as_ptr(),
+            lda,
+            x.as_ptr(),
+            incx,
+            beta,
+            y.as_mut_ptr(),
+            incy,
+        )
+    }
+
+    #[inline]
+    unsafe fn gbmv(
+        trans: Transpose,
+        m: Int,
+        n: Int,
+        kl: Int,
+        ku: Int,
+        alpha: Self,
+        a: &[Self],
+        lda: Int,
+        x: &[Self],
+        incx: Int,
+        beta: Self,
+        y: &mut [Self],
+        incy: Int,
+    ) {
+        Self::gbmv_(
+            trans,
+            m,
+            n,
+            kl,
+            ku,
+            alpha,
+            a.as_ptr(),
+            lda,
+            x.as_ptr(),
+            incx,
+            beta,
+            y.as_mut_ptr(),
+            incy,
+        )
+    }
+
+    #[inline]
+    unsafe fn hemv(
+        uplo: Uplo,
+        n: Int,
+        alpha: Self,
+        a: &[Self],
+        lda: Int,
+        x: &[Self],
+        incx: Int,
+        beta: Self,
+        y: &mut [Self],
+        incy: Int,
+    ) {
+        Self::hemv_(
+            uplo,
+            n,
+            alpha,
+            a.as_ptr(),
+            lda,
+            x.as_ptr(),
+            incx,
+            beta,
+            y.as_mut_ptr(),
+            incy,
+        )
+    }
+
+    #[inline]
+    unsafe fn hbmv(
+        uplo: Uplo,
+        n: Int,
+        k: Int,
+        alpha: Self,
+        a: &[Self],
+        lda: Int,
+        x: &[Self],
+        incx: Int,
+        beta: Self,
+        y: &mut [Self],
+        incy: Int,
+    ) {
+        Self::hbmv_(
+            uplo,
+            n,
+            k,
+            alpha,
+            a.as_ptr(),
+            lda,
+            x.as_ptr(),
+            incx,
+            beta,
+            y.as_mut_ptr(),
+            incy,
+        )
+    }
+
+    #[inline]
+    unsafe fn hpmv(
+        uplo: Uplo,
+        n: Int,
+        alpha: Self,
+        ap: &[Self],
+        x: &[Self],
+        incx: Int,
+        beta: Self,
+        y: &mut [Self],
+        incy: Int,
+    ) {
+        Self::hpmv_(
+            uplo,
+            n,
+            alpha,
+            ap.as_ptr(),
+            x.as_ptr(),
+            incx,
+            beta,
+            y.as_mut_ptr(),
+            incy,
+        )
+    }
+
+    #[inline]
+    unsafe fn trmv(
+        uplo: Uplo,
+        trans: Transpose,
+        diag: Diag,
+        n: Int,
+        a: &[Self],
+        lda: Int,
+        x: &mut [Self],
+        incx: Int,
+    ) {
+        Self::trmv_(uplo, trans, diag, n, a.as_ptr(), lda, x.as_mut_ptr(), incx)
+    }
+
+    #[inline]
+    unsafe fn tbmv(
+        uplo: Uplo,
+        trans: Transpose,
+        diag: Diag,
+        n: Int,
+        k: Int,
+        a: &[Self],
+        lda: Int,
+        x: &mut [Self],
+        incx: Int,
+    ) {
+        Self::tbmv_(
+            uplo,
+            trans,
+            diag,
+            n,
+            k,
+            a.as_ptr(),
+            lda,
+            x.as_mut_ptr(),
+            incx,
+        )
+    }
+
+    #[inline]
+    unsafe fn tpmv(
+        uplo: Uplo,
+        trans: Transpose,
+        diag: Diag,
+        n: Int,
+        ap: &[Self],
+        x: &mut [Self],
+        incx: Int,
+    ) {
+        Self::tpmv_(uplo, trans, diag, n, ap.as_ptr(), x.as_mut_ptr(), incx)
+    }
+
+    #[inline]
+    unsafe fn trsv(
+        uplo: Uplo,
+        trans: Transpose,
+        diag: Diag,
+        n: Int,
+        a: &[Self],
+        lda: Int,
+        x: &mut [Self],
+        incx: Int,
+    ) {
+        Self::trsv_(uplo, trans, diag, n, a.as_ptr(), lda, x.as_mut_ptr(), incx)
+    }
+
+    #[inline]
+    unsafe fn tbsv(
+        uplo: Uplo,
+        trans: Transpose,
+        diag: Diag,
+        n: Int,
+        k: Int,
+        a: &[Self],
+        lda: Int,
+        x: &mut [Self],
+        incx: Int,
+    ) {
+        Self::tbsv_(
+            uplo,
+            trans,
+            diag,
+            n,
+            k,
+            a.as_ptr(),
+            lda,
+            x.as_mut_ptr(),
+            incx,
+        )
+    }
+
+    #[inline]
+    unsafe fn tpsv(
+        uplo: Uplo,
+        trans: Transpose,
+        diag: Diag,
+        n: Int,
+        ap: &[Self],
+        x: &mut [Self],
+        incx: Int,
+    ) {
+        Self::tpsv_(uplo, trans, diag, n, ap.as_ptr(), x.as_mut_ptr(), incx)
+    }
+
+    #[inline]
+    unsafe fn geru(
+        m: Int,
+        n: Int,
+        alpha: Self,
+        x: &[Self],
+        incx: Int,
+        y: &[Self],
+        incy: Int,
+        a: &mut [Self],
+        lda: Int,
+    ) {
+        Self::geru_(
+            m,
+            n,
+            alpha,
+            x.as_ptr(),
+            incx,
+            y.as_ptr(),
+            incy,
+            a.as_mut_ptr(),
+            lda,
+        )
+    }
+
+    #[inline]
+    unsafe fn gerc(
+        m: Int,
+        n: Int,
+        alpha: Self,
+        x: &[Self],
+        incx: Int,
+        y: &[Self],
+        incy: Int,
+        a: &mut [Self],
+        lda: Int,
+    ) {
+        Self::gerc_(
+            m,
+            n,
+            alpha,
+            x.as_ptr(),
+            incx,
+            y.as_ptr(),
+            incy,
+            a.as_mut_ptr(),
+            lda,
+        )
+    }
+
+    #[inline]
+    unsafe fn her(
+        uplo: Uplo,
+        n: Int,
+        alpha: Self::Real,
+        x: &[Self],
+        incx: Int,
+        a: &mut [Self],
+        lda: Int,
+    ) {
+        Self::her_(uplo, n, alpha, x.as_ptr(), incx, a.as_mut_ptr(), lda)
+    }
+
+    #[inline]
+    unsafe fn hpr(uplo: Uplo, n: Int, alpha: Self::Real, x: &[Self], incx: Int, ap: &mut [Self]) {
+        Self::hpr_(uplo, n, alpha, x.as_ptr(), incx, ap.as_mut_ptr())
+    }
+
+    #[inline]
+    unsafe fn her2(
+        uplo: Uplo,
+        n: Int,
+        alpha: Self,
+        x: &[Self],
+        incx: Int,
+        y: &[Self],
+        incy: Int,
+        a: &mut [Self],
+        lda: Int,
+    ) {
+        Self::her2_(
+            uplo,
+            n,
+            alpha,
+            x.as_ptr(),
+            incx,
+            y.as_ptr(),
+            incy,
+            a.as_mut_ptr(),
+            lda,
+        )
+    }
+
+    #[inline]
+    unsafe fn hpr2(
+        uplo: Uplo,
+        n: Int,
+        alpha: Self,
+        x: &[Self],
+        incx: Int,
+        y: &[Self],
+        incy: Int,
+        ap: &mut [Self],
+    ) {
+        Self::hpr2_(
+            uplo,
+            n,
+            alpha,
+            x.as_ptr(),
+            incx,
+            y.as_ptr(),
+            incy,
+            ap.as_mut_ptr(),
+        )
+    }
+
+    #[inline]
+    unsafe fn gemm(
+        transa: Transpose,
+        transb: Transpose,
+        m: Int,
+        n: Int,
+        k: Int,
+        alpha: Self,
+        a: &[Self],
+        lda: Int,
+        b: &[Self],
+        ldb: Int,
+        beta: Self,
+        c: &mut [Self],
+        ldc: Int,
+    ) {
+        Self::gemm_(
+            transa,
+            transb,
+            m,
+            n,
+            k,
+            alpha,
+            a.as_ptr(),
+            lda,
+            b.as_ptr(),
+            ldb,
+            beta,
+            c.as_mut_ptr(),
+            ldc,
+        )
+    }
+
+    #[inline]
+    unsafe fn symm(
+        side: Side,
+        uplo: Uplo,
+        m: Int,
+        n: Int,
+        alpha: Self,
+        a: &[Self],
+        lda: Int,
+        b: &[Self],
+        ldb: Int,
+        beta: Self,
+        c: &mut [Self],
+        ldc: Int,
+    ) {
+        Self::symm_(
+            side,
+            uplo,
+            m,
+            n,
+            alpha,
+            a.as_ptr(),
+            lda,
+            b.as_ptr(),
+            ldb,
+            beta,
+            c.as_mut_ptr(),
+            ldc,
+        )
+    }
+
+    #[inline]
+    unsafe fn hemm(
+        side: Side,
+        uplo: Uplo,
+        m: Int,
+        n: Int,
+        alpha: Self,
+        a: &[Self],
+        lda: Int,
+        b: &[Self],
+        ldb: Int,
+        beta: Self,
+        c: &mut [Self],
+        ldc: Int,
+    ) {
+        Self::hemm_(
+            side,
+            uplo,
+            m,
+            n,
+            alpha,
+            a.as_ptr(),
+            lda,
+            b.as_ptr(),
+            ldb,
+            beta,
+            c.as_mut_ptr(),
+            ldc,
+        )
+    }
+
+    #[inline]
+    unsafe fn syrk(
+        uplo: Uplo,
+        trans: NoneOrTrans,
+        n: Int,
+        k: Int,
+        alpha: Self,
+        a: &[Self],
+        lda: Int,
+        beta: Self,
+        c: &mut [Self],
+        ldc: Int,
+    ) {
+        Self::syrk_(
+            uplo,
+            trans,
+            n,
+            k,
+            alpha,
+            a.as_ptr(),
+            lda,
+            beta,
+            c.as_mut_ptr(),
+            ldc,
+        )
+    }
+
+    #[inline]
+    unsafe fn herk(
+        uplo: Uplo,
+        trans: NoneOrConj,
+        n: Int,
+        k: Int,
+        alpha: Self::Real,
+        a: &[Self],
+        lda: Int,
+        beta: Self::Real,
+        c: &mut [Self],
+        ldc: Int,
+    ) {
+        Self::herk_(
+            uplo,
+            trans,
+            n,
+            k,
+            alpha,
+            a.as_ptr(),
+            lda,
+            beta,
+            c.as_mut_ptr(),
+            ldc,
+        )
+    }
+
+    #[inline]
+    unsafe fn syr2k(
+        uplo: Uplo,
+        trans: NoneOrTrans,
+        n: Int,
+        k: Int,
+        alpha: Self,
+        a: &[Self],
+        lda: Int,
+        b: &[Self],
+        ldb: Int,
+        beta: Self,
+        c: &mut [Self],
+        ldc: Int,
+    ) {
+        Self::syr2k_(
+            uplo,
+            trans,
+            n,
+            k,
+            alpha,
+            a.as_ptr(),
+            lda,
+            b.as_ptr(),
+            ldb,
+            beta,
+            c.as_mut_ptr(),
+            ldc,
+        )
+    }
+
+    #[inline]
+    unsafe fn her2k(
+        uplo: Uplo,
+        trans: NoneOrConj,
+        n: Int,
+        k: Int,
+        alpha: Self,
+        a: &[Self],
+        lda: Int,
+        b: &[Self],
+        ldb: Int,
+        beta: Self::Real,
+        c: &mut [Self],
+        ldc: Int,
+    ) {
+        Self::her2k_(
+            uplo,
+            trans,
+            n,
+            k,
+            alpha,
+            a.as_ptr(),
+            lda,
+            b.as_ptr(),
+            ldb,
+            beta,
+            c.as_mut_ptr(),
+            ldc,
+        )
+    }
+
+    #[inline]
+    unsafe fn trmm(
+        side: Side,
+        uplo: Uplo,
+        transa: Transpose,
+        diag: Diag,
+        m: Int,
+        n: Int,
+        alpha: Self,
+        a: &[Self],
+        lda: Int,
+        b: &mut [Self],
+        ldb: Int,
+    ) {
+        Self::trmm_(
+            side,
+            uplo,
+            transa,
+            diag,
+            m,
+            n,
+            alpha,
+            a.as_ptr(),
+            lda,
+            b.as_mut_ptr(),
+            ldb,
+        )
+    }
+
+    #[inline]
+    unsafe fn trsm(
+        side: Side,
+        uplo: Uplo,
+        transa: Transpose,
+        diag: Diag,
+        m: Int,
+        n: Int,
+        alpha: Self,
+        a: &[Self],
+        lda: Int,
+        b: &mut [Self],
+        ldb: Int,
+    ) {
+        Self::trsm_(
+            side,
+            uplo,
+            transa,
+            diag,
+            m,
+            n,
+            alpha,
+            a.as_ptr(),
+            lda,
+            b.as_mut_ptr(),
+            ldb,
+        )
+    }
+}
+
+mod raw {
+    use crate::{Diag, Int, NoneOrConj, NoneOrTrans, Scalar, Side, Transpose, Uplo};
+
+    /// The routines of [`Blas`](super::Blas) taking raw pointers in place of slices.
+    ///
+    /// The views pass their elements to BLAS through these functions, as a slice spanning the
+    /// elements of a view could also span memory that is borrowed elsewhere.
+    pub trait Raw: Scalar {
+        unsafe fn rot_(
+            n: Int,
+            x: *mut Self,
+            incx: Int,
+            y: *mut Self,
+            incy: Int,
+            c: Self::Real,
+            s: Self::Real,
+        );
+
+        unsafe fn swap_(n: Int, x: *mut Self, incx: Int, y: *mut Self, incy: Int);
+
+        unsafe fn scal_(n: Int, a: Self, x: *mut Self, incx: Int);
+
+        unsafe fn rscal_(n: Int, a: Self::Real, x: *mut Self, incx: Int);
+
+        unsafe fn copy_(n: Int, x: *const Self, incx: Int, y: *mut Self, incy: Int);
+
+        unsafe fn axpy_(n: Int, alpha: Self, x: *const Self, incx: Int, y: *mut Self, incy: Int);
+
+        unsafe fn dot_(n: Int, x: *const Self, incx: Int, y: *const Self, incy: Int) -> Self;
+
+        unsafe fn dotc_(n: Int, x: *const Self, incx: Int, y: *const Self, incy: Int) -> Self;
+
+        unsafe fn nrm2_(n: Int, x: *const Self, incx: Int) -> Self::Real;
+
+        unsafe fn asum_(n: Int, x: *const Self, incx: Int) -> Self::Real;
+
+        unsafe fn iamax_(n: Int, x: *const Self, incx: Int) -> usize;
+
+        unsafe fn gemv_(
+            trans: Transpose,
+            m: Int,
+            n: Int,
+            alpha: Self,
+            a: *const Self,
+            lda: Int,
+            x: *const Self,
+            incx: Int,
+            beta: Self,
+            y: *mut Self,
+            incy: Int,
+        );
+
+        unsafe fn gbmv_(
+            trans: Transpose,
+            m: Int,
+            n: Int,
+            kl: Int,
+            ku: Int,
+            alpha: Self,
+            a: *const Self,
+            lda: Int,
+            x: *const Self,
+            incx: Int,
+            beta: Self,
+            y: *mut Self,
+            incy: Int,
+        );
+
+        unsafe fn hemv_(
+            uplo: Uplo,
+            n: Int,
+            alpha: Self,
+            a: *const Self,
+            lda: Int,
+            x: *const Self,
+            incx: Int,
+            beta: Self,
+            y: *mut Self,
+            incy: Int,
+        );
+
+        unsafe fn hbmv_(
+            uplo: Uplo,
+            n: Int,
+            k: Int,
+            alpha: Self,
+            a: *const Self,
+            lda: Int,
+            x: *const Self,
+            incx: Int,
+            beta: Self,
+            y: *mut Self,
+            incy: Int,
+        );
+
+        unsafe fn hpmv_(
+            uplo: Uplo,
+            n: Int,
+            alpha: Self,
+            ap: *const Self,
+            x: *const Self,
+            incx: Int,
+            beta: Self,
+            y: *mut Self,
+            incy: Int,
+        );
+
+        unsafe fn trmv_(
+            uplo: Uplo,
+            trans: Transpose,
+            diag: Diag,
+            n: Int,
+            a: *const Self,
+            lda: Int,
+            x: *mut Self,
+            incx: Int,
+        );
+
+        unsafe fn tbmv_(
+            uplo: Uplo,
+            trans: Transpose,
+            diag: Diag,
+            n: Int,
+            k: Int,
+            a: *const Self,
+            lda: Int,
+            x: *mut Self,
+            incx: Int,
+        );
+
+        unsafe fn tpmv_(
+            uplo: Uplo,
+            trans: Transpose,
+            diag: Diag,
+            n: Int,
+            ap: *const Self,
+            x: *mut Self,
+            incx: Int,
+        );
+
+        unsafe fn trsv_(
+            uplo: Uplo,
+            trans: Transpose,
+            diag: Diag,
+            n: Int,
+            a: *const Self,
+            lda: Int,
+            x: *mut Self,
+            incx: Int,
+        );
+
+        unsafe fn tbsv_(
+            uplo: Uplo,
+            trans: Transpose,
+            diag: Diag,
+            n: Int,
+            k: Int,
+            a: *const Self,
+            lda: Int,
+            x: *mut Self,
+            incx: Int,
+        );
+
+        unsafe fn tpsv_(
+            uplo: Uplo,
+            trans: Transpose,
+            diag: Diag,
+            n: Int,
+            ap: *const Self,
+            x: *mut Self,
+            incx: Int,
+        );
+
+        unsafe fn geru_(
+            m: Int,
+            n: Int,
+            alpha: Self,
+            x: *const Self,
+            incx: Int,
+            y: *const Self,
+            incy: Int,
+            a: *mut Self,
+            lda: Int,
+        );
+
+        unsafe fn gerc_(
+            m: Int,
+            n: Int,
+            alpha: Self,
+            x: *const Self,
+            incx: Int,
+            y: *const Self,
+            incy: Int,
+            a: *mut Self,
+            lda: Int,
+        );
+
+        unsafe fn her_(
+            uplo: Uplo,
+            n: Int,
+            alpha: Self::Real,
+            x: *const Self,
+            incx: Int,
+            a: *mut Self,
+            lda: Int,
+        );
+
+        unsafe fn hpr_(
+            uplo: Uplo,
+            n: Int,
+            alpha: Self::Real,
+            x: *const Self,
+            incx: Int,
+            ap: *mut Self,
+        );
+
+        unsafe fn her2_(
+            uplo: Uplo,
+            n: Int,
+            alpha: Self,
+            x: *const Self,
+            incx: Int,
+            y: *const Self,
+            incy: Int,
+            a: *mut Self,
+            lda: Int,
+        );
+
+        unsafe fn hpr2_(
+            uplo: Uplo,
+            n: Int,
+            alpha: Self,
+            x: *const Self,
+            incx: Int,
+            y: *const Self,
+            incy: Int,
+            ap: *mut Self,
+        );
+
+        unsafe fn gemm_(
+            transa: Transpose,
+            transb: Transpose,
+            m: Int,
+            n: Int,
+            k: Int,
+            alpha: Self,
+            a: *const Self,
+            lda: Int,
+            b: *const Self,
+            ldb: Int,
+            beta: Self,
+            c: *mut Self,
+            ldc: Int,
+        );
+
+        unsafe fn symm_(
+            side: Side,
+            uplo: Uplo,
+            m: Int,
+            n: Int,
+            alpha: Self,
+            a: *const Self,
+            lda: Int,
+            b: *const Self,
+            ldb: Int,
+            beta: Self,
+            c: *mut Self,
+            ldc: Int,
+        );
+
+        unsafe fn hemm_(
+            side: Side,
+            uplo: Uplo,
+            m: Int,
+            n: Int,
+            alpha: Self,
+            a: *const Self,
+            lda: Int,
+            b: *const Self,
+            ldb: Int,
+            beta: Self,
+            c: *mut Self,
+            ldc: Int,
+        );
+
+        unsafe fn syrk_(
+            uplo: Uplo,
+            trans: NoneOrTrans,
+            n: Int,
+            k: Int,
+            alpha: Self,
+            a: *const Self,
+            lda: Int,
+            beta: Self,
+            c: *mut Self,
+            ldc: Int,
+        );
+
+        unsafe fn herk_(
+            uplo: Uplo,
+            trans: NoneOrConj,
+            n: Int,
+            k: Int,
+            alpha: Self::Real,
+            a: *const Self,
+            lda: Int,
+            beta: Self::Real,
+            c: *mut Self,
+            ldc: Int,
+        );
+
+        unsafe fn syr2k_(
+            uplo: Uplo,
+            trans: NoneOrTrans,
+            n: Int,
+            k: Int,
+            alpha: Self,
+            a: *const Self,
+            lda: Int,
+            b: *const Self,
+            ldb: Int,
+            beta: Self,
+            c: *mut Self,
+            ldc: Int,
+        );
+
+        unsafe fn her2k_(
+            uplo: Uplo,
+            trans: NoneOrConj,
+            n: Int,
+            k: Int,
+            alpha: Self,
+            a: *const Self,
+            lda: Int,
+            b: *const Self,
+            ldb: Int,
+            beta: Self::Real,
+            c: *mut Self,
+            ldc: Int,
+        );
+
+        unsafe fn trmm_(
+            side: Side,
+            uplo: Uplo,
+            transa: Transpose,
+            diag: Diag,
+            m: Int,
+            n: Int,
+            alpha: Self,
+            a: *const Self,
+            lda: Int,
+            b: *mut Self,
+            ldb: Int,
+        );
+
+        unsafe fn trsm_(
+            side: Side,
+            uplo: Uplo,
+            transa: Transpose,
+            diag: Diag,
+            m: Int,
+            n: Int,
+            alpha: Self,
+            a: *const Self,
+            lda: Int,
+            b: *mut Self,
+            ldb: Int,
+        );
+    }
+}
+
+macro_rules! implement_real {
+    ($scalar:ty) => {
+        impl Scalar for $scalar {
+            type Real = $scalar;
+
+            #[inline]
+            fn zero() -> Self {
+                0.0
+            }
+
+            #[inline]
+            fn one() -> Self {
+                1.0
+            }
+
+            #[inline]
+            fn from_real(re: Self::Real) -> Self {
+                re
+            }
+
+            #[inline]
+            fn re(self) -> Self::Real {
+                self
+            }
+
+            #[inline]
+            fn conj(self) -> Self {
+                self
+            }
+        }
+    };
+}
+
+macro_rules! implement_complex {
+    ($scalar:ty, $real:ty) => {
+        impl Scalar for $scalar {
+            type Real = $real;
+
+            #[inline]
+            fn zero() -> Self {
+                <$scalar>::new(0.0, 0.0)
+            }
+
+            #[inline]
+            fn one() -> Self {
+                <$scalar>::new(1.0, 0.0)
+            }
+
+            #[inline]
+            fn from_real(re: Self::Real) -> Self {
+                <$scalar>::new(re, 0.0)
+            }
+
+            #[inline]
+            fn re(self) -> Self::Real {
+                self.re
+            }
+
+            #[inline]
+            fn conj(self) -> Self {
+                num::Complex::conj(&self)
+            }
+        }
+    };
+}
+
+implement_real!(f32);
+implement_real!(f64);
+implement_complex!(c32, f32);
+implement_complex!(c64, f64);
+
+macro_rules! implement_raw {
+    (
+        $scalar:ty,
+        rot: $rot:ident,
+        swap: $swap:ident,
+        scal: $scal:ident,
+        rscal: $rscal:ident,
+        copy: $copy:ident,
+        axpy: $axpy:ident,
+        dot: $dot:path,
+        dotc: $dotc:path,
+        nrm2: $nrm2:ident,
+        asum: $asum:ident,
+        iamax: $iamax:ident,
+        gemv: $gemv:ident,
+        gbmv: $gbmv:ident,
+        hemv: $hemv:ident,
+        hbmv: $hbmv:ident,
+        hpmv: $hpmv:ident,
+        trmv: $trmv:ident,
+        tbmv: $tbmv:ident,
+        tpmv: $tpmv:ident,
+        trsv: $trsv:ident,
+        tbsv: $tbsv:ident,
+        tpsv: $tpsv:ident,
+        geru: $geru:ident,
+        gerc: $gerc:ident,
+        her: $her:ident,
+        hpr: $hpr:ident,
+        her2: $her2:ident,
+        hpr2: $hpr2:ident,
+        gemm: $gemm:ident,
+        symm: $symm:ident,
+        hemm: $hemm:ident,
+        syrk: $syrk:ident,
+        herk: $herk:ident,
+        syr2k: $syr2k:ident,
+        her2k: $her2k:ident,
+        trmm: $trmm:ident,
+        trsm: $trsm:ident,
+    ) => {
+        impl raw::Raw for $scalar {
+            #[inline]
+            unsafe fn rot_(
+                n: Int,
+                x: *mut Self,
+                incx: Int,
+                y: *mut Self,
+                incy: Int,
+                c: Self::Real,
+                s: Self::Real,
+            ) {
+                ffi::$rot(&n, x.cast(), &incx, y.cast(), &incy, &c, &s)
+            }
+
+            #[inline]
+            unsafe fn swap_(n: Int, x: *mut Self, incx: Int, y: *mut Self, incy: Int) {
+                ffi::$swap(&n, x.cast(), &incx, y.cast(), &incy)
+            }
+
+            #[inline]
+            unsafe fn scal_(n: Int, a: Self, x: *mut Self, incx: Int) {
+                ffi::$scal(&n, (&a as *const Self).cast(), x.cast(), &incx)
+            }
+
+            #[inline]
+            unsafe fn rscal_(n: Int, a: Self::Real, x: *mut Self, incx: Int) {
+                ffi::$rscal(&n, &a, x.cast(), &incx)
+            }
+
+            #[inline]
+            unsafe fn copy_(n: Int, x: *const Self, incx: Int, y: *mut Self, incy: Int) {
+                ffi::$copy(&n, x.cast(), &incx, y.cast(), &incy)
+            }
+
+            #[inline]
+            unsafe fn axpy_(
+                n: Int,
+                alpha: Self,
+                x: *const Self,
+                incx: Int,
+                y: *mut Self,
+                incy: Int,
+            ) {
+                ffi::$axpy(
+                    &n,
+                    (&alpha as *const Self).cast(),
+                    x.cast(),
+                    &incx,
+                    y.cast(),
+                    &incy,
+                )
+            }
+
+            #[inline]
+            unsafe fn dot_(n: Int, x: *const Self, incx: Int, y: *const Self, incy: Int) -> Self {
+                $dot(&n, x.cast(), &incx, y.cast(), &incy)
+            }
+
+            #[inline]
+            unsafe fn dotc_(n: Int, x: *const Self, incx: Int, y: *const Self, incy: Int) -> Self {
+                $dotc(&n, x.cast(), &incx, y.cast(), &incy)
+            }
+
+            #[inline]
+            unsafe fn nrm2_(n: Int, x: *const Self, incx: Int) -> Self::Real {
+                ffi::$nrm2(&n, x.cast(), &incx)
+            }
+
+            #[inline]
+            unsafe fn asum_(n: Int, x: *const Self, incx: Int) -> Self::Real {
+                ffi::$asum(&n, x.cast(), &incx)
+            }
+
+            #[inline]
+            unsafe fn iamax_(n: Int, x: *const Self, incx: Int) -> usize {
+                ffi::$iamax(&n, x.cast(), &incx) as usize
+            }
+
+            #[inline]
+            unsafe fn gemv_(
+                trans: Transpose,
+                m: Int,
+                n: Int,
+                alpha: Self,
+                a: *const Self,
+                lda: Int,
+                x: *const Self,
+                incx: Int,
+                beta: Self,
+                y: *mut Self,
+                incy: Int,
+            ) {
+                ffi::$gemv(
+                    &(u8::from(trans) as c_char),
+                    &m,
+                    &n,
+                    (&alpha as *const Self).cast(),
+                    a.cast(),
+                    &lda,
+                    x.cast(),
+                    &incx,
+                    (&beta as *const Self).cast(),
+                    y.cast(),
+                    &incy,
+                )
+            }
+
+            #[inline]
+            unsafe fn gbmv_(
+                trans: Transpose,
+                m: Int,
+                n: Int,
+                kl: Int,
+                ku: Int,
+                alpha: Self,
+                a: *const Self,
+                lda: Int,
+                x: *const Self,
+                incx: Int,
+                beta: Self,
+                y: *mut Self,
+                incy: Int,
+            ) {
+                ffi::$gbmv(
+                    &(u8::from(trans) as c_char),
+                    &m,
+                    &n,
+                    &kl,
+                    &ku,
+                    (&alpha as *const Self).cast(),
+                    a.cast(),
+                    &lda,
+                    x.cast(),
+                    &incx,
+                    (&beta as *const Self).cast(),
+                    y.cast(),
+                    &incy,
+                )
+            }
+
+            #[inline]
+            unsafe fn hemv_(
+                uplo: Uplo,
+                n: Int,
+                alpha: Self,
+                a: *const Self,
+                lda: Int,
+                x: *const Self,
+                incx: Int,
+                beta: Self,
+                y: *mut Self,
+                incy: Int,
+            ) {
+                ffi::$hemv(
+                    &(u8::from(uplo) as c_char),
+                    &n,
+                    (&alpha as *const Self).cast(),
+                    a.cast(),
+                    &lda,
+                    x.cast(),
+                    &incx,
+                    (&beta as *const Self).cast(),
+                    y.cast(),
+                    &incy,
+                )
+            }
+
+            #[inline]
+            unsafe fn hbmv_(
+                uplo: Uplo,
+                n: Int,
+                k: Int,
+                alpha: Self,
+                a: *const Self,
+                lda: Int,
+                x: *const Self,
+                incx: Int,
+                beta: Self,
+                y: *mut Self,
+                incy: Int,
+            ) {
+                ffi::$hbmv(
+                    &(u8::from(uplo) as c_char),
+                    &n,
+                    &k,
+                    (&alpha as *const Self).cast(),
+                    a.cast(),
+                    &lda,
+                    x.cast(),
+                    &incx,
+                    (&beta as *const Self).cast(),
+                    y.cast(),
+                    &incy,
+                )
+            }
+
+            #[inline]
+            unsafe fn hpmv_(
+                uplo: Uplo,
+                n: Int,
+                alpha: Self,
+                ap: *const Self,
+                x: *const Self,
+                incx: Int,
+                beta: Self,
+                y: *mut Self,
+                incy: Int,
+            ) {
+                ffi::$hpmv(
+                    &(u8::from(uplo) as c_char),
+                    &n,
+                    (&alpha as *const Self).cast(),
+                    ap.cast(),
+                    x.cast(),
+                    &incx,
+                    (&beta as *const Self).cast(),
+                    y.cast(),
+                    &incy,
+                )
+            }
+
+            #[inline]
+            unsafe fn trmv_(
+                uplo: Uplo,
+                trans: Transpose,
+                diag: Diag,
+                n: Int,
+                a: *const Self,
+                lda: Int,
+                x: *mut Self,
+                incx: Int,
+            ) {
+                ffi::$trmv(
+                    &(u8::from(uplo) as c_char),
+                    &(u8::from(trans) as c_char),
+                    &(u8::from(diag) as c_char),
+                    &n,
+                    a.cast(),
+                    &lda,
+                    x.cast(),
+                    &incx,
+                )
+            }
+
+            #[inline]
+            unsafe fn tbmv_(
+                uplo: Uplo,
+                trans: Transpose,
+                diag: Diag,
+                n: Int,
+                k: Int,
+                a: *const Self,
+                lda: Int,
+                x: *mut Self,
+                incx: Int,
+            ) {
+                ffi::$tbmv(
+                    &(u8::from(uplo) as c_char),
+                    &(u8::from(trans) as c_char),
+                    &(u8::from(diag) as c_char),
+                    &n,
+                    &k,
+                    a.cast(),
+                    &lda,
+                    x.cast(),
+                    &incx,
+                )
+            }
+
+            #[inline]
+            unsafe fn tpmv_(
+                uplo: Uplo,
+                trans: Transpose,
+                diag: Diag,
+                n: Int,
+                ap: *const Self,
+                x: *mut Self,
+                incx: Int,
+            ) {
+                ffi::$tpmv(
+                    &(u8::from(uplo) as c_char),
+                    &(u8::from(trans) as c_char),
+                    &(u8::from(diag) as c_char),
+                    &n,
+                    ap.cast(),
+                    x.cast(),
+                    &incx,
+                )
+            }
+
+            #[inline]
+            unsafe fn trsv_(
+                uplo: Uplo,
+                trans: Transpose,
+                diag: Diag,
+                n: Int,
+                a: *const Self,
+                lda: Int,
+                x: *mut Self,
+                incx: Int,
+            ) {
+                ffi::$trsv(
+                    &(u8::from(uplo) as c_char),
+                    &(u8::from(trans) as c_char),
+                    &(u8::from(diag) as c_char),
+                    &n,
+                    a.cast(),
+                    &lda,
+                    x.cast(),
+                    &incx,
+                )
+            }
+
+            #[inline]
+            unsafe fn tbsv_(
+                uplo: Uplo,
+                trans: Transpose,
+                diag: Diag,
+                n: Int,
+                k: Int,
+                a: *const Self,
+                lda: Int,
+                x: *mut Self,
+                incx: Int,
+            ) {
+                ffi::$tbsv(
+                    &(u8::from(uplo) as c_char),
+                    &(u8::from(trans) as c_char),
+                    &(u8::from(diag) as c_char),
+                    &n,
+                    &k,
+                    a.cast(),
+                    &lda,
+                    x.cast(),
+                    &incx,
+                )
+            }
+
+            #[inline]
+            unsafe fn tpsv_(
+                uplo: Uplo,
+                trans: Transpose,
+                diag: Diag,
+                n: Int,
+                ap: *const Self,
+                x: *mut Self,
+                incx: Int,
+            ) {
+                ffi::$tpsv(
+                    &(u8::from(uplo) as c_char),
+                    &(u8::from(trans) as c_char),
+                    &(u8::from(diag) as c_char),
+                    &n,
+                    ap.cast(),
+                    x.cast(),
+                    &incx,
+                )
+            }
+
+            #[inline]
+            unsafe fn geru_(
+                m: Int,
+                n: Int,
+                alpha: Self,
+                x: *const Self,
+                incx: Int,
+                y: *const Self,
+                incy: Int,
+                a: *mut Self,
+                lda: Int,
+            ) {
+                ffi::$geru(
+                    &m,
+                    &n,
+                    (&alpha as *const Self).cast(),
+                    x.cast(),
+                    &incx,
+                    y.cast(),
+                    &incy,
+                    a.cast(),
+                    &lda,
+                )
+            }
+
+            #[inline]
+            unsafe fn gerc_(
+                m: Int,
+                n: Int,
+                alpha: Self,
+                x: *const Self,
+                incx: Int,
+                y: *const Self,
+                incy: Int,
+                a: *mut Self,
+                lda: Int,
+            ) {
+                ffi::$gerc(
+                    &m,
+                    &n,
+                    (&alpha as *const Self).cast(),
+                    x.cast(),
+                    &incx,
+                    y.cast(),
+                    &incy,
+                    a.cast(),
+                    &lda,
+                )
+            }
+
+            #[inline]
+            unsafe fn her_(
+                uplo: Uplo,
+                n: Int,
+                alpha: Self::Real,
+                x: *const Self,
+                incx: Int,
+                a: *mut Self,
+                lda: Int,
+            ) {
+                ffi::$her(
+                    &(u8::from(uplo) as c_char),
+                    &n,
+                    &alpha,
+                    x.cast(),
+                    &incx,
+                    a.cast(),
+                    &lda,
+                )
+            }
+
+            #[inline]
+            unsafe fn hpr_(
+                uplo: Uplo,
+                n: Int,
+                alpha: Self::Real,
+                x: *const Self,
+                incx: Int,
+                ap: *mut Self,
+            ) {
+                ffi::$hpr(
+                    &(u8::from(uplo) as c_char),
+                    &n,
+                    &alpha,
+                    x.cast(),
+                    &incx,
+                    ap.cast(),
+                )
+            }
+
+            #[inline]
+            unsafe fn her2_(
+                uplo: Uplo,
+                n: Int,
+                alpha: Self,
+                x: *const Self,
+                incx: Int,
+                y: *const Self,
+                incy: Int,
+                a: *mut Self,
+                lda: Int,
+            ) {
+                ffi::$her2(
+                    &(u8::from(uplo) as c_char),
+                    &n,
+                    (&alpha as *const Self).cast(),
+                    x.cast(),
+                    &incx,
+                    y.cast(),
+                    &incy,
+                    a.cast(),
+                    &lda,
+                )
+            }
+
+            #[inline]
+            unsafe fn hpr2_(
+                uplo: Uplo,
+                n: Int,
+                alpha: Self,
+                x: *const Self,
+                incx: Int,
+                y: *const Self,
+                incy: Int,
+                ap: *mut Self,
+            ) {
+                ffi::$hpr2(
+                    &(u8::from(uplo) as c_char),
+                    &n,
+                    (&alpha as *const Self).cast(),
+                    x.cast(),
+                    &incx,
+                    y.cast(),
+                    &incy,
+                    ap.cast(),
+                )
+            }
+
+            #[inline]
+            unsafe fn gemm_(
+                transa: Transpose,
+                transb: Transpose,
+                m: Int,
+                n: Int,
+                k: Int,
+                alpha: Self,
+                a: *const Self,
+                lda: Int,
+                b: *const Self,
+                ldb: Int,
+                beta: Self,
+                c: *mut Self,
+                ldc: Int,
+            ) {
+                ffi::$gemm(
+                    &(u8::from(transa) as c_char),
+                    &(u8::from(transb) as c_char),
+                    &m,
+                    &n,
+                    &k,
+                    (&alpha as *const Self).cast(),
+                    a.cast(),
+                    &lda,
+                    b.cast(),
+                    &ldb,
+                    (&beta as *const Self).cast(),
+                    c.cast(),
+                    &ldc,
+                )
+            }
+
+            #[inline]
+            unsafe fn symm_(
+                side: Side,
+                uplo: Uplo,
+                m: Int,
+                n: Int,
+                alpha: Self,
+                a: *const Self,
+                lda: Int,
+                b: *const Self,
+                ldb: Int,
+                beta: Self,
+                c: *mut Self,
+                ldc: Int,
+            ) {
+                ffi::$symm(
+                    &(u8::from(side) as c_char),
+                    &(u8::from(uplo) as c_char),
+                    &m,
+                    &n,
+                    (&alpha as *const Self).cast(),
+                    a.cast(),
+                    &lda,
+                    b.cast(),
+                    &ldb,
+                    (&beta as *const Self).cast(),
+                    c.cast(),
+                    &ldc,
+                )
+            }
+
+            #[inline]
+            unsafe fn hemm_(
+                side: Side,
+                uplo: Uplo,
+                m: Int,
+                n: Int,
+                alpha: Self,
+                a: *const Self,
+                lda: Int,
+                b: *const Self,
+                ldb: Int,
+                beta: Self,
+                c: *mut Self,
+                ldc: Int,
+            ) {
+                ffi::$hemm(
+                    &(u8::from(side) as c_char),
+                    &(u8::from(uplo) as c_char),
+                    &m,
+                    &n,
+                    (&alpha as *const Self).cast(),
+                    a.cast(),
+                    &lda,
+                    b.cast(),
+                    &ldb,
+                    (&beta as *const Self).cast(),
+                    c.cast(),
+                    &ldc,
+                )
+            }
+
+            #[inline]
+            unsafe fn syrk_(
+                uplo: Uplo,
+                trans: NoneOrTrans,
+                n: Int,
+                k: Int,
+                alpha: Self,
+                a: *const Self,
+                lda: Int,
+                beta: Self,
+                c: *mut Self,
+                ldc: Int,
+            ) {
+                ffi::$syrk(
+                    &(u8::from(uplo) as c_char),
+                    &(u8::from(trans) as c_char),
+                    &n,
+                    &k,
+                    (&alpha as *const Self).cast(),
+                    a.cast(),
+                    &lda,
+                    (&beta as *const Self).cast(),
+                    c.cast(),
+                    &ldc,
+                )
+            }
+
+            #[inline]
+            unsafe fn herk_(
+                uplo: Uplo,
+                trans: NoneOrConj,
+                n: Int,
+                k: Int,
+                alpha: Self::Real,
+                a: *const Self,
+                lda: Int,
+                beta: Self::Real,
+                c: *mut Self,
+                ldc: Int,
+            ) {
+                ffi::$herk(
+                    &(u8::from(uplo) as c_char),
+                    &(u8::from(trans) as c_char),
+                    &n,
+                    &k,
+                    &alpha,
+                    a.cast(),
+                    &lda,
+                    &beta,
+                    c.cast(),
+                    &ldc,
+                )
+            }
+
+            #[inline]
+            unsafe fn syr2k_(
+                uplo: Uplo,
+                trans: NoneOrTrans,
+                n: Int,
+                k: Int,
+                alpha: Self,
+                a: *const Self,
+                lda: Int,
+                b: *const Self,
+                ldb: Int,
+                beta: Self,
+                c: *mut Self,
+                ldc: Int,
+            ) {
+                ffi::$syr2k(
+                    &(u8::from(uplo) as c_char),
+                    &(u8::from(trans) as c_char),
+                    &n,
+                    &k,
+                    (&alpha as *const Self).cast(),
+                    a.cast(),
+                    &lda,
+                    b.cast(),
+                    &ldb,
+                    (&beta as *const Self).cast(),
+                    c.cast(),
+                    &ldc,
+                )
+            }
+
+            #[inline]
+            unsafe fn her2k_(
+                uplo: Uplo,
+                trans: NoneOrConj,
+                n: Int,
+                k: Int,
+                alpha: Self,
+                a: *const Self,
+                lda: Int,
+                b: *const Self,
+                ldb: Int,
+                beta: Self::Real,
+                c: *mut Self,
+                ldc: Int,
+            ) {
+                ffi::$her2k(
+                    &(u8::from(uplo) as c_char),
+                    &(u8::from(trans) as c_char),
+                    &n,
+                    &k,
+                    (&alpha as *const Self).cast(),
+                    a.cast(),
+                    &lda,
+                    b.cast(),
+                    &ldb,
+                    &beta,
+                    c.cast(),
+                    &ldc,
+                )
+            }
+
+            #[inline]
+            unsafe fn trmm_(
+                side: Side,
+                uplo: Uplo,
+                transa: Transpose,
+                diag: Diag,
+                m: Int,
+                n: Int,
+                alpha: Self,
+                a: *const Self,
+                lda: Int,
+                b: *mut Self,
+                ldb: Int,
+            ) {
+                ffi::$trmm(
+                    &(u8::from(side) as c_char),
+                    &(u8::from(uplo) as c_char),
+                    &(u8::from(transa) as c_char),
+                    &(u8::from(diag) as c_char),
+                    &m,
+                    &n,
+                    (&alpha as *const Self).cast(),
+                    a.cast(),
+                    &lda,
+                    b.cast(),
+                    &ldb,
+                )
+            }
+
+            #[inline]
+            unsafe fn trsm_(
+                side: Side,
+                uplo: Uplo,
+                transa: Transpose,
+                diag: Diag,
+                m: Int,
+                n: Int,
+                alpha: Self,
+                a: *const Self,
+                lda: Int,
+                b: *mut Self,
+                ldb: Int,
+            ) {
+                ffi::$trsm(
+                    &(u8::from(side) as c_char),
+                    &(u8::from(uplo) as c_char),
+                    &(u8::from(transa) as c_char),
+                    &(u8::from(diag) as c_char),
+                    &m,
+                    &n,
+                    (&alpha as *const Self).cast(),
+                    a.cast(),
+                    &lda,
+                    b.cast(),
+                    &ldb,
+                )
+            }
+        }
+
+        impl Blas for $scalar {}
+    };
+}
+
+implement_raw!(
+    f32,
+    rot: srot_,
+    swap: sswap_,
+    scal: sscal_,
+    rscal: sscal_,
+    copy: scopy_,
+    axpy: saxpy_,
+    dot: ffi::sdot_,
+    dotc: ffi::sdot_,
+    nrm2: snrm2_,
+    asum: sasum_,
+    iamax: isamax_,
+    gemv: sgemv_,
+    gbmv: sgbmv_,
+    hemv: ssymv_,
+    hbmv: ssbmv_,
+    hpmv: sspmv_,
+    trmv: strmv_,
+    tbmv: stbmv_,
+    tpmv: stpmv_,
+    trsv: strsv_,
+    tbsv: stbsv_,
+    tpsv: stpsv_,
+    geru: sger_,
+    gerc: sger_,
+    her: ssyr_,
+    hpr: sspr_,
+    her2: ssyr2_,
+    hpr2: sspr2_,
+    gemm: sgemm_,
+    symm: ssymm_,
+    hemm: ssymm_,
+    syrk: ssyrk_,
+    herk: ssyrk_,
+    syr2k: ssyr2k_,
+    her2k: ssyr2k_,
+    trmm: strmm_,
+    trsm: strsm_,
+);
+
+implement_raw!(
+    f64,
+    rot: drot_,
+    swap: dswap_,
+    scal: dscal_,
+    rscal: dscal_,
+    copy: dcopy_,
+    axpy: daxpy_,
+    dot: ffi::ddot_,
+    dotc: ffi::ddot_,
+    nrm2: dnrm2_,
+    asum: dasum_,
+    iamax: idamax_,
+    gemv: dgemv_,
+    gbmv: dgbmv_,
+    hemv: dsymv_,
+    hbmv: dsbmv_,
+    hpmv: dspmv_,
+    trmv: dtrmv_,
+    tbmv: dtbmv_,
+    tpmv: dtpmv_,
+    trsv: dtrsv_,
+    tbsv: dtbsv_,
+    tpsv: dtpsv_,
+    geru: dger_,
+    gerc: dger_,
+    her: dsyr_,
+    hpr: dspr_,
+    her2: dsyr2_,
+    hpr2: dspr2_,
+    gemm: dgemm_,
+    symm: dsymm_,
+    hemm: dsymm_,
+    syrk: dsyrk_,
+    herk: dsyrk_,
+    syr2k: dsyr2k_,
+    her2k: dsyr2k_,
+    trmm: dtrmm_,
+    trsm: dtrsm_,
+);
+
+implement_raw!(
+    c32,
+    rot: csrot_,
+    swap: cswap_,
+    scal: cscal_,
+    rscal: csscal_,
+    copy: ccopy_,
+    axpy: caxpy_,
+    dot: crate::complex::cdotu,
+    dotc: crate::complex::cdotc,
+    nrm2: scnrm2_,
+    asum: scasum_,
+    iamax: icamax_,
+    gemv: cgemv_,
+    gbmv: cgbmv_,
+    hemv: chemv_,
+    hbmv: chbmv_,
+    hpmv: chpmv_,
+    trmv: ctrmv_,
+    tbmv: ctbmv_,
+    tpmv: ctpmv_,
+    trsv: ctrsv_,
+    tbsv: ctbsv_,
+    tpsv: ctpsv_,
+    geru: cgeru_,
+    gerc: cgerc_,
+    her: cher_,
+    hpr: chpr_,
+    her2: cher2_,
+    hpr2: chpr2_,
+    gemm: cgemm_,
+    symm: csymm_,
+    hemm: chemm_,
+    syrk: csyrk_,
+    herk: cherk_,
+    syr2k: csyr2k_,
+    her2k: cher2k_,
+    trmm: ctrmm_,
+    trsm: ctrsm_,
+);
+
+implement_raw!(
+    c64,
+    rot: zdrot_,
+    swap: zswap_,
+    scal: zscal_,
+    rscal: zdscal_,
+    copy: zcopy_,
+    axpy: zaxpy_,
+    dot: crate::complex::zdotu,
+    dotc: crate::complex::zdotc,
+    nrm2: dznrm2_,
+    asum: dzasum_,
+    iamax: izamax_,
+    gemv: zgemv_,
+    gbmv: zgbmv_,
+    hemv: zhemv_,
+    hbmv: zhbmv_,
+    hpmv: zhpmv_,
+    trmv: ztrmv_,
+    tbmv: ztbmv_,
+    tpmv: ztpmv_,
+    trsv: ztrsv_,
+    tbsv: ztbsv_,
+    tpsv: ztpsv_,
+    geru: zgeru_,
+    gerc: zgerc_,
+    her: zher_,
+    hpr: zhpr_,
+    her2: zher2_,
+    hpr2: zhpr2_,
+    gemm: zgemm_,
+    symm: zsymm_,
+    hemm: zhemm_,
+    syrk: zsyrk_,
+    herk: zherk_,
+    syr2k: zsyr2k_,
+    her2k: zher2k_,
+    trmm: ztrmm_,
+    trsm: ztrsm_,
+);
@@ -0,0 +1,1498 @@
+//! Declarations of the ILP64 interface, whose integers are 64 bits wide and whose symbols are
+//! suffixed with `64_`, as in the builds of OpenBLAS with `INTERFACE64=1 SYMBOLSUFFIX=64_`.
+//!
+//! The declarations are generated by `bin/generate.py --ilp64`.
+
+use core::ffi::{c_char, c_double, c_float};
+
+use crate::{c32, c64, Int};
+
+extern "C" {
+    #[link_name = "srotg_64_"]
+    pub fn srotg_(a: *mut c_float, b: *mut c_float, c: *mut c_float, s: *mut c_float);
+    #[link_name = "srotmg_64_"]
+    pub fn srotmg_(
+        d1: *mut c_float,
+        d2: *mut c_float,
+        x1: *mut c_float,
+        y1: *const c_float,
+        param: *mut c_float,
+    );
+    #[link_name = "srot_64_"]
+    pub fn srot_(
+        n: *const Int,
+        x: *mut c_float,
+        incx: *const Int,
+        y: *mut c_float,
+        incy: *const Int,
+        c: *const c_float,
+        s: *const c_float,
+    );
+    #[link_name = "srotm_64_"]
+    pub fn srotm_(
+        n: *const Int,
+        x: *mut c_float,
+        incx: *const Int,
+        y: *mut c_float,
+        incy: *const Int,
+        param: *const c_float,
+    );
+    #[link_name = "sswap_64_"]
+    pub fn sswap_(
+        n: *const Int,
+        x: *mut c_float,
+        incx: *const Int,
+        y: *mut c_float,
+        incy: *const Int,
+    );
+    #[link_name = "sscal_64_"]
+    pub fn sscal_(n: *const Int, a: *const c_float, x: *mut c_float, incx: *const Int);
+    #[link_name = "scopy_64_"]
+    pub fn scopy_(
+        n: *const Int,
+        x: *const c_float,
+        incx: *const Int,
+        y: *mut c_float,
+        incy: *const Int,
+    );
+    #[link_name = "saxpy_64_"]
+    pub fn saxpy_(
+        n: *const Int,
+        alpha: *const c_float,
+        x: *const c_float,
+        incx: *const Int,
+        y: *mut c_float,
+        incy: *const Int,
+    );
+    #[link_name = "sdot_64_"]
+    pub fn sdot_(
+        n: *const Int,
+        x: *const c_float,
+        incx: *const Int,
+        y: *const c_float,
+        incy: *const Int,
+    ) -> c_float;
+    #[link_name = "sdsdot_64_"]
+    pub fn sdsdot_(
+        n: *const Int,
+        sb: *const c_float,
+        x: *const c_float,
+        incx: *const Int,
+        y: *const c_float,
+        incy: *const Int,
+    ) -> c_float;
+    #[link_name = "snrm2_64_"]
+    pub fn snrm2_(n: *const Int, x: *const c_float, incx: *const Int) -> c_float;
+    #[link_name = "scnrm2_64_"]
+    pub fn scnrm2_(n: *const Int, x: *const c32, incx: *const Int) -> c_float;
+    #[link_name = "sasum_64_"]
+    pub fn sasum_(n: *const Int, x: *const c_float, incx: *const Int) -> c_float;
+    #[link_name = "isamax_64_"]
+    pub fn isamax_(n: *const Int, x: *const c_float, incx: *const Int) -> Int;
+    #[link_name = "drotg_64_"]
+    pub fn drotg_(a: *mut c_double, b: *mut c_double, c: *mut c_double, s: *mut c_double);
+    #[link_name = "drotmg_64_"]
+    pub fn drotmg_(
+        d1: *mut c_double,
+        d2: *mut c_double,
+        x1: *mut c_double,
+        y1: *const c_double,
+        param: *mut c_double,
+    );
+    #[link_name = "drot_64_"]
+    pub fn drot_(
+        n: *const Int,
+        x: *mut c_double,
+        incx: *const Int,
+        y: *mut c_double,
+        incy: *const Int,
+        c: *const c_double,
+        s: *const c_double,
+    );
+    #[link_name = "drotm_64_"]
+    pub fn drotm_(
+        n: *const Int,
+        x: *mut c_double,
+        incx: *const Int,
+        y: *mut c_double,
+        incy: *const Int,
+        param: *const c_double,
+    );
+    #[link_name = "dswap_64_"]
+    pub fn dswap_(
+        n: *const Int,
+        x: *mut c_double,
+        incx: *const Int,
+        y: *mut c_double,
+        incy: *const Int,
+    );
+    #[link_name = "dscal_64_"]
+    pub fn dscal_(n: *const Int, a: *const c_double, x: *mut c_double, incx: *const Int);
+    #[link_name = "dcopy_64_"]
+    pub fn dcopy_(
+        n: *const Int,
+        x: *const c_double,
+        incx: *const Int,
+        y: *mut c_double,
+        incy: *const Int,
+    );
+    #[link_name = "daxpy_64_"]
+    pub fn daxpy_(
+        n: *const Int,
+        alpha: *const c_double,
+        x: *const c_double,
+        incx: *const Int,
+        y: *mut c_double,
+        incy: *const Int,
+    );
+    #[link_name = "ddot_64_"]
+    pub fn ddot_(
+        n: *const Int,
+        x: *const c_double,
+        incx: *const Int,
+        y: *const c_double,
+        incy: *const Int,
+    ) -> c_double;
+    #[link_name = "dsdot_64_"]
+    pub fn dsdot_(
+        n: *const Int,
+        x: *const c_float,
+        incx: *const Int,
+        y: *const c_float,
+        incy: *const Int,
+    ) -> c_double;
+    #[link_name = "dnrm2_64_"]
+    pub fn dnrm2_(n: *const Int, x: *const c_double, incx: *const Int) -> c_double;
+    #[link_name = "dznrm2_64_"]
+    pub fn dznrm2_(n: *const Int, x: *const c64, incx: *const Int) -> c_double;
+    #[link_name = "dasum_64_"]
+    pub fn dasum_(n: *const Int, x: *const c_double, incx: *const Int) -> c_double;
+    #[link_name = "idamax_64_"]
+    pub fn idamax_(n: *const Int, x: *const c_double, incx: *const Int) -> Int;
+    #[link_name = "crotg_64_"]
+    pub fn crotg_(a: *mut c32, b: *const c32, c: *mut c_float, s: *mut c32);
+    #[link_name = "csrot_64_"]
+    pub fn csrot_(
+        n: *const Int,
+        x: *mut c32,
+        incx: *const Int,
+        y: *mut c32,
+        incy: *const Int,
+        c: *const c_float,
+        s: *const c_float,
+    );
+    #[link_name = "cswap_64_"]
+    pub fn cswap_(n: *const Int, x: *mut c32, incx: *const Int, y: *mut c32, incy: *const Int);
+    #[link_name = "cscal_64_"]
+    pub fn cscal_(n: *const Int, a: *const c32, x: *mut c32, incx: *const Int);
+    #[link_name = "csscal_64_"]
+    pub fn csscal_(n: *const Int, a: *const c_float, x: *mut c32, incx: *const Int);
+    #[link_name = "ccopy_64_"]
+    pub fn ccopy_(n: *const Int, x: *const c32, incx: *const Int, y: *mut c32, incy: *const Int);
+    #[link_name = "caxpy_64_"]
+    pub fn caxpy_(
+        n: *const Int,
+        alpha: *const c32,
+        x: *const c32,
+        incx: *const Int,
+        y: *mut c32,
+        incy: *const Int,
+    );
+    #[cfg(not(feature = "complex-return"))]
+    #[link_name = "cdotu_64_"]
+    pub fn cdotu_(
+        pres: *mut c32,
+        n: *const Int,
+        x: *const c32,
+        incx: *const Int,
+        y: *const c32,
+        incy: *const Int,
+    );
+    #[cfg(not(feature = "complex-return"))]
+    #[link_name = "cdotc_64_"]
+    pub fn cdotc_(
+        pres: *mut c32,
+        n: *const Int,
+        x: *const c32,
+        incx: *const Int,
+        y: *const c32,
+        incy: *const Int,
+    );
+    #[link_name = "scasum_64_"]
+    pub fn scasum_(n: *const Int, x: *const c32, incx: *const Int) -> c_float;
+    #[link_name = "icamax_64_"]
+    pub fn icamax_(n: *const Int, x: *const c32, incx: *const Int) -> Int;
+    #[link_name = "zrotg_64_"]
+    pub fn zrotg_(a: *mut c64, b: *const c64, c: *mut c_double, s: *mut c64);
+    #[link_name = "zdrot_64_"]
+    pub fn zdrot_(
+        n: *const Int,
+        x: *mut c64,
+        incx: *const Int,
+        y: *mut c64,
+        incy: *const Int,
+        c: *const c_double,
+        s: *const c_double,
+    );
+    #[link_name = "zswap_64_"]
+    pub fn zswap_(n: *const Int, x: *mut c64, incx: *const Int, y: *mut c64, incy: *const Int);
+    #[link_name = "zscal_64_"]
+    pub fn zscal_(n: *const Int, a: *const c64, x: *mut c64, incx: *const Int);
+    #[link_name = "zdscal_64_"]
+    pub fn zdscal_(n: *const Int, a: *const c_double, x: *mut c64, incx: *const Int);
+    #[link_name = "zcopy_64_"]
+    pub fn zcopy_(n: *const Int, x: *const c64, incx: *const Int, y: *mut c64, incy: *const Int);
+    #[link_name = "zaxpy_64_"]
+    pub fn zaxpy_(
+        n: *const Int,
+        alpha: *const c64,
+        x: *const c64,
+        incx: *const Int,
+        y: *mut c64,
+        incy: *const Int,
+    );
+    #[cfg(not(feature = "complex-return"))]
+    #[link_name = "zdotu_64_"]
+    pub fn zdotu_(
+        pres: *mut c64,
+        n: *const Int,
+        x: *const c64,
+        incx: *const Int,
+        y: *const c64,
+        incy: *const Int,
+    );
+    #[cfg(not(feature = "complex-return"))]
+    #[link_name = "zdotc_64_"]
+    pub fn zdotc_(
+        pres: *mut c64,
+        n: *const Int,
+        x: *const c64,
+        incx: *const Int,
+        y: *const c64,
+        incy: *const Int,
+    );
+    #[link_name = "dzasum_64_"]
+    pub fn dzasum_(n: *const Int, x: *const c64, incx: *const Int) -> c_double;
+    #[link_name = "izamax_64_"]
+    pub fn izamax_(n: *const Int, x: *const c64, incx: *const Int) -> Int;
+}
+
+extern "C" {
+    #[link_name = "sgemv_64_"]
+    pub fn sgemv_(
+        trans: *const c_char,
+        m: *const Int,
+        n: *const Int,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const Int,
+        x: *const c_float,
+        incx: *const Int,
+        beta: *const c_float,
+        y: *mut c_float,
+        incy: *const Int,
+    );
+    #[link_name = "sgbmv_64_"]
+    pub fn sgbmv_(
+        trans: *const c_char,
+        m: *const Int,
+        n: *const Int,
+        kl: *const Int,
+        ku: *const Int,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const Int,
+        x: *const c_float,
+        incx: *const Int,
+        beta: *const c_float,
+        y: *mut c_float,
+        incy: *const Int,
+    );
+    #[link_name = "ssymv_64_"]
+    pub fn ssymv_(
+        uplo: *const c_char,
+        n: *const Int,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const Int,
+        x: *const c_float,
+        incx: *const Int,
+        beta: *const c_float,
+        y: *mut c_float,
+        incy: *const Int,
+    );
+    #[link_name = "ssbmv_64_"]
+    pub fn ssbmv_(
+        uplo: *const c_char,
+        n: *const Int,
+        k: *const Int,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const Int,
+        x: *const c_float,
+        incx: *const Int,
+        beta: *const c_float,
+        y: *mut c_float,
+        incy: *const Int,
+    );
+    #[link_name = "sspmv_64_"]
+    pub fn sspmv_(
+        uplo: *const c_char,
+        n: *const Int,
+        alpha: *const c_float,
+        ap: *const c_float,
+        x: *const c_float,
+        incx: *const Int,
+        beta: *const c_float,
+        y: *mut c_float,
+        incy: *const Int,
+    );
+    #[link_name = "strmv_64_"]
+    pub fn strmv_(
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        n: *const Int,
+        a: *const c_float,
+        lda: *const Int,
+        b: *mut c_float,
+        incx: *const Int,
+    );
+    #[link_name = "stbmv_64_"]
+    pub fn stbmv_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const Int,
+        k: *const Int,
+        a: *const c_float,
+        lda: *const Int,
+        x: *mut c_float,
+        incx: *const Int,
+    );
+    #[link_name = "stpmv_64_"]
+    pub fn stpmv_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const Int,
+        ap: *const c_float,
+        x: *mut c_float,
+        incx: *const Int,
+    );
+    #[link_name = "strsv_64_"]
+    pub fn strsv_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const Int,
+        a: *const c_float,
+        lda: *const Int,
+        x: *mut c_float,
+        incx: *const Int,
+    );
+    #[link_name = "stbsv_64_"]
+    pub fn stbsv_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const Int,
+        k: *const Int,
+        a: *const c_float,
+        lda: *const Int,
+        x: *mut c_float,
+        incx: *const Int,
+    );
+    #[link_name = "stpsv_64_"]
+    pub fn stpsv_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const Int,
+        ap: *const c_float,
+        x: *mut c_float,
+        incx: *const Int,
+    );
+    #[link_name = "sger_64_"]
+    pub fn sger_(
+        m: *const Int,
+        n: *const Int,
+        alpha: *const c_float,
+        x: *const c_float,
+        incx: *const Int,
+        y: *const c_float,
+        incy: *const Int,
+        a: *mut c_float,
+        lda: *const Int,
+    );
+    #[link_name = "ssyr_64_"]
+    pub fn ssyr_(
+        uplo: *const c_char,
+        n: *const Int,
+        alpha: *const c_float,
+        x: *const c_float,
+        incx: *const Int,
+        a: *mut c_float,
+        lda: *const Int,
+    );
+    #[link_name = "sspr_64_"]
+    pub fn sspr_(
+        uplo: *const c_char,
+        n: *const Int,
+        alpha: *const c_float,
+        x: *const c_float,
+        incx: *const Int,
+        ap: *mut c_float,
+    );
+    #[link_name = "ssyr2_64_"]
+    pub fn ssyr2_(
+        uplo: *const c_char,
+        n: *const Int,
+        alpha: *const c_float,
+        x: *const c_float,
+        incx: *const Int,
+        y: *const c_float,
+        incy: *const Int,
+        a: *mut c_float,
+        lda: *const Int,
+    );
+    #[link_name = "sspr2_64_"]
+    pub fn sspr2_(
+        uplo: *const c_char,
+        n: *const Int,
+        alpha: *const c_float,
+        x: *const c_float,
+        incx: *const Int,
+        y: *const c_float,
+        incy: *const Int,
+        ap: *mut c_float,
+    );
+    #[link_name = "dgemv_64_"]
+    pub fn dgemv_(
+        trans: *const c_char,
+        m: *const Int,
+        n: *const Int,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const Int,
+        x: *const c_double,
+        incx: *const Int,
+        beta: *const c_double,
+        y: *mut c_double,
+        incy: *const Int,
+    );
+    #[link_name = "dgbmv_64_"]
+    pub fn dgbmv_(
+        trans: *const c_char,
+        m: *const Int,
+        n: *const Int,
+        kl: *const Int,
+        ku: *const Int,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const Int,
+        x: *const c_double,
+        incx: *const Int,
+        beta: *const c_double,
+        y: *mut c_double,
+        incy: *const Int,
+    );
+    #[link_name = "dsymv_64_"]
+    pub fn dsymv_(
+        uplo: *const c_char,
+        n: *const Int,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const Int,
+        x: *const c_double,
+        incx: *const Int,
+        beta: *const c_double,
+        y: *mut c_double,
+        incy: *const Int,
+    );
+    #[link_name = "dsbmv_64_"]
+    pub fn dsbmv_(
+        uplo: *const c_char,
+        n: *const Int,
+        k: *const Int,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const Int,
+        x: *const c_double,
+        incx: *const Int,
+        beta: *const c_double,
+        y: *mut c_double,
+        incy: *const Int,
+    );
+    #[link_name = "dspmv_64_"]
+    pub fn dspmv_(
+        uplo: *const c_char,
+        n: *const Int,
+        alpha: *const c_double,
+        ap: *const c_double,
+        x: *const c_double,
+        incx: *const Int,
+        beta: *const c_double,
+        y: *mut c_double,
+        incy: *const Int,
+    );
+    #[link_name = "dtrmv_64_"]
+    pub fn dtrmv_(
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        n: *const Int,
+        a: *const c_double,
+        lda: *const Int,
+        b: *mut c_double,
+        incx: *const Int,
+    );
+    #[link_name = "dtbmv_64_"]
+    pub fn dtbmv_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const Int,
+        k: *const Int,
+        a: *const c_double,
+        lda: *const Int,
+        x: *mut c_double,
+        incx: *const Int,
+    );
+    #[link_name = "dtpmv_64_"]
+    pub fn dtpmv_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const Int,
+        ap: *const c_double,
+        x: *mut c_double,
+        incx: *const Int,
+    );
+    #[link_name = "dtrsv_64_"]
+    pub fn dtrsv_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const Int,
+        a: *const c_double,
+        lda: *const Int,
+        x: *mut c_double,
+        incx: *const Int,
+    );
+    #[link_name = "dtbsv_64_"]
+    pub fn dtbsv_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const Int,
+        k: *const Int,
+        a: *const c_double,
+        lda: *const Int,
+        x: *mut c_double,
+        incx: *const Int,
+    );
+    #[link_name = "dtpsv_64_"]
+    pub fn dtpsv_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const Int,
+        ap: *const c_double,
+        x: *mut c_double,
+        incx: *const Int,
+    );
+    #[link_name = "dger_64_"]
+    pub fn dger_(
+        m: *const Int,
+        n: *const Int,
+        alpha: *const c_double,
+        x: *const c_double,
+        incx: *const Int,
+        y: *const c_double,
+        incy: *const Int,
+        a: *mut c_double,
+        lda: *const Int,
+    );
+    #[link_name = "dsyr_64_"]
+    pub fn dsyr_(
+        uplo: *const c_char,
+        n: *const Int,
+        alpha: *const c_double,
+        x: *const c_double,
+        incx: *const Int,
+        a: *mut c_double,
+        lda: *const Int,
+    );
+    #[link_name = "dspr_64_"]
+    pub fn dspr_(
+        uplo: *const c_char,
+        n: *const Int,
+        alpha: *const c_double,
+        x: *const c_double,
+        incx: *const Int,
+        ap: *mut c_double,
+    );
+    #[link_name = "dsyr2_64_"]
+    pub fn dsyr2_(
+        uplo: *const c_char,
+        n: *const Int,
+        alpha: *const c_double,
+        x: *const c_double,
+        incx: *const Int,
+        y: *const c_double,
+        incy: *const Int,
+        a: *mut c_double,
+        lda: *const Int,
+    );
+    #[link_name = "dspr2_64_"]
+    pub fn dspr2_(
+        uplo: *const c_char,
+        n: *const Int,
+        alpha: *const c_double,
+        x: *const c_double,
+        incx: *const Int,
+        y: *const c_double,
+        incy: *const Int,
+        ap: *mut c_double,
+    );
+    #[link_name = "cgemv_64_"]
+    pub fn cgemv_(
+        trans: *const c_char,
+        m: *const Int,
+        n: *const Int,
+        alpha: *const c32,
+        a: *const c32,
+        lda: *const Int,
+        x: *const c32,
+        incx: *const Int,
+        beta: *const c32,
+        y: *mut c32,
+        incy: *const Int,
+    );
+    #[link_name = "cgbmv_64_"]
+    pub fn cgbmv_(
+        trans: *const c_char,
+        m: *const Int,
+        n: *const Int,
+        kl: *const Int,
+        ku: *const Int,
+        alpha: *const c32,
+        a: *const c32,
+        lda: *const Int,
+        x: *const c32,
+        incx: *const Int,
+        beta: *const c32,
+        y: *mut c32,
+        incy: *const Int,
+    );
+    #[link_name = "chemv_64_"]
+    pub fn chemv_(
+        uplo: *const c_char,
+        n: *const Int,
+        alpha: *const c32,
+        a: *const c32,
+        lda: *const Int,
+        x: *const c32,
+        incx: *const Int,
+        beta: *const c32,
+        y: *mut c32,
+        incy: *const Int,
+    );
+    #[link_name = "chbmv_64_"]
+    pub fn chbmv_(
+        uplo: *const c_char,
+        n: *const Int,
+        k: *const Int,
+        alpha: *const c32,
+        a: *const c32,
+        lda: *const Int,
+        x: *const c32,
+        incx: *const Int,
+        beta: *const c32,
+        y: *mut c32,
+        incy: *const Int,
+    );
+    #[link_name = "chpmv_64_"]
+    pub fn chpmv_(
+        uplo: *const c_char,
+        n: *const Int,
+        alpha: *const c32,
+        ap: *const c32,
+        x: *const c32,
+        incx: *const Int,
+        beta: *const c32,
+        y: *mut c32,
+        incy: *const Int,
+    );
+    #[link_name = "ctrmv_64_"]
+    pub fn ctrmv_(
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        n: *const Int,
+        a: *const c32,
+        lda: *const Int,
+        b: *mut c32,
+        incx: *const Int,
+    );
+    #[link_name = "ctbmv_64_"]
+    pub fn ctbmv_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const Int,
+        k: *const Int,
+        a: *const c32,
+        lda: *const Int,
+        x: *mut c32,
+        incx: *const Int,
+    );
+    #[link_name = "ctpmv_64_"]
+    pub fn ctpmv_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const Int,
+        ap: *const c32,
+        x: *mut c32,
+        incx: *const Int,
+    );
+    #[link_name = "ctrsv_64_"]
+    pub fn ctrsv_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const Int,
+        a: *const c32,
+        lda: *const Int,
+        x: *mut c32,
+        incx: *const Int,
+    );
+    #[link_name = "ctbsv_64_"]
+    pub fn ctbsv_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const Int,
+        k: *const Int,
+        a: *const c32,
+        lda: *const Int,
+        x: *mut c32,
+        incx: *const Int,
+    );
+    #[link_name = "ctpsv_64_"]
+    pub fn ctpsv_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const Int,
+        ap: *const c32,
+        x: *mut c32,
+        incx: *const Int,
+    );
+    #[link_name = "cgeru_64_"]
+    pub fn cgeru_(
+        m: *const Int,
+        n: *const Int,
+        alpha: *const c32,
+        x: *const c32,
+        incx: *const Int,
+        y: *const c32,
+        incy: *const Int,
+        a: *mut c32,
+        lda: *const Int,
+    );
+    #[link_name = "cgerc_64_"]
+    pub fn cgerc_(
+        m: *const Int,
+        n: *const Int,
+        alpha: *const c32,
+        x: *const c32,
+        incx: *const Int,
+        y: *const c32,
+        incy: *const Int,
+        a: *mut c32,
+        lda: *const Int,
+    );
+    #[link_name = "cher_64_"]
+    pub fn cher_(
+        uplo: *const c_char,
+        n: *const Int,
+        alpha: *const c_float,
+        x: *const c32,
+        incx: *const Int,
+        a: *mut c32,
+        lda: *const Int,
+    );
+    #[link_name = "chpr_64_"]
+    pub fn chpr_(
+        uplo: *const c_char,
+        n: *const Int,
+        alpha: *const c_float,
+        x: *const c32,
+        incx: *const Int,
+        ap: *mut c32,
+    );
+    #[link_name = "chpr2_64_"]
+    pub fn chpr2_(
+        uplo: *const c_char,
+        n: *const Int,
+        alpha: *const c32,
+        x: *const c32,
+        incx: *const Int,
+        y: *const c32,
+        incy: *const Int,
+        ap: *mut c32,
+    );
+    #[link_name = "cher2_64_"]
+    pub fn cher2_(
+        uplo: *const c_char,
+        n: *const Int,
+        alpha: *const c32,
+        x: *const c32,
+        incx: *const Int,
+        y: *const c32,
+        incy: *const Int,
+        a: *mut c32,
+        lda: *const Int,
+    );
+    #[link_name = "zgemv_64_"]
+    pub fn zgemv_(
+        trans: *const c_char,
+        m: *const Int,
+        n: *const Int,
+        alpha: *const c64,
+        a: *const c64,
+        lda: *const Int,
+        x: *const c64,
+        incx: *const Int,
+        beta: *const c64,
+        y: *mut c64,
+        incy: *const Int,
+    );
+    #[link_name = "zgbmv_64_"]
+    pub fn zgbmv_(
+        trans: *const c_char,
+        m: *const Int,
+        n: *const Int,
+        kl: *const Int,
+        ku: *const Int,
+        alpha: *const c64,
+        a: *const c64,
+        lda: *const Int,
+        x: *const c64,
+        incx: *const Int,
+        beta: *const c64,
+        y: *mut c64,
+        incy: *const Int,
+    );
+    #[link_name = "zhemv_64_"]
+    pub fn zhemv_(
+        uplo: *const c_char,
+        n: *const Int,
+        alpha: *const c64,
+        a: *const c64,
+        lda: *const Int,
+        x: *const c64,
+        incx: *const Int,
+        beta: *const c64,
+        y: *mut c64,
+        incy: *const Int,
+    );
+    #[link_name = "zhbmv_64_"]
+    pub fn zhbmv_(
+        uplo: *const c_char,
+        n: *const Int,
+        k: *const Int,
+        alpha: *const c64,
+        a: *const c64,
+        lda: *const Int,
+        x: *const c64,
+        incx: *const Int,
+        beta: *const c64,
+        y: *mut c64,
+        incy: *const Int,
+    );
+    #[link_name = "zhpmv_64_"]
+    pub fn zhpmv_(
+        uplo: *const c_char,
+        n: *const Int,
+        alpha: *const c64,
+        ap: *const c64,
+        x: *const c64,
+        incx: *const Int,
+        beta: *const c64,
+        y: *mut c64,
+        incy: *const Int,
+    );
+    #[link_name = "ztrmv_64_"]
+    pub fn ztrmv_(
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        n: *const Int,
+        a: *const c64,
+        lda: *const Int,
+        b: *mut c64,
+        incx: *const Int,
+    );
+    #[link_name = "ztbmv_64_"]
+    pub fn ztbmv_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const Int,
+        k: *const Int,
+        a: *const c64,
+        lda: *const Int,
+        x: *mut c64,
+        incx: *const Int,
+    );
+    #[link_name = "ztpmv_64_"]
+    pub fn ztpmv_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const Int,
+        ap: *const c64,
+        x: *mut c64,
+        incx: *const Int,
+    );
+    #[link_name = "ztrsv_64_"]
+    pub fn ztrsv_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const Int,
+        a: *const c64,
+        lda: *const Int,
+        x: *mut c64,
+        incx: *const Int,
+    );
+    #[link_name = "ztbsv_64_"]
+    pub fn ztbsv_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const Int,
+        k: *const Int,
+        a: *const c64,
+        lda: *const Int,
+        x: *mut c64,
+        incx: *const Int,
+    );
+    #[link_name = "ztpsv_64_"]
+    pub fn ztpsv_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const Int,
+        ap: *const c64,
+        x: *mut c64,
+        incx: *const Int,
+    );
+    #[link_name = "zgeru_64_"]
+    pub fn zgeru_(
+        m: *const Int,
+        n: *const Int,
+        alpha: *const c64,
+        x: *const c64,
+        incx: *const Int,
+        y: *const c64,
+        incy: *const Int,
+        a: *mut c64,
+        lda: *const Int,
+    );
+    #[link_name = "zgerc_64_"]
+    pub fn zgerc_(
+        m: *const Int,
+        n: *const Int,
+        alpha: *const c64,
+        x: *const c64,
+        incx: *const Int,
+        y: *const c64,
+        incy: *const Int,
+        a: *mut c64,
+        lda: *const Int,
+    );
+    #[link_name = "zher_64_"]
+    pub fn zher_(
+        uplo: *const c_char,
+        n: *const Int,
+        alpha: *const c_double,
+        x: *const c64,
+        incx: *const Int,
+        a: *mut c64,
+        lda: *const Int,
+    );
+    #[link_name = "zhpr_64_"]
+    pub fn zhpr_(
+        uplo: *const c_char,
+        n: *const Int,
+        alpha: *const c_double,
+        x: *const c64,
+        incx: *const Int,
+        ap: *mut c64,
+    );
+    #[link_name = "zher2_64_"]
+    pub fn zher2_(
+        uplo: *const c_char,
+        n: *const Int,
+        alpha: *const c64,
+        x: *const c64,
+        incx: *const Int,
+        y: *const c64,
+        incy: *const Int,
+        a: *mut c64,
+        lda: *const Int,
+    );
+    #[link_name = "zhpr2_64_"]
+    pub fn zhpr2_(
+        uplo: *const c_char,
+        n: *const Int,
+        alpha: *const c64,
+        x: *const c64,
+        incx: *const Int,
+        y: *const c64,
+        incy: *const Int,
+        ap: *mut c64,
+    );
+}
+
+extern "C" {
+    #[link_name = "sgemm_64_"]
+    pub fn sgemm_(
+        transa: *const c_char,
+        transb: *const c_char,
+        m: *const Int,
+        n: *const Int,
+        k: *const Int,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const Int,
+        b: *const c_float,
+        ldb: *const Int,
+        beta: *const c_float,
+        c: *mut c_float,
+        ldc: *const Int,
+    );
+    #[link_name = "ssymm_64_"]
+    pub fn ssymm_(
+        side: *const c_char,
+        uplo: *const c_char,
+        m: *const Int,
+        n: *const Int,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const Int,
+        b: *const c_float,
+        ldb: *const Int,
+        beta: *const c_float,
+        c: *mut c_float,
+        ldc: *const Int,
+    );
+    #[link_name = "ssyrk_64_"]
+    pub fn ssyrk_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const Int,
+        k: *const Int,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const Int,
+        beta: *const c_float,
+        c: *mut c_float,
+        ldc: *const Int,
+    );
+    #[link_name = "ssyr2k_64_"]
+    pub fn ssyr2k_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const Int,
+        k: *const Int,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const Int,
+        b: *const c_float,
+        ldb: *const Int,
+        beta: *const c_float,
+        c: *mut c_float,
+        ldc: *const Int,
+    );
+    #[link_name = "strmm_64_"]
+    pub fn strmm_(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const Int,
+        n: *const Int,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const Int,
+        b: *mut c_float,
+        ldb: *const Int,
+    );
+    #[link_name = "strsm_64_"]
+    pub fn strsm_(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const Int,
+        n: *const Int,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const Int,
+        b: *mut c_float,
+        ldb: *const Int,
+    );
+    #[link_name = "dgemm_64_"]
+    pub fn dgemm_(
+        transa: *const c_char,
+        transb: *const c_char,
+        m: *const Int,
+        n: *const Int,
+        k: *const Int,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const Int,
+        b: *const c_double,
+        ldb: *const Int,
+        beta: *const c_double,
+        c: *mut c_double,
+        ldc: *const Int,
+    );
+    #[link_name = "dsymm_64_"]
+    pub fn dsymm_(
+        side: *const c_char,
+        uplo: *const c_char,
+        m: *const Int,
+        n: *const Int,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const Int,
+        b: *const c_double,
+        ldb: *const Int,
+        beta: *const c_double,
+        c: *mut c_double,
+        ldc: *const Int,
+    );
+    #[link_name = "dsyrk_64_"]
+    pub fn dsyrk_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const Int,
+        k: *const Int,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const Int,
+        beta: *const c_double,
+        c: *mut c_double,
+        ldc: *const Int,
+    );
+    #[link_name = "dsyr2k_64_"]
+    pub fn dsyr2k_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const Int,
+        k: *const Int,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const Int,
+        b: *const c_double,
+        ldb: *const Int,
+        beta: *const c_double,
+        c: *mut c_double,
+        ldc: *const Int,
+    );
+    #[link_name = "dtrmm_64_"]
+    pub fn dtrmm_(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const Int,
+        n: *const Int,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const Int,
+        b: *mut c_double,
+        ldb: *const Int,
+    );
+    #[link_name = "dtrsm_64_"]
+    pub fn dtrsm_(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const Int,
+        n: *const Int,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const Int,
+        b: *mut c_double,
+        ldb: *const Int,
+    );
+    #[link_name = "cgemm_64_"]
+    pub fn cgemm_(
+        transa: *const c_char,
+        transb: *const c_char,
+        m: *const Int,
+        n: *const Int,
+        k: *const Int,
+        alpha: *const c32,
+        a: *const c32,
+        lda: *const Int,
+        b: *const c32,
+        ldb: *const Int,
+        beta: *const c32,
+        c: *mut c32,
+        ldc: *const Int,
+    );
+    #[link_name = "csymm_64_"]
+    pub fn csymm_(
+        side: *const c_char,
+        uplo: *const c_char,
+        m: *const Int,
+        n: *const Int,
+        alpha: *const c32,
+        a: *const c32,
+        lda: *const Int,
+        b: *const c32,
+        ldb: *const Int,
+        beta: *const c32,
+        c: *mut c32,
+        ldc: *const Int,
+    );
+    #[link_name = "chemm_64_"]
+    pub fn chemm_(
+        side: *const c_char,
+        uplo: *const c_char,
+        m: *const Int,
+        n: *const Int,
+        alpha: *const c32,
+        a: *const c32,
+        lda: *const Int,
+        b: *const c32,
+        ldb: *const Int,
+        beta: *const c32,
+        c: *mut c32,
+        ldc: *const Int,
+    );
+    #[link_name = "csyrk_64_"]
+    pub fn csyrk_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const Int,
+        k: *const Int,
+        alpha: *const c32,
+        a: *const c32,
+        lda: *const Int,
+        beta: *const c32,
+        c: *mut c32,
+        ldc: *const Int,
+    );
+    #[link_name = "cherk_64_"]
+    pub fn cherk_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const Int,
+        k: *const Int,
+        alpha: *const c_float,
+        a: *const c32,
+        lda: *const Int,
+        beta: *const c_float,
+        c: *mut c32,
+        ldc: *const Int,
+    );
+    #[link_name = "csyr2k_64_"]
+    pub fn csyr2k_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const Int,
+        k: *const Int,
+        alpha: *const c32,
+        a: *const c32,
+        lda: *const Int,
+        b: *const c32,
+        ldb: *const Int,
+        beta: *const c32,
+        c: *mut c32,
+        ldc: *const Int,
+    );
+    #[link_name = "cher2k_64_"]
+    pub fn cher2k_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const Int,
+        k: *const Int,
+        alpha: *const c32,
+        a: *const c32,
+        lda: *const Int,
+        b: *const c32,
+        ldb: *const Int,
+        beta: *const c_float,
+        c: *mut c32,
+        ldc: *const Int,
+    );
+    #[link_name = "ctrmm_64_"]
+    pub fn ctrmm_(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const Int,
+        n: *const Int,
+        alpha: *const c32,
+        a: *const c32,
+        lda: *const Int,
+        b: *mut c32,
+        ldb: *const Int,
+    );
+    #[link_name = "ctrsm_64_"]
+    pub fn ctrsm_(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const Int,
+        n: *const Int,
+        alpha: *const c32,
+        a: *const c32,
+        lda: *const Int,
+        b: *mut c32,
+        ldb: *const Int,
+    );
+    #[link_name = "zgemm_64_"]
+    pub fn zgemm_(
+        transa: *const c_char,
+        transb: *const c_char,
+        m: *const Int,
+        n: *const Int,
+        k: *const Int,
+        alpha: *const c64,
+        a: *const c64,
+        lda: *const Int,
+        b: *const c64,
+        ldb: *const Int,
+        beta: *const c64,
+        c: *mut c64,
+        ldc: *const Int,
+    );
+    #[link_name = "zsymm_64_"]
+    pub fn zsymm_(
+        side: *const c_char,
+        uplo: *const c_char,
+        m: *const Int,
+        n: *const Int,
+        alpha: *const c64,
+        a: *const c64,
+        lda: *const Int,
+        b: *const c64,
+        ldb: *const Int,
+        beta: *const c64,
+        c: *mut c64,
+        ldc: *const Int,
+    );
+    #[link_name = "zhemm_64_"]
+    pub fn zhemm_(
+        side: *const c_char,
+        uplo: *const c_char,
+        m: *const Int,
+        n: *const Int,
+        alpha: *const c64,
+        a: *const c64,
+        lda: *const Int,
+        b: *const c64,
+        ldb: *const Int,
+        beta: *const c64,
+        c: *mut c64,
+        ldc: *const Int,
+    );
+    #[link_name = "zsyrk_64_"]
+    pub fn zsyrk_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const Int,
+        k: *const Int,
+        alpha: *const c64,
+        a: *const c64,
+        lda: *const Int,
+        beta: *const c64,
+        c: *mut c64,
+        ldc: *const Int,
+    );
+    #[link_name = "zherk_64_"]
+    pub fn zherk_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const Int,
+        k: *const Int,
+        alpha: *const c_double,
+        a: *const c64,
+        lda: *const Int,
+        beta: *const c_double,
+        c: *mut c64,
+        ldc: *const Int,
+    );
+    #[link_name = "zsyr2k_64_"]
+    pub fn zsyr2k_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const Int,
+        k: *const Int,
+        alpha: *const c64,
+        a: *const c64,
+        lda: *const Int,
+        b: *const c64,
+        ldb: *const Int,
+        beta: *const c64,
+        c: *mut c64,
+        ldc: *const Int,
+    );
+    #[link_name = "zher2k_64_"]
+    pub fn zher2k_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const Int,
+        k: *const Int,
+        alpha: *const c64,
+        a: *const c64,
+        lda: *const Int,
+        b: *const c64,
+        ldb: *const Int,
+        beta: *const c_double,
+        c: *mut c64,
+        ldc: *const Int,
+    );
+    #[link_name = "ztrmm_64_"]
+    pub fn ztrmm_(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const Int,
+        n: *const Int,
+        alpha: *const c64,
+        a: *const c64,
+        lda: *const Int,
+        b: *mut c64,
+        ldb: *const Int,
+    );
+    #[link_name = "ztrsm_64_"]
+    pub fn ztrsm_(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const Int,
+        n: *const Int,
+        alpha: *const c64,
+        a: *const c64,
+        lda: *const Int,
+        b: *mut c64,
+        ldb: *const Int,
+    );
+}
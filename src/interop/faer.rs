@@ -0,0 +1,220 @@
+//! Extension methods and conversions for matrices of [`faer`].
+//!
+//! The methods and functions accept owned matrices, views, and references to them alike.
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use blas::interop::faer::MatrixExt;
+//! use faer::{mat, Mat};
+//!
+//! let a = mat![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+//! let b = Mat::<f64>::ones(3, 4);
+//! let mut c = Mat::zeros(2, 4);
+//!
+//! a.blas_gemm(&b, &mut c).unwrap();
+//! assert_eq!(c[(1, 3)], 15.0);
+//! ```
+//!
+//! [`faer`]: https://docs.rs/faer
+
+use faer::col::{AsColMut, AsColRef};
+use faer::mat::{AsMatMut, AsMatRef};
+
+use super::Strided;
+use crate::safe::BlasError;
+use crate::view::{MatMut, MatRef, VecMut, VecRef};
+use crate::{Blas, Diag, Side, Uplo};
+
+/// Extension methods for matrices.
+pub trait MatrixExt<T: Blas> {
+    /// Compute `C := A * B`.
+    fn blas_gemm<B, C>(&self, b: B, c: C) -> Result<(), BlasError>
+    where
+        B: AsMatRef<T = T, Rows = usize, Cols = usize>,
+        C: AsMatMut<T = T, Rows = usize, Cols = usize>;
+
+    /// Compute `C := alpha * A * B + beta * C`.
+    fn blas_gemm_scaled<B, C>(&self, alpha: T, b: B, beta: T, c: C) -> Result<(), BlasError>
+    where
+        B: AsMatRef<T = T, Rows = usize, Cols = usize>,
+        C: AsMatMut<T = T, Rows = usize, Cols = usize>;
+
+    /// Compute `y := A * x`.
+    fn blas_gemv<X, Y>(&self, x: X, y: Y) -> Result<(), BlasError>
+    where
+        X: AsColRef<T = T, Rows = usize>,
+        Y: AsColMut<T = T, Rows = usize>;
+
+    /// Compute `y := alpha * A * x + beta * y`.
+    fn blas_gemv_scaled<X, Y>(&self, alpha: T, x: X, beta: T, y: Y) -> Result<(), BlasError>
+    where
+        X: AsColRef<T = T, Rows = usize>,
+        Y: AsColMut<T = T, Rows = usize>;
+
+    /// Solve `A * X = alpha * B` or `X * A = alpha * B`, where `A` is triangular and `b` holds
+    /// `B` on entry and `X` on exit.
+    fn blas_trsm<B>(
+        &self,
+        side: Side,
+        uplo: Uplo,
+        diag: Diag,
+        alpha: T,
+        b: B,
+    ) -> Result<(), BlasError>
+    where
+        B: AsMatMut<T = T, Rows = usize, Cols = usize>;
+
+    /// Compute `C := alpha * A * A^T + beta * C`, where only the triangle `uplo` of `C` is
+    /// referenced.
+    fn blas_syrk<C>(&self, uplo: Uplo, alpha: T, beta: T, c: C) -> Result<(), BlasError>
+    where
+        C: AsMatMut<T = T, Rows = usize, Cols = usize>;
+}
+
+impl<T, M> MatrixExt<T> for M
+where
+    T: Blas,
+    M: AsMatRef<T = T, Rows = usize, Cols = usize>,
+{
+    #[inline]
+    fn blas_gemm<B, C>(&self, b: B, mut c: C) -> Result<(), BlasError>
+    where
+        B: AsMatRef<T = T, Rows = usize, Cols = usize>,
+        C: AsMatMut<T = T, Rows = usize, Cols = usize>,
+    {
+        let (a, b, c) = (matrix(self), matrix(&b), matrix_mut(&mut c));
+        unsafe { super::gemm(("blas_gemm", [1, 2]), a, T::one(), b, T::zero(), c) }
+    }
+
+    #[inline]
+    fn blas_gemm_scaled<B, C>(&self, alpha: T, b: B, beta: T, mut c: C) -> Result<(), BlasError>
+    where
+        B: AsMatRef<T = T, Rows = usize, Cols = usize>,
+        C: AsMatMut<T = T, Rows = usize, Cols = usize>,
+    {
+        let (a, b, c) = (matrix(self), matrix(&b), matrix_mut(&mut c));
+        unsafe { super::gemm(("blas_gemm_scaled", [2, 4]), a, alpha, b, beta, c) }
+    }
+
+    #[inline]
+    fn blas_gemv<X, Y>(&self, x: X, mut y: Y) -> Result<(), BlasError>
+    where
+        X: AsColRef<T = T, Rows = usize>,
+        Y: AsColMut<T = T, Rows = usize>,
+    {
+        let (a, x, y) = (matrix(self), vector(&x), vector_mut(&mut y));
+        unsafe { super::gemv(("blas_gemv", [1, 2]), a, T::one(), x, T::zero(), y) }
+    }
+
+    #[inline]
+    fn blas_gemv_scaled<X, Y>(&self, alpha: T, x: X, beta: T, mut y: Y) -> Result<(), BlasError>
+    where
+        X: AsColRef<T = T, Rows = usize>,
+        Y: AsColMut<T = T, Rows = usize>,
+    {
+        let (a, x, y) = (matrix(self), vector(&x), vector_mut(&mut y));
+        unsafe { super::gemv(("blas_gemv_scaled", [2, 4]), a, alpha, x, beta, y) }
+    }
+
+    #[inline]
+    fn blas_trsm<B>(
+        &self,
+        side: Side,
+        uplo: Uplo,
+        diag: Diag,
+        alpha: T,
+        mut b: B,
+    ) -> Result<(), BlasError>
+    where
+        B: AsMatMut<T = T, Rows = usize, Cols = usize>,
+    {
+        let (a, b) = (matrix(self), matrix_mut(&mut b));
+        unsafe { super::trsm(("blas_trsm", 5), side, uplo, diag, alpha, a, b) }
+    }
+
+    #[inline]
+    fn blas_syrk<C>(&self, uplo: Uplo, alpha: T, beta: T, mut c: C) -> Result<(), BlasError>
+    where
+        C: AsMatMut<T = T, Rows = usize, Cols = usize>,
+    {
+        let (a, c) = (matrix(self), matrix_mut(&mut c));
+        unsafe { super::syrk(("blas_syrk", 4), uplo, alpha, a, beta, c) }
+    }
+}
+
+/// Return a view of a matrix if its columns are contiguous.
+#[inline]
+pub fn mat_ref<T, M>(a: &M) -> Option<MatRef<'_, T>>
+where
+    M: AsMatRef<T = T, Rows = usize, Cols = usize>,
+{
+    unsafe { super::mat_ref(matrix(a)) }
+}
+
+/// Return a mutable view of a matrix if its columns are contiguous.
+#[inline]
+pub fn mat_mut<T, M>(a: &mut M) -> Option<MatMut<'_, T>>
+where
+    M: AsMatMut<T = T, Rows = usize, Cols = usize>,
+{
+    unsafe { super::mat_mut(matrix_mut(a)) }
+}
+
+/// Return a view of a vector if its stride is not zero.
+#[inline]
+pub fn vec_ref<T, X>(x: &X) -> Option<VecRef<'_, T>>
+where
+    X: AsColRef<T = T, Rows = usize>,
+{
+    unsafe { super::vec_ref(&vector(x)) }
+}
+
+/// Return a mutable view of a vector if its stride is not zero.
+#[inline]
+pub fn vec_mut<T, X>(x: &mut X) -> Option<VecMut<'_, T>>
+where
+    X: AsColMut<T = T, Rows = usize>,
+{
+    unsafe { super::vec_mut(&vector_mut(x)) }
+}
+
+fn matrix<T, M>(a: &M) -> Strided<T>
+where
+    M: AsMatRef<T = T, Rows = usize, Cols = usize>,
+{
+    let a = a.as_mat_ref();
+    Strided::matrix(
+        a.as_ptr().cast_mut(),
+        (a.nrows(), a.ncols()),
+        (a.row_stride(), a.col_stride()),
+    )
+}
+
+fn matrix_mut<T, M>(a: &mut M) -> Strided<T>
+where
+    M: AsMatMut<T = T, Rows = usize, Cols = usize>,
+{
+    let a = a.as_mat_mut();
+    Strided::matrix(
+        a.as_ptr_mut(),
+        (a.nrows(), a.ncols()),
+        (a.row_stride(), a.col_stride()),
+    )
+}
+
+fn vector<T, X>(x: &X) -> Strided<T>
+where
+    X: AsColRef<T = T, Rows = usize>,
+{
+    let x = x.as_col_ref();
+    Strided::vector(x.as_ptr().cast_mut(), x.nrows(), x.row_stride())
+}
+
+fn vector_mut<T, X>(x: &mut X) -> Strided<T>
+where
+    X: AsColMut<T = T, Rows = usize>,
+{
+    let x = x.as_col_mut();
+    Strided::vector(x.as_ptr_mut(), x.nrows(), x.row_stride())
+}
@@ -0,0 +1,443 @@
+//! Integration with other linear-algebra packages.
+//!
+//! Each submodule is enabled by the feature of the same name and provides extension methods that
+//! pass the matrices and vectors of a package to BLAS. The `faer` and `nalgebra` submodules also
+//! provide functions that convert them into [views](crate::view).
+//!
+//! The extension methods do not copy their arguments whenever their layout allows it. A matrix
+//! whose columns are contiguous and a nonnegative number of elements apart, which is at least
+//! the number of rows, is passed in place with that distance as its leading dimension, as are
+//! views of part of a larger matrix. So is a matrix whose rows are contiguous, as the transpose of
+//! a column-major matrix. A vector is passed in place with its stride as the increment unless the
+//! stride is zero. Arguments with any other layout are copied into column-major storage first,
+//! and so is the result, which is then written back. The conversions into views succeed for the
+//! matrices that are passed in place without being transposed and for the vectors that are
+//! passed in place.
+//!
+//! The errors of the extension methods number the parameters from one, the receiver being
+//! parameter zero.
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use crate::safe::BlasError;
+use crate::view::{self, check_match, MatMut, MatRef, VecMut, VecRef};
+use crate::{Blas, Int, Transpose};
+#[cfg(any(feature = "faer", feature = "nalgebra"))]
+use crate::{Diag, NoneOrTrans, Side, Uplo};
+
+#[cfg(feature = "faer")]
+pub mod faer;
+#[cfg(feature = "nalgebra")]
+pub mod nalgebra;
+#[cfg(feature = "ndarray")]
+pub mod ndarray;
+
+/// A matrix given by the address of its first element and its strides in elements.
+///
+/// A vector is a matrix with one column.
+struct Strided<T> {
+    ptr: *mut T,
+    rows: usize,
+    cols: usize,
+    row_stride: isize,
+    col_stride: isize,
+}
+
+impl<T> Strided<T> {
+    #[inline]
+    fn matrix(ptr: *mut T, (rows, cols): (usize, usize), strides: (isize, isize)) -> Self {
+        Strided {
+            ptr,
+            rows,
+            cols,
+            row_stride: strides.0,
+            col_stride: strides.1,
+        }
+    }
+
+    #[inline]
+    fn vector(ptr: *mut T, len: usize, stride: isize) -> Self {
+        Strided::matrix(ptr, (len, 1), (stride, 0))
+    }
+
+    /// Return the address of the element in row `i` and column `j`.
+    #[inline]
+    unsafe fn at(&self, i: usize, j: usize) -> *mut T {
+        self.ptr
+            .offset(i as isize * self.row_stride + j as isize * self.col_stride)
+    }
+}
+
+/// The layout of a matrix stored in column-major order as `op(S)`, where `S` has `rows` rows,
+/// `cols` columns, and leading dimension `ld`.
+#[derive(Clone, Copy)]
+struct Layout {
+    trans: Transpose,
+    rows: Int,
+    cols: Int,
+    ld: Int,
+}
+
+impl Layout {
+    /// Return the layout of a contiguous column-major matrix.
+    fn column_major(rows: usize, cols: usize) -> Self {
+        Layout {
+            trans: Transpose::None,
+            rows: dimension(rows),
+            cols: dimension(cols),
+            ld: dimension(rows.max(1)),
+        }
+    }
+}
+
+/// Compute `C := alpha * A * B + beta * C`, where `B` and `C` are parameters `params` of
+/// `routine`.
+///
+/// `a` and `b` must be valid for reads and `c` for writes.
+unsafe fn gemm<T: Blas>(
+    (routine, params): (&'static str, [usize; 2]),
+    a: Strided<T>,
+    alpha: T,
+    b: Strided<T>,
+    beta: T,
+    c: Strided<T>,
+) -> Result<(), BlasError> {
+    check_match(routine, params[0], dimension(b.rows), dimension(a.cols))?;
+    check_match(routine, params[1], dimension(c.rows), dimension(a.rows))?;
+    check_match(routine, params[1], dimension(c.cols), dimension(b.cols))?;
+    let a = Operand::new(a);
+    let b = Operand::new(b);
+    update(c, |trans, c| match trans {
+        Transpose::None => view::gemm(a.trans(), b.trans(), alpha, a.view()?, b.view()?, beta, c),
+        _ => view::gemm(
+            flip(b.trans()),
+            flip(a.trans()),
+            alpha,
+            b.view()?,
+            a.view()?,
+            beta,
+            c,
+        ),
+    })
+}
+
+/// Compute `y := alpha * A * x + beta * y`, where `x` and `y` are parameters `params` of
+/// `routine`.
+///
+/// `a` and `x` must be valid for reads and `y` for writes.
+unsafe fn gemv<T: Blas>(
+    (routine, params): (&'static str, [usize; 2]),
+    a: Strided<T>,
+    alpha: T,
+    x: Strided<T>,
+    beta: T,
+    y: Strided<T>,
+) -> Result<(), BlasError> {
+    check_match(routine, params[0], dimension(x.rows), dimension(a.cols))?;
+    check_match(routine, params[1], dimension(y.rows), dimension(a.rows))?;
+    let a = Operand::new(a);
+    let copy;
+    let x = match vec_ref(&x) {
+        Some(x) => x,
+        None => {
+            copy = column_major(&x);
+            VecRef::from(&copy[..])
+        }
+    };
+    match vec_mut(&y) {
+        Some(y) => view::gemv(a.trans(), alpha, a.view()?, x, beta, y),
+        None => {
+            let mut copy = column_major(&y);
+            view::gemv(
+                a.trans(),
+                alpha,
+                a.view()?,
+                x,
+                beta,
+                VecMut::from(&mut copy[..]),
+            )?;
+            write(&y, &copy);
+            Ok(())
+        }
+    }
+}
+
+/// Solve `A * X = alpha * B` or `X * A = alpha * B`, where `A` is triangular and `B` is parameter
+/// `param` of `routine`.
+///
+/// `a` must be valid for reads and `b` for writes.
+#[cfg(any(feature = "faer", feature = "nalgebra"))]
+unsafe fn trsm<T: Blas>(
+    (routine, param): (&'static str, usize),
+    side: Side,
+    uplo: Uplo,
+    diag: Diag,
+    alpha: T,
+    a: Strided<T>,
+    b: Strided<T>,
+) -> Result<(), BlasError> {
+    check_match(routine, 0, dimension(a.cols), dimension(a.rows))?;
+    let order = match side {
+        Side::Left => b.rows,
+        Side::Right => b.cols,
+    };
+    check_match(routine, param, dimension(order), dimension(a.rows))?;
+    let a = Operand::new(a);
+    // The triangle `uplo` of the transpose of a matrix is the other triangle of the matrix.
+    let uplo = match a.trans() {
+        Transpose::None => uplo,
+        _ => flip_uplo(uplo),
+    };
+    update(b, |trans, b| match trans {
+        Transpose::None => view::trsm(side, uplo, a.trans(), diag, alpha, a.view()?, b),
+        _ => view::trsm(
+            flip_side(side),
+            uplo,
+            flip(a.trans()),
+            diag,
+            alpha,
+            a.view()?,
+            b,
+        ),
+    })
+}
+
+/// Compute `C := alpha * A * A^T + beta * C`, where `C` is parameter `param` of `routine` and only
+/// its triangle `uplo` is referenced.
+///
+/// `a` must be valid for reads and `c` for writes.
+#[cfg(any(feature = "faer", feature = "nalgebra"))]
+unsafe fn syrk<T: Blas>(
+    (routine, param): (&'static str, usize),
+    uplo: Uplo,
+    alpha: T,
+    a: Strided<T>,
+    beta: T,
+    c: Strided<T>,
+) -> Result<(), BlasError> {
+    check_match(routine, param, dimension(c.rows), dimension(a.rows))?;
+    check_match(routine, param, dimension(c.cols), dimension(a.rows))?;
+    let a = Operand::new(a);
+    update(c, |trans, c| {
+        let uplo = match trans {
+            Transpose::None => uplo,
+            _ => flip_uplo(uplo),
+        };
+        let trans = match a.trans() {
+            Transpose::None => NoneOrTrans::None,
+            _ => NoneOrTrans::Ordinary,
+        };
+        view::syrk(uplo, trans, alpha, a.view()?, beta, c)
+    })
+}
+
+/// Call `f` with a mutable view of `S`, where `C = op(S)`, and `op`.
+///
+/// If BLAS cannot address `c` in place, `S` is a column-major copy of `C`, which is written back
+/// when `f` succeeds. `c` must be valid for writes.
+unsafe fn update<T, F>(c: Strided<T>, f: F) -> Result<(), BlasError>
+where
+    T: Blas,
+    F: FnOnce(Transpose, MatMut<T>) -> Result<(), BlasError>,
+{
+    match layout(&c) {
+        Some(layout) => {
+            let Layout { rows, cols, ld, .. } = layout;
+            f(layout.trans, MatMut::from_raw_parts(c.ptr, rows, cols, ld))
+        }
+        None => {
+            let mut copy = column_major(&c);
+            let Layout { rows, cols, ld, .. } = Layout::column_major(c.rows, c.cols);
+            f(Transpose::None, MatMut::new(&mut copy, rows, cols, ld)?)?;
+            write(&c, &copy);
+            Ok(())
+        }
+    }
+}
+
+/// A matrix passed to BLAS, either in place from `ptr` or copied.
+struct Operand<'a, T> {
+    ptr: *const T,
+    layout: Layout,
+    copy: Option<Vec<T>>,
+    marker: PhantomData<&'a [T]>,
+}
+
+impl<'a, T: Blas> Operand<'a, T> {
+    /// `a` must be valid for reads for `'a`.
+    unsafe fn new(a: Strided<T>) -> Self {
+        match layout(&a) {
+            Some(layout) => Operand {
+                ptr: a.ptr,
+                layout,
+                copy: None,
+                marker: PhantomData,
+            },
+            None => Operand {
+                ptr: a.ptr,
+                layout: Layout::column_major(a.rows, a.cols),
+                copy: Some(column_major(&a)),
+                marker: PhantomData,
+            },
+        }
+    }
+
+    #[inline]
+    fn trans(&self) -> Transpose {
+        self.layout.trans
+    }
+
+    fn view(&self) -> Result<MatRef<'_, T>, BlasError> {
+        let Layout { rows, cols, ld, .. } = self.layout;
+        match &self.copy {
+            Some(copy) => MatRef::new(copy, rows, cols, ld),
+            // SAFETY: The matrix is valid for reads for `'a`.
+            None => Ok(unsafe { MatRef::from_raw_parts(self.ptr, rows, cols, ld) }),
+        }
+    }
+}
+
+/// Return a view of a matrix whose columns are contiguous, which must be valid for reads for
+/// `'a`.
+#[cfg(any(feature = "faer", feature = "nalgebra"))]
+unsafe fn mat_ref<'a, T>(a: Strided<T>) -> Option<MatRef<'a, T>> {
+    let layout = layout(&a).filter(|layout| layout.trans == Transpose::None)?;
+    let Layout { rows, cols, ld, .. } = layout;
+    Some(MatRef::from_raw_parts(a.ptr, rows, cols, ld))
+}
+
+/// Return a mutable view of a matrix whose columns are contiguous, which must be valid for writes
+/// for `'a`.
+#[cfg(any(feature = "faer", feature = "nalgebra"))]
+unsafe fn mat_mut<'a, T>(a: Strided<T>) -> Option<MatMut<'a, T>> {
+    let layout = layout(&a).filter(|layout| layout.trans == Transpose::None)?;
+    let Layout { rows, cols, ld, .. } = layout;
+    Some(MatMut::from_raw_parts(a.ptr, rows, cols, ld))
+}
+
+/// Return a view of a vector whose stride is not zero, which must be valid for reads for `'a`.
+unsafe fn vec_ref<'a, T>(x: &Strided<T>) -> Option<VecRef<'a, T>> {
+    let inc = increment(x)?;
+    Some(VecRef::from_raw_parts(
+        start(x, inc),
+        dimension(x.rows),
+        inc,
+    ))
+}
+
+/// Return a mutable view of a vector whose stride is not zero, which must be valid for writes for
+/// `'a`.
+unsafe fn vec_mut<'a, T>(x: &Strided<T>) -> Option<VecMut<'a, T>> {
+    let inc = increment(x)?;
+    Some(VecMut::from_raw_parts(
+        start(x, inc),
+        dimension(x.rows),
+        inc,
+    ))
+}
+
+/// Return the layout of a matrix if BLAS can address it in place, which is when its columns or
+/// rows are contiguous and a nonnegative number of elements apart that is at least the number
+/// of rows or columns, respectively.
+fn layout<T>(a: &Strided<T>) -> Option<Layout> {
+    let (m, n) = (a.rows, a.cols);
+    let (rows, cols) = (Int::try_from(m).ok()?, Int::try_from(n).ok()?);
+    let (s0, s1) = (a.row_stride, a.col_stride);
+    if m == 0 || n == 0 {
+        Some(Layout::column_major(m, n))
+    } else if (m == 1 || s0 == 1) && (n == 1 || s1 >= m as isize) {
+        Some(Layout {
+            trans: Transpose::None,
+            rows,
+            cols,
+            ld: leading_dimension(n, s1, m)?,
+        })
+    } else if (n == 1 || s1 == 1) && (m == 1 || s0 >= n as isize) {
+        Some(Layout {
+            trans: Transpose::Ordinary,
+            rows: cols,
+            cols: rows,
+            ld: leading_dimension(m, s0, n)?,
+        })
+    } else {
+        None
+    }
+}
+
+/// Return the leading dimension of a matrix whose `count` columns are `stride` elements apart
+/// and have `len` elements, or `None` if it does not fit in an integer of BLAS.
+///
+/// The stride of a single column is irrelevant, and the leading dimension is then `len`.
+fn leading_dimension(count: usize, stride: isize, len: usize) -> Option<Int> {
+    match count {
+        1 => Int::try_from(len).ok(),
+        _ => Int::try_from(stride).ok(),
+    }
+}
+
+/// Return the increment of a vector if BLAS can address it in place, which is when its stride is
+/// not zero and fits in an integer of BLAS.
+fn increment<T>(x: &Strided<T>) -> Option<Int> {
+    match x.row_stride {
+        _ if x.rows <= 1 => Some(1),
+        0 => None,
+        stride => Int::try_from(stride).ok(),
+    }
+}
+
+/// Return the address of the element of a vector with the lowest address.
+unsafe fn start<T>(x: &Strided<T>, inc: Int) -> *mut T {
+    if inc < 0 {
+        x.at(x.rows - 1, 0)
+    } else {
+        x.ptr
+    }
+}
+
+/// Copy a matrix into column-major storage.
+unsafe fn column_major<T: Copy>(a: &Strided<T>) -> Vec<T> {
+    let mut copy = Vec::with_capacity(a.rows * a.cols);
+    for j in 0..a.cols {
+        for i in 0..a.rows {
+            copy.push(*a.at(i, j));
+        }
+    }
+    copy
+}
+
+/// Write a matrix back from column-major storage.
+unsafe fn write<T: Copy>(a: &Strided<T>, copy: &[T]) {
+    for j in 0..a.cols {
+        for i in 0..a.rows {
+            *a.at(i, j) = copy[i + j * a.rows];
+        }
+    }
+}
+
+fn flip(trans: Transpose) -> Transpose {
+    match trans {
+        Transpose::None => Transpose::Ordinary,
+        _ => Transpose::None,
+    }
+}
+
+#[cfg(any(feature = "faer", feature = "nalgebra"))]
+fn flip_side(side: Side) -> Side {
+    match side {
+        Side::Left => Side::Right,
+        Side::Right => Side::Left,
+    }
+}
+
+#[cfg(any(feature = "faer", feature = "nalgebra"))]
+fn flip_uplo(uplo: Uplo) -> Uplo {
+    match uplo {
+        Uplo::Upper => Uplo::Lower,
+        Uplo::Lower => Uplo::Upper,
+    }
+}
+
+fn dimension(value: usize) -> Int {
+    Int::try_from(value).expect("the dimension does not fit in an integer of BLAS")
+}
@@ -0,0 +1,298 @@
+//! Extension methods and conversions for matrices of [`nalgebra`].
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use blas::interop::nalgebra::MatrixExt;
+//! use nalgebra::DMatrix;
+//!
+//! let a = DMatrix::from_row_slice(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+//! let b = DMatrix::<f64>::from_element(3, 4, 1.0);
+//! let mut c = DMatrix::zeros(2, 4);
+//!
+//! a.blas_gemm(&b, &mut c).unwrap();
+//! assert_eq!(c[(1, 3)], 15.0);
+//! ```
+//!
+//! [`nalgebra`]: https://docs.rs/nalgebra
+
+use nalgebra::{Dim, Matrix, RawStorage, RawStorageMut, U1};
+
+use super::Strided;
+use crate::safe::BlasError;
+use crate::view::{MatMut, MatRef, VecMut, VecRef};
+use crate::{Blas, Diag, Side, Uplo};
+
+/// Extension methods for matrices.
+pub trait MatrixExt<T: Blas> {
+    /// Compute `C := A * B`.
+    fn blas_gemm<R1, C1, S1, R2, C2, S2>(
+        &self,
+        b: &Matrix<T, R1, C1, S1>,
+        c: &mut Matrix<T, R2, C2, S2>,
+    ) -> Result<(), BlasError>
+    where
+        R1: Dim,
+        C1: Dim,
+        S1: RawStorage<T, R1, C1>,
+        R2: Dim,
+        C2: Dim,
+        S2: RawStorageMut<T, R2, C2>;
+
+    /// Compute `C := alpha * A * B + beta * C`.
+    fn blas_gemm_scaled<R1, C1, S1, R2, C2, S2>(
+        &self,
+        alpha: T,
+        b: &Matrix<T, R1, C1, S1>,
+        beta: T,
+        c: &mut Matrix<T, R2, C2, S2>,
+    ) -> Result<(), BlasError>
+    where
+        R1: Dim,
+        C1: Dim,
+        S1: RawStorage<T, R1, C1>,
+        R2: Dim,
+        C2: Dim,
+        S2: RawStorageMut<T, R2, C2>;
+
+    /// Compute `y := A * x`.
+    fn blas_gemv<R1, S1, R2, S2>(
+        &self,
+        x: &Matrix<T, R1, U1, S1>,
+        y: &mut Matrix<T, R2, U1, S2>,
+    ) -> Result<(), BlasError>
+    where
+        R1: Dim,
+        S1: RawStorage<T, R1, U1>,
+        R2: Dim,
+        S2: RawStorageMut<T, R2, U1>;
+
+    /// Compute `y := alpha * A * x + beta * y`.
+    fn blas_gemv_scaled<R1, S1, R2, S2>(
+        &self,
+        alpha: T,
+        x: &Matrix<T, R1, U1, S1>,
+        beta: T,
+        y: &mut Matrix<T, R2, U1, S2>,
+    ) -> Result<(), BlasError>
+    where
+        R1: Dim,
+        S1: RawStorage<T, R1, U1>,
+        R2: Dim,
+        S2: RawStorageMut<T, R2, U1>;
+
+    /// Solve `A * X = alpha * B` or `X * A = alpha * B`, where `A` is triangular and `b` holds
+    /// `B` on entry and `X` on exit.
+    fn blas_trsm<R1, C1, S1>(
+        &self,
+        side: Side,
+        uplo: Uplo,
+        diag: Diag,
+        alpha: T,
+        b: &mut Matrix<T, R1, C1, S1>,
+    ) -> Result<(), BlasError>
+    where
+        R1: Dim,
+        C1: Dim,
+        S1: RawStorageMut<T, R1, C1>;
+
+    /// Compute `C := alpha * A * A^T + beta * C`, where only the triangle `uplo` of `C` is
+    /// referenced.
+    fn blas_syrk<R1, C1, S1>(
+        &self,
+        uplo: Uplo,
+        alpha: T,
+        beta: T,
+        c: &mut Matrix<T, R1, C1, S1>,
+    ) -> Result<(), BlasError>
+    where
+        R1: Dim,
+        C1: Dim,
+        S1: RawStorageMut<T, R1, C1>;
+}
+
+impl<T, R, C, S> MatrixExt<T> for Matrix<T, R, C, S>
+where
+    T: Blas,
+    R: Dim,
+    C: Dim,
+    S: RawStorage<T, R, C>,
+{
+    #[inline]
+    fn blas_gemm<R1, C1, S1, R2, C2, S2>(
+        &self,
+        b: &Matrix<T, R1, C1, S1>,
+        c: &mut Matrix<T, R2, C2, S2>,
+    ) -> Result<(), BlasError>
+    where
+        R1: Dim,
+        C1: Dim,
+        S1: RawStorage<T, R1, C1>,
+        R2: Dim,
+        C2: Dim,
+        S2: RawStorageMut<T, R2, C2>,
+    {
+        let (a, b, c) = (matrix(self), matrix(b), matrix_mut(c));
+        unsafe { super::gemm(("blas_gemm", [1, 2]), a, T::one(), b, T::zero(), c) }
+    }
+
+    #[inline]
+    fn blas_gemm_scaled<R1, C1, S1, R2, C2, S2>(
+        &self,
+        alpha: T,
+        b: &Matrix<T, R1, C1, S1>,
+        beta: T,
+        c: &mut Matrix<T, R2, C2, S2>,
+    ) -> Result<(), BlasError>
+    where
+        R1: Dim,
+        C1: Dim,
+        S1: RawStorage<T, R1, C1>,
+        R2: Dim,
+        C2: Dim,
+        S2: RawStorageMut<T, R2, C2>,
+    {
+        let (a, b, c) = (matrix(self), matrix(b), matrix_mut(c));
+        unsafe { super::gemm(("blas_gemm_scaled", [2, 4]), a, alpha, b, beta, c) }
+    }
+
+    #[inline]
+    fn blas_gemv<R1, S1, R2, S2>(
+        &self,
+        x: &Matrix<T, R1, U1, S1>,
+        y: &mut Matrix<T, R2, U1, S2>,
+    ) -> Result<(), BlasError>
+    where
+        R1: Dim,
+        S1: RawStorage<T, R1, U1>,
+        R2: Dim,
+        S2: RawStorageMut<T, R2, U1>,
+    {
+        let (a, x, y) = (matrix(self), vector(x), vector_mut(y));
+        unsafe { super::gemv(("blas_gemv", [1, 2]), a, T::one(), x, T::zero(), y) }
+    }
+
+    #[inline]
+    fn blas_gemv_scaled<R1, S1, R2, S2>(
+        &self,
+        alpha: T,
+        x: &Matrix<T, R1, U1, S1>,
+        beta: T,
+        y: &mut Matrix<T, R2, U1, S2>,
+    ) -> Result<(), BlasError>
+    where
+        R1: Dim,
+        S1: RawStorage<T, R1, U1>,
+        R2: Dim,
+        S2: RawStorageMut<T, R2, U1>,
+    {
+        let (a, x, y) = (matrix(self), vector(x), vector_mut(y));
+        unsafe { super::gemv(("blas_gemv_scaled", [2, 4]), a, alpha, x, beta, y) }
+    }
+
+    #[inline]
+    fn blas_trsm<R1, C1, S1>(
+        &self,
+        side: Side,
+        uplo: Uplo,
+        diag: Diag,
+        alpha: T,
+        b: &mut Matrix<T, R1, C1, S1>,
+    ) -> Result<(), BlasError>
+    where
+        R1: Dim,
+        C1: Dim,
+        S1: RawStorageMut<T, R1, C1>,
+    {
+        let (a, b) = (matrix(self), matrix_mut(b));
+        unsafe { super::trsm(("blas_trsm", 5), side, uplo, diag, alpha, a, b) }
+    }
+
+    #[inline]
+    fn blas_syrk<R1, C1, S1>(
+        &self,
+        uplo: Uplo,
+        alpha: T,
+        beta: T,
+        c: &mut Matrix<T, R1, C1, S1>,
+    ) -> Result<(), BlasError>
+    where
+        R1: Dim,
+        C1: Dim,
+        S1: RawStorageMut<T, R1, C1>,
+    {
+        let (a, c) = (matrix(self), matrix_mut(c));
+        unsafe { super::syrk(("blas_syrk", 4), uplo, alpha, a, beta, c) }
+    }
+}
+
+/// Return a view of a matrix if its columns are contiguous.
+#[inline]
+pub fn mat_ref<T, R, C, S>(a: &Matrix<T, R, C, S>) -> Option<MatRef<'_, T>>
+where
+    R: Dim,
+    C: Dim,
+    S: RawStorage<T, R, C>,
+{
+    unsafe { super::mat_ref(matrix(a)) }
+}
+
+/// Return a mutable view of a matrix if its columns are contiguous.
+#[inline]
+pub fn mat_mut<T, R, C, S>(a: &mut Matrix<T, R, C, S>) -> Option<MatMut<'_, T>>
+where
+    R: Dim,
+    C: Dim,
+    S: RawStorageMut<T, R, C>,
+{
+    unsafe { super::mat_mut(matrix_mut(a)) }
+}
+
+/// Return a view of a vector if its stride is not zero.
+#[inline]
+pub fn vec_ref<T, R, S>(x: &Matrix<T, R, U1, S>) -> Option<VecRef<'_, T>>
+where
+    R: Dim,
+    S: RawStorage<T, R, U1>,
+{
+    unsafe { super::vec_ref(&vector(x)) }
+}
+
+/// Return a mutable view of a vector if its stride is not zero.
+#[inline]
+pub fn vec_mut<T, R, S>(x: &mut Matrix<T, R, U1, S>) -> Option<VecMut<'_, T>>
+where
+    R: Dim,
+    S: RawStorageMut<T, R, U1>,
+{
+    unsafe { super::vec_mut(&vector_mut(x)) }
+}
+
+fn matrix<T, R: Dim, C: Dim, S: RawStorage<T, R, C>>(a: &Matrix<T, R, C, S>) -> Strided<T> {
+    let (row_stride, col_stride) = a.strides();
+    Strided::matrix(
+        a.as_ptr().cast_mut(),
+        a.shape(),
+        (row_stride as isize, col_stride as isize),
+    )
+}
+
+fn matrix_mut<T, R: Dim, C: Dim, S: RawStorageMut<T, R, C>>(
+    a: &mut Matrix<T, R, C, S>,
+) -> Strided<T> {
+    let (shape, (row_stride, col_stride)) = (a.shape(), a.strides());
+    Strided::matrix(
+        a.as_mut_ptr(),
+        shape,
+        (row_stride as isize, col_stride as isize),
+    )
+}
+
+fn vector<T, R: Dim, S: RawStorage<T, R, U1>>(x: &Matrix<T, R, U1, S>) -> Strided<T> {
+    Strided::vector(x.as_ptr().cast_mut(), x.nrows(), x.strides().0 as isize)
+}
+
+fn vector_mut<T, R: Dim, S: RawStorageMut<T, R, U1>>(x: &mut Matrix<T, R, U1, S>) -> Strided<T> {
+    let (len, stride) = (x.nrows(), x.strides().0);
+    Strided::vector(x.as_mut_ptr(), len, stride as isize)
+}
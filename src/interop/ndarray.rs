@@ -0,0 +1,155 @@
+//! Extension methods for arrays of [`ndarray`].
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use blas::interop::ndarray::MatrixExt;
+//! use ndarray::{array, Array2};
+//!
+//! let a = array![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+//! let b = Array2::<f64>::ones((3, 4));
+//! let mut c = Array2::zeros((2, 4));
+//!
+//! a.blas_gemm(&b, &mut c).unwrap();
+//! assert_eq!(c[[1, 3]], 15.0);
+//! ```
+//!
+//! [`ndarray`]: https://docs.rs/ndarray
+
+use ndarray::{ArrayBase, Data, DataMut, Ix1, Ix2};
+
+use super::Strided;
+use crate::safe::BlasError;
+use crate::Blas;
+
+/// Extension methods for two-dimensional arrays.
+pub trait MatrixExt<T: Blas> {
+    /// Compute `C := A * B`.
+    fn blas_gemm<S, R>(
+        &self,
+        b: &ArrayBase<S, Ix2>,
+        c: &mut ArrayBase<R, Ix2>,
+    ) -> Result<(), BlasError>
+    where
+        S: Data<Elem = T>,
+        R: DataMut<Elem = T>;
+
+    /// Compute `C := alpha * A * B + beta * C`.
+    fn blas_gemm_scaled<S, R>(
+        &self,
+        alpha: T,
+        b: &ArrayBase<S, Ix2>,
+        beta: T,
+        c: &mut ArrayBase<R, Ix2>,
+    ) -> Result<(), BlasError>
+    where
+        S: Data<Elem = T>,
+        R: DataMut<Elem = T>;
+
+    /// Compute `y := A * x`.
+    fn blas_gemv<S, R>(
+        &self,
+        x: &ArrayBase<S, Ix1>,
+        y: &mut ArrayBase<R, Ix1>,
+    ) -> Result<(), BlasError>
+    where
+        S: Data<Elem = T>,
+        R: DataMut<Elem = T>;
+
+    /// Compute `y := alpha * A * x + beta * y`.
+    fn blas_gemv_scaled<S, R>(
+        &self,
+        alpha: T,
+        x: &ArrayBase<S, Ix1>,
+        beta: T,
+        y: &mut ArrayBase<R, Ix1>,
+    ) -> Result<(), BlasError>
+    where
+        S: Data<Elem = T>,
+        R: DataMut<Elem = T>;
+}
+
+impl<T, D> MatrixExt<T> for ArrayBase<D, Ix2>
+where
+    T: Blas,
+    D: Data<Elem = T>,
+{
+    #[inline]
+    fn blas_gemm<S, R>(
+        &self,
+        b: &ArrayBase<S, Ix2>,
+        c: &mut ArrayBase<R, Ix2>,
+    ) -> Result<(), BlasError>
+    where
+        S: Data<Elem = T>,
+        R: DataMut<Elem = T>,
+    {
+        let (a, b, c) = (matrix(self), matrix(b), matrix_mut(c));
+        unsafe { super::gemm(("blas_gemm", [1, 2]), a, T::one(), b, T::zero(), c) }
+    }
+
+    #[inline]
+    fn blas_gemm_scaled<S, R>(
+        &self,
+        alpha: T,
+        b: &ArrayBase<S, Ix2>,
+        beta: T,
+        c: &mut ArrayBase<R, Ix2>,
+    ) -> Result<(), BlasError>
+    where
+        S: Data<Elem = T>,
+        R: DataMut<Elem = T>,
+    {
+        let (a, b, c) = (matrix(self), matrix(b), matrix_mut(c));
+        unsafe { super::gemm(("blas_gemm_scaled", [2, 4]), a, alpha, b, beta, c) }
+    }
+
+    #[inline]
+    fn blas_gemv<S, R>(
+        &self,
+        x: &ArrayBase<S, Ix1>,
+        y: &mut ArrayBase<R, Ix1>,
+    ) -> Result<(), BlasError>
+    where
+        S: Data<Elem = T>,
+        R: DataMut<Elem = T>,
+    {
+        let (a, x, y) = (matrix(self), vector(x), vector_mut(y));
+        unsafe { super::gemv(("blas_gemv", [1, 2]), a, T::one(), x, T::zero(), y) }
+    }
+
+    #[inline]
+    fn blas_gemv_scaled<S, R>(
+        &self,
+        alpha: T,
+        x: &ArrayBase<S, Ix1>,
+        beta: T,
+        y: &mut ArrayBase<R, Ix1>,
+    ) -> Result<(), BlasError>
+    where
+        S: Data<Elem = T>,
+        R: DataMut<Elem = T>,
+    {
+        let (a, x, y) = (matrix(self), vector(x), vector_mut(y));
+        unsafe { super::gemv(("blas_gemv_scaled", [2, 4]), a, alpha, x, beta, y) }
+    }
+}
+
+fn matrix<S: Data>(a: &ArrayBase<S, Ix2>) -> Strided<S::Elem> {
+    let strides = a.strides();
+    Strided::matrix(a.as_ptr().cast_mut(), a.dim(), (strides[0], strides[1]))
+}
+
+fn matrix_mut<S: DataMut>(a: &mut ArrayBase<S, Ix2>) -> Strided<S::Elem> {
+    let (dim, strides) = (a.dim(), (a.strides()[0], a.strides()[1]));
+    Strided::matrix(a.as_mut_ptr(), dim, strides)
+}
+
+fn vector<S: Data>(x: &ArrayBase<S, Ix1>) -> Strided<S::Elem> {
+    Strided::vector(x.as_ptr().cast_mut(), x.len(), x.strides()[0])
+}
+
+fn vector_mut<S: DataMut>(x: &mut ArrayBase<S, Ix1>) -> Strided<S::Elem> {
+    let (len, stride) = (x.len(), x.strides()[0]);
+    Strided::vector(x.as_mut_ptr(), len, stride)
+}
@@ -40,15 +40,88 @@
 //! );
 //! ```
 //!
+//! ## Features
+//!
+//! The `std` feature is enabled by default. Without it, the package is `no_std` and keeps all
+//! the wrappers of BLAS. The `alloc` feature, which `std` implies, is required by the portable
+//! fallbacks of `imatcopy`, `sbgemm`, `shgemm`, and `gemm_bf16bf16f32`, by the checked
+//! wrappers of `gemm_batch`, and by the conversions of [`band`] and [`packed`] matrices to and
+//! from dense storage, whereas the [`backend`] and [`xerbla`] modules and the `dynamic`,
+//! `parallel`, and `xerbla` features require `std`.
+//!
+//! The `faer`, `nalgebra`, and `ndarray` features, which imply `alloc`, enable the `interop`
+//! module with extension methods that pass the matrices of the packages of the same names to
+//! BLAS.
+//!
 //! [architecture]: https://blas-lapack-rs.github.io/architecture
 //! [blas]: https://en.wikipedia.org/wiki/BLAS
 
 #![allow(clippy::missing_safety_doc, clippy::too_many_arguments)]
+#![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(not(any(feature = "dynamic", feature = "ilp64", feature = "reference")))]
 extern crate blas_sys as ffi;
 extern crate num_complex as num;
 
-use libc::c_char;
+mod complex;
+mod extension;
+mod flags;
+mod generic;
+#[cfg(all(
+    feature = "ilp64",
+    not(any(feature = "dynamic", feature = "reference"))
+))]
+mod ilp64;
+#[cfg(feature = "reference")]
+mod reference;
+#[cfg(any(
+    not(all(
+        feature = "extension-axpby",
+        feature = "extension-gemmt",
+        feature = "extension-matcopy",
+    )),
+    feature = "reference",
+))]
+mod util;
+
+#[cfg(all(feature = "dynamic", not(feature = "reference")))]
+use dynamic::ffi;
+#[cfg(all(
+    feature = "ilp64",
+    not(any(feature = "dynamic", feature = "reference"))
+))]
+use ilp64 as ffi;
+#[cfg(feature = "reference")]
+use reference as ffi;
+
+#[cfg(feature = "std")]
+pub mod backend;
+pub mod band;
+#[cfg(feature = "cblas")]
+pub mod cblas;
+#[cfg(feature = "dynamic")]
+pub mod dynamic;
+#[cfg(any(feature = "faer", feature = "nalgebra", feature = "ndarray"))]
+pub mod interop;
+pub mod packed;
+pub mod safe;
+pub mod typed;
+pub mod view;
+#[cfg(feature = "std")]
+pub mod xerbla;
+
+pub use band::{BandMatrix, HermitianBand, SymmetricBand, TriangularBand};
+pub use extension::*;
+pub use flags::{Diag, NoneOrConj, NoneOrTrans, Side, Transpose, Uplo};
+pub use generic::{Blas, Scalar};
+pub use packed::{PackedHermitian, PackedSymmetric, PackedTriangular};
+pub use view::{Hermitian, MatMut, MatRef, Symmetric, Triangular, VecMut, VecRef};
+#[cfg(feature = "std")]
+pub use xerbla::{catch_blas_errors, XerblaError};
+
+use core::ffi::c_char;
 
 /// A complex number with 32-bit parts.
 #[allow(non_camel_case_types)]
@@ -58,6 +131,16 @@ pub type c32 = num::Complex<f32>;
 #[allow(non_camel_case_types)]
 pub type c64 = num::Complex<f64>;
 
+/// The integer type of dimensions, increments, and leading dimensions, which is `i64` with the
+/// `ilp64` feature and `i32` otherwise.
+#[cfg(not(feature = "ilp64"))]
+pub type Int = i32;
+
+/// The integer type of dimensions, increments, and leading dimensions, which is `i64` with the
+/// `ilp64` feature and `i32` otherwise.
+#[cfg(feature = "ilp64")]
+pub type Int = i64;
+
 #[inline]
 pub unsafe fn srotg(a: &mut f32, b: &mut f32, c: &mut f32, s: &mut f32) {
     ffi::srotg_(a, b, c, s)
@@ -69,12 +152,12 @@ pub unsafe fn srotmg(d1: &mut f32, d2: &mut f32, x1: &mut f32, y1: f32, param: &
 }
 
 #[inline]
-pub unsafe fn srot(n: i32, x: &mut [f32], incx: i32, y: &mut [f32], incy: i32, c: f32, s: f32) {
+pub unsafe fn srot(n: Int, x: &mut [f32], incx: Int, y: &mut [f32], incy: Int, c: f32, s: f32) {
     ffi::srot_(&n, x.as_mut_ptr(), &incx, y.as_mut_ptr(), &incy, &c, &s)
 }
 
 #[inline]
-pub unsafe fn srotm(n: i32, x: &mut [f32], incx: i32, y: &mut [f32], incy: i32, param: &[f32]) {
+pub unsafe fn srotm(n: Int, x: &mut [f32], incx: Int, y: &mut [f32], incy: Int, param: &[f32]) {
     ffi::srotm_(
         &n,
         x.as_mut_ptr(),
@@ -86,52 +169,52 @@ pub unsafe fn srotm(n: i32, x: &mut [f32], incx: i32, y: &mut [f32], incy: i32,
 }
 
 #[inline]
-pub unsafe fn sswap(n: i32, x: &mut [f32], incx: i32, y: &mut [f32], incy: i32) {
+pub unsafe fn sswap(n: Int, x: &mut [f32], incx: Int, y: &mut [f32], incy: Int) {
     ffi::sswap_(&n, x.as_mut_ptr(), &incx, y.as_mut_ptr(), &incy)
 }
 
 #[inline]
-pub unsafe fn sscal(n: i32, a: f32, x: &mut [f32], incx: i32) {
+pub unsafe fn sscal(n: Int, a: f32, x: &mut [f32], incx: Int) {
     ffi::sscal_(&n, &a, x.as_mut_ptr(), &incx)
 }
 
 #[inline]
-pub unsafe fn scopy(n: i32, x: &[f32], incx: i32, y: &mut [f32], incy: i32) {
+pub unsafe fn scopy(n: Int, x: &[f32], incx: Int, y: &mut [f32], incy: Int) {
     ffi::scopy_(&n, x.as_ptr(), &incx, y.as_mut_ptr(), &incy)
 }
 
 #[inline]
-pub unsafe fn saxpy(n: i32, alpha: f32, x: &[f32], incx: i32, y: &mut [f32], incy: i32) {
+pub unsafe fn saxpy(n: Int, alpha: f32, x: &[f32], incx: Int, y: &mut [f32], incy: Int) {
     ffi::saxpy_(&n, &alpha, x.as_ptr(), &incx, y.as_mut_ptr(), &incy)
 }
 
 #[inline]
-pub unsafe fn sdot(n: i32, x: &[f32], incx: i32, y: &[f32], incy: i32) -> f32 {
+pub unsafe fn sdot(n: Int, x: &[f32], incx: Int, y: &[f32], incy: Int) -> f32 {
     ffi::sdot_(&n, x.as_ptr(), &incx, y.as_ptr(), &incy)
 }
 
 #[inline]
-pub unsafe fn sdsdot(n: i32, sb: &[f32], x: &[f32], incx: i32, y: &[f32], incy: i32) -> f32 {
+pub unsafe fn sdsdot(n: Int, sb: &[f32], x: &[f32], incx: Int, y: &[f32], incy: Int) -> f32 {
     ffi::sdsdot_(&n, sb.as_ptr(), x.as_ptr(), &incx, y.as_ptr(), &incy)
 }
 
 #[inline]
-pub unsafe fn snrm2(n: i32, x: &[f32], incx: i32) -> f32 {
+pub unsafe fn snrm2(n: Int, x: &[f32], incx: Int) -> f32 {
     ffi::snrm2_(&n, x.as_ptr(), &incx)
 }
 
 #[inline]
-pub unsafe fn scnrm2(n: i32, x: &[c32], incx: i32) -> f32 {
+pub unsafe fn scnrm2(n: Int, x: &[c32], incx: Int) -> f32 {
     ffi::scnrm2_(&n, x.as_ptr() as *const _, &incx)
 }
 
 #[inline]
-pub unsafe fn sasum(n: i32, x: &[f32], incx: i32) -> f32 {
+pub unsafe fn sasum(n: Int, x: &[f32], incx: Int) -> f32 {
     ffi::sasum_(&n, x.as_ptr(), &incx)
 }
 
 #[inline]
-pub unsafe fn isamax(n: i32, x: &[f32], incx: i32) -> usize {
+pub unsafe fn isamax(n: Int, x: &[f32], incx: Int) -> usize {
     ffi::isamax_(&n, x.as_ptr(), &incx) as usize
 }
 
@@ -146,12 +229,12 @@ pub unsafe fn drotmg(d1: &mut f64, d2: &mut f64, x1: &mut f64, y1: f64, param: &
 }
 
 #[inline]
-pub unsafe fn drot(n: i32, x: &mut [f64], incx: i32, y: &mut [f64], incy: i32, c: f64, s: f64) {
+pub unsafe fn drot(n: Int, x: &mut [f64], incx: Int, y: &mut [f64], incy: Int, c: f64, s: f64) {
     ffi::drot_(&n, x.as_mut_ptr(), &incx, y.as_mut_ptr(), &incy, &c, &s)
 }
 
 #[inline]
-pub unsafe fn drotm(n: i32, x: &mut [f64], incx: i32, y: &mut [f64], incy: i32, param: &[f64]) {
+pub unsafe fn drotm(n: Int, x: &mut [f64], incx: Int, y: &mut [f64], incy: Int, param: &[f64]) {
     ffi::drotm_(
         &n,
         x.as_mut_ptr(),
@@ -163,52 +246,52 @@ pub unsafe fn drotm(n: i32, x: &mut [f64], incx: i32, y: &mut [f64], incy: i32,
 }
 
 #[inline]
-pub unsafe fn dswap(n: i32, x: &mut [f64], incx: i32, y: &mut [f64], incy: i32) {
+pub unsafe fn dswap(n: Int, x: &mut [f64], incx: Int, y: &mut [f64], incy: Int) {
     ffi::dswap_(&n, x.as_mut_ptr(), &incx, y.as_mut_ptr(), &incy)
 }
 
 #[inline]
-pub unsafe fn dscal(n: i32, a: f64, x: &mut [f64], incx: i32) {
+pub unsafe fn dscal(n: Int, a: f64, x: &mut [f64], incx: Int) {
     ffi::dscal_(&n, &a, x.as_mut_ptr(), &incx)
 }
 
 #[inline]
-pub unsafe fn dcopy(n: i32, x: &[f64], incx: i32, y: &mut [f64], incy: i32) {
+pub unsafe fn dcopy(n: Int, x: &[f64], incx: Int, y: &mut [f64], incy: Int) {
     ffi::dcopy_(&n, x.as_ptr(), &incx, y.as_mut_ptr(), &incy)
 }
 
 #[inline]
-pub unsafe fn daxpy(n: i32, alpha: f64, x: &[f64], incx: i32, y: &mut [f64], incy: i32) {
+pub unsafe fn daxpy(n: Int, alpha: f64, x: &[f64], incx: Int, y: &mut [f64], incy: Int) {
     ffi::daxpy_(&n, &alpha, x.as_ptr(), &incx, y.as_mut_ptr(), &incy)
 }
 
 #[inline]
-pub unsafe fn ddot(n: i32, x: &[f64], incx: i32, y: &[f64], incy: i32) -> f64 {
+pub unsafe fn ddot(n: Int, x: &[f64], incx: Int, y: &[f64], incy: Int) -> f64 {
     ffi::ddot_(&n, x.as_ptr(), &incx, y.as_ptr(), &incy)
 }
 
 #[inline]
-pub unsafe fn dsdot(n: i32, x: &[f32], incx: i32, y: &[f32], incy: i32) -> f64 {
+pub unsafe fn dsdot(n: Int, x: &[f32], incx: Int, y: &[f32], incy: Int) -> f64 {
     ffi::dsdot_(&n, x.as_ptr(), &incx, y.as_ptr(), &incy)
 }
 
 #[inline]
-pub unsafe fn dnrm2(n: i32, x: &[f64], incx: i32) -> f64 {
+pub unsafe fn dnrm2(n: Int, x: &[f64], incx: Int) -> f64 {
     ffi::dnrm2_(&n, x.as_ptr(), &incx)
 }
 
 #[inline]
-pub unsafe fn dznrm2(n: i32, x: &[c64], incx: i32) -> f64 {
+pub unsafe fn dznrm2(n: Int, x: &[c64], incx: Int) -> f64 {
     ffi::dznrm2_(&n, x.as_ptr() as *const _, &incx)
 }
 
 #[inline]
-pub unsafe fn dasum(n: i32, x: &[f64], incx: i32) -> f64 {
+pub unsafe fn dasum(n: Int, x: &[f64], incx: Int) -> f64 {
     ffi::dasum_(&n, x.as_ptr(), &incx)
 }
 
 #[inline]
-pub unsafe fn idamax(n: i32, x: &[f64], incx: i32) -> usize {
+pub unsafe fn idamax(n: Int, x: &[f64], incx: Int) -> usize {
     ffi::idamax_(&n, x.as_ptr(), &incx) as usize
 }
 
@@ -223,7 +306,7 @@ pub unsafe fn crotg(a: &mut c32, b: c32, c: &mut f32, s: &mut c32) {
 }
 
 #[inline]
-pub unsafe fn csrot(n: i32, x: &mut [c32], incx: i32, y: &mut [c32], incy: i32, c: f32, s: f32) {
+pub unsafe fn csrot(n: Int, x: &mut [c32], incx: Int, y: &mut [c32], incy: Int, c: f32, s: f32) {
     ffi::csrot_(
         &n,
         x.as_mut_ptr() as *mut _,
@@ -236,7 +319,7 @@ pub unsafe fn csrot(n: i32, x: &mut [c32], incx: i32, y: &mut [c32], incy: i32,
 }
 
 #[inline]
-pub unsafe fn cswap(n: i32, x: &mut [c32], incx: i32, y: &mut [c32], incy: i32) {
+pub unsafe fn cswap(n: Int, x: &mut [c32], incx: Int, y: &mut [c32], incy: Int) {
     ffi::cswap_(
         &n,
         x.as_mut_ptr() as *mut _,
@@ -247,7 +330,7 @@ pub unsafe fn cswap(n: i32, x: &mut [c32], incx: i32, y: &mut [c32], incy: i32)
 }
 
 #[inline]
-pub unsafe fn cscal(n: i32, a: c32, x: &mut [c32], incx: i32) {
+pub unsafe fn cscal(n: Int, a: c32, x: &mut [c32], incx: Int) {
     ffi::cscal_(
         &n,
         &a as *const _ as *const _,
@@ -257,12 +340,12 @@ pub unsafe fn cscal(n: i32, a: c32, x: &mut [c32], incx: i32) {
 }
 
 #[inline]
-pub unsafe fn csscal(n: i32, a: f32, x: &mut [c32], incx: i32) {
+pub unsafe fn csscal(n: Int, a: f32, x: &mut [c32], incx: Int) {
     ffi::csscal_(&n, &a, x.as_mut_ptr() as *mut _, &incx)
 }
 
 #[inline]
-pub unsafe fn ccopy(n: i32, x: &[c32], incx: i32, y: &mut [c32], incy: i32) {
+pub unsafe fn ccopy(n: Int, x: &[c32], incx: Int, y: &mut [c32], incy: Int) {
     ffi::ccopy_(
         &n,
         x.as_ptr() as *const _,
@@ -273,7 +356,7 @@ pub unsafe fn ccopy(n: i32, x: &[c32], incx: i32, y: &mut [c32], incy: i32) {
 }
 
 #[inline]
-pub unsafe fn caxpy(n: i32, alpha: c32, x: &[c32], incx: i32, y: &mut [c32], incy: i32) {
+pub unsafe fn caxpy(n: Int, alpha: c32, x: &[c32], incx: Int, y: &mut [c32], incy: Int) {
     ffi::caxpy_(
         &n,
         &alpha as *const _ as *const _,
@@ -285,9 +368,8 @@ pub unsafe fn caxpy(n: i32, alpha: c32, x: &[c32], incx: i32, y: &mut [c32], inc
 }
 
 #[inline]
-pub unsafe fn cdotu(pres: &mut [c32], n: i32, x: &[c32], incx: i32, y: &[c32], incy: i32) {
-    ffi::cdotu_(
-        pres.as_mut_ptr() as *mut _,
+pub unsafe fn cdotu(n: Int, x: &[c32], incx: Int, y: &[c32], incy: Int) -> c32 {
+    complex::cdotu(
         &n,
         x.as_ptr() as *const _,
         &incx,
@@ -297,9 +379,8 @@ pub unsafe fn cdotu(pres: &mut [c32], n: i32, x: &[c32], incx: i32, y: &[c32], i
 }
 
 #[inline]
-pub unsafe fn cdotc(pres: &mut [c32], n: i32, x: &[c32], incx: i32, y: &[c32], incy: i32) {
-    ffi::cdotc_(
-        pres.as_mut_ptr() as *mut _,
+pub unsafe fn cdotc(n: Int, x: &[c32], incx: Int, y: &[c32], incy: Int) -> c32 {
+    complex::cdotc(
         &n,
         x.as_ptr() as *const _,
         &incx,
@@ -309,12 +390,12 @@ pub unsafe fn cdotc(pres: &mut [c32], n: i32, x: &[c32], incx: i32, y: &[c32], i
 }
 
 #[inline]
-pub unsafe fn scasum(n: i32, x: &[c32], incx: i32) -> f32 {
+pub unsafe fn scasum(n: Int, x: &[c32], incx: Int) -> f32 {
     ffi::scasum_(&n, x.as_ptr() as *const _, &incx)
 }
 
 #[inline]
-pub unsafe fn icamax(n: i32, x: &[c32], incx: i32) -> usize {
+pub unsafe fn icamax(n: Int, x: &[c32], incx: Int) -> usize {
     ffi::icamax_(&n, x.as_ptr() as *const _, &incx) as usize
 }
 
@@ -329,7 +410,7 @@ pub unsafe fn zrotg(a: &mut c64, b: c64, c: &mut f64, s: &mut c64) {
 }
 
 #[inline]
-pub unsafe fn zdrot(n: i32, x: &mut [c64], incx: i32, y: &mut [c64], incy: i32, c: f64, s: f64) {
+pub unsafe fn zdrot(n: Int, x: &mut [c64], incx: Int, y: &mut [c64], incy: Int, c: f64, s: f64) {
     ffi::zdrot_(
         &n,
         x.as_mut_ptr() as *mut _,
@@ -342,7 +423,7 @@ pub unsafe fn zdrot(n: i32, x: &mut [c64], incx: i32, y: &mut [c64], incy: i32,
 }
 
 #[inline]
-pub unsafe fn zswap(n: i32, x: &mut [c64], incx: i32, y: &mut [c64], incy: i32) {
+pub unsafe fn zswap(n: Int, x: &mut [c64], incx: Int, y: &mut [c64], incy: Int) {
     ffi::zswap_(
         &n,
         x.as_mut_ptr() as *mut _,
@@ -353,7 +434,7 @@ pub unsafe fn zswap(n: i32, x: &mut [c64], incx: i32, y: &mut [c64], incy: i32)
 }
 
 #[inline]
-pub unsafe fn zscal(n: i32, a: c64, x: &mut [c64], incx: i32) {
+pub unsafe fn zscal(n: Int, a: c64, x: &mut [c64], incx: Int) {
     ffi::zscal_(
         &n,
         &a as *const _ as *const _,
@@ -363,12 +444,12 @@ pub unsafe fn zscal(n: i32, a: c64, x: &mut [c64], incx: i32) {
 }
 
 #[inline]
-pub unsafe fn zdscal(n: i32, a: f64, x: &mut [c64], incx: i32) {
+pub unsafe fn zdscal(n: Int, a: f64, x: &mut [c64], incx: Int) {
     ffi::zdscal_(&n, &a, x.as_mut_ptr() as *mut _, &incx)
 }
 
 #[inline]
-pub unsafe fn zcopy(n: i32, x: &[c64], incx: i32, y: &mut [c64], incy: i32) {
+pub unsafe fn zcopy(n: Int, x: &[c64], incx: Int, y: &mut [c64], incy: Int) {
     ffi::zcopy_(
         &n,
         x.as_ptr() as *const _,
@@ -379,7 +460,7 @@ pub unsafe fn zcopy(n: i32, x: &[c64], incx: i32, y: &mut [c64], incy: i32) {
 }
 
 #[inline]
-pub unsafe fn zaxpy(n: i32, alpha: c64, x: &[c64], incx: i32, y: &mut [c64], incy: i32) {
+pub unsafe fn zaxpy(n: Int, alpha: c64, x: &[c64], incx: Int, y: &mut [c64], incy: Int) {
     ffi::zaxpy_(
         &n,
         &alpha as *const _ as *const _,
@@ -391,9 +472,8 @@ pub unsafe fn zaxpy(n: i32, alpha: c64, x: &[c64], incx: i32, y: &mut [c64], inc
 }
 
 #[inline]
-pub unsafe fn zdotu(pres: &mut [c64], n: i32, x: &[c64], incx: i32, y: &[c64], incy: i32) {
-    ffi::zdotu_(
-        pres.as_mut_ptr() as *mut _,
+pub unsafe fn zdotu(n: Int, x: &[c64], incx: Int, y: &[c64], incy: Int) -> c64 {
+    complex::zdotu(
         &n,
         x.as_ptr() as *const _,
         &incx,
@@ -403,9 +483,8 @@ pub unsafe fn zdotu(pres: &mut [c64], n: i32, x: &[c64], incx: i32, y: &[c64], i
 }
 
 #[inline]
-pub unsafe fn zdotc(pres: &mut [c64], n: i32, x: &[c64], incx: i32, y: &[c64], incy: i32) {
-    ffi::zdotc_(
-        pres.as_mut_ptr() as *mut _,
+pub unsafe fn zdotc(n: Int, x: &[c64], incx: Int, y: &[c64], incy: Int) -> c64 {
+    complex::zdotc(
         &n,
         x.as_ptr() as *const _,
         &incx,
@@ -415,28 +494,28 @@ pub unsafe fn zdotc(pres: &mut [c64], n: i32, x: &[c64], incx: i32, y: &[c64], i
 }
 
 #[inline]
-pub unsafe fn dzasum(n: i32, x: &[c64], incx: i32) -> f64 {
+pub unsafe fn dzasum(n: Int, x: &[c64], incx: Int) -> f64 {
     ffi::dzasum_(&n, x.as_ptr() as *const _, &incx)
 }
 
 #[inline]
-pub unsafe fn izamax(n: i32, x: &[c64], incx: i32) -> usize {
+pub unsafe fn izamax(n: Int, x: &[c64], incx: Int) -> usize {
     ffi::izamax_(&n, x.as_ptr() as *const _, &incx) as usize
 }
 
 #[inline]
 pub unsafe fn sgemv(
     trans: u8,
-    m: i32,
-    n: i32,
+    m: Int,
+    n: Int,
     alpha: f32,
     a: &[f32],
-    lda: i32,
+    lda: Int,
     x: &[f32],
-    incx: i32,
+    incx: Int,
     beta: f32,
     y: &mut [f32],
-    incy: i32,
+    incy: Int,
 ) {
     ffi::sgemv_(
         &(trans as c_char),
@@ -456,18 +535,18 @@ pub unsafe fn sgemv(
 #[inline]
 pub unsafe fn sgbmv(
     trans: u8,
-    m: i32,
-    n: i32,
-    kl: i32,
-    ku: i32,
+    m: Int,
+    n: Int,
+    kl: Int,
+    ku: Int,
     alpha: f32,
     a: &[f32],
-    lda: i32,
+    lda: Int,
     x: &[f32],
-    incx: i32,
+    incx: Int,
     beta: f32,
     y: &mut [f32],
-    incy: i32,
+    incy: Int,
 ) {
     ffi::sgbmv_(
         &(trans as c_char),
@@ -489,15 +568,15 @@ pub unsafe fn sgbmv(
 #[inline]
 pub unsafe fn ssymv(
     uplo: u8,
-    n: i32,
+    n: Int,
     alpha: f32,
     a: &[f32],
-    lda: i32,
+    lda: Int,
     x: &[f32],
-    incx: i32,
+    incx: Int,
     beta: f32,
     y: &mut [f32],
-    incy: i32,
+    incy: Int,
 ) {
     ffi::ssymv_(
         &(uplo as c_char),
@@ -516,16 +595,16 @@ pub unsafe fn ssymv(
 #[inline]
 pub unsafe fn ssbmv(
     uplo: u8,
-    n: i32,
-    k: i32,
+    n: Int,
+    k: Int,
     alpha: f32,
     a: &[f32],
-    lda: i32,
+    lda: Int,
     x: &[f32],
-    incx: i32,
+    incx: Int,
     beta: f32,
     y: &mut [f32],
-    incy: i32,
+    incy: Int,
 ) {
     ffi::ssbmv_(
         &(uplo as c_char),
@@ -545,14 +624,14 @@ pub unsafe fn ssbmv(
 #[inline]
 pub unsafe fn sspmv(
     uplo: u8,
-    n: i32,
+    n: Int,
     alpha: f32,
     ap: &[f32],
     x: &[f32],
-    incx: i32,
+    incx: Int,
     beta: f32,
     y: &mut [f32],
-    incy: i32,
+    incy: Int,
 ) {
     ffi::sspmv_(
         &(uplo as c_char),
@@ -572,11 +651,11 @@ pub unsafe fn strmv(
     uplo: u8,
     transa: u8,
     diag: u8,
-    n: i32,
+    n: Int,
     a: &[f32],
-    lda: i32,
+    lda: Int,
     b: &mut [f32],
-    incx: i32,
+    incx: Int,
 ) {
     ffi::strmv_(
         &(uplo as c_char),
@@ -595,12 +674,12 @@ pub unsafe fn stbmv(
     uplo: u8,
     trans: u8,
     diag: u8,
-    n: i32,
-    k: i32,
+    n: Int,
+    k: Int,
     a: &[f32],
-    lda: i32,
+    lda: Int,
     x: &mut [f32],
-    incx: i32,
+    incx: Int,
 ) {
     ffi::stbmv_(
         &(uplo as c_char),
@@ -616,7 +695,7 @@ pub unsafe fn stbmv(
 }
 
 #[inline]
-pub unsafe fn stpmv(uplo: u8, trans: u8, diag: u8, n: i32, ap: &[f32], x: &mut [f32], incx: i32) {
+pub unsafe fn stpmv(uplo: u8, trans: u8, diag: u8, n: Int, ap: &[f32], x: &mut [f32], incx: Int) {
     ffi::stpmv_(
         &(uplo as c_char),
         &(trans as c_char),
@@ -633,11 +712,11 @@ pub unsafe fn strsv(
     uplo: u8,
     trans: u8,
     diag: u8,
-    n: i32,
+    n: Int,
     a: &[f32],
-    lda: i32,
+    lda: Int,
     x: &mut [f32],
-    incx: i32,
+    incx: Int,
 ) {
     ffi::strsv_(
         &(uplo as c_char),
@@ -656,12 +735,12 @@ pub unsafe fn stbsv(
     uplo: u8,
     trans: u8,
     diag: u8,
-    n: i32,
-    k: i32,
+    n: Int,
+    k: Int,
     a: &[f32],
-    lda: i32,
+    lda: Int,
     x: &mut [f32],
-    incx: i32,
+    incx: Int,
 ) {
     ffi::stbsv_(
         &(uplo as c_char),
@@ -677,7 +756,7 @@ pub unsafe fn stbsv(
 }
 
 #[inline]
-pub unsafe fn stpsv(uplo: u8, trans: u8, diag: u8, n: i32, ap: &[f32], x: &mut [f32], incx: i32) {
+pub unsafe fn stpsv(uplo: u8, trans: u8, diag: u8, n: Int, ap: &[f32], x: &mut [f32], incx: Int) {
     ffi::stpsv_(
         &(uplo as c_char),
         &(trans as c_char),
@@ -691,15 +770,15 @@ pub unsafe fn stpsv(uplo: u8, trans: u8, diag: u8, n: i32, ap: &[f32], x: &mut [
 
 #[inline]
 pub unsafe fn sger(
-    m: i32,
-    n: i32,
+    m: Int,
+    n: Int,
     alpha: f32,
     x: &[f32],
-    incx: i32,
+    incx: Int,
     y: &[f32],
-    incy: i32,
+    incy: Int,
     a: &mut [f32],
-    lda: i32,
+    lda: Int,
 ) {
     ffi::sger_(
         &m,
@@ -715,7 +794,7 @@ pub unsafe fn sger(
 }
 
 #[inline]
-pub unsafe fn ssyr(uplo: u8, n: i32, alpha: f32, x: &[f32], incx: i32, a: &mut [f32], lda: i32) {
+pub unsafe fn ssyr(uplo: u8, n: Int, alpha: f32, x: &[f32], incx: Int, a: &mut [f32], lda: Int) {
     ffi::ssyr_(
         &(uplo as c_char),
         &n,
@@ -728,7 +807,7 @@ pub unsafe fn ssyr(uplo: u8, n: i32, alpha: f32, x: &[f32], incx: i32, a: &mut [
 }
 
 #[inline]
-pub unsafe fn sspr(uplo: u8, n: i32, alpha: f32, x: &[f32], incx: i32, ap: &mut [f32]) {
+pub unsafe fn sspr(uplo: u8, n: Int, alpha: f32, x: &[f32], incx: Int, ap: &mut [f32]) {
     ffi::sspr_(
         &(uplo as c_char),
         &n,
@@ -742,14 +821,14 @@ pub unsafe fn sspr(uplo: u8, n: i32, alpha: f32, x: &[f32], incx: i32, ap: &mut
 #[inline]
 pub unsafe fn ssyr2(
     uplo: u8,
-    n: i32,
+    n: Int,
     alpha: f32,
     x: &[f32],
-    incx: i32,
+    incx: Int,
     y: &[f32],
-    incy: i32,
+    incy: Int,
     a: &mut [f32],
-    lda: i32,
+    lda: Int,
 ) {
     ffi::ssyr2_(
         &(uplo as c_char),
@@ -767,12 +846,12 @@ pub unsafe fn ssyr2(
 #[inline]
 pub unsafe fn sspr2(
     uplo: u8,
-    n: i32,
+    n: Int,
     alpha: f32,
     x: &[f32],
-    incx: i32,
+    incx: Int,
     y: &[f32],
-    incy: i32,
+    incy: Int,
     ap: &mut [f32],
 ) {
     ffi::sspr2_(
@@ -790,16 +869,16 @@ pub unsafe fn sspr2(
 #[inline]
 pub unsafe fn dgemv(
     trans: u8,
-    m: i32,
-    n: i32,
+    m: Int,
+    n: Int,
     alpha: f64,
     a: &[f64],
-    lda: i32,
+    lda: Int,
     x: &[f64],
-    incx: i32,
+    incx: Int,
     beta: f64,
     y: &mut [f64],
-    incy: i32,
+    incy: Int,
 ) {
     ffi::dgemv_(
         &(trans as c_char),
@@ -819,18 +898,18 @@ pub unsafe fn dgemv(
 #[inline]
 pub unsafe fn dgbmv(
     trans: u8,
-    m: i32,
-    n: i32,
-    kl: i32,
-    ku: i32,
+    m: Int,
+    n: Int,
+    kl: Int,
+    ku: Int,
     alpha: f64,
     a: &[f64],
-    lda: i32,
+    lda: Int,
     x: &[f64],
-    incx: i32,
+    incx: Int,
     beta: f64,
     y: &mut [f64],
-    incy: i32,
+    incy: Int,
 ) {
     ffi::dgbmv_(
         &(trans as c_char),
@@ -852,15 +931,15 @@ pub unsafe fn dgbmv(
 #[inline]
 pub unsafe fn dsymv(
     uplo: u8,
-    n: i32,
+    n: Int,
     alpha: f64,
     a: &[f64],
-    lda: i32,
+    lda: Int,
     x: &[f64],
-    incx: i32,
+    incx: Int,
     beta: f64,
     y: &mut [f64],
-    incy: i32,
+    incy: Int,
 ) {
     ffi::dsymv_(
         &(uplo as c_char),
@@ -879,16 +958,16 @@ pub unsafe fn dsymv(
 #[inline]
 pub unsafe fn dsbmv(
     uplo: u8,
-    n: i32,
-    k: i32,
+    n: Int,
+    k: Int,
     alpha: f64,
     a: &[f64],
-    lda: i32,
+    lda: Int,
     x: &[f64],
-    incx: i32,
+    incx: Int,
     beta: f64,
     y: &mut [f64],
-    incy: i32,
+    incy: Int,
 ) {
     ffi::dsbmv_(
         &(uplo as c_char),
@@ -908,14 +987,14 @@ pub unsafe fn dsbmv(
 #[inline]
 pub unsafe fn dspmv(
     uplo: u8,
-    n: i32,
+    n: Int,
     alpha: f64,
     ap: &[f64],
     x: &[f64],
-    incx: i32,
+    incx: Int,
     beta: f64,
     y: &mut [f64],
-    incy: i32,
+    incy: Int,
 ) {
     ffi::dspmv_(
         &(uplo as c_char),
@@ -935,11 +1014,11 @@ pub unsafe fn dtrmv(
     uplo: u8,
     transa: u8,
     diag: u8,
-    n: i32,
+    n: Int,
     a: &[f64],
-    lda: i32,
+    lda: Int,
     b: &mut [f64],
-    incx: i32,
+    incx: Int,
 ) {
     ffi::dtrmv_(
         &(uplo as c_char),
@@ -958,12 +1037,12 @@ pub unsafe fn dtbmv(
     uplo: u8,
     trans: u8,
     diag: u8,
-    n: i32,
-    k: i32,
+    n: Int,
+    k: Int,
     a: &[f64],
-    lda: i32,
+    lda: Int,
     x: &mut [f64],
-    incx: i32,
+    incx: Int,
 ) {
     ffi::dtbmv_(
         &(uplo as c_char),
@@ -979,7 +1058,7 @@ pub unsafe fn dtbmv(
 }
 
 #[inline]
-pub unsafe fn dtpmv(uplo: u8, trans: u8, diag: u8, n: i32, ap: &[f64], x: &mut [f64], incx: i32) {
+pub unsafe fn dtpmv(uplo: u8, trans: u8, diag: u8, n: Int, ap: &[f64], x: &mut [f64], incx: Int) {
     ffi::dtpmv_(
         &(uplo as c_char),
         &(trans as c_char),
@@ -996,11 +1075,11 @@ pub unsafe fn dtrsv(
     uplo: u8,
     trans: u8,
     diag: u8,
-    n: i32,
+    n: Int,
     a: &[f64],
-    lda: i32,
+    lda: Int,
     x: &mut [f64],
-    incx: i32,
+    incx: Int,
 ) {
     ffi::dtrsv_(
         &(uplo as c_char),
@@ -1019,12 +1098,12 @@ pub unsafe fn dtbsv(
     uplo: u8,
     trans: u8,
     diag: u8,
-    n: i32,
-    k: i32,
+    n: Int,
+    k: Int,
     a: &[f64],
-    lda: i32,
+    lda: Int,
     x: &mut [f64],
-    incx: i32,
+    incx: Int,
 ) {
     ffi::dtbsv_(
         &(uplo as c_char),
@@ -1040,7 +1119,7 @@ pub unsafe fn dtbsv(
 }
 
 #[inline]
-pub unsafe fn dtpsv(uplo: u8, trans: u8, diag: u8, n: i32, ap: &[f64], x: &mut [f64], incx: i32) {
+pub unsafe fn dtpsv(uplo: u8, trans: u8, diag: u8, n: Int, ap: &[f64], x: &mut [f64], incx: Int) {
     ffi::dtpsv_(
         &(uplo as c_char),
         &(trans as c_char),
@@ -1054,15 +1133,15 @@ pub unsafe fn dtpsv(uplo: u8, trans: u8, diag: u8, n: i32, ap: &[f64], x: &mut [
 
 #[inline]
 pub unsafe fn dger(
-    m: i32,
-    n: i32,
+    m: Int,
+    n: Int,
     alpha: f64,
     x: &[f64],
-    incx: i32,
+    incx: Int,
     y: &[f64],
-    incy: i32,
+    incy: Int,
     a: &mut [f64],
-    lda: i32,
+    lda: Int,
 ) {
     ffi::dger_(
         &m,
@@ -1078,7 +1157,7 @@ pub unsafe fn dger(
 }
 
 #[inline]
-pub unsafe fn dsyr(uplo: u8, n: i32, alpha: f64, x: &[f64], incx: i32, a: &mut [f64], lda: i32) {
+pub unsafe fn dsyr(uplo: u8, n: Int, alpha: f64, x: &[f64], incx: Int, a: &mut [f64], lda: Int) {
     ffi::dsyr_(
         &(uplo as c_char),
         &n,
@@ -1091,7 +1170,7 @@ pub unsafe fn dsyr(uplo: u8, n: i32, alpha: f64, x: &[f64], incx: i32, a: &mut [
 }
 
 #[inline]
-pub unsafe fn dspr(uplo: u8, n: i32, alpha: f64, x: &[f64], incx: i32, ap: &mut [f64]) {
+pub unsafe fn dspr(uplo: u8, n: Int, alpha: f64, x: &[f64], incx: Int, ap: &mut [f64]) {
     ffi::dspr_(
         &(uplo as c_char),
         &n,
@@ -1105,14 +1184,14 @@ pub unsafe fn dspr(uplo: u8, n: i32, alpha: f64, x: &[f64], incx: i32, ap: &mut
 #[inline]
 pub unsafe fn dsyr2(
     uplo: u8,
-    n: i32,
+    n: Int,
     alpha: f64,
     x: &[f64],
-    incx: i32,
+    incx: Int,
     y: &[f64],
-    incy: i32,
+    incy: Int,
     a: &mut [f64],
-    lda: i32,
+    lda: Int,
 ) {
     ffi::dsyr2_(
         &(uplo as c_char),
@@ -1130,12 +1209,12 @@ pub unsafe fn dsyr2(
 #[inline]
 pub unsafe fn dspr2(
     uplo: u8,
-    n: i32,
+    n: Int,
     alpha: f64,
     x: &[f64],
-    incx: i32,
+    incx: Int,
     y: &[f64],
-    incy: i32,
+    incy: Int,
     ap: &mut [f64],
 ) {
     ffi::dspr2_(
@@ -1153,16 +1232,16 @@ pub unsafe fn dspr2(
 #[inline]
 pub unsafe fn cgemv(
     trans: u8,
-    m: i32,
-    n: i32,
+    m: Int,
+    n: Int,
     alpha: c32,
     a: &[c32],
-    lda: i32,
+    lda: Int,
     x: &[c32],
-    incx: i32,
+    incx: Int,
     beta: c32,
     y: &mut [c32],
-    incy: i32,
+    incy: Int,
 ) {
     ffi::cgemv_(
         &(trans as c_char),
@@ -1182,18 +1261,18 @@ pub unsafe fn cgemv(
 #[inline]
 pub unsafe fn cgbmv(
     trans: u8,
-    m: i32,
-    n: i32,
-    kl: i32,
-    ku: i32,
+    m: Int,
+    n: Int,
+    kl: Int,
+    ku: Int,
     alpha: c32,
     a: &[c32],
-    lda: i32,
+    lda: Int,
     x: &[c32],
-    incx: i32,
+    incx: Int,
     beta: c32,
     y: &mut [c32],
-    incy: i32,
+    incy: Int,
 ) {
     ffi::cgbmv_(
         &(trans as c_char),
@@ -1215,15 +1294,15 @@ pub unsafe fn cgbmv(
 #[inline]
 pub unsafe fn chemv(
     uplo: u8,
-    n: i32,
+    n: Int,
     alpha: c32,
     a: &[c32],
-    lda: i32,
+    lda: Int,
     x: &[c32],
-    incx: i32,
+    incx: Int,
     beta: c32,
     y: &mut [c32],
-    incy: i32,
+    incy: Int,
 ) {
     ffi::chemv_(
         &(uplo as c_char),
@@ -1242,16 +1321,16 @@ pub unsafe fn chemv(
 #[inline]
 pub unsafe fn chbmv(
     uplo: u8,
-    n: i32,
-    k: i32,
+    n: Int,
+    k: Int,
     alpha: c32,
     a: &[c32],
-    lda: i32,
+    lda: Int,
     x: &[c32],
-    incx: i32,
+    incx: Int,
     beta: c32,
     y: &mut [c32],
-    incy: i32,
+    incy: Int,
 ) {
     ffi::chbmv_(
         &(uplo as c_char),
@@ -1271,14 +1350,14 @@ pub unsafe fn chbmv(
 #[inline]
 pub unsafe fn chpmv(
     uplo: u8,
-    n: i32,
+    n: Int,
     alpha: c32,
     ap: &[c32],
     x: &[c32],
-    incx: i32,
+    incx: Int,
     beta: c32,
     y: &mut [c32],
-    incy: i32,
+    incy: Int,
 ) {
     ffi::chpmv_(
         &(uplo as c_char),
@@ -1298,11 +1377,11 @@ pub unsafe fn ctrmv(
     uplo: u8,
     transa: u8,
     diag: u8,
-    n: i32,
+    n: Int,
     a: &[c32],
-    lda: i32,
+    lda: Int,
     b: &mut [c32],
-    incx: i32,
+    incx: Int,
 ) {
     ffi::ctrmv_(
         &(uplo as c_char),
@@ -1321,12 +1400,12 @@ pub unsafe fn ctbmv(
     uplo: u8,
     trans: u8,
     diag: u8,
-    n: i32,
-    k: i32,
+    n: Int,
+    k: Int,
     a: &[c32],
-    lda: i32,
+    lda: Int,
     x: &mut [c32],
-    incx: i32,
+    incx: Int,
 ) {
     ffi::ctbmv_(
         &(uplo as c_char),
@@ -1342,7 +1421,7 @@ pub unsafe fn ctbmv(
 }
 
 #[inline]
-pub unsafe fn ctpmv(uplo: u8, trans: u8, diag: u8, n: i32, ap: &[c32], x: &mut [c32], incx: i32) {
+pub unsafe fn ctpmv(uplo: u8, trans: u8, diag: u8, n: Int, ap: &[c32], x: &mut [c32], incx: Int) {
     ffi::ctpmv_(
         &(uplo as c_char),
         &(trans as c_char),
@@ -1359,11 +1438,11 @@ pub unsafe fn ctrsv(
     uplo: u8,
     trans: u8,
     diag: u8,
-    n: i32,
+    n: Int,
     a: &[c32],
-    lda: i32,
+    lda: Int,
     x: &mut [c32],
-    incx: i32,
+    incx: Int,
 ) {
     ffi::ctrsv_(
         &(uplo as c_char),
@@ -1382,12 +1461,12 @@ pub unsafe fn ctbsv(
     uplo: u8,
     trans: u8,
     diag: u8,
-    n: i32,
-    k: i32,
+    n: Int,
+    k: Int,
     a: &[c32],
-    lda: i32,
+    lda: Int,
     x: &mut [c32],
-    incx: i32,
+    incx: Int,
 ) {
     ffi::ctbsv_(
         &(uplo as c_char),
@@ -1403,7 +1482,7 @@ pub unsafe fn ctbsv(
 }
 
 #[inline]
-pub unsafe fn ctpsv(uplo: u8, trans: u8, diag: u8, n: i32, ap: &[c32], x: &mut [c32], incx: i32) {
+pub unsafe fn ctpsv(uplo: u8, trans: u8, diag: u8, n: Int, ap: &[c32], x: &mut [c32], incx: Int) {
     ffi::ctpsv_(
         &(uplo as c_char),
         &(trans as c_char),
@@ -1417,15 +1496,15 @@ pub unsafe fn ctpsv(uplo: u8, trans: u8, diag: u8, n: i32, ap: &[c32], x: &mut [
 
 #[inline]
 pub unsafe fn cgeru(
-    m: i32,
-    n: i32,
+    m: Int,
+    n: Int,
     alpha: c32,
     x: &[c32],
-    incx: i32,
+    incx: Int,
     y: &[c32],
-    incy: i32,
+    incy: Int,
     a: &mut [c32],
-    lda: i32,
+    lda: Int,
 ) {
     ffi::cgeru_(
         &m,
@@ -1442,15 +1521,15 @@ pub unsafe fn cgeru(
 
 #[inline]
 pub unsafe fn cgerc(
-    m: i32,
-    n: i32,
+    m: Int,
+    n: Int,
     alpha: c32,
     x: &[c32],
-    incx: i32,
+    incx: Int,
     y: &[c32],
-    incy: i32,
+    incy: Int,
     a: &mut [c32],
-    lda: i32,
+    lda: Int,
 ) {
     ffi::cgerc_(
         &m,
@@ -1466,7 +1545,7 @@ pub unsafe fn cgerc(
 }
 
 #[inline]
-pub unsafe fn cher(uplo: u8, n: i32, alpha: f32, x: &[c32], incx: i32, a: &mut [c32], lda: i32) {
+pub unsafe fn cher(uplo: u8, n: Int, alpha: f32, x: &[c32], incx: Int, a: &mut [c32], lda: Int) {
     ffi::cher_(
         &(uplo as c_char),
         &n,
@@ -1479,7 +1558,7 @@ pub unsafe fn cher(uplo: u8, n: i32, alpha: f32, x: &[c32], incx: i32, a: &mut [
 }
 
 #[inline]
-pub unsafe fn chpr(uplo: u8, n: i32, alpha: f32, x: &[c32], incx: i32, ap: &mut [c32]) {
+pub unsafe fn chpr(uplo: u8, n: Int, alpha: f32, x: &[c32], incx: Int, ap: &mut [c32]) {
     ffi::chpr_(
         &(uplo as c_char),
         &n,
@@ -1493,12 +1572,12 @@ pub unsafe fn chpr(uplo: u8, n: i32, alpha: f32, x: &[c32], incx: i32, ap: &mut
 #[inline]
 pub unsafe fn chpr2(
     uplo: u8,
-    n: i32,
+    n: Int,
     alpha: c32,
     x: &[c32],
-    incx: i32,
+    incx: Int,
     y: &[c32],
-    incy: i32,
+    incy: Int,
     ap: &mut [c32],
 ) {
     ffi::chpr2_(
@@ -1516,14 +1595,14 @@ pub unsafe fn chpr2(
 #[inline]
 pub unsafe fn cher2(
     uplo: u8,
-    n: i32,
+    n: Int,
     alpha: c32,
     x: &[c32],
-    incx: i32,
+    incx: Int,
     y: &[c32],
-    incy: i32,
+    incy: Int,
     a: &mut [c32],
-    lda: i32,
+    lda: Int,
 ) {
     ffi::cher2_(
         &(uplo as c_char),
@@ -1541,16 +1620,16 @@ pub unsafe fn cher2(
 #[inline]
 pub unsafe fn zgemv(
     trans: u8,
-    m: i32,
-    n: i32,
+    m: Int,
+    n: Int,
     alpha: c64,
     a: &[c64],
-    lda: i32,
+    lda: Int,
     x: &[c64],
-    incx: i32,
+    incx: Int,
     beta: c64,
     y: &mut [c64],
-    incy: i32,
+    incy: Int,
 ) {
     ffi::zgemv_(
         &(trans as c_char),
@@ -1570,18 +1649,18 @@ pub unsafe fn zgemv(
 #[inline]
 pub unsafe fn zgbmv(
     trans: u8,
-    m: i32,
-    n: i32,
-    kl: i32,
-    ku: i32,
+    m: Int,
+    n: Int,
+    kl: Int,
+    ku: Int,
     alpha: c64,
     a: &[c64],
-    lda: i32,
+    lda: Int,
     x: &[c64],
-    incx: i32,
+    incx: Int,
     beta: c64,
     y: &mut [c64],
-    incy: i32,
+    incy: Int,
 ) {
     ffi::zgbmv_(
         &(trans as c_char),
@@ -1603,15 +1682,15 @@ pub unsafe fn zgbmv(
 #[inline]
 pub unsafe fn zhemv(
     uplo: u8,
-    n: i32,
+    n: Int,
     alpha: c64,
     a: &[c64],
-    lda: i32,
+    lda: Int,
     x: &[c64],
-    incx: i32,
+    incx: Int,
     beta: c64,
     y: &mut [c64],
-    incy: i32,
+    incy: Int,
 ) {
     ffi::zhemv_(
         &(uplo as c_char),
@@ -1630,16 +1709,16 @@ pub unsafe fn zhemv(
 #[inline]
 pub unsafe fn zhbmv(
     uplo: u8,
-    n: i32,
-    k: i32,
+    n: Int,
+    k: Int,
     alpha: c64,
     a: &[c64],
-    lda: i32,
+    lda: Int,
     x: &[c64],
-    incx: i32,
+    incx: Int,
     beta: c64,
     y: &mut [c64],
-    incy: i32,
+    incy: Int,
 ) {
     ffi::zhbmv_(
         &(uplo as c_char),
@@ -1659,14 +1738,14 @@ pub unsafe fn zhbmv(
 #[inline]
 pub unsafe fn zhpmv(
     uplo: u8,
-    n: i32,
+    n: Int,
     alpha: c64,
     ap: &[c64],
     x: &[c64],
-    incx: i32,
+    incx: Int,
     beta: c64,
     y: &mut [c64],
-    incy: i32,
+    incy: Int,
 ) {
     ffi::zhpmv_(
         &(uplo as c_char),
@@ -1686,11 +1765,11 @@ pub unsafe fn ztrmv(
     uplo: u8,
     transa: u8,
     diag: u8,
-    n: i32,
+    n: Int,
     a: &[c64],
-    lda: i32,
+    lda: Int,
     b: &mut [c64],
-    incx: i32,
+    incx: Int,
 ) {
     ffi::ztrmv_(
         &(uplo as c_char),
@@ -1709,12 +1788,12 @@ pub unsafe fn ztbmv(
     uplo: u8,
     trans: u8,
     diag: u8,
-    n: i32,
-    k: i32,
+    n: Int,
+    k: Int,
     a: &[c64],
-    lda: i32,
+    lda: Int,
     x: &mut [c64],
-    incx: i32,
+    incx: Int,
 ) {
     ffi::ztbmv_(
         &(uplo as c_char),
@@ -1730,7 +1809,7 @@ pub unsafe fn ztbmv(
 }
 
 #[inline]
-pub unsafe fn ztpmv(uplo: u8, trans: u8, diag: u8, n: i32, ap: &[c64], x: &mut [c64], incx: i32) {
+pub unsafe fn ztpmv(uplo: u8, trans: u8, diag: u8, n: Int, ap: &[c64], x: &mut [c64], incx: Int) {
     ffi::ztpmv_(
         &(uplo as c_char),
         &(trans as c_char),
@@ -1747,11 +1826,11 @@ pub unsafe fn ztrsv(
     uplo: u8,
     trans: u8,
     diag: u8,
-    n: i32,
+    n: Int,
     a: &[c64],
-    lda: i32,
+    lda: Int,
     x: &mut [c64],
-    incx: i32,
+    incx: Int,
 ) {
     ffi::ztrsv_(
         &(uplo as c_char),
@@ -1770,12 +1849,12 @@ pub unsafe fn ztbsv(
     uplo: u8,
     trans: u8,
     diag: u8,
-    n: i32,
-    k: i32,
+    n: Int,
+    k: Int,
     a: &[c64],
-    lda: i32,
+    lda: Int,
     x: &mut [c64],
-    incx: i32,
+    incx: Int,
 ) {
     ffi::ztbsv_(
         &(uplo as c_char),
@@ -1791,7 +1870,7 @@ pub unsafe fn ztbsv(
 }
 
 #[inline]
-pub unsafe fn ztpsv(uplo: u8, trans: u8, diag: u8, n: i32, ap: &[c64], x: &mut [c64], incx: i32) {
+pub unsafe fn ztpsv(uplo: u8, trans: u8, diag: u8, n: Int, ap: &[c64], x: &mut [c64], incx: Int) {
     ffi::ztpsv_(
         &(uplo as c_char),
         &(trans as c_char),
@@ -1805,15 +1884,15 @@ pub unsafe fn ztpsv(uplo: u8, trans: u8, diag: u8, n: i32, ap: &[c64], x: &mut [
 
 #[inline]
 pub unsafe fn zgeru(
-    m: i32,
-    n: i32,
+    m: Int,
+    n: Int,
     alpha: c64,
     x: &[c64],
-    incx: i32,
+    incx: Int,
     y: &[c64],
-    incy: i32,
+    incy: Int,
     a: &mut [c64],
-    lda: i32,
+    lda: Int,
 ) {
     ffi::zgeru_(
         &m,
@@ -1830,15 +1909,15 @@ pub unsafe fn zgeru(
 
 #[inline]
 pub unsafe fn zgerc(
-    m: i32,
-    n: i32,
+    m: Int,
+    n: Int,
     alpha: c64,
     x: &[c64],
-    incx: i32,
+    incx: Int,
     y: &[c64],
-    incy: i32,
+    incy: Int,
     a: &mut [c64],
-    lda: i32,
+    lda: Int,
 ) {
     ffi::zgerc_(
         &m,
@@ -1854,7 +1933,7 @@ pub unsafe fn zgerc(
 }
 
 #[inline]
-pub unsafe fn zher(uplo: u8, n: i32, alpha: f64, x: &[c64], incx: i32, a: &mut [c64], lda: i32) {
+pub unsafe fn zher(uplo: u8, n: Int, alpha: f64, x: &[c64], incx: Int, a: &mut [c64], lda: Int) {
     ffi::zher_(
         &(uplo as c_char),
         &n,
@@ -1867,7 +1946,7 @@ pub unsafe fn zher(uplo: u8, n: i32, alpha: f64, x: &[c64], incx: i32, a: &mut [
 }
 
 #[inline]
-pub unsafe fn zhpr(uplo: u8, n: i32, alpha: f64, x: &[c64], incx: i32, ap: &mut [c64]) {
+pub unsafe fn zhpr(uplo: u8, n: Int, alpha: f64, x: &[c64], incx: Int, ap: &mut [c64]) {
     ffi::zhpr_(
         &(uplo as c_char),
         &n,
@@ -1881,14 +1960,14 @@ pub unsafe fn zhpr(uplo: u8, n: i32, alpha: f64, x: &[c64], incx: i32, ap: &mut
 #[inline]
 pub unsafe fn zher2(
     uplo: u8,
-    n: i32,
+    n: Int,
     alpha: c64,
     x: &[c64],
-    incx: i32,
+    incx: Int,
     y: &[c64],
-    incy: i32,
+    incy: Int,
     a: &mut [c64],
-    lda: i32,
+    lda: Int,
 ) {
     ffi::zher2_(
         &(uplo as c_char),
@@ -1906,12 +1985,12 @@ pub unsafe fn zher2(
 #[inline]
 pub unsafe fn zhpr2(
     uplo: u8,
-    n: i32,
+    n: Int,
     alpha: c64,
     x: &[c64],
-    incx: i32,
+    incx: Int,
     y: &[c64],
-    incy: i32,
+    incy: Int,
     ap: &mut [c64],
 ) {
     ffi::zhpr2_(
@@ -1930,17 +2009,17 @@ pub unsafe fn zhpr2(
 pub unsafe fn sgemm(
     transa: u8,
     transb: u8,
-    m: i32,
-    n: i32,
-    k: i32,
+    m: Int,
+    n: Int,
+    k: Int,
     alpha: f32,
     a: &[f32],
-    lda: i32,
+    lda: Int,
     b: &[f32],
-    ldb: i32,
+    ldb: Int,
     beta: f32,
     c: &mut [f32],
-    ldc: i32,
+    ldc: Int,
 ) {
     ffi::sgemm_(
         &(transa as c_char),
@@ -1963,16 +2042,16 @@ pub unsafe fn sgemm(
 pub unsafe fn ssymm(
     side: u8,
     uplo: u8,
-    m: i32,
-    n: i32,
+    m: Int,
+    n: Int,
     alpha: f32,
     a: &[f32],
-    lda: i32,
+    lda: Int,
     b: &[f32],
-    ldb: i32,
+    ldb: Int,
     beta: f32,
     c: &mut [f32],
-    ldc: i32,
+    ldc: Int,
 ) {
     ffi::ssymm_(
         &(side as c_char),
@@ -1994,14 +2073,14 @@ pub unsafe fn ssymm(
 pub unsafe fn ssyrk(
     uplo: u8,
     trans: u8,
-    n: i32,
-    k: i32,
+    n: Int,
+    k: Int,
     alpha: f32,
     a: &[f32],
-    lda: i32,
+    lda: Int,
     beta: f32,
     c: &mut [f32],
-    ldc: i32,
+    ldc: Int,
 ) {
     ffi::ssyrk_(
         &(uplo as c_char),
@@ -2021,16 +2100,16 @@ pub unsafe fn ssyrk(
 pub unsafe fn ssyr2k(
     uplo: u8,
     trans: u8,
-    n: i32,
-    k: i32,
+    n: Int,
+    k: Int,
     alpha: f32,
     a: &[f32],
-    lda: i32,
+    lda: Int,
     b: &[f32],
-    ldb: i32,
+    ldb: Int,
     beta: f32,
     c: &mut [f32],
-    ldc: i32,
+    ldc: Int,
 ) {
     ffi::ssyr2k_(
         &(uplo as c_char),
@@ -2054,13 +2133,13 @@ pub unsafe fn strmm(
     uplo: u8,
     transa: u8,
     diag: u8,
-    m: i32,
-    n: i32,
+    m: Int,
+    n: Int,
     alpha: f32,
     a: &[f32],
-    lda: i32,
+    lda: Int,
     b: &mut [f32],
-    ldb: i32,
+    ldb: Int,
 ) {
     ffi::strmm_(
         &(side as c_char),
@@ -2083,13 +2162,13 @@ pub unsafe fn strsm(
     uplo: u8,
     transa: u8,
     diag: u8,
-    m: i32,
-    n: i32,
+    m: Int,
+    n: Int,
     alpha: f32,
     a: &[f32],
-    lda: i32,
+    lda: Int,
     b: &mut [f32],
-    ldb: i32,
+    ldb: Int,
 ) {
     ffi::strsm_(
         &(side as c_char),
@@ -2110,17 +2189,17 @@ pub unsafe fn strsm(
 pub unsafe fn dgemm(
     transa: u8,
     transb: u8,
-    m: i32,
-    n: i32,
-    k: i32,
+    m: Int,
+    n: Int,
+    k: Int,
     alpha: f64,
     a: &[f64],
-    lda: i32,
+    lda: Int,
     b: &[f64],
-    ldb: i32,
+    ldb: Int,
     beta: f64,
     c: &mut [f64],
-    ldc: i32,
+    ldc: Int,
 ) {
     ffi::dgemm_(
         &(transa as c_char),
@@ -2143,16 +2222,16 @@ pub unsafe fn dgemm(
 pub unsafe fn dsymm(
     side: u8,
     uplo: u8,
-    m: i32,
-    n: i32,
+    m: Int,
+    n: Int,
     alpha: f64,
     a: &[f64],
-    lda: i32,
+    lda: Int,
     b: &[f64],
-    ldb: i32,
+    ldb: Int,
     beta: f64,
     c: &mut [f64],
-    ldc: i32,
+    ldc: Int,
 ) {
     ffi::dsymm_(
         &(side as c_char),
@@ -2174,14 +2253,14 @@ pub unsafe fn dsymm(
 pub unsafe fn dsyrk(
     uplo: u8,
     trans: u8,
-    n: i32,
-    k: i32,
+    n: Int,
+    k: Int,
     alpha: f64,
     a: &[f64],
-    lda: i32,
+    lda: Int,
     beta: f64,
     c: &mut [f64],
-    ldc: i32,
+    ldc: Int,
 ) {
     ffi::dsyrk_(
         &(uplo as c_char),
@@ -2201,16 +2280,16 @@ pub unsafe fn dsyrk(
 pub unsafe fn dsyr2k(
     uplo: u8,
     trans: u8,
-    n: i32,
-    k: i32,
+    n: Int,
+    k: Int,
     alpha: f64,
     a: &[f64],
-    lda: i32,
+    lda: Int,
     b: &[f64],
-    ldb: i32,
+    ldb: Int,
     beta: f64,
     c: &mut [f64],
-    ldc: i32,
+    ldc: Int,
 ) {
     ffi::dsyr2k_(
         &(uplo as c_char),
@@ -2234,13 +2313,13 @@ pub unsafe fn dtrmm(
     uplo: u8,
     transa: u8,
     diag: u8,
-    m: i32,
-    n: i32,
+    m: Int,
+    n: Int,
     alpha: f64,
     a: &[f64],
-    lda: i32,
+    lda: Int,
     b: &mut [f64],
-    ldb: i32,
+    ldb: Int,
 ) {
     ffi::dtrmm_(
         &(side as c_char),
@@ -2263,13 +2342,13 @@ pub unsafe fn dtrsm(
     uplo: u8,
     transa: u8,
     diag: u8,
-    m: i32,
-    n: i32,
+    m: Int,
+    n: Int,
     alpha: f64,
     a: &[f64],
-    lda: i32,
+    lda: Int,
     b: &mut [f64],
-    ldb: i32,
+    ldb: Int,
 ) {
     ffi::dtrsm_(
         &(side as c_char),
@@ -2290,17 +2369,17 @@ pub unsafe fn dtrsm(
 pub unsafe fn cgemm(
     transa: u8,
     transb: u8,
-    m: i32,
-    n: i32,
-    k: i32,
+    m: Int,
+    n: Int,
+    k: Int,
     alpha: c32,
     a: &[c32],
-    lda: i32,
+    lda: Int,
     b: &[c32],
-    ldb: i32,
+    ldb: Int,
     beta: c32,
     c: &mut [c32],
-    ldc: i32,
+    ldc: Int,
 ) {
     ffi::cgemm_(
         &(transa as c_char),
@@ -2323,16 +2402,16 @@ pub unsafe fn cgemm(
 pub unsafe fn csymm(
     side: u8,
     uplo: u8,
-    m: i32,
-    n: i32,
+    m: Int,
+    n: Int,
     alpha: c32,
     a: &[c32],
-    lda: i32,
+    lda: Int,
     b: &[c32],
-    ldb: i32,
+    ldb: Int,
     beta: c32,
     c: &mut [c32],
-    ldc: i32,
+    ldc: Int,
 ) {
     ffi::csymm_(
         &(side as c_char),
@@ -2354,16 +2433,16 @@ pub unsafe fn csymm(
 pub unsafe fn chemm(
     side: u8,
     uplo: u8,
-    m: i32,
-    n: i32,
+    m: Int,
+    n: Int,
     alpha: c32,
     a: &[c32],
-    lda: i32,
+    lda: Int,
     b: &[c32],
-    ldb: i32,
+    ldb: Int,
     beta: c32,
     c: &mut [c32],
-    ldc: i32,
+    ldc: Int,
 ) {
     ffi::chemm_(
         &(side as c_char),
@@ -2385,14 +2464,14 @@ pub unsafe fn chemm(
 pub unsafe fn csyrk(
     uplo: u8,
     trans: u8,
-    n: i32,
-    k: i32,
+    n: Int,
+    k: Int,
     alpha: c32,
     a: &[c32],
-    lda: i32,
+    lda: Int,
     beta: c32,
     c: &mut [c32],
-    ldc: i32,
+    ldc: Int,
 ) {
     ffi::csyrk_(
         &(uplo as c_char),
@@ -2412,14 +2491,14 @@ pub unsafe fn csyrk(
 pub unsafe fn cherk(
     uplo: u8,
     trans: u8,
-    n: i32,
-    k: i32,
+    n: Int,
+    k: Int,
     alpha: f32,
     a: &[c32],
-    lda: i32,
+    lda: Int,
     beta: f32,
     c: &mut [c32],
-    ldc: i32,
+    ldc: Int,
 ) {
     ffi::cherk_(
         &(uplo as c_char),
@@ -2439,16 +2518,16 @@ pub unsafe fn cherk(
 pub unsafe fn csyr2k(
     uplo: u8,
     trans: u8,
-    n: i32,
-    k: i32,
+    n: Int,
+    k: Int,
     alpha: c32,
     a: &[c32],
-    lda: i32,
+    lda: Int,
     b: &[c32],
-    ldb: i32,
+    ldb: Int,
     beta: c32,
     c: &mut [c32],
-    ldc: i32,
+    ldc: Int,
 ) {
     ffi::csyr2k_(
         &(uplo as c_char),
@@ -2470,16 +2549,16 @@ pub unsafe fn csyr2k(
 pub unsafe fn cher2k(
     uplo: u8,
     trans: u8,
-    n: i32,
-    k: i32,
+    n: Int,
+    k: Int,
     alpha: c32,
     a: &[c32],
-    lda: i32,
+    lda: Int,
     b: &[c32],
-    ldb: i32,
+    ldb: Int,
     beta: f32,
     c: &mut [c32],
-    ldc: i32,
+    ldc: Int,
 ) {
     ffi::cher2k_(
         &(uplo as c_char),
@@ -2503,13 +2582,13 @@ pub unsafe fn ctrmm(
     uplo: u8,
     transa: u8,
     diag: u8,
-    m: i32,
-    n: i32,
+    m: Int,
+    n: Int,
     alpha: c32,
     a: &[c32],
-    lda: i32,
+    lda: Int,
     b: &mut [c32],
-    ldb: i32,
+    ldb: Int,
 ) {
     ffi::ctrmm_(
         &(side as c_char),
@@ -2532,13 +2611,13 @@ pub unsafe fn ctrsm(
     uplo: u8,
     transa: u8,
     diag: u8,
-    m: i32,
-    n: i32,
+    m: Int,
+    n: Int,
     alpha: c32,
     a: &[c32],
-    lda: i32,
+    lda: Int,
     b: &mut [c32],
-    ldb: i32,
+    ldb: Int,
 ) {
     ffi::ctrsm_(
         &(side as c_char),
@@ -2559,17 +2638,17 @@ pub unsafe fn ctrsm(
 pub unsafe fn zgemm(
     transa: u8,
     transb: u8,
-    m: i32,
-    n: i32,
-    k: i32,
+    m: Int,
+    n: Int,
+    k: Int,
     alpha: c64,
     a: &[c64],
-    lda: i32,
+    lda: Int,
     b: &[c64],
-    ldb: i32,
+    ldb: Int,
     beta: c64,
     c: &mut [c64],
-    ldc: i32,
+    ldc: Int,
 ) {
     ffi::zgemm_(
         &(transa as c_char),
@@ -2592,16 +2671,16 @@ pub unsafe fn zgemm(
 pub unsafe fn zsymm(
     side: u8,
     uplo: u8,
-    m: i32,
-    n: i32,
+    m: Int,
+    n: Int,
     alpha: c64,
     a: &[c64],
-    lda: i32,
+    lda: Int,
     b: &[c64],
-    ldb: i32,
+    ldb: Int,
     beta: c64,
     c: &mut [c64],
-    ldc: i32,
+    ldc: Int,
 ) {
     ffi::zsymm_(
         &(side as c_char),
@@ -2623,16 +2702,16 @@ pub unsafe fn zsymm(
 pub unsafe fn zhemm(
     side: u8,
     uplo: u8,
-    m: i32,
-    n: i32,
+    m: Int,
+    n: Int,
     alpha: c64,
     a: &[c64],
-    lda: i32,
+    lda: Int,
     b: &[c64],
-    ldb: i32,
+    ldb: Int,
     beta: c64,
     c: &mut [c64],
-    ldc: i32,
+    ldc: Int,
 ) {
     ffi::zhemm_(
         &(side as c_char),
@@ -2654,14 +2733,14 @@ pub unsafe fn zhemm(
 pub unsafe fn zsyrk(
     uplo: u8,
     trans: u8,
-    n: i32,
-    k: i32,
+    n: Int,
+    k: Int,
     alpha: c64,
     a: &[c64],
-    lda: i32,
+    lda: Int,
     beta: c64,
     c: &mut [c64],
-    ldc: i32,
+    ldc: Int,
 ) {
     ffi::zsyrk_(
         &(uplo as c_char),
@@ -2681,14 +2760,14 @@ pub unsafe fn zsyrk(
 pub unsafe fn zherk(
     uplo: u8,
     trans: u8,
-    n: i32,
-    k: i32,
+    n: Int,
+    k: Int,
     alpha: f64,
     a: &[c64],
-    lda: i32,
+    lda: Int,
     beta: f64,
     c: &mut [c64],
-    ldc: i32,
+    ldc: Int,
 ) {
     ffi::zherk_(
         &(uplo as c_char),
@@ -2708,16 +2787,16 @@ pub unsafe fn zherk(
 pub unsafe fn zsyr2k(
     uplo: u8,
     trans: u8,
-    n: i32,
-    k: i32,
+    n: Int,
+    k: Int,
     alpha: c64,
     a: &[c64],
-    lda: i32,
+    lda: Int,
     b: &[c64],
-    ldb: i32,
+    ldb: Int,
     beta: c64,
     c: &mut [c64],
-    ldc: i32,
+    ldc: Int,
 ) {
     ffi::zsyr2k_(
         &(uplo as c_char),
@@ -2739,16 +2818,16 @@ pub unsafe fn zsyr2k(
 pub unsafe fn zher2k(
     uplo: u8,
     trans: u8,
-    n: i32,
-    k: i32,
+    n: Int,
+    k: Int,
     alpha: c64,
     a: &[c64],
-    lda: i32,
+    lda: Int,
     b: &[c64],
-    ldb: i32,
+    ldb: Int,
     beta: f64,
     c: &mut [c64],
-    ldc: i32,
+    ldc: Int,
 ) {
     ffi::zher2k_(
         &(uplo as c_char),
@@ -2772,13 +2851,13 @@ pub unsafe fn ztrmm(
     uplo: u8,
     transa: u8,
     diag: u8,
-    m: i32,
-    n: i32,
+    m: Int,
+    n: Int,
     alpha: c64,
     a: &[c64],
-    lda: i32,
+    lda: Int,
     b: &mut [c64],
-    ldb: i32,
+    ldb: Int,
 ) {
     ffi::ztrmm_(
         &(side as c_char),
@@ -2801,13 +2880,13 @@ pub unsafe fn ztrsm(
     uplo: u8,
     transa: u8,
     diag: u8,
-    m: i32,
-    n: i32,
+    m: Int,
+    n: Int,
     alpha: c64,
     a: &[c64],
-    lda: i32,
+    lda: Int,
     b: &mut [c64],
-    ldb: i32,
+    ldb: Int,
 ) {
     ffi::ztrsm_(
         &(side as c_char),
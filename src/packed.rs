@@ -0,0 +1,392 @@
+//! Matrices in packed storage.
+//!
+//! A packed matrix stores one triangle of a square matrix column by column in `n * (n + 1) / 2`
+//! elements, which is the layout expected by `spmv`, `hpmv`, `tpmv`, `tpsv`, `spr`, `hpr`,
+//! `spr2`, and `hpr2`. The types in this module couple the data, which can be owned or borrowed,
+//! with the order and the triangle, and they call the packed routines with them.
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use blas::packed::PackedSymmetric;
+//! use blas::Uplo;
+//!
+//! let dense = vec![
+//!     2.0, 1.0,
+//!     1.0, 3.0,
+//! ];
+//! let a = PackedSymmetric::from_dense(Uplo::Upper, 2, &dense, 2).unwrap();
+//! assert_eq!(a.as_slice(), &[2.0, 1.0, 3.0]);
+//!
+//! let x = vec![1.0, 1.0];
+//! let mut y = vec![0.0; 2];
+//! a.mul_vec(1.0, (&x[..]).into(), 0.0, (&mut y[..]).into()).unwrap();
+//! assert_eq!(y, vec![3.0, 4.0]);
+//! ```
+
+use core::marker::PhantomData;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::safe::{check_dimension, check_len, packed_len, BlasError};
+#[cfg(feature = "alloc")]
+use crate::safe::{check_leading_dimension, matrix_len};
+use crate::view::{self, check_match, VecMut, VecRef};
+use crate::{Blas, Diag, Int, Scalar, Transpose, Uplo};
+
+/// A symmetric matrix in packed storage.
+#[derive(Clone, Copy, Debug)]
+pub struct PackedSymmetric<T, D> {
+    packed: Packed<T, D>,
+}
+
+/// A Hermitian matrix in packed storage.
+///
+/// The imaginary parts of the diagonal are assumed to be zero.
+#[derive(Clone, Copy, Debug)]
+pub struct PackedHermitian<T, D> {
+    packed: Packed<T, D>,
+}
+
+/// A triangular matrix in packed storage.
+///
+/// With a unit diagonal, the diagonal is not referenced and reads as one.
+#[derive(Clone, Copy, Debug)]
+pub struct PackedTriangular<T, D> {
+    packed: Packed<T, D>,
+    diag: Diag,
+}
+
+/// The data, order, and triangle shared by the packed matrices.
+#[derive(Clone, Copy, Debug)]
+struct Packed<T, D> {
+    data: D,
+    n: Int,
+    uplo: Uplo,
+    marker: PhantomData<T>,
+}
+
+macro_rules! implement {
+    ($name:ident) => {
+        impl<T: Scalar, D: AsRef<[T]>> $name<T, D> {
+            /// Return the order.
+            #[inline]
+            pub fn n(&self) -> Int {
+                self.packed.n
+            }
+
+            /// Return the stored triangle.
+            #[inline]
+            pub fn uplo(&self) -> Uplo {
+                self.packed.uplo
+            }
+
+            /// Return the packed elements.
+            #[inline]
+            pub fn as_slice(&self) -> &[T] {
+                self.packed.data.as_ref()
+            }
+
+            /// Return the underlying data.
+            #[inline]
+            pub fn into_inner(self) -> D {
+                self.packed.data
+            }
+
+            /// Return the stored element `(i, j)`, which is in the stored triangle.
+            #[inline]
+            pub fn get_mut(&mut self, i: Int, j: Int) -> Option<&mut T>
+            where
+                D: AsMut<[T]>,
+            {
+                let position = self.packed.position(i, j)?;
+                Some(&mut self.packed.data.as_mut()[position])
+            }
+
+            /// Return the matrix in dense column-major storage with leading dimension `n`.
+            #[cfg(feature = "alloc")]
+            pub fn to_dense(&self) -> Vec<T> {
+                let n = self.packed.n;
+                let mut dense = Vec::with_capacity((n as usize) * (n as usize));
+                for j in 0..n {
+                    dense.extend((0..n).map(|i| self.get(i, j).unwrap()));
+                }
+                dense
+            }
+        }
+    };
+}
+
+implement!(PackedSymmetric);
+implement!(PackedHermitian);
+implement!(PackedTriangular);
+
+impl<T: Scalar, D: AsRef<[T]>> PackedSymmetric<T, D> {
+    /// Create a symmetric matrix of order `n` whose triangle `uplo` is packed in `data`.
+    pub fn new(uplo: Uplo, n: Int, data: D) -> Result<Self, BlasError> {
+        Ok(PackedSymmetric {
+            packed: Packed::new(("PackedSymmetric::new", 2), uplo, n, data)?,
+        })
+    }
+
+    /// Return element `(i, j)`.
+    #[inline]
+    pub fn get(&self, i: Int, j: Int) -> Option<T> {
+        let data = self.packed.data.as_ref();
+        match self.packed.position(i, j) {
+            Some(position) => Some(data[position]),
+            None => self.packed.position(j, i).map(|position| data[position]),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Scalar> PackedSymmetric<T, Vec<T>> {
+    /// Pack the triangle `uplo` of a dense matrix of order `n` with leading dimension `lda`.
+    pub fn from_dense(uplo: Uplo, n: Int, a: &[T], lda: Int) -> Result<Self, BlasError> {
+        let data = pack(("PackedSymmetric::from_dense", 2), uplo, n, a, lda)?;
+        Ok(PackedSymmetric {
+            packed: Packed::owned(uplo, n, data),
+        })
+    }
+}
+
+impl<T: Blas<Real = T>, D: AsRef<[T]>> PackedSymmetric<T, D> {
+    /// Compute `y := alpha * A * x + beta * y`.
+    pub fn mul_vec(&self, alpha: T, x: VecRef<T>, beta: T, y: VecMut<T>) -> Result<(), BlasError> {
+        let routine = "PackedSymmetric::mul_vec";
+        let (uplo, n) = (self.packed.uplo, self.packed.n);
+        check_match(routine, 2, x.len(), n)?;
+        check_match(routine, 4, y.len(), n)?;
+        view::hpmv(uplo, alpha, self.as_slice(), x, beta, y)
+    }
+
+    /// Compute `A := alpha * x * x^T + A`.
+    pub fn rank1_update(&mut self, alpha: T, x: VecRef<T>) -> Result<(), BlasError>
+    where
+        D: AsMut<[T]>,
+    {
+        let (uplo, n) = (self.packed.uplo, self.packed.n);
+        check_match("PackedSymmetric::rank1_update", 2, x.len(), n)?;
+        view::hpr(uplo, alpha, x, self.packed.data.as_mut())
+    }
+
+    /// Compute `A := alpha * x * y^T + alpha * y * x^T + A`.
+    pub fn rank2_update(&mut self, alpha: T, x: VecRef<T>, y: VecRef<T>) -> Result<(), BlasError>
+    where
+        D: AsMut<[T]>,
+    {
+        let routine = "PackedSymmetric::rank2_update";
+        let (uplo, n) = (self.packed.uplo, self.packed.n);
+        check_match(routine, 2, x.len(), n)?;
+        check_match(routine, 3, y.len(), n)?;
+        view::hpr2(uplo, alpha, x, y, self.packed.data.as_mut())
+    }
+}
+
+impl<T: Scalar, D: AsRef<[T]>> PackedHermitian<T, D> {
+    /// Create a Hermitian matrix of order `n` whose triangle `uplo` is packed in `data`.
+    pub fn new(uplo: Uplo, n: Int, data: D) -> Result<Self, BlasError> {
+        Ok(PackedHermitian {
+            packed: Packed::new(("PackedHermitian::new", 2), uplo, n, data)?,
+        })
+    }
+
+    /// Return element `(i, j)`.
+    #[inline]
+    pub fn get(&self, i: Int, j: Int) -> Option<T> {
+        let data = self.packed.data.as_ref();
+        if i == j {
+            let position = self.packed.position(i, j)?;
+            return Some(T::from_real(data[position].re()));
+        }
+        match self.packed.position(i, j) {
+            Some(position) => Some(data[position]),
+            None => self
+                .packed
+                .position(j, i)
+                .map(|position| data[position].conj()),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Scalar> PackedHermitian<T, Vec<T>> {
+    /// Pack the triangle `uplo` of a dense matrix of order `n` with leading dimension `lda`.
+    pub fn from_dense(uplo: Uplo, n: Int, a: &[T], lda: Int) -> Result<Self, BlasError> {
+        let data = pack(("PackedHermitian::from_dense", 2), uplo, n, a, lda)?;
+        Ok(PackedHermitian {
+            packed: Packed::owned(uplo, n, data),
+        })
+    }
+}
+
+impl<T: Blas, D: AsRef<[T]>> PackedHermitian<T, D> {
+    /// Compute `y := alpha * A * x + beta * y`.
+    pub fn mul_vec(&self, alpha: T, x: VecRef<T>, beta: T, y: VecMut<T>) -> Result<(), BlasError> {
+        let routine = "PackedHermitian::mul_vec";
+        let (uplo, n) = (self.packed.uplo, self.packed.n);
+        check_match(routine, 2, x.len(), n)?;
+        check_match(routine, 4, y.len(), n)?;
+        view::hpmv(uplo, alpha, self.as_slice(), x, beta, y)
+    }
+
+    /// Compute `A := alpha * x * x^H + A`.
+    pub fn rank1_update(&mut self, alpha: T::Real, x: VecRef<T>) -> Result<(), BlasError>
+    where
+        D: AsMut<[T]>,
+    {
+        let (uplo, n) = (self.packed.uplo, self.packed.n);
+        check_match("PackedHermitian::rank1_update", 2, x.len(), n)?;
+        view::hpr(uplo, alpha, x, self.packed.data.as_mut())
+    }
+
+    /// Compute `A := alpha * x * y^H + conj(alpha) * y * x^H + A`.
+    pub fn rank2_update(&mut self, alpha: T, x: VecRef<T>, y: VecRef<T>) -> Result<(), BlasError>
+    where
+        D: AsMut<[T]>,
+    {
+        let routine = "PackedHermitian::rank2_update";
+        let (uplo, n) = (self.packed.uplo, self.packed.n);
+        check_match(routine, 2, x.len(), n)?;
+        check_match(routine, 3, y.len(), n)?;
+        view::hpr2(uplo, alpha, x, y, self.packed.data.as_mut())
+    }
+}
+
+impl<T: Scalar, D: AsRef<[T]>> PackedTriangular<T, D> {
+    /// Create a triangular matrix of order `n` whose triangle `uplo` is packed in `data`.
+    pub fn new(uplo: Uplo, diag: Diag, n: Int, data: D) -> Result<Self, BlasError> {
+        Ok(PackedTriangular {
+            packed: Packed::new(("PackedTriangular::new", 3), uplo, n, data)?,
+            diag,
+        })
+    }
+
+    /// Return the kind of the diagonal.
+    #[inline]
+    pub fn diag(&self) -> Diag {
+        self.diag
+    }
+
+    /// Return element `(i, j)`, which is zero outside of the stored triangle.
+    #[inline]
+    pub fn get(&self, i: Int, j: Int) -> Option<T> {
+        if i < 0 || j < 0 || i >= self.packed.n || j >= self.packed.n {
+            return None;
+        }
+        if i == j && self.diag == Diag::Unit {
+            return Some(T::one());
+        }
+        let data = self.packed.data.as_ref();
+        match self.packed.position(i, j) {
+            Some(position) => Some(data[position]),
+            None => Some(T::zero()),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Scalar> PackedTriangular<T, Vec<T>> {
+    /// Pack the triangle `uplo` of a dense matrix of order `n` with leading dimension `lda`.
+    pub fn from_dense(
+        uplo: Uplo,
+        diag: Diag,
+        n: Int,
+        a: &[T],
+        lda: Int,
+    ) -> Result<Self, BlasError> {
+        let data = pack(("PackedTriangular::from_dense", 3), uplo, n, a, lda)?;
+        Ok(PackedTriangular {
+            packed: Packed::owned(uplo, n, data),
+            diag,
+        })
+    }
+}
+
+impl<T: Blas, D: AsRef<[T]>> PackedTriangular<T, D> {
+    /// Compute `x := op(A) * x`.
+    pub fn mul_vec_in_place(&self, trans: Transpose, x: VecMut<T>) -> Result<(), BlasError> {
+        let (uplo, n) = (self.packed.uplo, self.packed.n);
+        check_match("PackedTriangular::mul_vec_in_place", 2, x.len(), n)?;
+        view::tpmv(uplo, trans, self.diag, self.as_slice(), x)
+    }
+
+    /// Solve `op(A) * x = b`, where `x` holds `b` on entry.
+    pub fn solve_in_place(&self, trans: Transpose, x: VecMut<T>) -> Result<(), BlasError> {
+        let (uplo, n) = (self.packed.uplo, self.packed.n);
+        check_match("PackedTriangular::solve_in_place", 2, x.len(), n)?;
+        view::tpsv(uplo, trans, self.diag, self.as_slice(), x)
+    }
+}
+
+impl<T, D: AsRef<[T]>> Packed<T, D> {
+    /// Check the order, which is parameter `param` of `routine`, and the data following it.
+    fn new(
+        (routine, param): (&'static str, usize),
+        uplo: Uplo,
+        n: Int,
+        data: D,
+    ) -> Result<Self, BlasError> {
+        check_dimension(routine, param, n)?;
+        check_len(routine, param + 1, packed_len(n), data.as_ref().len())?;
+        Ok(Packed {
+            data,
+            n,
+            uplo,
+            marker: PhantomData,
+        })
+    }
+
+    /// Return the position of element `(i, j)` if it is in the stored triangle.
+    #[inline]
+    fn position(&self, i: Int, j: Int) -> Option<usize> {
+        if i < 0 || j < 0 || i >= self.n || j >= self.n {
+            return None;
+        }
+        let (i, j, n) = (i as usize, j as usize, self.n as usize);
+        match self.uplo {
+            Uplo::Upper if i <= j => Some(i + j * (j + 1) / 2),
+            Uplo::Lower if i >= j => Some(i - j + j * (2 * n - j + 1) / 2),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Packed<T, Vec<T>> {
+    #[inline]
+    fn owned(uplo: Uplo, n: Int, data: Vec<T>) -> Self {
+        Packed {
+            data,
+            n,
+            uplo,
+            marker: PhantomData,
+        }
+    }
+}
+
+/// Pack the triangle `uplo` of a dense matrix whose order is parameter `param` of `routine`.
+#[cfg(feature = "alloc")]
+fn pack<T: Copy>(
+    (routine, param): (&'static str, usize),
+    uplo: Uplo,
+    n: Int,
+    a: &[T],
+    lda: Int,
+) -> Result<Vec<T>, BlasError> {
+    check_dimension(routine, param, n)?;
+    check_leading_dimension(routine, param + 2, lda, n.max(1))?;
+    check_len(routine, param + 1, matrix_len(n, n, lda), a.len())?;
+    let (n, lda) = (n as usize, lda as usize);
+    let mut data = Vec::with_capacity(packed_len(n as Int));
+    for j in 0..n {
+        let rows = match uplo {
+            Uplo::Upper => 0..j + 1,
+            Uplo::Lower => j..n,
+        };
+        data.extend(rows.map(|i| a[i + j * lda]));
+    }
+    Ok(data)
+}
@@ -0,0 +1,819 @@
+use core::ffi::{c_double, c_float};
+
+use super::start;
+use crate::{c32, c64, Int, Scalar};
+
+unsafe fn rot<T: Scalar>(
+    n: Int,
+    x: *mut T,
+    incx: Int,
+    y: *mut T,
+    incy: Int,
+    c: T::Real,
+    s: T::Real,
+) {
+    if n <= 0 {
+        return;
+    }
+    let (c, s) = (T::from_real(c), T::from_real(s));
+    let (mut ix, mut iy) = (start(n, incx), start(n, incy));
+    for _ in 0..n {
+        let temp = c * *x.offset(ix) + s * *y.offset(iy);
+        *y.offset(iy) = c * *y.offset(iy) - s * *x.offset(ix);
+        *x.offset(ix) = temp;
+        ix += incx as isize;
+        iy += incy as isize;
+    }
+}
+
+unsafe fn swap<T: Scalar>(n: Int, x: *mut T, incx: Int, y: *mut T, incy: Int) {
+    if n <= 0 {
+        return;
+    }
+    let (mut ix, mut iy) = (start(n, incx), start(n, incy));
+    for _ in 0..n {
+        let temp = *x.offset(ix);
+        *x.offset(ix) = *y.offset(iy);
+        *y.offset(iy) = temp;
+        ix += incx as isize;
+        iy += incy as isize;
+    }
+}
+
+unsafe fn scal<T: Scalar>(n: Int, a: T, x: *mut T, incx: Int) {
+    if n <= 0 || incx <= 0 {
+        return;
+    }
+    for i in 0..n as isize {
+        let xi = x.offset(i * incx as isize);
+        *xi = a * *xi;
+    }
+}
+
+unsafe fn copy<T: Scalar>(n: Int, x: *const T, incx: Int, y: *mut T, incy: Int) {
+    if n <= 0 {
+        return;
+    }
+    let (mut ix, mut iy) = (start(n, incx), start(n, incy));
+    for _ in 0..n {
+        *y.offset(iy) = *x.offset(ix);
+        ix += incx as isize;
+        iy += incy as isize;
+    }
+}
+
+unsafe fn axpy<T: Scalar>(n: Int, alpha: T, x: *const T, incx: Int, y: *mut T, incy: Int) {
+    if n <= 0 || alpha == T::zero() {
+        return;
+    }
+    let (mut ix, mut iy) = (start(n, incx), start(n, incy));
+    for _ in 0..n {
+        *y.offset(iy) = *y.offset(iy) + alpha * *x.offset(ix);
+        ix += incx as isize;
+        iy += incy as isize;
+    }
+}
+
+unsafe fn dot<T: Scalar>(
+    n: Int,
+    x: *const T,
+    incx: Int,
+    y: *const T,
+    incy: Int,
+    conjugate: bool,
+) -> T {
+    let mut temp = T::zero();
+    if n <= 0 {
+        return temp;
+    }
+    let (mut ix, mut iy) = (start(n, incx), start(n, incy));
+    for _ in 0..n {
+        let xi = *x.offset(ix);
+        let xi = if conjugate { xi.conj() } else { xi };
+        temp = temp + xi * *y.offset(iy);
+        ix += incx as isize;
+        iy += incy as isize;
+    }
+    temp
+}
+
+macro_rules! implement_real {
+    ($real:ident, $rotg:ident, $rotmg:ident, $rotm:ident, $ssq:ident, $asum:ident, $iamax:ident) => {
+        unsafe fn $rotg(a: *mut $real, b: *mut $real, c: *mut $real, s: *mut $real) {
+            let (sa, sb) = (*a, *b);
+            let roe = if sa.abs() > sb.abs() { sa } else { sb };
+            let scale = sa.abs() + sb.abs();
+            let (r, z);
+            if scale == 0.0 {
+                *c = 1.0;
+                *s = 0.0;
+                r = 0.0;
+                z = 0.0;
+            } else {
+                let norm = scale * ((sa / scale).powi(2) + (sb / scale).powi(2)).sqrt();
+                r = if roe >= 0.0 { norm } else { -norm };
+                *c = sa / r;
+                *s = sb / r;
+                z = if sa.abs() > sb.abs() {
+                    *s
+                } else if *c != 0.0 {
+                    1.0 / *c
+                } else {
+                    1.0
+                };
+            }
+            *a = r;
+            *b = z;
+        }
+
+        unsafe fn $rotmg(
+            d1: *mut $real,
+            d2: *mut $real,
+            x1: *mut $real,
+            y1: *const $real,
+            param: *mut $real,
+        ) {
+            const GAM: $real = 4096.0;
+            const GAMSQ: $real = 16777216.0;
+            const RGAMSQ: $real = 5.9604645e-8;
+
+            let (mut d1_, mut d2_, mut x1_, y1) = (*d1, *d2, *x1, *y1);
+            let (mut h11, mut h12, mut h21, mut h22) = (0.0, 0.0, 0.0, 0.0);
+            let mut flag: $real;
+
+            if d1_ < 0.0 {
+                flag = -1.0;
+                d1_ = 0.0;
+                d2_ = 0.0;
+                x1_ = 0.0;
+            } else {
+                let p2 = d2_ * y1;
+                if p2 == 0.0 {
+                    *param = -2.0;
+                    return;
+                }
+                let p1 = d1_ * x1_;
+                let q2 = p2 * y1;
+                let q1 = p1 * x1_;
+                if q1.abs() > q2.abs() {
+                    h21 = -y1 / x1_;
+                    h12 = p2 / p1;
+                    let u = 1.0 - h12 * h21;
+                    if u > 0.0 {
+                        flag = 0.0;
+                        d1_ /= u;
+                        d2_ /= u;
+                        x1_ *= u;
+                    } else {
+                        flag = -1.0;
+                        h11 = 0.0;
+                        h12 = 0.0;
+                        h21 = 0.0;
+                        h22 = 0.0;
+                        d1_ = 0.0;
+                        d2_ = 0.0;
+                        x1_ = 0.0;
+                    }
+                } else if q2 < 0.0 {
+                    flag = -1.0;
+                    h11 = 0.0;
+                    h12 = 0.0;
+                    h21 = 0.0;
+                    h22 = 0.0;
+                    d1_ = 0.0;
+                    d2_ = 0.0;
+                    x1_ = 0.0;
+                } else {
+                    flag = 1.0;
+                    h11 = p1 / p2;
+                    h22 = x1_ / y1;
+                    let u = 1.0 + h11 * h22;
+                    let temp = d2_ / u;
+                    d2_ = d1_ / u;
+                    d1_ = temp;
+                    x1_ = y1 * u;
+                }
+                if d1_ != 0.0 {
+                    while d1_ <= RGAMSQ || d1_ >= GAMSQ {
+                        if flag == 0.0 {
+                            h11 = 1.0;
+                            h22 = 1.0;
+                        } else {
+                            h21 = -1.0;
+                            h12 = 1.0;
+                        }
+                        flag = -1.0;
+                        if d1_ <= RGAMSQ {
+                            d1_ *= GAM * GAM;
+                            x1_ /= GAM;
+                            h11 /= GAM;
+                            h12 /= GAM;
+                        } else {
+                            d1_ /= GAM * GAM;
+                            x1_ *= GAM;
+                            h11 *= GAM;
+                            h12 *= GAM;
+                        }
+                    }
+                }
+                if d2_ != 0.0 {
+                    while d2_.abs() <= RGAMSQ || d2_.abs() >= GAMSQ {
+                        if flag == 0.0 {
+                            h11 = 1.0;
+                            h22 = 1.0;
+                        } else {
+                            h21 = -1.0;
+                            h12 = 1.0;
+                        }
+                        flag = -1.0;
+                        if d2_.abs() <= RGAMSQ {
+                            d2_ *= GAM * GAM;
+                            h21 /= GAM;
+                            h22 /= GAM;
+                        } else {
+                            d2_ /= GAM * GAM;
+                            h21 *= GAM;
+                            h22 *= GAM;
+                        }
+                    }
+                }
+            }
+
+            if flag < 0.0 {
+                *param.add(1) = h11;
+                *param.add(2) = h21;
+                *param.add(3) = h12;
+                *param.add(4) = h22;
+            } else if flag == 0.0 {
+                *param.add(2) = h21;
+                *param.add(3) = h12;
+            } else {
+                *param.add(1) = h11;
+                *param.add(4) = h22;
+            }
+            *param = flag;
+            *d1 = d1_;
+            *d2 = d2_;
+            *x1 = x1_;
+        }
+
+        unsafe fn $rotm(
+            n: Int,
+            x: *mut $real,
+            incx: Int,
+            y: *mut $real,
+            incy: Int,
+            param: *const $real,
+        ) {
+            let flag = *param;
+            if n <= 0 || flag == -2.0 {
+                return;
+            }
+            let (h11, h21, h12, h22) = (*param.add(1), *param.add(2), *param.add(3), *param.add(4));
+            let (mut ix, mut iy) = (start(n, incx), start(n, incy));
+            for _ in 0..n {
+                let (w, z) = (*x.offset(ix), *y.offset(iy));
+                let (xi, yi) = if flag < 0.0 {
+                    (w * h11 + z * h12, w * h21 + z * h22)
+                } else if flag == 0.0 {
+                    (w + z * h12, w * h21 + z)
+                } else {
+                    (w * h11 + z, -w + h22 * z)
+                };
+                *x.offset(ix) = xi;
+                *y.offset(iy) = yi;
+                ix += incx as isize;
+                iy += incy as isize;
+            }
+        }
+
+        /// Accumulate `value` into a scaled sum of squares.
+        #[inline]
+        fn $ssq(value: $real, scale: &mut $real, ssq: &mut $real) {
+            if value != 0.0 {
+                let absxi = value.abs();
+                if *scale < absxi {
+                    *ssq = 1.0 + *ssq * (*scale / absxi).powi(2);
+                    *scale = absxi;
+                } else {
+                    *ssq += (absxi / *scale).powi(2);
+                }
+            }
+        }
+
+        /// Return the sum of the magnitudes of the elements.
+        unsafe fn $asum<T: Copy>(n: Int, x: *const T, incx: Int, abs1: fn(T) -> $real) -> $real {
+            let mut temp = 0.0;
+            if n <= 0 || incx <= 0 {
+                return temp;
+            }
+            for i in 0..n as isize {
+                temp += abs1(*x.offset(i * incx as isize));
+            }
+            temp
+        }
+
+        /// Return the 1-based index of the first element of the largest magnitude.
+        unsafe fn $iamax<T: Copy>(n: Int, x: *const T, incx: Int, abs1: fn(T) -> $real) -> Int {
+            if n < 1 || incx <= 0 {
+                return 0;
+            }
+            let mut index = 1;
+            let mut max = abs1(*x);
+            for i in 1..n {
+                let value = abs1(*x.offset(i as isize * incx as isize));
+                if value > max {
+                    index = i + 1;
+                    max = value;
+                }
+            }
+            index
+        }
+    };
+}
+
+implement_real!(f32, rotg_f32, rotmg_f32, rotm_f32, ssq_f32, asum_f32, iamax_f32);
+implement_real!(f64, rotg_f64, rotmg_f64, rotm_f64, ssq_f64, asum_f64, iamax_f64);
+
+macro_rules! implement_complex {
+    ($complex:ident, $real:ident, $rotg:ident, $ssq:ident, $nrm2:ident) => {
+        unsafe fn $rotg(a: *mut $complex, b: *const $complex, c: *mut $real, s: *mut $complex) {
+            let (ca, cb) = (*a, *b);
+            let abs = |z: $complex| z.re.hypot(z.im);
+            if abs(ca) == 0.0 {
+                *c = 0.0;
+                *s = $complex::new(1.0, 0.0);
+                *a = cb;
+            } else {
+                let scale = abs(ca) + abs(cb);
+                let norm = scale * ((abs(ca / scale)).powi(2) + (abs(cb / scale)).powi(2)).sqrt();
+                let alpha = ca / abs(ca);
+                *c = abs(ca) / norm;
+                *s = alpha * cb.conj() / norm;
+                *a = alpha * norm;
+            }
+        }
+
+        unsafe fn $nrm2(n: Int, x: *const $complex, incx: Int) -> $real {
+            if n < 1 || incx < 1 {
+                return 0.0;
+            }
+            let (mut scale, mut ssq) = (0.0, 1.0);
+            for i in 0..n as isize {
+                let xi = *x.offset(i * incx as isize);
+                $ssq(xi.re, &mut scale, &mut ssq);
+                $ssq(xi.im, &mut scale, &mut ssq);
+            }
+            scale * ssq.sqrt()
+        }
+    };
+}
+
+implement_complex!(c32, f32, rotg_c32, ssq_f32, nrm2_c32);
+implement_complex!(c64, f64, rotg_c64, ssq_f64, nrm2_c64);
+
+macro_rules! implement_nrm2 {
+    ($name:ident, $real:ident, $ssq:ident) => {
+        unsafe fn $name(n: Int, x: *const $real, incx: Int) -> $real {
+            if n < 1 || incx < 1 {
+                return 0.0;
+            }
+            if n == 1 {
+                return (*x).abs();
+            }
+            let (mut scale, mut ssq) = (0.0, 1.0);
+            for i in 0..n as isize {
+                $ssq(*x.offset(i * incx as isize), &mut scale, &mut ssq);
+            }
+            scale * ssq.sqrt()
+        }
+    };
+}
+
+implement_nrm2!(nrm2_f32, f32, ssq_f32);
+implement_nrm2!(nrm2_f64, f64, ssq_f64);
+
+#[inline]
+fn abs_f32(value: f32) -> f32 {
+    value.abs()
+}
+
+#[inline]
+fn abs_f64(value: f64) -> f64 {
+    value.abs()
+}
+
+#[inline]
+fn abs1_c32(value: c32) -> f32 {
+    value.re.abs() + value.im.abs()
+}
+
+#[inline]
+fn abs1_c64(value: c64) -> f64 {
+    value.re.abs() + value.im.abs()
+}
+
+// Single
+
+pub unsafe fn srotg_(a: *mut c_float, b: *mut c_float, c: *mut c_float, s: *mut c_float) {
+    rotg_f32(a, b, c, s)
+}
+
+pub unsafe fn srotmg_(
+    d1: *mut c_float,
+    d2: *mut c_float,
+    x1: *mut c_float,
+    y1: *const c_float,
+    param: *mut c_float,
+) {
+    rotmg_f32(d1, d2, x1, y1, param)
+}
+
+pub unsafe fn srot_(
+    n: *const Int,
+    x: *mut c_float,
+    incx: *const Int,
+    y: *mut c_float,
+    incy: *const Int,
+    c: *const c_float,
+    s: *const c_float,
+) {
+    rot(*n, x, *incx, y, *incy, *c, *s)
+}
+
+pub unsafe fn srotm_(
+    n: *const Int,
+    x: *mut c_float,
+    incx: *const Int,
+    y: *mut c_float,
+    incy: *const Int,
+    param: *const c_float,
+) {
+    rotm_f32(*n, x, *incx, y, *incy, param)
+}
+
+pub unsafe fn sswap_(
+    n: *const Int,
+    x: *mut c_float,
+    incx: *const Int,
+    y: *mut c_float,
+    incy: *const Int,
+) {
+    swap(*n, x, *incx, y, *incy)
+}
+
+pub unsafe fn sscal_(n: *const Int, a: *const c_float, x: *mut c_float, incx: *const Int) {
+    scal(*n, *a, x, *incx)
+}
+
+pub unsafe fn scopy_(
+    n: *const Int,
+    x: *const c_float,
+    incx: *const Int,
+    y: *mut c_float,
+    incy: *const Int,
+) {
+    copy(*n, x, *incx, y, *incy)
+}
+
+pub unsafe fn saxpy_(
+    n: *const Int,
+    alpha: *const c_float,
+    x: *const c_float,
+    incx: *const Int,
+    y: *mut c_float,
+    incy: *const Int,
+) {
+    axpy(*n, *alpha, x, *incx, y, *incy)
+}
+
+pub unsafe fn sdot_(
+    n: *const Int,
+    x: *const c_float,
+    incx: *const Int,
+    y: *const c_float,
+    incy: *const Int,
+) -> c_float {
+    dot(*n, x, *incx, y, *incy, false)
+}
+
+pub unsafe fn sdsdot_(
+    n: *const Int,
+    sb: *const c_float,
+    x: *const c_float,
+    incx: *const Int,
+    y: *const c_float,
+    incy: *const Int,
+) -> c_float {
+    let (n, incx, incy) = (*n, *incx, *incy);
+    let mut temp = *sb as f64;
+    if n <= 0 {
+        return temp as f32;
+    }
+    let (mut ix, mut iy) = (start(n, incx), start(n, incy));
+    for _ in 0..n {
+        temp += *x.offset(ix) as f64 * *y.offset(iy) as f64;
+        ix += incx as isize;
+        iy += incy as isize;
+    }
+    temp as f32
+}
+
+pub unsafe fn snrm2_(n: *const Int, x: *const c_float, incx: *const Int) -> c_float {
+    nrm2_f32(*n, x, *incx)
+}
+
+pub unsafe fn scnrm2_(n: *const Int, x: *const c32, incx: *const Int) -> c_float {
+    nrm2_c32(*n, x, *incx)
+}
+
+pub unsafe fn sasum_(n: *const Int, x: *const c_float, incx: *const Int) -> c_float {
+    asum_f32(*n, x, *incx, abs_f32)
+}
+
+pub unsafe fn isamax_(n: *const Int, x: *const c_float, incx: *const Int) -> Int {
+    iamax_f32(*n, x, *incx, abs_f32)
+}
+
+// Double
+
+pub unsafe fn drotg_(a: *mut c_double, b: *mut c_double, c: *mut c_double, s: *mut c_double) {
+    rotg_f64(a, b, c, s)
+}
+
+pub unsafe fn drotmg_(
+    d1: *mut c_double,
+    d2: *mut c_double,
+    x1: *mut c_double,
+    y1: *const c_double,
+    param: *mut c_double,
+) {
+    rotmg_f64(d1, d2, x1, y1, param)
+}
+
+pub unsafe fn drot_(
+    n: *const Int,
+    x: *mut c_double,
+    incx: *const Int,
+    y: *mut c_double,
+    incy: *const Int,
+    c: *const c_double,
+    s: *const c_double,
+) {
+    rot(*n, x, *incx, y, *incy, *c, *s)
+}
+
+pub unsafe fn drotm_(
+    n: *const Int,
+    x: *mut c_double,
+    incx: *const Int,
+    y: *mut c_double,
+    incy: *const Int,
+    param: *const c_double,
+) {
+    rotm_f64(*n, x, *incx, y, *incy, param)
+}
+
+pub unsafe fn dswap_(
+    n: *const Int,
+    x: *mut c_double,
+    incx: *const Int,
+    y: *mut c_double,
+    incy: *const Int,
+) {
+    swap(*n, x, *incx, y, *incy)
+}
+
+pub unsafe fn dscal_(n: *const Int, a: *const c_double, x: *mut c_double, incx: *const Int) {
+    scal(*n, *a, x, *incx)
+}
+
+pub unsafe fn dcopy_(
+    n: *const Int,
+    x: *const c_double,
+    incx: *const Int,
+    y: *mut c_double,
+    incy: *const Int,
+) {
+    copy(*n, x, *incx, y, *incy)
+}
+
+pub unsafe fn daxpy_(
+    n: *const Int,
+    alpha: *const c_double,
+    x: *const c_double,
+    incx: *const Int,
+    y: *mut c_double,
+    incy: *const Int,
+) {
+    axpy(*n, *alpha, x, *incx, y, *incy)
+}
+
+pub unsafe fn ddot_(
+    n: *const Int,
+    x: *const c_double,
+    incx: *const Int,
+    y: *const c_double,
+    incy: *const Int,
+) -> c_double {
+    dot(*n, x, *incx, y, *incy, false)
+}
+
+pub unsafe fn dsdot_(
+    n: *const Int,
+    x: *const c_float,
+    incx: *const Int,
+    y: *const c_float,
+    incy: *const Int,
+) -> c_double {
+    let (n, incx, incy) = (*n, *incx, *incy);
+    let mut temp = 0.0;
+    if n <= 0 {
+        return temp;
+    }
+    let (mut ix, mut iy) = (start(n, incx), start(n, incy));
+    for _ in 0..n {
+        temp += *x.offset(ix) as f64 * *y.offset(iy) as f64;
+        ix += incx as isize;
+        iy += incy as isize;
+    }
+    temp
+}
+
+pub unsafe fn dnrm2_(n: *const Int, x: *const c_double, incx: *const Int) -> c_double {
+    nrm2_f64(*n, x, *incx)
+}
+
+pub unsafe fn dznrm2_(n: *const Int, x: *const c64, incx: *const Int) -> c_double {
+    nrm2_c64(*n, x, *incx)
+}
+
+pub unsafe fn dasum_(n: *const Int, x: *const c_double, incx: *const Int) -> c_double {
+    asum_f64(*n, x, *incx, abs_f64)
+}
+
+pub unsafe fn idamax_(n: *const Int, x: *const c_double, incx: *const Int) -> Int {
+    iamax_f64(*n, x, *incx, abs_f64)
+}
+
+// Complex
+
+pub unsafe fn crotg_(a: *mut c32, b: *const c32, c: *mut c_float, s: *mut c32) {
+    rotg_c32(a, b, c, s)
+}
+
+pub unsafe fn csrot_(
+    n: *const Int,
+    x: *mut c32,
+    incx: *const Int,
+    y: *mut c32,
+    incy: *const Int,
+    c: *const c_float,
+    s: *const c_float,
+) {
+    rot(*n, x, *incx, y, *incy, *c, *s)
+}
+
+pub unsafe fn cswap_(n: *const Int, x: *mut c32, incx: *const Int, y: *mut c32, incy: *const Int) {
+    swap(*n, x, *incx, y, *incy)
+}
+
+pub unsafe fn cscal_(n: *const Int, a: *const c32, x: *mut c32, incx: *const Int) {
+    scal(*n, *a, x, *incx)
+}
+
+pub unsafe fn csscal_(n: *const Int, a: *const c_float, x: *mut c32, incx: *const Int) {
+    scal(*n, c32::new(*a, 0.0), x, *incx)
+}
+
+pub unsafe fn ccopy_(
+    n: *const Int,
+    x: *const c32,
+    incx: *const Int,
+    y: *mut c32,
+    incy: *const Int,
+) {
+    copy(*n, x, *incx, y, *incy)
+}
+
+pub unsafe fn caxpy_(
+    n: *const Int,
+    alpha: *const c32,
+    x: *const c32,
+    incx: *const Int,
+    y: *mut c32,
+    incy: *const Int,
+) {
+    axpy(*n, *alpha, x, *incx, y, *incy)
+}
+
+pub unsafe fn cdotu_(
+    pres: *mut c32,
+    n: *const Int,
+    x: *const c32,
+    incx: *const Int,
+    y: *const c32,
+    incy: *const Int,
+) {
+    *pres = dot(*n, x, *incx, y, *incy, false)
+}
+
+pub unsafe fn cdotc_(
+    pres: *mut c32,
+    n: *const Int,
+    x: *const c32,
+    incx: *const Int,
+    y: *const c32,
+    incy: *const Int,
+) {
+    *pres = dot(*n, x, *incx, y, *incy, true)
+}
+
+pub unsafe fn scasum_(n: *const Int, x: *const c32, incx: *const Int) -> c_float {
+    asum_f32(*n, x, *incx, abs1_c32)
+}
+
+pub unsafe fn icamax_(n: *const Int, x: *const c32, incx: *const Int) -> Int {
+    iamax_f32(*n, x, *incx, abs1_c32)
+}
+
+// Double complex
+
+pub unsafe fn zrotg_(a: *mut c64, b: *const c64, c: *mut c_double, s: *mut c64) {
+    rotg_c64(a, b, c, s)
+}
+
+pub unsafe fn zdrot_(
+    n: *const Int,
+    x: *mut c64,
+    incx: *const Int,
+    y: *mut c64,
+    incy: *const Int,
+    c: *const c_double,
+    s: *const c_double,
+) {
+    rot(*n, x, *incx, y, *incy, *c, *s)
+}
+
+pub unsafe fn zswap_(n: *const Int, x: *mut c64, incx: *const Int, y: *mut c64, incy: *const Int) {
+    swap(*n, x, *incx, y, *incy)
+}
+
+pub unsafe fn zscal_(n: *const Int, a: *const c64, x: *mut c64, incx: *const Int) {
+    scal(*n, *a, x, *incx)
+}
+
+pub unsafe fn zdscal_(n: *const Int, a: *const c_double, x: *mut c64, incx: *const Int) {
+    scal(*n, c64::new(*a, 0.0), x, *incx)
+}
+
+pub unsafe fn zcopy_(
+    n: *const Int,
+    x: *const c64,
+    incx: *const Int,
+    y: *mut c64,
+    incy: *const Int,
+) {
+    copy(*n, x, *incx, y, *incy)
+}
+
+pub unsafe fn zaxpy_(
+    n: *const Int,
+    alpha: *const c64,
+    x: *const c64,
+    incx: *const Int,
+    y: *mut c64,
+    incy: *const Int,
+) {
+    axpy(*n, *alpha, x, *incx, y, *incy)
+}
+
+pub unsafe fn zdotu_(
+    pres: *mut c64,
+    n: *const Int,
+    x: *const c64,
+    incx: *const Int,
+    y: *const c64,
+    incy: *const Int,
+) {
+    *pres = dot(*n, x, *incx, y, *incy, false)
+}
+
+pub unsafe fn zdotc_(
+    pres: *mut c64,
+    n: *const Int,
+    x: *const c64,
+    incx: *const Int,
+    y: *const c64,
+    incy: *const Int,
+) {
+    *pres = dot(*n, x, *incx, y, *incy, true)
+}
+
+pub unsafe fn dzasum_(n: *const Int, x: *const c64, incx: *const Int) -> c_double {
+    asum_f64(*n, x, *incx, abs1_c64)
+}
+
+pub unsafe fn izamax_(n: *const Int, x: *const c64, incx: *const Int) -> Int {
+    iamax_f64(*n, x, *incx, abs1_c64)
+}
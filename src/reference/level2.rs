@@ -0,0 +1,2112 @@
+use core::ffi::{c_char, c_double, c_float};
+
+use super::{lsame, start, xerbla};
+use crate::{c32, c64, Int, Scalar};
+
+/// Return the offset of element `(i, j)` of an upper packed matrix.
+#[inline]
+fn upper_packed(i: usize, j: usize) -> usize {
+    i + j * (j + 1) / 2
+}
+
+/// Return the offset of element `(i, j)` of a lower packed matrix of order `n`.
+#[inline]
+fn lower_packed(n: usize, i: usize, j: usize) -> usize {
+    i + j * (2 * n - j - 1) / 2
+}
+
+#[inline]
+fn conjugate<T: Scalar>(value: T, conjugate: bool) -> T {
+    if conjugate {
+        value.conj()
+    } else {
+        value
+    }
+}
+
+/// Compute `y := beta * y` for a vector of `n` elements.
+unsafe fn scale<T: Scalar>(n: Int, beta: T, y: *mut T, incy: Int) {
+    if beta == T::one() {
+        return;
+    }
+    let mut iy = start(n, incy);
+    for _ in 0..n {
+        let yi = y.offset(iy);
+        *yi = if beta == T::zero() {
+            T::zero()
+        } else {
+            beta * *yi
+        };
+        iy += incy as isize;
+    }
+}
+
+unsafe fn gemv<T: Scalar>(
+    name: &str,
+    trans: c_char,
+    m: Int,
+    n: Int,
+    alpha: T,
+    a: *const T,
+    lda: Int,
+    x: *const T,
+    incx: Int,
+    beta: T,
+    y: *mut T,
+    incy: Int,
+) {
+    let info = if !lsame(trans, b'N') && !lsame(trans, b'T') && !lsame(trans, b'C') {
+        1
+    } else if m < 0 {
+        2
+    } else if n < 0 {
+        3
+    } else if lda < m.max(1) {
+        6
+    } else if incx == 0 {
+        8
+    } else if incy == 0 {
+        11
+    } else {
+        0
+    };
+    if info != 0 {
+        return xerbla(name, info);
+    }
+    if m == 0 || n == 0 || (alpha == T::zero() && beta == T::one()) {
+        return;
+    }
+    let conj = lsame(trans, b'C');
+    let (lenx, leny) = if lsame(trans, b'N') { (n, m) } else { (m, n) };
+    let (kx, ky) = (start(lenx, incx), start(leny, incy));
+    let (incx, incy) = (incx as isize, incy as isize);
+    let a = |i: usize, j: usize| *a.add(i + j * lda as usize);
+
+    scale(leny, beta, y, incy as Int);
+    if alpha == T::zero() {
+        return;
+    }
+    if lsame(trans, b'N') {
+        let mut jx = kx;
+        for j in 0..n as usize {
+            let temp = alpha * *x.offset(jx);
+            let mut iy = ky;
+            for i in 0..m as usize {
+                *y.offset(iy) = *y.offset(iy) + temp * a(i, j);
+                iy += incy;
+            }
+            jx += incx;
+        }
+    } else {
+        let mut jy = ky;
+        for j in 0..n as usize {
+            let mut temp = T::zero();
+            let mut ix = kx;
+            for i in 0..m as usize {
+                temp = temp + conjugate(a(i, j), conj) * *x.offset(ix);
+                ix += incx;
+            }
+            *y.offset(jy) = *y.offset(jy) + alpha * temp;
+            jy += incy;
+        }
+    }
+}
+
+unsafe fn gbmv<T: Scalar>(
+    name: &str,
+    trans: c_char,
+    m: Int,
+    n: Int,
+    kl: Int,
+    ku: Int,
+    alpha: T,
+    a: *const T,
+    lda: Int,
+    x: *const T,
+    incx: Int,
+    beta: T,
+    y: *mut T,
+    incy: Int,
+) {
+    let info = if !lsame(trans, b'N') && !lsame(trans, b'T') && !lsame(trans, b'C') {
+        1
+    } else if m < 0 {
+        2
+    } else if n < 0 {
+        3
+    } else if kl < 0 {
+        4
+    } else if ku < 0 {
+        5
+    } else if lda < kl + ku + 1 {
+        8
+    } else if incx == 0 {
+        10
+    } else if incy == 0 {
+        13
+    } else {
+        0
+    };
+    if info != 0 {
+        return xerbla(name, info);
+    }
+    if m == 0 || n == 0 || (alpha == T::zero() && beta == T::one()) {
+        return;
+    }
+    let conj = lsame(trans, b'C');
+    let (lenx, leny) = if lsame(trans, b'N') { (n, m) } else { (m, n) };
+    let (mut kx, mut ky) = (start(lenx, incx), start(leny, incy));
+    let (m, kl, ku) = (m as usize, kl as usize, ku as usize);
+    let (incx, incy) = (incx as isize, incy as isize);
+    let a = |i: usize, j: usize| *a.add(ku + i - j + j * lda as usize);
+
+    scale(leny, beta, y, incy as Int);
+    if alpha == T::zero() {
+        return;
+    }
+    if lsame(trans, b'N') {
+        let mut jx = kx;
+        for j in 0..n as usize {
+            let temp = alpha * *x.offset(jx);
+            let mut iy = ky;
+            for i in j.saturating_sub(ku)..m.min(j + kl + 1) {
+                *y.offset(iy) = *y.offset(iy) + temp * a(i, j);
+                iy += incy;
+            }
+            jx += incx;
+            if j >= ku {
+                ky += incy;
+            }
+        }
+    } else {
+        let mut jy = ky;
+        for j in 0..n as usize {
+            let mut temp = T::zero();
+            let mut ix = kx;
+            for i in j.saturating_sub(ku)..m.min(j + kl + 1) {
+                temp = temp + conjugate(a(i, j), conj) * *x.offset(ix);
+                ix += incx;
+            }
+            *y.offset(jy) = *y.offset(jy) + alpha * temp;
+            jy += incy;
+            if j >= ku {
+                kx += incx;
+            }
+        }
+    }
+}
+
+/// Compute `y := alpha * A * x + beta * y` for a Hermitian matrix whose elements are located
+/// by `offset`. For real types, the matrix is symmetric.
+unsafe fn hemv_kernel<T: Scalar>(
+    upper: bool,
+    n: Int,
+    alpha: T,
+    a: *const T,
+    offset: impl Fn(usize, usize) -> usize,
+    x: *const T,
+    incx: Int,
+    beta: T,
+    y: *mut T,
+    incy: Int,
+) {
+    if n == 0 || (alpha == T::zero() && beta == T::one()) {
+        return;
+    }
+    let (kx, ky) = (start(n, incx), start(n, incy));
+    let a = |i: usize, j: usize| *a.add(offset(i, j));
+
+    scale(n, beta, y, incy);
+    if alpha == T::zero() {
+        return;
+    }
+    let (n, incx, incy) = (n as usize, incx as isize, incy as isize);
+    let (mut jx, mut jy) = (kx, ky);
+    if upper {
+        for j in 0..n {
+            let temp1 = alpha * *x.offset(jx);
+            let mut temp2 = T::zero();
+            let (mut ix, mut iy) = (kx, ky);
+            for i in 0..j {
+                *y.offset(iy) = *y.offset(iy) + temp1 * a(i, j);
+                temp2 = temp2 + a(i, j).conj() * *x.offset(ix);
+                ix += incx;
+                iy += incy;
+            }
+            *y.offset(jy) = *y.offset(jy) + temp1 * T::from_real(a(j, j).re()) + alpha * temp2;
+            jx += incx;
+            jy += incy;
+        }
+    } else {
+        for j in 0..n {
+            let temp1 = alpha * *x.offset(jx);
+            let mut temp2 = T::zero();
+            *y.offset(jy) = *y.offset(jy) + temp1 * T::from_real(a(j, j).re());
+            let (mut ix, mut iy) = (jx, jy);
+            for i in j + 1..n {
+                ix += incx;
+                iy += incy;
+                *y.offset(iy) = *y.offset(iy) + temp1 * a(i, j);
+                temp2 = temp2 + a(i, j).conj() * *x.offset(ix);
+            }
+            *y.offset(jy) = *y.offset(jy) + alpha * temp2;
+            jx += incx;
+            jy += incy;
+        }
+    }
+}
+
+unsafe fn hemv<T: Scalar>(
+    name: &str,
+    uplo: c_char,
+    n: Int,
+    alpha: T,
+    a: *const T,
+    lda: Int,
+    x: *const T,
+    incx: Int,
+    beta: T,
+    y: *mut T,
+    incy: Int,
+) {
+    let info = if !lsame(uplo, b'U') && !lsame(uplo, b'L') {
+        1
+    } else if n < 0 {
+        2
+    } else if lda < n.max(1) {
+        5
+    } else if incx == 0 {
+        7
+    } else if incy == 0 {
+        10
+    } else {
+        0
+    };
+    if info != 0 {
+        return xerbla(name, info);
+    }
+    let lda = lda as usize;
+    let offset = |i: usize, j: usize| i + j * lda;
+    hemv_kernel(
+        lsame(uplo, b'U'),
+        n,
+        alpha,
+        a,
+        offset,
+        x,
+        incx,
+        beta,
+        y,
+        incy,
+    )
+}
+
+unsafe fn hpmv<T: Scalar>(
+    name: &str,
+    uplo: c_char,
+    n: Int,
+    alpha: T,
+    ap: *const T,
+    x: *const T,
+    incx: Int,
+    beta: T,
+    y: *mut T,
+    incy: Int,
+) {
+    let info = if !lsame(uplo, b'U') && !lsame(uplo, b'L') {
+        1
+    } else if n < 0 {
+        2
+    } else if incx == 0 {
+        6
+    } else if incy == 0 {
+        9
+    } else {
+        0
+    };
+    if info != 0 {
+        return xerbla(name, info);
+    }
+    if lsame(uplo, b'U') {
+        hemv_kernel(true, n, alpha, ap, upper_packed, x, incx, beta, y, incy)
+    } else {
+        let order = n as usize;
+        let offset = |i: usize, j: usize| lower_packed(order, i, j);
+        hemv_kernel(false, n, alpha, ap, offset, x, incx, beta, y, incy)
+    }
+}
+
+unsafe fn hbmv<T: Scalar>(
+    name: &str,
+    uplo: c_char,
+    n: Int,
+    k: Int,
+    alpha: T,
+    a: *const T,
+    lda: Int,
+    x: *const T,
+    incx: Int,
+    beta: T,
+    y: *mut T,
+    incy: Int,
+) {
+    let info = if !lsame(uplo, b'U') && !lsame(uplo, b'L') {
+        1
+    } else if n < 0 {
+        2
+    } else if k < 0 {
+        3
+    } else if lda < k + 1 {
+        6
+    } else if incx == 0 {
+        8
+    } else if incy == 0 {
+        11
+    } else {
+        0
+    };
+    if info != 0 {
+        return xerbla(name, info);
+    }
+    if n == 0 || (alpha == T::zero() && beta == T::one()) {
+        return;
+    }
+    let (mut kx, mut ky) = (start(n, incx), start(n, incy));
+
+    scale(n, beta, y, incy);
+    if alpha == T::zero() {
+        return;
+    }
+    let (n, k, lda) = (n as usize, k as usize, lda as usize);
+    let (incx, incy) = (incx as isize, incy as isize);
+    let (mut jx, mut jy) = (kx, ky);
+    if lsame(uplo, b'U') {
+        let a = |i: usize, j: usize| *a.add(k + i - j + j * lda);
+        for j in 0..n {
+            let temp1 = alpha * *x.offset(jx);
+            let mut temp2 = T::zero();
+            let (mut ix, mut iy) = (kx, ky);
+            for i in j.saturating_sub(k)..j {
+                *y.offset(iy) = *y.offset(iy) + temp1 * a(i, j);
+                temp2 = temp2 + a(i, j).conj() * *x.offset(ix);
+                ix += incx;
+                iy += incy;
+            }
+            *y.offset(jy) = *y.offset(jy) + temp1 * T::from_real(a(j, j).re()) + alpha * temp2;
+            jx += incx;
+            jy += incy;
+            if j >= k {
+                kx += incx;
+                ky += incy;
+            }
+        }
+    } else {
+        let a = |i: usize, j: usize| *a.add(i - j + j * lda);
+        for j in 0..n {
+            let temp1 = alpha * *x.offset(jx);
+            let mut temp2 = T::zero();
+            *y.offset(jy) = *y.offset(jy) + temp1 * T::from_real(a(j, j).re());
+            let (mut ix, mut iy) = (jx, jy);
+            for i in j + 1..n.min(j + k + 1) {
+                ix += incx;
+                iy += incy;
+                *y.offset(iy) = *y.offset(iy) + temp1 * a(i, j);
+                temp2 = temp2 + a(i, j).conj() * *x.offset(ix);
+            }
+            *y.offset(jy) = *y.offset(jy) + alpha * temp2;
+            jx += incx;
+            jy += incy;
+        }
+    }
+}
+
+/// Check the flags shared by the triangular routines.
+fn check_triangular(uplo: c_char, trans: c_char, diag: c_char) -> Int {
+    if !lsame(uplo, b'U') && !lsame(uplo, b'L') {
+        1
+    } else if !lsame(trans, b'N') && !lsame(trans, b'T') && !lsame(trans, b'C') {
+        2
+    } else if !lsame(diag, b'U') && !lsame(diag, b'N') {
+        3
+    } else {
+        0
+    }
+}
+
+/// Compute `x := op(A) * x` for a triangular matrix whose elements are located by `offset`.
+unsafe fn trmv_kernel<T: Scalar>(
+    uplo: c_char,
+    trans: c_char,
+    diag: c_char,
+    n: Int,
+    a: *const T,
+    offset: impl Fn(usize, usize) -> usize,
+    x: *mut T,
+    incx: Int,
+) {
+    if n == 0 {
+        return;
+    }
+    let conj = lsame(trans, b'C');
+    let nounit = lsame(diag, b'N');
+    let kx = start(n, incx);
+    let a = |i: usize, j: usize| *a.add(offset(i, j));
+    let (n, incx) = (n as usize, incx as isize);
+    let last = kx + (n as isize - 1) * incx;
+
+    if lsame(trans, b'N') {
+        if lsame(uplo, b'U') {
+            let mut jx = kx;
+            for j in 0..n {
+                if *x.offset(jx) != T::zero() {
+                    let temp = *x.offset(jx);
+                    let mut ix = kx;
+                    for i in 0..j {
+                        *x.offset(ix) = *x.offset(ix) + temp * a(i, j);
+                        ix += incx;
+                    }
+                    if nounit {
+                        *x.offset(jx) = *x.offset(jx) * a(j, j);
+                    }
+                }
+                jx += incx;
+            }
+        } else {
+            let mut jx = last;
+            for j in (0..n).rev() {
+                if *x.offset(jx) != T::zero() {
+                    let temp = *x.offset(jx);
+                    let mut ix = last;
+                    for i in (j + 1..n).rev() {
+                        *x.offset(ix) = *x.offset(ix) + temp * a(i, j);
+                        ix -= incx;
+                    }
+                    if nounit {
+                        *x.offset(jx) = *x.offset(jx) * a(j, j);
+                    }
+                }
+                jx -= incx;
+            }
+        }
+    } else if lsame(uplo, b'U') {
+        let mut jx = last;
+        for j in (0..n).rev() {
+            let mut temp = *x.offset(jx);
+            let mut ix = jx;
+            if nounit {
+                temp = temp * conjugate(a(j, j), conj);
+            }
+            for i in (0..j).rev() {
+                ix -= incx;
+                temp = temp + conjugate(a(i, j), conj) * *x.offset(ix);
+            }
+            *x.offset(jx) = temp;
+            jx -= incx;
+        }
+    } else {
+        let mut jx = kx;
+        for j in 0..n {
+            let mut temp = *x.offset(jx);
+            let mut ix = jx;
+            if nounit {
+                temp = temp * conjugate(a(j, j), conj);
+            }
+            for i in j + 1..n {
+                ix += incx;
+                temp = temp + conjugate(a(i, j), conj) * *x.offset(ix);
+            }
+            *x.offset(jx) = temp;
+            jx += incx;
+        }
+    }
+}
+
+/// Solve `op(A) * x = b` for a triangular matrix whose elements are located by `offset`.
+unsafe fn trsv_kernel<T: Scalar>(
+    uplo: c_char,
+    trans: c_char,
+    diag: c_char,
+    n: Int,
+    a: *const T,
+    offset: impl Fn(usize, usize) -> usize,
+    x: *mut T,
+    incx: Int,
+) {
+    if n == 0 {
+        return;
+    }
+    let conj = lsame(trans, b'C');
+    let nounit = lsame(diag, b'N');
+    let kx = start(n, incx);
+    let a = |i: usize, j: usize| *a.add(offset(i, j));
+    let (n, incx) = (n as usize, incx as isize);
+    let last = kx + (n as isize - 1) * incx;
+
+    if lsame(trans, b'N') {
+        if lsame(uplo, b'U') {
+            let mut jx = last;
+            for j in (0..n).rev() {
+                if *x.offset(jx) != T::zero() {
+                    if nounit {
+                        *x.offset(jx) = *x.offset(jx) / a(j, j);
+                    }
+                    let temp = *x.offset(jx);
+                    let mut ix = jx;
+                    for i in (0..j).rev() {
+                        ix -= incx;
+                        *x.offset(ix) = *x.offset(ix) - temp * a(i, j);
+                    }
+                }
+                jx -= incx;
+            }
+        } else {
+            let mut jx = kx;
+            for j in 0..n {
+                if *x.offset(jx) != T::zero() {
+                    if nounit {
+                        *x.offset(jx) = *x.offset(jx) / a(j, j);
+                    }
+                    let temp = *x.offset(jx);
+                    let mut ix = jx;
+                    for i in j + 1..n {
+                        ix += incx;
+                        *x.offset(ix) = *x.offset(ix) - temp * a(i, j);
+                    }
+                }
+                jx += incx;
+            }
+        }
+    } else if lsame(uplo, b'U') {
+        let mut jx = kx;
+        for j in 0..n {
+            let mut temp = *x.offset(jx);
+            let mut ix = kx;
+            for i in 0..j {
+                temp = temp - conjugate(a(i, j), conj) * *x.offset(ix);
+                ix += incx;
+            }
+            if nounit {
+                temp = temp / conjugate(a(j, j), conj);
+            }
+            *x.offset(jx) = temp;
+            jx += incx;
+        }
+    } else {
+        let mut jx = last;
+        for j in (0..n).rev() {
+            let mut temp = *x.offset(jx);
+            let mut ix = last;
+            for i in (j + 1..n).rev() {
+                temp = temp - conjugate(a(i, j), conj) * *x.offset(ix);
+                ix -= incx;
+            }
+            if nounit {
+                temp = temp / conjugate(a(j, j), conj);
+            }
+            *x.offset(jx) = temp;
+            jx -= incx;
+        }
+    }
+}
+
+unsafe fn trmv<T: Scalar>(
+    name: &str,
+    uplo: c_char,
+    trans: c_char,
+    diag: c_char,
+    n: Int,
+    a: *const T,
+    lda: Int,
+    x: *mut T,
+    incx: Int,
+    solve: bool,
+) {
+    let info = match check_triangular(uplo, trans, diag) {
+        0 if n < 0 => 4,
+        0 if lda < n.max(1) => 6,
+        0 if incx == 0 => 8,
+        info => info,
+    };
+    if info != 0 {
+        return xerbla(name, info);
+    }
+    let lda = lda as usize;
+    let offset = |i: usize, j: usize| i + j * lda;
+    if solve {
+        trsv_kernel(uplo, trans, diag, n, a, offset, x, incx)
+    } else {
+        trmv_kernel(uplo, trans, diag, n, a, offset, x, incx)
+    }
+}
+
+unsafe fn tpmv<T: Scalar>(
+    name: &str,
+    uplo: c_char,
+    trans: c_char,
+    diag: c_char,
+    n: Int,
+    ap: *const T,
+    x: *mut T,
+    incx: Int,
+    solve: bool,
+) {
+    let info = match check_triangular(uplo, trans, diag) {
+        0 if n < 0 => 4,
+        0 if incx == 0 => 7,
+        info => info,
+    };
+    if info != 0 {
+        return xerbla(name, info);
+    }
+    let order = n as usize;
+    let upper = lsame(uplo, b'U');
+    let offset = |i: usize, j: usize| {
+        if upper {
+            upper_packed(i, j)
+        } else {
+            lower_packed(order, i, j)
+        }
+    };
+    if solve {
+        trsv_kernel(uplo, trans, diag, n, ap, offset, x, incx)
+    } else {
+        trmv_kernel(uplo, trans, diag, n, ap, offset, x, incx)
+    }
+}
+
+unsafe fn tbmv<T: Scalar>(
+    name: &str,
+    uplo: c_char,
+    trans: c_char,
+    diag: c_char,
+    n: Int,
+    k: Int,
+    a: *const T,
+    lda: Int,
+    x: *mut T,
+    incx: Int,
+) {
+    let info = match check_triangular(uplo, trans, diag) {
+        0 if n < 0 => 4,
+        0 if k < 0 => 5,
+        0 if lda < k + 1 => 7,
+        0 if incx == 0 => 9,
+        info => info,
+    };
+    if info != 0 {
+        return xerbla(name, info);
+    }
+    if n == 0 {
+        return;
+    }
+    let conj = lsame(trans, b'C');
+    let nounit = lsame(diag, b'N');
+    let mut kx = start(n, incx);
+    let (n, k, lda, incx) = (n as usize, k as usize, lda as usize, incx as isize);
+    let upper = lsame(uplo, b'U');
+    let a = |i: usize, j: usize| {
+        if upper {
+            *a.add(k + i - j + j * lda)
+        } else {
+            *a.add(i - j + j * lda)
+        }
+    };
+
+    if lsame(trans, b'N') {
+        if upper {
+            let mut jx = kx;
+            for j in 0..n {
+                if *x.offset(jx) != T::zero() {
+                    let temp = *x.offset(jx);
+                    let mut ix = kx;
+                    for i in j.saturating_sub(k)..j {
+                        *x.offset(ix) = *x.offset(ix) + temp * a(i, j);
+                        ix += incx;
+                    }
+                    if nounit {
+                        *x.offset(jx) = *x.offset(jx) * a(j, j);
+                    }
+                }
+                jx += incx;
+                if j >= k {
+                    kx += incx;
+                }
+            }
+        } else {
+            kx += (n as isize - 1) * incx;
+            let mut jx = kx;
+            for j in (0..n).rev() {
+                if *x.offset(jx) != T::zero() {
+                    let temp = *x.offset(jx);
+                    let mut ix = kx;
+                    for i in (j + 1..n.min(j + k + 1)).rev() {
+                        *x.offset(ix) = *x.offset(ix) + temp * a(i, j);
+                        ix -= incx;
+                    }
+                    if nounit {
+                        *x.offset(jx) = *x.offset(jx) * a(j, j);
+                    }
+                }
+                jx -= incx;
+                if n - 1 - j >= k {
+                    kx -= incx;
+                }
+            }
+        }
+    } else if upper {
+        kx += (n as isize - 1) * incx;
+        let mut jx = kx;
+        for j in (0..n).rev() {
+            let mut temp = *x.offset(jx);
+            kx -= incx;
+            let mut ix = kx;
+            if nounit {
+                temp = temp * conjugate(a(j, j), conj);
+            }
+            for i in (j.saturating_sub(k)..j).rev() {
+                temp = temp + conjugate(a(i, j), conj) * *x.offset(ix);
+                ix -= incx;
+            }
+            *x.offset(jx) = temp;
+            jx -= incx;
+        }
+    } else {
+        let mut jx = kx;
+        for j in 0..n {
+            let mut temp = *x.offset(jx);
+            kx += incx;
+            let mut ix = kx;
+            if nounit {
+                temp = temp * conjugate(a(j, j), conj);
+            }
+            for i in j + 1..n.min(j + k + 1) {
+                temp = temp + conjugate(a(i, j), conj) * *x.offset(ix);
+                ix += incx;
+            }
+            *x.offset(jx) = temp;
+            jx += incx;
+        }
+    }
+}
+
+unsafe fn tbsv<T: Scalar>(
+    name: &str,
+    uplo: c_char,
+    trans: c_char,
+    diag: c_char,
+    n: Int,
+    k: Int,
+    a: *const T,
+    lda: Int,
+    x: *mut T,
+    incx: Int,
+) {
+    let info = match check_triangular(uplo, trans, diag) {
+        0 if n < 0 => 4,
+        0 if k < 0 => 5,
+        0 if lda < k + 1 => 7,
+        0 if incx == 0 => 9,
+        info => info,
+    };
+    if info != 0 {
+        return xerbla(name, info);
+    }
+    if n == 0 {
+        return;
+    }
+    let conj = lsame(trans, b'C');
+    let nounit = lsame(diag, b'N');
+    let mut kx = start(n, incx);
+    let (n, k, lda, incx) = (n as usize, k as usize, lda as usize, incx as isize);
+    let upper = lsame(uplo, b'U');
+    let a = |i: usize, j: usize| {
+        if upper {
+            *a.add(k + i - j + j * lda)
+        } else {
+            *a.add(i - j + j * lda)
+        }
+    };
+
+    if lsame(trans, b'N') {
+        if upper {
+            kx += (n as isize - 1) * incx;
+            let mut jx = kx;
+            for j in (0..n).rev() {
+                kx -= incx;
+                if *x.offset(jx) != T::zero() {
+                    let mut ix = kx;
+                    if nounit {
+                        *x.offset(jx) = *x.offset(jx) / a(j, j);
+                    }
+                    let temp = *x.offset(jx);
+                    for i in (j.saturating_sub(k)..j).rev() {
+                        *x.offset(ix) = *x.offset(ix) - temp * a(i, j);
+                        ix -= incx;
+                    }
+                }
+                jx -= incx;
+            }
+        } else {
+            let mut jx = kx;
+            for j in 0..n {
+                kx += incx;
+                if *x.offset(jx) != T::zero() {
+                    let mut ix = kx;
+                    if nounit {
+                        *x.offset(jx) = *x.offset(jx) / a(j, j);
+                    }
+                    let temp = *x.offset(jx);
+                    for i in j + 1..n.min(j + k + 1) {
+                        *x.offset(ix) = *x.offset(ix) - temp * a(i, j);
+                        ix += incx;
+                    }
+                }
+                jx += incx;
+            }
+        }
+    } else if upper {
+        let mut jx = kx;
+        for j in 0..n {
+            let mut temp = *x.offset(jx);
+            let mut ix = kx;
+            for i in j.saturating_sub(k)..j {
+                temp = temp - conjugate(a(i, j), conj) * *x.offset(ix);
+                ix += incx;
+            }
+            if nounit {
+                temp = temp / conjugate(a(j, j), conj);
+            }
+            *x.offset(jx) = temp;
+            jx += incx;
+            if j >= k {
+                kx += incx;
+            }
+        }
+    } else {
+        kx += (n as isize - 1) * incx;
+        let mut jx = kx;
+        for j in (0..n).rev() {
+            let mut temp = *x.offset(jx);
+            let mut ix = kx;
+            for i in (j + 1..n.min(j + k + 1)).rev() {
+                temp = temp - conjugate(a(i, j), conj) * *x.offset(ix);
+                ix -= incx;
+            }
+            if nounit {
+                temp = temp / conjugate(a(j, j), conj);
+            }
+            *x.offset(jx) = temp;
+            jx -= incx;
+            if n - 1 - j >= k {
+                kx -= incx;
+            }
+        }
+    }
+}
+
+unsafe fn ger<T: Scalar>(
+    name: &str,
+    m: Int,
+    n: Int,
+    alpha: T,
+    x: *const T,
+    incx: Int,
+    y: *const T,
+    incy: Int,
+    a: *mut T,
+    lda: Int,
+    conj: bool,
+) {
+    let info = if m < 0 {
+        1
+    } else if n < 0 {
+        2
+    } else if incx == 0 {
+        5
+    } else if incy == 0 {
+        7
+    } else if lda < m.max(1) {
+        9
+    } else {
+        0
+    };
+    if info != 0 {
+        return xerbla(name, info);
+    }
+    if m == 0 || n == 0 || alpha == T::zero() {
+        return;
+    }
+    let (kx, mut jy) = (start(m, incx), start(n, incy));
+    let a = |i: usize, j: usize| a.add(i + j * lda as usize);
+    for j in 0..n as usize {
+        if *y.offset(jy) != T::zero() {
+            let temp = alpha * conjugate(*y.offset(jy), conj);
+            let mut ix = kx;
+            for i in 0..m as usize {
+                *a(i, j) = *a(i, j) + *x.offset(ix) * temp;
+                ix += incx as isize;
+            }
+        }
+        jy += incy as isize;
+    }
+}
+
+/// Compute `A := alpha * x * x^H + A` for a Hermitian matrix whose elements are located by
+/// `offset`.
+unsafe fn her_kernel<T: Scalar>(
+    upper: bool,
+    n: Int,
+    alpha: T::Real,
+    x: *const T,
+    incx: Int,
+    a: *mut T,
+    offset: impl Fn(usize, usize) -> usize,
+) {
+    if n == 0 || alpha == T::Real::zero() {
+        return;
+    }
+    let kx = start(n, incx);
+    let a = |i: usize, j: usize| a.add(offset(i, j));
+    let (n, incx) = (n as usize, incx as isize);
+    let alpha = T::from_real(alpha);
+    let mut jx = kx;
+    for j in 0..n {
+        let xj = *x.offset(jx);
+        if xj != T::zero() {
+            let temp = alpha * xj.conj();
+            let range = if upper { 0..j } else { j + 1..n };
+            let mut ix = if upper { kx } else { jx + incx };
+            for i in range {
+                *a(i, j) = *a(i, j) + *x.offset(ix) * temp;
+                ix += incx;
+            }
+            *a(j, j) = T::from_real((*a(j, j)).re() + (xj * temp).re());
+        } else {
+            *a(j, j) = T::from_real((*a(j, j)).re());
+        }
+        jx += incx;
+    }
+}
+
+unsafe fn her<T: Scalar>(
+    name: &str,
+    uplo: c_char,
+    n: Int,
+    alpha: T::Real,
+    x: *const T,
+    incx: Int,
+    a: *mut T,
+    lda: Int,
+) {
+    let info = if !lsame(uplo, b'U') && !lsame(uplo, b'L') {
+        1
+    } else if n < 0 {
+        2
+    } else if incx == 0 {
+        5
+    } else if lda < n.max(1) {
+        7
+    } else {
+        0
+    };
+    if info != 0 {
+        return xerbla(name, info);
+    }
+    let lda = lda as usize;
+    let offset = |i: usize, j: usize| i + j * lda;
+    her_kernel(lsame(uplo, b'U'), n, alpha, x, incx, a, offset)
+}
+
+unsafe fn hpr<T: Scalar>(
+    name: &str,
+    uplo: c_char,
+    n: Int,
+    alpha: T::Real,
+    x: *const T,
+    incx: Int,
+    ap: *mut T,
+) {
+    let info = if !lsame(uplo, b'U') && !lsame(uplo, b'L') {
+        1
+    } else if n < 0 {
+        2
+    } else if incx == 0 {
+        5
+    } else {
+        0
+    };
+    if info != 0 {
+        return xerbla(name, info);
+    }
+    if lsame(uplo, b'U') {
+        her_kernel(true, n, alpha, x, incx, ap, upper_packed)
+    } else {
+        let order = n as usize;
+        let offset = |i: usize, j: usize| lower_packed(order, i, j);
+        her_kernel(false, n, alpha, x, incx, ap, offset)
+    }
+}
+
+/// Compute `A := alpha * x * y^H + conj(alpha) * y * x^H + A` for a Hermitian matrix whose
+/// elements are located by `offset`.
+unsafe fn her2_kernel<T: Scalar>(
+    upper: bool,
+    n: Int,
+    alpha: T,
+    x: *const T,
+    incx: Int,
+    y: *const T,
+    incy: Int,
+    a: *mut T,
+    offset: impl Fn(usize, usize) -> usize,
+) {
+    if n == 0 || alpha == T::zero() {
+        return;
+    }
+    let (kx, ky) = (start(n, incx), start(n, incy));
+    let a = |i: usize, j: usize| a.add(offset(i, j));
+    let (n, incx, incy) = (n as usize, incx as isize, incy as isize);
+    let (mut jx, mut jy) = (kx, ky);
+    for j in 0..n {
+        let (xj, yj) = (*x.offset(jx), *y.offset(jy));
+        if xj != T::zero() || yj != T::zero() {
+            let temp1 = alpha * yj.conj();
+            let temp2 = (alpha * xj).conj();
+            let range = if upper { 0..j } else { j + 1..n };
+            let (mut ix, mut iy) = if upper {
+                (kx, ky)
+            } else {
+                (jx + incx, jy + incy)
+            };
+            for i in range {
+                *a(i, j) = *a(i, j) + *x.offset(ix) * temp1 + *y.offset(iy) * temp2;
+                ix += incx;
+                iy += incy;
+            }
+            *a(j, j) = T::from_real((*a(j, j)).re() + (xj * temp1 + yj * temp2).re());
+        } else {
+            *a(j, j) = T::from_real((*a(j, j)).re());
+        }
+        jx += incx;
+        jy += incy;
+    }
+}
+
+unsafe fn her2<T: Scalar>(
+    name: &str,
+    uplo: c_char,
+    n: Int,
+    alpha: T,
+    x: *const T,
+    incx: Int,
+    y: *const T,
+    incy: Int,
+    a: *mut T,
+    lda: Int,
+) {
+    let info = if !lsame(uplo, b'U') && !lsame(uplo, b'L') {
+        1
+    } else if n < 0 {
+        2
+    } else if incx == 0 {
+        5
+    } else if incy == 0 {
+        7
+    } else if lda < n.max(1) {
+        9
+    } else {
+        0
+    };
+    if info != 0 {
+        return xerbla(name, info);
+    }
+    let lda = lda as usize;
+    let offset = |i: usize, j: usize| i + j * lda;
+    her2_kernel(lsame(uplo, b'U'), n, alpha, x, incx, y, incy, a, offset)
+}
+
+unsafe fn hpr2<T: Scalar>(
+    name: &str,
+    uplo: c_char,
+    n: Int,
+    alpha: T,
+    x: *const T,
+    incx: Int,
+    y: *const T,
+    incy: Int,
+    ap: *mut T,
+) {
+    let info = if !lsame(uplo, b'U') && !lsame(uplo, b'L') {
+        1
+    } else if n < 0 {
+        2
+    } else if incx == 0 {
+        5
+    } else if incy == 0 {
+        7
+    } else {
+        0
+    };
+    if info != 0 {
+        return xerbla(name, info);
+    }
+    if lsame(uplo, b'U') {
+        her2_kernel(true, n, alpha, x, incx, y, incy, ap, upper_packed)
+    } else {
+        let order = n as usize;
+        let offset = |i: usize, j: usize| lower_packed(order, i, j);
+        her2_kernel(false, n, alpha, x, incx, y, incy, ap, offset)
+    }
+}
+
+// Single
+
+pub unsafe fn sgemv_(
+    trans: *const c_char,
+    m: *const Int,
+    n: *const Int,
+    alpha: *const c_float,
+    a: *const c_float,
+    lda: *const Int,
+    x: *const c_float,
+    incx: *const Int,
+    beta: *const c_float,
+    y: *mut c_float,
+    incy: *const Int,
+) {
+    gemv(
+        "SGEMV", *trans, *m, *n, *alpha, a, *lda, x, *incx, *beta, y, *incy,
+    )
+}
+
+pub unsafe fn sgbmv_(
+    trans: *const c_char,
+    m: *const Int,
+    n: *const Int,
+    kl: *const Int,
+    ku: *const Int,
+    alpha: *const c_float,
+    a: *const c_float,
+    lda: *const Int,
+    x: *const c_float,
+    incx: *const Int,
+    beta: *const c_float,
+    y: *mut c_float,
+    incy: *const Int,
+) {
+    gbmv(
+        "SGBMV", *trans, *m, *n, *kl, *ku, *alpha, a, *lda, x, *incx, *beta, y, *incy,
+    )
+}
+
+pub unsafe fn ssymv_(
+    uplo: *const c_char,
+    n: *const Int,
+    alpha: *const c_float,
+    a: *const c_float,
+    lda: *const Int,
+    x: *const c_float,
+    incx: *const Int,
+    beta: *const c_float,
+    y: *mut c_float,
+    incy: *const Int,
+) {
+    hemv(
+        "SSYMV", *uplo, *n, *alpha, a, *lda, x, *incx, *beta, y, *incy,
+    )
+}
+
+pub unsafe fn ssbmv_(
+    uplo: *const c_char,
+    n: *const Int,
+    k: *const Int,
+    alpha: *const c_float,
+    a: *const c_float,
+    lda: *const Int,
+    x: *const c_float,
+    incx: *const Int,
+    beta: *const c_float,
+    y: *mut c_float,
+    incy: *const Int,
+) {
+    hbmv(
+        "SSBMV", *uplo, *n, *k, *alpha, a, *lda, x, *incx, *beta, y, *incy,
+    )
+}
+
+pub unsafe fn sspmv_(
+    uplo: *const c_char,
+    n: *const Int,
+    alpha: *const c_float,
+    ap: *const c_float,
+    x: *const c_float,
+    incx: *const Int,
+    beta: *const c_float,
+    y: *mut c_float,
+    incy: *const Int,
+) {
+    hpmv("SSPMV", *uplo, *n, *alpha, ap, x, *incx, *beta, y, *incy)
+}
+
+pub unsafe fn strmv_(
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    n: *const Int,
+    a: *const c_float,
+    lda: *const Int,
+    b: *mut c_float,
+    incx: *const Int,
+) {
+    trmv("STRMV", *uplo, *transa, *diag, *n, a, *lda, b, *incx, false)
+}
+
+pub unsafe fn stbmv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const Int,
+    k: *const Int,
+    a: *const c_float,
+    lda: *const Int,
+    x: *mut c_float,
+    incx: *const Int,
+) {
+    tbmv("STBMV", *uplo, *trans, *diag, *n, *k, a, *lda, x, *incx)
+}
+
+pub unsafe fn stpmv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const Int,
+    ap: *const c_float,
+    x: *mut c_float,
+    incx: *const Int,
+) {
+    tpmv("STPMV", *uplo, *trans, *diag, *n, ap, x, *incx, false)
+}
+
+pub unsafe fn strsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const Int,
+    a: *const c_float,
+    lda: *const Int,
+    x: *mut c_float,
+    incx: *const Int,
+) {
+    trmv("STRSV", *uplo, *trans, *diag, *n, a, *lda, x, *incx, true)
+}
+
+pub unsafe fn stbsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const Int,
+    k: *const Int,
+    a: *const c_float,
+    lda: *const Int,
+    x: *mut c_float,
+    incx: *const Int,
+) {
+    tbsv("STBSV", *uplo, *trans, *diag, *n, *k, a, *lda, x, *incx)
+}
+
+pub unsafe fn stpsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const Int,
+    ap: *const c_float,
+    x: *mut c_float,
+    incx: *const Int,
+) {
+    tpmv("STPSV", *uplo, *trans, *diag, *n, ap, x, *incx, true)
+}
+
+pub unsafe fn sger_(
+    m: *const Int,
+    n: *const Int,
+    alpha: *const c_float,
+    x: *const c_float,
+    incx: *const Int,
+    y: *const c_float,
+    incy: *const Int,
+    a: *mut c_float,
+    lda: *const Int,
+) {
+    ger("SGER", *m, *n, *alpha, x, *incx, y, *incy, a, *lda, false)
+}
+
+pub unsafe fn ssyr_(
+    uplo: *const c_char,
+    n: *const Int,
+    alpha: *const c_float,
+    x: *const c_float,
+    incx: *const Int,
+    a: *mut c_float,
+    lda: *const Int,
+) {
+    her("SSYR", *uplo, *n, *alpha, x, *incx, a, *lda)
+}
+
+pub unsafe fn sspr_(
+    uplo: *const c_char,
+    n: *const Int,
+    alpha: *const c_float,
+    x: *const c_float,
+    incx: *const Int,
+    ap: *mut c_float,
+) {
+    hpr("SSPR", *uplo, *n, *alpha, x, *incx, ap)
+}
+
+pub unsafe fn ssyr2_(
+    uplo: *const c_char,
+    n: *const Int,
+    alpha: *const c_float,
+    x: *const c_float,
+    incx: *const Int,
+    y: *const c_float,
+    incy: *const Int,
+    a: *mut c_float,
+    lda: *const Int,
+) {
+    her2("SSYR2", *uplo, *n, *alpha, x, *incx, y, *incy, a, *lda)
+}
+
+pub unsafe fn sspr2_(
+    uplo: *const c_char,
+    n: *const Int,
+    alpha: *const c_float,
+    x: *const c_float,
+    incx: *const Int,
+    y: *const c_float,
+    incy: *const Int,
+    ap: *mut c_float,
+) {
+    hpr2("SSPR2", *uplo, *n, *alpha, x, *incx, y, *incy, ap)
+}
+
+// Double
+
+pub unsafe fn dgemv_(
+    trans: *const c_char,
+    m: *const Int,
+    n: *const Int,
+    alpha: *const c_double,
+    a: *const c_double,
+    lda: *const Int,
+    x: *const c_double,
+    incx: *const Int,
+    beta: *const c_double,
+    y: *mut c_double,
+    incy: *const Int,
+) {
+    gemv(
+        "DGEMV", *trans, *m, *n, *alpha, a, *lda, x, *incx, *beta, y, *incy,
+    )
+}
+
+pub unsafe fn dgbmv_(
+    trans: *const c_char,
+    m: *const Int,
+    n: *const Int,
+    kl: *const Int,
+    ku: *const Int,
+    alpha: *const c_double,
+    a: *const c_double,
+    lda: *const Int,
+    x: *const c_double,
+    incx: *const Int,
+    beta: *const c_double,
+    y: *mut c_double,
+    incy: *const Int,
+) {
+    gbmv(
+        "DGBMV", *trans, *m, *n, *kl, *ku, *alpha, a, *lda, x, *incx, *beta, y, *incy,
+    )
+}
+
+pub unsafe fn dsymv_(
+    uplo: *const c_char,
+    n: *const Int,
+    alpha: *const c_double,
+    a: *const c_double,
+    lda: *const Int,
+    x: *const c_double,
+    incx: *const Int,
+    beta: *const c_double,
+    y: *mut c_double,
+    incy: *const Int,
+) {
+    hemv(
+        "DSYMV", *uplo, *n, *alpha, a, *lda, x, *incx, *beta, y, *incy,
+    )
+}
+
+pub unsafe fn dsbmv_(
+    uplo: *const c_char,
+    n: *const Int,
+    k: *const Int,
+    alpha: *const c_double,
+    a: *const c_double,
+    lda: *const Int,
+    x: *const c_double,
+    incx: *const Int,
+    beta: *const c_double,
+    y: *mut c_double,
+    incy: *const Int,
+) {
+    hbmv(
+        "DSBMV", *uplo, *n, *k, *alpha, a, *lda, x, *incx, *beta, y, *incy,
+    )
+}
+
+pub unsafe fn dspmv_(
+    uplo: *const c_char,
+    n: *const Int,
+    alpha: *const c_double,
+    ap: *const c_double,
+    x: *const c_double,
+    incx: *const Int,
+    beta: *const c_double,
+    y: *mut c_double,
+    incy: *const Int,
+) {
+    hpmv("DSPMV", *uplo, *n, *alpha, ap, x, *incx, *beta, y, *incy)
+}
+
+pub unsafe fn dtrmv_(
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    n: *const Int,
+    a: *const c_double,
+    lda: *const Int,
+    b: *mut c_double,
+    incx: *const Int,
+) {
+    trmv("DTRMV", *uplo, *transa, *diag, *n, a, *lda, b, *incx, false)
+}
+
+pub unsafe fn dtbmv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const Int,
+    k: *const Int,
+    a: *const c_double,
+    lda: *const Int,
+    x: *mut c_double,
+    incx: *const Int,
+) {
+    tbmv("DTBMV", *uplo, *trans, *diag, *n, *k, a, *lda, x, *incx)
+}
+
+pub unsafe fn dtpmv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const Int,
+    ap: *const c_double,
+    x: *mut c_double,
+    incx: *const Int,
+) {
+    tpmv("DTPMV", *uplo, *trans, *diag, *n, ap, x, *incx, false)
+}
+
+pub unsafe fn dtrsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const Int,
+    a: *const c_double,
+    lda: *const Int,
+    x: *mut c_double,
+    incx: *const Int,
+) {
+    trmv("DTRSV", *uplo, *trans, *diag, *n, a, *lda, x, *incx, true)
+}
+
+pub unsafe fn dtbsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const Int,
+    k: *const Int,
+    a: *const c_double,
+    lda: *const Int,
+    x: *mut c_double,
+    incx: *const Int,
+) {
+    tbsv("DTBSV", *uplo, *trans, *diag, *n, *k, a, *lda, x, *incx)
+}
+
+pub unsafe fn dtpsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const Int,
+    ap: *const c_double,
+    x: *mut c_double,
+    incx: *const Int,
+) {
+    tpmv("DTPSV", *uplo, *trans, *diag, *n, ap, x, *incx, true)
+}
+
+pub unsafe fn dger_(
+    m: *const Int,
+    n: *const Int,
+    alpha: *const c_double,
+    x: *const c_double,
+    incx: *const Int,
+    y: *const c_double,
+    incy: *const Int,
+    a: *mut c_double,
+    lda: *const Int,
+) {
+    ger("DGER", *m, *n, *alpha, x, *incx, y, *incy, a, *lda, false)
+}
+
+pub unsafe fn dsyr_(
+    uplo: *const c_char,
+    n: *const Int,
+    alpha: *const c_double,
+    x: *const c_double,
+    incx: *const Int,
+    a: *mut c_double,
+    lda: *const Int,
+) {
+    her("DSYR", *uplo, *n, *alpha, x, *incx, a, *lda)
+}
+
+pub unsafe fn dspr_(
+    uplo: *const c_char,
+    n: *const Int,
+    alpha: *const c_double,
+    x: *const c_double,
+    incx: *const Int,
+    ap: *mut c_double,
+) {
+    hpr("DSPR", *uplo, *n, *alpha, x, *incx, ap)
+}
+
+pub unsafe fn dsyr2_(
+    uplo: *const c_char,
+    n: *const Int,
+    alpha: *const c_double,
+    x: *const c_double,
+    incx: *const Int,
+    y: *const c_double,
+    incy: *const Int,
+    a: *mut c_double,
+    lda: *const Int,
+) {
+    her2("DSYR2", *uplo, *n, *alpha, x, *incx, y, *incy, a, *lda)
+}
+
+pub unsafe fn dspr2_(
+    uplo: *const c_char,
+    n: *const Int,
+    alpha: *const c_double,
+    x: *const c_double,
+    incx: *const Int,
+    y: *const c_double,
+    incy: *const Int,
+    ap: *mut c_double,
+) {
+    hpr2("DSPR2", *uplo, *n, *alpha, x, *incx, y, *incy, ap)
+}
+
+// Complex
+
+pub unsafe fn cgemv_(
+    trans: *const c_char,
+    m: *const Int,
+    n: *const Int,
+    alpha: *const c32,
+    a: *const c32,
+    lda: *const Int,
+    x: *const c32,
+    incx: *const Int,
+    beta: *const c32,
+    y: *mut c32,
+    incy: *const Int,
+) {
+    gemv(
+        "CGEMV", *trans, *m, *n, *alpha, a, *lda, x, *incx, *beta, y, *incy,
+    )
+}
+
+pub unsafe fn cgbmv_(
+    trans: *const c_char,
+    m: *const Int,
+    n: *const Int,
+    kl: *const Int,
+    ku: *const Int,
+    alpha: *const c32,
+    a: *const c32,
+    lda: *const Int,
+    x: *const c32,
+    incx: *const Int,
+    beta: *const c32,
+    y: *mut c32,
+    incy: *const Int,
+) {
+    gbmv(
+        "CGBMV", *trans, *m, *n, *kl, *ku, *alpha, a, *lda, x, *incx, *beta, y, *incy,
+    )
+}
+
+pub unsafe fn chemv_(
+    uplo: *const c_char,
+    n: *const Int,
+    alpha: *const c32,
+    a: *const c32,
+    lda: *const Int,
+    x: *const c32,
+    incx: *const Int,
+    beta: *const c32,
+    y: *mut c32,
+    incy: *const Int,
+) {
+    hemv(
+        "CHEMV", *uplo, *n, *alpha, a, *lda, x, *incx, *beta, y, *incy,
+    )
+}
+
+pub unsafe fn chbmv_(
+    uplo: *const c_char,
+    n: *const Int,
+    k: *const Int,
+    alpha: *const c32,
+    a: *const c32,
+    lda: *const Int,
+    x: *const c32,
+    incx: *const Int,
+    beta: *const c32,
+    y: *mut c32,
+    incy: *const Int,
+) {
+    hbmv(
+        "CHBMV", *uplo, *n, *k, *alpha, a, *lda, x, *incx, *beta, y, *incy,
+    )
+}
+
+pub unsafe fn chpmv_(
+    uplo: *const c_char,
+    n: *const Int,
+    alpha: *const c32,
+    ap: *const c32,
+    x: *const c32,
+    incx: *const Int,
+    beta: *const c32,
+    y: *mut c32,
+    incy: *const Int,
+) {
+    hpmv("CHPMV", *uplo, *n, *alpha, ap, x, *incx, *beta, y, *incy)
+}
+
+pub unsafe fn ctrmv_(
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    n: *const Int,
+    a: *const c32,
+    lda: *const Int,
+    b: *mut c32,
+    incx: *const Int,
+) {
+    trmv("CTRMV", *uplo, *transa, *diag, *n, a, *lda, b, *incx, false)
+}
+
+pub unsafe fn ctbmv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const Int,
+    k: *const Int,
+    a: *const c32,
+    lda: *const Int,
+    x: *mut c32,
+    incx: *const Int,
+) {
+    tbmv("CTBMV", *uplo, *trans, *diag, *n, *k, a, *lda, x, *incx)
+}
+
+pub unsafe fn ctpmv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const Int,
+    ap: *const c32,
+    x: *mut c32,
+    incx: *const Int,
+) {
+    tpmv("CTPMV", *uplo, *trans, *diag, *n, ap, x, *incx, false)
+}
+
+pub unsafe fn ctrsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const Int,
+    a: *const c32,
+    lda: *const Int,
+    x: *mut c32,
+    incx: *const Int,
+) {
+    trmv("CTRSV", *uplo, *trans, *diag, *n, a, *lda, x, *incx, true)
+}
+
+pub unsafe fn ctbsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const Int,
+    k: *const Int,
+    a: *const c32,
+    lda: *const Int,
+    x: *mut c32,
+    incx: *const Int,
+) {
+    tbsv("CTBSV", *uplo, *trans, *diag, *n, *k, a, *lda, x, *incx)
+}
+
+pub unsafe fn ctpsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const Int,
+    ap: *const c32,
+    x: *mut c32,
+    incx: *const Int,
+) {
+    tpmv("CTPSV", *uplo, *trans, *diag, *n, ap, x, *incx, true)
+}
+
+pub unsafe fn cgeru_(
+    m: *const Int,
+    n: *const Int,
+    alpha: *const c32,
+    x: *const c32,
+    incx: *const Int,
+    y: *const c32,
+    incy: *const Int,
+    a: *mut c32,
+    lda: *const Int,
+) {
+    ger("CGERU", *m, *n, *alpha, x, *incx, y, *incy, a, *lda, false)
+}
+
+pub unsafe fn cgerc_(
+    m: *const Int,
+    n: *const Int,
+    alpha: *const c32,
+    x: *const c32,
+    incx: *const Int,
+    y: *const c32,
+    incy: *const Int,
+    a: *mut c32,
+    lda: *const Int,
+) {
+    ger("CGERC", *m, *n, *alpha, x, *incx, y, *incy, a, *lda, true)
+}
+
+pub unsafe fn cher_(
+    uplo: *const c_char,
+    n: *const Int,
+    alpha: *const c_float,
+    x: *const c32,
+    incx: *const Int,
+    a: *mut c32,
+    lda: *const Int,
+) {
+    her("CHER", *uplo, *n, *alpha, x, *incx, a, *lda)
+}
+
+pub unsafe fn chpr_(
+    uplo: *const c_char,
+    n: *const Int,
+    alpha: *const c_float,
+    x: *const c32,
+    incx: *const Int,
+    ap: *mut c32,
+) {
+    hpr("CHPR", *uplo, *n, *alpha, x, *incx, ap)
+}
+
+pub unsafe fn chpr2_(
+    uplo: *const c_char,
+    n: *const Int,
+    alpha: *const c32,
+    x: *const c32,
+    incx: *const Int,
+    y: *const c32,
+    incy: *const Int,
+    ap: *mut c32,
+) {
+    hpr2("CHPR2", *uplo, *n, *alpha, x, *incx, y, *incy, ap)
+}
+
+pub unsafe fn cher2_(
+    uplo: *const c_char,
+    n: *const Int,
+    alpha: *const c32,
+    x: *const c32,
+    incx: *const Int,
+    y: *const c32,
+    incy: *const Int,
+    a: *mut c32,
+    lda: *const Int,
+) {
+    her2("CHER2", *uplo, *n, *alpha, x, *incx, y, *incy, a, *lda)
+}
+
+// Double complex
+
+pub unsafe fn zgemv_(
+    trans: *const c_char,
+    m: *const Int,
+    n: *const Int,
+    alpha: *const c64,
+    a: *const c64,
+    lda: *const Int,
+    x: *const c64,
+    incx: *const Int,
+    beta: *const c64,
+    y: *mut c64,
+    incy: *const Int,
+) {
+    gemv(
+        "ZGEMV", *trans, *m, *n, *alpha, a, *lda, x, *incx, *beta, y, *incy,
+    )
+}
+
+pub unsafe fn zgbmv_(
+    trans: *const c_char,
+    m: *const Int,
+    n: *const Int,
+    kl: *const Int,
+    ku: *const Int,
+    alpha: *const c64,
+    a: *const c64,
+    lda: *const Int,
+    x: *const c64,
+    incx: *const Int,
+    beta: *const c64,
+    y: *mut c64,
+    incy: *const Int,
+) {
+    gbmv(
+        "ZGBMV", *trans, *m, *n, *kl, *ku, *alpha, a, *lda, x, *incx, *beta, y, *incy,
+    )
+}
+
+pub unsafe fn zhemv_(
+    uplo: *const c_char,
+    n: *const Int,
+    alpha: *const c64,
+    a: *const c64,
+    lda: *const Int,
+    x: *const c64,
+    incx: *const Int,
+    beta: *const c64,
+    y: *mut c64,
+    incy: *const Int,
+) {
+    hemv(
+        "ZHEMV", *uplo, *n, *alpha, a, *lda, x, *incx, *beta, y, *incy,
+    )
+}
+
+pub unsafe fn zhbmv_(
+    uplo: *const c_char,
+    n: *const Int,
+    k: *const Int,
+    alpha: *const c64,
+    a: *const c64,
+    lda: *const Int,
+    x: *const c64,
+    incx: *const Int,
+    beta: *const c64,
+    y: *mut c64,
+    incy: *const Int,
+) {
+    hbmv(
+        "ZHBMV", *uplo, *n, *k, *alpha, a, *lda, x, *incx, *beta, y, *incy,
+    )
+}
+
+pub unsafe fn zhpmv_(
+    uplo: *const c_char,
+    n: *const Int,
+    alpha: *const c64,
+    ap: *const c64,
+    x: *const c64,
+    incx: *const Int,
+    beta: *const c64,
+    y: *mut c64,
+    incy: *const Int,
+) {
+    hpmv("ZHPMV", *uplo, *n, *alpha, ap, x, *incx, *beta, y, *incy)
+}
+
+pub unsafe fn ztrmv_(
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    n: *const Int,
+    a: *const c64,
+    lda: *const Int,
+    b: *mut c64,
+    incx: *const Int,
+) {
+    trmv("ZTRMV", *uplo, *transa, *diag, *n, a, *lda, b, *incx, false)
+}
+
+pub unsafe fn ztbmv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const Int,
+    k: *const Int,
+    a: *const c64,
+    lda: *const Int,
+    x: *mut c64,
+    incx: *const Int,
+) {
+    tbmv("ZTBMV", *uplo, *trans, *diag, *n, *k, a, *lda, x, *incx)
+}
+
+pub unsafe fn ztpmv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const Int,
+    ap: *const c64,
+    x: *mut c64,
+    incx: *const Int,
+) {
+    tpmv("ZTPMV", *uplo, *trans, *diag, *n, ap, x, *incx, false)
+}
+
+pub unsafe fn ztrsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const Int,
+    a: *const c64,
+    lda: *const Int,
+    x: *mut c64,
+    incx: *const Int,
+) {
+    trmv("ZTRSV", *uplo, *trans, *diag, *n, a, *lda, x, *incx, true)
+}
+
+pub unsafe fn ztbsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const Int,
+    k: *const Int,
+    a: *const c64,
+    lda: *const Int,
+    x: *mut c64,
+    incx: *const Int,
+) {
+    tbsv("ZTBSV", *uplo, *trans, *diag, *n, *k, a, *lda, x, *incx)
+}
+
+pub unsafe fn ztpsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const Int,
+    ap: *const c64,
+    x: *mut c64,
+    incx: *const Int,
+) {
+    tpmv("ZTPSV", *uplo, *trans, *diag, *n, ap, x, *incx, true)
+}
+
+pub unsafe fn zgeru_(
+    m: *const Int,
+    n: *const Int,
+    alpha: *const c64,
+    x: *const c64,
+    incx: *const Int,
+    y: *const c64,
+    incy: *const Int,
+    a: *mut c64,
+    lda: *const Int,
+) {
+    ger("ZGERU", *m, *n, *alpha, x, *incx, y, *incy, a, *lda, false)
+}
+
+pub unsafe fn zgerc_(
+    m: *const Int,
+    n: *const Int,
+    alpha: *const c64,
+    x: *const c64,
+    incx: *const Int,
+    y: *const c64,
+    incy: *const Int,
+    a: *mut c64,
+    lda: *const Int,
+) {
+    ger("ZGERC", *m, *n, *alpha, x, *incx, y, *incy, a, *lda, true)
+}
+
+pub unsafe fn zher_(
+    uplo: *const c_char,
+    n: *const Int,
+    alpha: *const c_double,
+    x: *const c64,
+    incx: *const Int,
+    a: *mut c64,
+    lda: *const Int,
+) {
+    her("ZHER", *uplo, *n, *alpha, x, *incx, a, *lda)
+}
+
+pub unsafe fn zhpr_(
+    uplo: *const c_char,
+    n: *const Int,
+    alpha: *const c_double,
+    x: *const c64,
+    incx: *const Int,
+    ap: *mut c64,
+) {
+    hpr("ZHPR", *uplo, *n, *alpha, x, *incx, ap)
+}
+
+pub unsafe fn zher2_(
+    uplo: *const c_char,
+    n: *const Int,
+    alpha: *const c64,
+    x: *const c64,
+    incx: *const Int,
+    y: *const c64,
+    incy: *const Int,
+    a: *mut c64,
+    lda: *const Int,
+) {
+    her2("ZHER2", *uplo, *n, *alpha, x, *incx, y, *incy, a, *lda)
+}
+
+pub unsafe fn zhpr2_(
+    uplo: *const c_char,
+    n: *const Int,
+    alpha: *const c64,
+    x: *const c64,
+    incx: *const Int,
+    y: *const c64,
+    incy: *const Int,
+    ap: *mut c64,
+) {
+    hpr2("ZHPR2", *uplo, *n, *alpha, x, *incx, y, *incy, ap)
+}
@@ -0,0 +1,1073 @@
+use core::ffi::{c_char, c_double, c_float};
+
+use super::{lsame, xerbla};
+use crate::{c32, c64, Int, Scalar};
+
+#[inline]
+fn conjugate<T: Scalar>(value: T, conjugate: bool) -> T {
+    if conjugate {
+        value.conj()
+    } else {
+        value
+    }
+}
+
+#[inline]
+fn check_flag(flag: c_char, allowed: &[u8]) -> bool {
+    allowed.iter().any(|&value| lsame(flag, value))
+}
+
+/// Compute `C := beta * C` for the whole matrix or, when `uplo` is given, for its upper or lower
+/// triangle.
+unsafe fn scale<T: Scalar>(m: usize, n: usize, beta: T, c: *mut T, ldc: usize, uplo: Option<bool>) {
+    for j in 0..n {
+        let range = match uplo {
+            Some(true) => 0..j + 1,
+            Some(false) => j..m,
+            None => 0..m,
+        };
+        for i in range {
+            let cij = c.add(i + j * ldc);
+            *cij = if beta == T::zero() {
+                T::zero()
+            } else {
+                beta * *cij
+            };
+        }
+    }
+}
+
+unsafe fn gemm<T: Scalar>(
+    name: &str,
+    transa: c_char,
+    transb: c_char,
+    m: Int,
+    n: Int,
+    k: Int,
+    alpha: T,
+    a: *const T,
+    lda: Int,
+    b: *const T,
+    ldb: Int,
+    beta: T,
+    c: *mut T,
+    ldc: Int,
+) {
+    let nota = lsame(transa, b'N');
+    let notb = lsame(transb, b'N');
+    let nrowa = if nota { m } else { k };
+    let nrowb = if notb { k } else { n };
+    let info = if !check_flag(transa, b"NTC") {
+        1
+    } else if !check_flag(transb, b"NTC") {
+        2
+    } else if m < 0 {
+        3
+    } else if n < 0 {
+        4
+    } else if k < 0 {
+        5
+    } else if lda < nrowa.max(1) {
+        8
+    } else if ldb < nrowb.max(1) {
+        10
+    } else if ldc < m.max(1) {
+        13
+    } else {
+        0
+    };
+    if info != 0 {
+        return xerbla(name, info);
+    }
+    if m == 0 || n == 0 || ((alpha == T::zero() || k == 0) && beta == T::one()) {
+        return;
+    }
+    let (m, n, k) = (m as usize, n as usize, k as usize);
+    let (lda, ldb, ldc) = (lda as usize, ldb as usize, ldc as usize);
+    if alpha == T::zero() {
+        scale(m, n, beta, c, ldc, None);
+        return;
+    }
+    let (conja, conjb) = (lsame(transa, b'C'), lsame(transb, b'C'));
+    let op_a = |i: usize, l: usize| {
+        if nota {
+            *a.add(i + l * lda)
+        } else {
+            conjugate(*a.add(l + i * lda), conja)
+        }
+    };
+    let op_b = |l: usize, j: usize| {
+        if notb {
+            *b.add(l + j * ldb)
+        } else {
+            conjugate(*b.add(j + l * ldb), conjb)
+        }
+    };
+    let c = |i: usize, j: usize| c.add(i + j * ldc);
+    for j in 0..n {
+        if nota {
+            if beta != T::one() {
+                for i in 0..m {
+                    *c(i, j) = if beta == T::zero() {
+                        T::zero()
+                    } else {
+                        beta * *c(i, j)
+                    };
+                }
+            }
+            for l in 0..k {
+                let temp = alpha * op_b(l, j);
+                for i in 0..m {
+                    *c(i, j) = *c(i, j) + temp * op_a(i, l);
+                }
+            }
+        } else {
+            for i in 0..m {
+                let mut temp = T::zero();
+                for l in 0..k {
+                    temp = temp + op_a(i, l) * op_b(l, j);
+                }
+                *c(i, j) = if beta == T::zero() {
+                    alpha * temp
+                } else {
+                    alpha * temp + beta * *c(i, j)
+                };
+            }
+        }
+    }
+}
+
+unsafe fn hemm<T: Scalar>(
+    name: &str,
+    side: c_char,
+    uplo: c_char,
+    m: Int,
+    n: Int,
+    alpha: T,
+    a: *const T,
+    lda: Int,
+    b: *const T,
+    ldb: Int,
+    beta: T,
+    c: *mut T,
+    ldc: Int,
+    herm: bool,
+) {
+    let left = lsame(side, b'L');
+    let upper = lsame(uplo, b'U');
+    let nrowa = if left { m } else { n };
+    let info = if !check_flag(side, b"LR") {
+        1
+    } else if !check_flag(uplo, b"UL") {
+        2
+    } else if m < 0 {
+        3
+    } else if n < 0 {
+        4
+    } else if lda < nrowa.max(1) {
+        7
+    } else if ldb < m.max(1) {
+        9
+    } else if ldc < m.max(1) {
+        12
+    } else {
+        0
+    };
+    if info != 0 {
+        return xerbla(name, info);
+    }
+    if m == 0 || n == 0 || (alpha == T::zero() && beta == T::one()) {
+        return;
+    }
+    let (m, n) = (m as usize, n as usize);
+    let (lda, ldb, ldc) = (lda as usize, ldb as usize, ldc as usize);
+    if alpha == T::zero() {
+        scale(m, n, beta, c, ldc, None);
+        return;
+    }
+    let a = |i: usize, j: usize| {
+        if i == j && herm {
+            T::from_real((*a.add(i + j * lda)).re())
+        } else if (i <= j) == upper {
+            *a.add(i + j * lda)
+        } else {
+            conjugate(*a.add(j + i * lda), herm)
+        }
+    };
+    let b = |i: usize, j: usize| *b.add(i + j * ldb);
+    let c = |i: usize, j: usize| c.add(i + j * ldc);
+    for j in 0..n {
+        for i in 0..m {
+            let mut temp = T::zero();
+            if left {
+                for l in 0..m {
+                    temp = temp + a(i, l) * b(l, j);
+                }
+            } else {
+                for l in 0..n {
+                    temp = temp + b(i, l) * a(l, j);
+                }
+            }
+            *c(i, j) = if beta == T::zero() {
+                alpha * temp
+            } else {
+                alpha * temp + beta * *c(i, j)
+            };
+        }
+    }
+}
+
+/// Compute a rank-k or, when `b` is given, a rank-2k update of the upper or lower triangle of
+/// `C`. For Hermitian updates, the second term is scaled by `conj(alpha)`.
+unsafe fn rank_update<T: Scalar>(
+    name: &str,
+    uplo: c_char,
+    trans: c_char,
+    n: Int,
+    k: Int,
+    alpha: T,
+    a: *const T,
+    lda: Int,
+    b: Option<(*const T, Int)>,
+    beta: T,
+    c: *mut T,
+    ldc: Int,
+    herm: bool,
+    allowed: &[u8],
+) {
+    let notrans = lsame(trans, b'N');
+    let upper = lsame(uplo, b'U');
+    let nrowa = if notrans { n } else { k };
+    let info = if !check_flag(uplo, b"UL") {
+        1
+    } else if !check_flag(trans, allowed) {
+        2
+    } else if n < 0 {
+        3
+    } else if k < 0 {
+        4
+    } else if lda < nrowa.max(1) {
+        7
+    } else if b.is_some_and(|(_, ldb)| ldb < nrowa.max(1)) {
+        9
+    } else if ldc < n.max(1) {
+        if b.is_some() {
+            12
+        } else {
+            10
+        }
+    } else {
+        0
+    };
+    if info != 0 {
+        return xerbla(name, info);
+    }
+    if n == 0 || ((alpha == T::zero() || k == 0) && beta == T::one()) {
+        return;
+    }
+    let (n, k) = (n as usize, k as usize);
+    let (lda, ldc) = (lda as usize, ldc as usize);
+    let real = |value: T| {
+        if herm {
+            T::from_real(value.re())
+        } else {
+            value
+        }
+    };
+    if alpha == T::zero() {
+        scale(n, n, beta, c, ldc, Some(upper));
+        for j in 0..n {
+            let cjj = c.add(j + j * ldc);
+            *cjj = real(*cjj);
+        }
+        return;
+    }
+    let c = |i: usize, j: usize| c.add(i + j * ldc);
+    // Return the sum over `l` of `op(X)(i, l) * op(Y)(j, l)^H`, where the transpose is a
+    // conjugate one for Hermitian updates.
+    let product = |x: *const T, ldx: usize, y: *const T, ldy: usize, i: usize, j: usize| {
+        let mut temp = T::zero();
+        for l in 0..k {
+            temp = temp
+                + if notrans {
+                    *x.add(i + l * ldx) * conjugate(*y.add(j + l * ldy), herm)
+                } else {
+                    conjugate(*x.add(l + i * ldx), herm) * *y.add(l + j * ldy)
+                };
+        }
+        temp
+    };
+    for j in 0..n {
+        let range = if upper { 0..j + 1 } else { j..n };
+        for i in range {
+            let mut temp = match b {
+                Some((b, ldb)) => {
+                    let ldb = ldb as usize;
+                    alpha * product(a, lda, b, ldb, i, j)
+                        + conjugate(alpha, herm) * product(b, ldb, a, lda, i, j)
+                }
+                None => alpha * product(a, lda, a, lda, i, j),
+            };
+            if beta != T::zero() {
+                temp = temp + beta * *c(i, j);
+            }
+            *c(i, j) = if i == j { real(temp) } else { temp };
+        }
+    }
+}
+
+unsafe fn syrk<T: Scalar>(
+    name: &str,
+    uplo: c_char,
+    trans: c_char,
+    n: Int,
+    k: Int,
+    alpha: T,
+    a: *const T,
+    lda: Int,
+    beta: T,
+    c: *mut T,
+    ldc: Int,
+    allowed: &[u8],
+) {
+    rank_update(
+        name, uplo, trans, n, k, alpha, a, lda, None, beta, c, ldc, false, allowed,
+    )
+}
+
+unsafe fn herk<T: Scalar>(
+    name: &str,
+    uplo: c_char,
+    trans: c_char,
+    n: Int,
+    k: Int,
+    alpha: T::Real,
+    a: *const T,
+    lda: Int,
+    beta: T::Real,
+    c: *mut T,
+    ldc: Int,
+) {
+    let (alpha, beta) = (T::from_real(alpha), T::from_real(beta));
+    rank_update(
+        name, uplo, trans, n, k, alpha, a, lda, None, beta, c, ldc, true, b"NC",
+    )
+}
+
+unsafe fn syr2k<T: Scalar>(
+    name: &str,
+    uplo: c_char,
+    trans: c_char,
+    n: Int,
+    k: Int,
+    alpha: T,
+    a: *const T,
+    lda: Int,
+    b: *const T,
+    ldb: Int,
+    beta: T,
+    c: *mut T,
+    ldc: Int,
+    allowed: &[u8],
+) {
+    let b = Some((b, ldb));
+    rank_update(
+        name, uplo, trans, n, k, alpha, a, lda, b, beta, c, ldc, false, allowed,
+    )
+}
+
+unsafe fn her2k<T: Scalar>(
+    name: &str,
+    uplo: c_char,
+    trans: c_char,
+    n: Int,
+    k: Int,
+    alpha: T,
+    a: *const T,
+    lda: Int,
+    b: *const T,
+    ldb: Int,
+    beta: T::Real,
+    c: *mut T,
+    ldc: Int,
+) {
+    let (b, beta) = (Some((b, ldb)), T::from_real(beta));
+    rank_update(
+        name, uplo, trans, n, k, alpha, a, lda, b, beta, c, ldc, true, b"NC",
+    )
+}
+
+/// Compute `B := alpha * op(A) * B` or `B := alpha * B * op(A)` or, when `solve` is set, the
+/// corresponding solutions of `op(A) * X = alpha * B` or `X * op(A) = alpha * B`.
+unsafe fn trmm<T: Scalar>(
+    name: &str,
+    side: c_char,
+    uplo: c_char,
+    transa: c_char,
+    diag: c_char,
+    m: Int,
+    n: Int,
+    alpha: T,
+    a: *const T,
+    lda: Int,
+    b: *mut T,
+    ldb: Int,
+    solve: bool,
+) {
+    let left = lsame(side, b'L');
+    let nrowa = if left { m } else { n };
+    let info = if !check_flag(side, b"LR") {
+        1
+    } else if !check_flag(uplo, b"UL") {
+        2
+    } else if !check_flag(transa, b"NTC") {
+        3
+    } else if !check_flag(diag, b"UN") {
+        4
+    } else if m < 0 {
+        5
+    } else if n < 0 {
+        6
+    } else if lda < nrowa.max(1) {
+        9
+    } else if ldb < m.max(1) {
+        11
+    } else {
+        0
+    };
+    if info != 0 {
+        return xerbla(name, info);
+    }
+    if m == 0 || n == 0 {
+        return;
+    }
+    let (m, n) = (m as usize, n as usize);
+    let (lda, ldb) = (lda as usize, ldb as usize);
+    if alpha == T::zero() {
+        scale(m, n, T::zero(), b, ldb, None);
+        return;
+    }
+    let upper = lsame(uplo, b'U');
+    let notrans = lsame(transa, b'N');
+    let conj = lsame(transa, b'C');
+    let unit = lsame(diag, b'U');
+    let element = |i: usize, j: usize| {
+        if i == j && unit {
+            T::one()
+        } else if i == j || (i < j) == upper {
+            *a.add(i + j * lda)
+        } else {
+            T::zero()
+        }
+    };
+    let op_a = |i: usize, j: usize| {
+        if notrans {
+            element(i, j)
+        } else {
+            conjugate(element(j, i), conj)
+        }
+    };
+    // Whether `op(A)` is upper triangular.
+    let op_upper = upper == notrans;
+    let b = |i: usize, j: usize| b.add(i + j * ldb);
+
+    if left {
+        // Process the rows of each column in the order that leaves the rows still to be read
+        // intact.
+        let forward = op_upper != solve;
+        for j in 0..n {
+            for step in 0..m {
+                let i = if forward { step } else { m - 1 - step };
+                let range = if op_upper { i + 1..m } else { 0..i };
+                let mut temp = T::zero();
+                for l in range {
+                    temp = temp + op_a(i, l) * *b(l, j);
+                }
+                *b(i, j) = if solve {
+                    (alpha * *b(i, j) - temp) / op_a(i, i)
+                } else {
+                    alpha * (op_a(i, i) * *b(i, j) + temp)
+                };
+            }
+        }
+    } else {
+        let forward = op_upper == solve;
+        for step in 0..n {
+            let j = if forward { step } else { n - 1 - step };
+            let range = if op_upper { 0..j } else { j + 1..n };
+            for i in 0..m {
+                let mut temp = T::zero();
+                for l in range.clone() {
+                    temp = temp + *b(i, l) * op_a(l, j);
+                }
+                *b(i, j) = if solve {
+                    (alpha * *b(i, j) - temp) / op_a(j, j)
+                } else {
+                    alpha * (*b(i, j) * op_a(j, j) + temp)
+                };
+            }
+        }
+    }
+}
+
+// Single
+
+pub unsafe fn sgemm_(
+    transa: *const c_char,
+    transb: *const c_char,
+    m: *const Int,
+    n: *const Int,
+    k: *const Int,
+    alpha: *const c_float,
+    a: *const c_float,
+    lda: *const Int,
+    b: *const c_float,
+    ldb: *const Int,
+    beta: *const c_float,
+    c: *mut c_float,
+    ldc: *const Int,
+) {
+    gemm(
+        "SGEMM", *transa, *transb, *m, *n, *k, *alpha, a, *lda, b, *ldb, *beta, c, *ldc,
+    )
+}
+
+pub unsafe fn ssymm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    m: *const Int,
+    n: *const Int,
+    alpha: *const c_float,
+    a: *const c_float,
+    lda: *const Int,
+    b: *const c_float,
+    ldb: *const Int,
+    beta: *const c_float,
+    c: *mut c_float,
+    ldc: *const Int,
+) {
+    hemm(
+        "SSYMM", *side, *uplo, *m, *n, *alpha, a, *lda, b, *ldb, *beta, c, *ldc, false,
+    )
+}
+
+pub unsafe fn ssyrk_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const Int,
+    k: *const Int,
+    alpha: *const c_float,
+    a: *const c_float,
+    lda: *const Int,
+    beta: *const c_float,
+    c: *mut c_float,
+    ldc: *const Int,
+) {
+    syrk(
+        "SSYRK", *uplo, *trans, *n, *k, *alpha, a, *lda, *beta, c, *ldc, b"NTC",
+    )
+}
+
+pub unsafe fn ssyr2k_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const Int,
+    k: *const Int,
+    alpha: *const c_float,
+    a: *const c_float,
+    lda: *const Int,
+    b: *const c_float,
+    ldb: *const Int,
+    beta: *const c_float,
+    c: *mut c_float,
+    ldc: *const Int,
+) {
+    syr2k(
+        "SSYR2K", *uplo, *trans, *n, *k, *alpha, a, *lda, b, *ldb, *beta, c, *ldc, b"NTC",
+    )
+}
+
+pub unsafe fn strmm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    m: *const Int,
+    n: *const Int,
+    alpha: *const c_float,
+    a: *const c_float,
+    lda: *const Int,
+    b: *mut c_float,
+    ldb: *const Int,
+) {
+    trmm(
+        "STRMM", *side, *uplo, *transa, *diag, *m, *n, *alpha, a, *lda, b, *ldb, false,
+    )
+}
+
+pub unsafe fn strsm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    m: *const Int,
+    n: *const Int,
+    alpha: *const c_float,
+    a: *const c_float,
+    lda: *const Int,
+    b: *mut c_float,
+    ldb: *const Int,
+) {
+    trmm(
+        "STRSM", *side, *uplo, *transa, *diag, *m, *n, *alpha, a, *lda, b, *ldb, true,
+    )
+}
+
+// Double
+
+pub unsafe fn dgemm_(
+    transa: *const c_char,
+    transb: *const c_char,
+    m: *const Int,
+    n: *const Int,
+    k: *const Int,
+    alpha: *const c_double,
+    a: *const c_double,
+    lda: *const Int,
+    b: *const c_double,
+    ldb: *const Int,
+    beta: *const c_double,
+    c: *mut c_double,
+    ldc: *const Int,
+) {
+    gemm(
+        "DGEMM", *transa, *transb, *m, *n, *k, *alpha, a, *lda, b, *ldb, *beta, c, *ldc,
+    )
+}
+
+pub unsafe fn dsymm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    m: *const Int,
+    n: *const Int,
+    alpha: *const c_double,
+    a: *const c_double,
+    lda: *const Int,
+    b: *const c_double,
+    ldb: *const Int,
+    beta: *const c_double,
+    c: *mut c_double,
+    ldc: *const Int,
+) {
+    hemm(
+        "DSYMM", *side, *uplo, *m, *n, *alpha, a, *lda, b, *ldb, *beta, c, *ldc, false,
+    )
+}
+
+pub unsafe fn dsyrk_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const Int,
+    k: *const Int,
+    alpha: *const c_double,
+    a: *const c_double,
+    lda: *const Int,
+    beta: *const c_double,
+    c: *mut c_double,
+    ldc: *const Int,
+) {
+    syrk(
+        "DSYRK", *uplo, *trans, *n, *k, *alpha, a, *lda, *beta, c, *ldc, b"NTC",
+    )
+}
+
+pub unsafe fn dsyr2k_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const Int,
+    k: *const Int,
+    alpha: *const c_double,
+    a: *const c_double,
+    lda: *const Int,
+    b: *const c_double,
+    ldb: *const Int,
+    beta: *const c_double,
+    c: *mut c_double,
+    ldc: *const Int,
+) {
+    syr2k(
+        "DSYR2K", *uplo, *trans, *n, *k, *alpha, a, *lda, b, *ldb, *beta, c, *ldc, b"NTC",
+    )
+}
+
+pub unsafe fn dtrmm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    m: *const Int,
+    n: *const Int,
+    alpha: *const c_double,
+    a: *const c_double,
+    lda: *const Int,
+    b: *mut c_double,
+    ldb: *const Int,
+) {
+    trmm(
+        "DTRMM", *side, *uplo, *transa, *diag, *m, *n, *alpha, a, *lda, b, *ldb, false,
+    )
+}
+
+pub unsafe fn dtrsm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    m: *const Int,
+    n: *const Int,
+    alpha: *const c_double,
+    a: *const c_double,
+    lda: *const Int,
+    b: *mut c_double,
+    ldb: *const Int,
+) {
+    trmm(
+        "DTRSM", *side, *uplo, *transa, *diag, *m, *n, *alpha, a, *lda, b, *ldb, true,
+    )
+}
+
+// Complex
+
+pub unsafe fn cgemm_(
+    transa: *const c_char,
+    transb: *const c_char,
+    m: *const Int,
+    n: *const Int,
+    k: *const Int,
+    alpha: *const c32,
+    a: *const c32,
+    lda: *const Int,
+    b: *const c32,
+    ldb: *const Int,
+    beta: *const c32,
+    c: *mut c32,
+    ldc: *const Int,
+) {
+    gemm(
+        "CGEMM", *transa, *transb, *m, *n, *k, *alpha, a, *lda, b, *ldb, *beta, c, *ldc,
+    )
+}
+
+pub unsafe fn csymm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    m: *const Int,
+    n: *const Int,
+    alpha: *const c32,
+    a: *const c32,
+    lda: *const Int,
+    b: *const c32,
+    ldb: *const Int,
+    beta: *const c32,
+    c: *mut c32,
+    ldc: *const Int,
+) {
+    hemm(
+        "CSYMM", *side, *uplo, *m, *n, *alpha, a, *lda, b, *ldb, *beta, c, *ldc, false,
+    )
+}
+
+pub unsafe fn chemm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    m: *const Int,
+    n: *const Int,
+    alpha: *const c32,
+    a: *const c32,
+    lda: *const Int,
+    b: *const c32,
+    ldb: *const Int,
+    beta: *const c32,
+    c: *mut c32,
+    ldc: *const Int,
+) {
+    hemm(
+        "CHEMM", *side, *uplo, *m, *n, *alpha, a, *lda, b, *ldb, *beta, c, *ldc, true,
+    )
+}
+
+pub unsafe fn csyrk_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const Int,
+    k: *const Int,
+    alpha: *const c32,
+    a: *const c32,
+    lda: *const Int,
+    beta: *const c32,
+    c: *mut c32,
+    ldc: *const Int,
+) {
+    syrk(
+        "CSYRK", *uplo, *trans, *n, *k, *alpha, a, *lda, *beta, c, *ldc, b"NT",
+    )
+}
+
+pub unsafe fn cherk_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const Int,
+    k: *const Int,
+    alpha: *const c_float,
+    a: *const c32,
+    lda: *const Int,
+    beta: *const c_float,
+    c: *mut c32,
+    ldc: *const Int,
+) {
+    herk(
+        "CHERK", *uplo, *trans, *n, *k, *alpha, a, *lda, *beta, c, *ldc,
+    )
+}
+
+pub unsafe fn csyr2k_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const Int,
+    k: *const Int,
+    alpha: *const c32,
+    a: *const c32,
+    lda: *const Int,
+    b: *const c32,
+    ldb: *const Int,
+    beta: *const c32,
+    c: *mut c32,
+    ldc: *const Int,
+) {
+    syr2k(
+        "CSYR2K", *uplo, *trans, *n, *k, *alpha, a, *lda, b, *ldb, *beta, c, *ldc, b"NT",
+    )
+}
+
+pub unsafe fn cher2k_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const Int,
+    k: *const Int,
+    alpha: *const c32,
+    a: *const c32,
+    lda: *const Int,
+    b: *const c32,
+    ldb: *const Int,
+    beta: *const c_float,
+    c: *mut c32,
+    ldc: *const Int,
+) {
+    her2k(
+        "CHER2K", *uplo, *trans, *n, *k, *alpha, a, *lda, b, *ldb, *beta, c, *ldc,
+    )
+}
+
+pub unsafe fn ctrmm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    m: *const Int,
+    n: *const Int,
+    alpha: *const c32,
+    a: *const c32,
+    lda: *const Int,
+    b: *mut c32,
+    ldb: *const Int,
+) {
+    trmm(
+        "CTRMM", *side, *uplo, *transa, *diag, *m, *n, *alpha, a, *lda, b, *ldb, false,
+    )
+}
+
+pub unsafe fn ctrsm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    m: *const Int,
+    n: *const Int,
+    alpha: *const c32,
+    a: *const c32,
+    lda: *const Int,
+    b: *mut c32,
+    ldb: *const Int,
+) {
+    trmm(
+        "CTRSM", *side, *uplo, *transa, *diag, *m, *n, *alpha, a, *lda, b, *ldb, true,
+    )
+}
+
+// Double complex
+
+pub unsafe fn zgemm_(
+    transa: *const c_char,
+    transb: *const c_char,
+    m: *const Int,
+    n: *const Int,
+    k: *const Int,
+    alpha: *const c64,
+    a: *const c64,
+    lda: *const Int,
+    b: *const c64,
+    ldb: *const Int,
+    beta: *const c64,
+    c: *mut c64,
+    ldc: *const Int,
+) {
+    gemm(
+        "ZGEMM", *transa, *transb, *m, *n, *k, *alpha, a, *lda, b, *ldb, *beta, c, *ldc,
+    )
+}
+
+pub unsafe fn zsymm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    m: *const Int,
+    n: *const Int,
+    alpha: *const c64,
+    a: *const c64,
+    lda: *const Int,
+    b: *const c64,
+    ldb: *const Int,
+    beta: *const c64,
+    c: *mut c64,
+    ldc: *const Int,
+) {
+    hemm(
+        "ZSYMM", *side, *uplo, *m, *n, *alpha, a, *lda, b, *ldb, *beta, c, *ldc, false,
+    )
+}
+
+pub unsafe fn zhemm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    m: *const Int,
+    n: *const Int,
+    alpha: *const c64,
+    a: *const c64,
+    lda: *const Int,
+    b: *const c64,
+    ldb: *const Int,
+    beta: *const c64,
+    c: *mut c64,
+    ldc: *const Int,
+) {
+    hemm(
+        "ZHEMM", *side, *uplo, *m, *n, *alpha, a, *lda, b, *ldb, *beta, c, *ldc, true,
+    )
+}
+
+pub unsafe fn zsyrk_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const Int,
+    k: *const Int,
+    alpha: *const c64,
+    a: *const c64,
+    lda: *const Int,
+    beta: *const c64,
+    c: *mut c64,
+    ldc: *const Int,
+) {
+    syrk(
+        "ZSYRK", *uplo, *trans, *n, *k, *alpha, a, *lda, *beta, c, *ldc, b"NT",
+    )
+}
+
+pub unsafe fn zherk_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const Int,
+    k: *const Int,
+    alpha: *const c_double,
+    a: *const c64,
+    lda: *const Int,
+    beta: *const c_double,
+    c: *mut c64,
+    ldc: *const Int,
+) {
+    herk(
+        "ZHERK", *uplo, *trans, *n, *k, *alpha, a, *lda, *beta, c, *ldc,
+    )
+}
+
+pub unsafe fn zsyr2k_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const Int,
+    k: *const Int,
+    alpha: *const c64,
+    a: *const c64,
+    lda: *const Int,
+    b: *const c64,
+    ldb: *const Int,
+    beta: *const c64,
+    c: *mut c64,
+    ldc: *const Int,
+) {
+    syr2k(
+        "ZSYR2K", *uplo, *trans, *n, *k, *alpha, a, *lda, b, *ldb, *beta, c, *ldc, b"NT",
+    )
+}
+
+pub unsafe fn zher2k_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const Int,
+    k: *const Int,
+    alpha: *const c64,
+    a: *const c64,
+    lda: *const Int,
+    b: *const c64,
+    ldb: *const Int,
+    beta: *const c_double,
+    c: *mut c64,
+    ldc: *const Int,
+) {
+    her2k(
+        "ZHER2K", *uplo, *trans, *n, *k, *alpha, a, *lda, b, *ldb, *beta, c, *ldc,
+    )
+}
+
+pub unsafe fn ztrmm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    m: *const Int,
+    n: *const Int,
+    alpha: *const c64,
+    a: *const c64,
+    lda: *const Int,
+    b: *mut c64,
+    ldb: *const Int,
+) {
+    trmm(
+        "ZTRMM", *side, *uplo, *transa, *diag, *m, *n, *alpha, a, *lda, b, *ldb, false,
+    )
+}
+
+pub unsafe fn ztrsm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    m: *const Int,
+    n: *const Int,
+    alpha: *const c64,
+    a: *const c64,
+    lda: *const Int,
+    b: *mut c64,
+    ldb: *const Int,
+) {
+    trmm(
+        "ZTRSM", *side, *uplo, *transa, *diag, *m, *n, *alpha, a, *lda, b, *ldb, true,
+    )
+}
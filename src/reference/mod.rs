@@ -0,0 +1,25 @@
+//! Pure-Rust implementation of the reference BLAS.
+//!
+//! The module exposes the same symbols as `blas-sys` and is used in its place when the
+//! `reference` feature is enabled, so the wrappers work without a native library. The
+//! algorithms follow the reference Fortran implementation, including the treatment of negative
+//! increments, quick returns, and argument checks, which panic with the message that `XERBLA`
+//! would print unless the error is caught by `catch_blas_errors`.
+
+use core::ffi::c_char;
+
+use crate::util::{start, xerbla};
+
+mod level1;
+mod level2;
+mod level3;
+
+pub use level1::*;
+pub use level2::*;
+pub use level3::*;
+
+/// Check whether a flag matches a character, ignoring case.
+#[inline]
+fn lsame(ca: c_char, cb: u8) -> bool {
+    (ca as u8).eq_ignore_ascii_case(&cb)
+}
@@ -0,0 +1,963 @@
+use super::{
+    check_dimension, check_flag, check_leading_dimension, check_len, check_vector, matrix_len,
+    no_trans, BlasError, Reason, TRANS, UPLO,
+};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "half")]
+#[cfg_attr(not(feature = "alloc"), allow(unused_imports))]
+use crate::{bf16, f16};
+use crate::{c32, c64, Int};
+
+const ORDER: &[u8] = b"CR";
+const TRANS_R: &[u8] = b"NTCR";
+
+#[inline]
+pub fn saxpby(
+    n: Int,
+    alpha: f32,
+    x: &[f32],
+    incx: Int,
+    beta: f32,
+    y: &mut [f32],
+    incy: Int,
+) -> Result<(), BlasError> {
+    check_vector("saxpby", 3, n, incx, x.len())?;
+    check_vector("saxpby", 6, n, incy, y.len())?;
+    unsafe { crate::saxpby(n, alpha, x, incx, beta, y, incy) };
+    Ok(())
+}
+
+#[inline]
+pub fn sgemmt(
+    uplo: u8,
+    transa: u8,
+    transb: u8,
+    n: Int,
+    k: Int,
+    alpha: f32,
+    a: &[f32],
+    lda: Int,
+    b: &[f32],
+    ldb: Int,
+    beta: f32,
+    c: &mut [f32],
+    ldc: Int,
+) -> Result<(), BlasError> {
+    let routine = "sgemmt";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_flag(routine, 2, transa, TRANS)?;
+    check_flag(routine, 3, transb, TRANS)?;
+    check_dimension(routine, 4, n)?;
+    check_dimension(routine, 5, k)?;
+    let (nrowa, ncola) = if no_trans(transa) { (n, k) } else { (k, n) };
+    let (nrowb, ncolb) = if no_trans(transb) { (k, n) } else { (n, k) };
+    check_leading_dimension(routine, 8, lda, nrowa.max(1))?;
+    check_leading_dimension(routine, 10, ldb, nrowb.max(1))?;
+    check_leading_dimension(routine, 13, ldc, n.max(1))?;
+    check_len(routine, 7, matrix_len(nrowa, ncola, lda), a.len())?;
+    check_len(routine, 9, matrix_len(nrowb, ncolb, ldb), b.len())?;
+    check_len(routine, 12, matrix_len(n, n, ldc), c.len())?;
+    unsafe {
+        crate::sgemmt(
+            uplo, transa, transb, n, k, alpha, a, lda, b, ldb, beta, c, ldc,
+        )
+    };
+    Ok(())
+}
+
+#[cfg(any(
+    feature = "alloc",
+    all(feature = "extension-matcopy", not(feature = "reference")),
+))]
+#[inline]
+pub fn simatcopy(
+    order: u8,
+    trans: u8,
+    rows: Int,
+    cols: Int,
+    alpha: f32,
+    ab: &mut [f32],
+    lda: Int,
+    ldb: Int,
+) -> Result<(), BlasError> {
+    let routine = "simatcopy";
+    let (a, b) = check_copy(routine, order, trans, rows, cols, lda, ldb, 8)?;
+    check_len(routine, 6, a.max(b), ab.len())?;
+    unsafe { crate::simatcopy(order, trans, rows, cols, alpha, ab, lda, ldb) };
+    Ok(())
+}
+
+#[inline]
+pub fn somatcopy(
+    order: u8,
+    trans: u8,
+    rows: Int,
+    cols: Int,
+    alpha: f32,
+    a: &[f32],
+    lda: Int,
+    b: &mut [f32],
+    ldb: Int,
+) -> Result<(), BlasError> {
+    let routine = "somatcopy";
+    let (lena, lenb) = check_copy(routine, order, trans, rows, cols, lda, ldb, 9)?;
+    check_len(routine, 6, lena, a.len())?;
+    check_len(routine, 8, lenb, b.len())?;
+    unsafe { crate::somatcopy(order, trans, rows, cols, alpha, a, lda, b, ldb) };
+    Ok(())
+}
+
+#[inline]
+pub fn daxpby(
+    n: Int,
+    alpha: f64,
+    x: &[f64],
+    incx: Int,
+    beta: f64,
+    y: &mut [f64],
+    incy: Int,
+) -> Result<(), BlasError> {
+    check_vector("daxpby", 3, n, incx, x.len())?;
+    check_vector("daxpby", 6, n, incy, y.len())?;
+    unsafe { crate::daxpby(n, alpha, x, incx, beta, y, incy) };
+    Ok(())
+}
+
+#[inline]
+pub fn dgemmt(
+    uplo: u8,
+    transa: u8,
+    transb: u8,
+    n: Int,
+    k: Int,
+    alpha: f64,
+    a: &[f64],
+    lda: Int,
+    b: &[f64],
+    ldb: Int,
+    beta: f64,
+    c: &mut [f64],
+    ldc: Int,
+) -> Result<(), BlasError> {
+    let routine = "dgemmt";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_flag(routine, 2, transa, TRANS)?;
+    check_flag(routine, 3, transb, TRANS)?;
+    check_dimension(routine, 4, n)?;
+    check_dimension(routine, 5, k)?;
+    let (nrowa, ncola) = if no_trans(transa) { (n, k) } else { (k, n) };
+    let (nrowb, ncolb) = if no_trans(transb) { (k, n) } else { (n, k) };
+    check_leading_dimension(routine, 8, lda, nrowa.max(1))?;
+    check_leading_dimension(routine, 10, ldb, nrowb.max(1))?;
+    check_leading_dimension(routine, 13, ldc, n.max(1))?;
+    check_len(routine, 7, matrix_len(nrowa, ncola, lda), a.len())?;
+    check_len(routine, 9, matrix_len(nrowb, ncolb, ldb), b.len())?;
+    check_len(routine, 12, matrix_len(n, n, ldc), c.len())?;
+    unsafe {
+        crate::dgemmt(
+            uplo, transa, transb, n, k, alpha, a, lda, b, ldb, beta, c, ldc,
+        )
+    };
+    Ok(())
+}
+
+#[cfg(any(
+    feature = "alloc",
+    all(feature = "extension-matcopy", not(feature = "reference")),
+))]
+#[inline]
+pub fn dimatcopy(
+    order: u8,
+    trans: u8,
+    rows: Int,
+    cols: Int,
+    alpha: f64,
+    ab: &mut [f64],
+    lda: Int,
+    ldb: Int,
+) -> Result<(), BlasError> {
+    let routine = "dimatcopy";
+    let (a, b) = check_copy(routine, order, trans, rows, cols, lda, ldb, 8)?;
+    check_len(routine, 6, a.max(b), ab.len())?;
+    unsafe { crate::dimatcopy(order, trans, rows, cols, alpha, ab, lda, ldb) };
+    Ok(())
+}
+
+#[inline]
+pub fn domatcopy(
+    order: u8,
+    trans: u8,
+    rows: Int,
+    cols: Int,
+    alpha: f64,
+    a: &[f64],
+    lda: Int,
+    b: &mut [f64],
+    ldb: Int,
+) -> Result<(), BlasError> {
+    let routine = "domatcopy";
+    let (lena, lenb) = check_copy(routine, order, trans, rows, cols, lda, ldb, 9)?;
+    check_len(routine, 6, lena, a.len())?;
+    check_len(routine, 8, lenb, b.len())?;
+    unsafe { crate::domatcopy(order, trans, rows, cols, alpha, a, lda, b, ldb) };
+    Ok(())
+}
+
+#[inline]
+pub fn caxpby(
+    n: Int,
+    alpha: c32,
+    x: &[c32],
+    incx: Int,
+    beta: c32,
+    y: &mut [c32],
+    incy: Int,
+) -> Result<(), BlasError> {
+    check_vector("caxpby", 3, n, incx, x.len())?;
+    check_vector("caxpby", 6, n, incy, y.len())?;
+    unsafe { crate::caxpby(n, alpha, x, incx, beta, y, incy) };
+    Ok(())
+}
+
+#[inline]
+pub fn cgemmt(
+    uplo: u8,
+    transa: u8,
+    transb: u8,
+    n: Int,
+    k: Int,
+    alpha: c32,
+    a: &[c32],
+    lda: Int,
+    b: &[c32],
+    ldb: Int,
+    beta: c32,
+    c: &mut [c32],
+    ldc: Int,
+) -> Result<(), BlasError> {
+    let routine = "cgemmt";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_flag(routine, 2, transa, TRANS)?;
+    check_flag(routine, 3, transb, TRANS)?;
+    check_dimension(routine, 4, n)?;
+    check_dimension(routine, 5, k)?;
+    let (nrowa, ncola) = if no_trans(transa) { (n, k) } else { (k, n) };
+    let (nrowb, ncolb) = if no_trans(transb) { (k, n) } else { (n, k) };
+    check_leading_dimension(routine, 8, lda, nrowa.max(1))?;
+    check_leading_dimension(routine, 10, ldb, nrowb.max(1))?;
+    check_leading_dimension(routine, 13, ldc, n.max(1))?;
+    check_len(routine, 7, matrix_len(nrowa, ncola, lda), a.len())?;
+    check_len(routine, 9, matrix_len(nrowb, ncolb, ldb), b.len())?;
+    check_len(routine, 12, matrix_len(n, n, ldc), c.len())?;
+    unsafe {
+        crate::cgemmt(
+            uplo, transa, transb, n, k, alpha, a, lda, b, ldb, beta, c, ldc,
+        )
+    };
+    Ok(())
+}
+
+#[cfg(any(
+    feature = "alloc",
+    all(feature = "extension-matcopy", not(feature = "reference")),
+))]
+#[inline]
+pub fn cimatcopy(
+    order: u8,
+    trans: u8,
+    rows: Int,
+    cols: Int,
+    alpha: c32,
+    ab: &mut [c32],
+    lda: Int,
+    ldb: Int,
+) -> Result<(), BlasError> {
+    let routine = "cimatcopy";
+    let (a, b) = check_copy(routine, order, trans, rows, cols, lda, ldb, 8)?;
+    check_len(routine, 6, a.max(b), ab.len())?;
+    unsafe { crate::cimatcopy(order, trans, rows, cols, alpha, ab, lda, ldb) };
+    Ok(())
+}
+
+#[inline]
+pub fn comatcopy(
+    order: u8,
+    trans: u8,
+    rows: Int,
+    cols: Int,
+    alpha: c32,
+    a: &[c32],
+    lda: Int,
+    b: &mut [c32],
+    ldb: Int,
+) -> Result<(), BlasError> {
+    let routine = "comatcopy";
+    let (lena, lenb) = check_copy(routine, order, trans, rows, cols, lda, ldb, 9)?;
+    check_len(routine, 6, lena, a.len())?;
+    check_len(routine, 8, lenb, b.len())?;
+    unsafe { crate::comatcopy(order, trans, rows, cols, alpha, a, lda, b, ldb) };
+    Ok(())
+}
+
+#[inline]
+pub fn zaxpby(
+    n: Int,
+    alpha: c64,
+    x: &[c64],
+    incx: Int,
+    beta: c64,
+    y: &mut [c64],
+    incy: Int,
+) -> Result<(), BlasError> {
+    check_vector("zaxpby", 3, n, incx, x.len())?;
+    check_vector("zaxpby", 6, n, incy, y.len())?;
+    unsafe { crate::zaxpby(n, alpha, x, incx, beta, y, incy) };
+    Ok(())
+}
+
+#[inline]
+pub fn zgemmt(
+    uplo: u8,
+    transa: u8,
+    transb: u8,
+    n: Int,
+    k: Int,
+    alpha: c64,
+    a: &[c64],
+    lda: Int,
+    b: &[c64],
+    ldb: Int,
+    beta: c64,
+    c: &mut [c64],
+    ldc: Int,
+) -> Result<(), BlasError> {
+    let routine = "zgemmt";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_flag(routine, 2, transa, TRANS)?;
+    check_flag(routine, 3, transb, TRANS)?;
+    check_dimension(routine, 4, n)?;
+    check_dimension(routine, 5, k)?;
+    let (nrowa, ncola) = if no_trans(transa) { (n, k) } else { (k, n) };
+    let (nrowb, ncolb) = if no_trans(transb) { (k, n) } else { (n, k) };
+    check_leading_dimension(routine, 8, lda, nrowa.max(1))?;
+    check_leading_dimension(routine, 10, ldb, nrowb.max(1))?;
+    check_leading_dimension(routine, 13, ldc, n.max(1))?;
+    check_len(routine, 7, matrix_len(nrowa, ncola, lda), a.len())?;
+    check_len(routine, 9, matrix_len(nrowb, ncolb, ldb), b.len())?;
+    check_len(routine, 12, matrix_len(n, n, ldc), c.len())?;
+    unsafe {
+        crate::zgemmt(
+            uplo, transa, transb, n, k, alpha, a, lda, b, ldb, beta, c, ldc,
+        )
+    };
+    Ok(())
+}
+
+#[cfg(any(
+    feature = "alloc",
+    all(feature = "extension-matcopy", not(feature = "reference")),
+))]
+#[inline]
+pub fn zimatcopy(
+    order: u8,
+    trans: u8,
+    rows: Int,
+    cols: Int,
+    alpha: c64,
+    ab: &mut [c64],
+    lda: Int,
+    ldb: Int,
+) -> Result<(), BlasError> {
+    let routine = "zimatcopy";
+    let (a, b) = check_copy(routine, order, trans, rows, cols, lda, ldb, 8)?;
+    check_len(routine, 6, a.max(b), ab.len())?;
+    unsafe { crate::zimatcopy(order, trans, rows, cols, alpha, ab, lda, ldb) };
+    Ok(())
+}
+
+#[inline]
+pub fn zomatcopy(
+    order: u8,
+    trans: u8,
+    rows: Int,
+    cols: Int,
+    alpha: c64,
+    a: &[c64],
+    lda: Int,
+    b: &mut [c64],
+    ldb: Int,
+) -> Result<(), BlasError> {
+    let routine = "zomatcopy";
+    let (lena, lenb) = check_copy(routine, order, trans, rows, cols, lda, ldb, 9)?;
+    check_len(routine, 6, lena, a.len())?;
+    check_len(routine, 8, lenb, b.len())?;
+    unsafe { crate::zomatcopy(order, trans, rows, cols, alpha, a, lda, b, ldb) };
+    Ok(())
+}
+
+/// Check the arguments of `imatcopy` and `omatcopy` and return the numbers of elements spanned
+/// by the input and output matrices.
+#[inline]
+fn check_copy(
+    routine: &'static str,
+    order: u8,
+    trans: u8,
+    rows: Int,
+    cols: Int,
+    lda: Int,
+    ldb: Int,
+    ldb_param: usize,
+) -> Result<(usize, usize), BlasError> {
+    check_flag(routine, 1, order, ORDER)?;
+    check_flag(routine, 2, trans, TRANS_R)?;
+    check_dimension(routine, 3, rows)?;
+    check_dimension(routine, 4, cols)?;
+    let (rows, cols) = if order.eq_ignore_ascii_case(&b'R') {
+        (cols, rows)
+    } else {
+        (rows, cols)
+    };
+    let (rowsb, colsb) = if b"TC".contains(&trans.to_ascii_uppercase()) {
+        (cols, rows)
+    } else {
+        (rows, cols)
+    };
+    check_leading_dimension(routine, 7, lda, rows.max(1))?;
+    check_leading_dimension(routine, ldb_param, ldb, rowsb.max(1))?;
+    Ok((matrix_len(rows, cols, lda), matrix_len(rowsb, colsb, ldb)))
+}
+
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn sgemm_batch(
+    transa: u8,
+    transb: u8,
+    m: Int,
+    n: Int,
+    k: Int,
+    alpha: f32,
+    a: &[&[f32]],
+    lda: Int,
+    b: &[&[f32]],
+    ldb: Int,
+    beta: f32,
+    c: &mut [&mut [f32]],
+    ldc: Int,
+) -> Result<(), BlasError> {
+    let routine = "sgemm_batch";
+    let (lena, lenb, lenc) = check_batch(routine, transa, transb, m, n, k, lda, ldb, ldc, false)?;
+    check_len(routine, 7, c.len(), a.len())?;
+    check_len(routine, 9, c.len(), b.len())?;
+    for ((a, b), c) in a.iter().zip(b).zip(c.iter()) {
+        check_len(routine, 7, lena, a.len())?;
+        check_len(routine, 9, lenb, b.len())?;
+        check_len(routine, 12, lenc, c.len())?;
+    }
+    let a: Vec<_> = a.iter().map(|a| a.as_ptr()).collect();
+    let b: Vec<_> = b.iter().map(|b| b.as_ptr()).collect();
+    let c: Vec<_> = c.iter_mut().map(|c| c.as_mut_ptr()).collect();
+    unsafe {
+        crate::sgemm_batch(
+            &[transa],
+            &[transb],
+            &[m],
+            &[n],
+            &[k],
+            &[alpha],
+            &a,
+            &[lda],
+            &b,
+            &[ldb],
+            &[beta],
+            &c,
+            &[ldc],
+            &[c.len() as Int],
+        )
+    };
+    Ok(())
+}
+
+#[inline]
+pub fn sgemm_batch_strided(
+    transa: u8,
+    transb: u8,
+    m: Int,
+    n: Int,
+    k: Int,
+    alpha: f32,
+    a: &[f32],
+    lda: Int,
+    stridea: Int,
+    b: &[f32],
+    ldb: Int,
+    strideb: Int,
+    beta: f32,
+    c: &mut [f32],
+    ldc: Int,
+    stridec: Int,
+    batch_size: Int,
+) -> Result<(), BlasError> {
+    let routine = "sgemm_batch_strided";
+    let (lena, lenb, lenc) = check_batch(routine, transa, transb, m, n, k, lda, ldb, ldc, true)?;
+    check_dimension(routine, 9, stridea)?;
+    check_dimension(routine, 12, strideb)?;
+    check_dimension(routine, 16, stridec)?;
+    check_dimension(routine, 17, batch_size)?;
+    if batch_size > 1 {
+        check_stride(routine, 16, stridec, lenc)?;
+    }
+    check_len(routine, 7, batch_len(lena, stridea, batch_size), a.len())?;
+    check_len(routine, 10, batch_len(lenb, strideb, batch_size), b.len())?;
+    check_len(routine, 14, batch_len(lenc, stridec, batch_size), c.len())?;
+    unsafe {
+        crate::sgemm_batch_strided(
+            transa, transb, m, n, k, alpha, a, lda, stridea, b, ldb, strideb, beta, c, ldc,
+            stridec, batch_size,
+        )
+    };
+    Ok(())
+}
+
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn dgemm_batch(
+    transa: u8,
+    transb: u8,
+    m: Int,
+    n: Int,
+    k: Int,
+    alpha: f64,
+    a: &[&[f64]],
+    lda: Int,
+    b: &[&[f64]],
+    ldb: Int,
+    beta: f64,
+    c: &mut [&mut [f64]],
+    ldc: Int,
+) -> Result<(), BlasError> {
+    let routine = "dgemm_batch";
+    let (lena, lenb, lenc) = check_batch(routine, transa, transb, m, n, k, lda, ldb, ldc, false)?;
+    check_len(routine, 7, c.len(), a.len())?;
+    check_len(routine, 9, c.len(), b.len())?;
+    for ((a, b), c) in a.iter().zip(b).zip(c.iter()) {
+        check_len(routine, 7, lena, a.len())?;
+        check_len(routine, 9, lenb, b.len())?;
+        check_len(routine, 12, lenc, c.len())?;
+    }
+    let a: Vec<_> = a.iter().map(|a| a.as_ptr()).collect();
+    let b: Vec<_> = b.iter().map(|b| b.as_ptr()).collect();
+    let c: Vec<_> = c.iter_mut().map(|c| c.as_mut_ptr()).collect();
+    unsafe {
+        crate::dgemm_batch(
+            &[transa],
+            &[transb],
+            &[m],
+            &[n],
+            &[k],
+            &[alpha],
+            &a,
+            &[lda],
+            &b,
+            &[ldb],
+            &[beta],
+            &c,
+            &[ldc],
+            &[c.len() as Int],
+        )
+    };
+    Ok(())
+}
+
+#[inline]
+pub fn dgemm_batch_strided(
+    transa: u8,
+    transb: u8,
+    m: Int,
+    n: Int,
+    k: Int,
+    alpha: f64,
+    a: &[f64],
+    lda: Int,
+    stridea: Int,
+    b: &[f64],
+    ldb: Int,
+    strideb: Int,
+    beta: f64,
+    c: &mut [f64],
+    ldc: Int,
+    stridec: Int,
+    batch_size: Int,
+) -> Result<(), BlasError> {
+    let routine = "dgemm_batch_strided";
+    let (lena, lenb, lenc) = check_batch(routine, transa, transb, m, n, k, lda, ldb, ldc, true)?;
+    check_dimension(routine, 9, stridea)?;
+    check_dimension(routine, 12, strideb)?;
+    check_dimension(routine, 16, stridec)?;
+    check_dimension(routine, 17, batch_size)?;
+    if batch_size > 1 {
+        check_stride(routine, 16, stridec, lenc)?;
+    }
+    check_len(routine, 7, batch_len(lena, stridea, batch_size), a.len())?;
+    check_len(routine, 10, batch_len(lenb, strideb, batch_size), b.len())?;
+    check_len(routine, 14, batch_len(lenc, stridec, batch_size), c.len())?;
+    unsafe {
+        crate::dgemm_batch_strided(
+            transa, transb, m, n, k, alpha, a, lda, stridea, b, ldb, strideb, beta, c, ldc,
+            stridec, batch_size,
+        )
+    };
+    Ok(())
+}
+
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn cgemm_batch(
+    transa: u8,
+    transb: u8,
+    m: Int,
+    n: Int,
+    k: Int,
+    alpha: c32,
+    a: &[&[c32]],
+    lda: Int,
+    b: &[&[c32]],
+    ldb: Int,
+    beta: c32,
+    c: &mut [&mut [c32]],
+    ldc: Int,
+) -> Result<(), BlasError> {
+    let routine = "cgemm_batch";
+    let (lena, lenb, lenc) = check_batch(routine, transa, transb, m, n, k, lda, ldb, ldc, false)?;
+    check_len(routine, 7, c.len(), a.len())?;
+    check_len(routine, 9, c.len(), b.len())?;
+    for ((a, b), c) in a.iter().zip(b).zip(c.iter()) {
+        check_len(routine, 7, lena, a.len())?;
+        check_len(routine, 9, lenb, b.len())?;
+        check_len(routine, 12, lenc, c.len())?;
+    }
+    let a: Vec<_> = a.iter().map(|a| a.as_ptr()).collect();
+    let b: Vec<_> = b.iter().map(|b| b.as_ptr()).collect();
+    let c: Vec<_> = c.iter_mut().map(|c| c.as_mut_ptr()).collect();
+    unsafe {
+        crate::cgemm_batch(
+            &[transa],
+            &[transb],
+            &[m],
+            &[n],
+            &[k],
+            &[alpha],
+            &a,
+            &[lda],
+            &b,
+            &[ldb],
+            &[beta],
+            &c,
+            &[ldc],
+            &[c.len() as Int],
+        )
+    };
+    Ok(())
+}
+
+#[inline]
+pub fn cgemm_batch_strided(
+    transa: u8,
+    transb: u8,
+    m: Int,
+    n: Int,
+    k: Int,
+    alpha: c32,
+    a: &[c32],
+    lda: Int,
+    stridea: Int,
+    b: &[c32],
+    ldb: Int,
+    strideb: Int,
+    beta: c32,
+    c: &mut [c32],
+    ldc: Int,
+    stridec: Int,
+    batch_size: Int,
+) -> Result<(), BlasError> {
+    let routine = "cgemm_batch_strided";
+    let (lena, lenb, lenc) = check_batch(routine, transa, transb, m, n, k, lda, ldb, ldc, true)?;
+    check_dimension(routine, 9, stridea)?;
+    check_dimension(routine, 12, strideb)?;
+    check_dimension(routine, 16, stridec)?;
+    check_dimension(routine, 17, batch_size)?;
+    if batch_size > 1 {
+        check_stride(routine, 16, stridec, lenc)?;
+    }
+    check_len(routine, 7, batch_len(lena, stridea, batch_size), a.len())?;
+    check_len(routine, 10, batch_len(lenb, strideb, batch_size), b.len())?;
+    check_len(routine, 14, batch_len(lenc, stridec, batch_size), c.len())?;
+    unsafe {
+        crate::cgemm_batch_strided(
+            transa, transb, m, n, k, alpha, a, lda, stridea, b, ldb, strideb, beta, c, ldc,
+            stridec, batch_size,
+        )
+    };
+    Ok(())
+}
+
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn zgemm_batch(
+    transa: u8,
+    transb: u8,
+    m: Int,
+    n: Int,
+    k: Int,
+    alpha: c64,
+    a: &[&[c64]],
+    lda: Int,
+    b: &[&[c64]],
+    ldb: Int,
+    beta: c64,
+    c: &mut [&mut [c64]],
+    ldc: Int,
+) -> Result<(), BlasError> {
+    let routine = "zgemm_batch";
+    let (lena, lenb, lenc) = check_batch(routine, transa, transb, m, n, k, lda, ldb, ldc, false)?;
+    check_len(routine, 7, c.len(), a.len())?;
+    check_len(routine, 9, c.len(), b.len())?;
+    for ((a, b), c) in a.iter().zip(b).zip(c.iter()) {
+        check_len(routine, 7, lena, a.len())?;
+        check_len(routine, 9, lenb, b.len())?;
+        check_len(routine, 12, lenc, c.len())?;
+    }
+    let a: Vec<_> = a.iter().map(|a| a.as_ptr()).collect();
+    let b: Vec<_> = b.iter().map(|b| b.as_ptr()).collect();
+    let c: Vec<_> = c.iter_mut().map(|c| c.as_mut_ptr()).collect();
+    unsafe {
+        crate::zgemm_batch(
+            &[transa],
+            &[transb],
+            &[m],
+            &[n],
+            &[k],
+            &[alpha],
+            &a,
+            &[lda],
+            &b,
+            &[ldb],
+            &[beta],
+            &c,
+            &[ldc],
+            &[c.len() as Int],
+        )
+    };
+    Ok(())
+}
+
+#[inline]
+pub fn zgemm_batch_strided(
+    transa: u8,
+    transb: u8,
+    m: Int,
+    n: Int,
+    k: Int,
+    alpha: c64,
+    a: &[c64],
+    lda: Int,
+    stridea: Int,
+    b: &[c64],
+    ldb: Int,
+    strideb: Int,
+    beta: c64,
+    c: &mut [c64],
+    ldc: Int,
+    stridec: Int,
+    batch_size: Int,
+) -> Result<(), BlasError> {
+    let routine = "zgemm_batch_strided";
+    let (lena, lenb, lenc) = check_batch(routine, transa, transb, m, n, k, lda, ldb, ldc, true)?;
+    check_dimension(routine, 9, stridea)?;
+    check_dimension(routine, 12, strideb)?;
+    check_dimension(routine, 16, stridec)?;
+    check_dimension(routine, 17, batch_size)?;
+    if batch_size > 1 {
+        check_stride(routine, 16, stridec, lenc)?;
+    }
+    check_len(routine, 7, batch_len(lena, stridea, batch_size), a.len())?;
+    check_len(routine, 10, batch_len(lenb, strideb, batch_size), b.len())?;
+    check_len(routine, 14, batch_len(lenc, stridec, batch_size), c.len())?;
+    unsafe {
+        crate::zgemm_batch_strided(
+            transa, transb, m, n, k, alpha, a, lda, stridea, b, ldb, strideb, beta, c, ldc,
+            stridec, batch_size,
+        )
+    };
+    Ok(())
+}
+
+#[cfg(all(
+    feature = "half",
+    any(
+        feature = "alloc",
+        all(feature = "extension-sbgemm", not(feature = "reference"))
+    ),
+))]
+#[inline]
+pub fn sbgemm(
+    transa: u8,
+    transb: u8,
+    m: Int,
+    n: Int,
+    k: Int,
+    alpha: f32,
+    a: &[bf16],
+    lda: Int,
+    b: &[bf16],
+    ldb: Int,
+    beta: f32,
+    c: &mut [f32],
+    ldc: Int,
+) -> Result<(), BlasError> {
+    let routine = "sbgemm";
+    let (lena, lenb, lenc) = check_batch(routine, transa, transb, m, n, k, lda, ldb, ldc, false)?;
+    check_len(routine, 7, lena, a.len())?;
+    check_len(routine, 9, lenb, b.len())?;
+    check_len(routine, 12, lenc, c.len())?;
+    unsafe { crate::sbgemm(transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc) };
+    Ok(())
+}
+
+#[cfg(all(
+    feature = "half",
+    any(
+        feature = "alloc",
+        all(feature = "extension-shgemm", not(feature = "reference"))
+    ),
+))]
+#[inline]
+pub fn shgemm(
+    transa: u8,
+    transb: u8,
+    m: Int,
+    n: Int,
+    k: Int,
+    alpha: f32,
+    a: &[f16],
+    lda: Int,
+    b: &[f16],
+    ldb: Int,
+    beta: f32,
+    c: &mut [f32],
+    ldc: Int,
+) -> Result<(), BlasError> {
+    let routine = "shgemm";
+    let (lena, lenb, lenc) = check_batch(routine, transa, transb, m, n, k, lda, ldb, ldc, false)?;
+    check_len(routine, 7, lena, a.len())?;
+    check_len(routine, 9, lenb, b.len())?;
+    check_len(routine, 12, lenc, c.len())?;
+    unsafe { crate::shgemm(transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc) };
+    Ok(())
+}
+
+#[cfg(all(
+    feature = "half",
+    any(
+        feature = "alloc",
+        all(feature = "extension-gemm-bf16", not(feature = "reference"))
+    ),
+))]
+#[inline]
+pub fn gemm_bf16bf16f32(
+    transa: u8,
+    transb: u8,
+    m: Int,
+    n: Int,
+    k: Int,
+    alpha: f32,
+    a: &[bf16],
+    lda: Int,
+    b: &[bf16],
+    ldb: Int,
+    beta: f32,
+    c: &mut [f32],
+    ldc: Int,
+) -> Result<(), BlasError> {
+    let routine = "gemm_bf16bf16f32";
+    let (lena, lenb, lenc) = check_batch(routine, transa, transb, m, n, k, lda, ldb, ldc, false)?;
+    check_len(routine, 7, lena, a.len())?;
+    check_len(routine, 9, lenb, b.len())?;
+    check_len(routine, 12, lenc, c.len())?;
+    unsafe {
+        crate::gemm_bf16bf16f32(transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+    };
+    Ok(())
+}
+
+/// Check the arguments of `gemm`, which are shared by the matrices of a batch, and return the
+/// numbers of elements spanned by `A`, `B`, and `C`.
+#[inline]
+fn check_batch(
+    routine: &'static str,
+    transa: u8,
+    transb: u8,
+    m: Int,
+    n: Int,
+    k: Int,
+    lda: Int,
+    ldb: Int,
+    ldc: Int,
+    strided: bool,
+) -> Result<(usize, usize, usize), BlasError> {
+    check_flag(routine, 1, transa, TRANS)?;
+    check_flag(routine, 2, transb, TRANS)?;
+    check_dimension(routine, 3, m)?;
+    check_dimension(routine, 4, n)?;
+    check_dimension(routine, 5, k)?;
+    let (nrowa, ncola) = if no_trans(transa) { (m, k) } else { (k, m) };
+    let (nrowb, ncolb) = if no_trans(transb) { (k, n) } else { (n, k) };
+    let (ldb_param, ldc_param) = if strided { (11, 15) } else { (10, 13) };
+    check_leading_dimension(routine, 8, lda, nrowa.max(1))?;
+    check_leading_dimension(routine, ldb_param, ldb, nrowb.max(1))?;
+    check_leading_dimension(routine, ldc_param, ldc, m.max(1))?;
+    Ok((
+        matrix_len(nrowa, ncola, lda),
+        matrix_len(nrowb, ncolb, ldb),
+        matrix_len(m, n, ldc),
+    ))
+}
+
+/// Check that matrices of `len` elements that are `stride` elements apart do not overlap.
+///
+/// `stride` is nonnegative.
+#[inline]
+fn check_stride(
+    routine: &'static str,
+    param: usize,
+    stride: Int,
+    len: usize,
+) -> Result<(), BlasError> {
+    if (stride as usize) < len {
+        return Err(BlasError::InvalidArgument {
+            routine,
+            param,
+            reason: Reason::LeadingDimension {
+                actual: stride,
+                minimum: Int::try_from(len).unwrap_or(Int::MAX),
+            },
+        });
+    }
+    Ok(())
+}
+
+/// Return the number of elements spanned by `batch_size` matrices of `len` elements that are
+/// `stride` elements apart, or `usize::MAX` if the number overflows.
+///
+/// `stride` is nonnegative.
+#[inline]
+fn batch_len(len: usize, stride: Int, batch_size: Int) -> usize {
+    if len == 0 || batch_size <= 0 {
+        return 0;
+    }
+    (batch_size as usize - 1)
+        .checked_mul(stride as usize)
+        .and_then(|offset| offset.checked_add(len))
+        .unwrap_or(usize::MAX)
+}
@@ -0,0 +1,450 @@
+use super::{check_len, check_vector, traversed, BlasError};
+use crate::{c32, c64, Int};
+
+#[inline]
+pub fn srotg(a: &mut f32, b: &mut f32, c: &mut f32, s: &mut f32) {
+    unsafe { crate::srotg(a, b, c, s) }
+}
+
+#[inline]
+pub fn srotmg(
+    d1: &mut f32,
+    d2: &mut f32,
+    x1: &mut f32,
+    y1: f32,
+    param: &mut [f32],
+) -> Result<(), BlasError> {
+    check_len("srotmg", 5, 5, param.len())?;
+    unsafe { crate::srotmg(d1, d2, x1, y1, param) };
+    Ok(())
+}
+
+#[inline]
+pub fn srot(
+    n: Int,
+    x: &mut [f32],
+    incx: Int,
+    y: &mut [f32],
+    incy: Int,
+    c: f32,
+    s: f32,
+) -> Result<(), BlasError> {
+    check_vector("srot", 2, n, incx, x.len())?;
+    check_vector("srot", 4, n, incy, y.len())?;
+    unsafe { crate::srot(n, x, incx, y, incy, c, s) };
+    Ok(())
+}
+
+#[inline]
+pub fn srotm(
+    n: Int,
+    x: &mut [f32],
+    incx: Int,
+    y: &mut [f32],
+    incy: Int,
+    param: &[f32],
+) -> Result<(), BlasError> {
+    check_vector("srotm", 2, n, incx, x.len())?;
+    check_vector("srotm", 4, n, incy, y.len())?;
+    check_len("srotm", 6, 5, param.len())?;
+    unsafe { crate::srotm(n, x, incx, y, incy, param) };
+    Ok(())
+}
+
+#[inline]
+pub fn sswap(n: Int, x: &mut [f32], incx: Int, y: &mut [f32], incy: Int) -> Result<(), BlasError> {
+    check_vector("sswap", 2, n, incx, x.len())?;
+    check_vector("sswap", 4, n, incy, y.len())?;
+    unsafe { crate::sswap(n, x, incx, y, incy) };
+    Ok(())
+}
+
+#[inline]
+pub fn sscal(n: Int, a: f32, x: &mut [f32], incx: Int) -> Result<(), BlasError> {
+    check_vector("sscal", 3, n, incx, x.len())?;
+    unsafe { crate::sscal(n, a, x, incx.saturating_abs()) };
+    Ok(())
+}
+
+#[inline]
+pub fn scopy(n: Int, x: &[f32], incx: Int, y: &mut [f32], incy: Int) -> Result<(), BlasError> {
+    check_vector("scopy", 2, n, incx, x.len())?;
+    check_vector("scopy", 4, n, incy, y.len())?;
+    unsafe { crate::scopy(n, x, incx, y, incy) };
+    Ok(())
+}
+
+#[inline]
+pub fn saxpy(
+    n: Int,
+    alpha: f32,
+    x: &[f32],
+    incx: Int,
+    y: &mut [f32],
+    incy: Int,
+) -> Result<(), BlasError> {
+    check_vector("saxpy", 3, n, incx, x.len())?;
+    check_vector("saxpy", 5, n, incy, y.len())?;
+    unsafe { crate::saxpy(n, alpha, x, incx, y, incy) };
+    Ok(())
+}
+
+#[inline]
+pub fn sdot(n: Int, x: &[f32], incx: Int, y: &[f32], incy: Int) -> Result<f32, BlasError> {
+    check_vector("sdot", 2, n, incx, x.len())?;
+    check_vector("sdot", 4, n, incy, y.len())?;
+    Ok(unsafe { crate::sdot(n, x, incx, y, incy) })
+}
+
+#[inline]
+pub fn sdsdot(
+    n: Int,
+    sb: &[f32],
+    x: &[f32],
+    incx: Int,
+    y: &[f32],
+    incy: Int,
+) -> Result<f32, BlasError> {
+    check_len("sdsdot", 2, 1, sb.len())?;
+    check_vector("sdsdot", 3, n, incx, x.len())?;
+    check_vector("sdsdot", 5, n, incy, y.len())?;
+    Ok(unsafe { crate::sdsdot(n, sb, x, incx, y, incy) })
+}
+
+#[inline]
+pub fn snrm2(n: Int, x: &[f32], incx: Int) -> Result<f32, BlasError> {
+    check_vector("snrm2", 2, n, incx, x.len())?;
+    Ok(unsafe { crate::snrm2(n, x, incx.saturating_abs()) })
+}
+
+#[inline]
+pub fn scnrm2(n: Int, x: &[c32], incx: Int) -> Result<f32, BlasError> {
+    check_vector("scnrm2", 2, n, incx, x.len())?;
+    Ok(unsafe { crate::scnrm2(n, x, incx.saturating_abs()) })
+}
+
+#[inline]
+pub fn sasum(n: Int, x: &[f32], incx: Int) -> Result<f32, BlasError> {
+    check_vector("sasum", 2, n, incx, x.len())?;
+    Ok(unsafe { crate::sasum(n, x, incx.saturating_abs()) })
+}
+
+#[inline]
+pub fn isamax(n: Int, x: &[f32], incx: Int) -> Result<usize, BlasError> {
+    check_vector("isamax", 2, n, incx, x.len())?;
+    let position = unsafe { crate::isamax(n, x, incx.saturating_abs()) };
+    Ok(traversed(position, n, incx))
+}
+
+#[inline]
+pub fn drotg(a: &mut f64, b: &mut f64, c: &mut f64, s: &mut f64) {
+    unsafe { crate::drotg(a, b, c, s) }
+}
+
+#[inline]
+pub fn drotmg(
+    d1: &mut f64,
+    d2: &mut f64,
+    x1: &mut f64,
+    y1: f64,
+    param: &mut [f64],
+) -> Result<(), BlasError> {
+    check_len("drotmg", 5, 5, param.len())?;
+    unsafe { crate::drotmg(d1, d2, x1, y1, param) };
+    Ok(())
+}
+
+#[inline]
+pub fn drot(
+    n: Int,
+    x: &mut [f64],
+    incx: Int,
+    y: &mut [f64],
+    incy: Int,
+    c: f64,
+    s: f64,
+) -> Result<(), BlasError> {
+    check_vector("drot", 2, n, incx, x.len())?;
+    check_vector("drot", 4, n, incy, y.len())?;
+    unsafe { crate::drot(n, x, incx, y, incy, c, s) };
+    Ok(())
+}
+
+#[inline]
+pub fn drotm(
+    n: Int,
+    x: &mut [f64],
+    incx: Int,
+    y: &mut [f64],
+    incy: Int,
+    param: &[f64],
+) -> Result<(), BlasError> {
+    check_vector("drotm", 2, n, incx, x.len())?;
+    check_vector("drotm", 4, n, incy, y.len())?;
+    check_len("drotm", 6, 5, param.len())?;
+    unsafe { crate::drotm(n, x, incx, y, incy, param) };
+    Ok(())
+}
+
+#[inline]
+pub fn dswap(n: Int, x: &mut [f64], incx: Int, y: &mut [f64], incy: Int) -> Result<(), BlasError> {
+    check_vector("dswap", 2, n, incx, x.len())?;
+    check_vector("dswap", 4, n, incy, y.len())?;
+    unsafe { crate::dswap(n, x, incx, y, incy) };
+    Ok(())
+}
+
+#[inline]
+pub fn dscal(n: Int, a: f64, x: &mut [f64], incx: Int) -> Result<(), BlasError> {
+    check_vector("dscal", 3, n, incx, x.len())?;
+    unsafe { crate::dscal(n, a, x, incx.saturating_abs()) };
+    Ok(())
+}
+
+#[inline]
+pub fn dcopy(n: Int, x: &[f64], incx: Int, y: &mut [f64], incy: Int) -> Result<(), BlasError> {
+    check_vector("dcopy", 2, n, incx, x.len())?;
+    check_vector("dcopy", 4, n, incy, y.len())?;
+    unsafe { crate::dcopy(n, x, incx, y, incy) };
+    Ok(())
+}
+
+#[inline]
+pub fn daxpy(
+    n: Int,
+    alpha: f64,
+    x: &[f64],
+    incx: Int,
+    y: &mut [f64],
+    incy: Int,
+) -> Result<(), BlasError> {
+    check_vector("daxpy", 3, n, incx, x.len())?;
+    check_vector("daxpy", 5, n, incy, y.len())?;
+    unsafe { crate::daxpy(n, alpha, x, incx, y, incy) };
+    Ok(())
+}
+
+#[inline]
+pub fn ddot(n: Int, x: &[f64], incx: Int, y: &[f64], incy: Int) -> Result<f64, BlasError> {
+    check_vector("ddot", 2, n, incx, x.len())?;
+    check_vector("ddot", 4, n, incy, y.len())?;
+    Ok(unsafe { crate::ddot(n, x, incx, y, incy) })
+}
+
+#[inline]
+pub fn dsdot(n: Int, x: &[f32], incx: Int, y: &[f32], incy: Int) -> Result<f64, BlasError> {
+    check_vector("dsdot", 2, n, incx, x.len())?;
+    check_vector("dsdot", 4, n, incy, y.len())?;
+    Ok(unsafe { crate::dsdot(n, x, incx, y, incy) })
+}
+
+#[inline]
+pub fn dnrm2(n: Int, x: &[f64], incx: Int) -> Result<f64, BlasError> {
+    check_vector("dnrm2", 2, n, incx, x.len())?;
+    Ok(unsafe { crate::dnrm2(n, x, incx.saturating_abs()) })
+}
+
+#[inline]
+pub fn dznrm2(n: Int, x: &[c64], incx: Int) -> Result<f64, BlasError> {
+    check_vector("dznrm2", 2, n, incx, x.len())?;
+    Ok(unsafe { crate::dznrm2(n, x, incx.saturating_abs()) })
+}
+
+#[inline]
+pub fn dasum(n: Int, x: &[f64], incx: Int) -> Result<f64, BlasError> {
+    check_vector("dasum", 2, n, incx, x.len())?;
+    Ok(unsafe { crate::dasum(n, x, incx.saturating_abs()) })
+}
+
+#[inline]
+pub fn idamax(n: Int, x: &[f64], incx: Int) -> Result<usize, BlasError> {
+    check_vector("idamax", 2, n, incx, x.len())?;
+    let position = unsafe { crate::idamax(n, x, incx.saturating_abs()) };
+    Ok(traversed(position, n, incx))
+}
+
+#[inline]
+pub fn crotg(a: &mut c32, b: c32, c: &mut f32, s: &mut c32) {
+    unsafe { crate::crotg(a, b, c, s) }
+}
+
+#[inline]
+pub fn csrot(
+    n: Int,
+    x: &mut [c32],
+    incx: Int,
+    y: &mut [c32],
+    incy: Int,
+    c: f32,
+    s: f32,
+) -> Result<(), BlasError> {
+    check_vector("csrot", 2, n, incx, x.len())?;
+    check_vector("csrot", 4, n, incy, y.len())?;
+    unsafe { crate::csrot(n, x, incx, y, incy, c, s) };
+    Ok(())
+}
+
+#[inline]
+pub fn cswap(n: Int, x: &mut [c32], incx: Int, y: &mut [c32], incy: Int) -> Result<(), BlasError> {
+    check_vector("cswap", 2, n, incx, x.len())?;
+    check_vector("cswap", 4, n, incy, y.len())?;
+    unsafe { crate::cswap(n, x, incx, y, incy) };
+    Ok(())
+}
+
+#[inline]
+pub fn cscal(n: Int, a: c32, x: &mut [c32], incx: Int) -> Result<(), BlasError> {
+    check_vector("cscal", 3, n, incx, x.len())?;
+    unsafe { crate::cscal(n, a, x, incx.saturating_abs()) };
+    Ok(())
+}
+
+#[inline]
+pub fn csscal(n: Int, a: f32, x: &mut [c32], incx: Int) -> Result<(), BlasError> {
+    check_vector("csscal", 3, n, incx, x.len())?;
+    unsafe { crate::csscal(n, a, x, incx.saturating_abs()) };
+    Ok(())
+}
+
+#[inline]
+pub fn ccopy(n: Int, x: &[c32], incx: Int, y: &mut [c32], incy: Int) -> Result<(), BlasError> {
+    check_vector("ccopy", 2, n, incx, x.len())?;
+    check_vector("ccopy", 4, n, incy, y.len())?;
+    unsafe { crate::ccopy(n, x, incx, y, incy) };
+    Ok(())
+}
+
+#[inline]
+pub fn caxpy(
+    n: Int,
+    alpha: c32,
+    x: &[c32],
+    incx: Int,
+    y: &mut [c32],
+    incy: Int,
+) -> Result<(), BlasError> {
+    check_vector("caxpy", 3, n, incx, x.len())?;
+    check_vector("caxpy", 5, n, incy, y.len())?;
+    unsafe { crate::caxpy(n, alpha, x, incx, y, incy) };
+    Ok(())
+}
+
+#[inline]
+pub fn cdotu(n: Int, x: &[c32], incx: Int, y: &[c32], incy: Int) -> Result<c32, BlasError> {
+    check_vector("cdotu", 2, n, incx, x.len())?;
+    check_vector("cdotu", 4, n, incy, y.len())?;
+    Ok(unsafe { crate::cdotu(n, x, incx, y, incy) })
+}
+
+#[inline]
+pub fn cdotc(n: Int, x: &[c32], incx: Int, y: &[c32], incy: Int) -> Result<c32, BlasError> {
+    check_vector("cdotc", 2, n, incx, x.len())?;
+    check_vector("cdotc", 4, n, incy, y.len())?;
+    Ok(unsafe { crate::cdotc(n, x, incx, y, incy) })
+}
+
+#[inline]
+pub fn scasum(n: Int, x: &[c32], incx: Int) -> Result<f32, BlasError> {
+    check_vector("scasum", 2, n, incx, x.len())?;
+    Ok(unsafe { crate::scasum(n, x, incx.saturating_abs()) })
+}
+
+#[inline]
+pub fn icamax(n: Int, x: &[c32], incx: Int) -> Result<usize, BlasError> {
+    check_vector("icamax", 2, n, incx, x.len())?;
+    let position = unsafe { crate::icamax(n, x, incx.saturating_abs()) };
+    Ok(traversed(position, n, incx))
+}
+
+#[inline]
+pub fn zrotg(a: &mut c64, b: c64, c: &mut f64, s: &mut c64) {
+    unsafe { crate::zrotg(a, b, c, s) }
+}
+
+#[inline]
+pub fn zdrot(
+    n: Int,
+    x: &mut [c64],
+    incx: Int,
+    y: &mut [c64],
+    incy: Int,
+    c: f64,
+    s: f64,
+) -> Result<(), BlasError> {
+    check_vector("zdrot", 2, n, incx, x.len())?;
+    check_vector("zdrot", 4, n, incy, y.len())?;
+    unsafe { crate::zdrot(n, x, incx, y, incy, c, s) };
+    Ok(())
+}
+
+#[inline]
+pub fn zswap(n: Int, x: &mut [c64], incx: Int, y: &mut [c64], incy: Int) -> Result<(), BlasError> {
+    check_vector("zswap", 2, n, incx, x.len())?;
+    check_vector("zswap", 4, n, incy, y.len())?;
+    unsafe { crate::zswap(n, x, incx, y, incy) };
+    Ok(())
+}
+
+#[inline]
+pub fn zscal(n: Int, a: c64, x: &mut [c64], incx: Int) -> Result<(), BlasError> {
+    check_vector("zscal", 3, n, incx, x.len())?;
+    unsafe { crate::zscal(n, a, x, incx.saturating_abs()) };
+    Ok(())
+}
+
+#[inline]
+pub fn zdscal(n: Int, a: f64, x: &mut [c64], incx: Int) -> Result<(), BlasError> {
+    check_vector("zdscal", 3, n, incx, x.len())?;
+    unsafe { crate::zdscal(n, a, x, incx.saturating_abs()) };
+    Ok(())
+}
+
+#[inline]
+pub fn zcopy(n: Int, x: &[c64], incx: Int, y: &mut [c64], incy: Int) -> Result<(), BlasError> {
+    check_vector("zcopy", 2, n, incx, x.len())?;
+    check_vector("zcopy", 4, n, incy, y.len())?;
+    unsafe { crate::zcopy(n, x, incx, y, incy) };
+    Ok(())
+}
+
+#[inline]
+pub fn zaxpy(
+    n: Int,
+    alpha: c64,
+    x: &[c64],
+    incx: Int,
+    y: &mut [c64],
+    incy: Int,
+) -> Result<(), BlasError> {
+    check_vector("zaxpy", 3, n, incx, x.len())?;
+    check_vector("zaxpy", 5, n, incy, y.len())?;
+    unsafe { crate::zaxpy(n, alpha, x, incx, y, incy) };
+    Ok(())
+}
+
+#[inline]
+pub fn zdotu(n: Int, x: &[c64], incx: Int, y: &[c64], incy: Int) -> Result<c64, BlasError> {
+    check_vector("zdotu", 2, n, incx, x.len())?;
+    check_vector("zdotu", 4, n, incy, y.len())?;
+    Ok(unsafe { crate::zdotu(n, x, incx, y, incy) })
+}
+
+#[inline]
+pub fn zdotc(n: Int, x: &[c64], incx: Int, y: &[c64], incy: Int) -> Result<c64, BlasError> {
+    check_vector("zdotc", 2, n, incx, x.len())?;
+    check_vector("zdotc", 4, n, incy, y.len())?;
+    Ok(unsafe { crate::zdotc(n, x, incx, y, incy) })
+}
+
+#[inline]
+pub fn dzasum(n: Int, x: &[c64], incx: Int) -> Result<f64, BlasError> {
+    check_vector("dzasum", 2, n, incx, x.len())?;
+    Ok(unsafe { crate::dzasum(n, x, incx.saturating_abs()) })
+}
+
+#[inline]
+pub fn izamax(n: Int, x: &[c64], incx: Int) -> Result<usize, BlasError> {
+    check_vector("izamax", 2, n, incx, x.len())?;
+    let position = unsafe { crate::izamax(n, x, incx.saturating_abs()) };
+    Ok(traversed(position, n, incx))
+}
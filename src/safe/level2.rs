@@ -0,0 +1,1663 @@
+use super::{
+    check_dimension, check_flag, check_increment, check_leading_dimension, check_len, matrix_len,
+    no_trans, packed_len, vector_len, BlasError, DIAG, TRANS, UPLO,
+};
+use crate::{c32, c64, Int};
+
+#[inline]
+pub fn sgemv(
+    trans: u8,
+    m: Int,
+    n: Int,
+    alpha: f32,
+    a: &[f32],
+    lda: Int,
+    x: &[f32],
+    incx: Int,
+    beta: f32,
+    y: &mut [f32],
+    incy: Int,
+) -> Result<(), BlasError> {
+    let routine = "sgemv";
+    check_flag(routine, 1, trans, TRANS)?;
+    check_dimension(routine, 2, m)?;
+    check_dimension(routine, 3, n)?;
+    check_leading_dimension(routine, 6, lda, m.max(1))?;
+    check_increment(routine, 8, incx)?;
+    check_increment(routine, 11, incy)?;
+    let (lenx, leny) = if no_trans(trans) { (n, m) } else { (m, n) };
+    check_len(routine, 5, matrix_len(m, n, lda), a.len())?;
+    check_len(routine, 7, vector_len(lenx, incx), x.len())?;
+    check_len(routine, 10, vector_len(leny, incy), y.len())?;
+    unsafe { crate::sgemv(trans, m, n, alpha, a, lda, x, incx, beta, y, incy) };
+    Ok(())
+}
+
+#[inline]
+pub fn sgbmv(
+    trans: u8,
+    m: Int,
+    n: Int,
+    kl: Int,
+    ku: Int,
+    alpha: f32,
+    a: &[f32],
+    lda: Int,
+    x: &[f32],
+    incx: Int,
+    beta: f32,
+    y: &mut [f32],
+    incy: Int,
+) -> Result<(), BlasError> {
+    let routine = "sgbmv";
+    check_flag(routine, 1, trans, TRANS)?;
+    check_dimension(routine, 2, m)?;
+    check_dimension(routine, 3, n)?;
+    check_dimension(routine, 4, kl)?;
+    check_dimension(routine, 5, ku)?;
+    check_leading_dimension(routine, 8, lda, kl.saturating_add(ku).saturating_add(1))?;
+    check_increment(routine, 10, incx)?;
+    check_increment(routine, 13, incy)?;
+    let (lenx, leny) = if no_trans(trans) { (n, m) } else { (m, n) };
+    check_len(
+        routine,
+        7,
+        matrix_len(kl.saturating_add(ku).saturating_add(1), n, lda),
+        a.len(),
+    )?;
+    check_len(routine, 9, vector_len(lenx, incx), x.len())?;
+    check_len(routine, 12, vector_len(leny, incy), y.len())?;
+    unsafe { crate::sgbmv(trans, m, n, kl, ku, alpha, a, lda, x, incx, beta, y, incy) };
+    Ok(())
+}
+
+#[inline]
+pub fn ssymv(
+    uplo: u8,
+    n: Int,
+    alpha: f32,
+    a: &[f32],
+    lda: Int,
+    x: &[f32],
+    incx: Int,
+    beta: f32,
+    y: &mut [f32],
+    incy: Int,
+) -> Result<(), BlasError> {
+    let routine = "ssymv";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_dimension(routine, 2, n)?;
+    check_leading_dimension(routine, 5, lda, n.max(1))?;
+    check_increment(routine, 7, incx)?;
+    check_increment(routine, 10, incy)?;
+    check_len(routine, 4, matrix_len(n, n, lda), a.len())?;
+    check_len(routine, 6, vector_len(n, incx), x.len())?;
+    check_len(routine, 9, vector_len(n, incy), y.len())?;
+    unsafe { crate::ssymv(uplo, n, alpha, a, lda, x, incx, beta, y, incy) };
+    Ok(())
+}
+
+#[inline]
+pub fn ssbmv(
+    uplo: u8,
+    n: Int,
+    k: Int,
+    alpha: f32,
+    a: &[f32],
+    lda: Int,
+    x: &[f32],
+    incx: Int,
+    beta: f32,
+    y: &mut [f32],
+    incy: Int,
+) -> Result<(), BlasError> {
+    let routine = "ssbmv";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_dimension(routine, 2, n)?;
+    check_dimension(routine, 3, k)?;
+    check_leading_dimension(routine, 6, lda, k.saturating_add(1))?;
+    check_increment(routine, 8, incx)?;
+    check_increment(routine, 11, incy)?;
+    check_len(routine, 5, matrix_len(k.saturating_add(1), n, lda), a.len())?;
+    check_len(routine, 7, vector_len(n, incx), x.len())?;
+    check_len(routine, 10, vector_len(n, incy), y.len())?;
+    unsafe { crate::ssbmv(uplo, n, k, alpha, a, lda, x, incx, beta, y, incy) };
+    Ok(())
+}
+
+#[inline]
+pub fn sspmv(
+    uplo: u8,
+    n: Int,
+    alpha: f32,
+    ap: &[f32],
+    x: &[f32],
+    incx: Int,
+    beta: f32,
+    y: &mut [f32],
+    incy: Int,
+) -> Result<(), BlasError> {
+    let routine = "sspmv";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_dimension(routine, 2, n)?;
+    check_increment(routine, 6, incx)?;
+    check_increment(routine, 9, incy)?;
+    check_len(routine, 4, packed_len(n), ap.len())?;
+    check_len(routine, 5, vector_len(n, incx), x.len())?;
+    check_len(routine, 8, vector_len(n, incy), y.len())?;
+    unsafe { crate::sspmv(uplo, n, alpha, ap, x, incx, beta, y, incy) };
+    Ok(())
+}
+
+#[inline]
+pub fn strmv(
+    uplo: u8,
+    transa: u8,
+    diag: u8,
+    n: Int,
+    a: &[f32],
+    lda: Int,
+    b: &mut [f32],
+    incx: Int,
+) -> Result<(), BlasError> {
+    let routine = "strmv";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_flag(routine, 2, transa, TRANS)?;
+    check_flag(routine, 3, diag, DIAG)?;
+    check_dimension(routine, 4, n)?;
+    check_leading_dimension(routine, 6, lda, n.max(1))?;
+    check_increment(routine, 8, incx)?;
+    check_len(routine, 5, matrix_len(n, n, lda), a.len())?;
+    check_len(routine, 7, vector_len(n, incx), b.len())?;
+    unsafe { crate::strmv(uplo, transa, diag, n, a, lda, b, incx) };
+    Ok(())
+}
+
+#[inline]
+pub fn stbmv(
+    uplo: u8,
+    trans: u8,
+    diag: u8,
+    n: Int,
+    k: Int,
+    a: &[f32],
+    lda: Int,
+    x: &mut [f32],
+    incx: Int,
+) -> Result<(), BlasError> {
+    let routine = "stbmv";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_flag(routine, 2, trans, TRANS)?;
+    check_flag(routine, 3, diag, DIAG)?;
+    check_dimension(routine, 4, n)?;
+    check_dimension(routine, 5, k)?;
+    check_leading_dimension(routine, 7, lda, k.saturating_add(1))?;
+    check_increment(routine, 9, incx)?;
+    check_len(routine, 6, matrix_len(k.saturating_add(1), n, lda), a.len())?;
+    check_len(routine, 8, vector_len(n, incx), x.len())?;
+    unsafe { crate::stbmv(uplo, trans, diag, n, k, a, lda, x, incx) };
+    Ok(())
+}
+
+#[inline]
+pub fn stpmv(
+    uplo: u8,
+    trans: u8,
+    diag: u8,
+    n: Int,
+    ap: &[f32],
+    x: &mut [f32],
+    incx: Int,
+) -> Result<(), BlasError> {
+    let routine = "stpmv";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_flag(routine, 2, trans, TRANS)?;
+    check_flag(routine, 3, diag, DIAG)?;
+    check_dimension(routine, 4, n)?;
+    check_increment(routine, 7, incx)?;
+    check_len(routine, 5, packed_len(n), ap.len())?;
+    check_len(routine, 6, vector_len(n, incx), x.len())?;
+    unsafe { crate::stpmv(uplo, trans, diag, n, ap, x, incx) };
+    Ok(())
+}
+
+#[inline]
+pub fn strsv(
+    uplo: u8,
+    trans: u8,
+    diag: u8,
+    n: Int,
+    a: &[f32],
+    lda: Int,
+    x: &mut [f32],
+    incx: Int,
+) -> Result<(), BlasError> {
+    let routine = "strsv";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_flag(routine, 2, trans, TRANS)?;
+    check_flag(routine, 3, diag, DIAG)?;
+    check_dimension(routine, 4, n)?;
+    check_leading_dimension(routine, 6, lda, n.max(1))?;
+    check_increment(routine, 8, incx)?;
+    check_len(routine, 5, matrix_len(n, n, lda), a.len())?;
+    check_len(routine, 7, vector_len(n, incx), x.len())?;
+    unsafe { crate::strsv(uplo, trans, diag, n, a, lda, x, incx) };
+    Ok(())
+}
+
+#[inline]
+pub fn stbsv(
+    uplo: u8,
+    trans: u8,
+    diag: u8,
+    n: Int,
+    k: Int,
+    a: &[f32],
+    lda: Int,
+    x: &mut [f32],
+    incx: Int,
+) -> Result<(), BlasError> {
+    let routine = "stbsv";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_flag(routine, 2, trans, TRANS)?;
+    check_flag(routine, 3, diag, DIAG)?;
+    check_dimension(routine, 4, n)?;
+    check_dimension(routine, 5, k)?;
+    check_leading_dimension(routine, 7, lda, k.saturating_add(1))?;
+    check_increment(routine, 9, incx)?;
+    check_len(routine, 6, matrix_len(k.saturating_add(1), n, lda), a.len())?;
+    check_len(routine, 8, vector_len(n, incx), x.len())?;
+    unsafe { crate::stbsv(uplo, trans, diag, n, k, a, lda, x, incx) };
+    Ok(())
+}
+
+#[inline]
+pub fn stpsv(
+    uplo: u8,
+    trans: u8,
+    diag: u8,
+    n: Int,
+    ap: &[f32],
+    x: &mut [f32],
+    incx: Int,
+) -> Result<(), BlasError> {
+    let routine = "stpsv";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_flag(routine, 2, trans, TRANS)?;
+    check_flag(routine, 3, diag, DIAG)?;
+    check_dimension(routine, 4, n)?;
+    check_increment(routine, 7, incx)?;
+    check_len(routine, 5, packed_len(n), ap.len())?;
+    check_len(routine, 6, vector_len(n, incx), x.len())?;
+    unsafe { crate::stpsv(uplo, trans, diag, n, ap, x, incx) };
+    Ok(())
+}
+
+#[inline]
+pub fn sger(
+    m: Int,
+    n: Int,
+    alpha: f32,
+    x: &[f32],
+    incx: Int,
+    y: &[f32],
+    incy: Int,
+    a: &mut [f32],
+    lda: Int,
+) -> Result<(), BlasError> {
+    let routine = "sger";
+    check_dimension(routine, 1, m)?;
+    check_dimension(routine, 2, n)?;
+    check_increment(routine, 5, incx)?;
+    check_increment(routine, 7, incy)?;
+    check_leading_dimension(routine, 9, lda, m.max(1))?;
+    check_len(routine, 4, vector_len(m, incx), x.len())?;
+    check_len(routine, 6, vector_len(n, incy), y.len())?;
+    check_len(routine, 8, matrix_len(m, n, lda), a.len())?;
+    unsafe { crate::sger(m, n, alpha, x, incx, y, incy, a, lda) };
+    Ok(())
+}
+
+#[inline]
+pub fn ssyr(
+    uplo: u8,
+    n: Int,
+    alpha: f32,
+    x: &[f32],
+    incx: Int,
+    a: &mut [f32],
+    lda: Int,
+) -> Result<(), BlasError> {
+    let routine = "ssyr";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_dimension(routine, 2, n)?;
+    check_increment(routine, 5, incx)?;
+    check_leading_dimension(routine, 7, lda, n.max(1))?;
+    check_len(routine, 4, vector_len(n, incx), x.len())?;
+    check_len(routine, 6, matrix_len(n, n, lda), a.len())?;
+    unsafe { crate::ssyr(uplo, n, alpha, x, incx, a, lda) };
+    Ok(())
+}
+
+#[inline]
+pub fn sspr(
+    uplo: u8,
+    n: Int,
+    alpha: f32,
+    x: &[f32],
+    incx: Int,
+    ap: &mut [f32],
+) -> Result<(), BlasError> {
+    let routine = "sspr";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_dimension(routine, 2, n)?;
+    check_increment(routine, 5, incx)?;
+    check_len(routine, 4, vector_len(n, incx), x.len())?;
+    check_len(routine, 6, packed_len(n), ap.len())?;
+    unsafe { crate::sspr(uplo, n, alpha, x, incx, ap) };
+    Ok(())
+}
+
+#[inline]
+pub fn ssyr2(
+    uplo: u8,
+    n: Int,
+    alpha: f32,
+    x: &[f32],
+    incx: Int,
+    y: &[f32],
+    incy: Int,
+    a: &mut [f32],
+    lda: Int,
+) -> Result<(), BlasError> {
+    let routine = "ssyr2";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_dimension(routine, 2, n)?;
+    check_increment(routine, 5, incx)?;
+    check_increment(routine, 7, incy)?;
+    check_leading_dimension(routine, 9, lda, n.max(1))?;
+    check_len(routine, 4, vector_len(n, incx), x.len())?;
+    check_len(routine, 6, vector_len(n, incy), y.len())?;
+    check_len(routine, 8, matrix_len(n, n, lda), a.len())?;
+    unsafe { crate::ssyr2(uplo, n, alpha, x, incx, y, incy, a, lda) };
+    Ok(())
+}
+
+#[inline]
+pub fn sspr2(
+    uplo: u8,
+    n: Int,
+    alpha: f32,
+    x: &[f32],
+    incx: Int,
+    y: &[f32],
+    incy: Int,
+    ap: &mut [f32],
+) -> Result<(), BlasError> {
+    let routine = "sspr2";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_dimension(routine, 2, n)?;
+    check_increment(routine, 5, incx)?;
+    check_increment(routine, 7, incy)?;
+    check_len(routine, 4, vector_len(n, incx), x.len())?;
+    check_len(routine, 6, vector_len(n, incy), y.len())?;
+    check_len(routine, 8, packed_len(n), ap.len())?;
+    unsafe { crate::sspr2(uplo, n, alpha, x, incx, y, incy, ap) };
+    Ok(())
+}
+
+#[inline]
+pub fn dgemv(
+    trans: u8,
+    m: Int,
+    n: Int,
+    alpha: f64,
+    a: &[f64],
+    lda: Int,
+    x: &[f64],
+    incx: Int,
+    beta: f64,
+    y: &mut [f64],
+    incy: Int,
+) -> Result<(), BlasError> {
+    let routine = "dgemv";
+    check_flag(routine, 1, trans, TRANS)?;
+    check_dimension(routine, 2, m)?;
+    check_dimension(routine, 3, n)?;
+    check_leading_dimension(routine, 6, lda, m.max(1))?;
+    check_increment(routine, 8, incx)?;
+    check_increment(routine, 11, incy)?;
+    let (lenx, leny) = if no_trans(trans) { (n, m) } else { (m, n) };
+    check_len(routine, 5, matrix_len(m, n, lda), a.len())?;
+    check_len(routine, 7, vector_len(lenx, incx), x.len())?;
+    check_len(routine, 10, vector_len(leny, incy), y.len())?;
+    unsafe { crate::dgemv(trans, m, n, alpha, a, lda, x, incx, beta, y, incy) };
+    Ok(())
+}
+
+#[inline]
+pub fn dgbmv(
+    trans: u8,
+    m: Int,
+    n: Int,
+    kl: Int,
+    ku: Int,
+    alpha: f64,
+    a: &[f64],
+    lda: Int,
+    x: &[f64],
+    incx: Int,
+    beta: f64,
+    y: &mut [f64],
+    incy: Int,
+) -> Result<(), BlasError> {
+    let routine = "dgbmv";
+    check_flag(routine, 1, trans, TRANS)?;
+    check_dimension(routine, 2, m)?;
+    check_dimension(routine, 3, n)?;
+    check_dimension(routine, 4, kl)?;
+    check_dimension(routine, 5, ku)?;
+    check_leading_dimension(routine, 8, lda, kl.saturating_add(ku).saturating_add(1))?;
+    check_increment(routine, 10, incx)?;
+    check_increment(routine, 13, incy)?;
+    let (lenx, leny) = if no_trans(trans) { (n, m) } else { (m, n) };
+    check_len(
+        routine,
+        7,
+        matrix_len(kl.saturating_add(ku).saturating_add(1), n, lda),
+        a.len(),
+    )?;
+    check_len(routine, 9, vector_len(lenx, incx), x.len())?;
+    check_len(routine, 12, vector_len(leny, incy), y.len())?;
+    unsafe { crate::dgbmv(trans, m, n, kl, ku, alpha, a, lda, x, incx, beta, y, incy) };
+    Ok(())
+}
+
+#[inline]
+pub fn dsymv(
+    uplo: u8,
+    n: Int,
+    alpha: f64,
+    a: &[f64],
+    lda: Int,
+    x: &[f64],
+    incx: Int,
+    beta: f64,
+    y: &mut [f64],
+    incy: Int,
+) -> Result<(), BlasError> {
+    let routine = "dsymv";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_dimension(routine, 2, n)?;
+    check_leading_dimension(routine, 5, lda, n.max(1))?;
+    check_increment(routine, 7, incx)?;
+    check_increment(routine, 10, incy)?;
+    check_len(routine, 4, matrix_len(n, n, lda), a.len())?;
+    check_len(routine, 6, vector_len(n, incx), x.len())?;
+    check_len(routine, 9, vector_len(n, incy), y.len())?;
+    unsafe { crate::dsymv(uplo, n, alpha, a, lda, x, incx, beta, y, incy) };
+    Ok(())
+}
+
+#[inline]
+pub fn dsbmv(
+    uplo: u8,
+    n: Int,
+    k: Int,
+    alpha: f64,
+    a: &[f64],
+    lda: Int,
+    x: &[f64],
+    incx: Int,
+    beta: f64,
+    y: &mut [f64],
+    incy: Int,
+) -> Result<(), BlasError> {
+    let routine = "dsbmv";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_dimension(routine, 2, n)?;
+    check_dimension(routine, 3, k)?;
+    check_leading_dimension(routine, 6, lda, k.saturating_add(1))?;
+    check_increment(routine, 8, incx)?;
+    check_increment(routine, 11, incy)?;
+    check_len(routine, 5, matrix_len(k.saturating_add(1), n, lda), a.len())?;
+    check_len(routine, 7, vector_len(n, incx), x.len())?;
+    check_len(routine, 10, vector_len(n, incy), y.len())?;
+    unsafe { crate::dsbmv(uplo, n, k, alpha, a, lda, x, incx, beta, y, incy) };
+    Ok(())
+}
+
+#[inline]
+pub fn dspmv(
+    uplo: u8,
+    n: Int,
+    alpha: f64,
+    ap: &[f64],
+    x: &[f64],
+    incx: Int,
+    beta: f64,
+    y: &mut [f64],
+    incy: Int,
+) -> Result<(), BlasError> {
+    let routine = "dspmv";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_dimension(routine, 2, n)?;
+    check_increment(routine, 6, incx)?;
+    check_increment(routine, 9, incy)?;
+    check_len(routine, 4, packed_len(n), ap.len())?;
+    check_len(routine, 5, vector_len(n, incx), x.len())?;
+    check_len(routine, 8, vector_len(n, incy), y.len())?;
+    unsafe { crate::dspmv(uplo, n, alpha, ap, x, incx, beta, y, incy) };
+    Ok(())
+}
+
+#[inline]
+pub fn dtrmv(
+    uplo: u8,
+    transa: u8,
+    diag: u8,
+    n: Int,
+    a: &[f64],
+    lda: Int,
+    b: &mut [f64],
+    incx: Int,
+) -> Result<(), BlasError> {
+    let routine = "dtrmv";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_flag(routine, 2, transa, TRANS)?;
+    check_flag(routine, 3, diag, DIAG)?;
+    check_dimension(routine, 4, n)?;
+    check_leading_dimension(routine, 6, lda, n.max(1))?;
+    check_increment(routine, 8, incx)?;
+    check_len(routine, 5, matrix_len(n, n, lda), a.len())?;
+    check_len(routine, 7, vector_len(n, incx), b.len())?;
+    unsafe { crate::dtrmv(uplo, transa, diag, n, a, lda, b, incx) };
+    Ok(())
+}
+
+#[inline]
+pub fn dtbmv(
+    uplo: u8,
+    trans: u8,
+    diag: u8,
+    n: Int,
+    k: Int,
+    a: &[f64],
+    lda: Int,
+    x: &mut [f64],
+    incx: Int,
+) -> Result<(), BlasError> {
+    let routine = "dtbmv";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_flag(routine, 2, trans, TRANS)?;
+    check_flag(routine, 3, diag, DIAG)?;
+    check_dimension(routine, 4, n)?;
+    check_dimension(routine, 5, k)?;
+    check_leading_dimension(routine, 7, lda, k.saturating_add(1))?;
+    check_increment(routine, 9, incx)?;
+    check_len(routine, 6, matrix_len(k.saturating_add(1), n, lda), a.len())?;
+    check_len(routine, 8, vector_len(n, incx), x.len())?;
+    unsafe { crate::dtbmv(uplo, trans, diag, n, k, a, lda, x, incx) };
+    Ok(())
+}
+
+#[inline]
+pub fn dtpmv(
+    uplo: u8,
+    trans: u8,
+    diag: u8,
+    n: Int,
+    ap: &[f64],
+    x: &mut [f64],
+    incx: Int,
+) -> Result<(), BlasError> {
+    let routine = "dtpmv";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_flag(routine, 2, trans, TRANS)?;
+    check_flag(routine, 3, diag, DIAG)?;
+    check_dimension(routine, 4, n)?;
+    check_increment(routine, 7, incx)?;
+    check_len(routine, 5, packed_len(n), ap.len())?;
+    check_len(routine, 6, vector_len(n, incx), x.len())?;
+    unsafe { crate::dtpmv(uplo, trans, diag, n, ap, x, incx) };
+    Ok(())
+}
+
+#[inline]
+pub fn dtrsv(
+    uplo: u8,
+    trans: u8,
+    diag: u8,
+    n: Int,
+    a: &[f64],
+    lda: Int,
+    x: &mut [f64],
+    incx: Int,
+) -> Result<(), BlasError> {
+    let routine = "dtrsv";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_flag(routine, 2, trans, TRANS)?;
+    check_flag(routine, 3, diag, DIAG)?;
+    check_dimension(routine, 4, n)?;
+    check_leading_dimension(routine, 6, lda, n.max(1))?;
+    check_increment(routine, 8, incx)?;
+    check_len(routine, 5, matrix_len(n, n, lda), a.len())?;
+    check_len(routine, 7, vector_len(n, incx), x.len())?;
+    unsafe { crate::dtrsv(uplo, trans, diag, n, a, lda, x, incx) };
+    Ok(())
+}
+
+#[inline]
+pub fn dtbsv(
+    uplo: u8,
+    trans: u8,
+    diag: u8,
+    n: Int,
+    k: Int,
+    a: &[f64],
+    lda: Int,
+    x: &mut [f64],
+    incx: Int,
+) -> Result<(), BlasError> {
+    let routine = "dtbsv";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_flag(routine, 2, trans, TRANS)?;
+    check_flag(routine, 3, diag, DIAG)?;
+    check_dimension(routine, 4, n)?;
+    check_dimension(routine, 5, k)?;
+    check_leading_dimension(routine, 7, lda, k.saturating_add(1))?;
+    check_increment(routine, 9, incx)?;
+    check_len(routine, 6, matrix_len(k.saturating_add(1), n, lda), a.len())?;
+    check_len(routine, 8, vector_len(n, incx), x.len())?;
+    unsafe { crate::dtbsv(uplo, trans, diag, n, k, a, lda, x, incx) };
+    Ok(())
+}
+
+#[inline]
+pub fn dtpsv(
+    uplo: u8,
+    trans: u8,
+    diag: u8,
+    n: Int,
+    ap: &[f64],
+    x: &mut [f64],
+    incx: Int,
+) -> Result<(), BlasError> {
+    let routine = "dtpsv";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_flag(routine, 2, trans, TRANS)?;
+    check_flag(routine, 3, diag, DIAG)?;
+    check_dimension(routine, 4, n)?;
+    check_increment(routine, 7, incx)?;
+    check_len(routine, 5, packed_len(n), ap.len())?;
+    check_len(routine, 6, vector_len(n, incx), x.len())?;
+    unsafe { crate::dtpsv(uplo, trans, diag, n, ap, x, incx) };
+    Ok(())
+}
+
+#[inline]
+pub fn dger(
+    m: Int,
+    n: Int,
+    alpha: f64,
+    x: &[f64],
+    incx: Int,
+    y: &[f64],
+    incy: Int,
+    a: &mut [f64],
+    lda: Int,
+) -> Result<(), BlasError> {
+    let routine = "dger";
+    check_dimension(routine, 1, m)?;
+    check_dimension(routine, 2, n)?;
+    check_increment(routine, 5, incx)?;
+    check_increment(routine, 7, incy)?;
+    check_leading_dimension(routine, 9, lda, m.max(1))?;
+    check_len(routine, 4, vector_len(m, incx), x.len())?;
+    check_len(routine, 6, vector_len(n, incy), y.len())?;
+    check_len(routine, 8, matrix_len(m, n, lda), a.len())?;
+    unsafe { crate::dger(m, n, alpha, x, incx, y, incy, a, lda) };
+    Ok(())
+}
+
+#[inline]
+pub fn dsyr(
+    uplo: u8,
+    n: Int,
+    alpha: f64,
+    x: &[f64],
+    incx: Int,
+    a: &mut [f64],
+    lda: Int,
+) -> Result<(), BlasError> {
+    let routine = "dsyr";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_dimension(routine, 2, n)?;
+    check_increment(routine, 5, incx)?;
+    check_leading_dimension(routine, 7, lda, n.max(1))?;
+    check_len(routine, 4, vector_len(n, incx), x.len())?;
+    check_len(routine, 6, matrix_len(n, n, lda), a.len())?;
+    unsafe { crate::dsyr(uplo, n, alpha, x, incx, a, lda) };
+    Ok(())
+}
+
+#[inline]
+pub fn dspr(
+    uplo: u8,
+    n: Int,
+    alpha: f64,
+    x: &[f64],
+    incx: Int,
+    ap: &mut [f64],
+) -> Result<(), BlasError> {
+    let routine = "dspr";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_dimension(routine, 2, n)?;
+    check_increment(routine, 5, incx)?;
+    check_len(routine, 4, vector_len(n, incx), x.len())?;
+    check_len(routine, 6, packed_len(n), ap.len())?;
+    unsafe { crate::dspr(uplo, n, alpha, x, incx, ap) };
+    Ok(())
+}
+
+#[inline]
+pub fn dsyr2(
+    uplo: u8,
+    n: Int,
+    alpha: f64,
+    x: &[f64],
+    incx: Int,
+    y: &[f64],
+    incy: Int,
+    a: &mut [f64],
+    lda: Int,
+) -> Result<(), BlasError> {
+    let routine = "dsyr2";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_dimension(routine, 2, n)?;
+    check_increment(routine, 5, incx)?;
+    check_increment(routine, 7, incy)?;
+    check_leading_dimension(routine, 9, lda, n.max(1))?;
+    check_len(routine, 4, vector_len(n, incx), x.len())?;
+    check_len(routine, 6, vector_len(n, incy), y.len())?;
+    check_len(routine, 8, matrix_len(n, n, lda), a.len())?;
+    unsafe { crate::dsyr2(uplo, n, alpha, x, incx, y, incy, a, lda) };
+    Ok(())
+}
+
+#[inline]
+pub fn dspr2(
+    uplo: u8,
+    n: Int,
+    alpha: f64,
+    x: &[f64],
+    incx: Int,
+    y: &[f64],
+    incy: Int,
+    ap: &mut [f64],
+) -> Result<(), BlasError> {
+    let routine = "dspr2";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_dimension(routine, 2, n)?;
+    check_increment(routine, 5, incx)?;
+    check_increment(routine, 7, incy)?;
+    check_len(routine, 4, vector_len(n, incx), x.len())?;
+    check_len(routine, 6, vector_len(n, incy), y.len())?;
+    check_len(routine, 8, packed_len(n), ap.len())?;
+    unsafe { crate::dspr2(uplo, n, alpha, x, incx, y, incy, ap) };
+    Ok(())
+}
+
+#[inline]
+pub fn cgemv(
+    trans: u8,
+    m: Int,
+    n: Int,
+    alpha: c32,
+    a: &[c32],
+    lda: Int,
+    x: &[c32],
+    incx: Int,
+    beta: c32,
+    y: &mut [c32],
+    incy: Int,
+) -> Result<(), BlasError> {
+    let routine = "cgemv";
+    check_flag(routine, 1, trans, TRANS)?;
+    check_dimension(routine, 2, m)?;
+    check_dimension(routine, 3, n)?;
+    check_leading_dimension(routine, 6, lda, m.max(1))?;
+    check_increment(routine, 8, incx)?;
+    check_increment(routine, 11, incy)?;
+    let (lenx, leny) = if no_trans(trans) { (n, m) } else { (m, n) };
+    check_len(routine, 5, matrix_len(m, n, lda), a.len())?;
+    check_len(routine, 7, vector_len(lenx, incx), x.len())?;
+    check_len(routine, 10, vector_len(leny, incy), y.len())?;
+    unsafe { crate::cgemv(trans, m, n, alpha, a, lda, x, incx, beta, y, incy) };
+    Ok(())
+}
+
+#[inline]
+pub fn cgbmv(
+    trans: u8,
+    m: Int,
+    n: Int,
+    kl: Int,
+    ku: Int,
+    alpha: c32,
+    a: &[c32],
+    lda: Int,
+    x: &[c32],
+    incx: Int,
+    beta: c32,
+    y: &mut [c32],
+    incy: Int,
+) -> Result<(), BlasError> {
+    let routine = "cgbmv";
+    check_flag(routine, 1, trans, TRANS)?;
+    check_dimension(routine, 2, m)?;
+    check_dimension(routine, 3, n)?;
+    check_dimension(routine, 4, kl)?;
+    check_dimension(routine, 5, ku)?;
+    check_leading_dimension(routine, 8, lda, kl.saturating_add(ku).saturating_add(1))?;
+    check_increment(routine, 10, incx)?;
+    check_increment(routine, 13, incy)?;
+    let (lenx, leny) = if no_trans(trans) { (n, m) } else { (m, n) };
+    check_len(
+        routine,
+        7,
+        matrix_len(kl.saturating_add(ku).saturating_add(1), n, lda),
+        a.len(),
+    )?;
+    check_len(routine, 9, vector_len(lenx, incx), x.len())?;
+    check_len(routine, 12, vector_len(leny, incy), y.len())?;
+    unsafe { crate::cgbmv(trans, m, n, kl, ku, alpha, a, lda, x, incx, beta, y, incy) };
+    Ok(())
+}
+
+#[inline]
+pub fn chemv(
+    uplo: u8,
+    n: Int,
+    alpha: c32,
+    a: &[c32],
+    lda: Int,
+    x: &[c32],
+    incx: Int,
+    beta: c32,
+    y: &mut [c32],
+    incy: Int,
+) -> Result<(), BlasError> {
+    let routine = "chemv";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_dimension(routine, 2, n)?;
+    check_leading_dimension(routine, 5, lda, n.max(1))?;
+    check_increment(routine, 7, incx)?;
+    check_increment(routine, 10, incy)?;
+    check_len(routine, 4, matrix_len(n, n, lda), a.len())?;
+    check_len(routine, 6, vector_len(n, incx), x.len())?;
+    check_len(routine, 9, vector_len(n, incy), y.len())?;
+    unsafe { crate::chemv(uplo, n, alpha, a, lda, x, incx, beta, y, incy) };
+    Ok(())
+}
+
+#[inline]
+pub fn chbmv(
+    uplo: u8,
+    n: Int,
+    k: Int,
+    alpha: c32,
+    a: &[c32],
+    lda: Int,
+    x: &[c32],
+    incx: Int,
+    beta: c32,
+    y: &mut [c32],
+    incy: Int,
+) -> Result<(), BlasError> {
+    let routine = "chbmv";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_dimension(routine, 2, n)?;
+    check_dimension(routine, 3, k)?;
+    check_leading_dimension(routine, 6, lda, k.saturating_add(1))?;
+    check_increment(routine, 8, incx)?;
+    check_increment(routine, 11, incy)?;
+    check_len(routine, 5, matrix_len(k.saturating_add(1), n, lda), a.len())?;
+    check_len(routine, 7, vector_len(n, incx), x.len())?;
+    check_len(routine, 10, vector_len(n, incy), y.len())?;
+    unsafe { crate::chbmv(uplo, n, k, alpha, a, lda, x, incx, beta, y, incy) };
+    Ok(())
+}
+
+#[inline]
+pub fn chpmv(
+    uplo: u8,
+    n: Int,
+    alpha: c32,
+    ap: &[c32],
+    x: &[c32],
+    incx: Int,
+    beta: c32,
+    y: &mut [c32],
+    incy: Int,
+) -> Result<(), BlasError> {
+    let routine = "chpmv";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_dimension(routine, 2, n)?;
+    check_increment(routine, 6, incx)?;
+    check_increment(routine, 9, incy)?;
+    check_len(routine, 4, packed_len(n), ap.len())?;
+    check_len(routine, 5, vector_len(n, incx), x.len())?;
+    check_len(routine, 8, vector_len(n, incy), y.len())?;
+    unsafe { crate::chpmv(uplo, n, alpha, ap, x, incx, beta, y, incy) };
+    Ok(())
+}
+
+#[inline]
+pub fn ctrmv(
+    uplo: u8,
+    transa: u8,
+    diag: u8,
+    n: Int,
+    a: &[c32],
+    lda: Int,
+    b: &mut [c32],
+    incx: Int,
+) -> Result<(), BlasError> {
+    let routine = "ctrmv";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_flag(routine, 2, transa, TRANS)?;
+    check_flag(routine, 3, diag, DIAG)?;
+    check_dimension(routine, 4, n)?;
+    check_leading_dimension(routine, 6, lda, n.max(1))?;
+    check_increment(routine, 8, incx)?;
+    check_len(routine, 5, matrix_len(n, n, lda), a.len())?;
+    check_len(routine, 7, vector_len(n, incx), b.len())?;
+    unsafe { crate::ctrmv(uplo, transa, diag, n, a, lda, b, incx) };
+    Ok(())
+}
+
+#[inline]
+pub fn ctbmv(
+    uplo: u8,
+    trans: u8,
+    diag: u8,
+    n: Int,
+    k: Int,
+    a: &[c32],
+    lda: Int,
+    x: &mut [c32],
+    incx: Int,
+) -> Result<(), BlasError> {
+    let routine = "ctbmv";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_flag(routine, 2, trans, TRANS)?;
+    check_flag(routine, 3, diag, DIAG)?;
+    check_dimension(routine, 4, n)?;
+    check_dimension(routine, 5, k)?;
+    check_leading_dimension(routine, 7, lda, k.saturating_add(1))?;
+    check_increment(routine, 9, incx)?;
+    check_len(routine, 6, matrix_len(k.saturating_add(1), n, lda), a.len())?;
+    check_len(routine, 8, vector_len(n, incx), x.len())?;
+    unsafe { crate::ctbmv(uplo, trans, diag, n, k, a, lda, x, incx) };
+    Ok(())
+}
+
+#[inline]
+pub fn ctpmv(
+    uplo: u8,
+    trans: u8,
+    diag: u8,
+    n: Int,
+    ap: &[c32],
+    x: &mut [c32],
+    incx: Int,
+) -> Result<(), BlasError> {
+    let routine = "ctpmv";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_flag(routine, 2, trans, TRANS)?;
+    check_flag(routine, 3, diag, DIAG)?;
+    check_dimension(routine, 4, n)?;
+    check_increment(routine, 7, incx)?;
+    check_len(routine, 5, packed_len(n), ap.len())?;
+    check_len(routine, 6, vector_len(n, incx), x.len())?;
+    unsafe { crate::ctpmv(uplo, trans, diag, n, ap, x, incx) };
+    Ok(())
+}
+
+#[inline]
+pub fn ctrsv(
+    uplo: u8,
+    trans: u8,
+    diag: u8,
+    n: Int,
+    a: &[c32],
+    lda: Int,
+    x: &mut [c32],
+    incx: Int,
+) -> Result<(), BlasError> {
+    let routine = "ctrsv";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_flag(routine, 2, trans, TRANS)?;
+    check_flag(routine, 3, diag, DIAG)?;
+    check_dimension(routine, 4, n)?;
+    check_leading_dimension(routine, 6, lda, n.max(1))?;
+    check_increment(routine, 8, incx)?;
+    check_len(routine, 5, matrix_len(n, n, lda), a.len())?;
+    check_len(routine, 7, vector_len(n, incx), x.len())?;
+    unsafe { crate::ctrsv(uplo, trans, diag, n, a, lda, x, incx) };
+    Ok(())
+}
+
+#[inline]
+pub fn ctbsv(
+    uplo: u8,
+    trans: u8,
+    diag: u8,
+    n: Int,
+    k: Int,
+    a: &[c32],
+    lda: Int,
+    x: &mut [c32],
+    incx: Int,
+) -> Result<(), BlasError> {
+    let routine = "ctbsv";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_flag(routine, 2, trans, TRANS)?;
+    check_flag(routine, 3, diag, DIAG)?;
+    check_dimension(routine, 4, n)?;
+    check_dimension(routine, 5, k)?;
+    check_leading_dimension(routine, 7, lda, k.saturating_add(1))?;
+    check_increment(routine, 9, incx)?;
+    check_len(routine, 6, matrix_len(k.saturating_add(1), n, lda), a.len())?;
+    check_len(routine, 8, vector_len(n, incx), x.len())?;
+    unsafe { crate::ctbsv(uplo, trans, diag, n, k, a, lda, x, incx) };
+    Ok(())
+}
+
+#[inline]
+pub fn ctpsv(
+    uplo: u8,
+    trans: u8,
+    diag: u8,
+    n: Int,
+    ap: &[c32],
+    x: &mut [c32],
+    incx: Int,
+) -> Result<(), BlasError> {
+    let routine = "ctpsv";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_flag(routine, 2, trans, TRANS)?;
+    check_flag(routine, 3, diag, DIAG)?;
+    check_dimension(routine, 4, n)?;
+    check_increment(routine, 7, incx)?;
+    check_len(routine, 5, packed_len(n), ap.len())?;
+    check_len(routine, 6, vector_len(n, incx), x.len())?;
+    unsafe { crate::ctpsv(uplo, trans, diag, n, ap, x, incx) };
+    Ok(())
+}
+
+#[inline]
+pub fn cgeru(
+    m: Int,
+    n: Int,
+    alpha: c32,
+    x: &[c32],
+    incx: Int,
+    y: &[c32],
+    incy: Int,
+    a: &mut [c32],
+    lda: Int,
+) -> Result<(), BlasError> {
+    let routine = "cgeru";
+    check_dimension(routine, 1, m)?;
+    check_dimension(routine, 2, n)?;
+    check_increment(routine, 5, incx)?;
+    check_increment(routine, 7, incy)?;
+    check_leading_dimension(routine, 9, lda, m.max(1))?;
+    check_len(routine, 4, vector_len(m, incx), x.len())?;
+    check_len(routine, 6, vector_len(n, incy), y.len())?;
+    check_len(routine, 8, matrix_len(m, n, lda), a.len())?;
+    unsafe { crate::cgeru(m, n, alpha, x, incx, y, incy, a, lda) };
+    Ok(())
+}
+
+#[inline]
+pub fn cgerc(
+    m: Int,
+    n: Int,
+    alpha: c32,
+    x: &[c32],
+    incx: Int,
+    y: &[c32],
+    incy: Int,
+    a: &mut [c32],
+    lda: Int,
+) -> Result<(), BlasError> {
+    let routine = "cgerc";
+    check_dimension(routine, 1, m)?;
+    check_dimension(routine, 2, n)?;
+    check_increment(routine, 5, incx)?;
+    check_increment(routine, 7, incy)?;
+    check_leading_dimension(routine, 9, lda, m.max(1))?;
+    check_len(routine, 4, vector_len(m, incx), x.len())?;
+    check_len(routine, 6, vector_len(n, incy), y.len())?;
+    check_len(routine, 8, matrix_len(m, n, lda), a.len())?;
+    unsafe { crate::cgerc(m, n, alpha, x, incx, y, incy, a, lda) };
+    Ok(())
+}
+
+#[inline]
+pub fn cher(
+    uplo: u8,
+    n: Int,
+    alpha: f32,
+    x: &[c32],
+    incx: Int,
+    a: &mut [c32],
+    lda: Int,
+) -> Result<(), BlasError> {
+    let routine = "cher";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_dimension(routine, 2, n)?;
+    check_increment(routine, 5, incx)?;
+    check_leading_dimension(routine, 7, lda, n.max(1))?;
+    check_len(routine, 4, vector_len(n, incx), x.len())?;
+    check_len(routine, 6, matrix_len(n, n, lda), a.len())?;
+    unsafe { crate::cher(uplo, n, alpha, x, incx, a, lda) };
+    Ok(())
+}
+
+#[inline]
+pub fn chpr(
+    uplo: u8,
+    n: Int,
+    alpha: f32,
+    x: &[c32],
+    incx: Int,
+    ap: &mut [c32],
+) -> Result<(), BlasError> {
+    let routine = "chpr";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_dimension(routine, 2, n)?;
+    check_increment(routine, 5, incx)?;
+    check_len(routine, 4, vector_len(n, incx), x.len())?;
+    check_len(routine, 6, packed_len(n), ap.len())?;
+    unsafe { crate::chpr(uplo, n, alpha, x, incx, ap) };
+    Ok(())
+}
+
+#[inline]
+pub fn chpr2(
+    uplo: u8,
+    n: Int,
+    alpha: c32,
+    x: &[c32],
+    incx: Int,
+    y: &[c32],
+    incy: Int,
+    ap: &mut [c32],
+) -> Result<(), BlasError> {
+    let routine = "chpr2";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_dimension(routine, 2, n)?;
+    check_increment(routine, 5, incx)?;
+    check_increment(routine, 7, incy)?;
+    check_len(routine, 4, vector_len(n, incx), x.len())?;
+    check_len(routine, 6, vector_len(n, incy), y.len())?;
+    check_len(routine, 8, packed_len(n), ap.len())?;
+    unsafe { crate::chpr2(uplo, n, alpha, x, incx, y, incy, ap) };
+    Ok(())
+}
+
+#[inline]
+pub fn cher2(
+    uplo: u8,
+    n: Int,
+    alpha: c32,
+    x: &[c32],
+    incx: Int,
+    y: &[c32],
+    incy: Int,
+    a: &mut [c32],
+    lda: Int,
+) -> Result<(), BlasError> {
+    let routine = "cher2";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_dimension(routine, 2, n)?;
+    check_increment(routine, 5, incx)?;
+    check_increment(routine, 7, incy)?;
+    check_leading_dimension(routine, 9, lda, n.max(1))?;
+    check_len(routine, 4, vector_len(n, incx), x.len())?;
+    check_len(routine, 6, vector_len(n, incy), y.len())?;
+    check_len(routine, 8, matrix_len(n, n, lda), a.len())?;
+    unsafe { crate::cher2(uplo, n, alpha, x, incx, y, incy, a, lda) };
+    Ok(())
+}
+
+#[inline]
+pub fn zgemv(
+    trans: u8,
+    m: Int,
+    n: Int,
+    alpha: c64,
+    a: &[c64],
+    lda: Int,
+    x: &[c64],
+    incx: Int,
+    beta: c64,
+    y: &mut [c64],
+    incy: Int,
+) -> Result<(), BlasError> {
+    let routine = "zgemv";
+    check_flag(routine, 1, trans, TRANS)?;
+    check_dimension(routine, 2, m)?;
+    check_dimension(routine, 3, n)?;
+    check_leading_dimension(routine, 6, lda, m.max(1))?;
+    check_increment(routine, 8, incx)?;
+    check_increment(routine, 11, incy)?;
+    let (lenx, leny) = if no_trans(trans) { (n, m) } else { (m, n) };
+    check_len(routine, 5, matrix_len(m, n, lda), a.len())?;
+    check_len(routine, 7, vector_len(lenx, incx), x.len())?;
+    check_len(routine, 10, vector_len(leny, incy), y.len())?;
+    unsafe { crate::zgemv(trans, m, n, alpha, a, lda, x, incx, beta, y, incy) };
+    Ok(())
+}
+
+#[inline]
+pub fn zgbmv(
+    trans: u8,
+    m: Int,
+    n: Int,
+    kl: Int,
+    ku: Int,
+    alpha: c64,
+    a: &[c64],
+    lda: Int,
+    x: &[c64],
+    incx: Int,
+    beta: c64,
+    y: &mut [c64],
+    incy: Int,
+) -> Result<(), BlasError> {
+    let routine = "zgbmv";
+    check_flag(routine, 1, trans, TRANS)?;
+    check_dimension(routine, 2, m)?;
+    check_dimension(routine, 3, n)?;
+    check_dimension(routine, 4, kl)?;
+    check_dimension(routine, 5, ku)?;
+    check_leading_dimension(routine, 8, lda, kl.saturating_add(ku).saturating_add(1))?;
+    check_increment(routine, 10, incx)?;
+    check_increment(routine, 13, incy)?;
+    let (lenx, leny) = if no_trans(trans) { (n, m) } else { (m, n) };
+    check_len(
+        routine,
+        7,
+        matrix_len(kl.saturating_add(ku).saturating_add(1), n, lda),
+        a.len(),
+    )?;
+    check_len(routine, 9, vector_len(lenx, incx), x.len())?;
+    check_len(routine, 12, vector_len(leny, incy), y.len())?;
+    unsafe { crate::zgbmv(trans, m, n, kl, ku, alpha, a, lda, x, incx, beta, y, incy) };
+    Ok(())
+}
+
+#[inline]
+pub fn zhemv(
+    uplo: u8,
+    n: Int,
+    alpha: c64,
+    a: &[c64],
+    lda: Int,
+    x: &[c64],
+    incx: Int,
+    beta: c64,
+    y: &mut [c64],
+    incy: Int,
+) -> Result<(), BlasError> {
+    let routine = "zhemv";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_dimension(routine, 2, n)?;
+    check_leading_dimension(routine, 5, lda, n.max(1))?;
+    check_increment(routine, 7, incx)?;
+    check_increment(routine, 10, incy)?;
+    check_len(routine, 4, matrix_len(n, n, lda), a.len())?;
+    check_len(routine, 6, vector_len(n, incx), x.len())?;
+    check_len(routine, 9, vector_len(n, incy), y.len())?;
+    unsafe { crate::zhemv(uplo, n, alpha, a, lda, x, incx, beta, y, incy) };
+    Ok(())
+}
+
+#[inline]
+pub fn zhbmv(
+    uplo: u8,
+    n: Int,
+    k: Int,
+    alpha: c64,
+    a: &[c64],
+    lda: Int,
+    x: &[c64],
+    incx: Int,
+    beta: c64,
+    y: &mut [c64],
+    incy: Int,
+) -> Result<(), BlasError> {
+    let routine = "zhbmv";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_dimension(routine, 2, n)?;
+    check_dimension(routine, 3, k)?;
+    check_leading_dimension(routine, 6, lda, k.saturating_add(1))?;
+    check_increment(routine, 8, incx)?;
+    check_increment(routine, 11, incy)?;
+    check_len(routine, 5, matrix_len(k.saturating_add(1), n, lda), a.len())?;
+    check_len(routine, 7, vector_len(n, incx), x.len())?;
+    check_len(routine, 10, vector_len(n, incy), y.len())?;
+    unsafe { crate::zhbmv(uplo, n, k, alpha, a, lda, x, incx, beta, y, incy) };
+    Ok(())
+}
+
+#[inline]
+pub fn zhpmv(
+    uplo: u8,
+    n: Int,
+    alpha: c64,
+    ap: &[c64],
+    x: &[c64],
+    incx: Int,
+    beta: c64,
+    y: &mut [c64],
+    incy: Int,
+) -> Result<(), BlasError> {
+    let routine = "zhpmv";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_dimension(routine, 2, n)?;
+    check_increment(routine, 6, incx)?;
+    check_increment(routine, 9, incy)?;
+    check_len(routine, 4, packed_len(n), ap.len())?;
+    check_len(routine, 5, vector_len(n, incx), x.len())?;
+    check_len(routine, 8, vector_len(n, incy), y.len())?;
+    unsafe { crate::zhpmv(uplo, n, alpha, ap, x, incx, beta, y, incy) };
+    Ok(())
+}
+
+#[inline]
+pub fn ztrmv(
+    uplo: u8,
+    transa: u8,
+    diag: u8,
+    n: Int,
+    a: &[c64],
+    lda: Int,
+    b: &mut [c64],
+    incx: Int,
+) -> Result<(), BlasError> {
+    let routine = "ztrmv";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_flag(routine, 2, transa, TRANS)?;
+    check_flag(routine, 3, diag, DIAG)?;
+    check_dimension(routine, 4, n)?;
+    check_leading_dimension(routine, 6, lda, n.max(1))?;
+    check_increment(routine, 8, incx)?;
+    check_len(routine, 5, matrix_len(n, n, lda), a.len())?;
+    check_len(routine, 7, vector_len(n, incx), b.len())?;
+    unsafe { crate::ztrmv(uplo, transa, diag, n, a, lda, b, incx) };
+    Ok(())
+}
+
+#[inline]
+pub fn ztbmv(
+    uplo: u8,
+    trans: u8,
+    diag: u8,
+    n: Int,
+    k: Int,
+    a: &[c64],
+    lda: Int,
+    x: &mut [c64],
+    incx: Int,
+) -> Result<(), BlasError> {
+    let routine = "ztbmv";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_flag(routine, 2, trans, TRANS)?;
+    check_flag(routine, 3, diag, DIAG)?;
+    check_dimension(routine, 4, n)?;
+    check_dimension(routine, 5, k)?;
+    check_leading_dimension(routine, 7, lda, k.saturating_add(1))?;
+    check_increment(routine, 9, incx)?;
+    check_len(routine, 6, matrix_len(k.saturating_add(1), n, lda), a.len())?;
+    check_len(routine, 8, vector_len(n, incx), x.len())?;
+    unsafe { crate::ztbmv(uplo, trans, diag, n, k, a, lda, x, incx) };
+    Ok(())
+}
+
+#[inline]
+pub fn ztpmv(
+    uplo: u8,
+    trans: u8,
+    diag: u8,
+    n: Int,
+    ap: &[c64],
+    x: &mut [c64],
+    incx: Int,
+) -> Result<(), BlasError> {
+    let routine = "ztpmv";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_flag(routine, 2, trans, TRANS)?;
+    check_flag(routine, 3, diag, DIAG)?;
+    check_dimension(routine, 4, n)?;
+    check_increment(routine, 7, incx)?;
+    check_len(routine, 5, packed_len(n), ap.len())?;
+    check_len(routine, 6, vector_len(n, incx), x.len())?;
+    unsafe { crate::ztpmv(uplo, trans, diag, n, ap, x, incx) };
+    Ok(())
+}
+
+#[inline]
+pub fn ztrsv(
+    uplo: u8,
+    trans: u8,
+    diag: u8,
+    n: Int,
+    a: &[c64],
+    lda: Int,
+    x: &mut [c64],
+    incx: Int,
+) -> Result<(), BlasError> {
+    let routine = "ztrsv";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_flag(routine, 2, trans, TRANS)?;
+    check_flag(routine, 3, diag, DIAG)?;
+    check_dimension(routine, 4, n)?;
+    check_leading_dimension(routine, 6, lda, n.max(1))?;
+    check_increment(routine, 8, incx)?;
+    check_len(routine, 5, matrix_len(n, n, lda), a.len())?;
+    check_len(routine, 7, vector_len(n, incx), x.len())?;
+    unsafe { crate::ztrsv(uplo, trans, diag, n, a, lda, x, incx) };
+    Ok(())
+}
+
+#[inline]
+pub fn ztbsv(
+    uplo: u8,
+    trans: u8,
+    diag: u8,
+    n: Int,
+    k: Int,
+    a: &[c64],
+    lda: Int,
+    x: &mut [c64],
+    incx: Int,
+) -> Result<(), BlasError> {
+    let routine = "ztbsv";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_flag(routine, 2, trans, TRANS)?;
+    check_flag(routine, 3, diag, DIAG)?;
+    check_dimension(routine, 4, n)?;
+    check_dimension(routine, 5, k)?;
+    check_leading_dimension(routine, 7, lda, k.saturating_add(1))?;
+    check_increment(routine, 9, incx)?;
+    check_len(routine, 6, matrix_len(k.saturating_add(1), n, lda), a.len())?;
+    check_len(routine, 8, vector_len(n, incx), x.len())?;
+    unsafe { crate::ztbsv(uplo, trans, diag, n, k, a, lda, x, incx) };
+    Ok(())
+}
+
+#[inline]
+pub fn ztpsv(
+    uplo: u8,
+    trans: u8,
+    diag: u8,
+    n: Int,
+    ap: &[c64],
+    x: &mut [c64],
+    incx: Int,
+) -> Result<(), BlasError> {
+    let routine = "ztpsv";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_flag(routine, 2, trans, TRANS)?;
+    check_flag(routine, 3, diag, DIAG)?;
+    check_dimension(routine, 4, n)?;
+    check_increment(routine, 7, incx)?;
+    check_len(routine, 5, packed_len(n), ap.len())?;
+    check_len(routine, 6, vector_len(n, incx), x.len())?;
+    unsafe { crate::ztpsv(uplo, trans, diag, n, ap, x, incx) };
+    Ok(())
+}
+
+#[inline]
+pub fn zgeru(
+    m: Int,
+    n: Int,
+    alpha: c64,
+    x: &[c64],
+    incx: Int,
+    y: &[c64],
+    incy: Int,
+    a: &mut [c64],
+    lda: Int,
+) -> Result<(), BlasError> {
+    let routine = "zgeru";
+    check_dimension(routine, 1, m)?;
+    check_dimension(routine, 2, n)?;
+    check_increment(routine, 5, incx)?;
+    check_increment(routine, 7, incy)?;
+    check_leading_dimension(routine, 9, lda, m.max(1))?;
+    check_len(routine, 4, vector_len(m, incx), x.len())?;
+    check_len(routine, 6, vector_len(n, incy), y.len())?;
+    check_len(routine, 8, matrix_len(m, n, lda), a.len())?;
+    unsafe { crate::zgeru(m, n, alpha, x, incx, y, incy, a, lda) };
+    Ok(())
+}
+
+#[inline]
+pub fn zgerc(
+    m: Int,
+    n: Int,
+    alpha: c64,
+    x: &[c64],
+    incx: Int,
+    y: &[c64],
+    incy: Int,
+    a: &mut [c64],
+    lda: Int,
+) -> Result<(), BlasError> {
+    let routine = "zgerc";
+    check_dimension(routine, 1, m)?;
+    check_dimension(routine, 2, n)?;
+    check_increment(routine, 5, incx)?;
+    check_increment(routine, 7, incy)?;
+    check_leading_dimension(routine, 9, lda, m.max(1))?;
+    check_len(routine, 4, vector_len(m, incx), x.len())?;
+    check_len(routine, 6, vector_len(n, incy), y.len())?;
+    check_len(routine, 8, matrix_len(m, n, lda), a.len())?;
+    unsafe { crate::zgerc(m, n, alpha, x, incx, y, incy, a, lda) };
+    Ok(())
+}
+
+#[inline]
+pub fn zher(
+    uplo: u8,
+    n: Int,
+    alpha: f64,
+    x: &[c64],
+    incx: Int,
+    a: &mut [c64],
+    lda: Int,
+) -> Result<(), BlasError> {
+    let routine = "zher";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_dimension(routine, 2, n)?;
+    check_increment(routine, 5, incx)?;
+    check_leading_dimension(routine, 7, lda, n.max(1))?;
+    check_len(routine, 4, vector_len(n, incx), x.len())?;
+    check_len(routine, 6, matrix_len(n, n, lda), a.len())?;
+    unsafe { crate::zher(uplo, n, alpha, x, incx, a, lda) };
+    Ok(())
+}
+
+#[inline]
+pub fn zhpr(
+    uplo: u8,
+    n: Int,
+    alpha: f64,
+    x: &[c64],
+    incx: Int,
+    ap: &mut [c64],
+) -> Result<(), BlasError> {
+    let routine = "zhpr";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_dimension(routine, 2, n)?;
+    check_increment(routine, 5, incx)?;
+    check_len(routine, 4, vector_len(n, incx), x.len())?;
+    check_len(routine, 6, packed_len(n), ap.len())?;
+    unsafe { crate::zhpr(uplo, n, alpha, x, incx, ap) };
+    Ok(())
+}
+
+#[inline]
+pub fn zher2(
+    uplo: u8,
+    n: Int,
+    alpha: c64,
+    x: &[c64],
+    incx: Int,
+    y: &[c64],
+    incy: Int,
+    a: &mut [c64],
+    lda: Int,
+) -> Result<(), BlasError> {
+    let routine = "zher2";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_dimension(routine, 2, n)?;
+    check_increment(routine, 5, incx)?;
+    check_increment(routine, 7, incy)?;
+    check_leading_dimension(routine, 9, lda, n.max(1))?;
+    check_len(routine, 4, vector_len(n, incx), x.len())?;
+    check_len(routine, 6, vector_len(n, incy), y.len())?;
+    check_len(routine, 8, matrix_len(n, n, lda), a.len())?;
+    unsafe { crate::zher2(uplo, n, alpha, x, incx, y, incy, a, lda) };
+    Ok(())
+}
+
+#[inline]
+pub fn zhpr2(
+    uplo: u8,
+    n: Int,
+    alpha: c64,
+    x: &[c64],
+    incx: Int,
+    y: &[c64],
+    incy: Int,
+    ap: &mut [c64],
+) -> Result<(), BlasError> {
+    let routine = "zhpr2";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_dimension(routine, 2, n)?;
+    check_increment(routine, 5, incx)?;
+    check_increment(routine, 7, incy)?;
+    check_len(routine, 4, vector_len(n, incx), x.len())?;
+    check_len(routine, 6, vector_len(n, incy), y.len())?;
+    check_len(routine, 8, packed_len(n), ap.len())?;
+    unsafe { crate::zhpr2(uplo, n, alpha, x, incx, y, incy, ap) };
+    Ok(())
+}
@@ -0,0 +1,915 @@
+use super::{
+    check_dimension, check_flag, check_leading_dimension, check_len, left_side, matrix_len,
+    no_trans, BlasError, DIAG, SIDE, TRANS, TRANS_NC, TRANS_NT, UPLO,
+};
+use crate::{c32, c64, Int};
+
+#[inline]
+pub fn sgemm(
+    transa: u8,
+    transb: u8,
+    m: Int,
+    n: Int,
+    k: Int,
+    alpha: f32,
+    a: &[f32],
+    lda: Int,
+    b: &[f32],
+    ldb: Int,
+    beta: f32,
+    c: &mut [f32],
+    ldc: Int,
+) -> Result<(), BlasError> {
+    let routine = "sgemm";
+    check_flag(routine, 1, transa, TRANS)?;
+    check_flag(routine, 2, transb, TRANS)?;
+    check_dimension(routine, 3, m)?;
+    check_dimension(routine, 4, n)?;
+    check_dimension(routine, 5, k)?;
+    let (nrowa, ncola) = if no_trans(transa) { (m, k) } else { (k, m) };
+    let (nrowb, ncolb) = if no_trans(transb) { (k, n) } else { (n, k) };
+    check_leading_dimension(routine, 8, lda, nrowa.max(1))?;
+    check_leading_dimension(routine, 10, ldb, nrowb.max(1))?;
+    check_leading_dimension(routine, 13, ldc, m.max(1))?;
+    check_len(routine, 7, matrix_len(nrowa, ncola, lda), a.len())?;
+    check_len(routine, 9, matrix_len(nrowb, ncolb, ldb), b.len())?;
+    check_len(routine, 12, matrix_len(m, n, ldc), c.len())?;
+    unsafe { crate::sgemm(transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc) };
+    Ok(())
+}
+
+#[inline]
+pub fn ssymm(
+    side: u8,
+    uplo: u8,
+    m: Int,
+    n: Int,
+    alpha: f32,
+    a: &[f32],
+    lda: Int,
+    b: &[f32],
+    ldb: Int,
+    beta: f32,
+    c: &mut [f32],
+    ldc: Int,
+) -> Result<(), BlasError> {
+    let routine = "ssymm";
+    check_flag(routine, 1, side, SIDE)?;
+    check_flag(routine, 2, uplo, UPLO)?;
+    check_dimension(routine, 3, m)?;
+    check_dimension(routine, 4, n)?;
+    let nrowa = if left_side(side) { m } else { n };
+    check_leading_dimension(routine, 7, lda, nrowa.max(1))?;
+    check_leading_dimension(routine, 9, ldb, m.max(1))?;
+    check_leading_dimension(routine, 12, ldc, m.max(1))?;
+    check_len(routine, 6, matrix_len(nrowa, nrowa, lda), a.len())?;
+    check_len(routine, 8, matrix_len(m, n, ldb), b.len())?;
+    check_len(routine, 11, matrix_len(m, n, ldc), c.len())?;
+    unsafe { crate::ssymm(side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc) };
+    Ok(())
+}
+
+#[inline]
+pub fn ssyrk(
+    uplo: u8,
+    trans: u8,
+    n: Int,
+    k: Int,
+    alpha: f32,
+    a: &[f32],
+    lda: Int,
+    beta: f32,
+    c: &mut [f32],
+    ldc: Int,
+) -> Result<(), BlasError> {
+    let routine = "ssyrk";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_flag(routine, 2, trans, TRANS)?;
+    check_dimension(routine, 3, n)?;
+    check_dimension(routine, 4, k)?;
+    let (nrowa, ncola) = if no_trans(trans) { (n, k) } else { (k, n) };
+    check_leading_dimension(routine, 7, lda, nrowa.max(1))?;
+    check_leading_dimension(routine, 10, ldc, n.max(1))?;
+    check_len(routine, 6, matrix_len(nrowa, ncola, lda), a.len())?;
+    check_len(routine, 9, matrix_len(n, n, ldc), c.len())?;
+    unsafe { crate::ssyrk(uplo, trans, n, k, alpha, a, lda, beta, c, ldc) };
+    Ok(())
+}
+
+#[inline]
+pub fn ssyr2k(
+    uplo: u8,
+    trans: u8,
+    n: Int,
+    k: Int,
+    alpha: f32,
+    a: &[f32],
+    lda: Int,
+    b: &[f32],
+    ldb: Int,
+    beta: f32,
+    c: &mut [f32],
+    ldc: Int,
+) -> Result<(), BlasError> {
+    let routine = "ssyr2k";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_flag(routine, 2, trans, TRANS)?;
+    check_dimension(routine, 3, n)?;
+    check_dimension(routine, 4, k)?;
+    let (nrowa, ncola) = if no_trans(trans) { (n, k) } else { (k, n) };
+    check_leading_dimension(routine, 7, lda, nrowa.max(1))?;
+    check_leading_dimension(routine, 9, ldb, nrowa.max(1))?;
+    check_leading_dimension(routine, 12, ldc, n.max(1))?;
+    check_len(routine, 6, matrix_len(nrowa, ncola, lda), a.len())?;
+    check_len(routine, 8, matrix_len(nrowa, ncola, ldb), b.len())?;
+    check_len(routine, 11, matrix_len(n, n, ldc), c.len())?;
+    unsafe { crate::ssyr2k(uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc) };
+    Ok(())
+}
+
+#[inline]
+pub fn strmm(
+    side: u8,
+    uplo: u8,
+    transa: u8,
+    diag: u8,
+    m: Int,
+    n: Int,
+    alpha: f32,
+    a: &[f32],
+    lda: Int,
+    b: &mut [f32],
+    ldb: Int,
+) -> Result<(), BlasError> {
+    let routine = "strmm";
+    check_flag(routine, 1, side, SIDE)?;
+    check_flag(routine, 2, uplo, UPLO)?;
+    check_flag(routine, 3, transa, TRANS)?;
+    check_flag(routine, 4, diag, DIAG)?;
+    check_dimension(routine, 5, m)?;
+    check_dimension(routine, 6, n)?;
+    let nrowa = if left_side(side) { m } else { n };
+    check_leading_dimension(routine, 9, lda, nrowa.max(1))?;
+    check_leading_dimension(routine, 11, ldb, m.max(1))?;
+    check_len(routine, 8, matrix_len(nrowa, nrowa, lda), a.len())?;
+    check_len(routine, 10, matrix_len(m, n, ldb), b.len())?;
+    unsafe { crate::strmm(side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb) };
+    Ok(())
+}
+
+#[inline]
+pub fn strsm(
+    side: u8,
+    uplo: u8,
+    transa: u8,
+    diag: u8,
+    m: Int,
+    n: Int,
+    alpha: f32,
+    a: &[f32],
+    lda: Int,
+    b: &mut [f32],
+    ldb: Int,
+) -> Result<(), BlasError> {
+    let routine = "strsm";
+    check_flag(routine, 1, side, SIDE)?;
+    check_flag(routine, 2, uplo, UPLO)?;
+    check_flag(routine, 3, transa, TRANS)?;
+    check_flag(routine, 4, diag, DIAG)?;
+    check_dimension(routine, 5, m)?;
+    check_dimension(routine, 6, n)?;
+    let nrowa = if left_side(side) { m } else { n };
+    check_leading_dimension(routine, 9, lda, nrowa.max(1))?;
+    check_leading_dimension(routine, 11, ldb, m.max(1))?;
+    check_len(routine, 8, matrix_len(nrowa, nrowa, lda), a.len())?;
+    check_len(routine, 10, matrix_len(m, n, ldb), b.len())?;
+    unsafe { crate::strsm(side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb) };
+    Ok(())
+}
+
+#[inline]
+pub fn dgemm(
+    transa: u8,
+    transb: u8,
+    m: Int,
+    n: Int,
+    k: Int,
+    alpha: f64,
+    a: &[f64],
+    lda: Int,
+    b: &[f64],
+    ldb: Int,
+    beta: f64,
+    c: &mut [f64],
+    ldc: Int,
+) -> Result<(), BlasError> {
+    let routine = "dgemm";
+    check_flag(routine, 1, transa, TRANS)?;
+    check_flag(routine, 2, transb, TRANS)?;
+    check_dimension(routine, 3, m)?;
+    check_dimension(routine, 4, n)?;
+    check_dimension(routine, 5, k)?;
+    let (nrowa, ncola) = if no_trans(transa) { (m, k) } else { (k, m) };
+    let (nrowb, ncolb) = if no_trans(transb) { (k, n) } else { (n, k) };
+    check_leading_dimension(routine, 8, lda, nrowa.max(1))?;
+    check_leading_dimension(routine, 10, ldb, nrowb.max(1))?;
+    check_leading_dimension(routine, 13, ldc, m.max(1))?;
+    check_len(routine, 7, matrix_len(nrowa, ncola, lda), a.len())?;
+    check_len(routine, 9, matrix_len(nrowb, ncolb, ldb), b.len())?;
+    check_len(routine, 12, matrix_len(m, n, ldc), c.len())?;
+    unsafe { crate::dgemm(transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc) };
+    Ok(())
+}
+
+#[inline]
+pub fn dsymm(
+    side: u8,
+    uplo: u8,
+    m: Int,
+    n: Int,
+    alpha: f64,
+    a: &[f64],
+    lda: Int,
+    b: &[f64],
+    ldb: Int,
+    beta: f64,
+    c: &mut [f64],
+    ldc: Int,
+) -> Result<(), BlasError> {
+    let routine = "dsymm";
+    check_flag(routine, 1, side, SIDE)?;
+    check_flag(routine, 2, uplo, UPLO)?;
+    check_dimension(routine, 3, m)?;
+    check_dimension(routine, 4, n)?;
+    let nrowa = if left_side(side) { m } else { n };
+    check_leading_dimension(routine, 7, lda, nrowa.max(1))?;
+    check_leading_dimension(routine, 9, ldb, m.max(1))?;
+    check_leading_dimension(routine, 12, ldc, m.max(1))?;
+    check_len(routine, 6, matrix_len(nrowa, nrowa, lda), a.len())?;
+    check_len(routine, 8, matrix_len(m, n, ldb), b.len())?;
+    check_len(routine, 11, matrix_len(m, n, ldc), c.len())?;
+    unsafe { crate::dsymm(side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc) };
+    Ok(())
+}
+
+#[inline]
+pub fn dsyrk(
+    uplo: u8,
+    trans: u8,
+    n: Int,
+    k: Int,
+    alpha: f64,
+    a: &[f64],
+    lda: Int,
+    beta: f64,
+    c: &mut [f64],
+    ldc: Int,
+) -> Result<(), BlasError> {
+    let routine = "dsyrk";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_flag(routine, 2, trans, TRANS)?;
+    check_dimension(routine, 3, n)?;
+    check_dimension(routine, 4, k)?;
+    let (nrowa, ncola) = if no_trans(trans) { (n, k) } else { (k, n) };
+    check_leading_dimension(routine, 7, lda, nrowa.max(1))?;
+    check_leading_dimension(routine, 10, ldc, n.max(1))?;
+    check_len(routine, 6, matrix_len(nrowa, ncola, lda), a.len())?;
+    check_len(routine, 9, matrix_len(n, n, ldc), c.len())?;
+    unsafe { crate::dsyrk(uplo, trans, n, k, alpha, a, lda, beta, c, ldc) };
+    Ok(())
+}
+
+#[inline]
+pub fn dsyr2k(
+    uplo: u8,
+    trans: u8,
+    n: Int,
+    k: Int,
+    alpha: f64,
+    a: &[f64],
+    lda: Int,
+    b: &[f64],
+    ldb: Int,
+    beta: f64,
+    c: &mut [f64],
+    ldc: Int,
+) -> Result<(), BlasError> {
+    let routine = "dsyr2k";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_flag(routine, 2, trans, TRANS)?;
+    check_dimension(routine, 3, n)?;
+    check_dimension(routine, 4, k)?;
+    let (nrowa, ncola) = if no_trans(trans) { (n, k) } else { (k, n) };
+    check_leading_dimension(routine, 7, lda, nrowa.max(1))?;
+    check_leading_dimension(routine, 9, ldb, nrowa.max(1))?;
+    check_leading_dimension(routine, 12, ldc, n.max(1))?;
+    check_len(routine, 6, matrix_len(nrowa, ncola, lda), a.len())?;
+    check_len(routine, 8, matrix_len(nrowa, ncola, ldb), b.len())?;
+    check_len(routine, 11, matrix_len(n, n, ldc), c.len())?;
+    unsafe { crate::dsyr2k(uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc) };
+    Ok(())
+}
+
+#[inline]
+pub fn dtrmm(
+    side: u8,
+    uplo: u8,
+    transa: u8,
+    diag: u8,
+    m: Int,
+    n: Int,
+    alpha: f64,
+    a: &[f64],
+    lda: Int,
+    b: &mut [f64],
+    ldb: Int,
+) -> Result<(), BlasError> {
+    let routine = "dtrmm";
+    check_flag(routine, 1, side, SIDE)?;
+    check_flag(routine, 2, uplo, UPLO)?;
+    check_flag(routine, 3, transa, TRANS)?;
+    check_flag(routine, 4, diag, DIAG)?;
+    check_dimension(routine, 5, m)?;
+    check_dimension(routine, 6, n)?;
+    let nrowa = if left_side(side) { m } else { n };
+    check_leading_dimension(routine, 9, lda, nrowa.max(1))?;
+    check_leading_dimension(routine, 11, ldb, m.max(1))?;
+    check_len(routine, 8, matrix_len(nrowa, nrowa, lda), a.len())?;
+    check_len(routine, 10, matrix_len(m, n, ldb), b.len())?;
+    unsafe { crate::dtrmm(side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb) };
+    Ok(())
+}
+
+#[inline]
+pub fn dtrsm(
+    side: u8,
+    uplo: u8,
+    transa: u8,
+    diag: u8,
+    m: Int,
+    n: Int,
+    alpha: f64,
+    a: &[f64],
+    lda: Int,
+    b: &mut [f64],
+    ldb: Int,
+) -> Result<(), BlasError> {
+    let routine = "dtrsm";
+    check_flag(routine, 1, side, SIDE)?;
+    check_flag(routine, 2, uplo, UPLO)?;
+    check_flag(routine, 3, transa, TRANS)?;
+    check_flag(routine, 4, diag, DIAG)?;
+    check_dimension(routine, 5, m)?;
+    check_dimension(routine, 6, n)?;
+    let nrowa = if left_side(side) { m } else { n };
+    check_leading_dimension(routine, 9, lda, nrowa.max(1))?;
+    check_leading_dimension(routine, 11, ldb, m.max(1))?;
+    check_len(routine, 8, matrix_len(nrowa, nrowa, lda), a.len())?;
+    check_len(routine, 10, matrix_len(m, n, ldb), b.len())?;
+    unsafe { crate::dtrsm(side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb) };
+    Ok(())
+}
+
+#[inline]
+pub fn cgemm(
+    transa: u8,
+    transb: u8,
+    m: Int,
+    n: Int,
+    k: Int,
+    alpha: c32,
+    a: &[c32],
+    lda: Int,
+    b: &[c32],
+    ldb: Int,
+    beta: c32,
+    c: &mut [c32],
+    ldc: Int,
+) -> Result<(), BlasError> {
+    let routine = "cgemm";
+    check_flag(routine, 1, transa, TRANS)?;
+    check_flag(routine, 2, transb, TRANS)?;
+    check_dimension(routine, 3, m)?;
+    check_dimension(routine, 4, n)?;
+    check_dimension(routine, 5, k)?;
+    let (nrowa, ncola) = if no_trans(transa) { (m, k) } else { (k, m) };
+    let (nrowb, ncolb) = if no_trans(transb) { (k, n) } else { (n, k) };
+    check_leading_dimension(routine, 8, lda, nrowa.max(1))?;
+    check_leading_dimension(routine, 10, ldb, nrowb.max(1))?;
+    check_leading_dimension(routine, 13, ldc, m.max(1))?;
+    check_len(routine, 7, matrix_len(nrowa, ncola, lda), a.len())?;
+    check_len(routine, 9, matrix_len(nrowb, ncolb, ldb), b.len())?;
+    check_len(routine, 12, matrix_len(m, n, ldc), c.len())?;
+    unsafe { crate::cgemm(transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc) };
+    Ok(())
+}
+
+#[inline]
+pub fn csymm(
+    side: u8,
+    uplo: u8,
+    m: Int,
+    n: Int,
+    alpha: c32,
+    a: &[c32],
+    lda: Int,
+    b: &[c32],
+    ldb: Int,
+    beta: c32,
+    c: &mut [c32],
+    ldc: Int,
+) -> Result<(), BlasError> {
+    let routine = "csymm";
+    check_flag(routine, 1, side, SIDE)?;
+    check_flag(routine, 2, uplo, UPLO)?;
+    check_dimension(routine, 3, m)?;
+    check_dimension(routine, 4, n)?;
+    let nrowa = if left_side(side) { m } else { n };
+    check_leading_dimension(routine, 7, lda, nrowa.max(1))?;
+    check_leading_dimension(routine, 9, ldb, m.max(1))?;
+    check_leading_dimension(routine, 12, ldc, m.max(1))?;
+    check_len(routine, 6, matrix_len(nrowa, nrowa, lda), a.len())?;
+    check_len(routine, 8, matrix_len(m, n, ldb), b.len())?;
+    check_len(routine, 11, matrix_len(m, n, ldc), c.len())?;
+    unsafe { crate::csymm(side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc) };
+    Ok(())
+}
+
+#[inline]
+pub fn chemm(
+    side: u8,
+    uplo: u8,
+    m: Int,
+    n: Int,
+    alpha: c32,
+    a: &[c32],
+    lda: Int,
+    b: &[c32],
+    ldb: Int,
+    beta: c32,
+    c: &mut [c32],
+    ldc: Int,
+) -> Result<(), BlasError> {
+    let routine = "chemm";
+    check_flag(routine, 1, side, SIDE)?;
+    check_flag(routine, 2, uplo, UPLO)?;
+    check_dimension(routine, 3, m)?;
+    check_dimension(routine, 4, n)?;
+    let nrowa = if left_side(side) { m } else { n };
+    check_leading_dimension(routine, 7, lda, nrowa.max(1))?;
+    check_leading_dimension(routine, 9, ldb, m.max(1))?;
+    check_leading_dimension(routine, 12, ldc, m.max(1))?;
+    check_len(routine, 6, matrix_len(nrowa, nrowa, lda), a.len())?;
+    check_len(routine, 8, matrix_len(m, n, ldb), b.len())?;
+    check_len(routine, 11, matrix_len(m, n, ldc), c.len())?;
+    unsafe { crate::chemm(side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc) };
+    Ok(())
+}
+
+#[inline]
+pub fn csyrk(
+    uplo: u8,
+    trans: u8,
+    n: Int,
+    k: Int,
+    alpha: c32,
+    a: &[c32],
+    lda: Int,
+    beta: c32,
+    c: &mut [c32],
+    ldc: Int,
+) -> Result<(), BlasError> {
+    let routine = "csyrk";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_flag(routine, 2, trans, TRANS_NT)?;
+    check_dimension(routine, 3, n)?;
+    check_dimension(routine, 4, k)?;
+    let (nrowa, ncola) = if no_trans(trans) { (n, k) } else { (k, n) };
+    check_leading_dimension(routine, 7, lda, nrowa.max(1))?;
+    check_leading_dimension(routine, 10, ldc, n.max(1))?;
+    check_len(routine, 6, matrix_len(nrowa, ncola, lda), a.len())?;
+    check_len(routine, 9, matrix_len(n, n, ldc), c.len())?;
+    unsafe { crate::csyrk(uplo, trans, n, k, alpha, a, lda, beta, c, ldc) };
+    Ok(())
+}
+
+#[inline]
+pub fn cherk(
+    uplo: u8,
+    trans: u8,
+    n: Int,
+    k: Int,
+    alpha: f32,
+    a: &[c32],
+    lda: Int,
+    beta: f32,
+    c: &mut [c32],
+    ldc: Int,
+) -> Result<(), BlasError> {
+    let routine = "cherk";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_flag(routine, 2, trans, TRANS_NC)?;
+    check_dimension(routine, 3, n)?;
+    check_dimension(routine, 4, k)?;
+    let (nrowa, ncola) = if no_trans(trans) { (n, k) } else { (k, n) };
+    check_leading_dimension(routine, 7, lda, nrowa.max(1))?;
+    check_leading_dimension(routine, 10, ldc, n.max(1))?;
+    check_len(routine, 6, matrix_len(nrowa, ncola, lda), a.len())?;
+    check_len(routine, 9, matrix_len(n, n, ldc), c.len())?;
+    unsafe { crate::cherk(uplo, trans, n, k, alpha, a, lda, beta, c, ldc) };
+    Ok(())
+}
+
+#[inline]
+pub fn csyr2k(
+    uplo: u8,
+    trans: u8,
+    n: Int,
+    k: Int,
+    alpha: c32,
+    a: &[c32],
+    lda: Int,
+    b: &[c32],
+    ldb: Int,
+    beta: c32,
+    c: &mut [c32],
+    ldc: Int,
+) -> Result<(), BlasError> {
+    let routine = "csyr2k";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_flag(routine, 2, trans, TRANS_NT)?;
+    check_dimension(routine, 3, n)?;
+    check_dimension(routine, 4, k)?;
+    let (nrowa, ncola) = if no_trans(trans) { (n, k) } else { (k, n) };
+    check_leading_dimension(routine, 7, lda, nrowa.max(1))?;
+    check_leading_dimension(routine, 9, ldb, nrowa.max(1))?;
+    check_leading_dimension(routine, 12, ldc, n.max(1))?;
+    check_len(routine, 6, matrix_len(nrowa, ncola, lda), a.len())?;
+    check_len(routine, 8, matrix_len(nrowa, ncola, ldb), b.len())?;
+    check_len(routine, 11, matrix_len(n, n, ldc), c.len())?;
+    unsafe { crate::csyr2k(uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc) };
+    Ok(())
+}
+
+#[inline]
+pub fn cher2k(
+    uplo: u8,
+    trans: u8,
+    n: Int,
+    k: Int,
+    alpha: c32,
+    a: &[c32],
+    lda: Int,
+    b: &[c32],
+    ldb: Int,
+    beta: f32,
+    c: &mut [c32],
+    ldc: Int,
+) -> Result<(), BlasError> {
+    let routine = "cher2k";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_flag(routine, 2, trans, TRANS_NC)?;
+    check_dimension(routine, 3, n)?;
+    check_dimension(routine, 4, k)?;
+    let (nrowa, ncola) = if no_trans(trans) { (n, k) } else { (k, n) };
+    check_leading_dimension(routine, 7, lda, nrowa.max(1))?;
+    check_leading_dimension(routine, 9, ldb, nrowa.max(1))?;
+    check_leading_dimension(routine, 12, ldc, n.max(1))?;
+    check_len(routine, 6, matrix_len(nrowa, ncola, lda), a.len())?;
+    check_len(routine, 8, matrix_len(nrowa, ncola, ldb), b.len())?;
+    check_len(routine, 11, matrix_len(n, n, ldc), c.len())?;
+    unsafe { crate::cher2k(uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc) };
+    Ok(())
+}
+
+#[inline]
+pub fn ctrmm(
+    side: u8,
+    uplo: u8,
+    transa: u8,
+    diag: u8,
+    m: Int,
+    n: Int,
+    alpha: c32,
+    a: &[c32],
+    lda: Int,
+    b: &mut [c32],
+    ldb: Int,
+) -> Result<(), BlasError> {
+    let routine = "ctrmm";
+    check_flag(routine, 1, side, SIDE)?;
+    check_flag(routine, 2, uplo, UPLO)?;
+    check_flag(routine, 3, transa, TRANS)?;
+    check_flag(routine, 4, diag, DIAG)?;
+    check_dimension(routine, 5, m)?;
+    check_dimension(routine, 6, n)?;
+    let nrowa = if left_side(side) { m } else { n };
+    check_leading_dimension(routine, 9, lda, nrowa.max(1))?;
+    check_leading_dimension(routine, 11, ldb, m.max(1))?;
+    check_len(routine, 8, matrix_len(nrowa, nrowa, lda), a.len())?;
+    check_len(routine, 10, matrix_len(m, n, ldb), b.len())?;
+    unsafe { crate::ctrmm(side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb) };
+    Ok(())
+}
+
+#[inline]
+pub fn ctrsm(
+    side: u8,
+    uplo: u8,
+    transa: u8,
+    diag: u8,
+    m: Int,
+    n: Int,
+    alpha: c32,
+    a: &[c32],
+    lda: Int,
+    b: &mut [c32],
+    ldb: Int,
+) -> Result<(), BlasError> {
+    let routine = "ctrsm";
+    check_flag(routine, 1, side, SIDE)?;
+    check_flag(routine, 2, uplo, UPLO)?;
+    check_flag(routine, 3, transa, TRANS)?;
+    check_flag(routine, 4, diag, DIAG)?;
+    check_dimension(routine, 5, m)?;
+    check_dimension(routine, 6, n)?;
+    let nrowa = if left_side(side) { m } else { n };
+    check_leading_dimension(routine, 9, lda, nrowa.max(1))?;
+    check_leading_dimension(routine, 11, ldb, m.max(1))?;
+    check_len(routine, 8, matrix_len(nrowa, nrowa, lda), a.len())?;
+    check_len(routine, 10, matrix_len(m, n, ldb), b.len())?;
+    unsafe { crate::ctrsm(side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb) };
+    Ok(())
+}
+
+#[inline]
+pub fn zgemm(
+    transa: u8,
+    transb: u8,
+    m: Int,
+    n: Int,
+    k: Int,
+    alpha: c64,
+    a: &[c64],
+    lda: Int,
+    b: &[c64],
+    ldb: Int,
+    beta: c64,
+    c: &mut [c64],
+    ldc: Int,
+) -> Result<(), BlasError> {
+    let routine = "zgemm";
+    check_flag(routine, 1, transa, TRANS)?;
+    check_flag(routine, 2, transb, TRANS)?;
+    check_dimension(routine, 3, m)?;
+    check_dimension(routine, 4, n)?;
+    check_dimension(routine, 5, k)?;
+    let (nrowa, ncola) = if no_trans(transa) { (m, k) } else { (k, m) };
+    let (nrowb, ncolb) = if no_trans(transb) { (k, n) } else { (n, k) };
+    check_leading_dimension(routine, 8, lda, nrowa.max(1))?;
+    check_leading_dimension(routine, 10, ldb, nrowb.max(1))?;
+    check_leading_dimension(routine, 13, ldc, m.max(1))?;
+    check_len(routine, 7, matrix_len(nrowa, ncola, lda), a.len())?;
+    check_len(routine, 9, matrix_len(nrowb, ncolb, ldb), b.len())?;
+    check_len(routine, 12, matrix_len(m, n, ldc), c.len())?;
+    unsafe { crate::zgemm(transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc) };
+    Ok(())
+}
+
+#[inline]
+pub fn zsymm(
+    side: u8,
+    uplo: u8,
+    m: Int,
+    n: Int,
+    alpha: c64,
+    a: &[c64],
+    lda: Int,
+    b: &[c64],
+    ldb: Int,
+    beta: c64,
+    c: &mut [c64],
+    ldc: Int,
+) -> Result<(), BlasError> {
+    let routine = "zsymm";
+    check_flag(routine, 1, side, SIDE)?;
+    check_flag(routine, 2, uplo, UPLO)?;
+    check_dimension(routine, 3, m)?;
+    check_dimension(routine, 4, n)?;
+    let nrowa = if left_side(side) { m } else { n };
+    check_leading_dimension(routine, 7, lda, nrowa.max(1))?;
+    check_leading_dimension(routine, 9, ldb, m.max(1))?;
+    check_leading_dimension(routine, 12, ldc, m.max(1))?;
+    check_len(routine, 6, matrix_len(nrowa, nrowa, lda), a.len())?;
+    check_len(routine, 8, matrix_len(m, n, ldb), b.len())?;
+    check_len(routine, 11, matrix_len(m, n, ldc), c.len())?;
+    unsafe { crate::zsymm(side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc) };
+    Ok(())
+}
+
+#[inline]
+pub fn zhemm(
+    side: u8,
+    uplo: u8,
+    m: Int,
+    n: Int,
+    alpha: c64,
+    a: &[c64],
+    lda: Int,
+    b: &[c64],
+    ldb: Int,
+    beta: c64,
+    c: &mut [c64],
+    ldc: Int,
+) -> Result<(), BlasError> {
+    let routine = "zhemm";
+    check_flag(routine, 1, side, SIDE)?;
+    check_flag(routine, 2, uplo, UPLO)?;
+    check_dimension(routine, 3, m)?;
+    check_dimension(routine, 4, n)?;
+    let nrowa = if left_side(side) { m } else { n };
+    check_leading_dimension(routine, 7, lda, nrowa.max(1))?;
+    check_leading_dimension(routine, 9, ldb, m.max(1))?;
+    check_leading_dimension(routine, 12, ldc, m.max(1))?;
+    check_len(routine, 6, matrix_len(nrowa, nrowa, lda), a.len())?;
+    check_len(routine, 8, matrix_len(m, n, ldb), b.len())?;
+    check_len(routine, 11, matrix_len(m, n, ldc), c.len())?;
+    unsafe { crate::zhemm(side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc) };
+    Ok(())
+}
+
+#[inline]
+pub fn zsyrk(
+    uplo: u8,
+    trans: u8,
+    n: Int,
+    k: Int,
+    alpha: c64,
+    a: &[c64],
+    lda: Int,
+    beta: c64,
+    c: &mut [c64],
+    ldc: Int,
+) -> Result<(), BlasError> {
+    let routine = "zsyrk";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_flag(routine, 2, trans, TRANS_NT)?;
+    check_dimension(routine, 3, n)?;
+    check_dimension(routine, 4, k)?;
+    let (nrowa, ncola) = if no_trans(trans) { (n, k) } else { (k, n) };
+    check_leading_dimension(routine, 7, lda, nrowa.max(1))?;
+    check_leading_dimension(routine, 10, ldc, n.max(1))?;
+    check_len(routine, 6, matrix_len(nrowa, ncola, lda), a.len())?;
+    check_len(routine, 9, matrix_len(n, n, ldc), c.len())?;
+    unsafe { crate::zsyrk(uplo, trans, n, k, alpha, a, lda, beta, c, ldc) };
+    Ok(())
+}
+
+#[inline]
+pub fn zherk(
+    uplo: u8,
+    trans: u8,
+    n: Int,
+    k: Int,
+    alpha: f64,
+    a: &[c64],
+    lda: Int,
+    beta: f64,
+    c: &mut [c64],
+    ldc: Int,
+) -> Result<(), BlasError> {
+    let routine = "zherk";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_flag(routine, 2, trans, TRANS_NC)?;
+    check_dimension(routine, 3, n)?;
+    check_dimension(routine, 4, k)?;
+    let (nrowa, ncola) = if no_trans(trans) { (n, k) } else { (k, n) };
+    check_leading_dimension(routine, 7, lda, nrowa.max(1))?;
+    check_leading_dimension(routine, 10, ldc, n.max(1))?;
+    check_len(routine, 6, matrix_len(nrowa, ncola, lda), a.len())?;
+    check_len(routine, 9, matrix_len(n, n, ldc), c.len())?;
+    unsafe { crate::zherk(uplo, trans, n, k, alpha, a, lda, beta, c, ldc) };
+    Ok(())
+}
+
+#[inline]
+pub fn zsyr2k(
+    uplo: u8,
+    trans: u8,
+    n: Int,
+    k: Int,
+    alpha: c64,
+    a: &[c64],
+    lda: Int,
+    b: &[c64],
+    ldb: Int,
+    beta: c64,
+    c: &mut [c64],
+    ldc: Int,
+) -> Result<(), BlasError> {
+    let routine = "zsyr2k";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_flag(routine, 2, trans, TRANS_NT)?;
+    check_dimension(routine, 3, n)?;
+    check_dimension(routine, 4, k)?;
+    let (nrowa, ncola) = if no_trans(trans) { (n, k) } else { (k, n) };
+    check_leading_dimension(routine, 7, lda, nrowa.max(1))?;
+    check_leading_dimension(routine, 9, ldb, nrowa.max(1))?;
+    check_leading_dimension(routine, 12, ldc, n.max(1))?;
+    check_len(routine, 6, matrix_len(nrowa, ncola, lda), a.len())?;
+    check_len(routine, 8, matrix_len(nrowa, ncola, ldb), b.len())?;
+    check_len(routine, 11, matrix_len(n, n, ldc), c.len())?;
+    unsafe { crate::zsyr2k(uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc) };
+    Ok(())
+}
+
+#[inline]
+pub fn zher2k(
+    uplo: u8,
+    trans: u8,
+    n: Int,
+    k: Int,
+    alpha: c64,
+    a: &[c64],
+    lda: Int,
+    b: &[c64],
+    ldb: Int,
+    beta: f64,
+    c: &mut [c64],
+    ldc: Int,
+) -> Result<(), BlasError> {
+    let routine = "zher2k";
+    check_flag(routine, 1, uplo, UPLO)?;
+    check_flag(routine, 2, trans, TRANS_NC)?;
+    check_dimension(routine, 3, n)?;
+    check_dimension(routine, 4, k)?;
+    let (nrowa, ncola) = if no_trans(trans) { (n, k) } else { (k, n) };
+    check_leading_dimension(routine, 7, lda, nrowa.max(1))?;
+    check_leading_dimension(routine, 9, ldb, nrowa.max(1))?;
+    check_leading_dimension(routine, 12, ldc, n.max(1))?;
+    check_len(routine, 6, matrix_len(nrowa, ncola, lda), a.len())?;
+    check_len(routine, 8, matrix_len(nrowa, ncola, ldb), b.len())?;
+    check_len(routine, 11, matrix_len(n, n, ldc), c.len())?;
+    unsafe { crate::zher2k(uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc) };
+    Ok(())
+}
+
+#[inline]
+pub fn ztrmm(
+    side: u8,
+    uplo: u8,
+    transa: u8,
+    diag: u8,
+    m: Int,
+    n: Int,
+    alpha: c64,
+    a: &[c64],
+    lda: Int,
+    b: &mut [c64],
+    ldb: Int,
+) -> Result<(), BlasError> {
+    let routine = "ztrmm";
+    check_flag(routine, 1, side, SIDE)?;
+    check_flag(routine, 2, uplo, UPLO)?;
+    check_flag(routine, 3, transa, TRANS)?;
+    check_flag(routine, 4, diag, DIAG)?;
+    check_dimension(routine, 5, m)?;
+    check_dimension(routine, 6, n)?;
+    let nrowa = if left_side(side) { m } else { n };
+    check_leading_dimension(routine, 9, lda, nrowa.max(1))?;
+    check_leading_dimension(routine, 11, ldb, m.max(1))?;
+    check_len(routine, 8, matrix_len(nrowa, nrowa, lda), a.len())?;
+    check_len(routine, 10, matrix_len(m, n, ldb), b.len())?;
+    unsafe { crate::ztrmm(side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb) };
+    Ok(())
+}
+
+#[inline]
+pub fn ztrsm(
+    side: u8,
+    uplo: u8,
+    transa: u8,
+    diag: u8,
+    m: Int,
+    n: Int,
+    alpha: c64,
+    a: &[c64],
+    lda: Int,
+    b: &mut [c64],
+    ldb: Int,
+) -> Result<(), BlasError> {
+    let routine = "ztrsm";
+    check_flag(routine, 1, side, SIDE)?;
+    check_flag(routine, 2, uplo, UPLO)?;
+    check_flag(routine, 3, transa, TRANS)?;
+    check_flag(routine, 4, diag, DIAG)?;
+    check_dimension(routine, 5, m)?;
+    check_dimension(routine, 6, n)?;
+    let nrowa = if left_side(side) { m } else { n };
+    check_leading_dimension(routine, 9, lda, nrowa.max(1))?;
+    check_leading_dimension(routine, 11, ldb, m.max(1))?;
+    check_len(routine, 8, matrix_len(nrowa, nrowa, lda), a.len())?;
+    check_len(routine, 10, matrix_len(m, n, ldb), b.len())?;
+    unsafe { crate::ztrsm(side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb) };
+    Ok(())
+}
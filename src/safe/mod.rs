@@ -0,0 +1,284 @@
+//! Checked wrappers.
+//!
+//! The functions in this module mirror the unsafe wrappers at the root of the crate but verify
+//! that the dimensions, increments, and slice lengths they are given agree before calling BLAS.
+//! A violated contract is reported as a [`BlasError`] instead of being handed to the backend.
+//!
+//! As in BLAS, a vector with a negative increment is traversed backwards, and its slice starts
+//! with the element at the lowest address, which is the last one visited. The slice must hold
+//! `1 + (n - 1) * |inc|` elements in either case. A reversed vector can also be expressed with
+//! [`VecRef::rev`](crate::VecRef::rev) and the wrappers in [`view`](crate::view). The norms,
+//! sums, and positions of the largest elements are computed for such vectors too, whereas BLAS
+//! returns zero; among equal largest elements, the position of the last one is then returned.
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use blas::safe::*;
+//!
+//! let x = vec![1.0, 2.0, 3.0];
+//! let mut y = vec![0.0; 3];
+//!
+//! daxpy(3, 2.0, &x, 1, &mut y, 1).unwrap();
+//! assert!(daxpy(3, 2.0, &x, 2, &mut y, 1).is_err());
+//! ```
+
+use core::fmt;
+
+use crate::Int;
+
+mod extension;
+mod level1;
+mod level2;
+mod level3;
+
+pub use extension::*;
+pub use level1::*;
+pub use level2::*;
+pub use level3::*;
+
+/// An error reported by a checked wrapper.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BlasError {
+    /// A slice is shorter than the dimensions and increments require.
+    ///
+    /// `param` is the 1-based position of the slice in the argument list of the routine.
+    BufferTooShort {
+        routine: &'static str,
+        param: usize,
+        required: usize,
+        actual: usize,
+    },
+    /// An argument has a value that the reference implementation would reject.
+    ///
+    /// `param` is the 1-based position of the argument as reported by the reference `XERBLA`.
+    InvalidArgument {
+        routine: &'static str,
+        param: usize,
+        reason: Reason,
+    },
+}
+
+/// The reason an argument has been rejected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Reason {
+    /// The flag is not one of the characters accepted by the routine.
+    InvalidFlag(u8),
+    /// The dimension is negative.
+    NegativeDimension(Int),
+    /// The increment is zero.
+    ZeroIncrement,
+    /// The leading dimension is smaller than the minimum required.
+    LeadingDimension { actual: Int, minimum: Int },
+    /// The dimension does not agree with the dimensions of the other arguments.
+    DimensionMismatch { actual: Int, expected: Int },
+}
+
+impl fmt::Display for BlasError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            BlasError::BufferTooShort {
+                routine,
+                param,
+                required,
+                actual,
+            } => write!(
+                formatter,
+                "{}: parameter {} requires at least {} elements but has {}",
+                routine, param, required, actual,
+            ),
+            BlasError::InvalidArgument {
+                routine,
+                param,
+                reason,
+            } => write!(formatter, "{}: parameter {} {}", routine, param, reason),
+        }
+    }
+}
+
+impl fmt::Display for Reason {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Reason::InvalidFlag(value) => {
+                write!(formatter, "has an invalid flag {:?}", value as char)
+            }
+            Reason::NegativeDimension(value) => {
+                write!(formatter, "is a negative dimension {}", value)
+            }
+            Reason::ZeroIncrement => write!(formatter, "is a zero increment"),
+            Reason::LeadingDimension { actual, minimum } => write!(
+                formatter,
+                "is a leading dimension {} less than {}",
+                actual, minimum,
+            ),
+            Reason::DimensionMismatch { actual, expected } => write!(
+                formatter,
+                "has a dimension {} that does not match {}",
+                actual, expected,
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BlasError {}
+
+pub(crate) const DIAG: &[u8] = b"UN";
+pub(crate) const SIDE: &[u8] = b"LR";
+pub(crate) const TRANS: &[u8] = b"NTC";
+pub(crate) const TRANS_NC: &[u8] = b"NC";
+pub(crate) const TRANS_NT: &[u8] = b"NT";
+pub(crate) const UPLO: &[u8] = b"UL";
+
+/// Check whether a transpose flag is `N`, ignoring case as `LSAME` does.
+#[inline]
+pub(crate) fn no_trans(trans: u8) -> bool {
+    trans.eq_ignore_ascii_case(&b'N')
+}
+
+/// Check whether a side flag is `L`, ignoring case as `LSAME` does.
+#[inline]
+pub(crate) fn left_side(side: u8) -> bool {
+    side.eq_ignore_ascii_case(&b'L')
+}
+
+/// Return the number of elements spanned by a vector of `n` elements with increment `inc`.
+#[inline]
+pub(crate) fn vector_len(n: Int, inc: Int) -> usize {
+    if n <= 0 {
+        return 0;
+    }
+    (n as usize - 1)
+        .saturating_mul(inc.unsigned_abs() as usize)
+        .saturating_add(1)
+}
+
+#[inline]
+pub(crate) fn check_len(
+    routine: &'static str,
+    param: usize,
+    required: usize,
+    actual: usize,
+) -> Result<(), BlasError> {
+    if actual < required {
+        return Err(BlasError::BufferTooShort {
+            routine,
+            param,
+            required,
+            actual,
+        });
+    }
+    Ok(())
+}
+
+#[inline]
+pub(crate) fn check_vector(
+    routine: &'static str,
+    param: usize,
+    n: Int,
+    inc: Int,
+    len: usize,
+) -> Result<(), BlasError> {
+    check_len(routine, param, vector_len(n, inc), len)
+}
+
+/// Return the position in the order of traversal with `inc` of an element found by `IAMAX` with
+/// the magnitude of `inc`.
+#[inline]
+pub(crate) fn traversed(position: usize, n: Int, inc: Int) -> usize {
+    if inc < 0 && position > 0 {
+        n as usize + 1 - position
+    } else {
+        position
+    }
+}
+
+/// Return the number of elements spanned by a column-major matrix.
+#[inline]
+pub(crate) fn matrix_len(rows: Int, cols: Int, ld: Int) -> usize {
+    if rows <= 0 || cols <= 0 {
+        return 0;
+    }
+    (cols as usize - 1)
+        .saturating_mul(ld as usize)
+        .saturating_add(rows as usize)
+}
+
+/// Return the number of elements of a packed triangular matrix of order `n`.
+#[inline]
+pub(crate) fn packed_len(n: Int) -> usize {
+    if n <= 0 {
+        return 0;
+    }
+    let n = n as usize;
+    n.saturating_mul(n + 1) / 2
+}
+
+#[inline]
+pub(crate) fn check_flag(
+    routine: &'static str,
+    param: usize,
+    value: u8,
+    accepted: &[u8],
+) -> Result<(), BlasError> {
+    if !accepted.contains(&value.to_ascii_uppercase()) {
+        return Err(BlasError::InvalidArgument {
+            routine,
+            param,
+            reason: Reason::InvalidFlag(value),
+        });
+    }
+    Ok(())
+}
+
+#[inline]
+pub(crate) fn check_dimension(
+    routine: &'static str,
+    param: usize,
+    value: Int,
+) -> Result<(), BlasError> {
+    if value < 0 {
+        return Err(BlasError::InvalidArgument {
+            routine,
+            param,
+            reason: Reason::NegativeDimension(value),
+        });
+    }
+    Ok(())
+}
+
+#[inline]
+pub(crate) fn check_increment(
+    routine: &'static str,
+    param: usize,
+    value: Int,
+) -> Result<(), BlasError> {
+    if value == 0 {
+        return Err(BlasError::InvalidArgument {
+            routine,
+            param,
+            reason: Reason::ZeroIncrement,
+        });
+    }
+    Ok(())
+}
+
+#[inline]
+pub(crate) fn check_leading_dimension(
+    routine: &'static str,
+    param: usize,
+    value: Int,
+    minimum: Int,
+) -> Result<(), BlasError> {
+    if value < minimum {
+        return Err(BlasError::InvalidArgument {
+            routine,
+            param,
+            reason: Reason::LeadingDimension {
+                actual: value,
+                minimum,
+            },
+        });
+    }
+    Ok(())
+}
@@ -0,0 +1,1055 @@
+use crate::{c32, c64, Diag, Int, Transpose, Uplo};
+
+#[inline]
+pub unsafe fn sgemv(
+    trans: Transpose,
+    m: Int,
+    n: Int,
+    alpha: f32,
+    a: &[f32],
+    lda: Int,
+    x: &[f32],
+    incx: Int,
+    beta: f32,
+    y: &mut [f32],
+    incy: Int,
+) {
+    crate::sgemv(trans.into(), m, n, alpha, a, lda, x, incx, beta, y, incy)
+}
+
+#[inline]
+pub unsafe fn sgbmv(
+    trans: Transpose,
+    m: Int,
+    n: Int,
+    kl: Int,
+    ku: Int,
+    alpha: f32,
+    a: &[f32],
+    lda: Int,
+    x: &[f32],
+    incx: Int,
+    beta: f32,
+    y: &mut [f32],
+    incy: Int,
+) {
+    crate::sgbmv(
+        trans.into(),
+        m,
+        n,
+        kl,
+        ku,
+        alpha,
+        a,
+        lda,
+        x,
+        incx,
+        beta,
+        y,
+        incy,
+    )
+}
+
+#[inline]
+pub unsafe fn ssymv(
+    uplo: Uplo,
+    n: Int,
+    alpha: f32,
+    a: &[f32],
+    lda: Int,
+    x: &[f32],
+    incx: Int,
+    beta: f32,
+    y: &mut [f32],
+    incy: Int,
+) {
+    crate::ssymv(uplo.into(), n, alpha, a, lda, x, incx, beta, y, incy)
+}
+
+#[inline]
+pub unsafe fn ssbmv(
+    uplo: Uplo,
+    n: Int,
+    k: Int,
+    alpha: f32,
+    a: &[f32],
+    lda: Int,
+    x: &[f32],
+    incx: Int,
+    beta: f32,
+    y: &mut [f32],
+    incy: Int,
+) {
+    crate::ssbmv(uplo.into(), n, k, alpha, a, lda, x, incx, beta, y, incy)
+}
+
+#[inline]
+pub unsafe fn sspmv(
+    uplo: Uplo,
+    n: Int,
+    alpha: f32,
+    ap: &[f32],
+    x: &[f32],
+    incx: Int,
+    beta: f32,
+    y: &mut [f32],
+    incy: Int,
+) {
+    crate::sspmv(uplo.into(), n, alpha, ap, x, incx, beta, y, incy)
+}
+
+#[inline]
+pub unsafe fn strmv(
+    uplo: Uplo,
+    transa: Transpose,
+    diag: Diag,
+    n: Int,
+    a: &[f32],
+    lda: Int,
+    b: &mut [f32],
+    incx: Int,
+) {
+    crate::strmv(uplo.into(), transa.into(), diag.into(), n, a, lda, b, incx)
+}
+
+#[inline]
+pub unsafe fn stbmv(
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    n: Int,
+    k: Int,
+    a: &[f32],
+    lda: Int,
+    x: &mut [f32],
+    incx: Int,
+) {
+    crate::stbmv(
+        uplo.into(),
+        trans.into(),
+        diag.into(),
+        n,
+        k,
+        a,
+        lda,
+        x,
+        incx,
+    )
+}
+
+#[inline]
+pub unsafe fn stpmv(
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    n: Int,
+    ap: &[f32],
+    x: &mut [f32],
+    incx: Int,
+) {
+    crate::stpmv(uplo.into(), trans.into(), diag.into(), n, ap, x, incx)
+}
+
+#[inline]
+pub unsafe fn strsv(
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    n: Int,
+    a: &[f32],
+    lda: Int,
+    x: &mut [f32],
+    incx: Int,
+) {
+    crate::strsv(uplo.into(), trans.into(), diag.into(), n, a, lda, x, incx)
+}
+
+#[inline]
+pub unsafe fn stbsv(
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    n: Int,
+    k: Int,
+    a: &[f32],
+    lda: Int,
+    x: &mut [f32],
+    incx: Int,
+) {
+    crate::stbsv(
+        uplo.into(),
+        trans.into(),
+        diag.into(),
+        n,
+        k,
+        a,
+        lda,
+        x,
+        incx,
+    )
+}
+
+#[inline]
+pub unsafe fn stpsv(
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    n: Int,
+    ap: &[f32],
+    x: &mut [f32],
+    incx: Int,
+) {
+    crate::stpsv(uplo.into(), trans.into(), diag.into(), n, ap, x, incx)
+}
+
+#[inline]
+pub unsafe fn sger(
+    m: Int,
+    n: Int,
+    alpha: f32,
+    x: &[f32],
+    incx: Int,
+    y: &[f32],
+    incy: Int,
+    a: &mut [f32],
+    lda: Int,
+) {
+    crate::sger(m, n, alpha, x, incx, y, incy, a, lda)
+}
+
+#[inline]
+pub unsafe fn ssyr(uplo: Uplo, n: Int, alpha: f32, x: &[f32], incx: Int, a: &mut [f32], lda: Int) {
+    crate::ssyr(uplo.into(), n, alpha, x, incx, a, lda)
+}
+
+#[inline]
+pub unsafe fn sspr(uplo: Uplo, n: Int, alpha: f32, x: &[f32], incx: Int, ap: &mut [f32]) {
+    crate::sspr(uplo.into(), n, alpha, x, incx, ap)
+}
+
+#[inline]
+pub unsafe fn ssyr2(
+    uplo: Uplo,
+    n: Int,
+    alpha: f32,
+    x: &[f32],
+    incx: Int,
+    y: &[f32],
+    incy: Int,
+    a: &mut [f32],
+    lda: Int,
+) {
+    crate::ssyr2(uplo.into(), n, alpha, x, incx, y, incy, a, lda)
+}
+
+#[inline]
+pub unsafe fn sspr2(
+    uplo: Uplo,
+    n: Int,
+    alpha: f32,
+    x: &[f32],
+    incx: Int,
+    y: &[f32],
+    incy: Int,
+    ap: &mut [f32],
+) {
+    crate::sspr2(uplo.into(), n, alpha, x, incx, y, incy, ap)
+}
+
+#[inline]
+pub unsafe fn dgemv(
+    trans: Transpose,
+    m: Int,
+    n: Int,
+    alpha: f64,
+    a: &[f64],
+    lda: Int,
+    x: &[f64],
+    incx: Int,
+    beta: f64,
+    y: &mut [f64],
+    incy: Int,
+) {
+    crate::dgemv(trans.into(), m, n, alpha, a, lda, x, incx, beta, y, incy)
+}
+
+#[inline]
+pub unsafe fn dgbmv(
+    trans: Transpose,
+    m: Int,
+    n: Int,
+    kl: Int,
+    ku: Int,
+    alpha: f64,
+    a: &[f64],
+    lda: Int,
+    x: &[f64],
+    incx: Int,
+    beta: f64,
+    y: &mut [f64],
+    incy: Int,
+) {
+    crate::dgbmv(
+        trans.into(),
+        m,
+        n,
+        kl,
+        ku,
+        alpha,
+        a,
+        lda,
+        x,
+        incx,
+        beta,
+        y,
+        incy,
+    )
+}
+
+#[inline]
+pub unsafe fn dsymv(
+    uplo: Uplo,
+    n: Int,
+    alpha: f64,
+    a: &[f64],
+    lda: Int,
+    x: &[f64],
+    incx: Int,
+    beta: f64,
+    y: &mut [f64],
+    incy: Int,
+) {
+    crate::dsymv(uplo.into(), n, alpha, a, lda, x, incx, beta, y, incy)
+}
+
+#[inline]
+pub unsafe fn dsbmv(
+    uplo: Uplo,
+    n: Int,
+    k: Int,
+    alpha: f64,
+    a: &[f64],
+    lda: Int,
+    x: &[f64],
+    incx: Int,
+    beta: f64,
+    y: &mut [f64],
+    incy: Int,
+) {
+    crate::dsbmv(uplo.into(), n, k, alpha, a, lda, x, incx, beta, y, incy)
+}
+
+#[inline]
+pub unsafe fn dspmv(
+    uplo: Uplo,
+    n: Int,
+    alpha: f64,
+    ap: &[f64],
+    x: &[f64],
+    incx: Int,
+    beta: f64,
+    y: &mut [f64],
+    incy: Int,
+) {
+    crate::dspmv(uplo.into(), n, alpha, ap, x, incx, beta, y, incy)
+}
+
+#[inline]
+pub unsafe fn dtrmv(
+    uplo: Uplo,
+    transa: Transpose,
+    diag: Diag,
+    n: Int,
+    a: &[f64],
+    lda: Int,
+    b: &mut [f64],
+    incx: Int,
+) {
+    crate::dtrmv(uplo.into(), transa.into(), diag.into(), n, a, lda, b, incx)
+}
+
+#[inline]
+pub unsafe fn dtbmv(
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    n: Int,
+    k: Int,
+    a: &[f64],
+    lda: Int,
+    x: &mut [f64],
+    incx: Int,
+) {
+    crate::dtbmv(
+        uplo.into(),
+        trans.into(),
+        diag.into(),
+        n,
+        k,
+        a,
+        lda,
+        x,
+        incx,
+    )
+}
+
+#[inline]
+pub unsafe fn dtpmv(
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    n: Int,
+    ap: &[f64],
+    x: &mut [f64],
+    incx: Int,
+) {
+    crate::dtpmv(uplo.into(), trans.into(), diag.into(), n, ap, x, incx)
+}
+
+#[inline]
+pub unsafe fn dtrsv(
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    n: Int,
+    a: &[f64],
+    lda: Int,
+    x: &mut [f64],
+    incx: Int,
+) {
+    crate::dtrsv(uplo.into(), trans.into(), diag.into(), n, a, lda, x, incx)
+}
+
+#[inline]
+pub unsafe fn dtbsv(
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    n: Int,
+    k: Int,
+    a: &[f64],
+    lda: Int,
+    x: &mut [f64],
+    incx: Int,
+) {
+    crate::dtbsv(
+        uplo.into(),
+        trans.into(),
+        diag.into(),
+        n,
+        k,
+        a,
+        lda,
+        x,
+        incx,
+    )
+}
+
+#[inline]
+pub unsafe fn dtpsv(
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    n: Int,
+    ap: &[f64],
+    x: &mut [f64],
+    incx: Int,
+) {
+    crate::dtpsv(uplo.into(), trans.into(), diag.into(), n, ap, x, incx)
+}
+
+#[inline]
+pub unsafe fn dger(
+    m: Int,
+    n: Int,
+    alpha: f64,
+    x: &[f64],
+    incx: Int,
+    y: &[f64],
+    incy: Int,
+    a: &mut [f64],
+    lda: Int,
+) {
+    crate::dger(m, n, alpha, x, incx, y, incy, a, lda)
+}
+
+#[inline]
+pub unsafe fn dsyr(uplo: Uplo, n: Int, alpha: f64, x: &[f64], incx: Int, a: &mut [f64], lda: Int) {
+    crate::dsyr(uplo.into(), n, alpha, x, incx, a, lda)
+}
+
+#[inline]
+pub unsafe fn dspr(uplo: Uplo, n: Int, alpha: f64, x: &[f64], incx: Int, ap: &mut [f64]) {
+    crate::dspr(uplo.into(), n, alpha, x, incx, ap)
+}
+
+#[inline]
+pub unsafe fn dsyr2(
+    uplo: Uplo,
+    n: Int,
+    alpha: f64,
+    x: &[f64],
+    incx: Int,
+    y: &[f64],
+    incy: Int,
+    a: &mut [f64],
+    lda: Int,
+) {
+    crate::dsyr2(uplo.into(), n, alpha, x, incx, y, incy, a, lda)
+}
+
+#[inline]
+pub unsafe fn dspr2(
+    uplo: Uplo,
+    n: Int,
+    alpha: f64,
+    x: &[f64],
+    incx: Int,
+    y: &[f64],
+    incy: Int,
+    ap: &mut [f64],
+) {
+    crate::dspr2(uplo.into(), n, alpha, x, incx, y, incy, ap)
+}
+
+#[inline]
+pub unsafe fn cgemv(
+    trans: Transpose,
+    m: Int,
+    n: Int,
+    alpha: c32,
+    a: &[c32],
+    lda: Int,
+    x: &[c32],
+    incx: Int,
+    beta: c32,
+    y: &mut [c32],
+    incy: Int,
+) {
+    crate::cgemv(trans.into(), m, n, alpha, a, lda, x, incx, beta, y, incy)
+}
+
+#[inline]
+pub unsafe fn cgbmv(
+    trans: Transpose,
+    m: Int,
+    n: Int,
+    kl: Int,
+    ku: Int,
+    alpha: c32,
+    a: &[c32],
+    lda: Int,
+    x: &[c32],
+    incx: Int,
+    beta: c32,
+    y: &mut [c32],
+    incy: Int,
+) {
+    crate::cgbmv(
+        trans.into(),
+        m,
+        n,
+        kl,
+        ku,
+        alpha,
+        a,
+        lda,
+        x,
+        incx,
+        beta,
+        y,
+        incy,
+    )
+}
+
+#[inline]
+pub unsafe fn chemv(
+    uplo: Uplo,
+    n: Int,
+    alpha: c32,
+    a: &[c32],
+    lda: Int,
+    x: &[c32],
+    incx: Int,
+    beta: c32,
+    y: &mut [c32],
+    incy: Int,
+) {
+    crate::chemv(uplo.into(), n, alpha, a, lda, x, incx, beta, y, incy)
+}
+
+#[inline]
+pub unsafe fn chbmv(
+    uplo: Uplo,
+    n: Int,
+    k: Int,
+    alpha: c32,
+    a: &[c32],
+    lda: Int,
+    x: &[c32],
+    incx: Int,
+    beta: c32,
+    y: &mut [c32],
+    incy: Int,
+) {
+    crate::chbmv(uplo.into(), n, k, alpha, a, lda, x, incx, beta, y, incy)
+}
+
+#[inline]
+pub unsafe fn chpmv(
+    uplo: Uplo,
+    n: Int,
+    alpha: c32,
+    ap: &[c32],
+    x: &[c32],
+    incx: Int,
+    beta: c32,
+    y: &mut [c32],
+    incy: Int,
+) {
+    crate::chpmv(uplo.into(), n, alpha, ap, x, incx, beta, y, incy)
+}
+
+#[inline]
+pub unsafe fn ctrmv(
+    uplo: Uplo,
+    transa: Transpose,
+    diag: Diag,
+    n: Int,
+    a: &[c32],
+    lda: Int,
+    b: &mut [c32],
+    incx: Int,
+) {
+    crate::ctrmv(uplo.into(), transa.into(), diag.into(), n, a, lda, b, incx)
+}
+
+#[inline]
+pub unsafe fn ctbmv(
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    n: Int,
+    k: Int,
+    a: &[c32],
+    lda: Int,
+    x: &mut [c32],
+    incx: Int,
+) {
+    crate::ctbmv(
+        uplo.into(),
+        trans.into(),
+        diag.into(),
+        n,
+        k,
+        a,
+        lda,
+        x,
+        incx,
+    )
+}
+
+#[inline]
+pub unsafe fn ctpmv(
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    n: Int,
+    ap: &[c32],
+    x: &mut [c32],
+    incx: Int,
+) {
+    crate::ctpmv(uplo.into(), trans.into(), diag.into(), n, ap, x, incx)
+}
+
+#[inline]
+pub unsafe fn ctrsv(
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    n: Int,
+    a: &[c32],
+    lda: Int,
+    x: &mut [c32],
+    incx: Int,
+) {
+    crate::ctrsv(uplo.into(), trans.into(), diag.into(), n, a, lda, x, incx)
+}
+
+#[inline]
+pub unsafe fn ctbsv(
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    n: Int,
+    k: Int,
+    a: &[c32],
+    lda: Int,
+    x: &mut [c32],
+    incx: Int,
+) {
+    crate::ctbsv(
+        uplo.into(),
+        trans.into(),
+        diag.into(),
+        n,
+        k,
+        a,
+        lda,
+        x,
+        incx,
+    )
+}
+
+#[inline]
+pub unsafe fn ctpsv(
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    n: Int,
+    ap: &[c32],
+    x: &mut [c32],
+    incx: Int,
+) {
+    crate::ctpsv(uplo.into(), trans.into(), diag.into(), n, ap, x, incx)
+}
+
+#[inline]
+pub unsafe fn cgeru(
+    m: Int,
+    n: Int,
+    alpha: c32,
+    x: &[c32],
+    incx: Int,
+    y: &[c32],
+    incy: Int,
+    a: &mut [c32],
+    lda: Int,
+) {
+    crate::cgeru(m, n, alpha, x, incx, y, incy, a, lda)
+}
+
+#[inline]
+pub unsafe fn cgerc(
+    m: Int,
+    n: Int,
+    alpha: c32,
+    x: &[c32],
+    incx: Int,
+    y: &[c32],
+    incy: Int,
+    a: &mut [c32],
+    lda: Int,
+) {
+    crate::cgerc(m, n, alpha, x, incx, y, incy, a, lda)
+}
+
+#[inline]
+pub unsafe fn cher(uplo: Uplo, n: Int, alpha: f32, x: &[c32], incx: Int, a: &mut [c32], lda: Int) {
+    crate::cher(uplo.into(), n, alpha, x, incx, a, lda)
+}
+
+#[inline]
+pub unsafe fn chpr(uplo: Uplo, n: Int, alpha: f32, x: &[c32], incx: Int, ap: &mut [c32]) {
+    crate::chpr(uplo.into(), n, alpha, x, incx, ap)
+}
+
+#[inline]
+pub unsafe fn chpr2(
+    uplo: Uplo,
+    n: Int,
+    alpha: c32,
+    x: &[c32],
+    incx: Int,
+    y: &[c32],
+    incy: Int,
+    ap: &mut [c32],
+) {
+    crate::chpr2(uplo.into(), n, alpha, x, incx, y, incy, ap)
+}
+
+#[inline]
+pub unsafe fn cher2(
+    uplo: Uplo,
+    n: Int,
+    alpha: c32,
+    x: &[c32],
+    incx: Int,
+    y: &[c32],
+    incy: Int,
+    a: &mut [c32],
+    lda: Int,
+) {
+    crate::cher2(uplo.into(), n, alpha, x, incx, y, incy, a, lda)
+}
+
+#[inline]
+pub unsafe fn zgemv(
+    trans: Transpose,
+    m: Int,
+    n: Int,
+    alpha: c64,
+    a: &[c64],
+    lda: Int,
+    x: &[c64],
+    incx: Int,
+    beta: c64,
+    y: &mut [c64],
+    incy: Int,
+) {
+    crate::zgemv(trans.into(), m, n, alpha, a, lda, x, incx, beta, y, incy)
+}
+
+#[inline]
+pub unsafe fn zgbmv(
+    trans: Transpose,
+    m: Int,
+    n: Int,
+    kl: Int,
+    ku: Int,
+    alpha: c64,
+    a: &[c64],
+    lda: Int,
+    x: &[c64],
+    incx: Int,
+    beta: c64,
+    y: &mut [c64],
+    incy: Int,
+) {
+    crate::zgbmv(
+        trans.into(),
+        m,
+        n,
+        kl,
+        ku,
+        alpha,
+        a,
+        lda,
+        x,
+        incx,
+        beta,
+        y,
+        incy,
+    )
+}
+
+#[inline]
+pub unsafe fn zhemv(
+    uplo: Uplo,
+    n: Int,
+    alpha: c64,
+    a: &[c64],
+    lda: Int,
+    x: &[c64],
+    incx: Int,
+    beta: c64,
+    y: &mut [c64],
+    incy: Int,
+) {
+    crate::zhemv(uplo.into(), n, alpha, a, lda, x, incx, beta, y, incy)
+}
+
+#[inline]
+pub unsafe fn zhbmv(
+    uplo: Uplo,
+    n: Int,
+    k: Int,
+    alpha: c64,
+    a: &[c64],
+    lda: Int,
+    x: &[c64],
+    incx: Int,
+    beta: c64,
+    y: &mut [c64],
+    incy: Int,
+) {
+    crate::zhbmv(uplo.into(), n, k, alpha, a, lda, x, incx, beta, y, incy)
+}
+
+#[inline]
+pub unsafe fn zhpmv(
+    uplo: Uplo,
+    n: Int,
+    alpha: c64,
+    ap: &[c64],
+    x: &[c64],
+    incx: Int,
+    beta: c64,
+    y: &mut [c64],
+    incy: Int,
+) {
+    crate::zhpmv(uplo.into(), n, alpha, ap, x, incx, beta, y, incy)
+}
+
+#[inline]
+pub unsafe fn ztrmv(
+    uplo: Uplo,
+    transa: Transpose,
+    diag: Diag,
+    n: Int,
+    a: &[c64],
+    lda: Int,
+    b: &mut [c64],
+    incx: Int,
+) {
+    crate::ztrmv(uplo.into(), transa.into(), diag.into(), n, a, lda, b, incx)
+}
+
+#[inline]
+pub unsafe fn ztbmv(
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    n: Int,
+    k: Int,
+    a: &[c64],
+    lda: Int,
+    x: &mut [c64],
+    incx: Int,
+) {
+    crate::ztbmv(
+        uplo.into(),
+        trans.into(),
+        diag.into(),
+        n,
+        k,
+        a,
+        lda,
+        x,
+        incx,
+    )
+}
+
+#[inline]
+pub unsafe fn ztpmv(
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    n: Int,
+    ap: &[c64],
+    x: &mut [c64],
+    incx: Int,
+) {
+    crate::ztpmv(uplo.into(), trans.into(), diag.into(), n, ap, x, incx)
+}
+
+#[inline]
+pub unsafe fn ztrsv(
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    n: Int,
+    a: &[c64],
+    lda: Int,
+    x: &mut [c64],
+    incx: Int,
+) {
+    crate::ztrsv(uplo.into(), trans.into(), diag.into(), n, a, lda, x, incx)
+}
+
+#[inline]
+pub unsafe fn ztbsv(
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    n: Int,
+    k: Int,
+    a: &[c64],
+    lda: Int,
+    x: &mut [c64],
+    incx: Int,
+) {
+    crate::ztbsv(
+        uplo.into(),
+        trans.into(),
+        diag.into(),
+        n,
+        k,
+        a,
+        lda,
+        x,
+        incx,
+    )
+}
+
+#[inline]
+pub unsafe fn ztpsv(
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    n: Int,
+    ap: &[c64],
+    x: &mut [c64],
+    incx: Int,
+) {
+    crate::ztpsv(uplo.into(), trans.into(), diag.into(), n, ap, x, incx)
+}
+
+#[inline]
+pub unsafe fn zgeru(
+    m: Int,
+    n: Int,
+    alpha: c64,
+    x: &[c64],
+    incx: Int,
+    y: &[c64],
+    incy: Int,
+    a: &mut [c64],
+    lda: Int,
+) {
+    crate::zgeru(m, n, alpha, x, incx, y, incy, a, lda)
+}
+
+#[inline]
+pub unsafe fn zgerc(
+    m: Int,
+    n: Int,
+    alpha: c64,
+    x: &[c64],
+    incx: Int,
+    y: &[c64],
+    incy: Int,
+    a: &mut [c64],
+    lda: Int,
+) {
+    crate::zgerc(m, n, alpha, x, incx, y, incy, a, lda)
+}
+
+#[inline]
+pub unsafe fn zher(uplo: Uplo, n: Int, alpha: f64, x: &[c64], incx: Int, a: &mut [c64], lda: Int) {
+    crate::zher(uplo.into(), n, alpha, x, incx, a, lda)
+}
+
+#[inline]
+pub unsafe fn zhpr(uplo: Uplo, n: Int, alpha: f64, x: &[c64], incx: Int, ap: &mut [c64]) {
+    crate::zhpr(uplo.into(), n, alpha, x, incx, ap)
+}
+
+#[inline]
+pub unsafe fn zher2(
+    uplo: Uplo,
+    n: Int,
+    alpha: c64,
+    x: &[c64],
+    incx: Int,
+    y: &[c64],
+    incy: Int,
+    a: &mut [c64],
+    lda: Int,
+) {
+    crate::zher2(uplo.into(), n, alpha, x, incx, y, incy, a, lda)
+}
+
+#[inline]
+pub unsafe fn zhpr2(
+    uplo: Uplo,
+    n: Int,
+    alpha: c64,
+    x: &[c64],
+    incx: Int,
+    y: &[c64],
+    incy: Int,
+    ap: &mut [c64],
+) {
+    crate::zhpr2(uplo.into(), n, alpha, x, incx, y, incy, ap)
+}
@@ -0,0 +1,833 @@
+use crate::{c32, c64, Diag, Int, NoneOrConj, NoneOrTrans, Side, Transpose, Uplo};
+
+#[inline]
+pub unsafe fn sgemm(
+    transa: Transpose,
+    transb: Transpose,
+    m: Int,
+    n: Int,
+    k: Int,
+    alpha: f32,
+    a: &[f32],
+    lda: Int,
+    b: &[f32],
+    ldb: Int,
+    beta: f32,
+    c: &mut [f32],
+    ldc: Int,
+) {
+    crate::sgemm(
+        transa.into(),
+        transb.into(),
+        m,
+        n,
+        k,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+        beta,
+        c,
+        ldc,
+    )
+}
+
+#[inline]
+pub unsafe fn ssymm(
+    side: Side,
+    uplo: Uplo,
+    m: Int,
+    n: Int,
+    alpha: f32,
+    a: &[f32],
+    lda: Int,
+    b: &[f32],
+    ldb: Int,
+    beta: f32,
+    c: &mut [f32],
+    ldc: Int,
+) {
+    crate::ssymm(
+        side.into(),
+        uplo.into(),
+        m,
+        n,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+        beta,
+        c,
+        ldc,
+    )
+}
+
+#[inline]
+pub unsafe fn ssyrk(
+    uplo: Uplo,
+    trans: Transpose,
+    n: Int,
+    k: Int,
+    alpha: f32,
+    a: &[f32],
+    lda: Int,
+    beta: f32,
+    c: &mut [f32],
+    ldc: Int,
+) {
+    crate::ssyrk(uplo.into(), trans.into(), n, k, alpha, a, lda, beta, c, ldc)
+}
+
+#[inline]
+pub unsafe fn ssyr2k(
+    uplo: Uplo,
+    trans: Transpose,
+    n: Int,
+    k: Int,
+    alpha: f32,
+    a: &[f32],
+    lda: Int,
+    b: &[f32],
+    ldb: Int,
+    beta: f32,
+    c: &mut [f32],
+    ldc: Int,
+) {
+    crate::ssyr2k(
+        uplo.into(),
+        trans.into(),
+        n,
+        k,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+        beta,
+        c,
+        ldc,
+    )
+}
+
+#[inline]
+pub unsafe fn strmm(
+    side: Side,
+    uplo: Uplo,
+    transa: Transpose,
+    diag: Diag,
+    m: Int,
+    n: Int,
+    alpha: f32,
+    a: &[f32],
+    lda: Int,
+    b: &mut [f32],
+    ldb: Int,
+) {
+    crate::strmm(
+        side.into(),
+        uplo.into(),
+        transa.into(),
+        diag.into(),
+        m,
+        n,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+    )
+}
+
+#[inline]
+pub unsafe fn strsm(
+    side: Side,
+    uplo: Uplo,
+    transa: Transpose,
+    diag: Diag,
+    m: Int,
+    n: Int,
+    alpha: f32,
+    a: &[f32],
+    lda: Int,
+    b: &mut [f32],
+    ldb: Int,
+) {
+    crate::strsm(
+        side.into(),
+        uplo.into(),
+        transa.into(),
+        diag.into(),
+        m,
+        n,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+    )
+}
+
+#[inline]
+pub unsafe fn dgemm(
+    transa: Transpose,
+    transb: Transpose,
+    m: Int,
+    n: Int,
+    k: Int,
+    alpha: f64,
+    a: &[f64],
+    lda: Int,
+    b: &[f64],
+    ldb: Int,
+    beta: f64,
+    c: &mut [f64],
+    ldc: Int,
+) {
+    crate::dgemm(
+        transa.into(),
+        transb.into(),
+        m,
+        n,
+        k,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+        beta,
+        c,
+        ldc,
+    )
+}
+
+#[inline]
+pub unsafe fn dsymm(
+    side: Side,
+    uplo: Uplo,
+    m: Int,
+    n: Int,
+    alpha: f64,
+    a: &[f64],
+    lda: Int,
+    b: &[f64],
+    ldb: Int,
+    beta: f64,
+    c: &mut [f64],
+    ldc: Int,
+) {
+    crate::dsymm(
+        side.into(),
+        uplo.into(),
+        m,
+        n,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+        beta,
+        c,
+        ldc,
+    )
+}
+
+#[inline]
+pub unsafe fn dsyrk(
+    uplo: Uplo,
+    trans: Transpose,
+    n: Int,
+    k: Int,
+    alpha: f64,
+    a: &[f64],
+    lda: Int,
+    beta: f64,
+    c: &mut [f64],
+    ldc: Int,
+) {
+    crate::dsyrk(uplo.into(), trans.into(), n, k, alpha, a, lda, beta, c, ldc)
+}
+
+#[inline]
+pub unsafe fn dsyr2k(
+    uplo: Uplo,
+    trans: Transpose,
+    n: Int,
+    k: Int,
+    alpha: f64,
+    a: &[f64],
+    lda: Int,
+    b: &[f64],
+    ldb: Int,
+    beta: f64,
+    c: &mut [f64],
+    ldc: Int,
+) {
+    crate::dsyr2k(
+        uplo.into(),
+        trans.into(),
+        n,
+        k,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+        beta,
+        c,
+        ldc,
+    )
+}
+
+#[inline]
+pub unsafe fn dtrmm(
+    side: Side,
+    uplo: Uplo,
+    transa: Transpose,
+    diag: Diag,
+    m: Int,
+    n: Int,
+    alpha: f64,
+    a: &[f64],
+    lda: Int,
+    b: &mut [f64],
+    ldb: Int,
+) {
+    crate::dtrmm(
+        side.into(),
+        uplo.into(),
+        transa.into(),
+        diag.into(),
+        m,
+        n,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+    )
+}
+
+#[inline]
+pub unsafe fn dtrsm(
+    side: Side,
+    uplo: Uplo,
+    transa: Transpose,
+    diag: Diag,
+    m: Int,
+    n: Int,
+    alpha: f64,
+    a: &[f64],
+    lda: Int,
+    b: &mut [f64],
+    ldb: Int,
+) {
+    crate::dtrsm(
+        side.into(),
+        uplo.into(),
+        transa.into(),
+        diag.into(),
+        m,
+        n,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+    )
+}
+
+#[inline]
+pub unsafe fn cgemm(
+    transa: Transpose,
+    transb: Transpose,
+    m: Int,
+    n: Int,
+    k: Int,
+    alpha: c32,
+    a: &[c32],
+    lda: Int,
+    b: &[c32],
+    ldb: Int,
+    beta: c32,
+    c: &mut [c32],
+    ldc: Int,
+) {
+    crate::cgemm(
+        transa.into(),
+        transb.into(),
+        m,
+        n,
+        k,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+        beta,
+        c,
+        ldc,
+    )
+}
+
+#[inline]
+pub unsafe fn csymm(
+    side: Side,
+    uplo: Uplo,
+    m: Int,
+    n: Int,
+    alpha: c32,
+    a: &[c32],
+    lda: Int,
+    b: &[c32],
+    ldb: Int,
+    beta: c32,
+    c: &mut [c32],
+    ldc: Int,
+) {
+    crate::csymm(
+        side.into(),
+        uplo.into(),
+        m,
+        n,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+        beta,
+        c,
+        ldc,
+    )
+}
+
+#[inline]
+pub unsafe fn chemm(
+    side: Side,
+    uplo: Uplo,
+    m: Int,
+    n: Int,
+    alpha: c32,
+    a: &[c32],
+    lda: Int,
+    b: &[c32],
+    ldb: Int,
+    beta: c32,
+    c: &mut [c32],
+    ldc: Int,
+) {
+    crate::chemm(
+        side.into(),
+        uplo.into(),
+        m,
+        n,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+        beta,
+        c,
+        ldc,
+    )
+}
+
+#[inline]
+pub unsafe fn csyrk(
+    uplo: Uplo,
+    trans: NoneOrTrans,
+    n: Int,
+    k: Int,
+    alpha: c32,
+    a: &[c32],
+    lda: Int,
+    beta: c32,
+    c: &mut [c32],
+    ldc: Int,
+) {
+    crate::csyrk(uplo.into(), trans.into(), n, k, alpha, a, lda, beta, c, ldc)
+}
+
+#[inline]
+pub unsafe fn cherk(
+    uplo: Uplo,
+    trans: NoneOrConj,
+    n: Int,
+    k: Int,
+    alpha: f32,
+    a: &[c32],
+    lda: Int,
+    beta: f32,
+    c: &mut [c32],
+    ldc: Int,
+) {
+    crate::cherk(uplo.into(), trans.into(), n, k, alpha, a, lda, beta, c, ldc)
+}
+
+#[inline]
+pub unsafe fn csyr2k(
+    uplo: Uplo,
+    trans: NoneOrTrans,
+    n: Int,
+    k: Int,
+    alpha: c32,
+    a: &[c32],
+    lda: Int,
+    b: &[c32],
+    ldb: Int,
+    beta: c32,
+    c: &mut [c32],
+    ldc: Int,
+) {
+    crate::csyr2k(
+        uplo.into(),
+        trans.into(),
+        n,
+        k,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+        beta,
+        c,
+        ldc,
+    )
+}
+
+#[inline]
+pub unsafe fn cher2k(
+    uplo: Uplo,
+    trans: NoneOrConj,
+    n: Int,
+    k: Int,
+    alpha: c32,
+    a: &[c32],
+    lda: Int,
+    b: &[c32],
+    ldb: Int,
+    beta: f32,
+    c: &mut [c32],
+    ldc: Int,
+) {
+    crate::cher2k(
+        uplo.into(),
+        trans.into(),
+        n,
+        k,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+        beta,
+        c,
+        ldc,
+    )
+}
+
+#[inline]
+pub unsafe fn ctrmm(
+    side: Side,
+    uplo: Uplo,
+    transa: Transpose,
+    diag: Diag,
+    m: Int,
+    n: Int,
+    alpha: c32,
+    a: &[c32],
+    lda: Int,
+    b: &mut [c32],
+    ldb: Int,
+) {
+    crate::ctrmm(
+        side.into(),
+        uplo.into(),
+        transa.into(),
+        diag.into(),
+        m,
+        n,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+    )
+}
+
+#[inline]
+pub unsafe fn ctrsm(
+    side: Side,
+    uplo: Uplo,
+    transa: Transpose,
+    diag: Diag,
+    m: Int,
+    n: Int,
+    alpha: c32,
+    a: &[c32],
+    lda: Int,
+    b: &mut [c32],
+    ldb: Int,
+) {
+    crate::ctrsm(
+        side.into(),
+        uplo.into(),
+        transa.into(),
+        diag.into(),
+        m,
+        n,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+    )
+}
+
+#[inline]
+pub unsafe fn zgemm(
+    transa: Transpose,
+    transb: Transpose,
+    m: Int,
+    n: Int,
+    k: Int,
+    alpha: c64,
+    a: &[c64],
+    lda: Int,
+    b: &[c64],
+    ldb: Int,
+    beta: c64,
+    c: &mut [c64],
+    ldc: Int,
+) {
+    crate::zgemm(
+        transa.into(),
+        transb.into(),
+        m,
+        n,
+        k,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+        beta,
+        c,
+        ldc,
+    )
+}
+
+#[inline]
+pub unsafe fn zsymm(
+    side: Side,
+    uplo: Uplo,
+    m: Int,
+    n: Int,
+    alpha: c64,
+    a: &[c64],
+    lda: Int,
+    b: &[c64],
+    ldb: Int,
+    beta: c64,
+    c: &mut [c64],
+    ldc: Int,
+) {
+    crate::zsymm(
+        side.into(),
+        uplo.into(),
+        m,
+        n,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+        beta,
+        c,
+        ldc,
+    )
+}
+
+#[inline]
+pub unsafe fn zhemm(
+    side: Side,
+    uplo: Uplo,
+    m: Int,
+    n: Int,
+    alpha: c64,
+    a: &[c64],
+    lda: Int,
+    b: &[c64],
+    ldb: Int,
+    beta: c64,
+    c: &mut [c64],
+    ldc: Int,
+) {
+    crate::zhemm(
+        side.into(),
+        uplo.into(),
+        m,
+        n,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+        beta,
+        c,
+        ldc,
+    )
+}
+
+#[inline]
+pub unsafe fn zsyrk(
+    uplo: Uplo,
+    trans: NoneOrTrans,
+    n: Int,
+    k: Int,
+    alpha: c64,
+    a: &[c64],
+    lda: Int,
+    beta: c64,
+    c: &mut [c64],
+    ldc: Int,
+) {
+    crate::zsyrk(uplo.into(), trans.into(), n, k, alpha, a, lda, beta, c, ldc)
+}
+
+#[inline]
+pub unsafe fn zherk(
+    uplo: Uplo,
+    trans: NoneOrConj,
+    n: Int,
+    k: Int,
+    alpha: f64,
+    a: &[c64],
+    lda: Int,
+    beta: f64,
+    c: &mut [c64],
+    ldc: Int,
+) {
+    crate::zherk(uplo.into(), trans.into(), n, k, alpha, a, lda, beta, c, ldc)
+}
+
+#[inline]
+pub unsafe fn zsyr2k(
+    uplo: Uplo,
+    trans: NoneOrTrans,
+    n: Int,
+    k: Int,
+    alpha: c64,
+    a: &[c64],
+    lda: Int,
+    b: &[c64],
+    ldb: Int,
+    beta: c64,
+    c: &mut [c64],
+    ldc: Int,
+) {
+    crate::zsyr2k(
+        uplo.into(),
+        trans.into(),
+        n,
+        k,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+        beta,
+        c,
+        ldc,
+    )
+}
+
+#[inline]
+pub unsafe fn zher2k(
+    uplo: Uplo,
+    trans: NoneOrConj,
+    n: Int,
+    k: Int,
+    alpha: c64,
+    a: &[c64],
+    lda: Int,
+    b: &[c64],
+    ldb: Int,
+    beta: f64,
+    c: &mut [c64],
+    ldc: Int,
+) {
+    crate::zher2k(
+        uplo.into(),
+        trans.into(),
+        n,
+        k,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+        beta,
+        c,
+        ldc,
+    )
+}
+
+#[inline]
+pub unsafe fn ztrmm(
+    side: Side,
+    uplo: Uplo,
+    transa: Transpose,
+    diag: Diag,
+    m: Int,
+    n: Int,
+    alpha: c64,
+    a: &[c64],
+    lda: Int,
+    b: &mut [c64],
+    ldb: Int,
+) {
+    crate::ztrmm(
+        side.into(),
+        uplo.into(),
+        transa.into(),
+        diag.into(),
+        m,
+        n,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+    )
+}
+
+#[inline]
+pub unsafe fn ztrsm(
+    side: Side,
+    uplo: Uplo,
+    transa: Transpose,
+    diag: Diag,
+    m: Int,
+    n: Int,
+    alpha: c64,
+    a: &[c64],
+    lda: Int,
+    b: &mut [c64],
+    ldb: Int,
+) {
+    crate::ztrsm(
+        side.into(),
+        uplo.into(),
+        transa.into(),
+        diag.into(),
+        m,
+        n,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+    )
+}
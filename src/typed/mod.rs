@@ -0,0 +1,35 @@
+//! Wrappers that take typed flags.
+//!
+//! The functions in this module mirror the Level 2 and Level 3 wrappers at the root of the
+//! crate but accept [`Transpose`], [`Uplo`], [`Diag`], and [`Side`] in place of raw character
+//! codes. The rank updates of complex matrices, which admit only two operations, accept
+//! [`NoneOrTrans`] or [`NoneOrConj`] instead of [`Transpose`].
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use blas::typed::*;
+//! use blas::Transpose;
+//!
+//! let (m, n, k) = (2, 4, 3);
+//! let a = vec![1.0; 6];
+//! let b = vec![1.0; 12];
+//! let mut c = vec![0.0; 8];
+//!
+//! unsafe {
+//!     dgemm(Transpose::None, Transpose::None, m, n, k, 1.0, &a, m, &b, k, 0.0, &mut c, m);
+//! }
+//! ```
+//!
+//! [`Transpose`]: crate::Transpose
+//! [`Uplo`]: crate::Uplo
+//! [`Diag`]: crate::Diag
+//! [`Side`]: crate::Side
+//! [`NoneOrTrans`]: crate::NoneOrTrans
+//! [`NoneOrConj`]: crate::NoneOrConj
+
+mod level2;
+mod level3;
+
+pub use level2::*;
+pub use level3::*;
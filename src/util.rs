@@ -0,0 +1,47 @@
+//! Helpers shared by the reference implementation and the fallbacks of the extensions.
+
+use crate::Int;
+
+/// Report an invalid argument, panicking unless it is caught by `catch_blas_errors`.
+///
+/// The name of the routine is reported in uppercase.
+#[cfg(any(
+    not(all(feature = "extension-gemmt", feature = "extension-matcopy")),
+    feature = "reference",
+))]
+#[cold]
+pub(crate) fn xerbla(srname: &str, info: Int) {
+    use core::fmt::{self, Write};
+
+    /// A name displayed in uppercase.
+    struct Uppercase<'a>(&'a str);
+
+    impl fmt::Display for Uppercase<'_> {
+        fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            self.0
+                .chars()
+                .try_for_each(|c| formatter.write_char(c.to_ascii_uppercase()))
+        }
+    }
+
+    #[cfg(feature = "std")]
+    if crate::xerbla::report(&srname.to_ascii_uppercase(), info) {
+        return;
+    }
+    panic!(
+        " ** On entry to {} parameter number {} had an illegal value",
+        Uppercase(srname),
+        info,
+    )
+}
+
+/// Return the offset of the first element visited for a vector with increment `inc`.
+#[cfg(any(not(feature = "extension-axpby"), feature = "reference"))]
+#[inline]
+pub(crate) fn start(n: Int, inc: Int) -> isize {
+    if inc < 0 {
+        (1 - n as isize) * inc as isize
+    } else {
+        0
+    }
+}
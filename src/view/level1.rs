@@ -0,0 +1,74 @@
+use super::{check_match, VecMut, VecRef};
+use crate::safe::{traversed, BlasError};
+use crate::Blas;
+
+#[inline]
+pub fn rot<T: Blas>(x: VecMut<T>, y: VecMut<T>, c: T::Real, s: T::Real) -> Result<(), BlasError> {
+    check_match("rot", 2, y.len, x.len)?;
+    unsafe { T::rot_(x.len, x.ptr, x.inc, y.ptr, y.inc, c, s) };
+    Ok(())
+}
+
+#[inline]
+pub fn swap<T: Blas>(x: VecMut<T>, y: VecMut<T>) -> Result<(), BlasError> {
+    check_match("swap", 2, y.len, x.len)?;
+    unsafe { T::swap_(x.len, x.ptr, x.inc, y.ptr, y.inc) };
+    Ok(())
+}
+
+#[inline]
+pub fn scal<T: Blas>(alpha: T, x: VecMut<T>) {
+    unsafe { T::scal_(x.len, alpha, x.ptr, x.inc.saturating_abs()) }
+}
+
+/// Scale a vector by a real scalar.
+#[inline]
+pub fn rscal<T: Blas>(alpha: T::Real, x: VecMut<T>) {
+    unsafe { T::rscal_(x.len, alpha, x.ptr, x.inc.saturating_abs()) }
+}
+
+#[inline]
+pub fn copy<T: Blas>(x: VecRef<T>, y: VecMut<T>) -> Result<(), BlasError> {
+    check_match("copy", 2, y.len, x.len)?;
+    unsafe { T::copy_(x.len, x.ptr, x.inc, y.ptr, y.inc) };
+    Ok(())
+}
+
+#[inline]
+pub fn axpy<T: Blas>(alpha: T, x: VecRef<T>, y: VecMut<T>) -> Result<(), BlasError> {
+    check_match("axpy", 3, y.len, x.len)?;
+    unsafe { T::axpy_(x.len, alpha, x.ptr, x.inc, y.ptr, y.inc) };
+    Ok(())
+}
+
+#[inline]
+pub fn dot<T: Blas>(x: VecRef<T>, y: VecRef<T>) -> Result<T, BlasError> {
+    check_match("dot", 2, y.len, x.len)?;
+    Ok(unsafe { T::dot_(x.len, x.ptr, x.inc, y.ptr, y.inc) })
+}
+
+#[inline]
+pub fn dotc<T: Blas>(x: VecRef<T>, y: VecRef<T>) -> Result<T, BlasError> {
+    check_match("dotc", 2, y.len, x.len)?;
+    Ok(unsafe { T::dotc_(x.len, x.ptr, x.inc, y.ptr, y.inc) })
+}
+
+#[inline]
+pub fn nrm2<T: Blas>(x: VecRef<T>) -> T::Real {
+    unsafe { T::nrm2_(x.len, x.ptr, x.inc.saturating_abs()) }
+}
+
+#[inline]
+pub fn asum<T: Blas>(x: VecRef<T>) -> T::Real {
+    unsafe { T::asum_(x.len, x.ptr, x.inc.saturating_abs()) }
+}
+
+/// Return the 1-based position of the element with the largest absolute value, which is zero
+/// for an empty vector.
+///
+/// For a reversed vector, the position of the last of several largest elements is returned.
+#[inline]
+pub fn iamax<T: Blas>(x: VecRef<T>) -> usize {
+    let position = unsafe { T::iamax_(x.len, x.ptr, x.inc.saturating_abs()) };
+    traversed(position, x.len, x.inc)
+}
@@ -0,0 +1,336 @@
+use super::{check_match, check_square, op_dimensions, MatMut, MatRef, VecMut, VecRef};
+use crate::safe::{check_dimension, check_len, packed_len, BlasError};
+use crate::{Blas, Diag, Int, Transpose, Uplo};
+
+#[inline]
+pub fn gemv<T: Blas>(
+    trans: Transpose,
+    alpha: T,
+    a: MatRef<T>,
+    x: VecRef<T>,
+    beta: T,
+    y: VecMut<T>,
+) -> Result<(), BlasError> {
+    let routine = "gemv";
+    let (rows, cols) = op_dimensions(trans, &a);
+    check_match(routine, 4, x.len, cols)?;
+    check_match(routine, 6, y.len, rows)?;
+    unsafe {
+        T::gemv_(
+            trans, a.rows, a.cols, alpha, a.ptr, a.ld, x.ptr, x.inc, beta, y.ptr, y.inc,
+        )
+    };
+    Ok(())
+}
+
+/// Multiply a band matrix by a vector.
+///
+/// The columns of `a` hold the `kl + ku + 1` diagonals of the band as in `gbmv`, so `a` has
+/// that many rows and as many columns as the matrix. The number of rows of the matrix is taken
+/// from `y` or, if the matrix is transposed, from `x`.
+#[inline]
+pub fn gbmv<T: Blas>(
+    trans: Transpose,
+    kl: Int,
+    ku: Int,
+    alpha: T,
+    a: MatRef<T>,
+    x: VecRef<T>,
+    beta: T,
+    y: VecMut<T>,
+) -> Result<(), BlasError> {
+    let routine = "gbmv";
+    let m = match trans {
+        Transpose::None => y.len,
+        _ => x.len,
+    };
+    check_dimension(routine, 2, kl)?;
+    check_dimension(routine, 3, ku)?;
+    check_match(routine, 5, a.rows, kl.saturating_add(ku).saturating_add(1))?;
+    let (rows, cols) = match trans {
+        Transpose::None => (m, a.cols),
+        _ => (a.cols, m),
+    };
+    check_match(routine, 6, x.len, cols)?;
+    check_match(routine, 8, y.len, rows)?;
+    unsafe {
+        T::gbmv_(
+            trans, m, a.cols, kl, ku, alpha, a.ptr, a.ld, x.ptr, x.inc, beta, y.ptr, y.inc,
+        )
+    };
+    Ok(())
+}
+
+#[inline]
+pub fn hemv<T: Blas>(
+    uplo: Uplo,
+    alpha: T,
+    a: MatRef<T>,
+    x: VecRef<T>,
+    beta: T,
+    y: VecMut<T>,
+) -> Result<(), BlasError> {
+    let routine = "hemv";
+    let n = check_square(routine, 3, &a)?;
+    check_match(routine, 4, x.len, n)?;
+    check_match(routine, 6, y.len, n)?;
+    unsafe {
+        T::hemv_(
+            uplo, n, alpha, a.ptr, a.ld, x.ptr, x.inc, beta, y.ptr, y.inc,
+        )
+    };
+    Ok(())
+}
+
+/// Multiply a Hermitian band matrix by a vector.
+///
+/// The columns of `a` hold the `k + 1` diagonals of one triangle as in `hbmv`.
+#[inline]
+pub fn hbmv<T: Blas>(
+    uplo: Uplo,
+    k: Int,
+    alpha: T,
+    a: MatRef<T>,
+    x: VecRef<T>,
+    beta: T,
+    y: VecMut<T>,
+) -> Result<(), BlasError> {
+    let routine = "hbmv";
+    check_dimension(routine, 2, k)?;
+    check_match(routine, 4, a.rows, k.saturating_add(1))?;
+    check_match(routine, 5, x.len, a.cols)?;
+    check_match(routine, 7, y.len, a.cols)?;
+    unsafe {
+        T::hbmv_(
+            uplo, a.cols, k, alpha, a.ptr, a.ld, x.ptr, x.inc, beta, y.ptr, y.inc,
+        )
+    };
+    Ok(())
+}
+
+/// Multiply a packed Hermitian matrix by a vector.
+///
+/// The order of the matrix is taken from `x`.
+#[inline]
+pub fn hpmv<T: Blas>(
+    uplo: Uplo,
+    alpha: T,
+    ap: &[T],
+    x: VecRef<T>,
+    beta: T,
+    y: VecMut<T>,
+) -> Result<(), BlasError> {
+    let routine = "hpmv";
+    let n = x.len;
+    check_len(routine, 3, packed_len(n), ap.len())?;
+    check_match(routine, 6, y.len, n)?;
+    unsafe {
+        T::hpmv_(
+            uplo,
+            n,
+            alpha,
+            ap.as_ptr(),
+            x.ptr,
+            x.inc,
+            beta,
+            y.ptr,
+            y.inc,
+        )
+    };
+    Ok(())
+}
+
+#[inline]
+pub fn trmv<T: Blas>(
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    a: MatRef<T>,
+    x: VecMut<T>,
+) -> Result<(), BlasError> {
+    let routine = "trmv";
+    let n = check_square(routine, 4, &a)?;
+    check_match(routine, 5, x.len, n)?;
+    unsafe { T::trmv_(uplo, trans, diag, n, a.ptr, a.ld, x.ptr, x.inc) };
+    Ok(())
+}
+
+/// Multiply a triangular band matrix by a vector.
+///
+/// The columns of `a` hold the `k + 1` diagonals of the triangle as in `tbmv`.
+#[inline]
+pub fn tbmv<T: Blas>(
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    k: Int,
+    a: MatRef<T>,
+    x: VecMut<T>,
+) -> Result<(), BlasError> {
+    let routine = "tbmv";
+    check_dimension(routine, 4, k)?;
+    check_match(routine, 5, a.rows, k.saturating_add(1))?;
+    check_match(routine, 6, x.len, a.cols)?;
+    unsafe { T::tbmv_(uplo, trans, diag, a.cols, k, a.ptr, a.ld, x.ptr, x.inc) };
+    Ok(())
+}
+
+/// Multiply a packed triangular matrix by a vector.
+///
+/// The order of the matrix is taken from `x`.
+#[inline]
+pub fn tpmv<T: Blas>(
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    ap: &[T],
+    x: VecMut<T>,
+) -> Result<(), BlasError> {
+    let n = x.len;
+    check_len("tpmv", 4, packed_len(n), ap.len())?;
+    unsafe { T::tpmv_(uplo, trans, diag, n, ap.as_ptr(), x.ptr, x.inc) };
+    Ok(())
+}
+
+#[inline]
+pub fn trsv<T: Blas>(
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    a: MatRef<T>,
+    x: VecMut<T>,
+) -> Result<(), BlasError> {
+    let routine = "trsv";
+    let n = check_square(routine, 4, &a)?;
+    check_match(routine, 5, x.len, n)?;
+    unsafe { T::trsv_(uplo, trans, diag, n, a.ptr, a.ld, x.ptr, x.inc) };
+    Ok(())
+}
+
+/// Solve a triangular band system.
+///
+/// The columns of `a` hold the `k + 1` diagonals of the triangle as in `tbsv`.
+#[inline]
+pub fn tbsv<T: Blas>(
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    k: Int,
+    a: MatRef<T>,
+    x: VecMut<T>,
+) -> Result<(), BlasError> {
+    let routine = "tbsv";
+    check_dimension(routine, 4, k)?;
+    check_match(routine, 5, a.rows, k.saturating_add(1))?;
+    check_match(routine, 6, x.len, a.cols)?;
+    unsafe { T::tbsv_(uplo, trans, diag, a.cols, k, a.ptr, a.ld, x.ptr, x.inc) };
+    Ok(())
+}
+
+/// Solve a packed triangular system.
+///
+/// The order of the matrix is taken from `x`.
+#[inline]
+pub fn tpsv<T: Blas>(
+    uplo: Uplo,
+    trans: Transpose,
+    diag: Diag,
+    ap: &[T],
+    x: VecMut<T>,
+) -> Result<(), BlasError> {
+    let n = x.len;
+    check_len("tpsv", 4, packed_len(n), ap.len())?;
+    unsafe { T::tpsv_(uplo, trans, diag, n, ap.as_ptr(), x.ptr, x.inc) };
+    Ok(())
+}
+
+#[inline]
+pub fn geru<T: Blas>(alpha: T, x: VecRef<T>, y: VecRef<T>, a: MatMut<T>) -> Result<(), BlasError> {
+    let routine = "geru";
+    check_match(routine, 2, x.len, a.rows)?;
+    check_match(routine, 3, y.len, a.cols)?;
+    unsafe {
+        T::geru_(
+            a.rows, a.cols, alpha, x.ptr, x.inc, y.ptr, y.inc, a.ptr, a.ld,
+        )
+    };
+    Ok(())
+}
+
+#[inline]
+pub fn gerc<T: Blas>(alpha: T, x: VecRef<T>, y: VecRef<T>, a: MatMut<T>) -> Result<(), BlasError> {
+    let routine = "gerc";
+    check_match(routine, 2, x.len, a.rows)?;
+    check_match(routine, 3, y.len, a.cols)?;
+    unsafe {
+        T::gerc_(
+            a.rows, a.cols, alpha, x.ptr, x.inc, y.ptr, y.inc, a.ptr, a.ld,
+        )
+    };
+    Ok(())
+}
+
+#[inline]
+pub fn her<T: Blas>(
+    uplo: Uplo,
+    alpha: T::Real,
+    x: VecRef<T>,
+    a: MatMut<T>,
+) -> Result<(), BlasError> {
+    let routine = "her";
+    let n = check_square(routine, 4, &a.view())?;
+    check_match(routine, 3, x.len, n)?;
+    unsafe { T::her_(uplo, n, alpha, x.ptr, x.inc, a.ptr, a.ld) };
+    Ok(())
+}
+
+/// Perform a rank-1 update of a packed Hermitian matrix.
+///
+/// The order of the matrix is taken from `x`.
+#[inline]
+pub fn hpr<T: Blas>(
+    uplo: Uplo,
+    alpha: T::Real,
+    x: VecRef<T>,
+    ap: &mut [T],
+) -> Result<(), BlasError> {
+    let n = x.len;
+    check_len("hpr", 4, packed_len(n), ap.len())?;
+    unsafe { T::hpr_(uplo, n, alpha, x.ptr, x.inc, ap.as_mut_ptr()) };
+    Ok(())
+}
+
+#[inline]
+pub fn her2<T: Blas>(
+    uplo: Uplo,
+    alpha: T,
+    x: VecRef<T>,
+    y: VecRef<T>,
+    a: MatMut<T>,
+) -> Result<(), BlasError> {
+    let routine = "her2";
+    let n = check_square(routine, 5, &a.view())?;
+    check_match(routine, 3, x.len, n)?;
+    check_match(routine, 4, y.len, n)?;
+    unsafe { T::her2_(uplo, n, alpha, x.ptr, x.inc, y.ptr, y.inc, a.ptr, a.ld) };
+    Ok(())
+}
+
+/// Perform a rank-2 update of a packed Hermitian matrix.
+///
+/// The order of the matrix is taken from `x`.
+#[inline]
+pub fn hpr2<T: Blas>(
+    uplo: Uplo,
+    alpha: T,
+    x: VecRef<T>,
+    y: VecRef<T>,
+    ap: &mut [T],
+) -> Result<(), BlasError> {
+    let routine = "hpr2";
+    let n = x.len;
+    check_match(routine, 4, y.len, n)?;
+    check_len(routine, 5, packed_len(n), ap.len())?;
+    unsafe { T::hpr2_(uplo, n, alpha, x.ptr, x.inc, y.ptr, y.inc, ap.as_mut_ptr()) };
+    Ok(())
+}
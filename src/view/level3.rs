@@ -0,0 +1,235 @@
+use super::{check_match, check_square, op_dimensions, MatMut, MatRef};
+use crate::safe::BlasError;
+use crate::{Blas, Diag, Int, NoneOrConj, NoneOrTrans, Side, Transpose, Uplo};
+
+#[inline]
+pub fn gemm<T: Blas>(
+    transa: Transpose,
+    transb: Transpose,
+    alpha: T,
+    a: MatRef<T>,
+    b: MatRef<T>,
+    beta: T,
+    c: MatMut<T>,
+) -> Result<(), BlasError> {
+    let routine = "gemm";
+    let (m, k) = op_dimensions(transa, &a);
+    let (rows, n) = op_dimensions(transb, &b);
+    check_match(routine, 7, c.rows, m)?;
+    check_match(routine, 5, rows, k)?;
+    check_match(routine, 7, c.cols, n)?;
+    unsafe {
+        T::gemm_(
+            transa, transb, m, n, k, alpha, a.ptr, a.ld, b.ptr, b.ld, beta, c.ptr, c.ld,
+        )
+    };
+    Ok(())
+}
+
+#[inline]
+pub fn symm<T: Blas>(
+    side: Side,
+    uplo: Uplo,
+    alpha: T,
+    a: MatRef<T>,
+    b: MatRef<T>,
+    beta: T,
+    c: MatMut<T>,
+) -> Result<(), BlasError> {
+    let (m, n) = check_side("symm", side, &a, &b, &c)?;
+    unsafe {
+        T::symm_(
+            side, uplo, m, n, alpha, a.ptr, a.ld, b.ptr, b.ld, beta, c.ptr, c.ld,
+        )
+    };
+    Ok(())
+}
+
+#[inline]
+pub fn hemm<T: Blas>(
+    side: Side,
+    uplo: Uplo,
+    alpha: T,
+    a: MatRef<T>,
+    b: MatRef<T>,
+    beta: T,
+    c: MatMut<T>,
+) -> Result<(), BlasError> {
+    let (m, n) = check_side("hemm", side, &a, &b, &c)?;
+    unsafe {
+        T::hemm_(
+            side, uplo, m, n, alpha, a.ptr, a.ld, b.ptr, b.ld, beta, c.ptr, c.ld,
+        )
+    };
+    Ok(())
+}
+
+/// Perform a symmetric rank-k update.
+#[inline]
+pub fn syrk<T: Blas>(
+    uplo: Uplo,
+    trans: NoneOrTrans,
+    alpha: T,
+    a: MatRef<T>,
+    beta: T,
+    c: MatMut<T>,
+) -> Result<(), BlasError> {
+    let routine = "syrk";
+    let (n, k) = check_rank(routine, trans.into(), &a, None, &c)?;
+    unsafe { T::syrk_(uplo, trans, n, k, alpha, a.ptr, a.ld, beta, c.ptr, c.ld) };
+    Ok(())
+}
+
+/// Perform a Hermitian rank-k update.
+#[inline]
+pub fn herk<T: Blas>(
+    uplo: Uplo,
+    trans: NoneOrConj,
+    alpha: T::Real,
+    a: MatRef<T>,
+    beta: T::Real,
+    c: MatMut<T>,
+) -> Result<(), BlasError> {
+    let routine = "herk";
+    let (n, k) = check_rank(routine, trans.into(), &a, None, &c)?;
+    unsafe { T::herk_(uplo, trans, n, k, alpha, a.ptr, a.ld, beta, c.ptr, c.ld) };
+    Ok(())
+}
+
+/// Perform a symmetric rank-2k update.
+#[inline]
+pub fn syr2k<T: Blas>(
+    uplo: Uplo,
+    trans: NoneOrTrans,
+    alpha: T,
+    a: MatRef<T>,
+    b: MatRef<T>,
+    beta: T,
+    c: MatMut<T>,
+) -> Result<(), BlasError> {
+    let routine = "syr2k";
+    let (n, k) = check_rank(routine, trans.into(), &a, Some(&b), &c)?;
+    unsafe {
+        T::syr2k_(
+            uplo, trans, n, k, alpha, a.ptr, a.ld, b.ptr, b.ld, beta, c.ptr, c.ld,
+        )
+    };
+    Ok(())
+}
+
+/// Perform a Hermitian rank-2k update.
+#[inline]
+pub fn her2k<T: Blas>(
+    uplo: Uplo,
+    trans: NoneOrConj,
+    alpha: T,
+    a: MatRef<T>,
+    b: MatRef<T>,
+    beta: T::Real,
+    c: MatMut<T>,
+) -> Result<(), BlasError> {
+    let routine = "her2k";
+    let (n, k) = check_rank(routine, trans.into(), &a, Some(&b), &c)?;
+    unsafe {
+        T::her2k_(
+            uplo, trans, n, k, alpha, a.ptr, a.ld, b.ptr, b.ld, beta, c.ptr, c.ld,
+        )
+    };
+    Ok(())
+}
+
+#[inline]
+pub fn trmm<T: Blas>(
+    side: Side,
+    uplo: Uplo,
+    transa: Transpose,
+    diag: Diag,
+    alpha: T,
+    a: MatRef<T>,
+    b: MatMut<T>,
+) -> Result<(), BlasError> {
+    let routine = "trmm";
+    check_triangular(routine, side, &a, &b)?;
+    unsafe {
+        T::trmm_(
+            side, uplo, transa, diag, b.rows, b.cols, alpha, a.ptr, a.ld, b.ptr, b.ld,
+        )
+    };
+    Ok(())
+}
+
+#[inline]
+pub fn trsm<T: Blas>(
+    side: Side,
+    uplo: Uplo,
+    transa: Transpose,
+    diag: Diag,
+    alpha: T,
+    a: MatRef<T>,
+    b: MatMut<T>,
+) -> Result<(), BlasError> {
+    let routine = "trsm";
+    check_triangular(routine, side, &a, &b)?;
+    unsafe {
+        T::trsm_(
+            side, uplo, transa, diag, b.rows, b.cols, alpha, a.ptr, a.ld, b.ptr, b.ld,
+        )
+    };
+    Ok(())
+}
+
+/// Check the arguments of `symm` and `hemm` and return the dimensions of `C`.
+#[inline]
+fn check_side<T>(
+    routine: &'static str,
+    side: Side,
+    a: &MatRef<T>,
+    b: &MatRef<T>,
+    c: &MatMut<T>,
+) -> Result<(Int, Int), BlasError> {
+    let order = check_square(routine, 4, a)?;
+    check_match(routine, 5, b.rows, c.rows)?;
+    check_match(routine, 5, b.cols, c.cols)?;
+    let expected = match side {
+        Side::Left => c.rows,
+        Side::Right => c.cols,
+    };
+    check_match(routine, 4, order, expected)?;
+    Ok((c.rows, c.cols))
+}
+
+/// Check the arguments of the rank updates and return `n` and `k`.
+#[inline]
+fn check_rank<T>(
+    routine: &'static str,
+    trans: Transpose,
+    a: &MatRef<T>,
+    b: Option<&MatRef<T>>,
+    c: &MatMut<T>,
+) -> Result<(Int, Int), BlasError> {
+    let (n, k) = op_dimensions(trans, a);
+    check_match(routine, 4, n, c.rows)?;
+    if let Some(b) = b {
+        check_match(routine, 5, b.rows, a.rows)?;
+        check_match(routine, 5, b.cols, a.cols)?;
+    }
+    let param = if b.is_some() { 7 } else { 6 };
+    check_match(routine, param, c.cols, c.rows)?;
+    Ok((n, k))
+}
+
+/// Check that the order of a triangular matrix agrees with the side it is applied from.
+#[inline]
+fn check_triangular<T>(
+    routine: &'static str,
+    side: Side,
+    a: &MatRef<T>,
+    b: &MatMut<T>,
+) -> Result<(), BlasError> {
+    let order = check_square(routine, 6, a)?;
+    let expected = match side {
+        Side::Left => b.rows,
+        Side::Right => b.cols,
+    };
+    check_match(routine, 6, order, expected)
+}
@@ -0,0 +1,702 @@
+//! Strided views and wrappers that take them.
+//!
+//! A view couples a slice with the dimensions and the increment or leading dimension that
+//! describe a vector or a column-major matrix stored in it. The layout is validated when the view
+//! is created, and the functions in this module derive the dimensions passed to BLAS from the
+//! views they are given. Views that do not fit together are reported as a [`BlasError`]. The rows,
+//! columns, and diagonals of a matrix view are vector views, to which the Level 1 functions apply.
+//! A matrix view wrapped in [`Symmetric`], [`Hermitian`], or [`Triangular`] records which
+//! triangle is referenced and calls the structured routines with it.
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use blas::view::*;
+//! use blas::Transpose;
+//!
+//! let a = vec![1.0; 6];
+//! let b = vec![1.0; 12];
+//! let mut c = vec![0.0; 8];
+//!
+//! let a = MatRef::new(&a, 2, 3, 2).unwrap();
+//! let b = MatRef::new(&b, 3, 4, 3).unwrap();
+//! let c = MatMut::new(&mut c, 2, 4, 2).unwrap();
+//!
+//! gemm(Transpose::None, Transpose::None, 1.0, a, b, 0.0, c).unwrap();
+//!
+//! let norm = nrm2(b.row(1));
+//! let sum = asum(b.diag(0));
+//! ```
+//!
+//! [`BlasError`]: crate::safe::BlasError
+
+use core::marker::PhantomData;
+use core::slice;
+
+use crate::safe::{
+    check_dimension, check_increment, check_leading_dimension, check_len, matrix_len, vector_len,
+    BlasError, Reason,
+};
+use crate::{Int, Transpose};
+
+mod level1;
+mod level2;
+mod level3;
+mod structured;
+
+pub use level1::*;
+pub use level2::*;
+pub use level3::*;
+pub use structured::{Hermitian, Symmetric, Triangular};
+
+/// An immutable view of a strided vector.
+#[derive(Debug)]
+pub struct VecRef<'a, T> {
+    ptr: *const T,
+    span: Option<usize>,
+    len: Int,
+    inc: Int,
+    marker: PhantomData<&'a [T]>,
+}
+
+/// A mutable view of a strided vector.
+#[derive(Debug)]
+pub struct VecMut<'a, T> {
+    ptr: *mut T,
+    span: Option<usize>,
+    len: Int,
+    inc: Int,
+    marker: PhantomData<&'a mut [T]>,
+}
+
+/// An immutable view of a column-major matrix.
+#[derive(Debug)]
+pub struct MatRef<'a, T> {
+    ptr: *const T,
+    span: Option<usize>,
+    rows: Int,
+    cols: Int,
+    ld: Int,
+    marker: PhantomData<&'a [T]>,
+}
+
+/// A mutable view of a column-major matrix.
+#[derive(Debug)]
+pub struct MatMut<'a, T> {
+    ptr: *mut T,
+    span: Option<usize>,
+    rows: Int,
+    cols: Int,
+    ld: Int,
+    marker: PhantomData<&'a mut [T]>,
+}
+
+// The views are passed to BLAS as raw pointers, which start at the element with the lowest
+// address. The span is the number of elements from there to the last element if the view borrows
+// all of them, including those between its own elements, and `None` otherwise.
+
+impl<T> Clone for VecRef<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for VecRef<'_, T> {}
+
+impl<T> Clone for MatRef<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for MatRef<'_, T> {}
+
+unsafe impl<T: Sync> Send for VecRef<'_, T> {}
+unsafe impl<T: Sync> Sync for VecRef<'_, T> {}
+unsafe impl<T: Send> Send for VecMut<'_, T> {}
+unsafe impl<T: Sync> Sync for VecMut<'_, T> {}
+unsafe impl<T: Sync> Send for MatRef<'_, T> {}
+unsafe impl<T: Sync> Sync for MatRef<'_, T> {}
+unsafe impl<T: Send> Send for MatMut<'_, T> {}
+unsafe impl<T: Sync> Sync for MatMut<'_, T> {}
+
+impl<'a, T> VecRef<'a, T> {
+    /// Create a view of `len` elements that are `inc` elements apart.
+    ///
+    /// As in BLAS, `data` starts with the element at the lowest address whatever the sign of
+    /// `inc`, and a negative `inc` traverses the vector backwards from the element at offset
+    /// `(1 - len) * inc`.
+    pub fn new(data: &'a [T], len: Int, inc: Int) -> Result<Self, BlasError> {
+        check_vector_layout("VecRef::new", data.len(), len, inc)?;
+        Ok(VecRef {
+            ptr: data.as_ptr(),
+            span: Some(data.len()),
+            len,
+            inc,
+            marker: PhantomData,
+        })
+    }
+
+    /// Create a view of the vector whose element with the lowest address is at `ptr`, which
+    /// must be valid for reads of its elements for `'a`.
+    #[cfg(any(feature = "faer", feature = "nalgebra", feature = "ndarray"))]
+    pub(crate) unsafe fn from_raw_parts(ptr: *const T, len: Int, inc: Int) -> Self {
+        VecRef {
+            ptr,
+            span: dense(vector_len(len, inc), len as usize),
+            len,
+            inc,
+            marker: PhantomData,
+        }
+    }
+
+    /// Return the number of elements.
+    #[inline]
+    pub fn len(&self) -> Int {
+        self.len
+    }
+
+    /// Check whether the vector has no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Return the distance between consecutive elements.
+    #[inline]
+    pub fn inc(&self) -> Int {
+        self.inc
+    }
+
+    /// Return the underlying slice.
+    ///
+    /// The function returns `None` for a view converted from a vector of another package whose
+    /// elements are not contiguous, as the memory between them is not borrowed by the view.
+    #[inline]
+    pub fn as_slice(&self) -> Option<&'a [T]> {
+        self.span.map(|span| unsafe { span_of(self.ptr, span) })
+    }
+
+    /// Return a view of the same elements in reverse order.
+    #[inline]
+    pub fn rev(self) -> Self {
+        VecRef {
+            inc: -self.inc,
+            ..self
+        }
+    }
+
+    /// Return element `i` in the order of traversal.
+    #[inline]
+    pub fn get(&self, i: Int) -> Option<&'a T> {
+        offset(self.len, self.inc, i).map(|offset| unsafe { &*self.ptr.add(offset) })
+    }
+
+    /// Return an iterator over the elements in the order of traversal.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + 'a {
+        let view = *self;
+        (0..view.len).map(move |i| view.get(i).unwrap())
+    }
+}
+
+impl<'a, T> VecMut<'a, T> {
+    /// Create a view of `len` elements that are `inc` elements apart.
+    ///
+    /// The elements are laid out as for [`VecRef::new`].
+    pub fn new(data: &'a mut [T], len: Int, inc: Int) -> Result<Self, BlasError> {
+        check_vector_layout("VecMut::new", data.len(), len, inc)?;
+        Ok(VecMut {
+            ptr: data.as_mut_ptr(),
+            span: Some(data.len()),
+            len,
+            inc,
+            marker: PhantomData,
+        })
+    }
+
+    /// Create a mutable view of the vector whose element with the lowest address is at `ptr`,
+    /// which must be valid for writes of its elements for `'a`.
+    #[cfg(any(feature = "faer", feature = "nalgebra", feature = "ndarray"))]
+    pub(crate) unsafe fn from_raw_parts(ptr: *mut T, len: Int, inc: Int) -> Self {
+        VecMut {
+            ptr,
+            span: dense(vector_len(len, inc), len as usize),
+            len,
+            inc,
+            marker: PhantomData,
+        }
+    }
+
+    /// Return the number of elements.
+    #[inline]
+    pub fn len(&self) -> Int {
+        self.len
+    }
+
+    /// Check whether the vector has no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Return the distance between consecutive elements.
+    #[inline]
+    pub fn inc(&self) -> Int {
+        self.inc
+    }
+
+    /// Return the underlying slice.
+    ///
+    /// The function returns `None` as [`VecRef::as_slice`] does.
+    #[inline]
+    pub fn as_slice(&self) -> Option<&[T]> {
+        self.span.map(|span| unsafe { span_of(self.ptr, span) })
+    }
+
+    /// Return the underlying slice mutably.
+    ///
+    /// The function returns `None` as [`VecRef::as_slice`] does.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> Option<&mut [T]> {
+        self.span.map(|span| unsafe { span_of_mut(self.ptr, span) })
+    }
+
+    /// Return an immutable view of the same elements.
+    #[inline]
+    pub fn view(&self) -> VecRef<'_, T> {
+        VecRef {
+            ptr: self.ptr,
+            span: self.span,
+            len: self.len,
+            inc: self.inc,
+            marker: PhantomData,
+        }
+    }
+
+    /// Return a mutable view of the same elements for a shorter lifetime.
+    #[inline]
+    pub fn view_mut(&mut self) -> VecMut<'_, T> {
+        VecMut {
+            ptr: self.ptr,
+            span: self.span,
+            len: self.len,
+            inc: self.inc,
+            marker: PhantomData,
+        }
+    }
+
+    /// Return a view of the same elements in reverse order.
+    #[inline]
+    pub fn rev(self) -> Self {
+        VecMut {
+            inc: -self.inc,
+            ..self
+        }
+    }
+
+    /// Return element `i` in the order of traversal.
+    #[inline]
+    pub fn get(&self, i: Int) -> Option<&T> {
+        offset(self.len, self.inc, i).map(|offset| unsafe { &*self.ptr.add(offset) })
+    }
+
+    /// Return element `i` in the order of traversal mutably.
+    #[inline]
+    pub fn get_mut(&mut self, i: Int) -> Option<&mut T> {
+        offset(self.len, self.inc, i).map(|offset| unsafe { &mut *self.ptr.add(offset) })
+    }
+}
+
+impl<'a, T> From<&'a [T]> for VecRef<'a, T> {
+    /// Create a view of a contiguous vector.
+    #[inline]
+    fn from(data: &'a [T]) -> Self {
+        VecRef {
+            ptr: data.as_ptr(),
+            span: Some(data.len()),
+            len: contiguous_len(data.len()),
+            inc: 1,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> From<&'a mut [T]> for VecMut<'a, T> {
+    /// Create a view of a contiguous vector.
+    #[inline]
+    fn from(data: &'a mut [T]) -> Self {
+        VecMut {
+            ptr: data.as_mut_ptr(),
+            span: Some(data.len()),
+            len: contiguous_len(data.len()),
+            inc: 1,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> MatRef<'a, T> {
+    /// Create a view of a `rows`-by-`cols` matrix whose columns are `ld` elements apart.
+    pub fn new(data: &'a [T], rows: Int, cols: Int, ld: Int) -> Result<Self, BlasError> {
+        check_matrix_layout("MatRef::new", data.len(), rows, cols, ld)?;
+        Ok(MatRef {
+            ptr: data.as_ptr(),
+            span: Some(data.len()),
+            rows,
+            cols,
+            ld,
+            marker: PhantomData,
+        })
+    }
+
+    /// Create a view of the matrix whose first element is at `ptr`, which must be valid for reads
+    /// of its elements for `'a`.
+    #[cfg(any(feature = "faer", feature = "nalgebra", feature = "ndarray"))]
+    pub(crate) unsafe fn from_raw_parts(ptr: *const T, rows: Int, cols: Int, ld: Int) -> Self {
+        MatRef {
+            ptr,
+            span: dense(matrix_len(rows, cols, ld), rows as usize * cols as usize),
+            rows,
+            cols,
+            ld,
+            marker: PhantomData,
+        }
+    }
+
+    /// Return the number of rows.
+    #[inline]
+    pub fn rows(&self) -> Int {
+        self.rows
+    }
+
+    /// Return the number of columns.
+    #[inline]
+    pub fn cols(&self) -> Int {
+        self.cols
+    }
+
+    /// Return the leading dimension, which is the distance between consecutive columns.
+    #[inline]
+    pub fn ld(&self) -> Int {
+        self.ld
+    }
+
+    /// Return the underlying slice.
+    ///
+    /// The function returns `None` for a view converted from a matrix of another package whose
+    /// columns are not contiguous, as the memory between them is not borrowed by the view.
+    #[inline]
+    pub fn as_slice(&self) -> Option<&'a [T]> {
+        self.span.map(|span| unsafe { span_of(self.ptr, span) })
+    }
+
+    /// Return a view of row `i`.
+    ///
+    /// The function panics if `i` is not less than the number of rows.
+    #[inline]
+    pub fn row(&self, i: Int) -> VecRef<'a, T> {
+        self.vector(row_layout(self.rows, self.cols, self.ld, i))
+    }
+
+    /// Return a view of column `j`.
+    ///
+    /// The function panics if `j` is not less than the number of columns.
+    #[inline]
+    pub fn col(&self, j: Int) -> VecRef<'a, T> {
+        self.vector(col_layout(self.rows, self.cols, self.ld, j))
+    }
+
+    /// Return a view of diagonal `k`, which is the main diagonal for zero, above it for positive
+    /// values, and below it for negative ones.
+    ///
+    /// The function panics if the diagonal lies outside of the matrix, which does not happen for
+    /// the main diagonal.
+    #[inline]
+    pub fn diag(&self, k: Int) -> VecRef<'a, T> {
+        self.vector(diag_layout(self.rows, self.cols, self.ld, k))
+    }
+
+    #[inline]
+    fn vector(&self, (start, len, inc): (usize, Int, Int)) -> VecRef<'a, T> {
+        let (start, span) = tail(self.span, start, len);
+        VecRef {
+            ptr: unsafe { self.ptr.add(start) },
+            span,
+            len,
+            inc,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> MatMut<'a, T> {
+    /// Create a view of a `rows`-by-`cols` matrix whose columns are `ld` elements apart.
+    pub fn new(data: &'a mut [T], rows: Int, cols: Int, ld: Int) -> Result<Self, BlasError> {
+        check_matrix_layout("MatMut::new", data.len(), rows, cols, ld)?;
+        Ok(MatMut {
+            ptr: data.as_mut_ptr(),
+            span: Some(data.len()),
+            rows,
+            cols,
+            ld,
+            marker: PhantomData,
+        })
+    }
+
+    /// Create a mutable view of the matrix whose first element is at `ptr`, which must be valid
+    /// for writes of its elements for `'a`.
+    #[cfg(any(feature = "faer", feature = "nalgebra", feature = "ndarray"))]
+    pub(crate) unsafe fn from_raw_parts(ptr: *mut T, rows: Int, cols: Int, ld: Int) -> Self {
+        MatMut {
+            ptr,
+            span: dense(matrix_len(rows, cols, ld), rows as usize * cols as usize),
+            rows,
+            cols,
+            ld,
+            marker: PhantomData,
+        }
+    }
+
+    /// Return the number of rows.
+    #[inline]
+    pub fn rows(&self) -> Int {
+        self.rows
+    }
+
+    /// Return the number of columns.
+    #[inline]
+    pub fn cols(&self) -> Int {
+        self.cols
+    }
+
+    /// Return the leading dimension, which is the distance between consecutive columns.
+    #[inline]
+    pub fn ld(&self) -> Int {
+        self.ld
+    }
+
+    /// Return the underlying slice.
+    ///
+    /// The function returns `None` as [`MatRef::as_slice`] does.
+    #[inline]
+    pub fn as_slice(&self) -> Option<&[T]> {
+        self.span.map(|span| unsafe { span_of(self.ptr, span) })
+    }
+
+    /// Return the underlying slice mutably.
+    ///
+    /// The function returns `None` as [`MatRef::as_slice`] does.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> Option<&mut [T]> {
+        self.span.map(|span| unsafe { span_of_mut(self.ptr, span) })
+    }
+
+    /// Return an immutable view of the same elements.
+    #[inline]
+    pub fn view(&self) -> MatRef<'_, T> {
+        MatRef {
+            ptr: self.ptr,
+            span: self.span,
+            rows: self.rows,
+            cols: self.cols,
+            ld: self.ld,
+            marker: PhantomData,
+        }
+    }
+
+    /// Return a mutable view of the same elements for a shorter lifetime.
+    #[inline]
+    pub fn view_mut(&mut self) -> MatMut<'_, T> {
+        MatMut {
+            ptr: self.ptr,
+            span: self.span,
+            rows: self.rows,
+            cols: self.cols,
+            ld: self.ld,
+            marker: PhantomData,
+        }
+    }
+
+    /// Return a mutable view of row `i`.
+    ///
+    /// The function panics as [`MatRef::row`] does.
+    #[inline]
+    pub fn row_mut(&mut self, i: Int) -> VecMut<'_, T> {
+        self.vector_mut(row_layout(self.rows, self.cols, self.ld, i))
+    }
+
+    /// Return a mutable view of column `j`.
+    ///
+    /// The function panics as [`MatRef::col`] does.
+    #[inline]
+    pub fn col_mut(&mut self, j: Int) -> VecMut<'_, T> {
+        self.vector_mut(col_layout(self.rows, self.cols, self.ld, j))
+    }
+
+    /// Return a mutable view of diagonal `k`.
+    ///
+    /// The diagonal is selected and the function panics as in [`MatRef::diag`].
+    #[inline]
+    pub fn diag_mut(&mut self, k: Int) -> VecMut<'_, T> {
+        self.vector_mut(diag_layout(self.rows, self.cols, self.ld, k))
+    }
+
+    #[inline]
+    fn vector_mut(&mut self, (start, len, inc): (usize, Int, Int)) -> VecMut<'_, T> {
+        let (start, span) = tail(self.span, start, len);
+        VecMut {
+            ptr: unsafe { self.ptr.add(start) },
+            span,
+            len,
+            inc,
+            marker: PhantomData,
+        }
+    }
+}
+
+/// Return the offset of element `i` of a vector in the order of traversal.
+#[inline]
+fn offset(len: Int, inc: Int, i: Int) -> Option<usize> {
+    if i < 0 || i >= len {
+        return None;
+    }
+    let position = if inc < 0 { len - 1 - i } else { i };
+    Some(position as usize * inc.unsigned_abs() as usize)
+}
+
+/// Return the offset, length, and increment of row `i`.
+#[inline]
+fn row_layout(rows: Int, cols: Int, ld: Int, i: Int) -> (usize, Int, Int) {
+    assert!(
+        0 <= i && i < rows,
+        "row {} is out of bounds for {} rows",
+        i,
+        rows
+    );
+    (i as usize, cols, ld)
+}
+
+/// Return the offset, length, and increment of column `j`.
+#[inline]
+fn col_layout(rows: Int, cols: Int, ld: Int, j: Int) -> (usize, Int, Int) {
+    assert!(
+        0 <= j && j < cols,
+        "column {} is out of bounds for {} columns",
+        j,
+        cols
+    );
+    (j as usize * ld as usize, rows, 1)
+}
+
+/// Return the offset, length, and increment of diagonal `k`.
+#[inline]
+fn diag_layout(rows: Int, cols: Int, ld: Int, k: Int) -> (usize, Int, Int) {
+    assert!(
+        k == 0 || (-rows < k && k < cols),
+        "diagonal {} is out of bounds for a {}-by-{} matrix",
+        k,
+        rows,
+        cols,
+    );
+    let (i, j) = if k < 0 { (-k, 0) } else { (0, k) };
+    let len = (rows - i).min(cols - j);
+    (i as usize + j as usize * ld as usize, len, ld + 1)
+}
+
+/// Return the offset and the span of the vector of `len` elements from offset `start` of a
+/// matrix with span `span`.
+///
+/// An empty vector starts at the first element of the matrix, since `start` may lie past its end.
+#[inline]
+fn tail(span: Option<usize>, start: usize, len: Int) -> (usize, Option<usize>) {
+    let start = if len > 0 { start } else { 0 };
+    (start, span.map(|span| span.saturating_sub(start)))
+}
+
+/// Return the span of `len` elements if they are contiguous, which is `len` itself.
+#[cfg(any(feature = "faer", feature = "nalgebra", feature = "ndarray"))]
+#[inline]
+fn dense(span: usize, len: usize) -> Option<usize> {
+    if span == len {
+        Some(span)
+    } else {
+        None
+    }
+}
+
+/// Return the slice of `span` elements at `ptr`.
+#[inline]
+unsafe fn span_of<'a, T>(ptr: *const T, span: usize) -> &'a [T] {
+    slice::from_raw_parts(ptr, span)
+}
+
+#[inline]
+unsafe fn span_of_mut<'a, T>(ptr: *mut T, span: usize) -> &'a mut [T] {
+    slice::from_raw_parts_mut(ptr, span)
+}
+
+/// Return the length of a contiguous vector, which is capped at the largest dimension.
+#[inline]
+fn contiguous_len(len: usize) -> Int {
+    len.min(Int::MAX as usize) as Int
+}
+
+fn check_vector_layout(
+    routine: &'static str,
+    actual: usize,
+    len: Int,
+    inc: Int,
+) -> Result<(), BlasError> {
+    check_dimension(routine, 2, len)?;
+    check_increment(routine, 3, inc)?;
+    check_len(routine, 1, vector_len(len, inc), actual)
+}
+
+fn check_matrix_layout(
+    routine: &'static str,
+    actual: usize,
+    rows: Int,
+    cols: Int,
+    ld: Int,
+) -> Result<(), BlasError> {
+    check_dimension(routine, 2, rows)?;
+    check_dimension(routine, 3, cols)?;
+    check_leading_dimension(routine, 4, ld, rows.max(1))?;
+    check_len(routine, 1, matrix_len(rows, cols, ld), actual)
+}
+
+#[inline]
+pub(crate) fn check_match(
+    routine: &'static str,
+    param: usize,
+    actual: Int,
+    expected: Int,
+) -> Result<(), BlasError> {
+    if actual != expected {
+        return Err(BlasError::InvalidArgument {
+            routine,
+            param,
+            reason: Reason::DimensionMismatch { actual, expected },
+        });
+    }
+    Ok(())
+}
+
+/// Check that a matrix is square and return its order.
+#[inline]
+fn check_square<T>(routine: &'static str, param: usize, a: &MatRef<T>) -> Result<Int, BlasError> {
+    check_match(routine, param, a.cols, a.rows)?;
+    Ok(a.rows)
+}
+
+/// Return the dimensions of `op(A)`.
+#[inline]
+fn op_dimensions<T>(trans: Transpose, a: &MatRef<T>) -> (Int, Int) {
+    match trans {
+        Transpose::None => (a.rows, a.cols),
+        _ => (a.cols, a.rows),
+    }
+}
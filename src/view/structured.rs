@@ -0,0 +1,333 @@
+use super::{
+    check_square, hemm, hemv, her, her2, her2k, herk, symm, syr2k, syrk, trmm, trmv, trsm, trsv,
+    MatMut, MatRef, VecMut, VecRef,
+};
+use crate::safe::BlasError;
+use crate::{Blas, Diag, Int, NoneOrConj, NoneOrTrans, Side, Transpose, Uplo};
+
+/// A symmetric matrix of which only one triangle is referenced.
+#[derive(Clone, Copy, Debug)]
+pub struct Symmetric<M> {
+    matrix: M,
+    uplo: Uplo,
+}
+
+/// A Hermitian matrix of which only one triangle is referenced.
+///
+/// The imaginary parts of the diagonal are assumed to be zero.
+#[derive(Clone, Copy, Debug)]
+pub struct Hermitian<M> {
+    matrix: M,
+    uplo: Uplo,
+}
+
+/// A triangular matrix of which only one triangle is referenced.
+///
+/// With a unit diagonal, the diagonal is not referenced either and is assumed to be one.
+#[derive(Clone, Copy, Debug)]
+pub struct Triangular<M> {
+    matrix: M,
+    uplo: Uplo,
+    diag: Diag,
+}
+
+macro_rules! implement {
+    ($name:ident) => {
+        impl<M> $name<M> {
+            /// Return the referenced triangle.
+            #[inline]
+            pub fn uplo(&self) -> Uplo {
+                self.uplo
+            }
+
+            /// Return the underlying view.
+            #[inline]
+            pub fn into_inner(self) -> M {
+                self.matrix
+            }
+        }
+
+        impl<'a, T> $name<MatRef<'a, T>> {
+            /// Return the order.
+            #[inline]
+            pub fn n(&self) -> Int {
+                self.matrix.rows
+            }
+        }
+
+        impl<'a, T> $name<MatMut<'a, T>> {
+            /// Return the order.
+            #[inline]
+            pub fn n(&self) -> Int {
+                self.matrix.rows
+            }
+        }
+    };
+}
+
+implement!(Symmetric);
+implement!(Hermitian);
+implement!(Triangular);
+
+impl<'a, T> Symmetric<MatRef<'a, T>> {
+    /// Create a symmetric matrix whose triangle `uplo` is referenced in `a`.
+    #[inline]
+    pub fn new(uplo: Uplo, a: MatRef<'a, T>) -> Result<Self, BlasError> {
+        check_square("Symmetric::new", 2, &a)?;
+        Ok(Symmetric { matrix: a, uplo })
+    }
+}
+
+impl<'a, T> Symmetric<MatMut<'a, T>> {
+    /// Create a symmetric matrix whose triangle `uplo` is referenced in the mutable view `a`.
+    #[inline]
+    pub fn new_mut(uplo: Uplo, a: MatMut<'a, T>) -> Result<Self, BlasError> {
+        check_square("Symmetric::new_mut", 2, &a.view())?;
+        Ok(Symmetric { matrix: a, uplo })
+    }
+
+    /// Return an immutable view of the same matrix.
+    #[inline]
+    pub fn view(&self) -> Symmetric<MatRef<'_, T>> {
+        Symmetric {
+            matrix: self.matrix.view(),
+            uplo: self.uplo,
+        }
+    }
+}
+
+impl<'a, T: Blas> Symmetric<MatRef<'a, T>> {
+    /// Compute `C := alpha * A * B + beta * C` or `C := alpha * B * A + beta * C` with `symm`.
+    #[inline]
+    pub fn mul_mat(
+        &self,
+        side: Side,
+        alpha: T,
+        b: MatRef<T>,
+        beta: T,
+        c: MatMut<T>,
+    ) -> Result<(), BlasError> {
+        symm(side, self.uplo, alpha, self.matrix, b, beta, c)
+    }
+}
+
+impl<'a, T: Blas<Real = T>> Symmetric<MatRef<'a, T>> {
+    /// Compute `y := alpha * A * x + beta * y` with `symv`.
+    #[inline]
+    pub fn mul_vec(&self, alpha: T, x: VecRef<T>, beta: T, y: VecMut<T>) -> Result<(), BlasError> {
+        hemv(self.uplo, alpha, self.matrix, x, beta, y)
+    }
+}
+
+impl<'a, T: Blas> Symmetric<MatMut<'a, T>> {
+    /// Compute `C := alpha * op(A) * op(A)^T + beta * C` with `syrk`.
+    #[inline]
+    pub fn rank_k_update(
+        &mut self,
+        trans: NoneOrTrans,
+        alpha: T,
+        a: MatRef<T>,
+        beta: T,
+    ) -> Result<(), BlasError> {
+        syrk(self.uplo, trans, alpha, a, beta, self.matrix.view_mut())
+    }
+
+    /// Compute `C := alpha * op(A) * op(B)^T + alpha * op(B) * op(A)^T + beta * C` with
+    /// `syr2k`.
+    #[inline]
+    pub fn rank_2k_update(
+        &mut self,
+        trans: NoneOrTrans,
+        alpha: T,
+        a: MatRef<T>,
+        b: MatRef<T>,
+        beta: T,
+    ) -> Result<(), BlasError> {
+        syr2k(self.uplo, trans, alpha, a, b, beta, self.matrix.view_mut())
+    }
+}
+
+impl<'a, T: Blas<Real = T>> Symmetric<MatMut<'a, T>> {
+    /// Compute `A := alpha * x * x^T + A` with `syr`.
+    #[inline]
+    pub fn rank1_update(&mut self, alpha: T, x: VecRef<T>) -> Result<(), BlasError> {
+        her(self.uplo, alpha, x, self.matrix.view_mut())
+    }
+
+    /// Compute `A := alpha * x * y^T + alpha * y * x^T + A` with `syr2`.
+    #[inline]
+    pub fn rank2_update(&mut self, alpha: T, x: VecRef<T>, y: VecRef<T>) -> Result<(), BlasError> {
+        her2(self.uplo, alpha, x, y, self.matrix.view_mut())
+    }
+}
+
+impl<'a, T> Hermitian<MatRef<'a, T>> {
+    /// Create a Hermitian matrix whose triangle `uplo` is referenced in `a`.
+    #[inline]
+    pub fn new(uplo: Uplo, a: MatRef<'a, T>) -> Result<Self, BlasError> {
+        check_square("Hermitian::new", 2, &a)?;
+        Ok(Hermitian { matrix: a, uplo })
+    }
+}
+
+impl<'a, T> Hermitian<MatMut<'a, T>> {
+    /// Create a Hermitian matrix whose triangle `uplo` is referenced in the mutable view `a`.
+    #[inline]
+    pub fn new_mut(uplo: Uplo, a: MatMut<'a, T>) -> Result<Self, BlasError> {
+        check_square("Hermitian::new_mut", 2, &a.view())?;
+        Ok(Hermitian { matrix: a, uplo })
+    }
+
+    /// Return an immutable view of the same matrix.
+    #[inline]
+    pub fn view(&self) -> Hermitian<MatRef<'_, T>> {
+        Hermitian {
+            matrix: self.matrix.view(),
+            uplo: self.uplo,
+        }
+    }
+}
+
+impl<'a, T: Blas> Hermitian<MatRef<'a, T>> {
+    /// Compute `y := alpha * A * x + beta * y` with `hemv`.
+    #[inline]
+    pub fn mul_vec(&self, alpha: T, x: VecRef<T>, beta: T, y: VecMut<T>) -> Result<(), BlasError> {
+        hemv(self.uplo, alpha, self.matrix, x, beta, y)
+    }
+
+    /// Compute `C := alpha * A * B + beta * C` or `C := alpha * B * A + beta * C` with `hemm`.
+    #[inline]
+    pub fn mul_mat(
+        &self,
+        side: Side,
+        alpha: T,
+        b: MatRef<T>,
+        beta: T,
+        c: MatMut<T>,
+    ) -> Result<(), BlasError> {
+        hemm(side, self.uplo, alpha, self.matrix, b, beta, c)
+    }
+}
+
+impl<'a, T: Blas> Hermitian<MatMut<'a, T>> {
+    /// Compute `A := alpha * x * x^H + A` with `her`.
+    #[inline]
+    pub fn rank1_update(&mut self, alpha: T::Real, x: VecRef<T>) -> Result<(), BlasError> {
+        her(self.uplo, alpha, x, self.matrix.view_mut())
+    }
+
+    /// Compute `A := alpha * x * y^H + conj(alpha) * y * x^H + A` with `her2`.
+    #[inline]
+    pub fn rank2_update(&mut self, alpha: T, x: VecRef<T>, y: VecRef<T>) -> Result<(), BlasError> {
+        her2(self.uplo, alpha, x, y, self.matrix.view_mut())
+    }
+
+    /// Compute `C := alpha * op(A) * op(A)^H + beta * C` with `herk`.
+    #[inline]
+    pub fn rank_k_update(
+        &mut self,
+        trans: NoneOrConj,
+        alpha: T::Real,
+        a: MatRef<T>,
+        beta: T::Real,
+    ) -> Result<(), BlasError> {
+        herk(self.uplo, trans, alpha, a, beta, self.matrix.view_mut())
+    }
+
+    /// Compute `C := alpha * op(A) * op(B)^H + conj(alpha) * op(B) * op(A)^H + beta * C` with
+    /// `her2k`.
+    #[inline]
+    pub fn rank_2k_update(
+        &mut self,
+        trans: NoneOrConj,
+        alpha: T,
+        a: MatRef<T>,
+        b: MatRef<T>,
+        beta: T::Real,
+    ) -> Result<(), BlasError> {
+        her2k(self.uplo, trans, alpha, a, b, beta, self.matrix.view_mut())
+    }
+}
+
+impl<M> Triangular<M> {
+    /// Return the kind of the diagonal.
+    #[inline]
+    pub fn diag(&self) -> Diag {
+        self.diag
+    }
+}
+
+impl<'a, T> Triangular<MatRef<'a, T>> {
+    /// Create a triangular matrix whose triangle `uplo` is referenced in `a`.
+    #[inline]
+    pub fn new(uplo: Uplo, diag: Diag, a: MatRef<'a, T>) -> Result<Self, BlasError> {
+        check_square("Triangular::new", 3, &a)?;
+        Ok(Triangular {
+            matrix: a,
+            uplo,
+            diag,
+        })
+    }
+}
+
+impl<'a, T> Triangular<MatMut<'a, T>> {
+    /// Create a triangular matrix whose triangle `uplo` is referenced in the mutable view `a`.
+    #[inline]
+    pub fn new_mut(uplo: Uplo, diag: Diag, a: MatMut<'a, T>) -> Result<Self, BlasError> {
+        check_square("Triangular::new_mut", 3, &a.view())?;
+        Ok(Triangular {
+            matrix: a,
+            uplo,
+            diag,
+        })
+    }
+
+    /// Return an immutable view of the same matrix.
+    #[inline]
+    pub fn view(&self) -> Triangular<MatRef<'_, T>> {
+        Triangular {
+            matrix: self.matrix.view(),
+            uplo: self.uplo,
+            diag: self.diag,
+        }
+    }
+}
+
+impl<'a, T: Blas> Triangular<MatRef<'a, T>> {
+    /// Compute `x := op(A) * x` with `trmv`.
+    #[inline]
+    pub fn mul_vec_in_place(&self, trans: Transpose, x: VecMut<T>) -> Result<(), BlasError> {
+        trmv(self.uplo, trans, self.diag, self.matrix, x)
+    }
+
+    /// Solve `op(A) * x = b` with `trsv`, where `x` holds `b` on entry.
+    #[inline]
+    pub fn solve_in_place(&self, trans: Transpose, x: VecMut<T>) -> Result<(), BlasError> {
+        trsv(self.uplo, trans, self.diag, self.matrix, x)
+    }
+
+    /// Compute `B := alpha * op(A) * B` or `B := alpha * B * op(A)` with `trmm`.
+    #[inline]
+    pub fn mul_mat_in_place(
+        &self,
+        side: Side,
+        trans: Transpose,
+        alpha: T,
+        b: MatMut<T>,
+    ) -> Result<(), BlasError> {
+        trmm(side, self.uplo, trans, self.diag, alpha, self.matrix, b)
+    }
+
+    /// Solve `op(A) * X = alpha * B` or `X * op(A) = alpha * B` with `trsm`, where `b` holds
+    /// `B` on entry and `X` on exit.
+    #[inline]
+    pub fn solve_mat_in_place(
+        &self,
+        side: Side,
+        trans: Transpose,
+        alpha: T,
+        b: MatMut<T>,
+    ) -> Result<(), BlasError> {
+        trsm(side, self.uplo, trans, self.diag, alpha, self.matrix, b)
+    }
+}
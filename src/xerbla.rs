@@ -0,0 +1,112 @@
+//! Handling of the errors reported via `XERBLA`.
+//!
+//! BLAS routines report an invalid argument by calling `XERBLA`, which prints a message and
+//! usually terminates the process. With the `xerbla` feature, the package exports its own
+//! `xerbla_` symbol (`xerbla_64_` with the `ilp64` feature), which takes the place of the one of
+//! the backend when the backend is linked statically or resolves the symbol from the executable.
+//! The error is then recorded if the call happens within [`catch_blas_errors`], and it is printed
+//! otherwise, without terminating the process. In both cases, the routine returns without
+//! computing anything. The implementations of the `reference` feature and of the extensions
+//! report their errors in the same way but panic outside of `catch_blas_errors`.
+
+use std::cell::RefCell;
+use std::error;
+use std::fmt;
+
+use crate::Int;
+
+thread_local! {
+    static SCOPE: RefCell<Option<Option<XerblaError>>> = const { RefCell::new(None) };
+}
+
+/// An invalid argument reported by a routine.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct XerblaError {
+    /// The name of the routine, such as `DGEMM`.
+    pub routine: String,
+    /// The position of the invalid argument, starting from one.
+    pub param: Int,
+}
+
+/// The scope of a call to `catch_blas_errors`, which restores the enclosing scope on drop.
+struct Guard(Option<Option<XerblaError>>);
+
+impl fmt::Display for XerblaError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "on entry to {} parameter number {} had an illegal value",
+            self.routine, self.param,
+        )
+    }
+}
+
+impl error::Error for XerblaError {}
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        let outer = self.0.take();
+        let _ = SCOPE.try_with(|scope| scope.replace(outer));
+    }
+}
+
+/// Call `f` and return the first invalid argument reported by a routine during the call.
+///
+/// The result of `f` is discarded if an error is reported. Calls can be nested, in which case
+/// an error is returned by the innermost call.
+pub fn catch_blas_errors<F, R>(f: F) -> Result<R, XerblaError>
+where
+    F: FnOnce() -> R,
+{
+    let mut guard = Guard(SCOPE.with(|scope| scope.replace(Some(None))));
+    let result = f();
+    let outer = guard.0.take();
+    match SCOPE.with(|scope| scope.replace(outer)).flatten() {
+        Some(error) => Err(error),
+        None => Ok(result),
+    }
+}
+
+/// Record an invalid argument if within `catch_blas_errors` and return whether it is recorded.
+#[cfg(any(
+    not(all(feature = "extension-gemmt", feature = "extension-matcopy")),
+    feature = "reference",
+    feature = "xerbla",
+))]
+pub(crate) fn report(routine: &str, param: Int) -> bool {
+    SCOPE
+        .try_with(|scope| match *scope.borrow_mut() {
+            Some(ref mut error) => {
+                if error.is_none() {
+                    *error = Some(XerblaError {
+                        routine: routine.to_owned(),
+                        param,
+                    });
+                }
+                true
+            }
+            None => false,
+        })
+        .unwrap_or(false)
+}
+
+/// Record or print an invalid argument reported by the backend.
+#[cfg(feature = "xerbla")]
+#[cfg_attr(not(feature = "ilp64"), no_mangle)]
+#[cfg_attr(feature = "ilp64", export_name = "xerbla_64_")]
+pub unsafe extern "C" fn xerbla_(srname: *const core::ffi::c_char, info: *const Int, len: usize) {
+    let name = if srname.is_null() {
+        &[][..]
+    } else {
+        std::slice::from_raw_parts(srname as *const u8, len)
+    };
+    let name = name.split(|&c| c == 0).next().unwrap_or(&[]);
+    let name = String::from_utf8_lossy(name);
+    let (name, param) = (name.trim_end(), *info);
+    if !report(name, param) {
+        eprintln!(
+            " ** On entry to {} parameter number {} had an illegal value",
+            name, param,
+        );
+    }
+}
@@ -0,0 +1,15 @@
+#![cfg(feature = "reference")]
+
+use blas::backend::{self, Vendor};
+
+#[test]
+fn reference() {
+    assert_eq!(backend::vendor(), Vendor::Reference);
+    assert_eq!(backend::vendor().to_string(), "Reference");
+    assert_eq!(
+        backend::version().as_deref(),
+        Some(env!("CARGO_PKG_VERSION"))
+    );
+    assert_eq!(backend::threads(), Some(1));
+    assert!(!backend::set_threads(4));
+}
@@ -0,0 +1,241 @@
+#![cfg(feature = "reference")]
+
+mod common;
+
+use blas::safe::{BlasError, Reason};
+use blas::view::*;
+use blas::*;
+use common::*;
+
+const UPLOS: [Uplo; 2] = [Uplo::Upper, Uplo::Lower];
+
+fn check_general<T: Element + Blas>() {
+    let mut rng = Rng::new();
+    let (m, n, kl, ku) = (5, 4, 2, 1);
+    let a = matrix::<T>(&mut rng, n, leading(m));
+    let ab = BandMatrix::from_dense(m as Int, n as Int, kl, ku, &a, leading(m) as Int).unwrap();
+    assert_eq!(ab.ld(), kl + ku + 1);
+    let mut dense = vec![T::zero(); m * n];
+    for j in 0..n {
+        for i in 0..m {
+            let inside = i + ku as usize >= j && j + kl as usize >= i;
+            let expected = if inside {
+                a[i + j * leading(m)]
+            } else {
+                T::zero()
+            };
+            assert_eq!(ab.get(i as Int, j as Int), Some(expected));
+            assert_eq!(ab.clone().get_mut(i as Int, j as Int).is_some(), inside);
+            dense[i + j * m] = expected;
+        }
+    }
+    assert_eq!(ab.get(m as Int, 0), None);
+    assert_eq!(ab.to_dense(), dense);
+
+    for trans in [Transpose::None, Transpose::Ordinary, Transpose::Conjugate] {
+        let (rows, cols) = if trans == Transpose::None {
+            (m, n)
+        } else {
+            (n, m)
+        };
+        let x = vector::<T>(&mut rng, cols, 2);
+        let mut y = vector::<T>(&mut rng, rows, -1);
+        let (alpha, beta) = (T::random(&mut rng), T::random(&mut rng));
+        let mut expected = y.clone();
+        let xv = VecRef::new(&x, cols as Int, 2).unwrap();
+        let av = MatRef::new(&dense, m as Int, n as Int, m as Int).unwrap();
+        let yv = VecMut::new(&mut expected, rows as Int, -1).unwrap();
+        gemv(trans, alpha, av, xv, beta, yv).unwrap();
+        let yv = VecMut::new(&mut y, rows as Int, -1).unwrap();
+        ab.mul_vec(trans, alpha, xv, beta, yv).unwrap();
+        assert_close(&y, &expected, "mul_vec");
+    }
+}
+
+fn check_symmetric<T: Element + Blas<Real = T>>() {
+    let mut rng = Rng::new();
+    let (n, k) = (5, 2);
+    let a = matrix::<T>(&mut rng, n, n);
+    for uplo in UPLOS {
+        let ab = SymmetricBand::from_dense(uplo, n as Int, k, &a, n as Int).unwrap();
+        let dense = ab.to_dense();
+        for j in 0..n {
+            for i in 0..n {
+                let expected = if i.abs_diff(j) > k as usize {
+                    T::zero()
+                } else {
+                    common::symmetric(uplo as u8, false, |i, j| a[i + j * n], i, j)
+                };
+                assert_eq!(dense[i + j * n], expected);
+            }
+        }
+        let x = vector::<T>(&mut rng, n, -2);
+        let mut y = vector::<T>(&mut rng, n, 1);
+        let (alpha, beta) = (T::random(&mut rng), T::random(&mut rng));
+        let mut expected = y.clone();
+        let xv = VecRef::new(&x, n as Int, -2).unwrap();
+        let av = MatRef::new(&dense, n as Int, n as Int, n as Int).unwrap();
+        hemv(uplo, alpha, av, xv, beta, VecMut::from(&mut expected[..])).unwrap();
+        ab.mul_vec(alpha, xv, beta, VecMut::from(&mut y[..]))
+            .unwrap();
+        assert_close(&y, &expected, "mul_vec");
+    }
+}
+
+fn check_hermitian<T: Element + Blas>() {
+    let mut rng = Rng::new();
+    let (n, k) = (5, 1);
+    let a = matrix::<T>(&mut rng, n, n);
+    for uplo in UPLOS {
+        let ab = HermitianBand::from_dense(uplo, n as Int, k, &a, n as Int).unwrap();
+        let dense = ab.to_dense();
+        for j in 0..n {
+            for i in 0..n {
+                let expected = if i.abs_diff(j) > k as usize {
+                    T::zero()
+                } else {
+                    common::symmetric(uplo as u8, true, |i, j| a[i + j * n], i, j)
+                };
+                assert_eq!(dense[i + j * n], expected);
+            }
+        }
+        let x = vector::<T>(&mut rng, n, 1);
+        let mut y = vector::<T>(&mut rng, n, 3);
+        let (alpha, beta) = (T::random(&mut rng), T::random(&mut rng));
+        let mut expected = y.clone();
+        let xv = VecRef::from(&x[..]);
+        let av = MatRef::new(&dense, n as Int, n as Int, n as Int).unwrap();
+        let yv = VecMut::new(&mut expected, n as Int, 3).unwrap();
+        hemv(uplo, alpha, av, xv, beta, yv).unwrap();
+        let yv = VecMut::new(&mut y, n as Int, 3).unwrap();
+        ab.mul_vec(alpha, xv, beta, yv).unwrap();
+        assert_close(&y, &expected, "mul_vec");
+    }
+}
+
+fn check_triangular<T: Element + Blas>() {
+    let mut rng = Rng::new();
+    let (n, k) = (5, 2);
+    let mut a = matrix::<T>(&mut rng, n, n);
+    strengthen(&mut a, n, n);
+    for uplo in UPLOS {
+        for diag in [Diag::NonUnit, Diag::Unit] {
+            let ab = TriangularBand::from_dense(uplo, diag, n as Int, k, &a, n as Int).unwrap();
+            let dense = ab.to_dense();
+            for j in 0..n {
+                for i in 0..n {
+                    let expected = if i.abs_diff(j) > k as usize {
+                        T::zero()
+                    } else {
+                        common::triangular(uplo as u8, diag as u8, |i, j| a[i + j * n], i, j)
+                    };
+                    assert_eq!(dense[i + j * n], expected);
+                }
+            }
+            let av = MatRef::new(&dense, n as Int, n as Int, n as Int).unwrap();
+            for trans in [Transpose::None, Transpose::Ordinary, Transpose::Conjugate] {
+                let x = vector::<T>(&mut rng, n, -1);
+
+                let mut expected = x.clone();
+                let mut actual = x.clone();
+                let xv = VecMut::new(&mut expected, n as Int, -1).unwrap();
+                trmv(uplo, trans, diag, av, xv).unwrap();
+                let xv = VecMut::new(&mut actual, n as Int, -1).unwrap();
+                ab.mul_vec_in_place(trans, xv).unwrap();
+                assert_close(&actual, &expected, "mul_vec_in_place");
+
+                let mut expected = x.clone();
+                let mut actual = x;
+                let xv = VecMut::new(&mut expected, n as Int, -1).unwrap();
+                trsv(uplo, trans, diag, av, xv).unwrap();
+                let xv = VecMut::new(&mut actual, n as Int, -1).unwrap();
+                ab.solve_in_place(trans, xv).unwrap();
+                assert_close(&actual, &expected, "solve_in_place");
+            }
+        }
+    }
+}
+
+#[test]
+fn general() {
+    check_general::<f32>();
+    check_general::<f64>();
+    check_general::<c32>();
+    check_general::<c64>();
+}
+
+#[test]
+fn symmetric() {
+    check_symmetric::<f32>();
+    check_symmetric::<f64>();
+}
+
+#[test]
+fn hermitian() {
+    check_hermitian::<f32>();
+    check_hermitian::<f64>();
+    check_hermitian::<c32>();
+    check_hermitian::<c64>();
+}
+
+#[test]
+fn triangular() {
+    check_triangular::<f32>();
+    check_triangular::<f64>();
+    check_triangular::<c32>();
+    check_triangular::<c64>();
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        BandMatrix::new(4, 4, 1, 2, [0.0f64; 16], 3).unwrap_err(),
+        BlasError::InvalidArgument {
+            routine: "BandMatrix::new",
+            param: 6,
+            reason: Reason::LeadingDimension {
+                actual: 3,
+                minimum: 4,
+            },
+        },
+    );
+    assert_eq!(
+        SymmetricBand::new(Uplo::Upper, 4, 1, [0.0f64; 7], 2).unwrap_err(),
+        BlasError::BufferTooShort {
+            routine: "SymmetricBand::new",
+            param: 4,
+            required: 8,
+            actual: 7,
+        },
+    );
+    assert_eq!(
+        TriangularBand::new(Uplo::Lower, Diag::Unit, 4, -1, [0.0f64; 0], 1).unwrap_err(),
+        BlasError::InvalidArgument {
+            routine: "TriangularBand::new",
+            param: 4,
+            reason: Reason::NegativeDimension(-1),
+        },
+    );
+
+    let data = [1.0f64; 12];
+    let ab = BandMatrix::new(3, 4, 1, 1, &data[..], 3).unwrap();
+    let x = [1.0; 3];
+    let mut y = [0.0; 3];
+    assert_eq!(
+        ab.mul_vec(
+            Transpose::None,
+            1.0,
+            VecRef::from(&x[..]),
+            0.0,
+            VecMut::from(&mut y[..]),
+        ),
+        Err(BlasError::InvalidArgument {
+            routine: "BandMatrix::mul_vec",
+            param: 3,
+            reason: Reason::DimensionMismatch {
+                actual: 3,
+                expected: 4,
+            },
+        }),
+    );
+}
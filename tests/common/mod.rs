@@ -0,0 +1,328 @@
+#![allow(dead_code)]
+
+use blas::{c32, c64, Int, Scalar};
+
+pub const ALL_TRANS: [u8; 3] = [b'N', b'T', b'C'];
+pub const ALL_UPLO: [u8; 2] = [b'U', b'L'];
+pub const ALL_DIAG: [u8; 2] = [b'N', b'U'];
+pub const ALL_SIDE: [u8; 2] = [b'L', b'R'];
+
+/// Pairs of increments covering positive, negative, and non-unit strides.
+pub const INCREMENTS: [(Int, Int); 4] = [(1, 1), (2, -3), (-1, 2), (-2, -1)];
+
+pub trait Element: Scalar {
+    const COMPLEX: bool;
+    const EPSILON: f64;
+
+    fn random(rng: &mut Rng) -> Self;
+
+    fn nan() -> Self;
+
+    fn magnitude(self) -> f64;
+
+    /// Return the sum of the absolute values of the real and imaginary parts.
+    fn abs1(self) -> f64;
+
+    fn distance(self, other: Self) -> f64;
+}
+
+macro_rules! implement_real {
+    ($type:ident) => {
+        impl Element for $type {
+            const COMPLEX: bool = false;
+            const EPSILON: f64 = $type::EPSILON as f64;
+
+            fn random(rng: &mut Rng) -> Self {
+                rng.next() as $type
+            }
+
+            fn nan() -> Self {
+                $type::NAN
+            }
+
+            fn magnitude(self) -> f64 {
+                self.abs() as f64
+            }
+
+            fn abs1(self) -> f64 {
+                self.abs() as f64
+            }
+
+            fn distance(self, other: Self) -> f64 {
+                if self.is_nan() || other.is_nan() {
+                    f64::INFINITY
+                } else {
+                    (self - other).abs() as f64
+                }
+            }
+        }
+    };
+}
+
+macro_rules! implement_complex {
+    ($type:ident, $real:ident) => {
+        impl Element for $type {
+            const COMPLEX: bool = true;
+            const EPSILON: f64 = $real::EPSILON as f64;
+
+            fn random(rng: &mut Rng) -> Self {
+                $type::new(rng.next() as $real, rng.next() as $real)
+            }
+
+            fn nan() -> Self {
+                $type::new($real::NAN, $real::NAN)
+            }
+
+            fn magnitude(self) -> f64 {
+                self.re.hypot(self.im) as f64
+            }
+
+            fn abs1(self) -> f64 {
+                (self.re.abs() + self.im.abs()) as f64
+            }
+
+            fn distance(self, other: Self) -> f64 {
+                self.re.distance(other.re).max(self.im.distance(other.im))
+            }
+        }
+    };
+}
+
+implement_real!(f32);
+implement_real!(f64);
+implement_complex!(c32, f32);
+implement_complex!(c64, f64);
+
+/// A xorshift generator producing reproducible values in `[-1, 1)`.
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new() -> Self {
+        Rng(0x2545_f491_4f6c_dd1d)
+    }
+
+    pub fn next(&mut self) -> f64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 >> 11) as f64 / (1u64 << 52) as f64 - 1.0
+    }
+}
+
+/// Return the values used for `alpha` and `beta`: zero, one, and a random value.
+pub fn scalars<T: Element>(rng: &mut Rng) -> [T; 3] {
+    [T::zero(), T::one(), T::random(rng)]
+}
+
+pub fn random<T: Element>(rng: &mut Rng, len: usize) -> Vec<T> {
+    (0..len).map(|_| T::random(rng)).collect()
+}
+
+/// Return the number of elements spanned by a vector, which is at least one so that buffers
+/// are never empty.
+pub fn vector_len(n: usize, inc: Int) -> usize {
+    if n == 0 {
+        1
+    } else {
+        1 + (n - 1) * inc.unsigned_abs() as usize
+    }
+}
+
+/// Return the position of the `i`-th element of a vector in its buffer, which is counted from
+/// the end for negative increments.
+pub fn position(i: usize, n: usize, inc: Int) -> usize {
+    if inc > 0 {
+        i * inc as usize
+    } else {
+        (n - 1 - i) * inc.unsigned_abs() as usize
+    }
+}
+
+/// Create a buffer for a vector with random elements and random padding.
+pub fn vector<T: Element>(rng: &mut Rng, n: usize, inc: Int) -> Vec<T> {
+    random(rng, vector_len(n, inc))
+}
+
+pub fn gather<T: Copy>(buffer: &[T], n: usize, inc: Int) -> Vec<T> {
+    (0..n).map(|i| buffer[position(i, n, inc)]).collect()
+}
+
+pub fn scatter<T: Copy>(buffer: &mut [T], values: &[T], inc: Int) {
+    let n = values.len();
+    for (i, &value) in values.iter().enumerate() {
+        buffer[position(i, n, inc)] = value;
+    }
+}
+
+/// Create a buffer for a column-major matrix with random elements and random padding.
+pub fn matrix<T: Element>(rng: &mut Rng, cols: usize, ld: usize) -> Vec<T> {
+    random(rng, (ld * cols).max(1))
+}
+
+/// Return the leading dimension used for a matrix, which leaves one row of padding.
+pub fn leading(rows: usize) -> usize {
+    rows.max(1) + 1
+}
+
+/// Replace the elements of a vector with NaNs, which must not be read.
+pub fn poison_vector<T: Element>(buffer: &mut [T], n: usize, inc: Int) {
+    for i in 0..n {
+        buffer[position(i, n, inc)] = T::nan();
+    }
+}
+
+/// Replace the elements of a matrix with NaNs, which must not be read.
+pub fn poison_matrix<T: Element>(buffer: &mut [T], rows: usize, cols: usize, ld: usize) {
+    for j in 0..cols {
+        for i in 0..rows {
+            buffer[i + j * ld] = T::nan();
+        }
+    }
+}
+
+/// Make the diagonal of a matrix dominant so that triangular solves are well conditioned.
+pub fn strengthen<T: Element>(buffer: &mut [T], n: usize, ld: usize) {
+    let two = T::one() + T::one();
+    for i in 0..n {
+        buffer[i + i * ld] = buffer[i + i * ld] + two + two;
+    }
+}
+
+/// Compute `alpha * product + beta * value` without reading the terms scaled by zero.
+pub fn combine<T: Element>(alpha: T, product: T, beta: T, value: T) -> T {
+    let mut result = T::zero();
+    if alpha != T::zero() {
+        result = alpha * product;
+    }
+    if beta != T::zero() {
+        result = result + beta * value;
+    }
+    result
+}
+
+/// Return `op(value)` for a transpose flag.
+pub fn apply<T: Element>(trans: u8, value: T) -> T {
+    if trans == b'C' {
+        value.conj()
+    } else {
+        value
+    }
+}
+
+/// Return element `(i, j)` of `op(A)` for a column-major matrix.
+pub fn op<T: Element>(trans: u8, a: &[T], ld: usize, i: usize, j: usize) -> T {
+    if trans == b'N' {
+        a[i + j * ld]
+    } else {
+        apply(trans, a[j + i * ld])
+    }
+}
+
+/// Return element `(i, j)` of a symmetric or Hermitian matrix stored in one triangle.
+pub fn symmetric<T: Element>(
+    uplo: u8,
+    hermitian: bool,
+    element: impl Fn(usize, usize) -> T,
+    i: usize,
+    j: usize,
+) -> T {
+    if i == j && hermitian {
+        T::from_real(element(i, i).re())
+    } else if (i <= j) == (uplo == b'U') {
+        element(i, j)
+    } else if hermitian {
+        element(j, i).conj()
+    } else {
+        element(j, i)
+    }
+}
+
+/// Return element `(i, j)` of a triangular matrix stored in one triangle.
+pub fn triangular<T: Element>(
+    uplo: u8,
+    diag: u8,
+    element: impl Fn(usize, usize) -> T,
+    i: usize,
+    j: usize,
+) -> T {
+    if i == j {
+        if diag == b'U' {
+            T::one()
+        } else {
+            element(i, i)
+        }
+    } else if (i < j) == (uplo == b'U') {
+        element(i, j)
+    } else {
+        T::zero()
+    }
+}
+
+/// Return the offset of element `(i, j)` of a packed matrix of order `n`.
+pub fn packed(uplo: u8, n: usize, i: usize, j: usize) -> usize {
+    if uplo == b'U' {
+        i + j * (j + 1) / 2
+    } else {
+        i + j * (2 * n - j - 1) / 2
+    }
+}
+
+/// Return whether `(i, j)` lies in the stored triangle.
+pub fn stored(uplo: u8, i: usize, j: usize) -> bool {
+    (i <= j) == (uplo == b'U') || i == j
+}
+
+/// Solve a dense system of order `n` given by `element` with `rhs`, using the triangular
+/// structure implied by `upper`.
+pub fn substitute<T: Element>(
+    n: usize,
+    upper: bool,
+    element: impl Fn(usize, usize) -> T,
+    rhs: &[T],
+) -> Vec<T> {
+    let mut x = rhs.to_vec();
+    let order: Vec<usize> = if upper {
+        (0..n).rev().collect()
+    } else {
+        (0..n).collect()
+    };
+    for &i in &order {
+        let mut sum = x[i];
+        for (l, &value) in x.iter().enumerate() {
+            if l != i && (l > i) == upper {
+                sum = sum - element(i, l) * value;
+            }
+        }
+        x[i] = sum / element(i, i);
+    }
+    x
+}
+
+#[track_caller]
+pub fn assert_close<T: Element>(actual: &[T], expected: &[T], context: &str) {
+    assert_eq!(actual.len(), expected.len(), "{}", context);
+    let tolerance = T::EPSILON * 1e3;
+    for (i, (&actual, &expected)) in actual.iter().zip(expected).enumerate() {
+        assert!(
+            actual.distance(expected) <= tolerance * (1.0 + expected.magnitude()),
+            "{}: element {} is {:?} instead of {:?}",
+            context,
+            i,
+            actual,
+            expected,
+        );
+    }
+}
+
+/// Compare a real result of a routine operating on `T` with the precision of `T`.
+#[track_caller]
+pub fn assert_close_real<T: Element>(actual: f64, expected: f64, context: &str) {
+    let tolerance = T::EPSILON * 1e3;
+    assert!(
+        (actual - expected).abs() <= tolerance * (1.0 + expected.abs()),
+        "{}: {} instead of {}",
+        context,
+        actual,
+        expected,
+    );
+}
@@ -0,0 +1,43 @@
+#![cfg(all(feature = "dynamic", not(feature = "reference"), target_os = "linux"))]
+
+use std::panic;
+
+use blas::backend::{self, Vendor};
+use blas::dynamic::{self, Error};
+
+#[test]
+fn load() {
+    assert!(dynamic::path().is_none());
+    match dynamic::load("libblas-missing.so") {
+        Err(Error::Open { path, .. }) => assert_eq!(path.to_str(), Some("libblas-missing.so")),
+        result => panic!("unexpected {:?}", result),
+    }
+    assert!(matches!(
+        dynamic::load_first(Vec::<&str>::new()),
+        Err(Error::NotConfigured),
+    ));
+
+    dynamic::load_first(["libblas-missing.so", "libc.so.6"]).unwrap();
+    assert_eq!(
+        dynamic::path().and_then(|path| path.to_str()),
+        Some("libc.so.6")
+    );
+    assert!(matches!(
+        dynamic::load("libc.so.6"),
+        Err(Error::AlreadyLoaded)
+    ));
+
+    assert_eq!(backend::vendor(), Vendor::Unknown);
+    assert_eq!(backend::version(), None);
+    assert_eq!(backend::threads(), None);
+    assert!(!backend::set_threads(2));
+
+    let missing = dynamic::missing_symbols().unwrap();
+    assert!(missing.iter().any(|symbol| symbol.starts_with("dgemm_")));
+
+    let result = panic::catch_unwind(|| unsafe { blas::ddot(0, &[], 1, &[], 1) });
+    let error = result.unwrap_err();
+    let message = error.downcast_ref::<String>().unwrap();
+    assert!(message.starts_with("the symbol ddot_"));
+    assert!(message.ends_with("is missing from the BLAS library libc.so.6"));
+}
@@ -0,0 +1,642 @@
+#![cfg(feature = "reference")]
+
+mod common;
+
+use blas::*;
+use common::*;
+
+const SIZES: [usize; 4] = [0, 1, 3, 5];
+const SHAPES: [(usize, usize); 5] = [(0, 0), (0, 2), (2, 0), (1, 1), (3, 4)];
+const ALL_ORDER: [u8; 2] = [b'C', b'R'];
+const ALL_COPY: [u8; 4] = [b'N', b'T', b'C', b'R'];
+
+type Axpby<T> = unsafe fn(Int, T, &[T], Int, T, &mut [T], Int);
+type Gemmt<T> = unsafe fn(u8, u8, u8, Int, Int, T, &[T], Int, &[T], Int, T, &mut [T], Int);
+type Imatcopy<T> = unsafe fn(u8, u8, Int, Int, T, &mut [T], Int, Int);
+type Omatcopy<T> = unsafe fn(u8, u8, Int, Int, T, &[T], Int, &mut [T], Int);
+type Gemm<T> = unsafe fn(u8, u8, Int, Int, Int, T, &[T], Int, &[T], Int, T, &mut [T], Int);
+type GemmBatch<T> = unsafe fn(
+    &[u8],
+    &[u8],
+    &[Int],
+    &[Int],
+    &[Int],
+    &[T],
+    &[*const T],
+    &[Int],
+    &[*const T],
+    &[Int],
+    &[T],
+    &[*mut T],
+    &[Int],
+    &[Int],
+);
+#[cfg(feature = "half")]
+type Mixed<T> = unsafe fn(u8, u8, Int, Int, Int, f32, &[T], Int, &[T], Int, f32, &mut [f32], Int);
+type Group = (u8, u8, usize, usize, usize, usize);
+type GemmBatchStrided<T> =
+    unsafe fn(u8, u8, Int, Int, Int, T, &[T], Int, Int, &[T], Int, Int, T, &mut [T], Int, Int, Int);
+
+fn check_axpby<T: Element>(routine: Axpby<T>) {
+    let mut rng = Rng::new();
+    for n in SIZES {
+        for (incx, incy) in INCREMENTS {
+            for alpha in scalars::<T>(&mut rng) {
+                for beta in scalars::<T>(&mut rng) {
+                    let mut x = vector::<T>(&mut rng, n, incx);
+                    let mut y = vector::<T>(&mut rng, n, incy);
+                    let mut expected = y.clone();
+                    let ys: Vec<T> = gather(&x, n, incx)
+                        .into_iter()
+                        .zip(gather(&y, n, incy))
+                        .map(|(a, b)| combine(alpha, a, beta, b))
+                        .collect();
+                    scatter(&mut expected, &ys, incy);
+                    if alpha == T::zero() {
+                        poison_vector(&mut x, n, incx);
+                    }
+                    if beta == T::zero() {
+                        poison_vector(&mut y, n, incy);
+                    }
+                    unsafe { routine(n as Int, alpha, &x, incx, beta, &mut y, incy) };
+                    let context = format!(
+                        "axpby n = {}, incx = {}, incy = {}, alpha = {:?}, beta = {:?}",
+                        n, incx, incy, alpha, beta,
+                    );
+                    assert_close(&y, &expected, &context);
+                }
+            }
+        }
+    }
+}
+
+fn check_gemmt<T: Element>(routine: Gemmt<T>) {
+    let mut rng = Rng::new();
+    for uplo in ALL_UPLO {
+        for transa in ALL_TRANS {
+            for transb in ALL_TRANS {
+                for (n, k) in [(0, 0), (0, 2), (2, 0), (1, 1), (3, 4), (4, 3)] {
+                    for alpha in scalars::<T>(&mut rng) {
+                        for beta in scalars::<T>(&mut rng) {
+                            let (rowsa, colsa) = if transa == b'N' { (n, k) } else { (k, n) };
+                            let (rowsb, colsb) = if transb == b'N' { (k, n) } else { (n, k) };
+                            let (lda, ldb, ldc) = (leading(rowsa), leading(rowsb), leading(n));
+                            let mut a = matrix::<T>(&mut rng, colsa, lda);
+                            let mut b = matrix::<T>(&mut rng, colsb, ldb);
+                            let mut c = matrix::<T>(&mut rng, n, ldc);
+                            let mut expected = c.clone();
+                            for j in 0..n {
+                                for i in 0..n {
+                                    if !stored(uplo, i, j) {
+                                        continue;
+                                    }
+                                    let product = (0..k).fold(T::zero(), |sum, l| {
+                                        sum + op(transa, &a, lda, i, l) * op(transb, &b, ldb, l, j)
+                                    });
+                                    expected[i + j * ldc] =
+                                        combine(alpha, product, beta, c[i + j * ldc]);
+                                    if beta == T::zero() {
+                                        c[i + j * ldc] = T::nan();
+                                    }
+                                }
+                            }
+                            if alpha == T::zero() {
+                                poison_matrix(&mut a, rowsa, colsa, lda);
+                                poison_matrix(&mut b, rowsb, colsb, ldb);
+                            }
+                            unsafe {
+                                routine(
+                                    uplo, transa, transb, n as Int, k as Int, alpha, &a,
+                                    lda as Int, &b, ldb as Int, beta, &mut c, ldc as Int,
+                                )
+                            };
+                            let context = format!(
+                                "gemmt uplo = {}, transa = {}, transb = {}, n = {}, k = {}, alpha = {:?}, beta = {:?}",
+                                uplo as char, transa as char, transb as char, n, k, alpha, beta,
+                            );
+                            assert_close(&c, &expected, &context);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Return the position in `B` and the expected value of each element copied from `A`.
+fn copy_expected<T: Element>(
+    order: u8,
+    trans: u8,
+    (rows, cols): (usize, usize),
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    ldb: usize,
+) -> Vec<(usize, T)> {
+    let (rows, cols) = if order == b'R' {
+        (cols, rows)
+    } else {
+        (rows, cols)
+    };
+    let mut elements = vec![];
+    for j in 0..cols {
+        for i in 0..rows {
+            let mut value = a[i + j * lda];
+            if trans == b'C' || trans == b'R' {
+                value = value.conj();
+            }
+            let position = if trans == b'T' || trans == b'C' {
+                j + i * ldb
+            } else {
+                i + j * ldb
+            };
+            elements.push((position, combine(alpha, value, T::zero(), T::zero())));
+        }
+    }
+    elements
+}
+
+/// Return the column-major numbers of rows of `A` and `B` for a copy.
+fn copy_rows(order: u8, trans: u8, (rows, cols): (usize, usize)) -> (usize, usize) {
+    let (rows, cols) = if order == b'R' {
+        (cols, rows)
+    } else {
+        (rows, cols)
+    };
+    if trans == b'T' || trans == b'C' {
+        (rows, cols)
+    } else {
+        (rows, rows)
+    }
+}
+
+fn check_omatcopy<T: Element>(routine: Omatcopy<T>) {
+    let mut rng = Rng::new();
+    for order in ALL_ORDER {
+        for trans in ALL_COPY {
+            for (rows, cols) in SHAPES {
+                for alpha in scalars::<T>(&mut rng) {
+                    let (rowsa, rowsb) = copy_rows(order, trans, (rows, cols));
+                    let (lda, ldb) = (leading(rowsa), leading(rowsb));
+                    let count = rows.max(cols);
+                    let mut a = matrix::<T>(&mut rng, count, lda);
+                    let mut b = matrix::<T>(&mut rng, count, ldb);
+                    let mut expected = b.clone();
+                    for (position, value) in
+                        copy_expected(order, trans, (rows, cols), alpha, &a, lda, ldb)
+                    {
+                        expected[position] = value;
+                    }
+                    if alpha == T::zero() {
+                        let len = a.len();
+                        poison_vector(&mut a, len, 1);
+                    }
+                    unsafe {
+                        routine(
+                            order,
+                            trans,
+                            rows as Int,
+                            cols as Int,
+                            alpha,
+                            &a,
+                            lda as Int,
+                            &mut b,
+                            ldb as Int,
+                        )
+                    };
+                    let context = format!(
+                        "omatcopy order = {}, trans = {}, rows = {}, cols = {}, alpha = {:?}",
+                        order as char, trans as char, rows, cols, alpha,
+                    );
+                    assert_close(&b, &expected, &context);
+                }
+            }
+        }
+    }
+}
+
+fn check_imatcopy<T: Element>(routine: Imatcopy<T>) {
+    let mut rng = Rng::new();
+    for order in ALL_ORDER {
+        for trans in ALL_COPY {
+            for (rows, cols) in SHAPES {
+                for alpha in scalars::<T>(&mut rng) {
+                    let (rowsa, rowsb) = copy_rows(order, trans, (rows, cols));
+                    let (lda, ldb) = (leading(rowsa), leading(rowsb));
+                    let mut ab = matrix::<T>(&mut rng, rows.max(cols), lda.max(ldb));
+                    let mut expected = ab.clone();
+                    for (position, value) in
+                        copy_expected(order, trans, (rows, cols), alpha, &ab, lda, ldb)
+                    {
+                        expected[position] = value;
+                    }
+                    unsafe {
+                        routine(
+                            order,
+                            trans,
+                            rows as Int,
+                            cols as Int,
+                            alpha,
+                            &mut ab,
+                            lda as Int,
+                            ldb as Int,
+                        )
+                    };
+                    let context = format!(
+                        "imatcopy order = {}, trans = {}, rows = {}, cols = {}, alpha = {:?}",
+                        order as char, trans as char, rows, cols, alpha,
+                    );
+                    assert_close(&ab, &expected, &context);
+                }
+            }
+        }
+    }
+}
+
+fn check_gemm_batch_strided<T: Element>(routine: GemmBatchStrided<T>, gemm: Gemm<T>) {
+    let mut rng = Rng::new();
+    for transa in ALL_TRANS {
+        for transb in ALL_TRANS {
+            for (m, n, k) in [(0, 2, 3), (2, 3, 0), (3, 2, 4)] {
+                for batch in [0, 1, 4] {
+                    let (rowsa, colsa) = if transa == b'N' { (m, k) } else { (k, m) };
+                    let (rowsb, colsb) = if transb == b'N' { (k, n) } else { (n, k) };
+                    let (lda, ldb, ldc) = (leading(rowsa), leading(rowsb), leading(m));
+                    let (stridea, strideb, stridec) = (lda * colsa, 0, ldc * n + 1);
+                    let a = random::<T>(&mut rng, stridea * batch.max(1));
+                    let b = matrix::<T>(&mut rng, colsb, ldb);
+                    let mut c = random::<T>(&mut rng, stridec * batch.max(1));
+                    let (alpha, beta) = (T::random(&mut rng), T::random(&mut rng));
+                    let mut expected = c.clone();
+                    for i in 0..batch {
+                        unsafe {
+                            gemm(
+                                transa,
+                                transb,
+                                m as Int,
+                                n as Int,
+                                k as Int,
+                                alpha,
+                                &a[i * stridea..],
+                                lda as Int,
+                                &b,
+                                ldb as Int,
+                                beta,
+                                &mut expected[i * stridec..],
+                                ldc as Int,
+                            )
+                        };
+                    }
+                    unsafe {
+                        routine(
+                            transa,
+                            transb,
+                            m as Int,
+                            n as Int,
+                            k as Int,
+                            alpha,
+                            &a,
+                            lda as Int,
+                            stridea as Int,
+                            &b,
+                            ldb as Int,
+                            strideb as Int,
+                            beta,
+                            &mut c,
+                            ldc as Int,
+                            stridec as Int,
+                            batch as Int,
+                        )
+                    };
+                    let context = format!(
+                        "gemm_batch_strided transa = {}, transb = {}, m = {}, n = {}, k = {}, batch = {}",
+                        transa as char, transb as char, m, n, k, batch,
+                    );
+                    assert_close(&c, &expected, &context);
+                }
+            }
+        }
+    }
+}
+
+fn check_gemm_batch<T: Element>(routine: GemmBatch<T>, gemm: Gemm<T>) {
+    let mut rng = Rng::new();
+    let groups: [Group; 3] = [
+        (b'N', b'T', 2, 3, 4, 3),
+        (b'C', b'N', 3, 1, 2, 0),
+        (b'T', b'C', 1, 2, 3, 2),
+    ];
+    let (mut a, mut b, mut c) = (vec![], vec![], vec![]);
+    for &(transa, transb, m, n, k, size) in &groups {
+        let (rowsa, colsa) = if transa == b'N' { (m, k) } else { (k, m) };
+        let (rowsb, colsb) = if transb == b'N' { (k, n) } else { (n, k) };
+        for _ in 0..size {
+            a.push(matrix::<T>(&mut rng, colsa, leading(rowsa)));
+            b.push(matrix::<T>(&mut rng, colsb, leading(rowsb)));
+            c.push(matrix::<T>(&mut rng, n, leading(m)));
+        }
+    }
+    let alpha: Vec<T> = groups.iter().map(|_| T::random(&mut rng)).collect();
+    let beta: Vec<T> = groups.iter().map(|_| T::random(&mut rng)).collect();
+    let mut expected = c.clone();
+    let mut i = 0;
+    for (g, &(transa, transb, m, n, k, size)) in groups.iter().enumerate() {
+        let lda = leading(if transa == b'N' { m } else { k }) as Int;
+        let ldb = leading(if transb == b'N' { k } else { n }) as Int;
+        for _ in 0..size {
+            unsafe {
+                gemm(
+                    transa,
+                    transb,
+                    m as Int,
+                    n as Int,
+                    k as Int,
+                    alpha[g],
+                    &a[i],
+                    lda,
+                    &b[i],
+                    ldb,
+                    beta[g],
+                    &mut expected[i],
+                    leading(m) as Int,
+                )
+            };
+            i += 1;
+        }
+    }
+    let column = |f: fn(&Group) -> usize| -> Vec<Int> {
+        groups.iter().map(|group| f(group) as Int).collect()
+    };
+    let lda = column(|&(transa, _, m, _, k, _)| leading(if transa == b'N' { m } else { k }));
+    let ldb = column(|&(_, transb, _, n, k, _)| leading(if transb == b'N' { k } else { n }));
+    let pointers: Vec<*mut T> = c.iter_mut().map(|c| c.as_mut_ptr()).collect();
+    unsafe {
+        routine(
+            &groups.map(|group| group.0),
+            &groups.map(|group| group.1),
+            &column(|group| group.2),
+            &column(|group| group.3),
+            &column(|group| group.4),
+            &alpha,
+            &a.iter().map(|a| a.as_ptr()).collect::<Vec<_>>(),
+            &lda,
+            &b.iter().map(|b| b.as_ptr()).collect::<Vec<_>>(),
+            &ldb,
+            &beta,
+            &pointers,
+            &column(|group| leading(group.2)),
+            &column(|group| group.5),
+        )
+    };
+    for (c, expected) in c.iter().zip(&expected) {
+        assert_close(c, expected, "gemm_batch");
+    }
+}
+
+#[test]
+fn axpby() {
+    check_axpby::<f32>(saxpby);
+    check_axpby::<f64>(daxpby);
+    check_axpby::<c32>(caxpby);
+    check_axpby::<c64>(zaxpby);
+}
+
+#[test]
+fn gemmt() {
+    check_gemmt::<f32>(sgemmt);
+    check_gemmt::<f64>(dgemmt);
+    check_gemmt::<c32>(cgemmt);
+    check_gemmt::<c64>(zgemmt);
+}
+
+#[test]
+fn omatcopy() {
+    check_omatcopy::<f32>(somatcopy);
+    check_omatcopy::<f64>(domatcopy);
+    check_omatcopy::<c32>(comatcopy);
+    check_omatcopy::<c64>(zomatcopy);
+}
+
+#[test]
+fn imatcopy() {
+    check_imatcopy::<f32>(simatcopy);
+    check_imatcopy::<f64>(dimatcopy);
+    check_imatcopy::<c32>(cimatcopy);
+    check_imatcopy::<c64>(zimatcopy);
+}
+
+#[test]
+fn gemm_batch() {
+    check_gemm_batch::<f32>(sgemm_batch, sgemm);
+    check_gemm_batch::<f64>(dgemm_batch, dgemm);
+    check_gemm_batch::<c32>(cgemm_batch, cgemm);
+    check_gemm_batch::<c64>(zgemm_batch, zgemm);
+}
+
+#[test]
+fn gemm_batch_strided() {
+    check_gemm_batch_strided::<f32>(sgemm_batch_strided, sgemm);
+    check_gemm_batch_strided::<f64>(dgemm_batch_strided, dgemm);
+    check_gemm_batch_strided::<c32>(cgemm_batch_strided, cgemm);
+    check_gemm_batch_strided::<c64>(zgemm_batch_strided, zgemm);
+}
+
+#[test]
+fn checked() {
+    let a = vec![1.0; 6];
+    let mut b = vec![0.0; 6];
+    assert!(safe::domatcopy(b'R', b'T', 2, 3, 1.0, &a, 3, &mut b, 2).is_ok());
+    assert_eq!(
+        safe::domatcopy(b'R', b'T', 2, 3, 1.0, &a, 3, &mut b, 1),
+        Err(safe::BlasError::InvalidArgument {
+            routine: "domatcopy",
+            param: 9,
+            reason: safe::Reason::LeadingDimension {
+                actual: 1,
+                minimum: 2,
+            },
+        }),
+    );
+    assert_eq!(
+        safe::dimatcopy(b'C', b'X', 2, 3, 1.0, &mut b, 2, 3),
+        Err(safe::BlasError::InvalidArgument {
+            routine: "dimatcopy",
+            param: 2,
+            reason: safe::Reason::InvalidFlag(b'X'),
+        }),
+    );
+    let mut c = vec![0.0; 3];
+    assert_eq!(
+        safe::dgemmt(b'U', b'N', b'N', 2, 3, 1.0, &a, 2, &a, 3, 0.0, &mut c, 2),
+        Err(safe::BlasError::BufferTooShort {
+            routine: "dgemmt",
+            param: 12,
+            required: 4,
+            actual: 3,
+        }),
+    );
+    let (x, y) = (vec![1.0; 4], vec![2.0; 4]);
+    let (mut p, mut q) = (vec![0.0; 4], vec![0.0; 4]);
+    let mut c: Vec<&mut [f64]> = vec![&mut p, &mut q];
+    safe::dgemm_batch(
+        b'N',
+        b'N',
+        2,
+        2,
+        2,
+        1.0,
+        &[&x, &y],
+        2,
+        &[&y, &x],
+        2,
+        0.0,
+        &mut c,
+        2,
+    )
+    .unwrap();
+    assert_eq!((p, q), (vec![4.0; 4], vec![4.0; 4]));
+    let mut c = vec![0.0; 7];
+    assert_eq!(
+        safe::dgemm_batch_strided(
+            b'N', b'N', 2, 2, 2, 1.0, &x, 2, 0, &y, 2, 0, 0.0, &mut c, 2, 3, 2
+        ),
+        Err(safe::BlasError::InvalidArgument {
+            routine: "dgemm_batch_strided",
+            param: 16,
+            reason: safe::Reason::LeadingDimension {
+                actual: 3,
+                minimum: 4,
+            },
+        }),
+    );
+    assert!(safe::dgemm_batch_strided(
+        b'N', b'N', 2, 2, 2, 1.0, &x, 2, 0, &y, 2, 0, 0.0, &mut c, 2, 4, 2
+    )
+    .is_err());
+    assert_eq!(
+        safe::dgemm_batch_strided(
+            b'N', b'N', 2, 2, 2, 1.0, &x, 2, -4, &y, 2, 0, 0.0, &mut c, 2, 4, 1
+        ),
+        Err(safe::BlasError::InvalidArgument {
+            routine: "dgemm_batch_strided",
+            param: 9,
+            reason: safe::Reason::NegativeDimension(-4),
+        }),
+    );
+    assert_eq!(
+        safe::dgemm_batch_strided(
+            b'N', b'N', 2, 2, 2, 1.0, &x, 2, 0, &y, 2, 0, 0.0, &mut c, 2, -4, 1
+        ),
+        Err(safe::BlasError::InvalidArgument {
+            routine: "dgemm_batch_strided",
+            param: 16,
+            reason: safe::Reason::NegativeDimension(-4),
+        }),
+    );
+    assert_eq!(
+        safe::dgemm_batch_strided(
+            b'N', b'N', 2, 2, 2, 1.0, &x, 2, 4, &y, 2, 0, 0.0, &mut c, 2, 4, 2
+        ),
+        Err(safe::BlasError::BufferTooShort {
+            routine: "dgemm_batch_strided",
+            param: 7,
+            required: 8,
+            actual: 4,
+        }),
+    );
+    assert!(matches!(
+        safe::dgemm_batch_strided(
+            b'N',
+            b'N',
+            2,
+            2,
+            2,
+            1.0,
+            &x,
+            2,
+            Int::MAX,
+            &y,
+            2,
+            0,
+            0.0,
+            &mut c,
+            2,
+            Int::MAX,
+            Int::MAX,
+        ),
+        Err(safe::BlasError::BufferTooShort { param: 7, .. }),
+    ));
+}
+
+#[cfg(feature = "half")]
+fn check_mixed<T: Copy>(routine: Mixed<T>, narrow: fn(f32) -> T)
+where
+    f32: From<T>,
+{
+    let mut rng = Rng::new();
+    for transa in ALL_TRANS {
+        for transb in ALL_TRANS {
+            let (m, n, k) = (3, 2, 4);
+            let (rowsa, colsa) = if transa == b'N' { (m, k) } else { (k, m) };
+            let (rowsb, colsb) = if transb == b'N' { (k, n) } else { (n, k) };
+            let (lda, ldb, ldc) = (leading(rowsa), leading(rowsb), leading(m));
+            let a: Vec<T> = matrix::<f32>(&mut rng, colsa, lda)
+                .into_iter()
+                .map(narrow)
+                .collect();
+            let b: Vec<T> = matrix::<f32>(&mut rng, colsb, ldb)
+                .into_iter()
+                .map(narrow)
+                .collect();
+            let mut c = matrix::<f32>(&mut rng, n, ldc);
+            let (alpha, beta) = (f32::random(&mut rng), f32::random(&mut rng));
+            let mut expected = c.clone();
+            let wide = |data: &[T]| {
+                data.iter()
+                    .map(|&value| f32::from(value))
+                    .collect::<Vec<_>>()
+            };
+            unsafe {
+                sgemm(
+                    transa,
+                    transb,
+                    m as Int,
+                    n as Int,
+                    k as Int,
+                    alpha,
+                    &wide(&a),
+                    lda as Int,
+                    &wide(&b),
+                    ldb as Int,
+                    beta,
+                    &mut expected,
+                    ldc as Int,
+                );
+                routine(
+                    transa, transb, m as Int, n as Int, k as Int, alpha, &a, lda as Int, &b,
+                    ldb as Int, beta, &mut c, ldc as Int,
+                );
+            }
+            assert_eq!(c, expected);
+        }
+    }
+}
+
+#[cfg(feature = "half")]
+#[test]
+fn mixed() {
+    check_mixed::<bf16>(sbgemm, bf16::from_f32);
+    check_mixed::<f16>(shgemm, f16::from_f32);
+    check_mixed::<bf16>(gemm_bf16bf16f32, bf16::from_f32);
+    let a = vec![bf16::ONE; 3];
+    let mut c = vec![0.0; 4];
+    assert_eq!(
+        safe::sbgemm(b'N', b'N', 2, 2, 2, 1.0, &a, 2, &a, 2, 0.0, &mut c, 2),
+        Err(safe::BlasError::BufferTooShort {
+            routine: "sbgemm",
+            param: 7,
+            required: 4,
+            actual: 3,
+        }),
+    );
+}
@@ -0,0 +1,381 @@
+#![cfg(all(feature = "faer", feature = "reference"))]
+
+mod common;
+
+use blas::interop::faer::{mat_mut, mat_ref, vec_mut, vec_ref, MatrixExt};
+use blas::safe::{BlasError, Reason};
+use blas::*;
+use common::*;
+use faer::{Col, ColRef, Mat, MatMut, MatRef};
+
+const UPLOS: [Uplo; 2] = [Uplo::Upper, Uplo::Lower];
+
+/// A matrix held by a `Mat` in one of the layouts returned by `layouts`.
+struct Stored<T> {
+    mat: Mat<T>,
+    rows: usize,
+    cols: usize,
+    layout: usize,
+}
+
+impl<T: Element> Stored<T> {
+    fn new(values: &Mat<T>, layout: usize) -> Self {
+        let (m, n) = (values.nrows(), values.ncols());
+        let mat = match layout {
+            1 => Mat::from_fn(n, m, |i, j| values[(j, i)]),
+            2 => Mat::from_fn(m + 2, n + 1, |i, j| {
+                if (1..=m).contains(&i) && j > 0 {
+                    values[(i - 1, j - 1)]
+                } else {
+                    T::nan()
+                }
+            }),
+            3 => Mat::from_fn(m, n, |i, j| values[(m - 1 - i, j)]),
+            4 => Mat::from_fn(m, n, |i, j| values[(i, n - 1 - j)]),
+            _ => values.clone(),
+        };
+        Stored {
+            mat,
+            rows: m,
+            cols: n,
+            layout,
+        }
+    }
+
+    fn view(&self) -> MatRef<'_, T> {
+        match self.layout {
+            1 => self.mat.transpose(),
+            2 => self.mat.submatrix(1, 1, self.rows, self.cols),
+            3 => self.mat.reverse_rows(),
+            4 => self.mat.reverse_cols(),
+            _ => self.mat.as_ref(),
+        }
+    }
+
+    fn view_mut(&mut self) -> MatMut<'_, T> {
+        match self.layout {
+            1 => self.mat.transpose_mut(),
+            2 => self.mat.submatrix_mut(1, 1, self.rows, self.cols),
+            3 => self.mat.reverse_rows_mut(),
+            4 => self.mat.reverse_cols_mut(),
+            _ => self.mat.as_mut(),
+        }
+    }
+}
+
+/// Return the same matrix in the column-major and row-major layouts, as a submatrix, and with
+/// reversed rows or columns. BLAS addresses the first two in place unless `Mat` pads them.
+fn layouts<T: Element>(values: &Mat<T>) -> Vec<Stored<T>> {
+    (0..5).map(|layout| Stored::new(values, layout)).collect()
+}
+
+fn random_matrix<T: Element>(rng: &mut Rng, rows: usize, cols: usize) -> Mat<T> {
+    let values = random::<T>(rng, rows * cols);
+    Mat::from_fn(rows, cols, |i, j| values[i + j * rows])
+}
+
+/// Return the elements of a matrix in column-major order.
+fn entries<T: Element>(a: MatRef<'_, T>) -> Vec<T> {
+    (0..a.ncols())
+        .flat_map(|j| (0..a.nrows()).map(move |i| a[(i, j)]))
+        .collect()
+}
+
+fn product<T: Element>(
+    alpha: T,
+    a: MatRef<'_, T>,
+    b: MatRef<'_, T>,
+    beta: T,
+    c: MatRef<'_, T>,
+) -> Mat<T> {
+    Mat::from_fn(c.nrows(), c.ncols(), |i, j| {
+        let sum = (0..a.ncols()).fold(T::zero(), |sum, l| sum + a[(i, l)] * b[(l, j)]);
+        combine(alpha, sum, beta, c[(i, j)])
+    })
+}
+
+fn check_gemm<T: Element + Blas>() {
+    let mut rng = Rng::new();
+    let (m, n, k) = (3, 4, 2);
+    let (alpha, beta) = (T::random(&mut rng), T::random(&mut rng));
+    let a = random_matrix::<T>(&mut rng, m, k);
+    let b = random_matrix::<T>(&mut rng, k, n);
+    let c = random_matrix::<T>(&mut rng, m, n);
+    let expected = entries(product(alpha, a.as_ref(), b.as_ref(), beta, c.as_ref()).as_ref());
+    for (i, a) in layouts(&a).iter().enumerate() {
+        for (j, b) in layouts(&b).iter().enumerate() {
+            for (l, mut c) in layouts(&c).into_iter().enumerate() {
+                a.view()
+                    .blas_gemm_scaled(alpha, b.view(), beta, c.view_mut())
+                    .unwrap();
+                let context = format!("gemm with layouts {}, {}, and {}", i, j, l);
+                assert_close(&entries(c.view()), &expected, &context);
+            }
+        }
+    }
+
+    let mut c = Mat::from_fn(m, n, |_, _| T::nan());
+    a.blas_gemm(&b, &mut c).unwrap();
+    let zero = Mat::from_fn(m, n, |_, _| T::zero());
+    let expected = product(T::one(), a.as_ref(), b.as_ref(), T::zero(), zero.as_ref());
+    assert_close(
+        &entries(c.as_ref()),
+        &entries(expected.as_ref()),
+        "gemm with owned matrices",
+    );
+}
+
+fn check_gemv<T: Element + Blas>() {
+    let mut rng = Rng::new();
+    let (m, n) = (3, 4);
+    let (alpha, beta) = (T::random(&mut rng), T::random(&mut rng));
+    let a = random_matrix::<T>(&mut rng, m, n);
+    let x = random_matrix::<T>(&mut rng, 2, n);
+    let y = random_matrix::<T>(&mut rng, m, 1);
+    for (i, a) in layouts(&a).iter().enumerate() {
+        // A row of a column-major matrix has a stride other than one.
+        let xs: [ColRef<'_, T>; 3] = [
+            x.row(0).transpose(),
+            x.row(1).transpose().reverse_rows(),
+            x.as_ref().transpose().col(0),
+        ];
+        for (j, xv) in xs.into_iter().enumerate() {
+            let column = Mat::from_fn(n, 1, |i, _| xv[i]);
+            for reversed in [false, true] {
+                let mut y = y.clone();
+                let yv = y.col_mut(0);
+                let mut yv = if reversed { yv.reverse_rows_mut() } else { yv };
+                let before = Mat::from_fn(m, 1, |i, _| yv[i]);
+                let expected = product(alpha, a.view(), column.as_ref(), beta, before.as_ref());
+                a.view()
+                    .blas_gemv_scaled(alpha, xv, beta, yv.as_mut())
+                    .unwrap();
+                let actual = Mat::from_fn(m, 1, |i, _| yv[i]);
+                let context = format!("gemv with layouts {} and {}, reversed {}", i, j, reversed);
+                assert_close(
+                    &entries(actual.as_ref()),
+                    &entries(expected.as_ref()),
+                    &context,
+                );
+            }
+        }
+    }
+
+    let x = Col::from_fn(n, |i| x[(0, i)]);
+    let mut y = Col::from_fn(m, |_| T::nan());
+    a.blas_gemv(&x, &mut y).unwrap();
+    let column = Mat::from_fn(n, 1, |i, _| x[i]);
+    let zero = Mat::from_fn(m, 1, |_, _| T::zero());
+    let expected = product(
+        T::one(),
+        a.as_ref(),
+        column.as_ref(),
+        T::zero(),
+        zero.as_ref(),
+    );
+    assert_close(
+        &(0..m).map(|i| y[i]).collect::<Vec<_>>(),
+        &entries(expected.as_ref()),
+        "gemv with owned vectors",
+    );
+}
+
+fn check_trsm<T: Element + Blas>() {
+    let mut rng = Rng::new();
+    let (m, n) = (3, 4);
+    let alpha = T::random(&mut rng);
+    let b = random_matrix::<T>(&mut rng, m, n);
+    let scaled: Vec<T> = entries(b.as_ref())
+        .into_iter()
+        .map(|value| alpha * value)
+        .collect();
+    for side in [Side::Left, Side::Right] {
+        let k = if side == Side::Left { m } else { n };
+        let mut values = random::<T>(&mut rng, k * k);
+        strengthen(&mut values, k, k);
+        let a = Mat::from_fn(k, k, |i, j| values[i + j * k]);
+        for uplo in UPLOS {
+            for diag in [Diag::NonUnit, Diag::Unit] {
+                let triangle = Mat::from_fn(k, k, |i, j| {
+                    common::triangular(uplo as u8, diag as u8, |i, j| a[(i, j)], i, j)
+                });
+                for (i, a) in layouts(&a).iter().enumerate() {
+                    for (j, mut b) in layouts(&b).into_iter().enumerate() {
+                        a.view()
+                            .blas_trsm(side, uplo, diag, alpha, b.view_mut())
+                            .unwrap();
+                        let zero = Mat::from_fn(m, n, |_, _| T::zero());
+                        let (one, x) = (T::one(), b.view());
+                        let actual = match side {
+                            Side::Left => {
+                                product(one, triangle.as_ref(), x, T::zero(), zero.as_ref())
+                            }
+                            Side::Right => {
+                                product(one, x, triangle.as_ref(), T::zero(), zero.as_ref())
+                            }
+                        };
+                        let context =
+                            format!("trsm on the {:?} with layouts {} and {}", side, i, j);
+                        assert_close(&entries(actual.as_ref()), &scaled, &context);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn check_syrk<T: Element + Blas>() {
+    let mut rng = Rng::new();
+    let (n, k) = (4, 3);
+    let (alpha, beta) = (T::random(&mut rng), T::random(&mut rng));
+    let a = random_matrix::<T>(&mut rng, n, k);
+    let c = random_matrix::<T>(&mut rng, n, n);
+    let full = product(alpha, a.as_ref(), a.transpose(), beta, c.as_ref());
+    for uplo in UPLOS {
+        let expected = Mat::from_fn(n, n, |i, j| {
+            if stored(uplo as u8, i, j) {
+                full[(i, j)]
+            } else {
+                c[(i, j)]
+            }
+        });
+        for (i, a) in layouts(&a).iter().enumerate() {
+            for (j, mut c) in layouts(&c).into_iter().enumerate() {
+                a.view().blas_syrk(uplo, alpha, beta, c.view_mut()).unwrap();
+                let context = format!("syrk with layouts {} and {}", i, j);
+                assert_close(&entries(c.view()), &entries(expected.as_ref()), &context);
+            }
+        }
+    }
+}
+
+#[test]
+fn gemm() {
+    check_gemm::<f32>();
+    check_gemm::<f64>();
+    check_gemm::<c32>();
+    check_gemm::<c64>();
+}
+
+#[test]
+fn gemv() {
+    check_gemv::<f32>();
+    check_gemv::<f64>();
+    check_gemv::<c32>();
+    check_gemv::<c64>();
+}
+
+#[test]
+fn trsm() {
+    check_trsm::<f32>();
+    check_trsm::<f64>();
+    check_trsm::<c32>();
+    check_trsm::<c64>();
+}
+
+#[test]
+fn syrk() {
+    check_syrk::<f32>();
+    check_syrk::<f64>();
+    check_syrk::<c32>();
+    check_syrk::<c64>();
+}
+
+#[test]
+fn conversions() {
+    let mut rng = Rng::new();
+    let values = random_matrix::<f64>(&mut rng, 3, 2);
+    for (i, mut stored) in layouts(&values).into_iter().enumerate() {
+        let compatible = i == 0 || i == 2;
+        match mat_ref(&stored.view()) {
+            Some(a) => {
+                assert!(compatible, "mat_ref with layout {}", i);
+                assert_eq!((a.rows(), a.cols()), (3, 2));
+                for j in 0..2 {
+                    for r in 0..3 {
+                        assert_eq!(a.col(j).get(r), Some(&values[(r as usize, j as usize)]));
+                    }
+                }
+            }
+            None => assert!(!compatible, "mat_ref with layout {}", i),
+        }
+        assert_eq!(mat_mut(&mut stored.view_mut()).is_some(), compatible);
+    }
+
+    let mut x = random_matrix::<f64>(&mut rng, 2, 3);
+    let row = x.row(1).transpose().reverse_rows();
+    let xv = vec_ref(&row).unwrap();
+    assert_eq!(xv.inc() as isize, -x.col_stride());
+    assert_eq!(
+        xv.iter().copied().collect::<Vec<_>>(),
+        vec![x[(1, 2)], x[(1, 1)], x[(1, 0)]],
+    );
+    let mut row = x.row_mut(0).transpose_mut();
+    *vec_mut(&mut row).unwrap().get_mut(1).unwrap() = 7.0;
+    assert_eq!(x[(0, 1)], 7.0);
+
+    let mut data = (0..6).map(f64::from).collect::<Vec<_>>();
+    let (mut top, bottom) =
+        MatMut::from_column_major_slice_mut(&mut data, 3, 2).split_at_row_mut(2);
+    let mut a = mat_mut(&mut top).unwrap();
+    assert_eq!((a.rows(), a.cols(), a.ld()), (2, 2, 3));
+    assert!(a.as_mut_slice().is_none());
+    view::scal(2.0, a.col_mut(1));
+    assert_eq!((bottom[(0, 0)], bottom[(0, 1)]), (2.0, 5.0));
+    assert_eq!(data, [0.0, 1.0, 2.0, 6.0, 8.0, 5.0]);
+}
+
+#[test]
+fn errors() {
+    let a = Mat::<f64>::zeros(2, 3);
+    let b = Mat::<f64>::zeros(3, 4);
+    let mut c = Mat::<f64>::zeros(2, 5);
+    assert_eq!(
+        a.blas_gemm(&b, &mut c),
+        Err(BlasError::InvalidArgument {
+            routine: "blas_gemm",
+            param: 2,
+            reason: Reason::DimensionMismatch {
+                actual: 5,
+                expected: 4,
+            },
+        }),
+    );
+    let x = Col::<f64>::zeros(2);
+    let mut y = Col::<f64>::zeros(2);
+    assert_eq!(
+        a.blas_gemv_scaled(1.0, &x, 0.0, &mut y),
+        Err(BlasError::InvalidArgument {
+            routine: "blas_gemv_scaled",
+            param: 2,
+            reason: Reason::DimensionMismatch {
+                actual: 2,
+                expected: 3,
+            },
+        }),
+    );
+    let mut b = Mat::<f64>::zeros(3, 2);
+    assert_eq!(
+        a.blas_trsm(Side::Left, Uplo::Upper, Diag::NonUnit, 1.0, &mut b),
+        Err(BlasError::InvalidArgument {
+            routine: "blas_trsm",
+            param: 0,
+            reason: Reason::DimensionMismatch {
+                actual: 3,
+                expected: 2,
+            },
+        }),
+    );
+    let mut c = Mat::<f64>::zeros(3, 3);
+    assert_eq!(
+        a.blas_syrk(Uplo::Lower, 1.0, 0.0, c.as_mut()),
+        Err(BlasError::InvalidArgument {
+            routine: "blas_syrk",
+            param: 4,
+            reason: Reason::DimensionMismatch {
+                actual: 3,
+                expected: 2,
+            },
+        }),
+    );
+}
@@ -0,0 +1,459 @@
+mod common;
+
+use blas::*;
+use common::*;
+
+const SIZES: [usize; 4] = [0, 1, 3, 5];
+
+type Rotg<T> = unsafe fn(&mut T, &mut T, &mut T, &mut T);
+type ComplexRotg<T, R> = unsafe fn(&mut T, T, &mut R, &mut T);
+type Rotmg<T> = unsafe fn(&mut T, &mut T, &mut T, T, &mut [T]);
+type Rotm<T> = unsafe fn(Int, &mut [T], Int, &mut [T], Int, &[T]);
+type Rot<T, R> = unsafe fn(Int, &mut [T], Int, &mut [T], Int, R, R);
+type Swap<T> = unsafe fn(Int, &mut [T], Int, &mut [T], Int);
+type Scal<T, A> = unsafe fn(Int, A, &mut [T], Int);
+type Copy<T> = unsafe fn(Int, &[T], Int, &mut [T], Int);
+type Axpy<T> = unsafe fn(Int, T, &[T], Int, &mut [T], Int);
+type Dot<T, R> = unsafe fn(Int, &[T], Int, &[T], Int) -> R;
+type Norm<T, R> = unsafe fn(Int, &[T], Int) -> R;
+type Iamax<T> = unsafe fn(Int, &[T], Int) -> usize;
+
+fn check_rotg<T: Element>(routine: Rotg<T>) {
+    let mut rng = Rng::new();
+    let one = T::one();
+    let mut cases = vec![(T::zero(), T::zero()), (one, T::zero()), (T::zero(), one)];
+    cases.extend((0..5).map(|_| (T::random(&mut rng), T::random(&mut rng))));
+    for (a, b) in cases {
+        let (mut r, mut z, mut c, mut s) = (a, b, T::zero(), T::zero());
+        unsafe { routine(&mut r, &mut z, &mut c, &mut s) };
+        let context = format!("rotg a = {:?}, b = {:?}", a, b);
+        assert_close(&[c * a + s * b, c * b - s * a], &[r, T::zero()], &context);
+        assert_close(&[c * c + s * s], &[one], &context);
+    }
+}
+
+fn check_complex_rotg<T: Element>(routine: ComplexRotg<T, T::Real>) {
+    let mut rng = Rng::new();
+    let one = T::one();
+    let mut cases = vec![(T::zero(), T::zero()), (one, T::zero()), (T::zero(), one)];
+    cases.extend((0..5).map(|_| (T::random(&mut rng), T::random(&mut rng))));
+    for (a, b) in cases {
+        let (mut r, mut c, mut s) = (a, T::Real::zero(), T::zero());
+        unsafe { routine(&mut r, b, &mut c, &mut s) };
+        let c = T::from_real(c);
+        let context = format!("rotg a = {:?}, b = {:?}", a, b);
+        assert_close(
+            &[c * a + s * b, c * b - s.conj() * a],
+            &[r, T::zero()],
+            &context,
+        );
+        assert_close(&[c * c + s * s.conj()], &[one], &context);
+    }
+}
+
+fn check_rotmg<T: Element>(routine: Rotmg<T>) {
+    let mut rng = Rng::new();
+    let half = T::one() / (T::one() + T::one());
+    for case in 0..8 {
+        let mut d1 = T::random(&mut rng) * T::random(&mut rng) + T::one();
+        let mut d2 = T::random(&mut rng) * T::random(&mut rng) + half;
+        let mut x1 = T::random(&mut rng);
+        let y1 = if case == 0 {
+            T::zero()
+        } else {
+            T::random(&mut rng)
+        };
+        let (d1_0, d2_0, x1_0) = (d1, d2, x1);
+        let mut param = [T::zero(); 5];
+        unsafe { routine(&mut d1, &mut d2, &mut x1, y1, &mut param) };
+        let [h11, h21, h12, h22] = matrix_from_param(&param);
+        let context = format!("rotmg case {}", case);
+        assert_close(
+            &[h11 * x1_0 + h12 * y1, h21 * x1_0 + h22 * y1],
+            &[x1, T::zero()],
+            &context,
+        );
+        assert_close(
+            &[d1 * x1 * x1],
+            &[d1_0 * x1_0 * x1_0 + d2_0 * y1 * y1],
+            &context,
+        );
+    }
+}
+
+fn matrix_from_param<T: Element>(param: &[T]) -> [T; 4] {
+    let (one, zero) = (T::one(), T::zero());
+    let flag = param[0];
+    if flag == -one {
+        [param[1], param[2], param[3], param[4]]
+    } else if flag == zero {
+        [one, param[2], param[3], one]
+    } else if flag == one {
+        [param[1], -one, one, param[4]]
+    } else {
+        [one, zero, zero, one]
+    }
+}
+
+fn check_rotm<T: Element>(routine: Rotm<T>) {
+    let mut rng = Rng::new();
+    let one = T::one();
+    for flag in [-(one + one), -one, T::zero(), one] {
+        for n in SIZES {
+            for (incx, incy) in INCREMENTS {
+                let mut param = random::<T>(&mut rng, 5);
+                param[0] = flag;
+                let [h11, h21, h12, h22] = matrix_from_param(&param);
+                let mut x = vector::<T>(&mut rng, n, incx);
+                let mut y = vector::<T>(&mut rng, n, incy);
+                let (mut x_expected, mut y_expected) = (x.clone(), y.clone());
+                let (xs, ys) = (gather(&x, n, incx), gather(&y, n, incy));
+                let xs_new: Vec<T> = xs
+                    .iter()
+                    .zip(&ys)
+                    .map(|(&a, &b)| h11 * a + h12 * b)
+                    .collect();
+                let ys_new: Vec<T> = xs
+                    .iter()
+                    .zip(&ys)
+                    .map(|(&a, &b)| h21 * a + h22 * b)
+                    .collect();
+                scatter(&mut x_expected, &xs_new, incx);
+                scatter(&mut y_expected, &ys_new, incy);
+                unsafe { routine(n as Int, &mut x, incx, &mut y, incy, &param) };
+                let context = format!(
+                    "rotm flag = {:?}, n = {}, incx = {}, incy = {}",
+                    flag, n, incx, incy
+                );
+                assert_close(&x, &x_expected, &context);
+                assert_close(&y, &y_expected, &context);
+            }
+        }
+    }
+}
+
+fn check_rot<T: Element>(routine: Rot<T, T::Real>) {
+    let mut rng = Rng::new();
+    for n in SIZES {
+        for (incx, incy) in INCREMENTS {
+            let (c, s) = (T::random(&mut rng).re(), T::random(&mut rng).re());
+            let (ct, st) = (T::from_real(c), T::from_real(s));
+            let mut x = vector::<T>(&mut rng, n, incx);
+            let mut y = vector::<T>(&mut rng, n, incy);
+            let (mut x_expected, mut y_expected) = (x.clone(), y.clone());
+            let (xs, ys) = (gather(&x, n, incx), gather(&y, n, incy));
+            let xs_new: Vec<T> = xs.iter().zip(&ys).map(|(&a, &b)| ct * a + st * b).collect();
+            let ys_new: Vec<T> = xs.iter().zip(&ys).map(|(&a, &b)| ct * b - st * a).collect();
+            scatter(&mut x_expected, &xs_new, incx);
+            scatter(&mut y_expected, &ys_new, incy);
+            unsafe { routine(n as Int, &mut x, incx, &mut y, incy, c, s) };
+            let context = format!("rot n = {}, incx = {}, incy = {}", n, incx, incy);
+            assert_close(&x, &x_expected, &context);
+            assert_close(&y, &y_expected, &context);
+        }
+    }
+}
+
+fn check_swap<T: Element>(routine: Swap<T>) {
+    let mut rng = Rng::new();
+    for n in SIZES {
+        for (incx, incy) in INCREMENTS {
+            let mut x = vector::<T>(&mut rng, n, incx);
+            let mut y = vector::<T>(&mut rng, n, incy);
+            let (mut x_expected, mut y_expected) = (x.clone(), y.clone());
+            scatter(&mut x_expected, &gather(&y, n, incy), incx);
+            scatter(&mut y_expected, &gather(&x, n, incx), incy);
+            unsafe { routine(n as Int, &mut x, incx, &mut y, incy) };
+            let context = format!("swap n = {}, incx = {}, incy = {}", n, incx, incy);
+            assert_close(&x, &x_expected, &context);
+            assert_close(&y, &y_expected, &context);
+        }
+    }
+}
+
+fn check_scal<T: Element, A: Element>(routine: Scal<T, A>, convert: fn(A) -> T) {
+    let mut rng = Rng::new();
+    for n in SIZES {
+        for (incx, _) in INCREMENTS {
+            for alpha in scalars::<A>(&mut rng) {
+                let mut x = vector::<T>(&mut rng, n, incx);
+                let mut expected = x.clone();
+                // Non-positive increments make BLAS return early.
+                if incx > 0 {
+                    let xs: Vec<T> = gather(&x, n, incx)
+                        .into_iter()
+                        .map(|value| convert(alpha) * value)
+                        .collect();
+                    scatter(&mut expected, &xs, incx);
+                }
+                unsafe { routine(n as Int, alpha, &mut x, incx) };
+                let context = format!("scal n = {}, incx = {}, alpha = {:?}", n, incx, alpha);
+                assert_close(&x, &expected, &context);
+            }
+        }
+    }
+}
+
+fn check_copy<T: Element>(routine: Copy<T>) {
+    let mut rng = Rng::new();
+    for n in SIZES {
+        for (incx, incy) in INCREMENTS {
+            let x = vector::<T>(&mut rng, n, incx);
+            let mut y = vector::<T>(&mut rng, n, incy);
+            let mut expected = y.clone();
+            scatter(&mut expected, &gather(&x, n, incx), incy);
+            unsafe { routine(n as Int, &x, incx, &mut y, incy) };
+            let context = format!("copy n = {}, incx = {}, incy = {}", n, incx, incy);
+            assert_close(&y, &expected, &context);
+        }
+    }
+}
+
+fn check_axpy<T: Element>(routine: Axpy<T>) {
+    let mut rng = Rng::new();
+    for n in SIZES {
+        for (incx, incy) in INCREMENTS {
+            for alpha in scalars::<T>(&mut rng) {
+                let mut x = vector::<T>(&mut rng, n, incx);
+                if alpha == T::zero() {
+                    poison_vector(&mut x, n, incx);
+                }
+                let mut y = vector::<T>(&mut rng, n, incy);
+                let mut expected = y.clone();
+                let ys: Vec<T> = gather(&x, n, incx)
+                    .into_iter()
+                    .zip(gather(&y, n, incy))
+                    .map(|(a, b)| combine(alpha, a, T::one(), b))
+                    .collect();
+                scatter(&mut expected, &ys, incy);
+                unsafe { routine(n as Int, alpha, &x, incx, &mut y, incy) };
+                let context = format!(
+                    "axpy n = {}, incx = {}, incy = {}, alpha = {:?}",
+                    n, incx, incy, alpha
+                );
+                assert_close(&y, &expected, &context);
+            }
+        }
+    }
+}
+
+/// Compute the dot product of two vectors in buffers, optionally conjugating the first one.
+fn dot_product<T: Element>(x: &[T], incx: Int, y: &[T], incy: Int, n: usize, conjugate: bool) -> T {
+    gather(x, n, incx)
+        .into_iter()
+        .zip(gather(y, n, incy))
+        .fold(T::zero(), |sum, (a, b)| {
+            sum + if conjugate { a.conj() } else { a } * b
+        })
+}
+
+fn check_dot<T: Element>(routine: Dot<T, T>) {
+    let mut rng = Rng::new();
+    for n in SIZES {
+        for (incx, incy) in INCREMENTS {
+            let x = vector::<T>(&mut rng, n, incx);
+            let y = vector::<T>(&mut rng, n, incy);
+            let expected = dot_product(&x, incx, &y, incy, n, false);
+            let actual = unsafe { routine(n as Int, &x, incx, &y, incy) };
+            let context = format!("dot n = {}, incx = {}, incy = {}", n, incx, incy);
+            assert_close(&[actual], &[expected], &context);
+        }
+    }
+}
+
+fn check_complex_dot<T: Element>(routine: Dot<T, T>, conjugate: bool) {
+    let mut rng = Rng::new();
+    for n in SIZES {
+        for (incx, incy) in INCREMENTS {
+            let x = vector::<T>(&mut rng, n, incx);
+            let y = vector::<T>(&mut rng, n, incy);
+            let expected = dot_product(&x, incx, &y, incy, n, conjugate);
+            let actual = unsafe { routine(n as Int, &x, incx, &y, incy) };
+            let context = format!("dot n = {}, incx = {}, incy = {}", n, incx, incy);
+            assert_close(&[actual], &[expected], &context);
+        }
+    }
+}
+
+fn check_nrm2<T: Element>(routine: Norm<T, T::Real>)
+where
+    T::Real: Element,
+{
+    let mut rng = Rng::new();
+    for n in SIZES {
+        for (incx, _) in INCREMENTS.into_iter().filter(|&(incx, _)| incx > 0) {
+            let x = vector::<T>(&mut rng, n, incx);
+            let sum = gather(&x, n, incx)
+                .into_iter()
+                .fold(T::zero(), |sum, value| sum + value.conj() * value);
+            let expected = sum.re().magnitude().sqrt();
+            let actual = unsafe { routine(n as Int, &x, incx) };
+            let context = format!("nrm2 n = {}, incx = {}", n, incx);
+            assert_close_real::<T>(actual.magnitude(), expected, &context);
+        }
+    }
+}
+
+fn check_asum<T: Element>(routine: Norm<T, T::Real>)
+where
+    T::Real: Element,
+{
+    let mut rng = Rng::new();
+    for n in SIZES {
+        for (incx, _) in INCREMENTS {
+            let x = vector::<T>(&mut rng, n, incx);
+            let expected = if incx > 0 {
+                gather(&x, n, incx).into_iter().map(T::abs1).sum()
+            } else {
+                0.0
+            };
+            let actual = unsafe { routine(n as Int, &x, incx) };
+            let context = format!("asum n = {}, incx = {}", n, incx);
+            assert_close_real::<T>(actual.magnitude(), expected, &context);
+        }
+    }
+}
+
+fn check_iamax<T: Element>(routine: Iamax<T>) {
+    let mut rng = Rng::new();
+    for n in SIZES {
+        for (incx, _) in INCREMENTS {
+            let mut x = vector::<T>(&mut rng, n, incx);
+            if n > 2 {
+                // A tie is resolved in favor of the first element.
+                let values = gather(&x, n, incx);
+                let last = values[n - 1];
+                let mut values = values;
+                values[1] = last;
+                scatter(&mut x, &values, incx);
+            }
+            let mut expected = 0;
+            if incx > 0 {
+                let mut max = -1.0;
+                for (i, value) in gather(&x, n, incx).into_iter().enumerate() {
+                    if value.abs1() > max {
+                        max = value.abs1();
+                        expected = i + 1;
+                    }
+                }
+            }
+            let actual = unsafe { routine(n as Int, &x, incx) };
+            assert_eq!(actual, expected, "iamax n = {}, incx = {}", n, incx);
+        }
+    }
+}
+
+#[test]
+fn rotg() {
+    check_rotg::<f32>(srotg);
+    check_rotg::<f64>(drotg);
+    check_complex_rotg::<c32>(crotg);
+    check_complex_rotg::<c64>(zrotg);
+}
+
+#[test]
+fn rotmg() {
+    check_rotmg::<f32>(srotmg);
+    check_rotmg::<f64>(drotmg);
+}
+
+#[test]
+fn rotm() {
+    check_rotm::<f32>(srotm);
+    check_rotm::<f64>(drotm);
+}
+
+#[test]
+fn rot() {
+    check_rot::<f32>(srot);
+    check_rot::<f64>(drot);
+    check_rot::<c32>(csrot);
+    check_rot::<c64>(zdrot);
+}
+
+#[test]
+fn swap() {
+    check_swap::<f32>(sswap);
+    check_swap::<f64>(dswap);
+    check_swap::<c32>(cswap);
+    check_swap::<c64>(zswap);
+}
+
+#[test]
+fn scal() {
+    check_scal::<f32, f32>(sscal, |alpha| alpha);
+    check_scal::<f64, f64>(dscal, |alpha| alpha);
+    check_scal::<c32, c32>(cscal, |alpha| alpha);
+    check_scal::<c64, c64>(zscal, |alpha| alpha);
+    check_scal::<c32, f32>(csscal, c32::from_real);
+    check_scal::<c64, f64>(zdscal, c64::from_real);
+}
+
+#[test]
+fn copy() {
+    check_copy::<f32>(scopy);
+    check_copy::<f64>(dcopy);
+    check_copy::<c32>(ccopy);
+    check_copy::<c64>(zcopy);
+}
+
+#[test]
+fn axpy() {
+    check_axpy::<f32>(saxpy);
+    check_axpy::<f64>(daxpy);
+    check_axpy::<c32>(caxpy);
+    check_axpy::<c64>(zaxpy);
+}
+
+#[test]
+fn dot() {
+    check_dot::<f32>(sdot);
+    check_dot::<f64>(ddot);
+    check_complex_dot::<c32>(cdotu, false);
+    check_complex_dot::<c64>(zdotu, false);
+    check_complex_dot::<c32>(cdotc, true);
+    check_complex_dot::<c64>(zdotc, true);
+}
+
+#[test]
+fn extended_dot() {
+    let mut rng = Rng::new();
+    for n in SIZES {
+        for (incx, incy) in INCREMENTS {
+            let x = vector::<f32>(&mut rng, n, incx);
+            let y = vector::<f32>(&mut rng, n, incy);
+            let widen =
+                |buffer: &[f32]| buffer.iter().map(|&value| value as f64).collect::<Vec<_>>();
+            let expected = dot_product(&widen(&x), incx, &widen(&y), incy, n, false);
+            let context = format!("dsdot n = {}, incx = {}, incy = {}", n, incx, incy);
+            let actual = unsafe { dsdot(n as Int, &x, incx, &y, incy) };
+            assert_close(&[actual], &[expected], &context);
+            let actual = unsafe { sdsdot(n as Int, &[0.5], &x, incx, &y, incy) };
+            assert_close(&[actual], &[(expected + 0.5) as f32], &context);
+        }
+    }
+}
+
+#[test]
+fn nrm2() {
+    check_nrm2::<f32>(snrm2);
+    check_nrm2::<f64>(dnrm2);
+    check_nrm2::<c32>(scnrm2);
+    check_nrm2::<c64>(dznrm2);
+}
+
+#[test]
+fn asum() {
+    check_asum::<f32>(sasum);
+    check_asum::<f64>(dasum);
+    check_asum::<c32>(scasum);
+    check_asum::<c64>(dzasum);
+}
+
+#[test]
+fn iamax() {
+    check_iamax::<f32>(isamax);
+    check_iamax::<f64>(idamax);
+    check_iamax::<c32>(icamax);
+    check_iamax::<c64>(izamax);
+}
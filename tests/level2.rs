@@ -0,0 +1,642 @@
+mod common;
+
+use blas::*;
+use common::*;
+
+const SIZES: [usize; 4] = [0, 1, 3, 4];
+const SHAPES: [(usize, usize); 6] = [(0, 0), (0, 2), (2, 0), (1, 1), (3, 4), (4, 3)];
+const BANDS: [(usize, usize); 3] = [(0, 0), (1, 2), (2, 1)];
+
+type Gemv<T> = unsafe fn(u8, Int, Int, T, &[T], Int, &[T], Int, T, &mut [T], Int);
+type Gbmv<T> = unsafe fn(u8, Int, Int, Int, Int, T, &[T], Int, &[T], Int, T, &mut [T], Int);
+type Hemv<T> = unsafe fn(u8, Int, T, &[T], Int, &[T], Int, T, &mut [T], Int);
+type Hbmv<T> = unsafe fn(u8, Int, Int, T, &[T], Int, &[T], Int, T, &mut [T], Int);
+type Hpmv<T> = unsafe fn(u8, Int, T, &[T], &[T], Int, T, &mut [T], Int);
+type Trmv<T> = unsafe fn(u8, u8, u8, Int, &[T], Int, &mut [T], Int);
+type Tbmv<T> = unsafe fn(u8, u8, u8, Int, Int, &[T], Int, &mut [T], Int);
+type Tpmv<T> = unsafe fn(u8, u8, u8, Int, &[T], &mut [T], Int);
+type Ger<T> = unsafe fn(Int, Int, T, &[T], Int, &[T], Int, &mut [T], Int);
+type Her<T, R> = unsafe fn(u8, Int, R, &[T], Int, &mut [T], Int);
+type Hpr<T, R> = unsafe fn(u8, Int, R, &[T], Int, &mut [T]);
+type Her2<T> = unsafe fn(u8, Int, T, &[T], Int, &[T], Int, &mut [T], Int);
+type Hpr2<T> = unsafe fn(u8, Int, T, &[T], Int, &[T], Int, &mut [T]);
+
+/// Compute `alpha * A * x + beta * y` for a matrix given by `element`.
+fn multiply<T: Element>(
+    element: impl Fn(usize, usize) -> T,
+    alpha: T,
+    x: &[T],
+    beta: T,
+    y: &[T],
+) -> Vec<T> {
+    y.iter()
+        .enumerate()
+        .map(|(i, &value)| {
+            let product = x
+                .iter()
+                .enumerate()
+                .fold(T::zero(), |sum, (j, &x)| sum + element(i, j) * x);
+            combine(alpha, product, beta, value)
+        })
+        .collect()
+}
+
+/// Store the band of a dense `m`-by-`n` matrix in the format of `gbmv`.
+fn band<T: Element>(
+    rng: &mut Rng,
+    full: &[T],
+    (m, n): (usize, usize),
+    (kl, ku): (usize, usize),
+    lda: usize,
+) -> Vec<T> {
+    let mut a = matrix(rng, n, lda);
+    for j in 0..n {
+        for i in j.saturating_sub(ku)..m.min(j + kl + 1) {
+            a[ku + i - j + j * lda] = full[i + j * m];
+        }
+    }
+    a
+}
+
+/// Return whether `(i, j)` lies within the band.
+fn in_band(i: usize, j: usize, (kl, ku): (usize, usize)) -> bool {
+    i <= j + kl && j <= i + ku
+}
+
+/// Return the lower and upper bandwidths of a triangular band matrix.
+fn triangular_band(uplo: u8, k: usize) -> (usize, usize) {
+    if uplo == b'U' {
+        (0, k)
+    } else {
+        (k, 0)
+    }
+}
+
+fn packed_storage<T: Element>(full: &[T], uplo: u8, n: usize) -> Vec<T> {
+    let mut ap = vec![T::zero(); (n * (n + 1) / 2).max(1)];
+    for j in 0..n {
+        for i in 0..n {
+            if stored(uplo, i, j) {
+                ap[packed(uplo, n, i, j)] = full[i + j * n];
+            }
+        }
+    }
+    ap
+}
+
+fn general_storage<T: Element>(rng: &mut Rng, full: &[T], m: usize, n: usize, ld: usize) -> Vec<T> {
+    let mut a = matrix(rng, n, ld);
+    for j in 0..n {
+        for i in 0..m {
+            a[i + j * ld] = full[i + j * m];
+        }
+    }
+    a
+}
+
+fn check_gemv<T: Element>(routine: Gemv<T>) {
+    let mut rng = Rng::new();
+    for trans in ALL_TRANS {
+        for (m, n) in SHAPES {
+            for (incx, incy) in INCREMENTS {
+                for alpha in scalars::<T>(&mut rng) {
+                    for beta in scalars::<T>(&mut rng) {
+                        let (lenx, leny) = if trans == b'N' { (n, m) } else { (m, n) };
+                        let lda = leading(m);
+                        let mut a = matrix::<T>(&mut rng, n, lda);
+                        let mut x = vector::<T>(&mut rng, lenx, incx);
+                        let mut y = vector::<T>(&mut rng, leny, incy);
+                        let mut expected = y.clone();
+                        if m > 0 && n > 0 {
+                            let element = |i, j| op(trans, &a, lda, i, j);
+                            let (xs, ys) = (gather(&x, lenx, incx), gather(&y, leny, incy));
+                            scatter(
+                                &mut expected,
+                                &multiply(element, alpha, &xs, beta, &ys),
+                                incy,
+                            );
+                            poison(
+                                alpha,
+                                beta,
+                                (&mut a, m, n, lda),
+                                (&mut x, lenx, incx),
+                                (&mut y, leny, incy),
+                            );
+                        }
+                        unsafe {
+                            routine(
+                                trans, m as Int, n as Int, alpha, &a, lda as Int, &x, incx, beta,
+                                &mut y, incy,
+                            )
+                        };
+                        let context = format!(
+                            "gemv trans = {}, m = {}, n = {}, incx = {}, incy = {}, alpha = {:?}, beta = {:?}",
+                            trans as char, m, n, incx, incy, alpha, beta,
+                        );
+                        assert_close(&y, &expected, &context);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Replace the operands that must not be read given `alpha` and `beta` with NaNs.
+fn poison<T: Element>(
+    alpha: T,
+    beta: T,
+    (a, rows, cols, lda): (&mut [T], usize, usize, usize),
+    (x, lenx, incx): (&mut [T], usize, Int),
+    (y, leny, incy): (&mut [T], usize, Int),
+) {
+    if alpha == T::zero() {
+        poison_matrix(a, rows, cols, lda);
+        poison_vector(x, lenx, incx);
+    }
+    if beta == T::zero() {
+        poison_vector(y, leny, incy);
+    }
+}
+
+fn check_gbmv<T: Element>(routine: Gbmv<T>) {
+    let mut rng = Rng::new();
+    for trans in ALL_TRANS {
+        for (m, n) in SHAPES {
+            for (kl, ku) in BANDS {
+                for (incx, incy) in INCREMENTS {
+                    for alpha in scalars::<T>(&mut rng) {
+                        for beta in scalars::<T>(&mut rng) {
+                            let (lenx, leny) = if trans == b'N' { (n, m) } else { (m, n) };
+                            let lda = kl + ku + 2;
+                            let full = random::<T>(&mut rng, m * n);
+                            let mut a = band(&mut rng, &full, (m, n), (kl, ku), lda);
+                            let mut x = vector::<T>(&mut rng, lenx, incx);
+                            let mut y = vector::<T>(&mut rng, leny, incy);
+                            let mut expected = y.clone();
+                            if m > 0 && n > 0 {
+                                let dense = |i: usize, j: usize| {
+                                    if in_band(i, j, (kl, ku)) {
+                                        full[i + j * m]
+                                    } else {
+                                        T::zero()
+                                    }
+                                };
+                                let element = |i, j| {
+                                    if trans == b'N' {
+                                        dense(i, j)
+                                    } else {
+                                        apply(trans, dense(j, i))
+                                    }
+                                };
+                                let (xs, ys) = (gather(&x, lenx, incx), gather(&y, leny, incy));
+                                scatter(
+                                    &mut expected,
+                                    &multiply(element, alpha, &xs, beta, &ys),
+                                    incy,
+                                );
+                                poison(
+                                    alpha,
+                                    beta,
+                                    (&mut a, lda, n, lda),
+                                    (&mut x, lenx, incx),
+                                    (&mut y, leny, incy),
+                                );
+                            }
+                            unsafe {
+                                routine(
+                                    trans, m as Int, n as Int, kl as Int, ku as Int, alpha, &a,
+                                    lda as Int, &x, incx, beta, &mut y, incy,
+                                )
+                            };
+                            let context = format!(
+                                "gbmv trans = {}, m = {}, n = {}, kl = {}, ku = {}, incx = {}, incy = {}, \
+                                 alpha = {:?}, beta = {:?}",
+                                trans as char, m, n, kl, ku, incx, incy, alpha, beta,
+                            );
+                            assert_close(&y, &expected, &context);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The storage formats of symmetric, Hermitian, and triangular matrices.
+#[derive(Clone, Copy, Debug)]
+enum Storage {
+    General,
+    Band(usize),
+    Packed,
+}
+
+impl Storage {
+    fn all() -> [Storage; 4] {
+        [
+            Storage::General,
+            Storage::Band(0),
+            Storage::Band(2),
+            Storage::Packed,
+        ]
+    }
+
+    /// Store the triangle of a dense matrix of order `n` selected by `uplo`, and return the
+    /// storage and its leading dimension.
+    fn store<T: Element>(self, rng: &mut Rng, full: &[T], uplo: u8, n: usize) -> (Vec<T>, usize) {
+        match self {
+            Storage::General => {
+                let lda = leading(n);
+                (general_storage(rng, full, n, n, lda), lda)
+            }
+            Storage::Band(k) => {
+                let lda = k + 2;
+                (band(rng, full, (n, n), triangular_band(uplo, k), lda), lda)
+            }
+            Storage::Packed => (packed_storage(full, uplo, n), 0),
+        }
+    }
+
+    /// Return element `(i, j)` of the dense matrix as seen by a routine using this format.
+    fn element<T: Element>(self, full: &[T], n: usize, i: usize, j: usize) -> T {
+        match self {
+            Storage::Band(k) if i > j + k || j > i + k => T::zero(),
+            _ => full[i + j * n],
+        }
+    }
+}
+
+fn check_hemv<T: Element>(general: Hemv<T>, banded: Hbmv<T>, packed: Hpmv<T>) {
+    let mut rng = Rng::new();
+    for storage in Storage::all() {
+        for uplo in ALL_UPLO {
+            for n in SIZES {
+                for (incx, incy) in INCREMENTS {
+                    for alpha in scalars::<T>(&mut rng) {
+                        for beta in scalars::<T>(&mut rng) {
+                            let full = random::<T>(&mut rng, n * n);
+                            let (mut a, lda) = storage.store(&mut rng, &full, uplo, n);
+                            let mut x = vector::<T>(&mut rng, n, incx);
+                            let mut y = vector::<T>(&mut rng, n, incy);
+                            let mut expected = y.clone();
+                            if n > 0 {
+                                let element = |i, j| {
+                                    symmetric(
+                                        uplo,
+                                        true,
+                                        |i, j| storage.element(&full, n, i, j),
+                                        i,
+                                        j,
+                                    )
+                                };
+                                let (xs, ys) = (gather(&x, n, incx), gather(&y, n, incy));
+                                scatter(
+                                    &mut expected,
+                                    &multiply(element, alpha, &xs, beta, &ys),
+                                    incy,
+                                );
+                                let len = a.len();
+                                poison(
+                                    alpha,
+                                    beta,
+                                    (&mut a, len, 1, len),
+                                    (&mut x, n, incx),
+                                    (&mut y, n, incy),
+                                );
+                            }
+                            let (n, lda) = (n as Int, lda as Int);
+                            unsafe {
+                                match storage {
+                                    Storage::General => general(
+                                        uplo, n, alpha, &a, lda, &x, incx, beta, &mut y, incy,
+                                    ),
+                                    Storage::Band(k) => banded(
+                                        uplo, n, k as Int, alpha, &a, lda, &x, incx, beta, &mut y,
+                                        incy,
+                                    ),
+                                    Storage::Packed => {
+                                        packed(uplo, n, alpha, &a, &x, incx, beta, &mut y, incy)
+                                    }
+                                }
+                            };
+                            let context = format!(
+                                "hemv storage = {:?}, uplo = {}, n = {}, incx = {}, incy = {}, alpha = {:?}, \
+                                 beta = {:?}",
+                                storage, uplo as char, n, incx, incy, alpha, beta,
+                            );
+                            assert_close(&y, &expected, &context);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn check_trmv<T: Element>(general: Trmv<T>, banded: Tbmv<T>, packed: Tpmv<T>, solve: bool) {
+    let mut rng = Rng::new();
+    for storage in Storage::all() {
+        for uplo in ALL_UPLO {
+            for trans in ALL_TRANS {
+                for diag in ALL_DIAG {
+                    for n in SIZES {
+                        for (incx, _) in INCREMENTS {
+                            let mut full = random::<T>(&mut rng, n * n);
+                            strengthen(&mut full, n, n);
+                            let (a, lda) = storage.store(&mut rng, &full, uplo, n);
+                            let mut x = vector::<T>(&mut rng, n, incx);
+                            let mut expected = x.clone();
+                            let dense = |i, j| {
+                                triangular(uplo, diag, |i, j| storage.element(&full, n, i, j), i, j)
+                            };
+                            let element = |i, j| {
+                                if trans == b'N' {
+                                    dense(i, j)
+                                } else {
+                                    apply(trans, dense(j, i))
+                                }
+                            };
+                            let xs = gather(&x, n, incx);
+                            let result = if solve {
+                                substitute(n, (uplo == b'U') == (trans == b'N'), element, &xs)
+                            } else {
+                                multiply(element, T::one(), &xs, T::zero(), &xs)
+                            };
+                            scatter(&mut expected, &result, incx);
+                            let (n, lda) = (n as Int, lda as Int);
+                            unsafe {
+                                match storage {
+                                    Storage::General => {
+                                        general(uplo, trans, diag, n, &a, lda, &mut x, incx)
+                                    }
+                                    Storage::Band(k) => banded(
+                                        uplo, trans, diag, n, k as Int, &a, lda, &mut x, incx,
+                                    ),
+                                    Storage::Packed => {
+                                        packed(uplo, trans, diag, n, &a, &mut x, incx)
+                                    }
+                                }
+                            };
+                            let context = format!(
+                                "{} storage = {:?}, uplo = {}, trans = {}, diag = {}, n = {}, incx = {}",
+                                if solve { "trsv" } else { "trmv" },
+                                storage,
+                                uplo as char,
+                                trans as char,
+                                diag as char,
+                                n,
+                                incx,
+                            );
+                            assert_close(&x, &expected, &context);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn check_ger<T: Element>(routine: Ger<T>, conjugate: bool) {
+    let mut rng = Rng::new();
+    for (m, n) in SHAPES {
+        for (incx, incy) in INCREMENTS {
+            for alpha in scalars::<T>(&mut rng) {
+                let lda = leading(m);
+                let mut a = matrix::<T>(&mut rng, n, lda);
+                let mut x = vector::<T>(&mut rng, m, incx);
+                let mut y = vector::<T>(&mut rng, n, incy);
+                let mut expected = a.clone();
+                let (xs, ys) = (gather(&x, m, incx), gather(&y, n, incy));
+                if alpha != T::zero() {
+                    for j in 0..n {
+                        let yj = if conjugate { ys[j].conj() } else { ys[j] };
+                        for i in 0..m {
+                            expected[i + j * lda] = expected[i + j * lda] + alpha * xs[i] * yj;
+                        }
+                    }
+                } else {
+                    poison_vector(&mut x, m, incx);
+                    poison_vector(&mut y, n, incy);
+                }
+                unsafe {
+                    routine(
+                        m as Int, n as Int, alpha, &x, incx, &y, incy, &mut a, lda as Int,
+                    )
+                };
+                let context = format!(
+                    "ger m = {}, n = {}, incx = {}, incy = {}, alpha = {:?}",
+                    m, n, incx, incy, alpha,
+                );
+                assert_close(&a, &expected, &context);
+            }
+        }
+    }
+}
+
+/// Apply a rank-one or rank-two Hermitian update to the stored triangle of `a` located by
+/// `index`, where `y` is `None` for rank-one updates.
+fn update<T: Element>(
+    a: &mut [T],
+    index: impl Fn(usize, usize) -> usize,
+    uplo: u8,
+    alpha: T,
+    x: &[T],
+    y: Option<&[T]>,
+) {
+    let n = x.len();
+    for j in 0..n {
+        for i in 0..n {
+            if !stored(uplo, i, j) {
+                continue;
+            }
+            let mut value = a[index(i, j)] + alpha * x[i] * x[j].conj();
+            if let Some(y) = y {
+                value =
+                    a[index(i, j)] + alpha * x[i] * y[j].conj() + alpha.conj() * y[i] * x[j].conj();
+            }
+            a[index(i, j)] = if i == j {
+                T::from_real(value.re())
+            } else {
+                value
+            };
+        }
+    }
+}
+
+fn check_her<T: Element>(general: Her<T, T::Real>, packed: Hpr<T, T::Real>)
+where
+    T::Real: Element,
+{
+    let mut rng = Rng::new();
+    for storage in [Storage::General, Storage::Packed] {
+        for uplo in ALL_UPLO {
+            for n in SIZES {
+                for (incx, _) in INCREMENTS {
+                    for alpha in scalars::<T::Real>(&mut rng) {
+                        let full = random::<T>(&mut rng, n * n);
+                        let (mut a, lda) = storage.store(&mut rng, &full, uplo, n);
+                        let mut x = vector::<T>(&mut rng, n, incx);
+                        let mut expected = a.clone();
+                        if n > 0 && alpha != T::Real::zero() {
+                            let xs = gather(&x, n, incx);
+                            match storage {
+                                Storage::Packed => update(
+                                    &mut expected,
+                                    |i, j| common::packed(uplo, n, i, j),
+                                    uplo,
+                                    T::from_real(alpha),
+                                    &xs,
+                                    None,
+                                ),
+                                _ => update(
+                                    &mut expected,
+                                    |i, j| i + j * lda,
+                                    uplo,
+                                    T::from_real(alpha),
+                                    &xs,
+                                    None,
+                                ),
+                            }
+                        } else {
+                            poison_vector(&mut x, n, incx);
+                        }
+                        unsafe {
+                            match storage {
+                                Storage::Packed => packed(uplo, n as Int, alpha, &x, incx, &mut a),
+                                _ => general(uplo, n as Int, alpha, &x, incx, &mut a, lda as Int),
+                            }
+                        };
+                        let context = format!(
+                            "her storage = {:?}, uplo = {}, n = {}, incx = {}, alpha = {:?}",
+                            storage, uplo as char, n, incx, alpha,
+                        );
+                        assert_close(&a, &expected, &context);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn check_her2<T: Element>(general: Her2<T>, packed: Hpr2<T>) {
+    let mut rng = Rng::new();
+    for storage in [Storage::General, Storage::Packed] {
+        for uplo in ALL_UPLO {
+            for n in SIZES {
+                for (incx, incy) in INCREMENTS {
+                    for alpha in scalars::<T>(&mut rng) {
+                        let full = random::<T>(&mut rng, n * n);
+                        let (mut a, lda) = storage.store(&mut rng, &full, uplo, n);
+                        let mut x = vector::<T>(&mut rng, n, incx);
+                        let mut y = vector::<T>(&mut rng, n, incy);
+                        let mut expected = a.clone();
+                        if n > 0 && alpha != T::zero() {
+                            let (xs, ys) = (gather(&x, n, incx), gather(&y, n, incy));
+                            match storage {
+                                Storage::Packed => update(
+                                    &mut expected,
+                                    |i, j| common::packed(uplo, n, i, j),
+                                    uplo,
+                                    alpha,
+                                    &xs,
+                                    Some(&ys),
+                                ),
+                                _ => update(
+                                    &mut expected,
+                                    |i, j| i + j * lda,
+                                    uplo,
+                                    alpha,
+                                    &xs,
+                                    Some(&ys),
+                                ),
+                            }
+                        } else {
+                            poison_vector(&mut x, n, incx);
+                            poison_vector(&mut y, n, incy);
+                        }
+                        unsafe {
+                            match storage {
+                                Storage::Packed => {
+                                    packed(uplo, n as Int, alpha, &x, incx, &y, incy, &mut a)
+                                }
+                                _ => general(
+                                    uplo, n as Int, alpha, &x, incx, &y, incy, &mut a, lda as Int,
+                                ),
+                            }
+                        };
+                        let context = format!(
+                            "her2 storage = {:?}, uplo = {}, n = {}, incx = {}, incy = {}, alpha = {:?}",
+                            storage, uplo as char, n, incx, incy, alpha,
+                        );
+                        assert_close(&a, &expected, &context);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn gemv() {
+    check_gemv::<f32>(sgemv);
+    check_gemv::<f64>(dgemv);
+    check_gemv::<c32>(cgemv);
+    check_gemv::<c64>(zgemv);
+}
+
+#[test]
+fn gbmv() {
+    check_gbmv::<f32>(sgbmv);
+    check_gbmv::<f64>(dgbmv);
+    check_gbmv::<c32>(cgbmv);
+    check_gbmv::<c64>(zgbmv);
+}
+
+#[test]
+fn hemv() {
+    check_hemv::<f32>(ssymv, ssbmv, sspmv);
+    check_hemv::<f64>(dsymv, dsbmv, dspmv);
+    check_hemv::<c32>(chemv, chbmv, chpmv);
+    check_hemv::<c64>(zhemv, zhbmv, zhpmv);
+}
+
+#[test]
+fn trmv() {
+    check_trmv::<f32>(strmv, stbmv, stpmv, false);
+    check_trmv::<f64>(dtrmv, dtbmv, dtpmv, false);
+    check_trmv::<c32>(ctrmv, ctbmv, ctpmv, false);
+    check_trmv::<c64>(ztrmv, ztbmv, ztpmv, false);
+}
+
+#[test]
+fn trsv() {
+    check_trmv::<f32>(strsv, stbsv, stpsv, true);
+    check_trmv::<f64>(dtrsv, dtbsv, dtpsv, true);
+    check_trmv::<c32>(ctrsv, ctbsv, ctpsv, true);
+    check_trmv::<c64>(ztrsv, ztbsv, ztpsv, true);
+}
+
+#[test]
+fn ger() {
+    check_ger::<f32>(sger, false);
+    check_ger::<f64>(dger, false);
+    check_ger::<c32>(cgeru, false);
+    check_ger::<c64>(zgeru, false);
+    check_ger::<c32>(cgerc, true);
+    check_ger::<c64>(zgerc, true);
+}
+
+#[test]
+fn her() {
+    check_her::<f32>(ssyr, sspr);
+    check_her::<f64>(dsyr, dspr);
+    check_her::<c32>(cher, chpr);
+    check_her::<c64>(zher, zhpr);
+}
+
+#[test]
+fn her2() {
+    check_her2::<f32>(ssyr2, sspr2);
+    check_her2::<f64>(dsyr2, dspr2);
+    check_her2::<c32>(cher2, chpr2);
+    check_her2::<c64>(zher2, zhpr2);
+}
@@ -0,0 +1,603 @@
+mod common;
+
+use blas::*;
+use common::*;
+
+const SHAPES: [(usize, usize); 6] = [(0, 0), (0, 2), (2, 0), (1, 1), (3, 4), (4, 3)];
+const PRODUCTS: [(usize, usize, usize); 7] = [
+    (0, 0, 0),
+    (0, 2, 3),
+    (2, 0, 3),
+    (2, 3, 0),
+    (1, 1, 1),
+    (3, 4, 2),
+    (4, 2, 3),
+];
+
+type Gemm<T> = unsafe fn(u8, u8, Int, Int, Int, T, &[T], Int, &[T], Int, T, &mut [T], Int);
+type Hemm<T> = unsafe fn(u8, u8, Int, Int, T, &[T], Int, &[T], Int, T, &mut [T], Int);
+type Herk<T, S> = unsafe fn(u8, u8, Int, Int, S, &[T], Int, S, &mut [T], Int);
+type Her2k<T, S> = unsafe fn(u8, u8, Int, Int, T, &[T], Int, &[T], Int, S, &mut [T], Int);
+type Trmm<T> = unsafe fn(u8, u8, u8, u8, Int, Int, T, &[T], Int, &mut [T], Int);
+
+/// Check the generic rank updates against the routines they forward to, transposing `a` with
+/// the flag each of them accepts.
+fn check_generic<T: Element + Blas>(
+    (syrk, herk): (Herk<T, T>, Herk<T, T::Real>),
+    (syr2k, her2k): (Her2k<T, T>, Her2k<T, T::Real>),
+) {
+    let mut rng = Rng::new();
+    let (n, k) = (3, 2);
+    let a = matrix::<T>(&mut rng, n, k);
+    let b = matrix::<T>(&mut rng, n, k);
+    let c = matrix::<T>(&mut rng, n, n);
+    let (alpha, beta) = (T::random(&mut rng), T::random(&mut rng));
+    let (scale, shift) = (alpha.re(), beta.re());
+    let (n, k) = (n as Int, k as Int);
+
+    let (mut actual, mut expected) = (c.clone(), c.clone());
+    unsafe {
+        T::syrk(
+            Uplo::Upper,
+            NoneOrTrans::Ordinary,
+            n,
+            k,
+            alpha,
+            &a,
+            k,
+            beta,
+            &mut actual,
+            n,
+        );
+        syrk(b'U', b'T', n, k, alpha, &a, k, beta, &mut expected, n);
+    }
+    assert_eq!(actual, expected);
+
+    let (mut actual, mut expected) = (c.clone(), c.clone());
+    unsafe {
+        T::herk(
+            Uplo::Lower,
+            NoneOrConj::Conjugate,
+            n,
+            k,
+            scale,
+            &a,
+            k,
+            shift,
+            &mut actual,
+            n,
+        );
+        herk(b'L', b'C', n, k, scale, &a, k, shift, &mut expected, n);
+    }
+    assert_eq!(actual, expected);
+
+    let (mut actual, mut expected) = (c.clone(), c.clone());
+    unsafe {
+        T::syr2k(
+            Uplo::Lower,
+            NoneOrTrans::Ordinary,
+            n,
+            k,
+            alpha,
+            &a,
+            k,
+            &b,
+            k,
+            beta,
+            &mut actual,
+            n,
+        );
+        syr2k(
+            b'L',
+            b'T',
+            n,
+            k,
+            alpha,
+            &a,
+            k,
+            &b,
+            k,
+            beta,
+            &mut expected,
+            n,
+        );
+    }
+    assert_eq!(actual, expected);
+
+    let (mut actual, mut expected) = (c.clone(), c);
+    unsafe {
+        T::her2k(
+            Uplo::Upper,
+            NoneOrConj::Conjugate,
+            n,
+            k,
+            alpha,
+            &a,
+            k,
+            &b,
+            k,
+            shift,
+            &mut actual,
+            n,
+        );
+        her2k(
+            b'U',
+            b'C',
+            n,
+            k,
+            alpha,
+            &a,
+            k,
+            &b,
+            k,
+            shift,
+            &mut expected,
+            n,
+        );
+    }
+    assert_eq!(actual, expected);
+}
+
+/// Replace the stored triangle of a matrix of order `n` with NaNs.
+fn poison_triangle<T: Element>(c: &mut [T], uplo: u8, n: usize, ldc: usize) {
+    for j in 0..n {
+        for i in 0..n {
+            if stored(uplo, i, j) {
+                c[i + j * ldc] = T::nan();
+            }
+        }
+    }
+}
+
+fn check_gemm<T: Element>(routine: Gemm<T>) {
+    let mut rng = Rng::new();
+    for transa in ALL_TRANS {
+        for transb in ALL_TRANS {
+            for (m, n, k) in PRODUCTS {
+                for alpha in scalars::<T>(&mut rng) {
+                    for beta in scalars::<T>(&mut rng) {
+                        let (rowsa, colsa) = if transa == b'N' { (m, k) } else { (k, m) };
+                        let (rowsb, colsb) = if transb == b'N' { (k, n) } else { (n, k) };
+                        let (lda, ldb, ldc) = (leading(rowsa), leading(rowsb), leading(m));
+                        let mut a = matrix::<T>(&mut rng, colsa, lda);
+                        let mut b = matrix::<T>(&mut rng, colsb, ldb);
+                        let mut c = matrix::<T>(&mut rng, n, ldc);
+                        let mut expected = c.clone();
+                        if m > 0 && n > 0 {
+                            for j in 0..n {
+                                for i in 0..m {
+                                    let product = (0..k).fold(T::zero(), |sum, l| {
+                                        sum + op(transa, &a, lda, i, l) * op(transb, &b, ldb, l, j)
+                                    });
+                                    expected[i + j * ldc] =
+                                        combine(alpha, product, beta, c[i + j * ldc]);
+                                }
+                            }
+                            if alpha == T::zero() {
+                                poison_matrix(&mut a, rowsa, colsa, lda);
+                                poison_matrix(&mut b, rowsb, colsb, ldb);
+                            }
+                            if beta == T::zero() {
+                                poison_matrix(&mut c, m, n, ldc);
+                            }
+                        }
+                        unsafe {
+                            routine(
+                                transa, transb, m as Int, n as Int, k as Int, alpha, &a,
+                                lda as Int, &b, ldb as Int, beta, &mut c, ldc as Int,
+                            )
+                        };
+                        let context = format!(
+                            "gemm transa = {}, transb = {}, m = {}, n = {}, k = {}, alpha = {:?}, beta = {:?}",
+                            transa as char, transb as char, m, n, k, alpha, beta,
+                        );
+                        assert_close(&c, &expected, &context);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn check_hemm<T: Element>(routine: Hemm<T>, hermitian: bool) {
+    let mut rng = Rng::new();
+    for side in ALL_SIDE {
+        for uplo in ALL_UPLO {
+            for (m, n) in SHAPES {
+                for alpha in scalars::<T>(&mut rng) {
+                    for beta in scalars::<T>(&mut rng) {
+                        let order = if side == b'L' { m } else { n };
+                        let (lda, ldb, ldc) = (leading(order), leading(m), leading(m));
+                        let mut a = matrix::<T>(&mut rng, order, lda);
+                        let mut b = matrix::<T>(&mut rng, n, ldb);
+                        let mut c = matrix::<T>(&mut rng, n, ldc);
+                        let mut expected = c.clone();
+                        if m > 0 && n > 0 {
+                            let s = |i, j| symmetric(uplo, hermitian, |i, j| a[i + j * lda], i, j);
+                            for j in 0..n {
+                                for i in 0..m {
+                                    let product = if side == b'L' {
+                                        (0..m).fold(T::zero(), |sum, l| {
+                                            sum + s(i, l) * b[l + j * ldb]
+                                        })
+                                    } else {
+                                        (0..n).fold(T::zero(), |sum, l| {
+                                            sum + b[i + l * ldb] * s(l, j)
+                                        })
+                                    };
+                                    expected[i + j * ldc] =
+                                        combine(alpha, product, beta, c[i + j * ldc]);
+                                }
+                            }
+                            if alpha == T::zero() {
+                                poison_matrix(&mut a, order, order, lda);
+                                poison_matrix(&mut b, m, n, ldb);
+                            }
+                            if beta == T::zero() {
+                                poison_matrix(&mut c, m, n, ldc);
+                            }
+                        }
+                        unsafe {
+                            routine(
+                                side, uplo, m as Int, n as Int, alpha, &a, lda as Int, &b,
+                                ldb as Int, beta, &mut c, ldc as Int,
+                            )
+                        };
+                        let context = format!(
+                            "hemm side = {}, uplo = {}, m = {}, n = {}, alpha = {:?}, beta = {:?}",
+                            side as char, uplo as char, m, n, alpha, beta,
+                        );
+                        assert_close(&c, &expected, &context);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Return the transpose flags accepted by a rank-k update.
+fn rank_transposes<T: Element>(hermitian: bool) -> &'static [u8] {
+    if !T::COMPLEX {
+        b"NTC"
+    } else if hermitian {
+        b"NC"
+    } else {
+        b"NT"
+    }
+}
+
+/// Compute the sum over `l` of `op(X)(i, l) * op(Y)(j, l)`, conjugating the second factor for
+/// Hermitian updates.
+fn rank_product<T: Element>(
+    trans: u8,
+    hermitian: bool,
+    (x, ldx): (&[T], usize),
+    (y, ldy): (&[T], usize),
+    k: usize,
+    i: usize,
+    j: usize,
+) -> T {
+    let trans = if hermitian && trans != b'N' {
+        b'C'
+    } else {
+        trans
+    };
+    let conjugate = |value: T| if hermitian { value.conj() } else { value };
+    (0..k).fold(T::zero(), |sum, l| {
+        sum + op(trans, x, ldx, i, l) * conjugate(op(trans, y, ldy, j, l))
+    })
+}
+
+fn check_herk<T: Element, S: Element>(routine: Herk<T, S>, convert: fn(S) -> T, hermitian: bool) {
+    let mut rng = Rng::new();
+    for uplo in ALL_UPLO {
+        for &trans in rank_transposes::<T>(hermitian) {
+            for (n, k) in SHAPES {
+                for alpha in scalars::<S>(&mut rng) {
+                    for beta in scalars::<S>(&mut rng) {
+                        let (rows, cols) = if trans == b'N' { (n, k) } else { (k, n) };
+                        let (lda, ldc) = (leading(rows), leading(n));
+                        let mut a = matrix::<T>(&mut rng, cols, lda);
+                        let mut c = matrix::<T>(&mut rng, n, ldc);
+                        let mut expected = c.clone();
+                        let (alpha_t, beta_t) = (convert(alpha), convert(beta));
+                        let quick = n == 0 || ((alpha == S::zero() || k == 0) && beta == S::one());
+                        if !quick {
+                            for j in 0..n {
+                                for i in 0..n {
+                                    if !stored(uplo, i, j) {
+                                        continue;
+                                    }
+                                    let product = rank_product(
+                                        trans,
+                                        hermitian,
+                                        (&a, lda),
+                                        (&a, lda),
+                                        k,
+                                        i,
+                                        j,
+                                    );
+                                    let value = combine(alpha_t, product, beta_t, c[i + j * ldc]);
+                                    expected[i + j * ldc] = if i == j && hermitian {
+                                        T::from_real(value.re())
+                                    } else {
+                                        value
+                                    };
+                                }
+                            }
+                            if alpha == S::zero() {
+                                poison_matrix(&mut a, rows, cols, lda);
+                            }
+                            if beta == S::zero() {
+                                poison_triangle(&mut c, uplo, n, ldc);
+                            }
+                        }
+                        unsafe {
+                            routine(
+                                uplo, trans, n as Int, k as Int, alpha, &a, lda as Int, beta,
+                                &mut c, ldc as Int,
+                            )
+                        };
+                        let context = format!(
+                            "herk uplo = {}, trans = {}, n = {}, k = {}, alpha = {:?}, beta = {:?}",
+                            uplo as char, trans as char, n, k, alpha, beta,
+                        );
+                        assert_close(&c, &expected, &context);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn check_her2k<T: Element, S: Element>(routine: Her2k<T, S>, convert: fn(S) -> T, hermitian: bool) {
+    let mut rng = Rng::new();
+    for uplo in ALL_UPLO {
+        for &trans in rank_transposes::<T>(hermitian) {
+            for (n, k) in SHAPES {
+                for alpha in scalars::<T>(&mut rng) {
+                    for beta in scalars::<S>(&mut rng) {
+                        let (rows, cols) = if trans == b'N' { (n, k) } else { (k, n) };
+                        let (lda, ldb, ldc) = (leading(rows), leading(rows), leading(n));
+                        let mut a = matrix::<T>(&mut rng, cols, lda);
+                        let mut b = matrix::<T>(&mut rng, cols, ldb);
+                        let mut c = matrix::<T>(&mut rng, n, ldc);
+                        let mut expected = c.clone();
+                        let beta_t = convert(beta);
+                        let quick = n == 0 || ((alpha == T::zero() || k == 0) && beta == S::one());
+                        if !quick {
+                            let other = if hermitian { alpha.conj() } else { alpha };
+                            for j in 0..n {
+                                for i in 0..n {
+                                    if !stored(uplo, i, j) {
+                                        continue;
+                                    }
+                                    let product = alpha
+                                        * rank_product(
+                                            trans,
+                                            hermitian,
+                                            (&a, lda),
+                                            (&b, ldb),
+                                            k,
+                                            i,
+                                            j,
+                                        )
+                                        + other
+                                            * rank_product(
+                                                trans,
+                                                hermitian,
+                                                (&b, ldb),
+                                                (&a, lda),
+                                                k,
+                                                i,
+                                                j,
+                                            );
+                                    let value = combine(T::one(), product, beta_t, c[i + j * ldc]);
+                                    expected[i + j * ldc] = if i == j && hermitian {
+                                        T::from_real(value.re())
+                                    } else {
+                                        value
+                                    };
+                                }
+                            }
+                            if alpha == T::zero() {
+                                poison_matrix(&mut a, rows, cols, lda);
+                                poison_matrix(&mut b, rows, cols, ldb);
+                            }
+                            if beta == S::zero() {
+                                poison_triangle(&mut c, uplo, n, ldc);
+                            }
+                        }
+                        unsafe {
+                            routine(
+                                uplo, trans, n as Int, k as Int, alpha, &a, lda as Int, &b,
+                                ldb as Int, beta, &mut c, ldc as Int,
+                            )
+                        };
+                        let context = format!(
+                            "her2k uplo = {}, trans = {}, n = {}, k = {}, alpha = {:?}, beta = {:?}",
+                            uplo as char, trans as char, n, k, alpha, beta,
+                        );
+                        assert_close(&c, &expected, &context);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn check_trmm<T: Element>(routine: Trmm<T>, solve: bool) {
+    let mut rng = Rng::new();
+    for side in ALL_SIDE {
+        for uplo in ALL_UPLO {
+            for transa in ALL_TRANS {
+                for diag in ALL_DIAG {
+                    for (m, n) in SHAPES {
+                        for alpha in scalars::<T>(&mut rng) {
+                            let order = if side == b'L' { m } else { n };
+                            let (lda, ldb) = (leading(order), leading(m));
+                            let mut a = matrix::<T>(&mut rng, order, lda);
+                            strengthen(&mut a, order, lda);
+                            let mut b = matrix::<T>(&mut rng, n, ldb);
+                            let mut expected = b.clone();
+                            if m > 0 && n > 0 {
+                                let t = |i, j| triangular(uplo, diag, |i, j| a[i + j * lda], i, j);
+                                let op_t = |i, j| {
+                                    if transa == b'N' {
+                                        t(i, j)
+                                    } else {
+                                        apply(transa, t(j, i))
+                                    }
+                                };
+                                let upper = (uplo == b'U') == (transa == b'N');
+                                let scaled = |i: usize, j: usize| alpha * b[i + j * ldb];
+                                if side == b'L' {
+                                    for j in 0..n {
+                                        let column: Vec<T> = (0..m).map(|i| scaled(i, j)).collect();
+                                        let result = if solve {
+                                            substitute(m, upper, op_t, &column)
+                                        } else {
+                                            (0..m)
+                                                .map(|i| {
+                                                    (0..m).fold(T::zero(), |sum, l| {
+                                                        sum + op_t(i, l) * column[l]
+                                                    })
+                                                })
+                                                .collect()
+                                        };
+                                        for i in 0..m {
+                                            expected[i + j * ldb] = result[i];
+                                        }
+                                    }
+                                } else {
+                                    for i in 0..m {
+                                        let row: Vec<T> = (0..n).map(|j| scaled(i, j)).collect();
+                                        let result = if solve {
+                                            substitute(n, !upper, |p, q| op_t(q, p), &row)
+                                        } else {
+                                            (0..n)
+                                                .map(|j| {
+                                                    (0..n).fold(T::zero(), |sum, l| {
+                                                        sum + row[l] * op_t(l, j)
+                                                    })
+                                                })
+                                                .collect()
+                                        };
+                                        for j in 0..n {
+                                            expected[i + j * ldb] = result[j];
+                                        }
+                                    }
+                                }
+                                if alpha == T::zero() {
+                                    for j in 0..n {
+                                        for i in 0..m {
+                                            expected[i + j * ldb] = T::zero();
+                                        }
+                                    }
+                                    poison_matrix(&mut a, order, order, lda);
+                                    poison_matrix(&mut b, m, n, ldb);
+                                }
+                            }
+                            unsafe {
+                                routine(
+                                    side, uplo, transa, diag, m as Int, n as Int, alpha, &a,
+                                    lda as Int, &mut b, ldb as Int,
+                                )
+                            };
+                            let context = format!(
+                                "{} side = {}, uplo = {}, transa = {}, diag = {}, m = {}, n = {}, alpha = {:?}",
+                                if solve { "trsm" } else { "trmm" },
+                                side as char,
+                                uplo as char,
+                                transa as char,
+                                diag as char,
+                                m,
+                                n,
+                                alpha,
+                            );
+                            assert_close(&b, &expected, &context);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn identity<T>(value: T) -> T {
+    value
+}
+
+#[test]
+fn gemm() {
+    check_gemm::<f32>(sgemm);
+    check_gemm::<f64>(dgemm);
+    check_gemm::<c32>(cgemm);
+    check_gemm::<c64>(zgemm);
+}
+
+#[test]
+fn symm() {
+    check_hemm::<f32>(ssymm, false);
+    check_hemm::<f64>(dsymm, false);
+    check_hemm::<c32>(csymm, false);
+    check_hemm::<c64>(zsymm, false);
+}
+
+#[test]
+fn hemm() {
+    check_hemm::<c32>(chemm, true);
+    check_hemm::<c64>(zhemm, true);
+}
+
+#[test]
+fn syrk() {
+    check_herk::<f32, f32>(ssyrk, identity, false);
+    check_herk::<f64, f64>(dsyrk, identity, false);
+    check_herk::<c32, c32>(csyrk, identity, false);
+    check_herk::<c64, c64>(zsyrk, identity, false);
+}
+
+#[test]
+fn herk() {
+    check_herk::<c32, f32>(cherk, c32::from_real, true);
+    check_herk::<c64, f64>(zherk, c64::from_real, true);
+}
+
+#[test]
+fn syr2k() {
+    check_her2k::<f32, f32>(ssyr2k, identity, false);
+    check_her2k::<f64, f64>(dsyr2k, identity, false);
+    check_her2k::<c32, c32>(csyr2k, identity, false);
+    check_her2k::<c64, c64>(zsyr2k, identity, false);
+}
+
+#[test]
+fn her2k() {
+    check_her2k::<c32, f32>(cher2k, c32::from_real, true);
+    check_her2k::<c64, f64>(zher2k, c64::from_real, true);
+}
+
+#[test]
+fn trmm() {
+    check_trmm::<f32>(strmm, false);
+    check_trmm::<f64>(dtrmm, false);
+    check_trmm::<c32>(ctrmm, false);
+    check_trmm::<c64>(ztrmm, false);
+}
+
+#[test]
+fn trsm() {
+    check_trmm::<f32>(strsm, true);
+    check_trmm::<f64>(dtrsm, true);
+    check_trmm::<c32>(ctrsm, true);
+    check_trmm::<c64>(ztrsm, true);
+}
+
+#[test]
+fn generic() {
+    check_generic::<f32>((ssyrk, ssyrk), (ssyr2k, ssyr2k));
+    check_generic::<f64>((dsyrk, dsyrk), (dsyr2k, dsyr2k));
+    check_generic::<c32>((csyrk, cherk), (csyr2k, cher2k));
+    check_generic::<c64>((zsyrk, zherk), (zsyr2k, zher2k));
+}
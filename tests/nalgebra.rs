@@ -0,0 +1,338 @@
+#![cfg(all(feature = "nalgebra", feature = "reference"))]
+
+mod common;
+
+use blas::interop::nalgebra::{mat_mut, mat_ref, vec_mut, vec_ref, MatrixExt};
+use blas::safe::{BlasError, Reason};
+use blas::*;
+use common::*;
+use nalgebra::{DMatrix, DMatrixView, DMatrixViewMut, DVector, DVectorView, DVectorViewMut, Dyn};
+
+const UPLOS: [Uplo; 2] = [Uplo::Upper, Uplo::Lower];
+
+/// A matrix stored with the given strides in a buffer whose other elements are NaN.
+struct Stored<T> {
+    data: Vec<T>,
+    rows: usize,
+    cols: usize,
+    strides: (usize, usize),
+}
+
+impl<T: Element + nalgebra::Scalar> Stored<T> {
+    fn new(values: &DMatrix<T>, strides: (usize, usize)) -> Self {
+        let (rows, cols) = values.shape();
+        let mut data = vec![T::nan(); (rows - 1) * strides.0 + (cols - 1) * strides.1 + 1];
+        for j in 0..cols {
+            for i in 0..rows {
+                data[i * strides.0 + j * strides.1] = values[(i, j)];
+            }
+        }
+        Stored {
+            data,
+            rows,
+            cols,
+            strides,
+        }
+    }
+
+    fn view(&self) -> DMatrixView<'_, T, Dyn, Dyn> {
+        let (rs, cs) = self.strides;
+        DMatrixView::from_slice_with_strides(&self.data, self.rows, self.cols, rs, cs)
+    }
+
+    fn view_mut(&mut self) -> DMatrixViewMut<'_, T, Dyn, Dyn> {
+        let (rs, cs) = self.strides;
+        DMatrixViewMut::from_slice_with_strides_mut(&mut self.data, self.rows, self.cols, rs, cs)
+    }
+
+    fn to_matrix(&self) -> DMatrix<T> {
+        self.view().clone_owned()
+    }
+}
+
+/// Return the same matrix stored with various strides, of which the padded and the last are
+/// copied by BLAS.
+fn layouts<T: Element + nalgebra::Scalar>(values: &DMatrix<T>) -> Vec<Stored<T>> {
+    let (rows, cols) = values.shape();
+    [
+        (1, rows),
+        (1, rows + 2),
+        (cols, 1),
+        (cols + 1, 1),
+        (2, 2 * rows),
+    ]
+    .into_iter()
+    .map(|strides| Stored::new(values, strides))
+    .collect()
+}
+
+fn random_matrix<T: Element + nalgebra::Scalar>(
+    rng: &mut Rng,
+    rows: usize,
+    cols: usize,
+) -> DMatrix<T> {
+    DMatrix::from_vec(rows, cols, random::<T>(rng, rows * cols))
+}
+
+fn product<T: Element + nalgebra::Scalar>(
+    alpha: T,
+    a: &DMatrix<T>,
+    b: &DMatrix<T>,
+    beta: T,
+    c: &DMatrix<T>,
+) -> DMatrix<T> {
+    DMatrix::from_fn(c.nrows(), c.ncols(), |i, j| {
+        let sum = (0..a.ncols()).fold(T::zero(), |sum, l| sum + a[(i, l)] * b[(l, j)]);
+        combine(alpha, sum, beta, c[(i, j)])
+    })
+}
+
+fn check_gemm<T: Element + Blas + nalgebra::Scalar>() {
+    let mut rng = Rng::new();
+    let (m, n, k) = (3, 4, 2);
+    let (alpha, beta) = (T::random(&mut rng), T::random(&mut rng));
+    let a = random_matrix::<T>(&mut rng, m, k);
+    let b = random_matrix::<T>(&mut rng, k, n);
+    let c = random_matrix::<T>(&mut rng, m, n);
+    let expected = product(alpha, &a, &b, beta, &c);
+    for (i, a) in layouts(&a).iter().enumerate() {
+        for (j, b) in layouts(&b).iter().enumerate() {
+            for (l, mut c) in layouts(&c).into_iter().enumerate() {
+                let mut cv = c.view_mut();
+                a.view()
+                    .blas_gemm_scaled(alpha, &b.view(), beta, &mut cv)
+                    .unwrap();
+                let context = format!("gemm with layouts {}, {}, and {}", i, j, l);
+                assert_close(cv.clone_owned().as_slice(), expected.as_slice(), &context);
+            }
+        }
+    }
+
+    let mut c = DMatrix::from_element(m, n, T::nan());
+    a.blas_gemm(&b, &mut c).unwrap();
+    let zero = DMatrix::from_element(m, n, T::zero());
+    let expected = product(T::one(), &a, &b, T::zero(), &zero);
+    assert_close(
+        c.as_slice(),
+        expected.as_slice(),
+        "gemm with owned matrices",
+    );
+}
+
+fn check_gemv<T: Element + Blas + nalgebra::Scalar>() {
+    let mut rng = Rng::new();
+    let (m, n) = (3, 4);
+    let (alpha, beta) = (T::random(&mut rng), T::random(&mut rng));
+    let a = random_matrix::<T>(&mut rng, m, n);
+    let x = random::<T>(&mut rng, 2 * n);
+    let y = random::<T>(&mut rng, 2 * m);
+    for (i, a) in layouts(&a).iter().enumerate() {
+        for inc in [1, 2] {
+            let xv = DVectorView::<T, Dyn, Dyn>::from_slice_with_strides(&x, n, inc, 1);
+            let mut y = y.clone();
+            let mut yv =
+                DVectorViewMut::<T, Dyn, Dyn>::from_slice_with_strides_mut(&mut y, m, 3 - inc, 1);
+            let column = DMatrix::from_iterator(n, 1, xv.iter().copied());
+            let before = DMatrix::from_iterator(m, 1, yv.iter().copied());
+            let expected = product(alpha, &a.to_matrix(), &column, beta, &before);
+            a.view()
+                .blas_gemv_scaled(alpha, &xv, beta, &mut yv)
+                .unwrap();
+            let context = format!("gemv with layout {} and increment {}", i, inc);
+            assert_close(yv.clone_owned().as_slice(), expected.as_slice(), &context);
+        }
+    }
+
+    let x = DVector::from_vec(random::<T>(&mut rng, n));
+    let mut y = DVector::from_element(m, T::nan());
+    a.blas_gemv(&x, &mut y).unwrap();
+    let column = DMatrix::from_column_slice(n, 1, x.as_slice());
+    let zero = DMatrix::from_element(m, 1, T::zero());
+    let expected = product(T::one(), &a, &column, T::zero(), &zero);
+    assert_close(y.as_slice(), expected.as_slice(), "gemv with owned vectors");
+}
+
+fn check_trsm<T: Element + Blas + nalgebra::Scalar>() {
+    let mut rng = Rng::new();
+    let (m, n) = (3, 4);
+    let alpha = T::random(&mut rng);
+    let b = random_matrix::<T>(&mut rng, m, n);
+    let scaled = b.map(|value| alpha * value);
+    for side in [Side::Left, Side::Right] {
+        let k = if side == Side::Left { m } else { n };
+        let mut values = random::<T>(&mut rng, k * k);
+        strengthen(&mut values, k, k);
+        let a = DMatrix::from_vec(k, k, values);
+        for uplo in UPLOS {
+            for diag in [Diag::NonUnit, Diag::Unit] {
+                let triangle = DMatrix::from_fn(k, k, |i, j| {
+                    common::triangular(uplo as u8, diag as u8, |i, j| a[(i, j)], i, j)
+                });
+                for (i, a) in layouts(&a).iter().enumerate() {
+                    for (j, mut b) in layouts(&b).into_iter().enumerate() {
+                        let mut bv = b.view_mut();
+                        a.view()
+                            .blas_trsm(side, uplo, diag, alpha, &mut bv)
+                            .unwrap();
+                        let x = bv.clone_owned();
+                        let zero = DMatrix::from_element(m, n, T::zero());
+                        let actual = match side {
+                            Side::Left => product(T::one(), &triangle, &x, T::zero(), &zero),
+                            Side::Right => product(T::one(), &x, &triangle, T::zero(), &zero),
+                        };
+                        let context =
+                            format!("trsm on the {:?} with layouts {} and {}", side, i, j);
+                        assert_close(actual.as_slice(), scaled.as_slice(), &context);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn check_syrk<T: Element + Blas + nalgebra::Scalar>() {
+    let mut rng = Rng::new();
+    let (n, k) = (4, 3);
+    let (alpha, beta) = (T::random(&mut rng), T::random(&mut rng));
+    let a = random_matrix::<T>(&mut rng, n, k);
+    let c = random_matrix::<T>(&mut rng, n, n);
+    let full = product(alpha, &a, &a.transpose(), beta, &c);
+    for uplo in UPLOS {
+        let expected = DMatrix::from_fn(n, n, |i, j| {
+            if stored(uplo as u8, i, j) {
+                full[(i, j)]
+            } else {
+                c[(i, j)]
+            }
+        });
+        for (i, a) in layouts(&a).iter().enumerate() {
+            for (j, mut c) in layouts(&c).into_iter().enumerate() {
+                let mut cv = c.view_mut();
+                a.view().blas_syrk(uplo, alpha, beta, &mut cv).unwrap();
+                let context = format!("syrk with layouts {} and {}", i, j);
+                assert_close(cv.clone_owned().as_slice(), expected.as_slice(), &context);
+            }
+        }
+    }
+}
+
+#[test]
+fn gemm() {
+    check_gemm::<f32>();
+    check_gemm::<f64>();
+    check_gemm::<c32>();
+    check_gemm::<c64>();
+}
+
+#[test]
+fn gemv() {
+    check_gemv::<f32>();
+    check_gemv::<f64>();
+    check_gemv::<c32>();
+    check_gemv::<c64>();
+}
+
+#[test]
+fn trsm() {
+    check_trsm::<f32>();
+    check_trsm::<f64>();
+    check_trsm::<c32>();
+    check_trsm::<c64>();
+}
+
+#[test]
+fn syrk() {
+    check_syrk::<f32>();
+    check_syrk::<f64>();
+    check_syrk::<c32>();
+    check_syrk::<c64>();
+}
+
+#[test]
+fn conversions() {
+    let mut rng = Rng::new();
+    let values = random_matrix::<f64>(&mut rng, 3, 2);
+    for (i, mut stored) in layouts(&values).into_iter().enumerate() {
+        match mat_ref(&stored.view()) {
+            Some(a) => {
+                assert!(i < 2, "mat_ref with layout {}", i);
+                assert_eq!(
+                    (a.rows(), a.cols(), a.ld()),
+                    (3, 2, stored.strides.1 as Int)
+                );
+                for j in 0..2 {
+                    for r in 0..3 {
+                        assert_eq!(a.col(j).get(r), Some(&values[(r as usize, j as usize)]));
+                    }
+                }
+            }
+            None => assert!(i >= 2, "mat_ref with layout {}", i),
+        }
+        assert_eq!(mat_mut(&mut stored.view_mut()).is_some(), i < 2);
+    }
+
+    let mut x = DVector::from_vec(random::<f64>(&mut rng, 6));
+    let column = x.clone();
+    let xv = vec_ref(&column).unwrap();
+    assert_eq!(xv.inc(), 1);
+    assert_eq!(xv.as_slice(), Some(column.as_slice()));
+    let mut strided =
+        DVectorViewMut::<f64, Dyn, Dyn>::from_slice_with_strides_mut(x.as_mut_slice(), 3, 2, 1);
+    *vec_mut(&mut strided).unwrap().get_mut(1).unwrap() = 7.0;
+    assert_eq!(x[2], 7.0);
+}
+
+#[test]
+fn errors() {
+    let a = DMatrix::<f64>::zeros(2, 3);
+    let b = DMatrix::<f64>::zeros(3, 4);
+    let mut c = DMatrix::<f64>::zeros(2, 5);
+    assert_eq!(
+        a.blas_gemm(&b, &mut c),
+        Err(BlasError::InvalidArgument {
+            routine: "blas_gemm",
+            param: 2,
+            reason: Reason::DimensionMismatch {
+                actual: 5,
+                expected: 4,
+            },
+        }),
+    );
+    let x = DVector::<f64>::zeros(3);
+    let mut y = DVector::<f64>::zeros(3);
+    assert_eq!(
+        a.blas_gemv(&x, &mut y),
+        Err(BlasError::InvalidArgument {
+            routine: "blas_gemv",
+            param: 2,
+            reason: Reason::DimensionMismatch {
+                actual: 3,
+                expected: 2,
+            },
+        }),
+    );
+    let mut b = DMatrix::<f64>::zeros(3, 2);
+    assert_eq!(
+        a.blas_trsm(Side::Right, Uplo::Lower, Diag::Unit, 1.0, &mut b),
+        Err(BlasError::InvalidArgument {
+            routine: "blas_trsm",
+            param: 0,
+            reason: Reason::DimensionMismatch {
+                actual: 3,
+                expected: 2,
+            },
+        }),
+    );
+    let mut c = DMatrix::<f64>::zeros(2, 3);
+    assert_eq!(
+        a.blas_syrk(Uplo::Upper, 1.0, 0.0, &mut c),
+        Err(BlasError::InvalidArgument {
+            routine: "blas_syrk",
+            param: 4,
+            reason: Reason::DimensionMismatch {
+                actual: 3,
+                expected: 2,
+            },
+        }),
+    );
+}
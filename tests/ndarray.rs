@@ -0,0 +1,216 @@
+#![cfg(all(feature = "ndarray", feature = "reference"))]
+
+mod common;
+
+use blas::interop::ndarray::MatrixExt;
+use blas::safe::{BlasError, Reason};
+use blas::*;
+use common::*;
+use ndarray::{s, Array1, Array2, ArrayView2, Axis, ShapeBuilder};
+
+fn product<T: Element>(
+    alpha: T,
+    a: ArrayView2<T>,
+    b: ArrayView2<T>,
+    beta: T,
+    c: &Array2<T>,
+) -> Array2<T> {
+    Array2::from_shape_fn(c.dim(), |(i, j)| {
+        let sum = (0..a.ncols()).fold(T::zero(), |sum, l| sum + a[[i, l]] * b[[l, j]]);
+        combine(alpha, sum, beta, c[[i, j]])
+    })
+}
+
+/// Return arrays holding the same random matrix in various layouts.
+fn layouts<T: Element>(rng: &mut Rng, rows: usize, cols: usize) -> Vec<Array2<T>> {
+    let values = Array2::from_shape_vec((rows, cols), random::<T>(rng, rows * cols)).unwrap();
+    let mut fortran = Array2::from_elem((rows, cols).f(), T::zero());
+    fortran.assign(&values);
+    let mut padded = Array2::from_elem((rows + 2, cols + 1), T::zero());
+    padded.slice_mut(s![1..rows + 1, 1..]).assign(&values);
+    let mut padded_fortran = Array2::from_elem((rows + 2, cols + 1).f(), T::zero());
+    padded_fortran
+        .slice_mut(s![1..rows + 1, 1..])
+        .assign(&values);
+    let mut reversed = Array2::from_elem((rows, cols), T::zero());
+    reversed.slice_mut(s![..;-1, ..]).assign(&values);
+    let mut strided = Array2::from_elem((2 * rows, cols).f(), T::zero());
+    strided.slice_mut(s![..;2, ..]).assign(&values);
+    vec![values, fortran, padded, padded_fortran, reversed, strided]
+}
+
+/// Return a view of the matrix held by an array returned by `layouts`.
+fn matrix_view<T>(array: &Array2<T>, index: usize, rows: usize) -> ArrayView2<'_, T> {
+    match index {
+        2 | 3 => array.slice(s![1..rows + 1, 1..]),
+        4 => array.slice(s![..;-1, ..]),
+        5 => array.slice(s![..;2, ..]),
+        _ => array.view(),
+    }
+}
+
+fn check_gemm<T: Element + Blas>() {
+    let mut rng = Rng::new();
+    let (m, n, k) = (3, 4, 2);
+    let (alpha, beta) = (T::random(&mut rng), T::random(&mut rng));
+    let a = layouts::<T>(&mut rng, m, k);
+    let b = layouts::<T>(&mut rng, k, n);
+    let c = layouts::<T>(&mut rng, m, n);
+    for (i, a) in a.iter().enumerate() {
+        let a = matrix_view(a, i, m);
+        for (j, b) in b.iter().enumerate() {
+            let b = matrix_view(b, j, k);
+            for (l, c) in c.iter().enumerate() {
+                let mut c = c.clone();
+                let mut cv = match l {
+                    2 | 3 => c.slice_mut(s![1..m + 1, 1..]),
+                    4 => c.slice_mut(s![..;-1, ..]),
+                    5 => c.slice_mut(s![..;2, ..]),
+                    _ => c.view_mut(),
+                };
+                let expected = product(alpha, a, b, beta, &cv.to_owned());
+                a.blas_gemm_scaled(alpha, &b, beta, &mut cv).unwrap();
+                let context = format!("gemm with layouts {}, {}, and {}", i, j, l);
+                assert_close(
+                    &cv.iter().copied().collect::<Vec<_>>(),
+                    &expected.iter().copied().collect::<Vec<_>>(),
+                    &context,
+                );
+            }
+        }
+    }
+
+    let a = &layouts::<T>(&mut rng, m, k)[0];
+    let b = &layouts::<T>(&mut rng, n, k)[1];
+    let mut c = Array2::from_elem((m, n), T::nan());
+    a.blas_gemm(&b.t(), &mut c).unwrap();
+    let expected = product(
+        T::one(),
+        a.view(),
+        b.t(),
+        T::zero(),
+        &Array2::from_elem((m, n), T::zero()),
+    );
+    assert_close(
+        c.as_slice().unwrap(),
+        expected.as_slice().unwrap(),
+        "gemm with a transposed view",
+    );
+}
+
+fn check_gemv<T: Element + Blas>() {
+    let mut rng = Rng::new();
+    let (m, n) = (3, 4);
+    let (alpha, beta) = (T::random(&mut rng), T::random(&mut rng));
+    let x = Array1::from(random::<T>(&mut rng, 2 * n));
+    let y = Array1::from(random::<T>(&mut rng, 2 * m));
+    let scalar = Array1::from(vec![T::random(&mut rng)]);
+    for (i, a) in layouts::<T>(&mut rng, m, n).iter().enumerate() {
+        let a = matrix_view(a, i, m);
+        let xs = [
+            x.slice(s![..n]),
+            x.slice(s![..;2]),
+            x.slice(s![..;-2]),
+            scalar.broadcast(n).unwrap(),
+        ];
+        for (j, x) in xs.iter().enumerate() {
+            let mut y = y.clone();
+            let mut yv = if j % 2 == 0 {
+                y.slice_mut(s![..m])
+            } else {
+                y.slice_mut(s![..;-2])
+            };
+            let column = x.view().insert_axis(Axis(1));
+            let expected = product(alpha, a, column, beta, &yv.to_owned().insert_axis(Axis(1)));
+            a.blas_gemv_scaled(alpha, x, beta, &mut yv).unwrap();
+            let context = format!("gemv with layouts {} and {}", i, j);
+            assert_close(
+                &yv.to_vec(),
+                &expected.into_raw_vec_and_offset().0,
+                &context,
+            );
+        }
+    }
+}
+
+#[test]
+fn gemm() {
+    check_gemm::<f32>();
+    check_gemm::<f64>();
+    check_gemm::<c32>();
+    check_gemm::<c64>();
+}
+
+#[test]
+fn gemv() {
+    check_gemv::<f32>();
+    check_gemv::<f64>();
+    check_gemv::<c32>();
+    check_gemv::<c64>();
+}
+
+#[test]
+fn errors() {
+    let a = Array2::<f64>::zeros((2, 3));
+    let b = Array2::<f64>::zeros((3, 4));
+    let mut c = Array2::<f64>::zeros((2, 5));
+    assert_eq!(
+        a.blas_gemm(&b, &mut c),
+        Err(BlasError::InvalidArgument {
+            routine: "blas_gemm",
+            param: 2,
+            reason: Reason::DimensionMismatch {
+                actual: 5,
+                expected: 4,
+            },
+        }),
+    );
+    let x = Array1::<f64>::zeros(2);
+    let mut y = Array1::<f64>::zeros(2);
+    assert_eq!(
+        a.blas_gemv_scaled(1.0, &x, 0.0, &mut y),
+        Err(BlasError::InvalidArgument {
+            routine: "blas_gemv_scaled",
+            param: 2,
+            reason: Reason::DimensionMismatch {
+                actual: 2,
+                expected: 3,
+            },
+        }),
+    );
+}
+
+#[test]
+fn split() {
+    let a = Array2::<f64>::ones((2, 3));
+    let b = Array2::<f64>::ones((3, 2));
+    let mut c = Array2::from_shape_vec((4, 2).f(), (0..8).map(f64::from).collect()).unwrap();
+    let (mut top, bottom) = c.view_mut().split_at(Axis(0), 2);
+    a.blas_gemm(&b, &mut top).unwrap();
+    assert_eq!(top, Array2::from_elem((2, 2), 3.0));
+    assert_eq!(bottom, ndarray::array![[2.0, 6.0], [3.0, 7.0]]);
+}
+
+#[test]
+fn sub_block() {
+    let a = Array2::<f64>::ones((2, 3));
+    let b = Array2::<f64>::ones((3, 2));
+    let mut c = Array2::from_shape_vec((4, 4).f(), (0..16).map(f64::from).collect()).unwrap();
+    let mut block = c.slice_mut(s![1..3, 1..3]);
+    assert_eq!(block.strides(), &[1, 4]);
+    a.blas_gemm(&b, &mut block).unwrap();
+    let expected = ndarray::array![
+        [0.0, 4.0, 8.0, 12.0],
+        [1.0, 3.0, 3.0, 13.0],
+        [2.0, 3.0, 3.0, 14.0],
+        [3.0, 7.0, 11.0, 15.0],
+    ];
+    assert_eq!(c, expected);
+
+    let x = Array1::<f64>::ones(3);
+    let mut row = c.row_mut(3);
+    let mut y = row.slice_mut(s![..3]);
+    assert_eq!(y.strides(), &[4]);
+    a.t().blas_gemv(&x.slice(s![..2]), &mut y).unwrap();
+    assert_eq!(c.row(3), ndarray::array![2.0, 2.0, 2.0, 15.0]);
+}
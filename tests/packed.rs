@@ -0,0 +1,252 @@
+#![cfg(feature = "reference")]
+
+mod common;
+
+use blas::safe::{BlasError, Reason};
+use blas::view::*;
+use blas::*;
+use common::*;
+
+const UPLOS: [Uplo; 2] = [Uplo::Upper, Uplo::Lower];
+
+fn check_storage<T: Element + Blas>() {
+    let mut rng = Rng::new();
+    let n = 4;
+    let a = matrix::<T>(&mut rng, n, leading(n));
+    for uplo in UPLOS {
+        let flag = uplo as u8;
+        let symmetric = PackedSymmetric::from_dense(uplo, n as Int, &a, leading(n) as Int).unwrap();
+        let hermitian = PackedHermitian::from_dense(uplo, n as Int, &a, leading(n) as Int).unwrap();
+        let triangular =
+            PackedTriangular::from_dense(uplo, Diag::Unit, n as Int, &a, leading(n) as Int)
+                .unwrap();
+        for j in 0..n {
+            for i in 0..n {
+                if stored(flag, i, j) {
+                    assert_eq!(
+                        symmetric.as_slice()[packed(flag, n, i, j)],
+                        a[i + j * leading(n)]
+                    );
+                }
+                let element = |i: usize, j: usize| a[i + j * leading(n)];
+                let (k, l) = (i as Int, j as Int);
+                assert_eq!(
+                    symmetric.get(k, l),
+                    Some(common::symmetric(flag, false, element, i, j))
+                );
+                assert_eq!(
+                    hermitian.get(k, l),
+                    Some(common::symmetric(flag, true, element, i, j))
+                );
+                assert_eq!(
+                    triangular.get(k, l),
+                    Some(common::triangular(flag, b'U', element, i, j))
+                );
+            }
+        }
+        assert_eq!(symmetric.get(n as Int, 0), None);
+        assert_eq!(symmetric.get(0, -1), None);
+
+        let dense = symmetric.to_dense();
+        let copy = PackedSymmetric::from_dense(uplo, n as Int, &dense, n as Int).unwrap();
+        assert_eq!(copy.as_slice(), symmetric.as_slice());
+
+        let mut borrowed =
+            PackedSymmetric::new(uplo, n as Int, symmetric.as_slice().to_vec()).unwrap();
+        let (i, j) = if uplo == Uplo::Upper { (0, 2) } else { (2, 0) };
+        assert!(borrowed.get_mut(j, i).is_none());
+        *borrowed.get_mut(i, j).unwrap() = T::one();
+        assert_eq!(borrowed.get(j, i), Some(T::one()));
+    }
+}
+
+fn check_symmetric<T: Element + Blas<Real = T>>() {
+    let mut rng = Rng::new();
+    let n = 4;
+    let a = matrix::<T>(&mut rng, n, n);
+    for uplo in UPLOS {
+        let mut ap = PackedSymmetric::from_dense(uplo, n as Int, &a, n as Int).unwrap();
+        let x = vector::<T>(&mut rng, n, 2);
+        let mut y = vector::<T>(&mut rng, n, -1);
+        let (alpha, beta) = (T::random(&mut rng), T::random(&mut rng));
+        let dense = ap.to_dense();
+        let mut expected = y.clone();
+        let xv = VecRef::new(&x, n as Int, 2).unwrap();
+        let av = MatRef::new(&dense, n as Int, n as Int, n as Int).unwrap();
+        hemv(
+            uplo,
+            alpha,
+            av,
+            xv,
+            beta,
+            VecMut::new(&mut expected, n as Int, -1).unwrap(),
+        )
+        .unwrap();
+        ap.mul_vec(alpha, xv, beta, VecMut::new(&mut y, n as Int, -1).unwrap())
+            .unwrap();
+        assert_close(&y, &expected, "mul_vec");
+
+        let y = vector::<T>(&mut rng, n, 1);
+        let yv = VecRef::from(&y[..]);
+        let mut dense = ap.to_dense();
+        let mut av = MatMut::new(&mut dense, n as Int, n as Int, n as Int).unwrap();
+        her(uplo, alpha, xv, av.view_mut()).unwrap();
+        her2(uplo, beta, xv, yv, av).unwrap();
+        ap.rank1_update(alpha, xv).unwrap();
+        ap.rank2_update(beta, xv, yv).unwrap();
+        let expected = PackedSymmetric::from_dense(uplo, n as Int, &dense, n as Int).unwrap();
+        assert_close(ap.as_slice(), expected.as_slice(), "rank updates");
+    }
+}
+
+fn check_hermitian<T: Element + Blas>() {
+    let mut rng = Rng::new();
+    let n = 4;
+    let a = matrix::<T>(&mut rng, n, n);
+    for uplo in UPLOS {
+        let mut ap = PackedHermitian::from_dense(uplo, n as Int, &a, n as Int).unwrap();
+        let x = vector::<T>(&mut rng, n, -2);
+        let mut y = vector::<T>(&mut rng, n, 1);
+        let (alpha, beta) = (T::random(&mut rng), T::random(&mut rng));
+        let dense = ap.to_dense();
+        let mut expected = y.clone();
+        let xv = VecRef::new(&x, n as Int, -2).unwrap();
+        let av = MatRef::new(&dense, n as Int, n as Int, n as Int).unwrap();
+        hemv(uplo, alpha, av, xv, beta, VecMut::from(&mut expected[..])).unwrap();
+        ap.mul_vec(alpha, xv, beta, VecMut::from(&mut y[..]))
+            .unwrap();
+        assert_close(&y, &expected, "mul_vec");
+
+        let scale = alpha.re();
+        let mut dense = ap.to_dense();
+        let mut av = MatMut::new(&mut dense, n as Int, n as Int, n as Int).unwrap();
+        her(uplo, scale, xv, av.view_mut()).unwrap();
+        her2(uplo, beta, xv, VecRef::from(&y[..]), av).unwrap();
+        ap.rank1_update(scale, xv).unwrap();
+        ap.rank2_update(beta, xv, VecRef::from(&y[..])).unwrap();
+        let expected = PackedHermitian::from_dense(uplo, n as Int, &dense, n as Int).unwrap();
+        assert_close(ap.as_slice(), expected.as_slice(), "rank updates");
+    }
+}
+
+fn check_triangular<T: Element + Blas>() {
+    let mut rng = Rng::new();
+    let n = 4;
+    let mut a = matrix::<T>(&mut rng, n, n);
+    strengthen(&mut a, n, n);
+    for uplo in UPLOS {
+        for diag in [Diag::NonUnit, Diag::Unit] {
+            for trans in [Transpose::None, Transpose::Ordinary, Transpose::Conjugate] {
+                let ap = PackedTriangular::from_dense(uplo, diag, n as Int, &a, n as Int).unwrap();
+                let av = MatRef::new(&a, n as Int, n as Int, n as Int).unwrap();
+                let x = vector::<T>(&mut rng, n, 2);
+
+                let mut expected = x.clone();
+                let mut actual = x.clone();
+                trmv(
+                    uplo,
+                    trans,
+                    diag,
+                    av,
+                    VecMut::new(&mut expected, n as Int, 2).unwrap(),
+                )
+                .unwrap();
+                ap.mul_vec_in_place(trans, VecMut::new(&mut actual, n as Int, 2).unwrap())
+                    .unwrap();
+                assert_close(&actual, &expected, "mul_vec_in_place");
+
+                let mut expected = x.clone();
+                let mut actual = x;
+                trsv(
+                    uplo,
+                    trans,
+                    diag,
+                    av,
+                    VecMut::new(&mut expected, n as Int, 2).unwrap(),
+                )
+                .unwrap();
+                ap.solve_in_place(trans, VecMut::new(&mut actual, n as Int, 2).unwrap())
+                    .unwrap();
+                assert_close(&actual, &expected, "solve_in_place");
+            }
+        }
+    }
+}
+
+#[test]
+fn storage() {
+    check_storage::<f32>();
+    check_storage::<f64>();
+    check_storage::<c32>();
+    check_storage::<c64>();
+}
+
+#[test]
+fn symmetric() {
+    check_symmetric::<f32>();
+    check_symmetric::<f64>();
+}
+
+#[test]
+fn hermitian() {
+    check_hermitian::<f32>();
+    check_hermitian::<f64>();
+    check_hermitian::<c32>();
+    check_hermitian::<c64>();
+}
+
+#[test]
+fn triangular() {
+    check_triangular::<f32>();
+    check_triangular::<f64>();
+    check_triangular::<c32>();
+    check_triangular::<c64>();
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        PackedSymmetric::new(Uplo::Upper, 3, [0.0f64; 5]).unwrap_err(),
+        BlasError::BufferTooShort {
+            routine: "PackedSymmetric::new",
+            param: 3,
+            required: 6,
+            actual: 5,
+        },
+    );
+    assert_eq!(
+        PackedTriangular::new(Uplo::Lower, Diag::Unit, -1, [0.0f64; 0]).unwrap_err(),
+        BlasError::InvalidArgument {
+            routine: "PackedTriangular::new",
+            param: 3,
+            reason: Reason::NegativeDimension(-1),
+        },
+    );
+    assert_eq!(
+        PackedHermitian::<f64, Vec<f64>>::from_dense(Uplo::Upper, 3, &[0.0; 9], 2).unwrap_err(),
+        BlasError::InvalidArgument {
+            routine: "PackedHermitian::from_dense",
+            param: 4,
+            reason: Reason::LeadingDimension {
+                actual: 2,
+                minimum: 3,
+            },
+        },
+    );
+
+    let data = [1.0f64; 6];
+    let ap = PackedSymmetric::new(Uplo::Upper, 3, &data[..]).unwrap();
+    let x = [1.0; 2];
+    let mut y = [0.0; 3];
+    assert_eq!(
+        ap.mul_vec(1.0, VecRef::from(&x[..]), 0.0, VecMut::from(&mut y[..])),
+        Err(BlasError::InvalidArgument {
+            routine: "PackedSymmetric::mul_vec",
+            param: 2,
+            reason: Reason::DimensionMismatch {
+                actual: 2,
+                expected: 3,
+            },
+        }),
+    );
+}
@@ -0,0 +1,453 @@
+use blas::c64;
+use blas::safe::*;
+
+fn short(routine: &'static str, param: usize, required: usize, actual: usize) -> BlasError {
+    BlasError::BufferTooShort {
+        routine,
+        param,
+        required,
+        actual,
+    }
+}
+
+fn invalid(routine: &'static str, param: usize, reason: Reason) -> BlasError {
+    BlasError::InvalidArgument {
+        routine,
+        param,
+        reason,
+    }
+}
+
+#[test]
+fn level1() {
+    let x = [1.0; 5];
+    let mut y = [0.0; 5];
+    assert_eq!(
+        daxpy(3, 1.0, &x, 3, &mut y, 1),
+        Err(short("daxpy", 3, 7, 5))
+    );
+    assert_eq!(
+        daxpy(3, 1.0, &x, 1, &mut y, -3),
+        Err(short("daxpy", 5, 7, 5))
+    );
+    assert_eq!(ddot(6, &x, 1, &y, 1), Err(short("ddot", 2, 6, 5)));
+    assert_eq!(ddot(3, &x, 1, &y, 3), Err(short("ddot", 4, 7, 5)));
+    assert_eq!(
+        dswap(2, &mut y, 5, &mut [0.0; 2], 1),
+        Err(short("dswap", 2, 6, 5))
+    );
+    assert_eq!(dcopy(3, &x, 2, &mut y, -4), Err(short("dcopy", 4, 9, 5)));
+    assert_eq!(dscal(2, 2.0, &mut y, 5), Err(short("dscal", 3, 6, 5)));
+    assert_eq!(dnrm2(6, &x, 1), Err(short("dnrm2", 2, 6, 5)));
+    assert_eq!(dasum(3, &x, -3), Err(short("dasum", 2, 7, 5)));
+    assert_eq!(idamax(3, &x, 4), Err(short("idamax", 2, 9, 5)));
+    assert_eq!(
+        drot(5, &mut [0.0; 4], 1, &mut y, 1, 1.0, 0.0),
+        Err(short("drot", 2, 5, 4)),
+    );
+    assert_eq!(
+        drotm(2, &mut [0.0; 2], 1, &mut [0.0; 2], 1, &[0.0; 4]),
+        Err(short("drotm", 6, 5, 4)),
+    );
+    let (mut d1, mut d2, mut x1) = (1.0, 1.0, 1.0);
+    assert_eq!(
+        drotmg(&mut d1, &mut d2, &mut x1, 1.0, &mut [0.0; 3]),
+        Err(short("drotmg", 5, 5, 3)),
+    );
+    assert_eq!(daxpy(0, 1.0, &[], 1, &mut [], 1), Ok(()));
+
+    let error = short("daxpy", 3, 7, 5);
+    assert_eq!(
+        error.to_string(),
+        "daxpy: parameter 3 requires at least 7 elements but has 5",
+    );
+}
+
+#[test]
+fn level2() {
+    let a = [0.0; 6];
+    let x = [0.0; 3];
+    let mut y = [0.0; 2];
+    assert_eq!(
+        dgemv(b'X', 2, 3, 1.0, &a, 2, &x, 1, 0.0, &mut y, 1),
+        Err(invalid("dgemv", 1, Reason::InvalidFlag(b'X'))),
+    );
+    assert_eq!(
+        dgemv(b'N', -1, 3, 1.0, &a, 2, &x, 1, 0.0, &mut y, 1),
+        Err(invalid("dgemv", 2, Reason::NegativeDimension(-1))),
+    );
+    assert_eq!(
+        dgemv(b'N', 2, -3, 1.0, &a, 2, &x, 1, 0.0, &mut y, 1),
+        Err(invalid("dgemv", 3, Reason::NegativeDimension(-3))),
+    );
+    assert_eq!(
+        dgemv(b'N', 2, 3, 1.0, &a, 1, &x, 1, 0.0, &mut y, 1),
+        Err(invalid(
+            "dgemv",
+            6,
+            Reason::LeadingDimension {
+                actual: 1,
+                minimum: 2
+            }
+        )),
+    );
+    assert_eq!(
+        dgemv(b'N', 2, 3, 1.0, &a, 2, &x, 0, 0.0, &mut y, 1),
+        Err(invalid("dgemv", 8, Reason::ZeroIncrement)),
+    );
+    assert_eq!(
+        dgemv(b'N', 2, 3, 1.0, &a, 2, &x, 1, 0.0, &mut y, 0),
+        Err(invalid("dgemv", 11, Reason::ZeroIncrement)),
+    );
+    assert_eq!(
+        dgemv(b'N', 2, 3, 1.0, &a[..5], 2, &x, 1, 0.0, &mut y, 1),
+        Err(short("dgemv", 5, 6, 5)),
+    );
+    assert_eq!(
+        dgemv(b'N', 2, 3, 1.0, &a, 2, &x[..2], 1, 0.0, &mut y, 1),
+        Err(short("dgemv", 7, 3, 2)),
+    );
+    assert_eq!(
+        dgemv(b'T', 2, 3, 1.0, &a, 2, &x, 1, 0.0, &mut y, 1),
+        Err(short("dgemv", 10, 3, 2)),
+    );
+
+    // A band matrix of order 3 with one subdiagonal and one superdiagonal.
+    let band = [0.0; 9];
+    let mut z = [0.0; 3];
+    assert_eq!(
+        dgbmv(b'N', 3, 3, -1, 1, 1.0, &band, 3, &x, 1, 0.0, &mut z, 1),
+        Err(invalid("dgbmv", 4, Reason::NegativeDimension(-1))),
+    );
+    assert_eq!(
+        dgbmv(b'N', 3, 3, 1, -1, 1.0, &band, 3, &x, 1, 0.0, &mut z, 1),
+        Err(invalid("dgbmv", 5, Reason::NegativeDimension(-1))),
+    );
+    assert_eq!(
+        dgbmv(b'N', 3, 3, 1, 1, 1.0, &band, 2, &x, 1, 0.0, &mut z, 1),
+        Err(invalid(
+            "dgbmv",
+            8,
+            Reason::LeadingDimension {
+                actual: 2,
+                minimum: 3
+            }
+        )),
+    );
+    assert_eq!(
+        dgbmv(b'N', 3, 3, 1, 1, 1.0, &band[..8], 3, &x, 1, 0.0, &mut z, 1),
+        Err(short("dgbmv", 7, 9, 8)),
+    );
+    assert_eq!(
+        dgbmv(b'N', 3, 3, 1, 1, 1.0, &band, 3, &x, 1, 0.0, &mut z, -2),
+        Err(short("dgbmv", 12, 5, 3)),
+    );
+    assert_eq!(
+        dsbmv(b'U', 3, -1, 1.0, &band, 2, &x, 1, 0.0, &mut z, 1),
+        Err(invalid("dsbmv", 3, Reason::NegativeDimension(-1))),
+    );
+    assert_eq!(
+        dsbmv(b'U', 3, 2, 1.0, &band, 2, &x, 1, 0.0, &mut z, 1),
+        Err(invalid(
+            "dsbmv",
+            6,
+            Reason::LeadingDimension {
+                actual: 2,
+                minimum: 3
+            }
+        )),
+    );
+    assert_eq!(
+        dsbmv(b'U', 3, 1, 1.0, &band, 2, &x, 1, 0.0, &mut z, 0),
+        Err(invalid("dsbmv", 11, Reason::ZeroIncrement)),
+    );
+    assert_eq!(
+        dtbmv(b'U', b'N', b'X', 3, 1, &band, 2, &mut z, 1),
+        Err(invalid("dtbmv", 3, Reason::InvalidFlag(b'X'))),
+    );
+    assert_eq!(
+        dtbmv(b'U', b'N', b'N', 3, -2, &band, 2, &mut z, 1),
+        Err(invalid("dtbmv", 5, Reason::NegativeDimension(-2))),
+    );
+    assert_eq!(
+        dtbmv(b'U', b'N', b'N', 3, 2, &band, 2, &mut z, 1),
+        Err(invalid(
+            "dtbmv",
+            7,
+            Reason::LeadingDimension {
+                actual: 2,
+                minimum: 3
+            }
+        )),
+    );
+    assert_eq!(
+        dtbsv(b'L', b'T', b'U', 3, 2, &band[..8], 3, &mut z, 1),
+        Err(short("dtbsv", 6, 9, 8)),
+    );
+
+    assert_eq!(
+        dspmv(b'U', 3, 1.0, &band[..5], &x, 1, 0.0, &mut z, 1),
+        Err(short("dspmv", 4, 6, 5)),
+    );
+    assert_eq!(
+        dtpsv(b'L', b'N', b'N', 3, &band, &mut z, 0),
+        Err(invalid("dtpsv", 7, Reason::ZeroIncrement)),
+    );
+    assert_eq!(
+        dtrsv(b'X', b'N', b'N', 3, &band, 3, &mut z, 1),
+        Err(invalid("dtrsv", 1, Reason::InvalidFlag(b'X'))),
+    );
+    assert_eq!(
+        dtrsv(b'U', b'N', b'N', 3, &band, 2, &mut z, 1),
+        Err(invalid(
+            "dtrsv",
+            6,
+            Reason::LeadingDimension {
+                actual: 2,
+                minimum: 3
+            }
+        )),
+    );
+    assert_eq!(
+        dger(-2, 3, 1.0, &x, 1, &x, 1, &mut band.clone(), 2),
+        Err(invalid("dger", 1, Reason::NegativeDimension(-2))),
+    );
+    assert_eq!(
+        dger(3, 3, 1.0, &x, 1, &x, 1, &mut band.clone(), 2),
+        Err(invalid(
+            "dger",
+            9,
+            Reason::LeadingDimension {
+                actual: 2,
+                minimum: 3
+            }
+        )),
+    );
+    assert_eq!(
+        dger(3, 3, 1.0, &x, 1, &x, 1, &mut [0.0; 8], 3),
+        Err(short("dger", 8, 9, 8)),
+    );
+    assert_eq!(
+        dsyr2(b'L', 3, 1.0, &x, 1, &x, 0, &mut band.clone(), 3),
+        Err(invalid("dsyr2", 7, Reason::ZeroIncrement)),
+    );
+
+    let error = invalid("dgemv", 8, Reason::ZeroIncrement);
+    assert_eq!(error.to_string(), "dgemv: parameter 8 is a zero increment");
+}
+
+#[test]
+fn level3() {
+    let a = [0.0; 6];
+    let b = [0.0; 12];
+    let mut c = [0.0; 8];
+    assert_eq!(
+        dgemm(b'X', b'N', 2, 4, 3, 1.0, &a, 2, &b, 3, 0.0, &mut c, 2),
+        Err(invalid("dgemm", 1, Reason::InvalidFlag(b'X'))),
+    );
+    assert_eq!(
+        dgemm(b'N', b'x', 2, 4, 3, 1.0, &a, 2, &b, 3, 0.0, &mut c, 2),
+        Err(invalid("dgemm", 2, Reason::InvalidFlag(b'x'))),
+    );
+    assert_eq!(
+        dgemm(b'N', b'N', -2, 4, 3, 1.0, &a, 2, &b, 3, 0.0, &mut c, 2),
+        Err(invalid("dgemm", 3, Reason::NegativeDimension(-2))),
+    );
+    assert_eq!(
+        dgemm(b'N', b'N', 2, -4, 3, 1.0, &a, 2, &b, 3, 0.0, &mut c, 2),
+        Err(invalid("dgemm", 4, Reason::NegativeDimension(-4))),
+    );
+    assert_eq!(
+        dgemm(b'N', b'N', 2, 4, -3, 1.0, &a, 2, &b, 3, 0.0, &mut c, 2),
+        Err(invalid("dgemm", 5, Reason::NegativeDimension(-3))),
+    );
+    assert_eq!(
+        dgemm(b'T', b'N', 2, 4, 3, 1.0, &a, 2, &b, 3, 0.0, &mut c, 2),
+        Err(invalid(
+            "dgemm",
+            8,
+            Reason::LeadingDimension {
+                actual: 2,
+                minimum: 3
+            }
+        )),
+    );
+    assert_eq!(
+        dgemm(b'N', b'T', 2, 4, 3, 1.0, &a, 2, &b, 3, 0.0, &mut c, 2),
+        Err(invalid(
+            "dgemm",
+            10,
+            Reason::LeadingDimension {
+                actual: 3,
+                minimum: 4
+            }
+        )),
+    );
+    assert_eq!(
+        dgemm(b'N', b'N', 2, 4, 3, 1.0, &a, 2, &b, 3, 0.0, &mut c, 1),
+        Err(invalid(
+            "dgemm",
+            13,
+            Reason::LeadingDimension {
+                actual: 1,
+                minimum: 2
+            }
+        )),
+    );
+    assert_eq!(
+        dgemm(b'N', b'N', 2, 4, 3, 1.0, &a[..5], 2, &b, 3, 0.0, &mut c, 2),
+        Err(short("dgemm", 7, 6, 5)),
+    );
+    assert_eq!(
+        dgemm(b'N', b'N', 2, 4, 3, 1.0, &a, 2, &b[..11], 3, 0.0, &mut c, 2),
+        Err(short("dgemm", 9, 12, 11)),
+    );
+    assert_eq!(
+        dgemm(b'N', b'N', 2, 4, 3, 1.0, &a, 2, &b, 3, 0.0, &mut c[..7], 2),
+        Err(short("dgemm", 12, 8, 7)),
+    );
+
+    assert_eq!(
+        dsymm(b'X', b'U', 2, 3, 1.0, &b, 3, &a, 2, 0.0, &mut c, 2),
+        Err(invalid("dsymm", 1, Reason::InvalidFlag(b'X'))),
+    );
+    assert_eq!(
+        dsymm(b'R', b'U', 2, 3, 1.0, &b, 2, &a, 2, 0.0, &mut c, 2),
+        Err(invalid(
+            "dsymm",
+            7,
+            Reason::LeadingDimension {
+                actual: 2,
+                minimum: 3
+            }
+        )),
+    );
+    assert_eq!(
+        dsymm(b'L', b'U', 2, 3, 1.0, &b, 2, &a, 1, 0.0, &mut c, 2),
+        Err(invalid(
+            "dsymm",
+            9,
+            Reason::LeadingDimension {
+                actual: 1,
+                minimum: 2
+            }
+        )),
+    );
+    assert_eq!(
+        dsymm(b'L', b'U', 2, 3, 1.0, &b, 2, &a, 2, 0.0, &mut c[..5], 2),
+        Err(short("dsymm", 11, 6, 5)),
+    );
+    assert_eq!(
+        dsyrk(b'U', b'T', 3, 2, 1.0, &a, 1, 0.0, &mut b.clone(), 3),
+        Err(invalid(
+            "dsyrk",
+            7,
+            Reason::LeadingDimension {
+                actual: 1,
+                minimum: 2
+            }
+        )),
+    );
+    assert_eq!(
+        dsyrk(b'U', b'N', 3, 2, 1.0, &a, 3, 0.0, &mut b.clone(), 2),
+        Err(invalid(
+            "dsyrk",
+            10,
+            Reason::LeadingDimension {
+                actual: 2,
+                minimum: 3
+            }
+        )),
+    );
+    assert_eq!(
+        dsyr2k(b'L', b'N', 3, 2, 1.0, &a, 3, &a, 2, 0.0, &mut b.clone(), 3),
+        Err(invalid(
+            "dsyr2k",
+            9,
+            Reason::LeadingDimension {
+                actual: 2,
+                minimum: 3
+            }
+        )),
+    );
+    assert_eq!(
+        dtrsm(b'L', b'U', b'N', b'X', 2, 3, 1.0, &a, 2, &mut c, 2),
+        Err(invalid("dtrsm", 4, Reason::InvalidFlag(b'X'))),
+    );
+    assert_eq!(
+        dtrsm(b'L', b'U', b'N', b'N', -2, 3, 1.0, &a, 2, &mut c, 2),
+        Err(invalid("dtrsm", 5, Reason::NegativeDimension(-2))),
+    );
+    assert_eq!(
+        dtrsm(b'R', b'U', b'N', b'N', 2, 3, 1.0, &b, 2, &mut c, 2),
+        Err(invalid(
+            "dtrsm",
+            9,
+            Reason::LeadingDimension {
+                actual: 2,
+                minimum: 3
+            }
+        )),
+    );
+    assert_eq!(
+        dtrmm(b'L', b'U', b'N', b'N', 2, 3, 1.0, &a, 2, &mut c, 1),
+        Err(invalid(
+            "dtrmm",
+            11,
+            Reason::LeadingDimension {
+                actual: 1,
+                minimum: 2
+            }
+        )),
+    );
+    assert_eq!(
+        dtrmm(b'L', b'U', b'N', b'N', 2, 3, 1.0, &a[..3], 2, &mut c, 2),
+        Err(short("dtrmm", 8, 4, 3)),
+    );
+
+    let a = [c64::new(0.0, 0.0); 6];
+    let mut c = [c64::new(0.0, 0.0); 9];
+    assert_eq!(
+        zherk(b'U', b'T', 3, 2, 1.0, &a, 3, 0.0, &mut c, 3),
+        Err(invalid("zherk", 2, Reason::InvalidFlag(b'T'))),
+    );
+    assert_eq!(
+        zsyrk(
+            b'U',
+            b'C',
+            3,
+            2,
+            c64::new(1.0, 0.0),
+            &a,
+            3,
+            c64::new(0.0, 0.0),
+            &mut c,
+            3
+        ),
+        Err(invalid("zsyrk", 2, Reason::InvalidFlag(b'C'))),
+    );
+    assert_eq!(
+        zher2k(
+            b'U',
+            b'C',
+            3,
+            2,
+            c64::new(1.0, 0.0),
+            &a,
+            2,
+            &a,
+            1,
+            0.0,
+            &mut c,
+            3
+        ),
+        Err(invalid(
+            "zher2k",
+            9,
+            Reason::LeadingDimension {
+                actual: 1,
+                minimum: 2
+            }
+        )),
+    );
+}
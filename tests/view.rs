@@ -0,0 +1,438 @@
+#![cfg(feature = "reference")]
+
+mod common;
+
+use blas::safe::{BlasError, Reason};
+use blas::view::*;
+use blas::*;
+use common::*;
+
+fn mismatch(routine: &'static str, param: usize, actual: Int, expected: Int) -> BlasError {
+    BlasError::InvalidArgument {
+        routine,
+        param,
+        reason: Reason::DimensionMismatch { actual, expected },
+    }
+}
+
+#[test]
+fn constructors() {
+    let mut data = vec![0.0f64; 10];
+    assert!(VecRef::new(&data, 4, 3).is_ok());
+    assert!(VecRef::new(&data, 4, -3).is_ok());
+    assert_eq!(
+        VecRef::new(&data, 4, 4).unwrap_err(),
+        BlasError::BufferTooShort {
+            routine: "VecRef::new",
+            param: 1,
+            required: 13,
+            actual: 10,
+        },
+    );
+    assert_eq!(
+        VecMut::new(&mut data, 2, 0).unwrap_err(),
+        BlasError::InvalidArgument {
+            routine: "VecMut::new",
+            param: 3,
+            reason: Reason::ZeroIncrement,
+        },
+    );
+    assert_eq!(VecRef::from(&data[..]).len(), 10);
+
+    assert!(MatRef::new(&data, 2, 3, 4).is_ok());
+    assert!(MatRef::new(&data, 0, 5, 1).is_ok());
+    assert_eq!(
+        MatRef::new(&data, 3, 3, 2).unwrap_err(),
+        BlasError::InvalidArgument {
+            routine: "MatRef::new",
+            param: 4,
+            reason: Reason::LeadingDimension {
+                actual: 2,
+                minimum: 3,
+            },
+        },
+    );
+    assert_eq!(
+        MatMut::new(&mut data, 3, 4, 3).unwrap_err(),
+        BlasError::BufferTooShort {
+            routine: "MatMut::new",
+            param: 1,
+            required: 12,
+            actual: 10,
+        },
+    );
+    assert_eq!(
+        MatMut::new(&mut data, -1, 2, 1).unwrap_err(),
+        BlasError::InvalidArgument {
+            routine: "MatMut::new",
+            param: 2,
+            reason: Reason::NegativeDimension(-1),
+        },
+    );
+}
+
+fn check_level1<T: Element + Blas>() {
+    let mut rng = Rng::new();
+    let n = 5;
+    for (incx, incy) in INCREMENTS {
+        let x = vector::<T>(&mut rng, n, incx);
+        let mut y = vector::<T>(&mut rng, n, incy);
+        let alpha = T::random(&mut rng);
+        let mut expected = y.clone();
+        unsafe { T::axpy(n as Int, alpha, &x, incx, &mut expected, incy) };
+        let xv = VecRef::new(&x, n as Int, incx).unwrap();
+        let mut yv = VecMut::new(&mut y, n as Int, incy).unwrap();
+        axpy(alpha, xv, yv.view_mut()).unwrap();
+        let actual = dotc(xv, yv.view()).unwrap();
+        assert_eq!(y, expected);
+        let expected = unsafe { T::dotc(n as Int, &x, incx, &y, incy) };
+        assert_eq!(actual, expected);
+    }
+    let x = random::<T>(&mut rng, 3);
+    let mut y = random::<T>(&mut rng, 4);
+    assert_eq!(
+        copy(VecRef::from(&x[..]), VecMut::from(&mut y[..])),
+        Err(mismatch("copy", 2, 4, 3)),
+    );
+}
+
+fn check_level2<T: Element + Blas>() {
+    let mut rng = Rng::new();
+    for trans in [Transpose::None, Transpose::Ordinary, Transpose::Conjugate] {
+        let (m, n) = (3, 4);
+        let (rows, cols) = if trans == Transpose::None {
+            (m, n)
+        } else {
+            (n, m)
+        };
+        let a = matrix::<T>(&mut rng, n, leading(m));
+        let x = vector::<T>(&mut rng, cols, 2);
+        let mut y = vector::<T>(&mut rng, rows, -1);
+        let (alpha, beta) = (T::random(&mut rng), T::random(&mut rng));
+        let mut expected = y.clone();
+        unsafe {
+            T::gemv(
+                trans,
+                m as Int,
+                n as Int,
+                alpha,
+                &a,
+                leading(m) as Int,
+                &x,
+                2,
+                beta,
+                &mut expected,
+                -1,
+            )
+        };
+        let av = MatRef::new(&a, m as Int, n as Int, leading(m) as Int).unwrap();
+        let xv = VecRef::new(&x, cols as Int, 2).unwrap();
+        let mut yv = VecMut::new(&mut y, rows as Int, -1).unwrap();
+        gemv(trans, alpha, av, xv, beta, yv.view_mut()).unwrap();
+        assert_eq!(y, expected);
+
+        let short = VecRef::new(&x, cols as Int - 1, 2).unwrap();
+        let mut y = vector::<T>(&mut rng, rows, 1);
+        let yv = VecMut::new(&mut y, rows as Int, 1).unwrap();
+        assert_eq!(
+            gemv(trans, alpha, av, short, beta, yv),
+            Err(mismatch("gemv", 4, cols as Int - 1, cols as Int)),
+        );
+    }
+    let mut a = matrix::<T>(&mut rng, 3, 2);
+    let x = random::<T>(&mut rng, 2);
+    let av = MatMut::new(&mut a, 2, 3, 2).unwrap();
+    assert_eq!(
+        her(Uplo::Upper, T::Real::one(), VecRef::from(&x[..]), av),
+        Err(mismatch("her", 4, 3, 2)),
+    );
+}
+
+fn check_structured<T: Element + Blas>() {
+    let mut rng = Rng::new();
+    let n = 3;
+    let mut a = matrix::<T>(&mut rng, n, n);
+    strengthen(&mut a, n, n);
+    let b = matrix::<T>(&mut rng, 2, n);
+    let x = random::<T>(&mut rng, n);
+    let (alpha, beta) = (T::random(&mut rng), T::random(&mut rng));
+    let (scale, shift) = (alpha.re(), beta.re());
+    let av = MatRef::new(&a, 3, 3, 3).unwrap();
+    let bv = MatRef::new(&b, 3, 2, 3).unwrap();
+    let xv = VecRef::from(&x[..]);
+    for uplo in [Uplo::Upper, Uplo::Lower] {
+        let hermitian = Hermitian::new(uplo, av).unwrap();
+        assert_eq!((hermitian.n(), hermitian.uplo()), (3, uplo));
+        let mut y = random::<T>(&mut rng, n);
+        let mut expected = y.clone();
+        hemv(uplo, alpha, av, xv, beta, VecMut::from(&mut expected[..])).unwrap();
+        hermitian
+            .mul_vec(alpha, xv, beta, VecMut::from(&mut y[..]))
+            .unwrap();
+        assert_eq!(y, expected);
+
+        let mut c = matrix::<T>(&mut rng, 2, 3);
+        let mut expected = c.clone();
+        let cv = MatMut::new(&mut expected, 3, 2, 3).unwrap();
+        hemm(Side::Left, uplo, alpha, av, bv, beta, cv).unwrap();
+        let cv = MatMut::new(&mut c, 3, 2, 3).unwrap();
+        hermitian.mul_mat(Side::Left, alpha, bv, beta, cv).unwrap();
+        assert_eq!(c, expected);
+
+        let mut c = a.clone();
+        let mut expected = a.clone();
+        let cv = MatMut::new(&mut expected, 3, 3, 3).unwrap();
+        herk(uplo, NoneOrConj::None, scale, bv, shift, cv).unwrap();
+        let cv = MatMut::new(&mut c, 3, 3, 3).unwrap();
+        let mut hermitian = Hermitian::new_mut(uplo, cv).unwrap();
+        hermitian
+            .rank_k_update(NoneOrConj::None, scale, bv, shift)
+            .unwrap();
+        hermitian.rank1_update(scale, xv).unwrap();
+        let cv = MatMut::new(&mut expected, 3, 3, 3).unwrap();
+        her(uplo, scale, xv, cv).unwrap();
+        assert_eq!(c, expected);
+
+        let mut c = a.clone();
+        let mut expected = a.clone();
+        let cv = MatMut::new(&mut expected, 3, 3, 3).unwrap();
+        syr2k(uplo, NoneOrTrans::None, alpha, bv, bv, beta, cv).unwrap();
+        let cv = MatMut::new(&mut c, 3, 3, 3).unwrap();
+        let mut symmetric = Symmetric::new_mut(uplo, cv).unwrap();
+        symmetric
+            .rank_2k_update(NoneOrTrans::None, alpha, bv, bv, beta)
+            .unwrap();
+        assert_eq!(symmetric.view().n(), 3);
+        assert_eq!(c, expected);
+
+        for diag in [Diag::NonUnit, Diag::Unit] {
+            let triangular = Triangular::new(uplo, diag, av).unwrap();
+            for trans in [Transpose::None, Transpose::Conjugate] {
+                let mut y = x.clone();
+                let mut expected = x.clone();
+                trsv(uplo, trans, diag, av, VecMut::from(&mut expected[..])).unwrap();
+                triangular
+                    .solve_in_place(trans, VecMut::from(&mut y[..]))
+                    .unwrap();
+                assert_eq!(y, expected);
+
+                let mut c = b.clone();
+                let mut expected = b.clone();
+                let cv = MatMut::new(&mut expected, 2, 3, 2).unwrap();
+                trmm(Side::Right, uplo, trans, diag, alpha, av, cv).unwrap();
+                let cv = MatMut::new(&mut c, 2, 3, 2).unwrap();
+                triangular
+                    .mul_mat_in_place(Side::Right, trans, alpha, cv)
+                    .unwrap();
+                assert_eq!(c, expected);
+            }
+        }
+    }
+    assert_eq!(
+        Symmetric::new(Uplo::Upper, bv).unwrap_err(),
+        mismatch("Symmetric::new", 2, 2, 3),
+    );
+}
+
+fn check_level3<T: Element + Blas>() {
+    let mut rng = Rng::new();
+    let none = Transpose::None;
+    for transa in [none, Transpose::Ordinary] {
+        for transb in [none, Transpose::Conjugate] {
+            let (m, n, k) = (2, 3, 4);
+            let (rowsa, colsa) = if transa == none { (m, k) } else { (k, m) };
+            let (rowsb, colsb) = if transb == none { (k, n) } else { (n, k) };
+            let (lda, ldb, ldc) = (leading(rowsa), leading(rowsb), leading(m));
+            let a = matrix::<T>(&mut rng, colsa, lda);
+            let b = matrix::<T>(&mut rng, colsb, ldb);
+            let mut c = matrix::<T>(&mut rng, n, ldc);
+            let (alpha, beta) = (T::random(&mut rng), T::random(&mut rng));
+            let mut expected = c.clone();
+            unsafe {
+                T::gemm(
+                    transa,
+                    transb,
+                    m as Int,
+                    n as Int,
+                    k as Int,
+                    alpha,
+                    &a,
+                    lda as Int,
+                    &b,
+                    ldb as Int,
+                    beta,
+                    &mut expected,
+                    ldc as Int,
+                )
+            };
+            let av = MatRef::new(&a, rowsa as Int, colsa as Int, lda as Int).unwrap();
+            let bv = MatRef::new(&b, rowsb as Int, colsb as Int, ldb as Int).unwrap();
+            let mut cv = MatMut::new(&mut c, m as Int, n as Int, ldc as Int).unwrap();
+            gemm(transa, transb, alpha, av, bv, beta, cv.view_mut()).unwrap();
+            let rows = if transa == none { rowsb } else { colsb };
+            assert_eq!(
+                gemm(transa, transb, alpha, bv, av, beta, cv),
+                Err(mismatch("gemm", 7, m as Int, rows as Int)),
+            );
+            assert_eq!(c, expected);
+        }
+    }
+    let a = matrix::<T>(&mut rng, 3, 3);
+    let mut b = matrix::<T>(&mut rng, 2, 3);
+    let av = MatRef::new(&a, 3, 3, 3).unwrap();
+    let bv = MatMut::new(&mut b, 3, 2, 3).unwrap();
+    assert_eq!(
+        trsm(
+            Side::Right,
+            Uplo::Lower,
+            none,
+            Diag::NonUnit,
+            T::one(),
+            av,
+            bv
+        ),
+        Err(mismatch("trsm", 6, 3, 2)),
+    );
+    let mut c = matrix::<T>(&mut rng, 2, 3);
+    let cv = MatMut::new(&mut c, 3, 2, 3).unwrap();
+    assert_eq!(
+        syrk(Uplo::Upper, NoneOrTrans::None, T::one(), av, T::zero(), cv),
+        Err(mismatch("syrk", 6, 2, 3)),
+    );
+}
+
+#[test]
+fn lines() {
+    let (rows, cols, ld) = (3, 4, 5);
+    let mut data: Vec<f64> = (0..ld * (cols - 1) + rows).map(|i| i as f64).collect();
+    let element = |i: Int, j: Int| (i + j * ld) as f64;
+    let a = MatRef::new(&data, rows, cols, ld).unwrap();
+    assert_eq!((a.row(1).len(), a.row(1).inc()), (cols, ld));
+    assert_eq!(
+        asum(a.row(1)),
+        (0..cols).map(|j| element(1, j)).sum::<f64>()
+    );
+    assert_eq!(
+        asum(a.col(2)),
+        (0..rows).map(|i| element(i, 2)).sum::<f64>()
+    );
+    assert_eq!(iamax(a.col(3)), rows as usize);
+    assert_eq!(
+        asum(a.diag(0)),
+        element(0, 0) + element(1, 1) + element(2, 2)
+    );
+    assert_eq!(asum(a.diag(2)), element(0, 2) + element(1, 3));
+    assert_eq!(asum(a.diag(-2)), element(2, 0));
+    assert_eq!(a.diag(3).len(), 1);
+    let sum = (0..rows).map(|i| element(i, 0)).sum::<f64>();
+    assert_eq!(dot(a.col(0), VecRef::from(&[1.0; 3][..])).unwrap(), sum);
+
+    let mut a = MatMut::new(&mut data, rows, cols, ld).unwrap();
+    scal(2.0, a.row_mut(2));
+    scal(-1.0, a.diag_mut(1));
+    assert_eq!(data[2 + ld as usize], 2.0 * element(2, 1));
+    assert_eq!(data[2 + 3 * ld as usize], -2.0 * element(2, 3));
+    assert_eq!(data[1 + 2 * ld as usize], -element(1, 2));
+
+    let empty = MatRef::new(&[0.0f32; 0], 0, 0, 1).unwrap();
+    assert!(empty.diag(0).is_empty());
+}
+
+#[test]
+fn reversed() {
+    let x = vec![1.0, 0.0, 2.0, 0.0, 3.0];
+    let mut y = vec![10.0, 20.0, 30.0];
+    let xv = VecRef::new(&x, 3, 2).unwrap();
+    assert_eq!(xv.rev().inc(), -2);
+    assert_eq!(
+        xv.rev().iter().copied().collect::<Vec<_>>(),
+        [3.0, 2.0, 1.0]
+    );
+    assert_eq!(xv.rev().get(0), Some(&3.0));
+    assert_eq!(xv.rev().get(3), None);
+    assert_eq!(
+        dot(xv.rev(), VecRef::from(&y[..])).unwrap(),
+        30.0 + 40.0 + 30.0
+    );
+
+    let mut yv = VecMut::from(&mut y[..]).rev();
+    *yv.get_mut(0).unwrap() = 0.0;
+    axpy(1.0, xv, yv).unwrap();
+    assert_eq!(y, [13.0, 22.0, 1.0]);
+
+    let data: Vec<f64> = (0..6).map(f64::from).collect();
+    let a = MatRef::new(&data, 2, 3, 2).unwrap();
+    assert_eq!(
+        a.row(1).rev().iter().copied().collect::<Vec<_>>(),
+        [5.0, 3.0, 1.0]
+    );
+    assert_eq!(iamax(a.row(1).rev()), 1);
+    assert_eq!(iamax(a.row(1)), 3);
+    assert_eq!(asum(a.row(1).rev()), 9.0);
+    assert_eq!(nrm2(a.col(2).rev()), nrm2(a.col(2)));
+
+    let x = [4.0, 1.0, -7.0, 2.0];
+    assert_eq!(blas::safe::idamax(4, &x, -1), Ok(2));
+    assert_eq!(blas::safe::idamax(2, &x, -3), Ok(2));
+    assert_eq!(blas::safe::dasum(2, &x, -2), Ok(11.0));
+
+    let mut x = vec![1.0, 0.0, 2.0, 0.0, 3.0];
+    scal(2.0, VecMut::new(&mut x, 3, 2).unwrap().rev());
+    assert_eq!(x, [2.0, 0.0, 4.0, 0.0, 6.0]);
+    let mut z = vec![c64::new(1.0, 1.0), c64::new(2.0, -1.0)];
+    rscal(3.0, VecMut::from(&mut z[..]).rev());
+    assert_eq!(z, [c64::new(3.0, 3.0), c64::new(6.0, -3.0)]);
+
+    let mut x = [4.0, 1.0, -7.0, 2.0];
+    assert_eq!(blas::safe::dscal(2, -1.0, &mut x, -3), Ok(()));
+    assert_eq!(x, [-4.0, 1.0, -7.0, -2.0]);
+    assert_eq!(blas::safe::zdscal(2, 2.0, &mut z, -1), Ok(()));
+    assert_eq!(z, [c64::new(6.0, 6.0), c64::new(12.0, -6.0)]);
+}
+
+#[test]
+fn level1() {
+    check_level1::<f32>();
+    check_level1::<f64>();
+    check_level1::<c32>();
+    check_level1::<c64>();
+}
+
+#[test]
+fn level2() {
+    check_level2::<f32>();
+    check_level2::<f64>();
+    check_level2::<c32>();
+    check_level2::<c64>();
+}
+
+#[test]
+fn level3() {
+    check_level3::<f32>();
+    check_level3::<f64>();
+    check_level3::<c32>();
+    check_level3::<c64>();
+}
+
+#[test]
+fn structured() {
+    check_structured::<f32>();
+    check_structured::<f64>();
+    check_structured::<c32>();
+    check_structured::<c64>();
+
+    let a = [4.0, 1.0, 0.0, 2.0, 5.0, 3.0, 0.0, 6.0, 7.0];
+    let x = [1.0, -1.0, 2.0];
+    let symmetric = Symmetric::new(Uplo::Lower, MatRef::new(&a, 3, 3, 3).unwrap()).unwrap();
+    let mut y = [1.0; 3];
+    symmetric
+        .mul_vec(2.0, VecRef::from(&x[..]), 1.0, VecMut::from(&mut y[..]))
+        .unwrap();
+    assert_eq!(y, [7.0, 5.0, 23.0]);
+
+    let mut a = a;
+    let mut symmetric =
+        Symmetric::new_mut(Uplo::Upper, MatMut::new(&mut a, 3, 3, 3).unwrap()).unwrap();
+    symmetric.rank1_update(1.0, VecRef::from(&x[..])).unwrap();
+    assert_eq!(a, [5.0, 1.0, 0.0, 1.0, 6.0, 3.0, 2.0, 4.0, 11.0]);
+}
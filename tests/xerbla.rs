@@ -0,0 +1,56 @@
+#![cfg(any(feature = "reference", feature = "xerbla"))]
+
+use blas::*;
+
+#[cfg(feature = "reference")]
+#[test]
+fn reference() {
+    let a = vec![1.0; 4];
+    let x = vec![1.0; 2];
+    let mut y = vec![0.0; 2];
+    assert_eq!(
+        catch_blas_errors(|| unsafe { dgemv(b'X', 2, 2, 1.0, &a, 2, &x, 1, 0.0, &mut y, 1) }),
+        Err(XerblaError {
+            routine: "DGEMV".into(),
+            param: 1,
+        }),
+    );
+    assert_eq!(y, vec![0.0; 2]);
+    assert_eq!(
+        catch_blas_errors(|| unsafe { dgemv(b'N', 2, 2, 1.0, &a, 2, &x, 1, 0.0, &mut y, 1) }),
+        Ok(()),
+    );
+    assert_eq!(y, vec![2.0; 2]);
+
+    let mut c = vec![0.0; 4];
+    let result = catch_blas_errors(|| {
+        let inner = catch_blas_errors(|| unsafe {
+            dgemmt(b'U', b'N', b'N', 2, 2, 1.0, &a, 1, &a, 2, 0.0, &mut c, 2)
+        });
+        unsafe { ddot(2, &x, 1, &x, 1) + inner.unwrap_err().param as f64 }
+    });
+    assert_eq!(result, Ok(10.0));
+
+    let result = std::panic::catch_unwind(|| unsafe {
+        let mut y = vec![0.0; 2];
+        dgemv(b'N', -1, 2, 1.0, &[], 1, &[], 1, 0.0, &mut y, 1)
+    });
+    assert!(result.is_err());
+}
+
+#[cfg(feature = "xerbla")]
+#[test]
+fn symbol() {
+    use blas::xerbla::xerbla_;
+
+    let name = b"DTRSM ";
+    let result = catch_blas_errors(|| unsafe { xerbla_(name.as_ptr() as *const _, &11, 6) });
+    let error = result.unwrap_err();
+    assert_eq!(error.routine, "DTRSM");
+    assert_eq!(error.param, 11);
+    assert_eq!(
+        error.to_string(),
+        "on entry to DTRSM parameter number 11 had an illegal value",
+    );
+    unsafe { xerbla_(name.as_ptr() as *const _, &2, 6) };
+}